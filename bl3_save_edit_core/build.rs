@@ -35,6 +35,7 @@ fn main() {
         "game_data/PROFILE_HEADS_DEFAULTS.csv",
         "game_data/PROFILE_SKINS.csv",
         "game_data/PROFILE_SKINS_DEFAULTS.csv",
+        "game_data/PROFILE_EVENT_COSMETICS.csv",
         "game_data/BALANCE_NAME_MAPPING.csv",
         "game_data/BALANCE_TO_INV_KEY.csv",
     ];
@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::fmt::Formatter;
 use std::str::FromStr;
 
@@ -6,6 +7,7 @@ use bitflags::bitflags;
 use bitvec::prelude::*;
 use byteorder::{BigEndian, WriteBytesExt};
 use encoding_rs::mem::decode_latin1;
+use once_cell::sync::Lazy;
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use serde::Deserialize;
 use strum::{Display, EnumString};
@@ -13,16 +15,29 @@ use tracing::warn;
 use base64::prelude::*;
 
 use crate::arbitrary_bits::{ArbitraryBitVec, ArbitraryBits};
+use crate::content_source::ContentSource;
+use crate::data_overrides;
 use crate::game_data::{BALANCE_NAME_MAPPING, BALANCE_TO_INV_KEY};
 use crate::parser::read_be_signed_int;
 use crate::resources::{
-    INVENTORY_INV_DATA_PARTS, INVENTORY_PARTS_ALL_CATEGORIZED, INVENTORY_SERIAL_DB,
+    ResourceCategorizedParts, INVENTORY_INV_DATA_PARTS, INVENTORY_SERIAL_DB,
     INVENTORY_SERIAL_DB_PARTS_CATEGORIZED,
 };
 
 pub const MAX_BL3_ITEM_PARTS: usize = 63;
 pub const MAX_BL3_ITEM_ANOINTMENTS: usize = 15;
 
+// Balance names are looked up for every item header, so avoid scanning the whole mapping each time.
+// Reversed so the first entry wins when an ident appears more than once.
+pub(crate) static BALANCE_NAMES_BY_SHORT_IDENT: Lazy<HashMap<&'static str, &'static str>> =
+    Lazy::new(|| {
+        BALANCE_NAME_MAPPING
+            .iter()
+            .rev()
+            .map(|gd| (gd.ident.rsplit('/').next().unwrap_or(gd.ident), gd.name))
+            .collect()
+    });
+
 bitflags! {
     #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
     pub struct ItemFlags: i32 {
@@ -40,6 +55,8 @@ pub struct Bl3Item {
     pub serial_version: u8,
     pub orig_seed: i32,
     decrypted_serial: Vec<u8>,
+    // No sourced table maps data versions to game patches, so this isn't used to guess the patch
+    // an item dropped on
     pub data_version: usize,
     pub balance_bits: usize,
     balance_part: BalancePart,
@@ -75,6 +92,26 @@ impl Bl3ItemParts {
     pub fn generic_parts(&self) -> &Vec<Bl3Part> {
         &self.generic_parts
     }
+
+    /// The part categories the serial db has for this item, including the limits of each.
+    pub fn categories(&self) -> Option<&'static [ResourceCategorizedParts]> {
+        INVENTORY_SERIAL_DB_PARTS_CATEGORIZED
+            .get(&self.part_inv_key)
+            .map(|c| c.as_slice())
+    }
+
+    pub fn part_category(&self, part: &Bl3Part) -> Option<&'static ResourceCategorizedParts> {
+        self.categories()?
+            .iter()
+            .find(|c| c.find_part(part).is_some())
+    }
+
+    pub fn category_part_count(&self, category: &str) -> usize {
+        self.parts
+            .iter()
+            .filter(|p| self.part_category(p).map(|c| c.category.as_str()) == Some(category))
+            .count()
+    }
 }
 
 #[derive(Debug, Clone, Default, Eq, PartialEq, Ord, PartialOrd, Deserialize)]
@@ -85,6 +122,12 @@ pub struct BalancePart {
     pub idx: usize,
 }
 
+impl BalancePart {
+    pub fn content_source(&self) -> ContentSource {
+        ContentSource::from_asset_path(&self.ident)
+    }
+}
+
 impl std::fmt::Display for BalancePart {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -97,6 +140,12 @@ impl std::fmt::Display for BalancePart {
             write!(f, " - ({})", name)?;
         }
 
+        let content_source = self.content_source();
+
+        if content_source != ContentSource::BaseGame {
+            write!(f, " [{}]", content_source.short_name())?;
+        }
+
         Ok(())
     }
 }
@@ -145,6 +194,16 @@ pub struct Bl3Part {
     pub idx: usize,
 }
 
+impl Bl3Part {
+    pub fn content_source(&self) -> ContentSource {
+        ContentSource::from_asset_path(&self.ident)
+    }
+
+    pub fn is_mayhem_part(&self) -> bool {
+        self.ident.contains("/MayhemParts/")
+    }
+}
+
 #[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Ord, PartialOrd, Display, EnumString)]
 pub enum ItemType {
     #[strum(serialize = "BPInvPart_Artifact_C", to_string = "Artifact")]
@@ -218,49 +277,38 @@ pub enum WeaponType {
     Heavy,
 }
 
-impl Bl3Item {
-    pub fn from_serial_bytes(serial: &[u8], flags: Option<ItemFlags>) -> Result<Self> {
-        if serial.len() < 5 {
-            bail!("Serial length must be longer than 4 characters.");
-        }
-
-        let initial_byte = serial[0];
-
-        if initial_byte != 3 && initial_byte != 4 {
-            bail!("Serial version was not 3 or 4 so we do not know how to decrypt this item.");
-        }
-
-        let serial_version = initial_byte;
-
-        let orig_seed = read_be_signed_int(&serial[1..5])?.1;
-
-        let mut serial = serial.to_vec();
-
-        let decrypted_serial = Self::bogodecrypt(&mut serial[5..], orig_seed);
-
-        let orig_checksum = &decrypted_serial[..2];
-
-        let data_to_checksum = [&serial[..5], b"\xFF\xFF", &decrypted_serial[2..]].concat();
-
-        let mut hasher = crc32fast::Hasher::new();
-        hasher.update(&data_to_checksum);
-        let computed_crc = hasher.finalize();
-
-        let mut computed_checksum = Vec::with_capacity(2);
-
-        computed_checksum
-            .write_u16::<BigEndian>((((computed_crc >> 16) ^ computed_crc) & 0xFFFF) as u16)?;
-
-        if orig_checksum != computed_checksum {
-            bail!("The expected checksum when deserializing this item does not match the original checksum");
-        }
+/// Everything stored in a serial before the item's parts, enough to show an item in a list without
+/// decoding all of its parts.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Bl3ItemHeader {
+    pub serial_version: u8,
+    pub data_version: usize,
+    pub balance_bits: usize,
+    pub balance_part: BalancePart,
+    pub inv_data_bits: usize,
+    pub inv_data_part: InvDataPart,
+    pub manufacturer_bits: usize,
+    pub manufacturer_part: ManufacturerPart,
+    pub level: usize,
+    pub rarity: ItemRarity,
+    pub weapon_type: Option<WeaponType>,
+    pub flags: Option<ItemFlags>,
+}
 
-        // What we will actually store
-        let decrypted_serial = &decrypted_serial[2..];
+impl Bl3ItemHeader {
+    pub fn from_serial_bytes(serial: &[u8], flags: Option<ItemFlags>) -> Result<Self> {
+        let (serial_version, _, decrypted_serial) = Bl3Item::decrypt_serial(serial)?;
 
-        // parse the serial data
         let mut bits = ArbitraryBits::new(decrypted_serial.view_bits::<Lsb0>());
 
+        Self::read(&mut bits, serial_version, flags)
+    }
+
+    fn read(
+        bits: &mut ArbitraryBits,
+        serial_version: u8,
+        flags: Option<ItemFlags>,
+    ) -> Result<Self> {
         let ident = bits.eat(8)?;
 
         // Ident will be 0 if is item is not obfuscated
@@ -278,13 +326,13 @@ impl Bl3Item {
         }
 
         let (balance, balance_bits, balance_idx) =
-            Self::inv_db_header_part("InventoryBalanceData", &mut bits, data_version)?;
+            Bl3Item::inv_db_header_part("InventoryBalanceData", bits, data_version)?;
 
         let (inv_data, inv_data_bits, inv_data_idx) =
-            Self::inv_db_header_part("InventoryData", &mut bits, data_version)?;
+            Bl3Item::inv_db_header_part("InventoryData", bits, data_version)?;
 
         let (manufacturer, manufacturer_bits, manufacturer_idx) =
-            Self::inv_db_header_part("ManufacturerData", &mut bits, data_version)?;
+            Bl3Item::inv_db_header_part("ManufacturerData", bits, data_version)?;
 
         let manufacturer_short = manufacturer.rsplit('.').next().map(|s| s.to_owned());
 
@@ -292,41 +340,72 @@ impl Bl3Item {
 
         let balance_short_name = balance.rsplit('.').next().map(|s| s.to_owned());
 
-        let item_part_data = &INVENTORY_PARTS_ALL_CATEGORIZED;
-        let item_part_info = balance_short_name
-            .as_ref()
-            .and_then(|bs| item_part_data.get(bs));
+        // User supplied overrides are checked before the embedded data
+        let rarity = data_overrides::balance_rarity(&balance).unwrap_or_default();
 
-        let balance_lower = balance.to_lowercase();
-        let balance_short_name_lower = balance_short_name
-            .as_ref()
-            .map(|b| b.to_lowercase())
-            .unwrap_or_else(|| balance_lower.clone());
+        let balance_eng_name = data_overrides::balance_name(&balance);
 
-        let balance_eng_name = BALANCE_NAME_MAPPING
-            .par_iter()
-            .find_first(|gd| {
-                balance_short_name_lower == gd.ident.rsplit('/').next().unwrap_or(gd.ident)
-            })
-            .map(|gd| gd.name.to_owned());
-
-        let balance_part = BalancePart {
-            ident: balance.clone(),
-            short_ident: balance_short_name,
-            name: balance_eng_name,
-            idx: balance_idx,
+        let weapon_type = match &balance {
+            b if b.contains("_PS_") => Some(WeaponType::Pistol),
+            b if b.contains("_SG_") => Some(WeaponType::Shotgun),
+            b if b.contains("_SM_") => Some(WeaponType::Smg),
+            b if b.contains("_AR_") => Some(WeaponType::Ar),
+            b if b.contains("_SR_") => Some(WeaponType::Sniper),
+            b if b.contains("_HW_") => Some(WeaponType::Heavy),
+            _ => None,
         };
 
-        let inv_data_part = InvDataPart {
-            ident: inv_data,
-            idx: inv_data_idx,
-        };
+        Ok(Self {
+            serial_version,
+            data_version,
+            balance_bits,
+            balance_part: BalancePart {
+                ident: balance,
+                short_ident: balance_short_name,
+                name: balance_eng_name,
+                idx: balance_idx,
+            },
+            inv_data_bits,
+            inv_data_part: InvDataPart {
+                ident: inv_data,
+                idx: inv_data_idx,
+            },
+            manufacturer_bits,
+            manufacturer_part: ManufacturerPart {
+                ident: manufacturer,
+                short_ident: manufacturer_short,
+                idx: manufacturer_idx,
+            },
+            level,
+            rarity,
+            weapon_type,
+            flags,
+        })
+    }
+}
 
-        let manufacturer_part = ManufacturerPart {
-            ident: manufacturer,
-            short_ident: manufacturer_short,
-            idx: manufacturer_idx,
-        };
+impl Bl3Item {
+    pub fn from_serial_bytes(serial: &[u8], flags: Option<ItemFlags>) -> Result<Self> {
+        let (serial_version, orig_seed, decrypted_serial) = Self::decrypt_serial(serial)?;
+
+        // parse the serial data
+        let mut bits = ArbitraryBits::new(decrypted_serial.view_bits::<Lsb0>());
+
+        let Bl3ItemHeader {
+            data_version,
+            balance_bits,
+            balance_part,
+            inv_data_bits,
+            inv_data_part,
+            manufacturer_bits,
+            manufacturer_part,
+            level,
+            rarity,
+            weapon_type,
+            ..
+        } = Bl3ItemHeader::read(&mut bits, serial_version, flags)?;
+
+        let balance_lower = balance_part.ident.to_lowercase();
 
         let mut item_type = ItemType::Other;
 
@@ -374,20 +453,6 @@ impl Bl3Item {
                 bail!("Could not fully parse the item data, there was unexpected data left.")
             }
 
-            let rarity = item_part_info
-                .and_then(|info| ItemRarity::from_str(&info.rarity).ok())
-                .unwrap_or_default();
-
-            let weapon_type = match &balance {
-                b if b.contains("_PS_") => Some(WeaponType::Pistol),
-                b if b.contains("_SG_") => Some(WeaponType::Shotgun),
-                b if b.contains("_SM_") => Some(WeaponType::Smg),
-                b if b.contains("_AR_") => Some(WeaponType::Ar),
-                b if b.contains("_SR_") => Some(WeaponType::Sniper),
-                b if b.contains("_HW_") => Some(WeaponType::Heavy),
-                _ => None,
-            };
-
             item_type = if weapon_type.is_some() {
                 ItemType::Weapon
             } else {
@@ -416,8 +481,6 @@ impl Bl3Item {
             None
         };
 
-        let decrypted_serial = decrypted_serial.to_vec();
-
         Ok(Self {
             serial_version,
             orig_seed,
@@ -436,6 +499,71 @@ impl Bl3Item {
         })
     }
 
+    // Returns the serial version, the original seed and the decrypted serial without its checksum
+    fn decrypt_serial(serial: &[u8]) -> Result<(u8, i32, Vec<u8>)> {
+        if serial.len() < 5 {
+            bail!("Serial length must be longer than 4 characters.");
+        }
+
+        let initial_byte = serial[0];
+
+        if initial_byte != 3 && initial_byte != 4 {
+            bail!("Serial version was not 3 or 4 so we do not know how to decrypt this item.");
+        }
+
+        let serial_version = initial_byte;
+
+        let orig_seed = read_be_signed_int(&serial[1..5])?.1;
+
+        let mut serial = serial.to_vec();
+
+        let decrypted_serial = Self::bogodecrypt(&mut serial[5..], orig_seed);
+
+        let orig_checksum = &decrypted_serial[..2];
+
+        let data_to_checksum = [&serial[..5], b"\xFF\xFF", &decrypted_serial[2..]].concat();
+
+        let mut hasher = crc32fast::Hasher::new();
+        hasher.update(&data_to_checksum);
+        let computed_crc = hasher.finalize();
+
+        let mut computed_checksum = Vec::with_capacity(2);
+
+        computed_checksum
+            .write_u16::<BigEndian>((((computed_crc >> 16) ^ computed_crc) & 0xFFFF) as u16)?;
+
+        if orig_checksum != computed_checksum {
+            bail!("The expected checksum when deserializing this item does not match the original checksum");
+        }
+
+        // What we will actually store
+        Ok((serial_version, orig_seed, decrypted_serial[2..].to_vec()))
+    }
+
+    pub fn header(&self) -> Bl3ItemHeader {
+        Bl3ItemHeader {
+            serial_version: self.serial_version,
+            data_version: self.data_version,
+            balance_bits: self.balance_bits,
+            balance_part: self.balance_part.clone(),
+            inv_data_bits: self.inv_data_bits,
+            inv_data_part: self.inv_data_part.clone(),
+            manufacturer_bits: self.manufacturer_bits,
+            manufacturer_part: self.manufacturer_part.clone(),
+            level: self.level,
+            rarity: self
+                .item_parts
+                .as_ref()
+                .map(|ip| ip.rarity.clone())
+                .unwrap_or_default(),
+            weapon_type: self
+                .item_parts
+                .as_ref()
+                .and_then(|ip| ip.weapon_type.clone()),
+            flags: self.flags,
+        }
+    }
+
     pub fn from_serial_base64(serial: &str) -> Result<Self> {
         if serial.len() < 5 {
             bail!("Serial length must be longer than 4 characters.");
@@ -477,6 +605,14 @@ impl Bl3Item {
         Ok(encrypted_full)
     }
 
+    /// The length of the serial returned by [`Bl3Item::encrypt_serial`], without encrypting it.
+    pub fn encoded_len(&self) -> usize {
+        // Serial version, seed and checksum
+        let header_len = 1 + 4 + 2;
+
+        header_len + self.decrypted_serial.len()
+    }
+
     pub fn get_serial_number(&self, orig_seed: bool) -> Result<Vec<u8>> {
         let seed = if orig_seed { self.orig_seed } else { 0 };
 
@@ -501,6 +637,21 @@ impl Bl3Item {
         &self.balance_part
     }
 
+    /// The in-game name for the item's balance, e.g. `Hellwalker`, if it is a known balance.
+    pub fn display_name(&self) -> Option<&str> {
+        let short_ident = self
+            .balance_part
+            .ident
+            .rsplit('.')
+            .next()
+            .unwrap_or(&self.balance_part.ident)
+            .to_lowercase();
+
+        BALANCE_NAMES_BY_SHORT_IDENT
+            .get(short_ident.as_str())
+            .copied()
+    }
+
     pub fn inv_data_part(&self) -> &InvDataPart {
         &self.inv_data_part
     }
@@ -598,6 +749,16 @@ impl Bl3Item {
         self.level
     }
 
+    pub fn mayhem_level(&self) -> Option<usize> {
+        self.item_parts
+            .as_ref()?
+            .generic_parts
+            .iter()
+            .find(|p| p.is_mayhem_part())
+            .and_then(|p| p.ident.rsplit('_').next())
+            .and_then(|l| l.parse().ok())
+    }
+
     pub fn set_level(&mut self, new_level: usize) -> Result<()> {
         self.level = new_level;
 
@@ -606,6 +767,22 @@ impl Bl3Item {
         Ok(())
     }
 
+    /// Sets the level, the mayhem part is kept unless `keep_mayhem_part` is false in which case the
+    /// item goes back to not being mayhem scaled.
+    pub fn relevel(&mut self, new_level: usize, keep_mayhem_part: bool) -> Result<()> {
+        self.level = new_level;
+
+        if !keep_mayhem_part {
+            if let Some(item_parts) = &mut self.item_parts {
+                item_parts.generic_parts.retain(|p| !p.is_mayhem_part());
+            }
+        }
+
+        self.update_weapon_serial()?;
+
+        Ok(())
+    }
+
     pub fn remove_part(&mut self, part: &Bl3Part) -> Result<()> {
         if let Some(item_parts) = &mut self.item_parts {
             if let Some(part_index) = item_parts
@@ -622,6 +799,8 @@ impl Bl3Item {
         Ok(())
     }
 
+    // Any part is accepted. There's no sourced list of part combinations that crash the game, so
+    // items aren't checked for them or flagged as unstable.
     pub fn add_part(&mut self, part: Bl3Part) -> Result<()> {
         if let Some(item_parts) = &mut self.item_parts {
             item_parts.parts.push(part);
@@ -905,6 +1084,27 @@ impl Bl3Item {
     }
 }
 
+pub fn parse_shift_inventory_code(code: &str) -> Result<Vec<Bl3Item>> {
+    let serials = code
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>();
+
+    if serials.is_empty() {
+        bail!("No item serials were found in the code.")
+    }
+
+    serials
+        .into_iter()
+        .enumerate()
+        .map(|(i, serial)| {
+            Bl3Item::from_serial_base64(serial)
+                .with_context(|| format!("failed to read item number {}", i + 1))
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -981,4 +1181,193 @@ mod tests {
         Bl3Item::from_serial_base64("bl3(BMo1YGLGQ0MGYsI1/FbX0bJzzEAlJV/zmj/7qVR3P7k=)").unwrap();
         Bl3Item::from_serial_base64("bl3(BDcRFWih0RoFBasjJ57Z1Zlf1975cgf2ns3n+pGwL9wo0iSoqfEvpNLcQBqq+kyitN3iuNu36Njp0sLClYQHFp550i9NgKN5J6xn8H2YeH1Ugoqv)").unwrap();
     }
+
+    #[test]
+    fn test_parse_shift_inventory_code() {
+        let serial = "BL3(AwAAAABmboC7I9xAEzwShMJVX8nPYwsAAA==)";
+
+        let items = parse_shift_inventory_code(&format!(" {}, {} ,", serial, serial))
+            .expect("failed to parse shift inventory code");
+
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].item_type, ItemType::Shield);
+        assert_eq!(items[0], items[1]);
+
+        assert!(parse_shift_inventory_code("").is_err());
+        assert!(parse_shift_inventory_code(&format!("{},BL3(AAAA)", serial)).is_err());
+    }
+
+    #[test]
+    fn test_display_name() {
+        let mut item = Bl3Item::from_serial_base64("BL3(AwAAAABmboC7I9xAEzwShMJVX8nPYwsAAA==)")
+            .expect("failed to read item");
+
+        assert_eq!(item.display_name(), Some("Old God"));
+
+        item.balance_part.ident = "/Game/Unknown/InvBalD_Unknown.InvBalD_Unknown".to_owned();
+
+        assert_eq!(item.display_name(), None);
+    }
+
+    #[test]
+    fn test_part_categories() {
+        let item = Bl3Item::from_serial_base64("BL3(AwAAAABmboC7I9xAEzwShMJVX8nPYwsAAA==)")
+            .expect("failed to read item");
+
+        let item_parts = item.item_parts.as_ref().unwrap();
+
+        let categories = item_parts
+            .parts()
+            .iter()
+            .map(|p| item_parts.part_category(p).map(|c| c.category.as_str()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            categories,
+            vec![
+                Some("Body"),
+                Some("Rarity"),
+                Some("Legendary Aug"),
+                Some("Augment"),
+                Some("Augment"),
+                Some("Element"),
+                Some("Material"),
+            ]
+        );
+
+        let body = item_parts
+            .categories()
+            .unwrap()
+            .iter()
+            .find(|c| c.category == "Body")
+            .unwrap();
+
+        assert_eq!((body.min_parts(), body.max_parts()), (1, 1));
+        assert_eq!(item_parts.category_part_count("Augment"), 2);
+        assert_eq!(item_parts.category_part_count("Grip"), 0);
+    }
+
+    #[test]
+    fn test_mayhem_level() {
+        let mut item = Bl3Item::from_serial_base64("BL3(AwAAAABmboC7I9xAEzwShMJVX8nPYwsAAA==)")
+            .expect("failed to read item");
+
+        assert_eq!(item.mayhem_level(), None);
+
+        let mayhem_part = INVENTORY_SERIAL_DB
+            .get_part_by_short_name("InventoryGenericPartData", "Part_WeaponMayhemLevel_07")
+            .expect("failed to find part");
+
+        item.add_generic_part(mayhem_part)
+            .expect("failed to add generic part");
+
+        assert_eq!(item.mayhem_level(), Some(7));
+    }
+
+    #[test]
+    fn test_relevel_keeps_mayhem_part() {
+        let mut item = Bl3Item::from_serial_base64("BL3(AwAAAADPwoC+I9xAkzsShMJVX8nPZesOAAA=)")
+            .expect("failed to read item");
+
+        assert_eq!((item.level(), item.mayhem_level()), (57, Some(10)));
+
+        item.relevel(72, true).expect("failed to set level");
+
+        let read = Bl3Item::from_serial_base64(&item.get_serial_number_base64(false).unwrap())
+            .expect("failed to read item");
+
+        assert_eq!((read.level(), read.mayhem_level()), (72, Some(10)));
+    }
+
+    #[test]
+    fn test_relevel_drops_mayhem_part() {
+        let mut item = Bl3Item::from_serial_base64("BL3(AwAAAADPwoC+I9xAkzsShMJVX8nPZesOAAA=)")
+            .expect("failed to read item");
+
+        let generic_parts = item.item_parts.as_ref().unwrap().generic_parts().len();
+
+        item.relevel(72, false).expect("failed to set level");
+
+        let read = Bl3Item::from_serial_base64(&item.get_serial_number_base64(false).unwrap())
+            .expect("failed to read item");
+
+        assert_eq!((read.level(), read.mayhem_level()), (72, None));
+        assert_eq!(
+            read.item_parts.as_ref().unwrap().generic_parts().len(),
+            generic_parts - 1
+        );
+    }
+
+    #[test]
+    fn test_encoded_len_grows_with_parts() {
+        let mut item = Bl3Item::from_serial_base64("BL3(AwAAAABmboC7I9xAEzwShMJVX8nPYwsAAA==)")
+            .expect("failed to read item");
+
+        assert_eq!(
+            item.encoded_len(),
+            item.get_serial_number(false).unwrap().len()
+        );
+
+        let part = item.item_parts.as_ref().unwrap().parts()[3].clone();
+
+        let start_len = item.encoded_len();
+        let mut previous_len = start_len;
+
+        while item.item_parts.as_ref().unwrap().parts().len() < MAX_BL3_ITEM_PARTS {
+            item.add_part(part.clone()).expect("failed to add part");
+
+            let encoded_len = item.encoded_len();
+
+            assert!(encoded_len >= previous_len);
+            assert_eq!(encoded_len, item.get_serial_number(false).unwrap().len());
+
+            previous_len = encoded_len;
+        }
+
+        assert!(previous_len > start_len);
+    }
+
+    #[test]
+    fn test_header_decode_is_cheaper_than_full_decode() {
+        let serials = [
+            "BL3(AwAAAABmboC7I9xAEzwShMJVX8nPYwsAAA==)",
+            "bl3(BMo1YGLGQ0MGYsI1/FbX0bJzzEAlJV/zmj/7qVR3P7k=)",
+            "bl3(BDcRFWih0RoFBasjJ57Z1Zlf1975cgf2ns3n+pGwL9wo0iSoqfEvpNLcQBqq+kyitN3iuNu36Njp0sLClYQHFp550i9NgKN5J6xn8H2YeH1Ugoqv)",
+        ]
+        .iter()
+        .map(|s| BASE64_STANDARD.decode(&s[4..s.len() - 1]).unwrap())
+        .collect::<Vec<_>>();
+
+        for serial in &serials {
+            let item = Bl3Item::from_serial_bytes(serial, None).unwrap();
+            let header = Bl3ItemHeader::from_serial_bytes(serial, None).unwrap();
+
+            assert_eq!(item.header(), header);
+        }
+
+        let rounds = 200;
+
+        let header_start = std::time::Instant::now();
+        for _ in 0..rounds {
+            for serial in &serials {
+                Bl3ItemHeader::from_serial_bytes(serial, None).unwrap();
+            }
+        }
+        let header_elapsed = header_start.elapsed();
+
+        let full_start = std::time::Instant::now();
+        for _ in 0..rounds {
+            for serial in &serials {
+                Bl3Item::from_serial_bytes(serial, None).unwrap();
+            }
+        }
+        let full_elapsed = full_start.elapsed();
+
+        assert!(
+            header_elapsed * 2 < full_elapsed,
+            "header decode took {:?}, full decode took {:?}",
+            header_elapsed,
+            full_elapsed
+        );
+    }
 }
@@ -1,5 +1,7 @@
 use strum::{Display, EnumIter, EnumMessage, EnumString};
 
+pub const MAX_GUARDIAN_REWARD_TOKENS: i32 = i32::MAX;
+
 #[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Clone)]
 pub struct GuardianRewardData {
     pub reward: GuardianReward,
@@ -103,8 +105,100 @@ pub enum GuardianReward {
     VehicleDamage,
 }
 
+impl GuardianReward {
+    pub const ALL: [GuardianReward; 18] = [
+        GuardianReward::Accuracy,
+        GuardianReward::ActionSkillCooldown,
+        GuardianReward::CriticalDamage,
+        GuardianReward::ElementalDamage,
+        GuardianReward::FFYLDuration,
+        GuardianReward::FFYLMovementSpeed,
+        GuardianReward::GrenadeDamage,
+        GuardianReward::GunDamage,
+        GuardianReward::GunFireRate,
+        GuardianReward::MaxHealth,
+        GuardianReward::MeleeDamage,
+        GuardianReward::RarityRate,
+        GuardianReward::RecoilReduction,
+        GuardianReward::ReloadSpeed,
+        GuardianReward::ShieldCapacity,
+        GuardianReward::ShieldRechargeDelay,
+        GuardianReward::ShieldRechargeRate,
+        GuardianReward::VehicleDamage,
+    ];
+}
+
 impl std::default::Default for GuardianReward {
     fn default() -> Self {
         Self::Accuracy
     }
 }
+
+/// Splits `total` tokens evenly across `reward_count` rewards, giving whatever is left over from
+/// the division to the reward at `priority_index`. Every amount is clamped to `cap`.
+pub fn distribute_tokens(
+    total: i32,
+    reward_count: usize,
+    priority_index: usize,
+    cap: i32,
+) -> Vec<i32> {
+    if reward_count == 0 {
+        return Vec::new();
+    }
+
+    let total = total.max(0);
+    let cap = cap.max(0);
+
+    let share = total / reward_count as i32;
+    let remainder = total % reward_count as i32;
+
+    (0..reward_count)
+        .map(|i| {
+            let amount = if i == priority_index {
+                share.saturating_add(remainder)
+            } else {
+                share
+            };
+
+            amount.min(cap)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_distribute_tokens_exact_division() {
+        assert_eq!(distribute_tokens(900, 18, 0, i32::MAX), vec![50; 18]);
+    }
+
+    #[test]
+    fn test_distribute_tokens_remainder_goes_to_priority() {
+        let distributed = distribute_tokens(905, 18, 3, i32::MAX);
+
+        assert_eq!(distributed[3], 55);
+        assert!(distributed
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| *i != 3)
+            .all(|(_, v)| *v == 50));
+        assert_eq!(distributed.iter().sum::<i32>(), 905);
+    }
+
+    #[test]
+    fn test_distribute_tokens_saturates_at_cap() {
+        assert_eq!(distribute_tokens(1000, 4, 0, 200), vec![200; 4]);
+
+        let distributed = distribute_tokens(803, 4, 1, 201);
+
+        assert_eq!(distributed, vec![200, 201, 200, 200]);
+    }
+
+    #[test]
+    fn test_distribute_tokens_empty_and_negative() {
+        assert!(distribute_tokens(100, 0, 0, i32::MAX).is_empty());
+        assert_eq!(distribute_tokens(-5, 3, 0, i32::MAX), vec![0; 3]);
+    }
+}
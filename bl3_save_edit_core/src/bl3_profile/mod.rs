@@ -1,13 +1,18 @@
 use std::fmt;
 use std::fmt::Formatter;
 use std::io::Write;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use byteorder::{LittleEndian, WriteBytesExt};
 
-use crate::bl3_profile::profile_data::ProfileData;
-use crate::file_helper::FileData;
+use crate::bl3_item::Bl3Item;
+use crate::bl3_profile::guardian_reward::GuardianReward;
+use crate::bl3_profile::profile_currency::ProfileCurrency;
+use crate::bl3_profile::profile_data::{ProfileData, VaultCardChallengeSeeds};
+use crate::bl3_profile::sdu::ProfileSduSlot;
+use crate::bl3_profile::skins::ProfileSkinType;
+use crate::file_helper::{FileData, FileMetadata, GameStore};
 use crate::game_data::{
     PROFILE_ECHO_THEMES, PROFILE_ECHO_THEMES_DEFAULTS, PROFILE_EMOTES, PROFILE_EMOTES_DEFAULTS,
     PROFILE_HEADS, PROFILE_HEADS_DEFAULTS, PROFILE_ROOM_DECORATIONS, PROFILE_SKINS,
@@ -16,6 +21,7 @@ use crate::game_data::{
 use crate::models::CustomFormatData;
 use crate::parser::{decrypt, encrypt, HeaderType};
 use crate::protos::oak_profile::Profile;
+use crate::resources::PROFILE_TEMPLATE;
 use crate::{file_helper, parser};
 
 pub mod guardian_reward;
@@ -29,6 +35,7 @@ pub mod util;
 #[derive(Debug, Clone, Default, Eq, Ord, PartialOrd)]
 pub struct Bl3Profile {
     pub file_name: String,
+    pub relative_dir: PathBuf,
     pub save_game_version: u32,
     pub package_version: u32,
     pub engine_major: u16,
@@ -41,6 +48,8 @@ pub struct Bl3Profile {
     pub custom_format_data: Vec<CustomFormatData>,
     pub save_game_type: String,
     pub header_type: HeaderType,
+    pub file_size: usize,
+    pub game_store: GameStore,
     pub profile_data: ProfileData,
 }
 
@@ -71,6 +80,7 @@ impl Bl3Profile {
             custom_format_data_count,
             custom_format_data,
             save_game_type,
+            file_size,
             ..
         } = file_data.clone();
 
@@ -81,6 +91,7 @@ impl Bl3Profile {
 
         Ok(Self {
             file_name,
+            relative_dir: PathBuf::new(),
             save_game_version: file_version,
             package_version,
             engine_major,
@@ -93,6 +104,8 @@ impl Bl3Profile {
             custom_format_data,
             save_game_type,
             header_type,
+            file_size,
+            game_store: file_helper::infer_game_store(file_location),
             profile_data,
         })
     }
@@ -103,6 +116,114 @@ impl Bl3Profile {
         Self::from_file_data(&file_data, header_type)
     }
 
+    /// A new profile with default settings and no progress, written for `header_type` when saved.
+    pub fn template(file_name: &str, header_type: HeaderType) -> Result<Self> {
+        if !HeaderType::PROFILE_TYPES.contains(&header_type) {
+            bail!("{} is not a profile type.", header_type);
+        }
+
+        let mut profile = Self::from_bytes(
+            Path::new(file_name),
+            PROFILE_TEMPLATE,
+            HeaderType::PcProfile,
+        )?;
+
+        profile.header_type = header_type;
+
+        Ok(profile)
+    }
+
+    /// Replaces all progress and settings with the template's, keeping the platform and header.
+    pub fn reset_to_template(&mut self) -> Result<()> {
+        let template = Self::template(&self.file_name, self.header_type)?;
+
+        self.profile_data = template.profile_data;
+
+        Ok(())
+    }
+
+    pub fn is_version_newer_than_supported(&self) -> bool {
+        file_helper::is_version_newer_than_supported(self.save_game_version, self.package_version)
+    }
+
+    pub fn metadata(&self) -> FileMetadata {
+        FileMetadata {
+            save_game_version: self.save_game_version,
+            package_version: self.package_version,
+            engine_version: file_helper::engine_version_text(
+                self.engine_major,
+                self.engine_minor,
+                self.engine_patch,
+                self.engine_build,
+            ),
+            build_id: self.build_id.clone(),
+            header_type: self.header_type,
+            file_size: self.file_size,
+        }
+    }
+
+    /// Where the profile is relative to the loaded folder.
+    pub fn relative_path(&self) -> PathBuf {
+        self.relative_dir.join(&self.file_name)
+    }
+
+    /// Sets Golden Keys, Diamond Keys or Vault Card keys.
+    pub fn set_currency(&mut self, currency: &ProfileCurrency, quantity: i32) -> Result<()> {
+        self.profile_data.set_currency(currency, quantity)
+    }
+
+    pub fn set_currency_by_hash(&mut self, hash: u32, quantity: i32) {
+        self.profile_data.set_currency_by_hash(hash, quantity)
+    }
+
+    pub fn set_vault_card_chests(&mut self, vault_card_id: u32, vault_card_chests: i32) {
+        self.profile_data
+            .set_vault_card_chests(vault_card_id, vault_card_chests)
+    }
+
+    /// Rerolls the Vault Card daily and weekly challenges, see
+    /// [`ProfileData::reroll_vault_card_challenges`].
+    pub fn reroll_vault_card_challenges(&mut self) -> VaultCardChallengeSeeds {
+        self.profile_data.reroll_vault_card_challenges()
+    }
+
+    pub fn set_sdu_slot(&mut self, sdu_slot: &ProfileSduSlot, level: i32) {
+        self.profile_data.set_sdu_slot(sdu_slot, level)
+    }
+
+    /// Unlocks every cosmetic of the given type, e.g. all character heads.
+    pub fn unlock_skin_set(&mut self, skin_type: &ProfileSkinType) {
+        self.profile_data.unlock_skin_set(skin_type)
+    }
+
+    /// Unlocks the event and SHiFT cosmetics that are still locked, returning how many there were.
+    pub fn unlock_event_cosmetics(&mut self) -> usize {
+        self.profile_data.unlock_event_cosmetics()
+    }
+
+    /// Sets the tokens spent on a reward. The guardian rank is not changed, see
+    /// [`Bl3Profile::set_guardian_rank`].
+    pub fn set_guardian_reward(
+        &mut self,
+        guardian_reward: &GuardianReward,
+        tokens: i32,
+    ) -> Result<()> {
+        self.profile_data
+            .set_guardian_reward(guardian_reward, tokens)
+    }
+
+    pub fn set_guardian_rank(&mut self, new_rank: i32, tokens: Option<i32>) {
+        self.profile_data.set_guardian_rank(new_rank, tokens)
+    }
+
+    pub fn add_bank_item(&mut self, item: &Bl3Item) -> Result<()> {
+        self.profile_data.add_bank_item(item)
+    }
+
+    pub fn remove_bank_item(&mut self, index: usize) {
+        self.profile_data.remove_bank_item(index)
+    }
+
     pub fn as_bytes(&self) -> Result<(Vec<u8>, Bl3Profile)> {
         let mut output = Vec::new();
 
@@ -133,7 +254,10 @@ impl Bl3Profile {
 
         //Now try re-reading it also - there's no point making an invalid save
         let file_name = Path::new(&self.file_name);
-        let new_profile = Self::from_bytes(file_name, &output, self.header_type)?;
+        let mut new_profile = Self::from_bytes(file_name, &output, self.header_type)?;
+
+        new_profile.game_store = self.game_store;
+        new_profile.relative_dir = self.relative_dir.clone();
 
         Ok((output, new_profile))
     }
@@ -264,8 +388,13 @@ impl fmt::Display for Bl3Profile {
 mod tests {
     use std::fs;
 
+    use crate::bl3_profile::profile_currency::{
+        currency_path_hash, GOLDEN_KEY, PROFILE_CURRENCIES, VAULT_CARD_2_KEY,
+    };
     use crate::bl3_profile::science_levels::BorderlandsScienceLevel;
     use crate::bl3_profile::sdu::{ProfileSduSlot, ProfileSduSlotData};
+    use crate::game_data::PROFILE_EVENT_COSMETICS;
+    use crate::protos::oak_profile::GuardianRankProfileData;
 
     use super::*;
 
@@ -442,4 +571,324 @@ mod tests {
         assert_eq!(bl3_profile.profile_data.weapon_skins_unlocked(), 24);
         assert_eq!(bl3_profile.profile_data.weapon_trinkets_unlocked(), 63);
     }
+
+    #[test]
+    fn test_profile_general_round_trip() {
+        let filename = Path::new("./test_files/1prof.sav");
+
+        let profile_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let mut bl3_profile =
+            Bl3Profile::from_bytes(filename, &profile_file_data, HeaderType::PcProfile)
+                .expect("failed to read test profile");
+
+        assert_eq!(bl3_profile.profile_data.guardian_experience(), 64959126);
+        assert_eq!(bl3_profile.profile_data.new_guardian_experience(), 64959126);
+        assert_eq!(bl3_profile.profile_data.mail_items().len(), 25);
+        assert_eq!(bl3_profile.profile_data.read_mail_count(), 4);
+        assert_eq!(bl3_profile.profile_data.shift_entitlements().len(), 5);
+        assert_eq!(
+            bl3_profile.profile_data.shift_entitlements()[0].source,
+            "DLC_PreorderBonus"
+        );
+
+        bl3_profile
+            .profile_data
+            .set_guardian_experience(70000000, 70000001);
+
+        assert_eq!(bl3_profile.profile_data.clear_mail(true), 4);
+
+        let entitlement = bl3_profile.profile_data.shift_entitlements()[0].clone();
+
+        bl3_profile.profile_data.set_shift_entitlement_consumed(
+            &entitlement.source,
+            entitlement.id,
+            !entitlement.consumed,
+        );
+
+        let (_, bl3_profile) = bl3_profile
+            .as_bytes()
+            .expect("failed to write test profile");
+
+        assert_eq!(bl3_profile.profile_data.guardian_experience(), 70000000);
+        assert_eq!(bl3_profile.profile_data.new_guardian_experience(), 70000001);
+        assert_eq!(bl3_profile.profile_data.mail_items().len(), 21);
+        assert_eq!(bl3_profile.profile_data.read_mail_count(), 0);
+        assert_eq!(bl3_profile.profile_data.profile.mail_guids.len(), 394);
+        assert_eq!(bl3_profile.profile_data.shift_entitlements().len(), 5);
+        assert_eq!(
+            bl3_profile.profile_data.shift_entitlements()[0].consumed,
+            !entitlement.consumed
+        );
+    }
+
+    #[test]
+    fn test_unlock_event_cosmetics() {
+        let filename = Path::new("./test_files/1prof.sav");
+
+        let profile_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let mut bl3_profile =
+            Bl3Profile::from_bytes(filename, &profile_file_data, HeaderType::PcProfile)
+                .expect("failed to read test profile");
+
+        bl3_profile
+            .profile_data
+            .profile
+            .unlocked_customizations
+            .clear();
+
+        let heads_before = bl3_profile.profile_data.character_heads_unlocked();
+
+        assert_eq!(
+            bl3_profile.unlock_event_cosmetics(),
+            PROFILE_EVENT_COSMETICS.len()
+        );
+        assert_eq!(
+            bl3_profile
+                .profile_data
+                .profile
+                .unlocked_customizations
+                .len(),
+            PROFILE_EVENT_COSMETICS.len()
+        );
+        assert!(bl3_profile.profile_data.character_heads_unlocked() > heads_before);
+
+        assert_eq!(bl3_profile.unlock_event_cosmetics(), 0);
+        assert_eq!(
+            bl3_profile
+                .profile_data
+                .profile
+                .unlocked_customizations
+                .len(),
+            PROFILE_EVENT_COSMETICS.len()
+        );
+
+        let (_, bl3_profile) = bl3_profile
+            .as_bytes()
+            .expect("failed to write test profile");
+
+        assert_eq!(
+            bl3_profile
+                .profile_data
+                .profile
+                .unlocked_customizations
+                .len(),
+            PROFILE_EVENT_COSMETICS.len()
+        );
+    }
+
+    #[test]
+    fn test_profile_currency_round_trip() {
+        let filename = Path::new("./test_files/1prof.sav");
+
+        let profile_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let mut bl3_profile =
+            Bl3Profile::from_bytes(filename, &profile_file_data, HeaderType::PcProfile)
+                .expect("failed to read test profile");
+
+        let currencies = bl3_profile.profile_data.currencies();
+
+        assert!(currencies.len() >= PROFILE_CURRENCIES.len());
+        assert_eq!(currencies[0].currency, Some(GOLDEN_KEY));
+        assert_eq!(currencies[0].current, 23);
+        assert_eq!(
+            bl3_profile
+                .profile_data
+                .currency_by_path(&GOLDEN_KEY.path.to_lowercase())
+                .expect("failed to read currency"),
+            23
+        );
+
+        let new_key_path =
+            "/Game/Gear/_Shared/_Design/InventoryCategories/InventoryCategory_TestKey";
+
+        bl3_profile
+            .profile_data
+            .set_currency_by_path(GOLDEN_KEY.path, 500)
+            .expect("failed to set currency");
+        bl3_profile
+            .profile_data
+            .set_currency_by_path(VAULT_CARD_2_KEY.path, 12)
+            .expect("failed to set currency");
+        bl3_profile
+            .profile_data
+            .set_currency_by_path(new_key_path, 7)
+            .expect("failed to set currency");
+        bl3_profile.profile_data.set_vault_card_chests(2, 3);
+
+        let (_, bl3_profile) = bl3_profile
+            .as_bytes()
+            .expect("failed to write test profile");
+
+        let new_key_hash = currency_path_hash(new_key_path).expect("failed to hash path");
+
+        assert_eq!(bl3_profile.profile_data.golden_keys(), 500);
+        assert_eq!(bl3_profile.profile_data.vault_card_2_keys(), 12);
+        assert_eq!(bl3_profile.profile_data.vault_card_2_chests(), 3);
+        assert_eq!(bl3_profile.profile_data.currency_by_hash(new_key_hash), 7);
+
+        let new_key = bl3_profile
+            .profile_data
+            .currencies()
+            .iter()
+            .find(|c| c.hash == new_key_hash)
+            .expect("unknown currency was not kept");
+
+        assert_eq!(new_key.currency, None);
+        assert_eq!(
+            new_key.name(),
+            format!("Unknown Currency ({})", new_key_hash)
+        );
+    }
+
+    #[test]
+    fn test_reroll_vault_card_challenges() {
+        let filename = Path::new("./test_files/1prof.sav");
+
+        let profile_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let mut bl3_profile =
+            Bl3Profile::from_bytes(filename, &profile_file_data, HeaderType::PcProfile)
+                .expect("failed to read test profile");
+
+        let old_seeds = bl3_profile.profile_data.vault_card_challenge_seeds();
+
+        let new_seeds = bl3_profile.reroll_vault_card_challenges();
+
+        assert_ne!(new_seeds.day_seed, old_seeds.day_seed);
+        assert_ne!(new_seeds.week_seed, old_seeds.week_seed);
+
+        let (_, bl3_profile) = bl3_profile
+            .as_bytes()
+            .expect("failed to write test profile");
+
+        assert_eq!(
+            bl3_profile.profile_data.vault_card_challenge_seeds(),
+            new_seeds
+        );
+        assert!(bl3_profile
+            .profile_data
+            .profile
+            .get_vault_card()
+            .vault_card_previous_challenges
+            .is_empty());
+    }
+
+    #[test]
+    fn test_template_round_trip() {
+        for header_type in HeaderType::PROFILE_TYPES {
+            let template =
+                Bl3Profile::template("profile.sav", header_type).expect("failed to build template");
+
+            assert_eq!(template.header_type, header_type);
+            assert!(template.profile_data.bank_items().is_empty());
+            assert!(template.profile_data.lost_loot_items().is_empty());
+            assert_eq!(template.profile_data.guardian_rank(), 0);
+            assert_eq!(template.profile_data.golden_keys(), 0);
+
+            let (output, saved) = template.as_bytes().expect("failed to write template");
+
+            let read = Bl3Profile::from_bytes(Path::new("profile.sav"), &output, header_type)
+                .expect("failed to read template");
+
+            assert_eq!(saved, template);
+            assert_eq!(read, template);
+            assert_eq!(read.header_type, header_type);
+        }
+
+        assert!(Bl3Profile::template("profile.sav", HeaderType::PcSave).is_err());
+    }
+
+    #[test]
+    fn test_template_from_fixture() {
+        // The template is this profile with everything but its settings removed
+        let filename = Path::new("./test_files/1prof.sav");
+
+        let profile_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let mut bl3_profile =
+            Bl3Profile::from_bytes(filename, &profile_file_data, HeaderType::PcProfile)
+                .expect("failed to read test profile");
+
+        let profile = &mut bl3_profile.profile_data.profile;
+
+        profile.friend_events.clear();
+        profile.max_friend_encounter_size = 0;
+        profile.profile_stats_data.clear();
+        profile.bank_inventory_category_list.clear();
+        profile.bank_inventory_list.clear();
+        profile.lost_loot_inventory_list.clear();
+        profile.npc_mail_items.clear();
+        profile.mail_guids.clear();
+        profile.profile_sdu_list.clear();
+        profile.unlocked_customizations.clear();
+        profile.unlocked_inventory_customization_parts.clear();
+        profile.guardian_rank = Some(GuardianRankProfileData::new()).into();
+        profile.unlocked_crew_quarters_decorations.clear();
+        profile.registered_downloadable_entitlements.clear();
+        profile.CitizenScienceLevelProgression.clear();
+        profile.bCitizenScienceHasSeenIntroVideo = false;
+
+        let (output, _) = bl3_profile.as_bytes().expect("failed to write template");
+
+        assert_eq!(output, PROFILE_TEMPLATE);
+    }
+
+    #[test]
+    fn test_reset_to_template() {
+        let filename = Path::new("./test_files/2profps4.sav");
+
+        let profile_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let mut bl3_profile =
+            Bl3Profile::from_bytes(filename, &profile_file_data, HeaderType::Ps4Profile)
+                .expect("failed to read test profile");
+
+        let save_game_version = bl3_profile.save_game_version;
+
+        bl3_profile
+            .reset_to_template()
+            .expect("failed to reset profile");
+
+        assert_eq!(bl3_profile.file_name, "2profps4.sav");
+        assert_eq!(bl3_profile.header_type, HeaderType::Ps4Profile);
+        assert_eq!(bl3_profile.save_game_version, save_game_version);
+        assert!(bl3_profile.profile_data.bank_items().is_empty());
+
+        let (output, _) = bl3_profile.as_bytes().expect("failed to write profile");
+
+        let read = Bl3Profile::from_bytes(filename, &output, HeaderType::Ps4Profile)
+            .expect("failed to read reset profile");
+
+        assert_eq!(read, bl3_profile);
+        assert_eq!(
+            read,
+            Bl3Profile::template("2profps4.sav", HeaderType::Ps4Profile).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_clear_bank_items() {
+        let filename = Path::new("./test_files/1prof.sav");
+
+        let profile_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let mut bl3_profile =
+            Bl3Profile::from_bytes(filename, &profile_file_data, HeaderType::PcProfile)
+                .expect("failed to read test profile");
+
+        let items = bl3_profile.profile_data.bank_items().len();
+
+        assert!(items > 0);
+        assert_eq!(bl3_profile.profile_data.clear_bank_items(), items);
+
+        let (output, _) = bl3_profile.as_bytes().expect("failed to write profile");
+
+        let read = Bl3Profile::from_bytes(filename, &output, HeaderType::PcProfile)
+            .expect("failed to read cleared profile");
+
+        assert!(read.profile_data.bank_items().is_empty());
+    }
 }
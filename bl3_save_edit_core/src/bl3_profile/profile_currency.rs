@@ -1,52 +1,92 @@
+use std::convert::TryInto;
+
 use anyhow::{Context, Result};
-use protobuf::RepeatedField;
-use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
-use strum::Display;
 
 use crate::bl3_profile::util::get_checksum_hash;
-use crate::protos::oak_shared::InventoryCategorySaveData;
-
-#[derive(Debug, Display)]
-pub enum ProfileCurrency {
-    #[strum(
-        to_string = "/Game/Gear/_Shared/_Design/InventoryCategories/InventoryCategory_GoldenKey"
-    )]
-    GoldenKey,
-    #[strum(
-        to_string = "/Game/Gear/_Shared/_Design/InventoryCategories/InventoryCategory_DiamondKey"
-    )]
-    DiamondKey,
-    #[strum(
-        to_string = "/Game/Gear/_Shared/_Design/InventoryCategories/InventoryCategory_VaultCard1Key"
-    )]
-    VaultCardOneId,
-    #[strum(
-        to_string = "/Game/Gear/_Shared/_Design/InventoryCategories/InventoryCategory_VaultCard2Key"
-    )]
-    VaultCardTwoId,
-    #[strum(
-        to_string = "/Game/Gear/_Shared/_Design/InventoryCategories/InventoryCategory_VaultCard3Key"
-    )]
-    VaultCardThreeId,
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub struct ProfileCurrency {
+    pub path: &'static str,
+    pub name: &'static str,
+    pub max: i32,
 }
 
+pub const GOLDEN_KEY: ProfileCurrency = ProfileCurrency {
+    path: "/Game/Gear/_Shared/_Design/InventoryCategories/InventoryCategory_GoldenKey",
+    name: "Golden Keys",
+    max: i32::MAX,
+};
+
+pub const DIAMOND_KEY: ProfileCurrency = ProfileCurrency {
+    path: "/Game/Gear/_Shared/_Design/InventoryCategories/InventoryCategory_DiamondKey",
+    name: "Diamond Keys",
+    max: i32::MAX,
+};
+
+pub const VAULT_CARD_1_KEY: ProfileCurrency = ProfileCurrency {
+    path: "/Game/Gear/_Shared/_Design/InventoryCategories/InventoryCategory_VaultCard1Key",
+    name: "Vault Card 1 Keys",
+    max: i32::MAX,
+};
+
+pub const VAULT_CARD_2_KEY: ProfileCurrency = ProfileCurrency {
+    path: "/Game/Gear/_Shared/_Design/InventoryCategories/InventoryCategory_VaultCard2Key",
+    name: "Vault Card 2 Keys",
+    max: i32::MAX,
+};
+
+pub const VAULT_CARD_3_KEY: ProfileCurrency = ProfileCurrency {
+    path: "/Game/Gear/_Shared/_Design/InventoryCategories/InventoryCategory_VaultCard3Key",
+    name: "Vault Card 3 Keys",
+    max: i32::MAX,
+};
+
+// New key types only need an entry here to show up in the Keys tab.
+pub const PROFILE_CURRENCIES: [ProfileCurrency; 5] = [
+    GOLDEN_KEY,
+    DIAMOND_KEY,
+    VAULT_CARD_1_KEY,
+    VAULT_CARD_2_KEY,
+    VAULT_CARD_3_KEY,
+];
+
+pub const VAULT_CARD_IDS: [u32; 3] = [1, 2, 3];
+
 impl ProfileCurrency {
-    pub fn get_hash(&self) -> Result<usize> {
-        get_checksum_hash(&self.to_string())
+    pub fn get_hash(&self) -> Result<u32> {
+        currency_path_hash(self.path)
     }
+}
+
+pub fn currency_path_hash(path: &str) -> Result<u32> {
+    get_checksum_hash(path)
+        .and_then(|h| h.try_into().map_err(anyhow::Error::new))
+        .with_context(|| format!("failed to read hash for currency: {}", path))
+}
 
-    pub fn get_profile_currency(
-        &self,
-        inv_cat_save_data: &RepeatedField<InventoryCategorySaveData>,
-    ) -> Result<i32> {
-        let hash = get_checksum_hash(&self.to_string())?;
+/// A currency stored in the profile, `currency` is `None` when the hash isn't in
+/// `PROFILE_CURRENCIES`.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub struct ProfileCurrencyData {
+    pub hash: u32,
+    pub currency: Option<ProfileCurrency>,
+    pub current: i32,
+}
+
+impl ProfileCurrencyData {
+    pub fn name(&self) -> String {
+        self.currency
+            .map(|c| c.name.to_owned())
+            .unwrap_or_else(|| format!("Unknown Currency ({})", self.hash))
+    }
 
-        let amount = inv_cat_save_data
-            .par_iter()
-            .find_first(|i| i.base_category_definition_hash as usize == hash)
-            .map(|i| i.quantity)
-            .with_context(|| format!("failed to read profile currency amount for: {:?}", self))?;
+    pub fn path(&self) -> String {
+        self.currency
+            .map(|c| c.path.to_owned())
+            .unwrap_or_else(|| format!("hash:{}", self.hash))
+    }
 
-        Ok(amount)
+    pub fn max(&self) -> i32 {
+        self.currency.map(|c| c.max).unwrap_or(i32::MAX)
     }
 }
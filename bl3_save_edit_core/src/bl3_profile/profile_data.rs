@@ -2,44 +2,58 @@ use std::convert::TryInto;
 
 use anyhow::{Context, Result};
 use derivative::Derivative;
+use rand::Rng;
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use strum::{EnumMessage, IntoEnumIterator};
 use tracing::error;
 
 use crate::bl3_item::Bl3Item;
-use crate::bl3_profile::guardian_reward::{GuardianReward, GuardianRewardData};
-use crate::bl3_profile::profile_currency::ProfileCurrency;
+use crate::bl3_profile::guardian_reward::{
+    GuardianReward, GuardianRewardData, MAX_GUARDIAN_REWARD_TOKENS,
+};
+use crate::bl3_profile::profile_currency::{
+    currency_path_hash, ProfileCurrency, ProfileCurrencyData, DIAMOND_KEY, GOLDEN_KEY,
+    PROFILE_CURRENCIES, VAULT_CARD_1_KEY, VAULT_CARD_2_KEY, VAULT_CARD_3_KEY,
+};
 use crate::bl3_profile::science_levels::{BorderlandsScienceInfo, BorderlandsScienceLevel};
 use crate::bl3_profile::sdu::{ProfileSduSlot, ProfileSduSlotData};
 use crate::bl3_profile::skins::{ProfileSkinType, SkinSet, WeaponSkinSet};
 use crate::bl3_profile::util::get_checksum_hash;
 use crate::game_data::{
     PROFILE_ECHO_THEMES, PROFILE_ECHO_THEMES_DEFAULTS, PROFILE_EMOTES, PROFILE_EMOTES_DEFAULTS,
-    PROFILE_HEADS, PROFILE_HEADS_DEFAULTS, PROFILE_SKINS, PROFILE_SKINS_DEFAULTS,
-    PROFILE_WEAPON_SKINS, PROFILE_WEAPON_TRINKETS,
+    PROFILE_EVENT_COSMETICS, PROFILE_HEADS, PROFILE_HEADS_DEFAULTS, PROFILE_SKINS,
+    PROFILE_SKINS_DEFAULTS, PROFILE_WEAPON_SKINS, PROFILE_WEAPON_TRINKETS,
 };
 use crate::protos::oak_profile::{
     GuardianRankProfileData, GuardianRankRewardSaveGameData, Profile,
 };
 use crate::protos::oak_shared::{
     CrewQuartersDecorationItemSaveGameData, InventoryCategorySaveData,
-    OakCustomizationSaveGameData, OakInventoryCustomizationPartInfo, OakSDUSaveGameData,
-    VaultCardRewardList, VaultCardSaveGameData,
+    OakCustomizationSaveGameData, OakInventoryCustomizationPartInfo, OakMailItem,
+    OakSDUSaveGameData, VaultCardRewardList, VaultCardSaveGameData,
 };
 
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ShiftEntitlementData {
+    pub source: String,
+    pub id: i32,
+    pub registered: bool,
+    pub consumed: bool,
+}
+
+/// Seeds the game picks the Vault Card daily and weekly challenges from.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub struct VaultCardChallengeSeeds {
+    pub day_seed: i32,
+    pub week_seed: i32,
+}
+
 #[derive(Derivative)]
 #[derivative(Debug, Clone, Default, Eq, PartialEq, Ord, PartialOrd)]
 pub struct ProfileData {
     #[derivative(Ord = "ignore", PartialOrd = "ignore")]
     pub profile: Profile,
-    golden_keys: i32,
-    diamond_keys: i32,
-    vault_card_1_keys: i32,
-    vault_card_1_chests: i32,
-    vault_card_2_keys: i32,
-    vault_card_2_chests: i32,
-    vault_card_3_keys: i32,
-    vault_card_3_chests: i32,
+    currencies: Vec<ProfileCurrencyData>,
     guardian_rank: i32,
     guardian_tokens: i32,
     guardian_rewards: Vec<GuardianRewardData>,
@@ -58,56 +72,42 @@ pub struct ProfileData {
 
 impl ProfileData {
     pub fn from_profile(profile: Profile) -> Result<Self> {
-        let golden_keys = ProfileCurrency::GoldenKey
-            .get_profile_currency(&profile.bank_inventory_category_list)
-            .unwrap_or(0);
-        let diamond_keys = ProfileCurrency::DiamondKey
-            .get_profile_currency(&profile.bank_inventory_category_list)
-            .unwrap_or(0);
-        let vault_card_1_keys = ProfileCurrency::VaultCardOneId
-            .get_profile_currency(&profile.bank_inventory_category_list)
-            .unwrap_or(0);
-
-        let vault_card_1_chests = profile
-            .vault_card
-            .as_ref()
-            .and_then(|vc| {
-                vc.vault_card_claimed_rewards
-                    .par_iter()
-                    .find_first(|v| v.vault_card_id == 1)
-                    .map(|v| v.vault_card_chests)
-            })
-            .unwrap_or(0);
+        let mut currencies = PROFILE_CURRENCIES
+            .iter()
+            .map(|currency| {
+                let hash = currency.get_hash()?;
 
-        let vault_card_2_keys = ProfileCurrency::VaultCardTwoId
-            .get_profile_currency(&profile.bank_inventory_category_list)
-            .unwrap_or(0);
+                let current = profile
+                    .bank_inventory_category_list
+                    .iter()
+                    .find(|i| i.base_category_definition_hash == hash)
+                    .map(|i| i.quantity)
+                    .unwrap_or(0);
 
-        let vault_card_2_chests = profile
-            .vault_card
-            .as_ref()
-            .and_then(|vc| {
-                vc.vault_card_claimed_rewards
-                    .par_iter()
-                    .find_first(|v| v.vault_card_id == 2)
-                    .map(|v| v.vault_card_chests)
+                Ok(ProfileCurrencyData {
+                    hash,
+                    currency: Some(*currency),
+                    current,
+                })
             })
-            .unwrap_or(0);
-
-        let vault_card_3_keys = ProfileCurrency::VaultCardThreeId
-            .get_profile_currency(&profile.bank_inventory_category_list)
-            .unwrap_or(0);
+            .collect::<Result<Vec<_>>>()?;
 
-        let vault_card_3_chests = profile
-            .vault_card
-            .as_ref()
-            .and_then(|vc| {
-                vc.vault_card_claimed_rewards
-                    .par_iter()
-                    .find_first(|v| v.vault_card_id == 3)
-                    .map(|v| v.vault_card_chests)
+        let unknown_currencies = profile
+            .bank_inventory_category_list
+            .iter()
+            .filter(|i| {
+                !currencies
+                    .iter()
+                    .any(|c| c.hash == i.base_category_definition_hash)
+            })
+            .map(|i| ProfileCurrencyData {
+                hash: i.base_category_definition_hash,
+                currency: None,
+                current: i.quantity,
             })
-            .unwrap_or(0);
+            .collect::<Vec<_>>();
+
+        currencies.extend(unknown_currencies);
 
         let guardian_rank_profile_data = profile
             .guardian_rank
@@ -131,7 +131,7 @@ impl ProfileData {
 
                 GuardianRewardData {
                     current,
-                    max: i32::MAX,
+                    max: MAX_GUARDIAN_REWARD_TOKENS,
                     reward,
                 }
             })
@@ -148,6 +148,7 @@ impl ProfileData {
             BorderlandsScienceInfo {
                 science_level: level,
                 solves,
+                xp: level.xp_from_solves(borderlands_science_level_solves),
                 tokens: profile.CitizenScienceCSBucksAmount,
             }
         };
@@ -245,14 +246,7 @@ impl ProfileData {
 
         Ok(Self {
             profile,
-            golden_keys,
-            diamond_keys,
-            vault_card_1_keys,
-            vault_card_1_chests,
-            vault_card_2_keys,
-            vault_card_2_chests,
-            vault_card_3_keys,
-            vault_card_3_chests,
+            currencies,
             guardian_rank,
             guardian_tokens: guardian_rank_tokens,
             guardian_rewards,
@@ -270,44 +264,70 @@ impl ProfileData {
         })
     }
 
+    pub fn currencies(&self) -> &Vec<ProfileCurrencyData> {
+        &self.currencies
+    }
+
+    pub fn currency_by_hash(&self, hash: u32) -> i32 {
+        self.currencies
+            .iter()
+            .find(|c| c.hash == hash)
+            .map(|c| c.current)
+            .unwrap_or(0)
+    }
+
+    pub fn currency_by_path(&self, path: &str) -> Result<i32> {
+        Ok(self.currency_by_hash(currency_path_hash(path)?))
+    }
+
     pub fn golden_keys(&self) -> i32 {
-        self.golden_keys
+        self.currency_by_path(GOLDEN_KEY.path).unwrap_or(0)
     }
 
+    // Only the profile's remaining Diamond Keys are stored. Saves don't record how many keys a
+    // character has used (no stat, challenge or inventory category for it), so usage can't be
+    // totalled across characters.
     pub fn diamond_keys(&self) -> i32 {
-        self.diamond_keys
+        self.currency_by_path(DIAMOND_KEY.path).unwrap_or(0)
     }
 
     pub fn vault_card_1_keys(&self) -> i32 {
-        self.vault_card_1_keys
+        self.currency_by_path(VAULT_CARD_1_KEY.path).unwrap_or(0)
     }
 
     pub fn vault_card_1_chests(&self) -> i32 {
-        self.vault_card_1_chests
+        self.vault_card_chests(1)
     }
 
     pub fn vault_card_2_keys(&self) -> i32 {
-        self.vault_card_2_keys
+        self.currency_by_path(VAULT_CARD_2_KEY.path).unwrap_or(0)
     }
 
     pub fn vault_card_2_chests(&self) -> i32 {
-        self.vault_card_2_chests
+        self.vault_card_chests(2)
     }
 
     pub fn vault_card_3_keys(&self) -> i32 {
-        self.vault_card_3_keys
+        self.currency_by_path(VAULT_CARD_3_KEY.path).unwrap_or(0)
     }
 
     pub fn vault_card_3_chests(&self) -> i32 {
-        self.vault_card_3_chests
+        self.vault_card_chests(3)
     }
 
     pub fn set_currency(&mut self, currency: &ProfileCurrency, quantity: i32) -> Result<()> {
-        let hash = currency
-            .get_hash()
-            .and_then(|h| h.try_into().map_err(anyhow::Error::new))
-            .with_context(|| format!("failed to read hash for currency: {}", currency))?;
+        self.set_currency_by_path(currency.path, quantity)
+    }
+
+    pub fn set_currency_by_path(&mut self, path: &str, quantity: i32) -> Result<()> {
+        let hash = currency_path_hash(path)?;
+
+        self.set_currency_by_hash(hash, quantity);
+
+        Ok(())
+    }
 
+    pub fn set_currency_by_hash(&mut self, hash: u32, quantity: i32) {
         if let Some(inv_cat_save_data) = self
             .profile
             .bank_inventory_category_list
@@ -326,25 +346,31 @@ impl ProfileData {
                 });
         }
 
-        match currency {
-            ProfileCurrency::GoldenKey => {
-                self.golden_keys = quantity;
-            }
-            ProfileCurrency::DiamondKey => {
-                self.diamond_keys = quantity;
-            }
-            ProfileCurrency::VaultCardOneId => {
-                self.vault_card_1_keys = quantity;
-            }
-            ProfileCurrency::VaultCardTwoId => {
-                self.vault_card_2_keys = quantity;
-            }
-            ProfileCurrency::VaultCardThreeId => {
-                self.vault_card_3_keys = quantity;
-            }
+        if let Some(currency) = self.currencies.iter_mut().find(|c| c.hash == hash) {
+            currency.current = quantity;
+        } else {
+            self.currencies.push(ProfileCurrencyData {
+                hash,
+                currency: PROFILE_CURRENCIES
+                    .iter()
+                    .find(|c| c.get_hash().ok() == Some(hash))
+                    .copied(),
+                current: quantity,
+            });
         }
+    }
 
-        Ok(())
+    pub fn vault_card_chests(&self, vault_card_id: u32) -> i32 {
+        self.profile
+            .vault_card
+            .as_ref()
+            .and_then(|vc| {
+                vc.vault_card_claimed_rewards
+                    .iter()
+                    .find(|v| v.vault_card_id == vault_card_id)
+                    .map(|v| v.vault_card_chests)
+            })
+            .unwrap_or(0)
     }
 
     pub fn set_vault_card_chests(&mut self, vault_card_id: u32, vault_card_chests: i32) {
@@ -389,13 +415,47 @@ impl ProfileData {
             })
             .into();
         }
+    }
 
-        match vault_card_id {
-            1 => self.vault_card_1_chests = vault_card_chests,
-            2 => self.vault_card_2_chests = vault_card_chests,
-            3 => self.vault_card_3_chests = vault_card_chests,
-            _ => (),
-        }
+    pub fn vault_card_challenge_seeds(&self) -> VaultCardChallengeSeeds {
+        self.profile
+            .vault_card
+            .as_ref()
+            .map(|vc| VaultCardChallengeSeeds {
+                day_seed: vc.current_day_seed,
+                week_seed: vc.current_week_seed,
+            })
+            .unwrap_or_default()
+    }
+
+    /// Gives the daily and weekly Vault Card challenges new seeds and forgets the previous
+    /// challenges, so the game rolls new ones the next time the profile is loaded. Returns the new
+    /// seeds.
+    pub fn reroll_vault_card_challenges(&mut self) -> VaultCardChallengeSeeds {
+        let current_seeds = self.vault_card_challenge_seeds();
+
+        let mut rng = rand::thread_rng();
+
+        let mut new_seed = |current: i32| loop {
+            let seed = rng.gen_range(1..i32::MAX);
+
+            if seed != current {
+                break seed;
+            }
+        };
+
+        let new_seeds = VaultCardChallengeSeeds {
+            day_seed: new_seed(current_seeds.day_seed),
+            week_seed: new_seed(current_seeds.week_seed),
+        };
+
+        let vault_card = self.profile.mut_vault_card();
+
+        vault_card.current_day_seed = new_seeds.day_seed;
+        vault_card.current_week_seed = new_seeds.week_seed;
+        vault_card.vault_card_previous_challenges.clear();
+
+        new_seeds
     }
 
     pub fn guardian_rank(&self) -> i32 {
@@ -492,12 +552,114 @@ impl ProfileData {
         self.borderlands_science_info.science_level = science_level.to_owned();
     }
 
+    pub fn set_borderlands_science_xp(&mut self, xp: u32) {
+        let science_level = self.borderlands_science_info.science_level;
+
+        self.profile.CitizenScienceLevelProgression = science_level.progression_with_xp(xp);
+
+        self.borderlands_science_info.xp =
+            science_level.xp_from_solves(&self.profile.CitizenScienceLevelProgression);
+    }
+
     pub fn set_borderlands_science_tokens(&mut self, tokens: i32) {
         self.profile.CitizenScienceCSBucksAmount = tokens;
 
         self.borderlands_science_info.tokens = tokens;
     }
 
+    pub fn guardian_experience(&self) -> i32 {
+        self.profile
+            .guardian_rank
+            .as_ref()
+            .map(|gr| gr.guardian_experience)
+            .unwrap_or(0)
+    }
+
+    pub fn new_guardian_experience(&self) -> i64 {
+        self.profile
+            .guardian_rank
+            .as_ref()
+            .map(|gr| gr.new_guardian_experience)
+            .unwrap_or(0)
+    }
+
+    pub fn set_guardian_experience(&mut self, experience: i32, new_experience: i64) {
+        if self.profile.guardian_rank.is_none() {
+            self.set_guardian_rank(self.guardian_rank, None);
+        }
+
+        if let Some(guardian_rank) = self.profile.guardian_rank.as_mut() {
+            guardian_rank.guardian_experience = experience;
+            guardian_rank.new_guardian_experience = new_experience;
+        }
+    }
+
+    pub fn mail_items(&self) -> &[OakMailItem] {
+        &self.profile.npc_mail_items
+    }
+
+    pub fn read_mail_count(&self) -> usize {
+        self.profile
+            .npc_mail_items
+            .iter()
+            .filter(|m| m.has_been_read)
+            .count()
+    }
+
+    // Only removes mail items from the mailbox, the received mail guid history is left untouched
+    // so the game doesn't resend mail that has already been delivered.
+    pub fn clear_mail(&mut self, read_only: bool) -> usize {
+        let (removed, kept): (Vec<_>, Vec<_>) = self
+            .profile
+            .take_npc_mail_items()
+            .into_iter()
+            .partition(|m| !read_only || m.has_been_read);
+
+        self.profile.npc_mail_items = kept.into();
+
+        self.profile
+            .unread_mail_guids
+            .retain(|g| !removed.iter().any(|m| m.mail_guid == *g));
+
+        removed.len()
+    }
+
+    pub fn shift_entitlements(&self) -> Vec<ShiftEntitlementData> {
+        self.profile
+            .registered_downloadable_entitlements
+            .iter()
+            .flat_map(|rde| {
+                let source = entitlement_source_name(&rde.entitlement_source_asset_path);
+
+                rde.entitlements.iter().map(move |e| ShiftEntitlementData {
+                    source: source.clone(),
+                    id: e.id,
+                    registered: e.registered,
+                    consumed: e.consumed > 0,
+                })
+            })
+            .collect()
+    }
+
+    // The profile stores how many times a reward was consumed, marking one that is already
+    // consumed keeps its count
+    pub fn set_shift_entitlement_consumed(&mut self, source: &str, id: i32, consumed: bool) {
+        if let Some(entitlement) = self
+            .profile
+            .registered_downloadable_entitlements
+            .iter_mut()
+            .filter(|rde| entitlement_source_name(&rde.entitlement_source_asset_path) == source)
+            .flat_map(|rde| rde.entitlements.iter_mut())
+            .find(|e| e.id == id)
+        {
+            if !consumed {
+                entitlement.consumed = 0;
+            } else if entitlement.consumed == 0 {
+                entitlement.consumed = 1;
+            }
+        }
+    }
+
     pub fn sdu_slots(&self) -> &Vec<ProfileSduSlotData> {
         &self.sdu_slots
     }
@@ -540,6 +702,12 @@ impl ProfileData {
         &mut self.bank_items
     }
 
+    /// Replaces the bank with the one from `other`.
+    pub fn set_bank_from(&mut self, other: &ProfileData) {
+        self.profile.bank_inventory_list = other.profile.bank_inventory_list.clone();
+        self.bank_items = other.bank_items.clone();
+    }
+
     pub fn remove_bank_item(&mut self, index: usize) {
         if index < self.profile.bank_inventory_list.len() {
             self.profile.bank_inventory_list.remove(index);
@@ -550,6 +718,24 @@ impl ProfileData {
         }
     }
 
+    /// Removes every bank item, returning how many were removed.
+    pub fn clear_bank_items(&mut self) -> usize {
+        let removed = self.bank_items.len();
+
+        self.profile.bank_inventory_list.clear();
+        self.bank_items.clear();
+
+        removed
+    }
+
+    pub fn take_bank_item(&mut self, index: usize) -> Option<Bl3Item> {
+        let item = self.bank_items.get(index).cloned()?;
+
+        self.remove_bank_item(index);
+
+        Some(item)
+    }
+
     pub fn add_bank_item(&mut self, item: &Bl3Item) -> Result<()> {
         let item_serial_number = item.get_serial_number(true)?;
 
@@ -601,6 +787,11 @@ impl ProfileData {
         self.emotes_unlocked
     }
 
+    // Decorations are stored as a list of unlocked asset paths with an `is_new` flag. The profile
+    // has no owned count per decoration, so there are no multiples to grant and no negative
+    // counts to fix. The Sanctuary stash size quirks named alongside this are out of scope, no
+    // quirk was described and the stash size is only the Bank SDU level, which the SDU editor
+    // already limits to `ProfileSduSlot::maximum`.
     pub fn room_decorations_unlocked(&self) -> usize {
         self.room_decorations_unlocked
     }
@@ -613,6 +804,52 @@ impl ProfileData {
         self.weapon_trinkets_unlocked
     }
 
+    /// Unlocks the cosmetics from events and SHiFT, any that are already unlocked are left alone.
+    /// Returns how many were unlocked.
+    pub fn unlock_event_cosmetics(&mut self) -> usize {
+        let mut unlocked = 0;
+
+        for c in PROFILE_EVENT_COSMETICS.iter() {
+            if self
+                .profile
+                .unlocked_customizations
+                .iter()
+                .any(|uc| uc.customization_asset_path.eq_ignore_ascii_case(c.ident))
+            {
+                continue;
+            }
+
+            self.profile
+                .unlocked_customizations
+                .push(OakCustomizationSaveGameData {
+                    is_new: true,
+                    customization_asset_path: c.ident.to_owned(),
+                    unknown_fields: Default::default(),
+                    cached_size: Default::default(),
+                });
+
+            match c.ident {
+                ident if PROFILE_SKINS.iter().any(|gd| gd.ident == ident) => {
+                    self.character_skins_unlocked += 1;
+                }
+                ident if PROFILE_HEADS.iter().any(|gd| gd.ident == ident) => {
+                    self.character_heads_unlocked += 1;
+                }
+                ident if PROFILE_ECHO_THEMES.iter().any(|gd| gd.ident == ident) => {
+                    self.echo_themes_unlocked += 1;
+                }
+                ident if PROFILE_EMOTES.iter().any(|gd| gd.ident == ident) => {
+                    self.emotes_unlocked += 1;
+                }
+                _ => (),
+            }
+
+            unlocked += 1;
+        }
+
+        unlocked
+    }
+
     pub fn unlock_skin_set(&mut self, skin_type: &ProfileSkinType) {
         let mut skins = skin_type.skin_set();
 
@@ -719,3 +956,11 @@ impl ProfileData {
         }
     }
 }
+
+fn entitlement_source_name(entitlement_source_asset_path: &str) -> String {
+    entitlement_source_asset_path
+        .rsplit('.')
+        .next()
+        .unwrap_or(entitlement_source_asset_path)
+        .replace("EntitlementSource_", "")
+}
@@ -5,6 +5,7 @@ use strum::Display;
 pub struct BorderlandsScienceInfo {
     pub science_level: BorderlandsScienceLevel,
     pub solves: i32,
+    pub xp: u32,
     pub tokens: i32,
 }
 
@@ -73,6 +74,26 @@ impl BorderlandsScienceLevel {
         bail!("Failed to read Borderlands Science Level.")
     }
 
+    fn tier_index(&self) -> Option<usize> {
+        BORDERLANDS_SCIENCE_LEVELS
+            .iter()
+            .position(|(_, l)| l == self)
+    }
+
+    /// Puzzles to solve at this level to reach the next one, 0 when there is no next level.
+    pub fn xp_to_next_tier(&self) -> u32 {
+        self.tier_index()
+            .map(|i| BORDERLANDS_SCIENCE_LEVELS[i].0 as u32)
+            .unwrap_or(0)
+    }
+
+    pub fn xp_from_solves(&self, progression: &[i32]) -> u32 {
+        self.tier_index()
+            .and_then(|i| progression.get(i))
+            .map(|xp| (*xp).max(0) as u32)
+            .unwrap_or(0)
+    }
+
     pub fn progression(&self) -> Vec<i32> {
         if *self == BorderlandsScienceLevel::None {
             return Vec::new();
@@ -92,6 +113,18 @@ impl BorderlandsScienceLevel {
 
         levels
     }
+
+    pub fn progression_with_xp(&self, xp: u32) -> Vec<i32> {
+        let mut levels = self.progression();
+
+        let max_xp = self.xp_to_next_tier().saturating_sub(1);
+
+        if let Some(level_xp) = self.tier_index().and_then(|i| levels.get_mut(i)) {
+            *level_xp = xp.min(max_xp) as i32;
+        }
+
+        levels
+    }
 }
 
 #[cfg(test)]
@@ -145,4 +178,33 @@ mod tests {
             vec![5, 10, 15, 20, 25, 30, 35, 40, 50, 0]
         );
     }
+
+    #[test]
+    pub fn test_science_level_xp() {
+        assert_eq!(BorderlandsScienceLevel::Mordecai.xp_to_next_tier(), 15);
+        assert_eq!(BorderlandsScienceLevel::TrueTannis.xp_to_next_tier(), 0);
+        assert_eq!(BorderlandsScienceLevel::None.xp_to_next_tier(), 0);
+
+        let progression = BorderlandsScienceLevel::Mordecai.progression_with_xp(7);
+
+        assert_eq!(progression, vec![5, 10, 7, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(
+            BorderlandsScienceLevel::from_solves(&progression).unwrap(),
+            BorderlandsScienceLevel::Mordecai
+        );
+        assert_eq!(
+            BorderlandsScienceLevel::Mordecai.xp_from_solves(&progression),
+            7
+        );
+
+        assert_eq!(
+            BorderlandsScienceLevel::Mordecai.progression_with_xp(100),
+            vec![5, 10, 14, 0, 0, 0, 0, 0, 0, 0]
+        );
+        assert_eq!(
+            BorderlandsScienceLevel::TrueTannis.progression_with_xp(100),
+            BorderlandsScienceLevel::TrueTannis.progression()
+        );
+        assert_eq!(BorderlandsScienceLevel::None.xp_from_solves(&[]), 0);
+    }
 }
@@ -62,6 +62,9 @@ impl ProfileSkinType {
         }
     }
 
+    // Only the customizations themselves are known. There's no sourced mapping from a
+    // customization to the challenge that rewards it, so unlocking one doesn't complete any
+    // challenges in the character saves.
     pub fn skin_set(&self) -> Vec<GameDataKv> {
         match self {
             ProfileSkinType::Regular(regular_skin_set) => match regular_skin_set {
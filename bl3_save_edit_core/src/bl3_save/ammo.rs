@@ -7,6 +7,13 @@ pub struct AmmoPoolData {
     pub max: i32,
 }
 
+/// A resource pool in the save that doesn't match any known `AmmoPool`, kept as is.
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Clone)]
+pub struct UnknownAmmoPoolData {
+    pub resource_path: String,
+    pub current: i32,
+}
+
 #[derive(Debug, Display, EnumString, EnumMessage, Eq, PartialEq, Ord, PartialOrd, Clone)]
 pub enum AmmoPool {
     #[strum(
@@ -53,6 +60,9 @@ impl std::default::Default for AmmoPool {
 }
 
 impl AmmoPool {
+    // The capacity with every ammo SDU bought. Saves only store the SDU levels and there's no
+    // sourced capacity per SDU level or unlock level per SDU, so Max Ammo can't fill to a
+    // level-appropriate amount and always uses this.
     pub fn maximum(&self) -> i32 {
         match self {
             AmmoPool::Grenade => 13,
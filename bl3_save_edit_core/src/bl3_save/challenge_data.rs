@@ -1,5 +1,10 @@
+use std::collections::BTreeMap;
+
 use strum::{Display, EnumIter, EnumMessage, EnumString};
 
+use crate::content_source::ContentSource;
+use crate::protos::oak_shared::ChallengeSaveGameData;
+
 #[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Clone)]
 pub struct ChallengeData {
     pub challenge: Challenge,
@@ -51,3 +56,211 @@ pub enum Challenge {
     )]
     SirenClassModSlot,
 }
+
+/// How many of the challenges in one category of a save have been completed.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct ChallengeCategoryCompletion {
+    pub category: String,
+    pub content_source: ContentSource,
+    pub completed: usize,
+    pub total: usize,
+}
+
+impl ChallengeCategoryCompletion {
+    pub fn percentage(&self) -> f32 {
+        if self.total == 0 {
+            return 100.0;
+        }
+
+        self.completed as f32 / self.total as f32 * 100.0
+    }
+
+    /// Whether `complete_all_challenges` completes the challenges in this category.
+    pub fn is_completable(&self, include_dlc: bool) -> bool {
+        is_completable_content_source(self.content_source, include_dlc)
+    }
+}
+
+fn is_completable_content_source(content_source: ContentSource, include_dlc: bool) -> bool {
+    match content_source {
+        ContentSource::BaseGame => true,
+        ContentSource::Unknown => false,
+        _ => include_dlc,
+    }
+}
+
+/// The folder a challenge is in under `Challenges`, such as `Account` or `Character`.
+///
+/// Challenges that aren't in a folder or are in an internal folder such as `_Test` don't have a
+/// category.
+pub fn challenge_category(challenge_path: &str) -> Option<&str> {
+    let (_, after_challenges) = challenge_path.split_once("/Challenges/")?;
+    let (category, _) = after_challenges.split_once('/')?;
+
+    if category.is_empty() || category.starts_with('_') {
+        None
+    } else {
+        Some(category)
+    }
+}
+
+/// Whether `complete_all_challenges` should touch this challenge, DLC and event challenges are
+/// only included when asked for.
+pub fn is_completable_challenge(challenge_path: &str, include_dlc: bool) -> bool {
+    challenge_category(challenge_path).is_some()
+        && is_completable_content_source(
+            ContentSource::from_asset_path(challenge_path),
+            include_dlc,
+        )
+}
+
+/// Completion of each challenge category, grouped by where the challenges come from.
+pub fn challenge_completion(
+    challenges: &[ChallengeSaveGameData],
+) -> Vec<ChallengeCategoryCompletion> {
+    let mut categories: BTreeMap<(ContentSource, &str), (usize, usize)> = BTreeMap::new();
+
+    for challenge in challenges {
+        let path = &challenge.challenge_class_path;
+
+        if let Some(category) = challenge_category(path) {
+            let (completed, total) = categories
+                .entry((ContentSource::from_asset_path(path), category))
+                .or_default();
+
+            if challenge.currently_completed {
+                *completed += 1;
+            }
+
+            *total += 1;
+        }
+    }
+
+    categories
+        .into_iter()
+        .map(
+            |((content_source, category), (completed, total))| ChallengeCategoryCompletion {
+                category: category.to_owned(),
+                content_source,
+                completed,
+                total,
+            },
+        )
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn challenge(path: &str, currently_completed: bool) -> ChallengeSaveGameData {
+        ChallengeSaveGameData {
+            challenge_class_path: path.to_owned(),
+            currently_completed,
+            ..ChallengeSaveGameData::default()
+        }
+    }
+
+    #[test]
+    fn test_challenge_category() {
+        assert_eq!(
+            challenge_category("/Game/GameData/Challenges/Account/Challenge_VaultReward_Mayhem.Challenge_VaultReward_Mayhem_C"),
+            Some("Account")
+        );
+        assert_eq!(
+            challenge_category("/Game/GameData/Challenges/Character/Siren/BP_Challenge_Siren_ClassMod.BP_Challenge_Siren_ClassMod_C"),
+            Some("Character")
+        );
+        assert_eq!(
+            challenge_category("/Game/GameData/Challenges/_Test/ChallengeRewardTest/Challenge_Salvage_RewardTest.Challenge_Salvage_RewardTest_C"),
+            None
+        );
+        assert_eq!(
+            challenge_category("/Game/GameData/Challenges/Challenge_NotReal.Challenge_NotReal_C"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_is_completable_challenge() {
+        let base_game = "/Game/GameData/Challenges/Account/Challenge_VaultReward_Mayhem.Challenge_VaultReward_Mayhem_C";
+        let dlc = "/Game/PatchDLC/Dandelion/GameData/Challenges/Crew/Challenge_Crew_Test.Challenge_Crew_Test_C";
+
+        assert!(is_completable_challenge(base_game, false));
+        assert!(!is_completable_challenge(dlc, false));
+        assert!(is_completable_challenge(dlc, true));
+    }
+
+    #[test]
+    fn test_challenge_category_is_completable() {
+        let completion = |content_source| ChallengeCategoryCompletion {
+            content_source,
+            ..ChallengeCategoryCompletion::default()
+        };
+
+        assert!(completion(ContentSource::BaseGame).is_completable(false));
+        assert!(!completion(ContentSource::Dlc2).is_completable(false));
+        assert!(completion(ContentSource::Dlc2).is_completable(true));
+        assert!(!completion(ContentSource::Unknown).is_completable(true));
+    }
+
+    #[test]
+    fn test_challenge_completion() {
+        let challenges = [
+            challenge(
+                "/Game/GameData/Challenges/Account/Challenge_A.Challenge_A_C",
+                true,
+            ),
+            challenge(
+                "/Game/GameData/Challenges/Account/Challenge_B.Challenge_B_C",
+                true,
+            ),
+            challenge(
+                "/Game/GameData/Challenges/Account/Challenge_C.Challenge_C_C",
+                false,
+            ),
+            challenge(
+                "/Game/GameData/Challenges/Character/Siren/Challenge_D.Challenge_D_C",
+                false,
+            ),
+            challenge(
+                "/Game/GameData/Challenges/_Test/Challenge_E.Challenge_E_C",
+                true,
+            ),
+            challenge(
+                "/Game/PatchDLC/Dandelion/GameData/Challenges/Crew/Challenge_F.Challenge_F_C",
+                true,
+            ),
+        ];
+
+        let completion = challenge_completion(&challenges);
+
+        assert_eq!(
+            completion,
+            vec![
+                ChallengeCategoryCompletion {
+                    category: "Account".to_owned(),
+                    content_source: ContentSource::BaseGame,
+                    completed: 2,
+                    total: 3,
+                },
+                ChallengeCategoryCompletion {
+                    category: "Character".to_owned(),
+                    content_source: ContentSource::BaseGame,
+                    completed: 0,
+                    total: 1,
+                },
+                ChallengeCategoryCompletion {
+                    category: "Crew".to_owned(),
+                    content_source: ContentSource::Dlc1,
+                    completed: 1,
+                    total: 1,
+                },
+            ]
+        );
+
+        assert!((completion[0].percentage() - 66.666_67).abs() < 0.001);
+        assert_eq!(completion[1].percentage(), 0.0);
+        assert_eq!(completion[2].percentage(), 100.0);
+    }
+}
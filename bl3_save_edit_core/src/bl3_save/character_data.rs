@@ -1,38 +1,54 @@
+use std::collections::HashSet;
 use std::str::FromStr;
 
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use derivative::Derivative;
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use strum::{EnumMessage, IntoEnumIterator};
 
 use crate::bl3_item::{Bl3Item, ItemFlags};
 use crate::bl3_profile::guardian_reward::GuardianReward;
-use crate::bl3_save::ammo::{AmmoPool, AmmoPoolData};
+use crate::bl3_save::ammo::{AmmoPool, AmmoPoolData, UnknownAmmoPoolData};
 use crate::bl3_save::challenge_data::Challenge;
 use crate::bl3_save::challenge_data::ChallengeData;
-use crate::bl3_save::inventory_slot::{InventorySlot, InventorySlotData};
+use crate::bl3_save::challenge_data::{
+    challenge_completion, is_completable_challenge, ChallengeCategoryCompletion,
+};
+use crate::bl3_save::customization::{
+    class_customizations, default_customization, CustomizationType,
+};
+use crate::bl3_save::inventory_slot::{find_best_for_slot, InventorySlot, InventorySlotData};
 use crate::bl3_save::level_data::{LEVEL_CHALLENGES, LEVEL_STAT};
+use crate::bl3_save::mission::{
+    check_objective_progress, objective_targets, ActiveMission, OBJECTIVE_SET_TARGETS,
+};
 use crate::bl3_save::models::Currency;
 use crate::bl3_save::player_class::PlayerClass;
-use crate::bl3_save::playthrough::Playthrough;
+use crate::bl3_save::playthrough::{Playthrough, PlaythroughType};
 use crate::bl3_save::sdu::{SaveSduSlot, SaveSduSlotData};
-use crate::bl3_save::util::{currency_amount_from_character, experience_to_level};
+use crate::bl3_save::skill_tree::{skill_tree_items, SkillTreeItem};
+use crate::bl3_save::travel_station::{MapLocation, TravelStation};
+use crate::bl3_save::util::{
+    ability_points_for_level, currency_amount_from_character, experience_to_level, FIRST_MISSION,
+    MAX_MAYHEM_LEVEL, STARTING_TRAVEL_STATION,
+};
 use crate::game_data::{
-    GameDataKv, PROFILE_ECHO_THEMES, PROFILE_ECHO_THEMES_DEFAULTS, PROFILE_HEADS,
-    PROFILE_HEADS_DEFAULTS, PROFILE_SKINS, PROFILE_SKINS_DEFAULTS, VEHICLE_CHASSIS_CYCLONE,
-    VEHICLE_CHASSIS_JETBEAST, VEHICLE_CHASSIS_OUTRUNNER, VEHICLE_CHASSIS_TECHNICAL,
-    VEHICLE_PARTS_CYCLONE, VEHICLE_PARTS_JETBEAST, VEHICLE_PARTS_OUTRUNNER,
-    VEHICLE_PARTS_TECHNICAL, VEHICLE_SKINS_CYCLONE, VEHICLE_SKINS_JETBEAST,
-    VEHICLE_SKINS_OUTRUNNER, VEHICLE_SKINS_TECHNICAL,
+    GameDataKv, FAST_TRAVEL, PROFILE_ECHO_THEMES, PROFILE_ECHO_THEMES_DEFAULTS,
+    VEHICLE_CHASSIS_CYCLONE, VEHICLE_CHASSIS_JETBEAST, VEHICLE_CHASSIS_OUTRUNNER,
+    VEHICLE_CHASSIS_TECHNICAL, VEHICLE_PARTS_CYCLONE, VEHICLE_PARTS_JETBEAST,
+    VEHICLE_PARTS_OUTRUNNER, VEHICLE_PARTS_TECHNICAL, VEHICLE_SKINS_CYCLONE,
+    VEHICLE_SKINS_JETBEAST, VEHICLE_SKINS_OUTRUNNER, VEHICLE_SKINS_TECHNICAL,
 };
 use crate::protos::oak_save::{
-    Character, GuardianRankCharacterSaveGameData, GuardianRankRewardCharacterSaveGameData,
-    GuardianRankSaveGameData, OakInventoryItemSaveGameData, VehicleUnlockedSaveGameData,
+    ActiveFastTravelSaveData, Character, GuardianRankCharacterSaveGameData,
+    GuardianRankRewardCharacterSaveGameData, GuardianRankSaveGameData,
+    MissionStatusPlayerSaveGameData, MissionStatusPlayerSaveGameData_MissionState,
+    OakCARMenuVehicleConfigSaveData, OakInventoryItemSaveGameData, VehicleUnlockedSaveGameData,
 };
 use crate::protos::oak_shared::{
     GameStatSaveGameData, InventoryCategorySaveData, OakSDUSaveGameData,
 };
-use crate::vehicle_data::{VehicleData, VehicleSubType, VehicleType};
+use crate::vehicle_data::{Vehicle, VehicleData, VehicleLoadout, VehicleSubType, VehicleType};
 
 pub const MAX_CHARACTER_LEVEL: usize = 72;
 
@@ -54,6 +70,7 @@ pub struct CharacterData {
     unlockable_inventory_slots: Vec<InventorySlotData>,
     sdu_slots: Vec<SaveSduSlotData>,
     ammo_pools: Vec<AmmoPoolData>,
+    unknown_ammo_pools: Vec<UnknownAmmoPoolData>,
     challenge_milestones: Vec<ChallengeData>,
     vehicle_data: [VehicleData; 12],
     inventory_items: Vec<Bl3Item>,
@@ -83,41 +100,25 @@ impl CharacterData {
             .map(|g| g.guardian_rank)
             .unwrap_or(0);
 
-        let available_head_skins = PROFILE_HEADS_DEFAULTS
-            .par_iter()
-            .chain(PROFILE_HEADS.par_iter())
-            .filter(|h| h.ident.contains(&player_class.to_string()))
-            .cloned()
-            .collect::<Vec<_>>();
-
-        let head_skin_selected = available_head_skins
-            .par_iter()
-            .find_first(|s| {
+        let head_skin_selected = class_customizations(player_class, CustomizationType::Head)
+            .into_iter()
+            .find(|s| {
                 character
                     .selected_customizations
-                    .par_iter()
+                    .iter()
                     .any(|cs| cs == s.ident)
             })
-            .cloned()
-            .unwrap_or(available_head_skins[0]);
+            .unwrap_or_else(|| default_customization(player_class, CustomizationType::Head));
 
-        let available_character_skins = PROFILE_SKINS_DEFAULTS
-            .par_iter()
-            .chain(PROFILE_SKINS.par_iter())
-            .filter(|h| h.ident.contains(&player_class.to_string()))
-            .cloned()
-            .collect::<Vec<_>>();
-
-        let character_skin_selected = available_character_skins
-            .par_iter()
-            .find_first(|s| {
+        let character_skin_selected = class_customizations(player_class, CustomizationType::Skin)
+            .into_iter()
+            .find(|s| {
                 character
                     .selected_customizations
-                    .par_iter()
+                    .iter()
                     .any(|cs| cs == s.ident)
             })
-            .cloned()
-            .unwrap_or(available_character_skins[0]);
+            .unwrap_or_else(|| default_customization(player_class, CustomizationType::Skin));
 
         let echo_theme_selected = PROFILE_ECHO_THEMES_DEFAULTS
             .par_iter()
@@ -171,25 +172,33 @@ impl CharacterData {
 
         sdu_slots.sort();
 
-        let mut ammo_pools = character
+        let mut ammo_pools = Vec::new();
+        let mut unknown_ammo_pools = Vec::new();
+
+        // Pools we don't know about yet are left in the save untouched
+        for rp in character
             .resource_pools
             .iter()
             .filter(|rp| !rp.resource_path.contains("Eridium"))
-            .map(|rp| {
-                let ammo = AmmoPool::from_str(&rp.resource_path)
-                    .with_context(|| format!("failed to read ammo: {}", &rp.resource_path))?;
-
+        {
+            if let Ok(ammo) = AmmoPool::from_str(&rp.resource_path) {
                 let max = ammo.maximum();
 
-                Ok(AmmoPoolData {
+                ammo_pools.push(AmmoPoolData {
                     pool: ammo,
                     current: rp.amount as i32,
                     max,
-                })
-            })
-            .collect::<Result<Vec<_>>>()?;
+                });
+            } else {
+                unknown_ammo_pools.push(UnknownAmmoPoolData {
+                    resource_path: rp.resource_path.clone(),
+                    current: rp.amount as i32,
+                });
+            }
+        }
 
         ammo_pools.sort();
+        unknown_ammo_pools.sort();
 
         let mut challenge_milestones = Challenge::iter()
             .filter(|challenge| {
@@ -363,6 +372,7 @@ impl CharacterData {
             unlockable_inventory_slots,
             sdu_slots,
             ammo_pools,
+            unknown_ammo_pools,
             challenge_milestones,
             vehicle_data,
             inventory_items,
@@ -409,6 +419,8 @@ impl CharacterData {
         Ok(())
     }
 
+    // Health and shield capacity aren't stored. The game scales them from the level with tables
+    // that aren't in the game data here, so the editor doesn't estimate them.
     pub fn player_level(&self) -> i32 {
         self.player_level
     }
@@ -481,6 +493,42 @@ impl CharacterData {
         Ok(())
     }
 
+    pub fn spent_ability_points(&self) -> i32 {
+        self.character
+            .ability_data
+            .as_ref()
+            .map(|ad| ad.tree_item_list.iter().map(|ti| ti.points).sum())
+            .unwrap_or(0)
+    }
+
+    pub fn skill_tree_items(&self) -> Vec<SkillTreeItem> {
+        self.character
+            .ability_data
+            .as_ref()
+            .map(|ad| skill_tree_items(&ad.tree_item_list))
+            .unwrap_or_default()
+    }
+
+    pub fn set_skill_tree_item_points(&mut self, path: &str, points: i32) -> Result<()> {
+        let tree_item = self
+            .character
+            .ability_data
+            .as_mut()
+            .context("failed to read Player ability data")?
+            .tree_item_list
+            .iter_mut()
+            .find(|ti| ti.item_asset_path == path)
+            .with_context(|| format!("failed to find skill: {}", path))?;
+
+        tree_item.points = points.clamp(0, tree_item.max_points);
+
+        Ok(())
+    }
+
+    pub fn expected_ability_points(&self) -> i32 {
+        (ability_points_for_level(self.player_level) - self.spent_ability_points()).max(0)
+    }
+
     pub fn guardian_rank(&self) -> i32 {
         self.guardian_rank
     }
@@ -673,10 +721,367 @@ impl CharacterData {
         Ok(())
     }
 
+    // Saves have no co-op or splitscreen flag. `Character.preferred_group_mode` (field 45) is the
+    // co-op loot mode preference, not an unlock, so it isn't offered as a way to leave solo mode.
     pub fn playthroughs(&self) -> &Vec<Playthrough> {
         &self.playthroughs
     }
 
+    fn current_playthrough_index(&self) -> usize {
+        self.character.last_play_through_index.max(0) as usize
+    }
+
+    pub fn playthrough_type(&self) -> PlaythroughType {
+        PlaythroughType::from_index(self.current_playthrough_index()).unwrap_or_default()
+    }
+
+    pub fn set_playthrough_type(&mut self, playthrough_type: PlaythroughType) -> Result<()> {
+        let playthrough_index = playthrough_type.index();
+
+        if playthrough_index >= self.playthroughs.len() {
+            bail!(
+                "{} has not been unlocked for this character.",
+                playthrough_type
+            );
+        }
+
+        self.character.last_play_through_index = playthrough_index as i32;
+
+        if let Some(travel_station) = self
+            .character
+            .last_active_travel_station_for_playthrough
+            .get(playthrough_index)
+        {
+            self.character.last_active_travel_station = travel_station.clone();
+        }
+
+        Ok(())
+    }
+
+    pub fn reset_playthrough(&mut self, playthrough_type: PlaythroughType) -> Result<()> {
+        let playthrough_index = playthrough_type.index();
+
+        if playthrough_index >= self.playthroughs.len() {
+            bail!(
+                "{} has not been unlocked for this character.",
+                playthrough_type
+            );
+        }
+
+        if let Some(mission_data) = self
+            .character
+            .mission_playthroughs_data
+            .get_mut(playthrough_index)
+        {
+            let mut first_mission = MissionStatusPlayerSaveGameData::new();
+            first_mission.status = MissionStatusPlayerSaveGameData_MissionState::MS_Active;
+            first_mission.mission_class_path = FIRST_MISSION.to_owned();
+
+            mission_data.mission_list.clear();
+            mission_data.mission_list.push(first_mission);
+            mission_data.tracked_mission_class_path = FIRST_MISSION.to_owned();
+        }
+
+        if let Some(travel_stations) = self
+            .character
+            .active_travel_stations_for_playthrough
+            .get_mut(playthrough_index)
+        {
+            travel_stations.active_travel_stations.clear();
+        }
+
+        if let Some(last_travel_station) = self
+            .character
+            .last_active_travel_station_for_playthrough
+            .get_mut(playthrough_index)
+        {
+            *last_travel_station = STARTING_TRAVEL_STATION.to_owned();
+        }
+
+        if let Some(game_state) = self
+            .character
+            .game_state_save_data_for_playthrough
+            .get_mut(playthrough_index)
+        {
+            game_state.last_traveled_map_id.clear();
+        }
+
+        // Normal mode stays completed so that True Vault Hunter Mode isn't locked
+        if playthrough_index > 0 {
+            self.character.playthroughs_completed = self
+                .character
+                .playthroughs_completed
+                .min(playthrough_index as i32);
+        }
+
+        self.playthroughs = Playthrough::playthroughs_from_character(&self.character)?;
+
+        Ok(())
+    }
+
+    /// The missions in progress in the playthrough, with the progress of their current objectives.
+    pub fn active_missions(&self, playthrough_type: PlaythroughType) -> Vec<ActiveMission> {
+        self.character
+            .mission_playthroughs_data
+            .get(playthrough_type.index())
+            .map(|mission_data| {
+                mission_data
+                    .mission_list
+                    .iter()
+                    .filter(|ms| {
+                        ms.status == MissionStatusPlayerSaveGameData_MissionState::MS_Active
+                    })
+                    .map(ActiveMission::from_mission_status)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn tracked_mission(&self, playthrough_type: PlaythroughType) -> Option<&str> {
+        self.character
+            .mission_playthroughs_data
+            .get(playthrough_type.index())
+            .map(|mission_data| mission_data.tracked_mission_class_path.as_str())
+            .filter(|path| !path.is_empty())
+    }
+
+    fn active_mission_status_mut(
+        &mut self,
+        playthrough_type: PlaythroughType,
+        mission_path: &str,
+    ) -> Result<&mut MissionStatusPlayerSaveGameData> {
+        self.character
+            .mission_playthroughs_data
+            .get_mut(playthrough_type.index())
+            .with_context(|| {
+                format!(
+                    "{} has not been unlocked for this character.",
+                    playthrough_type
+                )
+            })?
+            .mission_list
+            .iter_mut()
+            .find(|ms| {
+                ms.status == MissionStatusPlayerSaveGameData_MissionState::MS_Active
+                    && ms.mission_class_path.eq_ignore_ascii_case(mission_path)
+            })
+            .with_context(|| {
+                format!(
+                    "{} is not an active mission in {}",
+                    mission_path, playthrough_type
+                )
+            })
+    }
+
+    /// Only a mission that is active can be tracked.
+    pub fn set_tracked_mission(
+        &mut self,
+        playthrough_type: PlaythroughType,
+        mission_path: &str,
+    ) -> Result<()> {
+        let mission_path = self
+            .active_mission_status_mut(playthrough_type, mission_path)?
+            .mission_class_path
+            .clone();
+
+        if let Some(mission_data) = self
+            .character
+            .mission_playthroughs_data
+            .get_mut(playthrough_type.index())
+        {
+            mission_data.tracked_mission_class_path = mission_path;
+        }
+
+        Ok(())
+    }
+
+    /// Sets the progress of an objective in the current objective set of an active mission, see
+    /// [`check_objective_progress`] for the values that are allowed.
+    pub fn set_objective_progress(
+        &mut self,
+        playthrough_type: PlaythroughType,
+        mission_path: &str,
+        objective_index: usize,
+        progress: i32,
+    ) -> Result<()> {
+        let mission_status = self.active_mission_status_mut(playthrough_type, mission_path)?;
+
+        let target = objective_targets(
+            &mission_status.active_objective_set_path,
+            OBJECTIVE_SET_TARGETS,
+        )
+        .and_then(|t| t.get(objective_index).copied());
+
+        check_objective_progress(progress, target)?;
+
+        let objective_progress = mission_status
+            .objectives_progress
+            .get_mut(objective_index)
+            .with_context(|| {
+                format!(
+                    "{} has no objective number {}",
+                    mission_path,
+                    objective_index + 1
+                )
+            })?;
+
+        *objective_progress = progress;
+
+        Ok(())
+    }
+
+    pub fn mayhem_level(&self) -> u32 {
+        self.playthroughs
+            .get(self.current_playthrough_index())
+            .map(|p| p.mayhem_level.max(0) as u32)
+            .unwrap_or(0)
+    }
+
+    pub fn set_mayhem_level(&mut self, level: u32) -> Result<()> {
+        if level > MAX_MAYHEM_LEVEL {
+            bail!(
+                "Mayhem level {} is higher than the maximum of {}.",
+                level,
+                MAX_MAYHEM_LEVEL
+            );
+        }
+
+        let playthrough_index = self.current_playthrough_index();
+
+        let game_state = self
+            .character
+            .game_state_save_data_for_playthrough
+            .get_mut(playthrough_index)
+            .with_context(|| {
+                format!(
+                    "failed to find game state for playthrough {}",
+                    playthrough_index + 1
+                )
+            })?;
+
+        game_state.mayhem_level = level as i32;
+
+        if let Some(playthrough) = self.playthroughs.get_mut(playthrough_index) {
+            playthrough.mayhem_level = level as i32;
+        }
+
+        Ok(())
+    }
+
+    pub fn is_travel_station_unlocked(
+        &self,
+        playthrough_index: usize,
+        travel_station: &TravelStation,
+    ) -> bool {
+        self.playthroughs
+            .get(playthrough_index)
+            .map(|p| {
+                p.active_travel_stations
+                    .iter()
+                    .any(|s| travel_station.is_same_station(s))
+            })
+            .unwrap_or(false)
+    }
+
+    pub fn set_last_travel_station(
+        &mut self,
+        playthrough_index: usize,
+        travel_station: &TravelStation,
+        unlock_if_locked: bool,
+    ) -> Result<()> {
+        if playthrough_index
+            >= self
+                .character
+                .last_active_travel_station_for_playthrough
+                .len()
+        {
+            bail!(
+                "Playthrough {} does not exist in this save.",
+                playthrough_index + 1
+            );
+        }
+
+        if !self.is_travel_station_unlocked(playthrough_index, travel_station) {
+            if !unlock_if_locked {
+                bail!(
+                    "{} has not been unlocked in playthrough {}.",
+                    travel_station,
+                    playthrough_index + 1
+                );
+            }
+
+            let active_travel_stations = self
+                .character
+                .active_travel_stations_for_playthrough
+                .get_mut(playthrough_index)
+                .with_context(|| {
+                    format!(
+                        "failed to find travel stations for playthrough {}",
+                        playthrough_index + 1
+                    )
+                })?;
+
+            active_travel_stations
+                .active_travel_stations
+                .push(ActiveFastTravelSaveData {
+                    active_travel_station_name: travel_station.path.clone(),
+                    blacklisted: false,
+                    unknown_fields: Default::default(),
+                    cached_size: Default::default(),
+                });
+
+            if let Some(playthrough) = self.playthroughs.get_mut(playthrough_index) {
+                playthrough
+                    .active_travel_stations
+                    .push(travel_station.path.clone());
+            }
+        }
+
+        self.character.last_active_travel_station_for_playthrough[playthrough_index] =
+            travel_station.path.clone();
+
+        if playthrough_index == self.current_playthrough_index() {
+            self.character.last_active_travel_station = travel_station.path.clone();
+        }
+
+        if let Some(playthrough) = self.playthroughs.get_mut(playthrough_index) {
+            playthrough.last_travel_station = Some(travel_station.clone());
+
+            if let Some(current_map) = FAST_TRAVEL
+                .iter()
+                .find(|ft| travel_station.is_same_station(ft.ident))
+            {
+                playthrough.current_map = *current_map;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn last_visited_map(&self) -> Option<MapLocation> {
+        self.playthroughs
+            .get(self.current_playthrough_index())
+            .and_then(|p| {
+                MapLocation::all()
+                    .iter()
+                    .find(|m| m.name == p.current_map.name)
+                    .copied()
+            })
+    }
+
+    // Moves the current playthrough's spawn station to the map's travel station. The hashed map ids
+    // are left alone.
+    pub fn set_last_visited_map(&mut self, map_path: &str) -> Result<()> {
+        let map = MapLocation::from_path(map_path)
+            .with_context(|| format!("{} is not a known map", map_path))?;
+
+        self.set_last_travel_station(
+            self.current_playthrough_index(),
+            &TravelStation::from_path(map.travel_station),
+            true,
+        )
+    }
+
     pub fn unlockable_inventory_slots(&self) -> &Vec<InventorySlotData> {
         &self.unlockable_inventory_slots
     }
@@ -761,6 +1166,75 @@ impl CharacterData {
         Ok(())
     }
 
+    pub fn equipped_inventory_index(&self, inventory_slot: &InventorySlot) -> Option<usize> {
+        let slot_path = inventory_slot.get_serializations()[0];
+
+        self.character
+            .equipped_inventory_list
+            .iter()
+            .find(|s| s.slot_data_path == slot_path)
+            .and_then(|s| usize::try_from(s.inventory_list_index).ok())
+    }
+
+    /// The item in each equipped slot along with its inventory index, in slot order. Empty slots
+    /// are left out.
+    pub fn equipped_items(&self) -> Vec<(InventorySlot, usize, &Bl3Item)> {
+        InventorySlot::iter()
+            .filter_map(|slot| {
+                let index = self.equipped_inventory_index(&slot)?;
+                let item = self.inventory_items.get(index)?;
+
+                Some((slot, index, item))
+            })
+            .collect()
+    }
+
+    pub fn equip_inventory_item(
+        &mut self,
+        inventory_slot: &InventorySlot,
+        item_index: usize,
+    ) -> Result<()> {
+        let slot_path = inventory_slot.get_serializations()[0];
+
+        let slot = self
+            .character
+            .equipped_inventory_list
+            .iter_mut()
+            .find(|s| s.slot_data_path == slot_path)
+            .with_context(|| format!("failed to find inventory slot: {}", inventory_slot))?;
+
+        slot.inventory_list_index = item_index as i32;
+
+        Ok(())
+    }
+
+    /// The best of `items` for each unlocked slot along with its index in `items`. Slots with no
+    /// item that fits are left out.
+    pub fn best_items_for_slots(
+        &self,
+        items: &[Bl3Item],
+        player_class: PlayerClass,
+    ) -> Vec<(InventorySlot, usize)> {
+        self.unlockable_inventory_slots
+            .iter()
+            .filter(|s| s.unlocked)
+            .filter_map(|s| {
+                find_best_for_slot(items, &s.slot, player_class)
+                    .map(|item_index| (s.slot.clone(), item_index))
+            })
+            .collect()
+    }
+
+    pub fn equip_best_items(&mut self, items: &[Bl3Item]) -> Result<usize> {
+        let best_items = self.best_items_for_slots(items, self.player_class);
+
+        for (slot, item_index) in &best_items {
+            self.equip_inventory_item(slot, *item_index)?;
+        }
+
+        Ok(best_items.len())
+    }
+
     pub fn sdu_slots(&self) -> &Vec<SaveSduSlotData> {
         &self.sdu_slots
     }
@@ -799,6 +1273,10 @@ impl CharacterData {
         &self.ammo_pools
     }
 
+    pub fn unknown_ammo_pools(&self) -> &Vec<UnknownAmmoPoolData> {
+        &self.unknown_ammo_pools
+    }
+
     pub fn set_ammo_pool(&mut self, ammo_pool: &AmmoPool, amount: i32) -> Result<()> {
         let pool_path = ammo_pool.get_serializations()[0];
 
@@ -878,6 +1356,81 @@ impl CharacterData {
         }
     }
 
+    pub fn is_vehicle_asset_unlocked(&self, asset_path: &str) -> bool {
+        self.character
+            .vehicles_unlocked_data
+            .iter()
+            .any(|vd| vd.asset_path == asset_path)
+            || self
+                .character
+                .vehicle_parts_unlocked
+                .iter()
+                .any(|vp| vp == asset_path)
+    }
+
+    /// The skin and wheels the vehicle is called with, the game's stock ones are used for anything
+    /// the save doesn't have yet.
+    pub fn vehicle_loadout(&self, vehicle: &Vehicle) -> VehicleLoadout {
+        let body_asset_path = vehicle.body_asset_path();
+        let stock = vehicle.stock_loadout();
+
+        match self
+            .character
+            .vehicle_loadouts
+            .iter()
+            .find(|l| l.body_asset_path.eq_ignore_ascii_case(&body_asset_path))
+        {
+            Some(loadout) => {
+                let or_stock = |path: &str, stock: String| {
+                    if path.is_empty() {
+                        stock
+                    } else {
+                        path.to_owned()
+                    }
+                };
+
+                VehicleLoadout {
+                    vehicle: *vehicle,
+                    skin: or_stock(&loadout.material_decal_asset_path, stock.skin),
+                    wheels: or_stock(&loadout.wheel_asset_path, stock.wheels),
+                }
+            }
+            None => stock,
+        }
+    }
+
+    pub fn set_vehicle_loadout(&mut self, vehicle_loadout: &VehicleLoadout) {
+        let body_asset_path = vehicle_loadout.vehicle.body_asset_path();
+
+        let mut loadouts = self
+            .character
+            .vehicle_loadouts
+            .iter_mut()
+            .filter(|l| l.body_asset_path.eq_ignore_ascii_case(&body_asset_path))
+            .peekable();
+
+        if loadouts.peek().is_none() {
+            self.character
+                .vehicle_loadouts
+                .push(OakCARMenuVehicleConfigSaveData {
+                    body_asset_path,
+                    wheel_asset_path: vehicle_loadout.wheels.clone(),
+                    material_decal_asset_path: vehicle_loadout.skin.clone(),
+                    color_index_1: -1,
+                    color_index_2: -1,
+                    color_index_3: -1,
+                    ..Default::default()
+                });
+
+            return;
+        }
+
+        for loadout in loadouts {
+            loadout.wheel_asset_path = vehicle_loadout.wheels.clone();
+            loadout.material_decal_asset_path = vehicle_loadout.skin.clone();
+        }
+    }
+
     pub fn inventory_items(&self) -> &Vec<Bl3Item> {
         &self.inventory_items
     }
@@ -928,6 +1481,78 @@ impl CharacterData {
         }
     }
 
+    /// Removes every inventory item and empties the equipped slots, returning how many items were
+    /// removed.
+    pub fn clear_inventory_items(&mut self) -> usize {
+        let removed = self.inventory_items.len();
+
+        self.character.inventory_items.clear();
+        self.inventory_items.clear();
+
+        // Empty slots are stored as -1
+        for equipped in self.character.equipped_inventory_list.iter_mut() {
+            equipped.inventory_list_index = -1;
+        }
+
+        removed
+    }
+
+    pub fn take_inventory_item(&mut self, index: usize) -> Option<Bl3Item> {
+        let item = self.inventory_items.get(index).cloned()?;
+
+        self.remove_inventory_item(index);
+
+        let index = index as i32;
+
+        for equipped in self.character.equipped_inventory_list.iter_mut() {
+            if equipped.inventory_list_index == index {
+                equipped.inventory_list_index = -1;
+            } else if equipped.inventory_list_index > index {
+                equipped.inventory_list_index -= 1;
+            }
+        }
+
+        Some(item)
+    }
+
+    /// Replaces the inventory and the equipped items with the ones from `other`.
+    pub fn set_inventory_from(&mut self, other: &CharacterData) {
+        self.character.inventory_items = other.character.inventory_items.clone();
+        self.character.equipped_inventory_list = other.character.equipped_inventory_list.clone();
+        self.inventory_items = other.inventory_items.clone();
+        self.unlockable_inventory_slots = other.unlockable_inventory_slots.clone();
+    }
+
+    /// Removes items with the same serial as an earlier item in the inventory, returning how many
+    /// were removed. Equipped items are always kept.
+    pub fn remove_duplicate_inventory_items(&mut self) -> Result<usize> {
+        let equipped_indexes = self
+            .character
+            .equipped_inventory_list
+            .iter()
+            .map(|e| e.inventory_list_index)
+            .collect::<HashSet<_>>();
+
+        let mut seen_serials = HashSet::new();
+        let mut duplicate_indexes = Vec::new();
+
+        for (i, item) in self.inventory_items.iter().enumerate() {
+            let serial = item
+                .get_serial_number_base64(false)
+                .with_context(|| format!("failed to read serial of item number: {}", i + 1))?;
+
+            if !seen_serials.insert(serial) && !equipped_indexes.contains(&(i as i32)) {
+                duplicate_indexes.push(i);
+            }
+        }
+
+        for i in duplicate_indexes.iter().rev() {
+            self.take_inventory_item(*i);
+        }
+
+        Ok(duplicate_indexes.len())
+    }
+
     pub fn add_inventory_item(&mut self, pickup_order_index: i32, item: &Bl3Item) -> Result<()> {
         let new_oak_item = Self::create_inventory_item(pickup_order_index, item, true)?;
 
@@ -1006,6 +1631,53 @@ impl CharacterData {
         Ok(())
     }
 
+    pub fn incomplete_challenges<'a>(&self, challenge_paths: &[&'a str]) -> Vec<&'a str> {
+        challenge_paths
+            .iter()
+            .filter(|p| {
+                self.character
+                    .challenge_data
+                    .iter()
+                    .any(|c| c.challenge_class_path == **p && !c.currently_completed)
+            })
+            .copied()
+            .collect()
+    }
+
+    pub fn challenge_completion(&self) -> Vec<ChallengeCategoryCompletion> {
+        challenge_completion(&self.character.challenge_data)
+    }
+
+    /// Completes every base game challenge in this save, and the DLC and event challenges when
+    /// `include_dlc` is set. Returns how many were changed.
+    ///
+    /// The game data doesn't have the targets of each tier, so the tier a challenge has reached is
+    /// kept rather than being raised.
+    pub fn complete_all_challenges(&mut self, include_dlc: bool) -> Result<usize> {
+        let incomplete = self
+            .character
+            .challenge_data
+            .iter()
+            .filter(|c| {
+                !c.currently_completed
+                    && is_completable_challenge(&c.challenge_class_path, include_dlc)
+            })
+            .map(|c| {
+                (
+                    c.challenge_class_path.clone(),
+                    c.completed_count.max(1),
+                    c.completed_progress_level,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        for (challenge_path, completed_count, progress_level) in &incomplete {
+            self.unlock_challenge_obj(challenge_path, *completed_count, *progress_level)?;
+        }
+
+        Ok(incomplete.len())
+    }
+
     pub fn set_game_stat(&mut self, stat_path: &str, stat_value: i32) {
         if let Some(game_stat) = self
             .character
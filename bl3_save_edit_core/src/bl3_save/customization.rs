@@ -0,0 +1,148 @@
+use once_cell::sync::Lazy;
+
+use crate::bl3_save::player_class::PlayerClass;
+use crate::game_data::{
+    GameDataKv, PROFILE_HEADS, PROFILE_HEADS_DEFAULTS, PROFILE_SKINS, PROFILE_SKINS_DEFAULTS,
+};
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum CustomizationType {
+    Head,
+    Skin,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct ClassCustomization {
+    pub player_class: PlayerClass,
+    pub customization_type: CustomizationType,
+    pub is_default: bool,
+    pub customization: GameDataKv,
+}
+
+/// Every head and skin tagged with the class that can wear it.
+pub static CLASS_CUSTOMIZATIONS: Lazy<Vec<ClassCustomization>> = Lazy::new(|| {
+    let tables: [(CustomizationType, bool, &[GameDataKv]); 4] = [
+        (CustomizationType::Head, true, &PROFILE_HEADS_DEFAULTS),
+        (CustomizationType::Head, false, &PROFILE_HEADS),
+        (CustomizationType::Skin, true, &PROFILE_SKINS_DEFAULTS),
+        (CustomizationType::Skin, false, &PROFILE_SKINS),
+    ];
+
+    tables
+        .iter()
+        .flat_map(|(customization_type, is_default, table)| {
+            table.iter().filter_map(move |customization| {
+                Some(ClassCustomization {
+                    player_class: class_for_ident(customization.ident)?,
+                    customization_type: *customization_type,
+                    is_default: *is_default,
+                    customization: *customization,
+                })
+            })
+        })
+        .collect()
+});
+
+// Class folders and asset names don't share one spelling (SirenBrawler, BeastMaster, DA_SRNHead03)
+// but every path contains exactly one class name somewhere.
+fn class_for_ident(ident: &str) -> Option<PlayerClass> {
+    let ident = ident.to_lowercase();
+
+    let mut matching = PlayerClass::ALL
+        .iter()
+        .filter(|c| ident.contains(&c.to_string().to_lowercase()));
+
+    match (matching.next(), matching.next()) {
+        (Some(player_class), None) => Some(*player_class),
+        _ => None,
+    }
+}
+
+pub fn customization_for_ident(ident: &str) -> Option<&'static ClassCustomization> {
+    CLASS_CUSTOMIZATIONS
+        .iter()
+        .find(|c| c.customization.ident == ident)
+}
+
+/// The heads or skins the class can wear, sorted by name.
+pub fn class_customizations(
+    player_class: PlayerClass,
+    customization_type: CustomizationType,
+) -> Vec<GameDataKv> {
+    let mut customizations = CLASS_CUSTOMIZATIONS
+        .iter()
+        .filter(|c| c.player_class == player_class && c.customization_type == customization_type)
+        .map(|c| c.customization)
+        .collect::<Vec<_>>();
+
+    customizations.sort();
+
+    customizations
+}
+
+pub fn default_customization(
+    player_class: PlayerClass,
+    customization_type: CustomizationType,
+) -> GameDataKv {
+    CLASS_CUSTOMIZATIONS
+        .iter()
+        .find(|c| {
+            c.is_default
+                && c.player_class == player_class
+                && c.customization_type == customization_type
+        })
+        .map(|c| c.customization)
+        .expect("every class has a default head and skin")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_customization_has_a_class() {
+        assert_eq!(
+            CLASS_CUSTOMIZATIONS.len(),
+            PROFILE_HEADS_DEFAULTS.len()
+                + PROFILE_HEADS.len()
+                + PROFILE_SKINS_DEFAULTS.len()
+                + PROFILE_SKINS.len()
+        );
+
+        for player_class in PlayerClass::ALL {
+            for customization_type in [CustomizationType::Head, CustomizationType::Skin] {
+                let customizations = class_customizations(player_class, customization_type);
+
+                assert!(customizations.len() > 1);
+                assert!(customizations
+                    .contains(&default_customization(player_class, customization_type)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_customization_for_ident() {
+        let roll_player = customization_for_ident(
+            "/Game/PatchDLC/EventVDay/PlayerCharacters/_Shared/CustomHead_BeastMaster_Twitch.CustomHead_BeastMaster_Twitch",
+        )
+        .expect("failed to find head");
+
+        assert_eq!(roll_player.player_class, PlayerClass::BeastMaster);
+        assert_eq!(roll_player.customization_type, CustomizationType::Head);
+        assert_eq!(roll_player.customization.name, "Roll Player");
+
+        let streetwise = customization_for_ident(
+            "/Game/PatchDLC/VaultCard2/PlayerCharacters/SirenBrawler/Heads/DA_SRNHead03.DA_SRNHead03",
+        )
+        .expect("failed to find head");
+
+        assert_eq!(streetwise.player_class, PlayerClass::Siren);
+
+        assert_eq!(
+            default_customization(PlayerClass::Gunner, CustomizationType::Skin).name,
+            "Gunner Skin Default"
+        );
+
+        assert!(customization_for_ident("/Game/Not/A/Head").is_none());
+    }
+}
@@ -1,4 +1,9 @@
-use strum::{Display, EnumMessage, EnumString};
+use std::cmp::Reverse;
+
+use strum::{Display, EnumIter, EnumMessage, EnumString};
+
+use crate::bl3_item::{Bl3Item, ItemType};
+use crate::bl3_save::player_class::PlayerClass;
 
 #[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Clone)]
 pub struct InventorySlotData {
@@ -6,7 +11,9 @@ pub struct InventorySlotData {
     pub unlocked: bool,
 }
 
-#[derive(Debug, Display, EnumString, EnumMessage, Eq, PartialEq, Ord, PartialOrd, Clone)]
+#[derive(
+    Debug, Display, EnumString, EnumIter, EnumMessage, Eq, PartialEq, Ord, PartialOrd, Clone,
+)]
 pub enum InventorySlot {
     #[strum(
         serialize = "/Game/Gear/Weapons/_Shared/_Design/InventorySlots/BPInvSlot_Weapon1.BPInvSlot_Weapon1",
@@ -55,3 +62,90 @@ impl std::default::Default for InventorySlot {
         Self::Grenade
     }
 }
+
+impl InventorySlot {
+    pub fn item_type(&self) -> ItemType {
+        match self {
+            InventorySlot::Weapon1
+            | InventorySlot::Weapon2
+            | InventorySlot::Weapon3
+            | InventorySlot::Weapon4 => ItemType::Weapon,
+            InventorySlot::Shield => ItemType::Shield,
+            InventorySlot::Grenade => ItemType::GrenadeMod,
+            InventorySlot::ClassMod => ItemType::ClassMod,
+            InventorySlot::Artifact => ItemType::Artifact,
+        }
+    }
+
+    fn rank(&self) -> usize {
+        match self {
+            InventorySlot::Weapon2 => 1,
+            InventorySlot::Weapon3 => 2,
+            InventorySlot::Weapon4 => 3,
+            _ => 0,
+        }
+    }
+}
+
+// Weapon slots take the highest, second highest etc. level weapon so each slot gets a different weapon
+pub fn find_best_for_slot(
+    items: &[Bl3Item],
+    slot: &InventorySlot,
+    player_class: PlayerClass,
+) -> Option<usize> {
+    let item_type = slot.item_type();
+
+    let mut matching_items = items
+        .iter()
+        .enumerate()
+        .filter(|(_, item)| item.item_type == item_type)
+        // Class mods only work for the class they were made for
+        .filter(|(_, item)| {
+            *slot != InventorySlot::ClassMod
+                || PlayerClass::from_class_mod_balance(&item.balance_part().ident)
+                    == Some(player_class)
+        })
+        .collect::<Vec<_>>();
+
+    matching_items.sort_by_key(|(_, item)| Reverse(item.level()));
+
+    matching_items.get(slot.rank()).map(|(i, _)| *i)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bl3_item::Bl3Item;
+    use crate::bl3_save::inventory_slot::{find_best_for_slot, InventorySlot};
+    use crate::bl3_save::player_class::PlayerClass;
+
+    fn item_with_level(serial: &str, level: usize) -> Bl3Item {
+        let mut item = Bl3Item::from_serial_base64(serial).expect("failed to read test item");
+
+        item.set_level(level).expect("failed to set item level");
+
+        item
+    }
+
+    #[test]
+    fn test_find_best_for_slot() {
+        let shield = "BL3(AwAAAABmboC7I9xAEzwShMJVX8nPYwsAAA==)";
+
+        let items = vec![
+            item_with_level(shield, 10),
+            item_with_level(shield, 57),
+            item_with_level(shield, 30),
+        ];
+
+        let class = PlayerClass::Siren;
+
+        assert_eq!(
+            find_best_for_slot(&items, &InventorySlot::Shield, class),
+            Some(1)
+        );
+        assert_eq!(
+            find_best_for_slot(&items, &InventorySlot::Weapon1, class),
+            None
+        );
+        assert_eq!(find_best_for_slot(&[], &InventorySlot::Shield, class), None);
+    }
+}
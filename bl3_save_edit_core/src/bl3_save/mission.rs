@@ -0,0 +1,142 @@
+use anyhow::{bail, Result};
+
+use crate::game_data::MISSION;
+use crate::protos::oak_save::MissionStatusPlayerSaveGameData;
+
+/// How far each objective of a mission's objective set has to go before it is complete.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ObjectiveSetTargets {
+    pub path: &'static str,
+    pub targets: &'static [i32],
+}
+
+// Only add objective sets that have been checked against the game data, the progress of an
+// objective without a known target can be set to anything that isn't negative.
+pub const OBJECTIVE_SET_TARGETS: &[ObjectiveSetTargets] = &[];
+
+pub fn objective_targets(
+    objective_set_path: &str,
+    objective_sets: &[ObjectiveSetTargets],
+) -> Option<&'static [i32]> {
+    objective_sets
+        .iter()
+        .find(|s| s.path.eq_ignore_ascii_case(objective_set_path))
+        .map(|s| s.targets)
+}
+
+/// Objectives can't go below zero or past their target when it is known.
+pub fn check_objective_progress(progress: i32, target: Option<i32>) -> Result<()> {
+    if progress < 0 {
+        bail!("objective progress can't be negative");
+    }
+
+    if let Some(target) = target {
+        if progress > target {
+            bail!(
+                "objective progress can't be more than its target of {}",
+                target
+            );
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ActiveMission {
+    pub path: String,
+    pub name: String,
+    pub objective_set_path: String,
+    pub objectives: Vec<MissionObjective>,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct MissionObjective {
+    pub progress: i32,
+    pub target: Option<i32>,
+}
+
+impl MissionObjective {
+    pub fn is_complete(&self) -> bool {
+        self.target.is_some_and(|t| self.progress >= t)
+    }
+}
+
+impl ActiveMission {
+    pub fn from_mission_status(mission_status: &MissionStatusPlayerSaveGameData) -> Self {
+        let name = MISSION
+            .iter()
+            .find(|m| {
+                mission_status
+                    .mission_class_path
+                    .eq_ignore_ascii_case(m.ident)
+            })
+            .map(|m| m.name.to_owned())
+            .unwrap_or_else(|| mission_status.mission_class_path.clone());
+
+        let targets = objective_targets(
+            &mission_status.active_objective_set_path,
+            OBJECTIVE_SET_TARGETS,
+        );
+
+        let objectives = mission_status
+            .objectives_progress
+            .iter()
+            .enumerate()
+            .map(|(i, progress)| MissionObjective {
+                progress: *progress,
+                target: targets.and_then(|t| t.get(i).copied()),
+            })
+            .collect();
+
+        ActiveMission {
+            path: mission_status.mission_class_path.clone(),
+            name,
+            objective_set_path: mission_status.active_objective_set_path.clone(),
+            objectives,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_OBJECTIVE_SETS: &[ObjectiveSetTargets] = &[ObjectiveSetTargets {
+        path: "/Game/Missions/Test/Mission_Test.Set_Test_ObjectiveSet",
+        targets: &[1, 10],
+    }];
+
+    #[test]
+    fn test_objective_targets() {
+        assert_eq!(
+            objective_targets(
+                "/game/missions/test/mission_test.set_test_objectiveset",
+                TEST_OBJECTIVE_SETS
+            ),
+            Some(&[1, 10][..])
+        );
+        assert_eq!(
+            objective_targets("/Game/Missions/Test/Other", TEST_OBJECTIVE_SETS),
+            None
+        );
+    }
+
+    #[test]
+    fn test_check_objective_progress() {
+        assert!(check_objective_progress(0, None).is_ok());
+        assert!(check_objective_progress(i32::MAX, None).is_ok());
+        assert!(check_objective_progress(10, Some(10)).is_ok());
+        assert!(check_objective_progress(11, Some(10)).is_err());
+        assert!(check_objective_progress(-1, None).is_err());
+    }
+
+    #[test]
+    fn test_objective_is_complete() {
+        let objective = |progress, target| MissionObjective { progress, target };
+
+        assert!(objective(10, Some(10)).is_complete());
+        assert!(!objective(0, Some(10)).is_complete());
+        assert!(!objective(10, None).is_complete());
+    }
+}
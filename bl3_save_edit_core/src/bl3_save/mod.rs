@@ -5,28 +5,42 @@ use anyhow::{Context, Result};
 use byteorder::{LittleEndian, WriteBytesExt};
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 
+use crate::bl3_item::Bl3Item;
+use crate::bl3_profile::guardian_reward::GuardianReward;
+use crate::bl3_save::ammo::AmmoPool;
 use crate::bl3_save::character_data::CharacterData;
 use crate::bl3_save::inventory_slot::InventorySlot;
-use crate::file_helper::FileData;
+use crate::bl3_save::playthrough::PlaythroughType;
+use crate::bl3_save::save_diff::SaveDiff;
+use crate::bl3_save::sdu::SaveSduSlot;
+use crate::bl3_save::travel_station::MapLocation;
+use crate::file_helper::{FileData, FileMetadata, FileOrigin};
 use crate::models::CustomFormatData;
 use crate::parser::{decrypt, encrypt, HeaderType};
+use crate::vehicle_data::{VehicleLoadout, VehicleType};
 use crate::{file_helper, parser};
 
 pub mod ammo;
 pub mod challenge_data;
 pub mod character_data;
+pub mod customization;
 pub mod fast_travel_unlock_data;
 pub mod inventory_slot;
 pub mod level_data;
+pub mod mission;
 pub mod models;
 pub mod player_class;
 pub mod playthrough;
+pub mod save_diff;
 pub mod sdu;
+pub mod skill_tree;
+pub mod travel_station;
 pub mod util;
 
 #[derive(Debug, Clone, Default, Eq, PartialEq, Ord, PartialOrd)]
 pub struct Bl3Save {
     pub file_name: String,
+    pub origin: FileOrigin,
     pub save_game_version: u32,
     pub package_version: u32,
     pub engine_major: u16,
@@ -39,6 +53,7 @@ pub struct Bl3Save {
     pub custom_format_data: Vec<CustomFormatData>,
     pub save_game_type: String,
     pub header_type: HeaderType,
+    pub file_size: usize,
     pub character_data: CharacterData,
 }
 
@@ -63,6 +78,7 @@ impl Bl3Save {
             custom_format_data_count,
             custom_format_data,
             save_game_type,
+            file_size,
             ..
         } = file_data.clone();
 
@@ -73,6 +89,7 @@ impl Bl3Save {
 
         Ok(Self {
             file_name,
+            origin: FileOrigin::Directory,
             save_game_version: file_version,
             package_version,
             engine_major,
@@ -85,6 +102,7 @@ impl Bl3Save {
             custom_format_data,
             save_game_type,
             header_type,
+            file_size,
             character_data,
         })
     }
@@ -95,6 +113,157 @@ impl Bl3Save {
         Self::from_file_data(&file_data, header_type)
     }
 
+    pub fn is_version_newer_than_supported(&self) -> bool {
+        file_helper::is_version_newer_than_supported(self.save_game_version, self.package_version)
+    }
+
+    pub fn metadata(&self) -> FileMetadata {
+        FileMetadata {
+            save_game_version: self.save_game_version,
+            package_version: self.package_version,
+            engine_version: file_helper::engine_version_text(
+                self.engine_major,
+                self.engine_minor,
+                self.engine_patch,
+                self.engine_build,
+            ),
+            build_id: self.build_id.clone(),
+            header_type: self.header_type,
+            file_size: self.file_size,
+        }
+    }
+
+    pub fn mayhem_level(&self) -> u32 {
+        self.character_data.mayhem_level()
+    }
+
+    pub fn set_mayhem_level(&mut self, level: u32) -> Result<()> {
+        self.character_data.set_mayhem_level(level)
+    }
+
+    pub fn playthrough_type(&self) -> PlaythroughType {
+        self.character_data.playthrough_type()
+    }
+
+    pub fn set_playthrough_type(&mut self, playthrough_type: PlaythroughType) -> Result<()> {
+        self.character_data.set_playthrough_type(playthrough_type)
+    }
+
+    pub fn reset_playthrough(&mut self, playthrough_type: PlaythroughType) -> Result<()> {
+        self.character_data.reset_playthrough(playthrough_type)
+    }
+
+    pub fn set_tracked_mission(
+        &mut self,
+        playthrough_type: PlaythroughType,
+        mission_path: &str,
+    ) -> Result<()> {
+        self.character_data
+            .set_tracked_mission(playthrough_type, mission_path)
+    }
+
+    pub fn set_objective_progress(
+        &mut self,
+        playthrough_type: PlaythroughType,
+        mission_path: &str,
+        objective_index: usize,
+        progress: i32,
+    ) -> Result<()> {
+        self.character_data.set_objective_progress(
+            playthrough_type,
+            mission_path,
+            objective_index,
+            progress,
+        )
+    }
+
+    pub fn last_visited_map(&self) -> Option<MapLocation> {
+        self.character_data.last_visited_map()
+    }
+
+    pub fn set_last_visited_map(&mut self, map_path: &str) -> Result<()> {
+        self.character_data.set_last_visited_map(map_path)
+    }
+
+    pub fn set_character_name(&mut self, name: &str) {
+        self.character_data.character.preferred_character_name = name.to_owned();
+    }
+
+    /// Sets the experience points, the level is worked out from them.
+    pub fn set_experience_points(&mut self, experience_points: i32) -> Result<()> {
+        self.character_data.set_player_level(experience_points)
+    }
+
+    /// Sets the experience points to the minimum needed for `level`.
+    pub fn set_level(&mut self, level: i32) -> Result<()> {
+        let experience_points = util::level_to_xp(level)
+            .with_context(|| format!("failed to find experience points for level: {}", level))?;
+
+        self.set_experience_points(experience_points)
+    }
+
+    pub fn set_money(&mut self, amount: i32) -> Result<()> {
+        self.character_data.set_money(amount)
+    }
+
+    pub fn set_eridium(&mut self, amount: i32) -> Result<()> {
+        self.character_data.set_eridium(amount)
+    }
+
+    pub fn set_sdu_slot(&mut self, sdu_slot: &SaveSduSlot, level: i32) {
+        self.character_data.set_sdu_slot(sdu_slot, level)
+    }
+
+    pub fn set_ammo_pool(&mut self, ammo_pool: &AmmoPool, amount: i32) -> Result<()> {
+        self.character_data.set_ammo_pool(ammo_pool, amount)
+    }
+
+    /// Unlocks every chassis, part or skin of the given vehicle type.
+    pub fn unlock_vehicle_data(&mut self, vehicle_type: &VehicleType) {
+        self.character_data.unlock_vehicle_data(vehicle_type)
+    }
+
+    /// Sets the skin and wheels a vehicle is called with, neither has to be unlocked.
+    pub fn set_vehicle_loadout(&mut self, vehicle_loadout: &VehicleLoadout) {
+        self.character_data.set_vehicle_loadout(vehicle_loadout)
+    }
+
+    /// Adds the item to the end of the backpack.
+    pub fn add_inventory_item(&mut self, item: &Bl3Item) -> Result<()> {
+        let pickup_order_index = self.character_data.inventory_items().len() as i32;
+
+        self.character_data
+            .add_inventory_item(pickup_order_index, item)
+    }
+
+    pub fn remove_inventory_item(&mut self, index: usize) {
+        self.character_data.remove_inventory_item(index)
+    }
+
+    pub fn set_guardian_rank(&mut self, new_rank: i32, tokens: Option<i32>) {
+        self.character_data.set_guardian_rank(new_rank, tokens)
+    }
+
+    /// The changes that turn `baseline` into this save, see [`SaveDiff`].
+    pub fn diff_from(&self, baseline: &Bl3Save) -> Result<SaveDiff> {
+        SaveDiff::between(baseline, self)
+    }
+
+    /// Applies a diff made by [`Bl3Save::diff_from`], the save is left as it was if it fails.
+    pub fn apply_diff(&mut self, diff: &SaveDiff) -> Result<()> {
+        diff.apply(self)
+    }
+
+    /// Fails if the save has no guardian rank data yet, [`Bl3Save::set_guardian_rank`] adds it.
+    pub fn set_guardian_reward(
+        &mut self,
+        guardian_reward: &GuardianReward,
+        tokens: i32,
+    ) -> Result<()> {
+        self.character_data
+            .set_guardian_reward(guardian_reward, tokens)
+    }
+
     pub fn as_bytes(&self) -> Result<(Vec<u8>, Bl3Save)> {
         let mut output = Vec::new();
 
@@ -125,7 +294,9 @@ impl Bl3Save {
 
         //Now try re-reading it also - there's no point making an invalid save
         let file_name = Path::new(&self.file_name);
-        let new_save = Self::from_bytes(file_name, &output, self.header_type)?;
+        let mut new_save = Self::from_bytes(file_name, &output, self.header_type)?;
+
+        new_save.origin = self.origin.clone();
 
         Ok((output, new_save))
     }
@@ -273,12 +444,17 @@ impl std::fmt::Display for Bl3Save {
 mod tests {
     use std::fs;
 
-    use crate::bl3_save::ammo::{AmmoPool, AmmoPoolData};
+    use crate::bl3_item::ItemType;
+    use crate::bl3_save::ammo::{AmmoPool, AmmoPoolData, UnknownAmmoPoolData};
     use crate::bl3_save::challenge_data::{Challenge, ChallengeData};
-    use crate::bl3_save::inventory_slot::InventorySlotData;
+    use crate::bl3_save::inventory_slot::{InventorySlot, InventorySlotData};
     use crate::bl3_save::player_class::PlayerClass;
     use crate::bl3_save::sdu::{SaveSduSlot, SaveSduSlotData};
-    use crate::vehicle_data::{VehicleData, VehicleSubType, VehicleType};
+    use crate::bl3_save::travel_station::TravelStation;
+    use crate::bl3_save::util::{MAX_MAYHEM_LEVEL, REQUIRED_XP_LIST};
+    use crate::content_source::ContentSource;
+    use crate::protos::oak_save::ResourcePoolSavegameData;
+    use crate::vehicle_data::{Vehicle, VehicleData, VehicleSubType, VehicleType};
 
     use super::*;
 
@@ -2312,4 +2488,740 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn test_spent_ability_points() {
+        let filename = Path::new("./test_files/19.sav");
+
+        let save_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let mut bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        assert_eq!(bl3_save.character_data.ability_points(), 5);
+        assert_eq!(bl3_save.character_data.spent_ability_points(), 58);
+        assert_eq!(bl3_save.character_data.expected_ability_points(), 5);
+
+        bl3_save
+            .character_data
+            .set_player_level(REQUIRED_XP_LIST[71][0])
+            .expect("failed to set player level");
+
+        assert_eq!(bl3_save.character_data.spent_ability_points(), 0);
+        assert_eq!(bl3_save.character_data.expected_ability_points(), 70);
+        assert_eq!(bl3_save.character_data.ability_points(), 70);
+    }
+
+    #[test]
+    fn test_set_mayhem_level() {
+        let filename = Path::new("./test_files/19.sav");
+
+        let save_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let mut bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        assert_eq!(bl3_save.mayhem_level(), 10);
+
+        bl3_save
+            .set_mayhem_level(3)
+            .expect("failed to set mayhem level");
+
+        assert!(bl3_save.set_mayhem_level(MAX_MAYHEM_LEVEL + 1).is_err());
+
+        let (_, bl3_save) = bl3_save.as_bytes().expect("failed to write save");
+
+        assert_eq!(bl3_save.mayhem_level(), 3);
+    }
+
+    #[test]
+    fn test_set_playthrough_type() {
+        let filename = Path::new("./test_files/19.sav");
+
+        let save_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let mut bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        assert_eq!(
+            bl3_save.playthrough_type(),
+            PlaythroughType::TrueVaultHunterMode
+        );
+
+        bl3_save
+            .set_playthrough_type(PlaythroughType::Normal)
+            .expect("failed to set playthrough type");
+
+        let (_, bl3_save) = bl3_save.as_bytes().expect("failed to write save");
+
+        assert_eq!(bl3_save.playthrough_type(), PlaythroughType::Normal);
+        assert_eq!(
+            bl3_save.last_visited_map().map(|m| m.name),
+            Some("Midnight's Cairn (Maliwan Takedown)")
+        );
+
+        let filename = Path::new("./test_files/5.sav");
+
+        let save_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let mut bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        assert_eq!(bl3_save.playthrough_type(), PlaythroughType::Normal);
+        assert!(bl3_save
+            .set_playthrough_type(PlaythroughType::TrueVaultHunterMode)
+            .is_err());
+    }
+
+    #[test]
+    fn test_reset_playthrough() {
+        let filename = Path::new("./test_files/19.sav");
+
+        let save_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let mut bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        let normal_playthrough = bl3_save.character_data.playthroughs()[0].clone();
+        let inventory_items = bl3_save.character_data.inventory_items().len();
+        let level = bl3_save.character_data.player_level();
+
+        bl3_save
+            .reset_playthrough(PlaythroughType::TrueVaultHunterMode)
+            .expect("failed to reset playthrough");
+
+        let (_, bl3_save) = bl3_save.as_bytes().expect("failed to write save");
+
+        let playthroughs = bl3_save.character_data.playthroughs();
+
+        assert_eq!(playthroughs[0], normal_playthrough);
+        assert!(playthroughs[1].missions_completed.is_empty());
+        assert_eq!(
+            playthroughs[1].active_missions,
+            vec!["Children of the Vault"]
+        );
+        assert!(playthroughs[1].active_travel_stations.is_empty());
+        assert_eq!(bl3_save.character_data.character.playthroughs_completed, 1);
+        assert_eq!(
+            bl3_save.character_data.inventory_items().len(),
+            inventory_items
+        );
+        assert_eq!(bl3_save.character_data.player_level(), level);
+
+        let filename = Path::new("./test_files/5.sav");
+
+        let save_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let mut bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        assert!(bl3_save
+            .reset_playthrough(PlaythroughType::TrueVaultHunterMode)
+            .is_err());
+    }
+
+    #[test]
+    fn test_complete_all_challenges() {
+        let filename = Path::new("./test_files/19.sav");
+
+        let save_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let mut bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        let dlc_completion_before = bl3_save
+            .character_data
+            .challenge_completion()
+            .into_iter()
+            .filter(|c| c.content_source != ContentSource::BaseGame)
+            .collect::<Vec<_>>();
+
+        bl3_save
+            .character_data
+            .complete_all_challenges(false)
+            .expect("failed to complete all challenges");
+
+        // The save should still parse after every challenge was completed
+        let (_, bl3_save) = bl3_save.as_bytes().expect("failed to write save");
+
+        let completion = bl3_save.character_data.challenge_completion();
+
+        assert!(completion
+            .iter()
+            .filter(|c| c.content_source == ContentSource::BaseGame)
+            .all(|c| c.completed == c.total));
+
+        assert_eq!(
+            completion
+                .into_iter()
+                .filter(|c| c.content_source != ContentSource::BaseGame)
+                .collect::<Vec<_>>(),
+            dlc_completion_before
+        );
+
+        // The internal test challenge isn't in a category so it is left alone
+        let test_challenge = "/Game/GameData/Challenges/_Test/ChallengeRewardTest/Challenge_Salvage_RewardTest.Challenge_Salvage_RewardTest_C";
+
+        assert_eq!(
+            bl3_save
+                .character_data
+                .incomplete_challenges(&[test_challenge]),
+            vec![test_challenge]
+        );
+    }
+
+    #[test]
+    fn test_metadata() {
+        let filename = Path::new("./test_files/19.sav");
+
+        let save_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        let metadata = bl3_save.metadata();
+
+        assert_eq!(metadata.save_game_version, bl3_save.save_game_version);
+        assert_eq!(metadata.package_version, bl3_save.package_version);
+        assert_eq!(
+            metadata.engine_version,
+            format!(
+                "{}.{}.{}.{}",
+                bl3_save.engine_major,
+                bl3_save.engine_minor,
+                bl3_save.engine_patch,
+                bl3_save.engine_build
+            )
+        );
+        assert_eq!(metadata.header_type, HeaderType::PcSave);
+        assert_eq!(metadata.file_size, save_file_data.len());
+    }
+
+    #[test]
+    fn test_set_last_visited_map() {
+        let filename = Path::new("./test_files/19.sav");
+
+        let save_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let mut bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        assert_eq!(
+            bl3_save.last_visited_map().map(|m| m.name),
+            Some("Sanctuary")
+        );
+
+        let atlas_hq = MapLocation::all()
+            .iter()
+            .find(|m| m.name == "Atlas HQ")
+            .copied()
+            .expect("failed to find Atlas HQ");
+
+        bl3_save
+            .set_last_visited_map(atlas_hq.travel_station)
+            .expect("failed to set last visited map");
+
+        assert!(bl3_save.set_last_visited_map("/Game/Maps/NotReal").is_err());
+
+        let (_, bl3_save) = bl3_save.as_bytes().expect("failed to write save");
+
+        assert_eq!(bl3_save.last_visited_map(), Some(atlas_hq));
+        assert_eq!(
+            bl3_save.character_data.playthroughs()[1].current_map.name,
+            "Atlas HQ"
+        );
+    }
+
+    #[test]
+    fn test_set_vehicle_loadout() {
+        let filename = Path::new("./test_files/19.sav");
+
+        let save_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let mut bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        let outrunner = bl3_save.character_data.vehicle_loadout(&Vehicle::Outrunner);
+
+        assert!(outrunner
+            .skin
+            .ends_with("CAR_Material_Outrunner_Psycho-mobile"));
+        assert!(outrunner.wheels.ends_with("CAR_Wheel_Outrunner_Hover"));
+
+        let skin = Vehicle::Outrunner
+            .skin_options()
+            .into_iter()
+            .find(|o| o.name == "Atlas")
+            .expect("failed to find skin");
+
+        let wheels = Vehicle::Outrunner
+            .wheel_options()
+            .into_iter()
+            .find(|o| o.name == "DuneBuggy")
+            .expect("failed to find wheels");
+
+        bl3_save.set_vehicle_loadout(&VehicleLoadout {
+            vehicle: Vehicle::Outrunner,
+            skin: skin.loadout_asset_path.clone(),
+            wheels: wheels.loadout_asset_path.clone(),
+        });
+
+        let mut cyclone = Vehicle::Cyclone.stock_loadout();
+        cyclone.skin = Vehicle::Cyclone.skin_options()[0]
+            .loadout_asset_path
+            .clone();
+
+        bl3_save.set_vehicle_loadout(&cyclone);
+
+        let (_, bl3_save) = bl3_save.as_bytes().expect("failed to write save");
+
+        let outrunner = bl3_save.character_data.vehicle_loadout(&Vehicle::Outrunner);
+
+        assert_eq!(outrunner.skin, skin.loadout_asset_path);
+        assert_eq!(outrunner.wheels, wheels.loadout_asset_path);
+        assert_eq!(
+            bl3_save.character_data.vehicle_loadout(&Vehicle::Cyclone),
+            cyclone
+        );
+        assert_eq!(bl3_save.character_data.character.vehicle_loadouts.len(), 4);
+
+        assert!(bl3_save
+            .character_data
+            .is_vehicle_asset_unlocked(skin.unlock_asset_path.as_ref().unwrap()));
+    }
+
+    #[test]
+    fn test_set_vehicle_loadout_missing() {
+        let filename = Path::new("./test_files/5.sav");
+
+        let save_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let mut bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        assert!(bl3_save
+            .character_data
+            .character
+            .vehicle_loadouts
+            .is_empty());
+        assert_eq!(
+            bl3_save.character_data.vehicle_loadout(&Vehicle::Technical),
+            Vehicle::Technical.stock_loadout()
+        );
+
+        let mut technical = Vehicle::Technical.stock_loadout();
+        technical.skin = Vehicle::Technical.skin_options()[1]
+            .loadout_asset_path
+            .clone();
+
+        bl3_save.set_vehicle_loadout(&technical);
+
+        let (_, bl3_save) = bl3_save.as_bytes().expect("failed to write save");
+
+        assert_eq!(
+            bl3_save.character_data.vehicle_loadout(&Vehicle::Technical),
+            technical
+        );
+        assert_eq!(bl3_save.character_data.character.vehicle_loadouts.len(), 1);
+    }
+
+    #[test]
+    fn test_unknown_ammo_pools_preserved() {
+        let filename = Path::new("./test_files/19.sav");
+
+        let save_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let mut bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        assert!(bl3_save.character_data.unknown_ammo_pools().is_empty());
+
+        let unknown_path = "/Game/PatchDLC/Test/Resource_Ammo_Test.Resource_Ammo_Test";
+
+        bl3_save
+            .character_data
+            .character
+            .resource_pools
+            .push(ResourcePoolSavegameData {
+                amount: 25.0,
+                resource_path: unknown_path.to_owned(),
+                ..Default::default()
+            });
+
+        let (_, mut bl3_save) = bl3_save.as_bytes().expect("failed to write save");
+
+        assert_eq!(
+            *bl3_save.character_data.unknown_ammo_pools(),
+            vec![UnknownAmmoPoolData {
+                resource_path: unknown_path.to_owned(),
+                current: 25,
+            }]
+        );
+
+        bl3_save
+            .character_data
+            .set_ammo_pool(&AmmoPool::Heavy, AmmoPool::Heavy.maximum())
+            .expect("failed to set ammo pool");
+
+        let (_, bl3_save) = bl3_save.as_bytes().expect("failed to write save");
+
+        assert_eq!(bl3_save.character_data.ammo_pools().len(), 7);
+        assert_eq!(bl3_save.character_data.unknown_ammo_pools().len(), 1);
+        assert_eq!(
+            bl3_save.character_data.unknown_ammo_pools()[0].resource_path,
+            unknown_path
+        );
+    }
+
+    #[test]
+    fn test_set_objective_progress() {
+        let filename = Path::new("./test_files/19.sav");
+
+        let save_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let mut bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        let playthrough = PlaythroughType::Normal;
+
+        let active_missions = bl3_save.character_data.active_missions(playthrough);
+
+        assert_eq!(
+            active_missions.len(),
+            bl3_save.character_data.playthroughs()[0]
+                .active_missions
+                .len()
+        );
+
+        let mission = active_missions
+            .iter()
+            .find(|m| !m.objectives.is_empty())
+            .expect("failed to find a mission with objectives");
+
+        let objective_index = mission.objectives.len() - 1;
+        let progress = mission.objectives[objective_index].progress + 1;
+
+        bl3_save
+            .set_objective_progress(playthrough, &mission.path, objective_index, progress)
+            .expect("failed to set objective progress");
+        bl3_save
+            .set_tracked_mission(playthrough, &mission.path)
+            .expect("failed to set tracked mission");
+
+        let (_, bl3_save) = bl3_save.as_bytes().expect("failed to write save");
+
+        let saved_mission = bl3_save
+            .character_data
+            .active_missions(playthrough)
+            .into_iter()
+            .find(|m| m.path == mission.path)
+            .expect("failed to find mission after saving");
+
+        assert_eq!(saved_mission.objectives[objective_index].progress, progress);
+        assert_eq!(
+            saved_mission.objectives[..objective_index],
+            mission.objectives[..objective_index]
+        );
+        assert_eq!(
+            bl3_save.character_data.tracked_mission(playthrough),
+            Some(mission.path.as_str())
+        );
+    }
+
+    #[test]
+    fn test_set_objective_progress_invalid() {
+        let filename = Path::new("./test_files/19.sav");
+
+        let save_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let mut bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        let original = bl3_save.clone();
+
+        let playthrough = PlaythroughType::Normal;
+
+        let mission = bl3_save
+            .character_data
+            .active_missions(playthrough)
+            .into_iter()
+            .find(|m| !m.objectives.is_empty())
+            .expect("failed to find a mission with objectives");
+
+        assert!(bl3_save
+            .set_objective_progress(playthrough, &mission.path, 0, -1)
+            .is_err());
+        assert!(bl3_save
+            .set_objective_progress(playthrough, &mission.path, mission.objectives.len(), 1)
+            .is_err());
+        assert!(bl3_save
+            .set_objective_progress(playthrough, "/Game/Missions/Unknown", 0, 1)
+            .is_err());
+        assert!(bl3_save
+            .set_tracked_mission(playthrough, "/Game/Missions/Unknown")
+            .is_err());
+
+        assert_eq!(bl3_save, original);
+    }
+
+    #[test]
+    fn test_set_last_travel_station() {
+        let filename = Path::new("./test_files/19.sav");
+
+        let save_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let mut bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        let unlocked_station = TravelStation::from_path(
+            bl3_save.character_data.playthroughs()[1].active_travel_stations[0].as_str(),
+        );
+
+        bl3_save
+            .character_data
+            .set_last_travel_station(1, &unlocked_station, false)
+            .expect("failed to set travel station");
+
+        let locked_station =
+            TravelStation::from_path("/Game/PatchDLC/NewStuff/FTS_NotReal.FTS_NotReal");
+
+        assert!(bl3_save
+            .character_data
+            .set_last_travel_station(0, &locked_station, false)
+            .is_err());
+
+        bl3_save
+            .character_data
+            .set_last_travel_station(0, &locked_station, true)
+            .expect("failed to unlock and set travel station");
+
+        assert!(bl3_save
+            .character_data
+            .set_last_travel_station(5, &unlocked_station, true)
+            .is_err());
+
+        let (_, bl3_save) = bl3_save.as_bytes().expect("failed to write save");
+
+        let playthroughs = bl3_save.character_data.playthroughs();
+
+        assert_eq!(
+            playthroughs[1].last_travel_station.as_ref(),
+            Some(&unlocked_station)
+        );
+        assert_eq!(
+            playthroughs[0].last_travel_station.as_ref(),
+            Some(&locked_station)
+        );
+        assert_eq!(
+            playthroughs[0]
+                .last_travel_station
+                .as_ref()
+                .map(|s| s.to_string()),
+            Some("/Game/PatchDLC/NewStuff/FTS_NotReal.FTS_NotReal".to_owned())
+        );
+        assert!(bl3_save
+            .character_data
+            .is_travel_station_unlocked(0, &locked_station));
+    }
+
+    #[test]
+    fn test_equip_best_items() {
+        let filename = Path::new("./test_files/19.sav");
+
+        let save_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let mut bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        let items = bl3_save.character_data.inventory_items().clone();
+
+        let equipped = bl3_save
+            .character_data
+            .equip_best_items(&items)
+            .expect("failed to equip best items");
+
+        assert_eq!(equipped, 8);
+
+        let weapon_indexes = [
+            InventorySlot::Weapon1,
+            InventorySlot::Weapon2,
+            InventorySlot::Weapon3,
+            InventorySlot::Weapon4,
+        ]
+        .iter()
+        .map(|s| {
+            bl3_save
+                .character_data
+                .equipped_inventory_index(s)
+                .expect("failed to read equipped weapon")
+        })
+        .collect::<Vec<_>>();
+
+        for (i, weapon_index) in weapon_indexes.iter().enumerate() {
+            assert_eq!(items[*weapon_index].item_type, ItemType::Weapon);
+            assert_eq!(items[*weapon_index].level(), 65);
+            assert!(!weapon_indexes[i + 1..].contains(weapon_index));
+        }
+
+        let shield_index = bl3_save
+            .character_data
+            .equipped_inventory_index(&InventorySlot::Shield)
+            .expect("failed to read equipped shield");
+
+        assert_eq!(items[shield_index].item_type, ItemType::Shield);
+
+        let class_mod_index = bl3_save
+            .character_data
+            .equipped_inventory_index(&InventorySlot::ClassMod)
+            .expect("failed to read equipped class mod");
+
+        assert_eq!(
+            PlayerClass::from_class_mod_balance(&items[class_mod_index].balance_part().ident),
+            Some(bl3_save.character_data.player_class())
+        );
+    }
+
+    #[test]
+    fn test_equipped_items() {
+        let filename = Path::new("./test_files/19.sav");
+
+        let save_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        let equipped_items = bl3_save.character_data.equipped_items();
+
+        assert!(!equipped_items.is_empty());
+        assert_eq!(equipped_items[0].0, InventorySlot::Weapon1);
+
+        for (slot, index, item) in equipped_items {
+            assert_eq!(
+                bl3_save.character_data.equipped_inventory_index(&slot),
+                Some(index)
+            );
+            assert_eq!(*item, bl3_save.character_data.inventory_items()[index]);
+            assert_eq!(item.item_type, slot.item_type());
+        }
+    }
+
+    #[test]
+    fn test_clear_inventory_items() {
+        let filename = Path::new("./test_files/19.sav");
+
+        let save_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let mut bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        let items = bl3_save.character_data.inventory_items().len();
+
+        assert!(items > 0);
+        assert_eq!(bl3_save.character_data.clear_inventory_items(), items);
+        assert!(bl3_save.character_data.inventory_items().is_empty());
+        assert!(bl3_save.character_data.equipped_items().is_empty());
+
+        let (output, _) = bl3_save.as_bytes().expect("failed to write save");
+
+        let read = Bl3Save::from_bytes(filename, &output, HeaderType::PcSave)
+            .expect("failed to read cleared save");
+
+        assert!(read.character_data.inventory_items().is_empty());
+    }
+
+    #[test]
+    fn test_remove_duplicate_inventory_items() {
+        let filename = Path::new("./test_files/19.sav");
+
+        let save_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let mut bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        bl3_save
+            .character_data
+            .remove_duplicate_inventory_items()
+            .expect("failed to remove duplicate items");
+
+        let items = bl3_save.character_data.inventory_items().clone();
+
+        let equipped_weapon_index = bl3_save
+            .character_data
+            .equipped_inventory_index(&InventorySlot::Weapon1)
+            .expect("failed to read equipped weapon");
+
+        let equipped_weapon = items[equipped_weapon_index].clone();
+
+        let duplicate = items
+            .iter()
+            .position(|i| *i != equipped_weapon)
+            .expect("failed to find an item to duplicate");
+
+        for _ in 0..2 {
+            bl3_save
+                .character_data
+                .add_inventory_item(0, &items[duplicate])
+                .expect("failed to add item");
+        }
+
+        // An equipped copy is kept even though an earlier item has the same serial
+        bl3_save
+            .character_data
+            .add_inventory_item(0, &equipped_weapon)
+            .expect("failed to add item");
+
+        bl3_save
+            .character_data
+            .equip_inventory_item(&InventorySlot::Weapon1, items.len() + 2)
+            .expect("failed to equip item");
+
+        let removed = bl3_save
+            .character_data
+            .remove_duplicate_inventory_items()
+            .expect("failed to remove duplicate items");
+
+        assert_eq!(removed, 2);
+        assert_eq!(
+            bl3_save.character_data.inventory_items().len(),
+            items.len() + 1
+        );
+
+        let equipped_weapon_index = bl3_save
+            .character_data
+            .equipped_inventory_index(&InventorySlot::Weapon1)
+            .expect("failed to read equipped weapon");
+
+        assert_eq!(equipped_weapon_index, items.len());
+        assert_eq!(
+            bl3_save.character_data.inventory_items()[equipped_weapon_index],
+            equipped_weapon
+        );
+    }
+
+    #[test]
+    fn test_set_inventory_from() {
+        let filename = Path::new("./test_files/19.sav");
+
+        let save_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let loaded = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        let mut bl3_save = loaded.clone();
+
+        let items = bl3_save.character_data.inventory_items().clone();
+
+        bl3_save
+            .character_data
+            .equip_best_items(&items)
+            .expect("failed to equip best items");
+
+        bl3_save.character_data.take_inventory_item(0);
+
+        bl3_save
+            .character_data
+            .set_inventory_from(&loaded.character_data);
+
+        assert_eq!(bl3_save, loaded);
+    }
 }
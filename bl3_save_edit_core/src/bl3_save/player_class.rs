@@ -37,4 +37,43 @@ impl PlayerClass {
         PlayerClass::Operative,
         PlayerClass::Siren,
     ];
+
+    /// The class a class mod is for, from its balance. DLC class mods use a short name for the
+    /// class in their path.
+    pub fn from_class_mod_balance(balance: &str) -> Option<PlayerClass> {
+        let balance = balance.to_lowercase();
+
+        PlayerClass::ALL.iter().copied().find(|class| {
+            let (name, short_name) = match class {
+                PlayerClass::BeastMaster => ("beastmaster", "/bsm/"),
+                PlayerClass::Gunner => ("gunner", "/gun/"),
+                PlayerClass::Operative => ("operative", "/ope/"),
+                PlayerClass::Siren => ("siren", "/srn/"),
+            };
+
+            balance.contains(name) || balance.contains(short_name)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bl3_save::player_class::PlayerClass;
+
+    #[test]
+    fn test_from_class_mod_balance() {
+        let gunner = "/Game/Gear/ClassMods/_Design/BalanceDefs/InvBalD_ClassMod_Gunner_05_Legendary.InvBalD_ClassMod_Gunner_05_Legendary";
+        let dlc_beastmaster = "/Game/PatchDLC/Ixora/Gear/ClassMods/_Design/BSM/L01/InvBalD_CM_Ixora_BSM_L01.InvBalD_CM_Ixora_BSM_L01";
+        let no_class = "/Game/Gear/ClassMods/_Design/BalanceDefs/InvBalD_ClassMod.InvBalD_ClassMod";
+
+        assert_eq!(
+            PlayerClass::from_class_mod_balance(gunner),
+            Some(PlayerClass::Gunner)
+        );
+        assert_eq!(
+            PlayerClass::from_class_mod_balance(dlc_beastmaster),
+            Some(PlayerClass::BeastMaster)
+        );
+        assert_eq!(PlayerClass::from_class_mod_balance(no_class), None);
+    }
 }
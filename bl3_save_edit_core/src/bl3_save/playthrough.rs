@@ -1,16 +1,46 @@
 use anyhow::Result;
 use rayon::iter::{IndexedParallelIterator, IntoParallelRefIterator, ParallelIterator};
 use rayon::slice::ParallelSliceMut;
+use strum::Display;
 
+use crate::bl3_save::travel_station::TravelStation;
 use crate::bl3_save::util::{get_filtered_mission_list, IMPORTANT_MISSIONS};
 use crate::game_data::{GameDataKv, FAST_TRAVEL, MISSION};
 use crate::protos::oak_save::{Character, MissionStatusPlayerSaveGameData_MissionState};
 
+#[derive(Debug, Copy, Clone, Display, Eq, PartialEq, Ord, PartialOrd, Default)]
+pub enum PlaythroughType {
+    #[default]
+    #[strum(to_string = "Normal")]
+    Normal,
+    #[strum(to_string = "True Vault Hunter Mode")]
+    TrueVaultHunterMode,
+}
+
+impl PlaythroughType {
+    pub const ALL: [PlaythroughType; 2] = [
+        PlaythroughType::Normal,
+        PlaythroughType::TrueVaultHunterMode,
+    ];
+
+    pub fn from_index(index: usize) -> Option<Self> {
+        PlaythroughType::ALL.get(index).copied()
+    }
+
+    pub fn index(&self) -> usize {
+        match self {
+            PlaythroughType::Normal => 0,
+            PlaythroughType::TrueVaultHunterMode => 1,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
 pub struct Playthrough {
     pub mayhem_level: i32,
     pub mayhem_random_seed: i32,
     pub current_map: GameDataKv,
+    pub last_travel_station: Option<TravelStation>,
     pub active_missions: Vec<String>,
     pub missions_completed: Vec<String>,
     pub mission_milestones: Vec<String>,
@@ -37,6 +67,11 @@ impl Playthrough {
                     })
                     .unwrap_or(FAST_TRAVEL[0]);
 
+                let last_travel_station = character
+                    .last_active_travel_station_for_playthrough
+                    .get(i)
+                    .map(|m| TravelStation::from_path(m));
+
                 let mission_playthrough_data = character
                     .mission_playthroughs_data
                     .get(i)
@@ -78,6 +113,7 @@ impl Playthrough {
                     mayhem_level,
                     mayhem_random_seed,
                     current_map,
+                    last_travel_station,
                     active_missions,
                     missions_completed,
                     mission_milestones,
@@ -0,0 +1,513 @@
+use std::collections::BTreeMap;
+
+use anyhow::{bail, Context, Result};
+use strum::IntoEnumIterator;
+
+use crate::bl3_item::Bl3Item;
+use crate::bl3_save::ammo::AmmoPool;
+use crate::bl3_save::player_class::PlayerClass;
+use crate::bl3_save::sdu::SaveSduSlot;
+use crate::bl3_save::Bl3Save;
+
+pub const SAVE_DIFF_VERSION: u32 = 1;
+
+const CHARACTER_NAME: &str = "character_name";
+const PLAYER_CLASS: &str = "player_class";
+const EXPERIENCE_POINTS: &str = "experience_points";
+const ABILITY_POINTS: &str = "ability_points";
+const GUARDIAN_RANK: &str = "guardian_rank";
+const MONEY: &str = "money";
+const ERIDIUM: &str = "eridium";
+const MAYHEM_LEVEL: &str = "mayhem_level";
+const ADDED_ITEMS: &str = "added_items";
+const SDU_PREFIX: &str = "sdu.";
+const AMMO_PREFIX: &str = "ammo.";
+
+/// The fields of a save that changed, mapped to their new value. Only the new values are kept so
+/// a diff can be shared as a patch and applied to any save, not just the one it was made from.
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct SaveDiff {
+    pub fields: BTreeMap<String, SaveDiffValue>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum SaveDiffValue {
+    Number(i32),
+    Text(String),
+    /// Base64 item serials.
+    Items(Vec<String>),
+}
+
+impl SaveDiffValue {
+    fn as_number(&self) -> Result<i32> {
+        match self {
+            SaveDiffValue::Number(n) => Ok(*n),
+            _ => bail!("expected a number"),
+        }
+    }
+
+    fn as_text(&self) -> Result<&str> {
+        match self {
+            SaveDiffValue::Text(t) => Ok(t),
+            _ => bail!("expected text"),
+        }
+    }
+
+    fn as_items(&self) -> Result<&[String]> {
+        match self {
+            SaveDiffValue::Items(i) => Ok(i),
+            _ => bail!("expected a list of item serials"),
+        }
+    }
+
+    fn to_json(&self) -> json::JsonValue {
+        match self {
+            SaveDiffValue::Number(n) => (*n).into(),
+            SaveDiffValue::Text(t) => t.as_str().into(),
+            SaveDiffValue::Items(i) => i.clone().into(),
+        }
+    }
+
+    fn from_json(value: &json::JsonValue) -> Result<Self> {
+        if let Some(t) = value.as_str() {
+            Ok(SaveDiffValue::Text(t.to_owned()))
+        } else if value.is_number() {
+            value
+                .as_i32()
+                .map(SaveDiffValue::Number)
+                .context("expected a whole number")
+        } else if value.is_array() {
+            value
+                .members()
+                .map(|i| {
+                    i.as_str()
+                        .map(str::to_owned)
+                        .context("expected an item serial")
+                })
+                .collect::<Result<Vec<_>>>()
+                .map(SaveDiffValue::Items)
+        } else {
+            bail!("unsupported value: {}", value)
+        }
+    }
+}
+
+fn sdu_field(sdu_slot: &SaveSduSlot) -> String {
+    format!("{}{}", SDU_PREFIX, sdu_slot)
+}
+
+fn ammo_field(ammo_pool: &AmmoPool) -> String {
+    format!("{}{}", AMMO_PREFIX, ammo_pool)
+}
+
+/// Items in `after` that aren't in `before`, each item in `before` only matches once.
+///
+/// Items are matched by serial, flags such as favorite or junk aren't part of the item.
+fn added_item_serials(before: &[Bl3Item], after: &[Bl3Item]) -> Result<Vec<String>> {
+    let mut unmatched = before
+        .iter()
+        .map(|item| item.get_serial_number(false))
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut added = Vec::new();
+
+    for item in after {
+        let serial = item.get_serial_number(false)?;
+
+        match unmatched.iter().position(|s| *s == serial) {
+            Some(i) => {
+                unmatched.swap_remove(i);
+            }
+            None => added.push(item.get_serial_number_base64(false)?),
+        }
+    }
+
+    Ok(added)
+}
+
+// The class and level reset the skill tree, so they go before anything that depends on them
+fn apply_order(name: &str) -> usize {
+    match name {
+        PLAYER_CLASS => 0,
+        EXPERIENCE_POINTS => 1,
+        ABILITY_POINTS => 2,
+        _ => 3,
+    }
+}
+
+fn apply_field(save: &mut Bl3Save, name: &str, value: &SaveDiffValue) -> Result<()> {
+    match name {
+        CHARACTER_NAME => save.set_character_name(value.as_text()?),
+        PLAYER_CLASS => {
+            let class_name = value.as_text()?;
+
+            let player_class = PlayerClass::ALL
+                .iter()
+                .find(|c| c.to_string() == class_name)
+                .copied()
+                .with_context(|| format!("unknown class: {}", class_name))?;
+
+            save.character_data.set_player_class(player_class)?;
+        }
+        EXPERIENCE_POINTS => save.set_experience_points(value.as_number()?)?,
+        ABILITY_POINTS => save.character_data.set_ability_points(value.as_number()?)?,
+        GUARDIAN_RANK => save.set_guardian_rank(value.as_number()?, None),
+        MONEY => save.set_money(value.as_number()?)?,
+        ERIDIUM => save.set_eridium(value.as_number()?)?,
+        MAYHEM_LEVEL => save.set_mayhem_level(
+            u32::try_from(value.as_number()?).context("mayhem level can't be negative")?,
+        )?,
+        ADDED_ITEMS => {
+            for serial in value.as_items()? {
+                let item = Bl3Item::from_serial_base64(serial)
+                    .with_context(|| format!("failed to read item: {}", serial))?;
+
+                save.add_inventory_item(&item)?;
+            }
+        }
+        _ => {
+            if let Some(sdu_name) = name.strip_prefix(SDU_PREFIX) {
+                let sdu_slot = SaveSduSlot::iter()
+                    .find(|s| s.to_string() == sdu_name)
+                    .with_context(|| format!("unknown SDU: {}", sdu_name))?;
+
+                save.set_sdu_slot(&sdu_slot, value.as_number()?);
+            } else if let Some(pool_name) = name.strip_prefix(AMMO_PREFIX) {
+                let ammo_pool = save
+                    .character_data
+                    .ammo_pools()
+                    .iter()
+                    .map(|a| a.pool.clone())
+                    .find(|p| p.to_string() == pool_name)
+                    .with_context(|| format!("save has no ammo pool: {}", pool_name))?;
+
+                save.set_ammo_pool(&ammo_pool, value.as_number()?)?;
+            } else {
+                bail!("unknown field")
+            }
+        }
+    }
+
+    Ok(())
+}
+
+impl SaveDiff {
+    /// The fields that differ between `baseline` and `save`, with their values in `save`.
+    pub fn between(baseline: &Bl3Save, save: &Bl3Save) -> Result<Self> {
+        let (before, after) = (&baseline.character_data, &save.character_data);
+
+        let mut fields = BTreeMap::new();
+
+        let mut push = |name: String, before: SaveDiffValue, after: SaveDiffValue| {
+            if before != after {
+                fields.insert(name, after);
+            }
+        };
+
+        push(
+            CHARACTER_NAME.to_owned(),
+            SaveDiffValue::Text(before.character.preferred_character_name.clone()),
+            SaveDiffValue::Text(after.character.preferred_character_name.clone()),
+        );
+        push(
+            PLAYER_CLASS.to_owned(),
+            SaveDiffValue::Text(before.player_class().to_string()),
+            SaveDiffValue::Text(after.player_class().to_string()),
+        );
+        push(
+            EXPERIENCE_POINTS.to_owned(),
+            SaveDiffValue::Number(before.character.experience_points),
+            SaveDiffValue::Number(after.character.experience_points),
+        );
+        push(
+            ABILITY_POINTS.to_owned(),
+            SaveDiffValue::Number(before.ability_points()),
+            SaveDiffValue::Number(after.ability_points()),
+        );
+        push(
+            GUARDIAN_RANK.to_owned(),
+            SaveDiffValue::Number(before.guardian_rank()),
+            SaveDiffValue::Number(after.guardian_rank()),
+        );
+        push(
+            MONEY.to_owned(),
+            SaveDiffValue::Number(before.money()),
+            SaveDiffValue::Number(after.money()),
+        );
+        push(
+            ERIDIUM.to_owned(),
+            SaveDiffValue::Number(before.eridium()),
+            SaveDiffValue::Number(after.eridium()),
+        );
+        push(
+            MAYHEM_LEVEL.to_owned(),
+            SaveDiffValue::Number(before.mayhem_level() as i32),
+            SaveDiffValue::Number(after.mayhem_level() as i32),
+        );
+
+        for a in after.sdu_slots() {
+            let current = before
+                .sdu_slots()
+                .iter()
+                .find(|b| b.sdu == a.sdu)
+                .map(|b| b.current)
+                .unwrap_or(0);
+
+            push(
+                sdu_field(&a.sdu),
+                SaveDiffValue::Number(current),
+                SaveDiffValue::Number(a.current),
+            );
+        }
+
+        for a in after.ammo_pools() {
+            let current = before
+                .ammo_pools()
+                .iter()
+                .find(|b| b.pool == a.pool)
+                .map(|b| b.current)
+                .unwrap_or(0);
+
+            push(
+                ammo_field(&a.pool),
+                SaveDiffValue::Number(current),
+                SaveDiffValue::Number(a.current),
+            );
+        }
+
+        let added_items = added_item_serials(before.inventory_items(), after.inventory_items())?;
+
+        if !added_items.is_empty() {
+            fields.insert(ADDED_ITEMS.to_owned(), SaveDiffValue::Items(added_items));
+        }
+
+        Ok(SaveDiff { fields })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// Applies every field to `save`. Fails without changing anything if any field can't be
+    /// applied.
+    pub fn apply(&self, save: &mut Bl3Save) -> Result<()> {
+        let mut fields = self.fields.iter().collect::<Vec<_>>();
+
+        fields.sort_by_key(|(name, _)| apply_order(name));
+
+        let mut patched = save.clone();
+
+        for (name, value) in fields {
+            apply_field(&mut patched, name, value)
+                .with_context(|| format!("failed to apply {}", name))?;
+        }
+
+        *save = patched;
+
+        Ok(())
+    }
+
+    pub fn to_json(&self) -> String {
+        let mut fields = json::JsonValue::new_object();
+
+        for (name, value) in &self.fields {
+            fields[name.as_str()] = value.to_json();
+        }
+
+        json::object! {
+            version: SAVE_DIFF_VERSION,
+            fields: fields,
+        }
+        .pretty(4)
+    }
+
+    pub fn from_json(data: &str) -> Result<Self> {
+        let data = json::parse(data).context("failed to parse patch file")?;
+
+        let version = data["version"]
+            .as_u32()
+            .context("patch file was missing a version")?;
+
+        if version > SAVE_DIFF_VERSION {
+            bail!(
+                "patch file version {} is newer than this editor supports ({})",
+                version,
+                SAVE_DIFF_VERSION
+            );
+        }
+
+        if !data["fields"].is_object() {
+            bail!("patch file was missing its fields");
+        }
+
+        let fields = data["fields"]
+            .entries()
+            .map(|(name, value)| {
+                SaveDiffValue::from_json(value)
+                    .map(|value| (name.to_owned(), value))
+                    .with_context(|| format!("patch file has an invalid value for {}", name))
+            })
+            .collect::<Result<BTreeMap<_, _>>>()?;
+
+        Ok(SaveDiff { fields })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crate::parser::HeaderType;
+
+    use super::*;
+
+    fn load_save() -> Bl3Save {
+        Bl3Save::from_bytes(
+            Path::new("./test_files/19.sav"),
+            include_bytes!("../../test_files/19.sav"),
+            HeaderType::PcSave,
+        )
+        .expect("failed to read test save")
+    }
+
+    // A value that is always a change from `current`
+    fn changed_from(current: i32, value: i32) -> i32 {
+        if current == value {
+            value - 1
+        } else {
+            value
+        }
+    }
+
+    fn edited_save(baseline: &Bl3Save) -> Bl3Save {
+        let mut save = baseline.clone();
+
+        let backpack = baseline
+            .character_data
+            .sdu_slots()
+            .iter()
+            .find(|s| s.sdu == SaveSduSlot::Backpack)
+            .map(|s| s.current)
+            .unwrap_or(0);
+
+        let grenades = baseline
+            .character_data
+            .ammo_pools()
+            .iter()
+            .find(|a| a.pool == AmmoPool::Grenade)
+            .map(|a| a.current)
+            .unwrap_or(0);
+
+        save.set_character_name("Patched");
+        save.set_money(changed_from(baseline.character_data.money(), 1_000_000))
+            .unwrap();
+        save.set_sdu_slot(
+            &SaveSduSlot::Backpack,
+            changed_from(backpack, SaveSduSlot::Backpack.maximum()),
+        );
+        save.set_ammo_pool(
+            &AmmoPool::Grenade,
+            changed_from(grenades, AmmoPool::Grenade.maximum()),
+        )
+        .unwrap();
+        save.add_inventory_item(&baseline.character_data.inventory_items()[0])
+            .unwrap();
+
+        save
+    }
+
+    #[test]
+    fn test_diff_from_same_save_is_empty() {
+        let save = load_save();
+
+        assert!(save.diff_from(&save).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_diff_from_only_has_changed_fields() {
+        let baseline = load_save();
+        let save = edited_save(&baseline);
+
+        let diff = save.diff_from(&baseline).unwrap();
+
+        assert_eq!(
+            diff.fields.keys().map(String::as_str).collect::<Vec<_>>(),
+            vec![
+                ADDED_ITEMS,
+                "ammo.Grenade",
+                CHARACTER_NAME,
+                MONEY,
+                "sdu.Backpack"
+            ]
+        );
+        assert_eq!(
+            diff.fields[MONEY],
+            SaveDiffValue::Number(save.character_data.money())
+        );
+        assert_eq!(
+            diff.fields[ADDED_ITEMS],
+            SaveDiffValue::Items(vec![baseline.character_data.inventory_items()[0]
+                .get_serial_number_base64(false)
+                .unwrap()])
+        );
+    }
+
+    #[test]
+    fn test_apply_diff() {
+        let baseline = load_save();
+        let save = edited_save(&baseline);
+
+        let diff = save.diff_from(&baseline).unwrap();
+
+        let mut patched = baseline.clone();
+        patched.apply_diff(&diff).expect("failed to apply diff");
+
+        assert!(patched.diff_from(&save).unwrap().is_empty());
+        assert_eq!(
+            patched.character_data.inventory_items().len(),
+            baseline.character_data.inventory_items().len() + 1
+        );
+    }
+
+    #[test]
+    fn test_apply_diff_changes_nothing_on_failure() {
+        let baseline = load_save();
+
+        let mut diff = SaveDiff::default();
+        diff.fields
+            .insert(MONEY.to_owned(), SaveDiffValue::Number(1_000_000));
+        diff.fields
+            .insert(ERIDIUM.to_owned(), SaveDiffValue::Text("lots".to_owned()));
+
+        let mut save = baseline.clone();
+
+        let err = save.apply_diff(&diff).unwrap_err();
+
+        assert!(err.to_string().contains(ERIDIUM));
+        assert_eq!(save, baseline);
+
+        let mut diff = SaveDiff::default();
+        diff.fields
+            .insert("unknown".to_owned(), SaveDiffValue::Number(1));
+
+        assert!(save.apply_diff(&diff).is_err());
+    }
+
+    #[test]
+    fn test_save_diff_json_round_trip() {
+        let baseline = load_save();
+        let diff = edited_save(&baseline).diff_from(&baseline).unwrap();
+
+        let imported = SaveDiff::from_json(&diff.to_json()).expect("failed to import diff");
+
+        assert_eq!(imported, diff);
+    }
+
+    #[test]
+    fn test_save_diff_from_invalid_json() {
+        assert!(SaveDiff::from_json("not json").is_err());
+        assert!(SaveDiff::from_json(r#"{"version": 1}"#).is_err());
+        assert!(SaveDiff::from_json(r#"{"version": 99, "fields": {}}"#).is_err());
+        assert!(SaveDiff::from_json(r#"{"version": 1, "fields": {"money": null}}"#).is_err());
+    }
+}
@@ -0,0 +1,191 @@
+use std::collections::BTreeSet;
+
+use crate::protos::oak_save::OakAbilityTreeItemSaveGameData;
+
+/// A skill that can have points spent in it. The save only stores asset paths, so skills are
+/// placed by the tier and slot named in their path rather than by their in-game name.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub struct SkillTreeItem {
+    pub tree: i32,
+    pub tier: usize,
+    pub slot: usize,
+    pub tree_name: String,
+    pub path: String,
+    pub points: i32,
+    pub max_points: i32,
+}
+
+impl SkillTreeItem {
+    pub fn name(&self) -> String {
+        format!("Tier {} Skill {}", self.tier + 1, self.slot + 1)
+    }
+}
+
+// Unreal names the first object "Name", then "Name_0", "Name_1" and so on.
+fn split_object_index(object_name: &str) -> (&str, usize) {
+    match object_name.rsplit_once('_') {
+        Some((name, index)) if index.chars().all(|c| c.is_ascii_digit()) && !index.is_empty() => {
+            (name, index.parse::<usize>().map(|i| i + 1).unwrap_or(0))
+        }
+        _ => (object_name, 0),
+    }
+}
+
+fn tree_name(path: &str) -> String {
+    let asset_name = path
+        .rsplit('/')
+        .next()
+        .and_then(|p| p.split('.').next())
+        .unwrap_or(path);
+
+    let short_name = asset_name.rsplit('_').next().unwrap_or(asset_name);
+
+    short_name
+        .chars()
+        .enumerate()
+        .fold(String::new(), |mut name, (i, c)| {
+            if i > 0 && c.is_ascii_uppercase() {
+                name.push(' ');
+            }
+
+            name.push(c);
+
+            name
+        })
+}
+
+fn tier_and_slot_names(path: &str) -> Option<(&str, &str)> {
+    let (_, objects) = path.split_once(':')?;
+
+    objects.split_once('.')
+}
+
+pub fn skill_tree_items(tree_items: &[OakAbilityTreeItemSaveGameData]) -> Vec<SkillTreeItem> {
+    let skills = tree_items
+        .iter()
+        .filter(|ti| ti.max_points > 0)
+        .filter_map(|ti| {
+            let (tier_name, slot_name) = tier_and_slot_names(&ti.item_asset_path)?;
+
+            Some((
+                ti,
+                split_object_index(tier_name),
+                split_object_index(slot_name).1,
+            ))
+        })
+        .collect::<Vec<_>>();
+
+    let mut items = skills
+        .iter()
+        .map(|(ti, tier_key, slot)| {
+            let tree_tiers = skills
+                .iter()
+                .filter(|(other, _, _)| other.tree_identifier == ti.tree_identifier)
+                .map(|(_, tier_key, _)| *tier_key)
+                .collect::<BTreeSet<_>>();
+
+            SkillTreeItem {
+                tree: ti.tree_identifier,
+                tier: tree_tiers.range(..*tier_key).count(),
+                slot: *slot,
+                tree_name: tree_name(&ti.item_asset_path),
+                path: ti.item_asset_path.clone(),
+                points: ti.points,
+                max_points: ti.max_points,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    items.sort();
+
+    items
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crate::bl3_save::Bl3Save;
+    use crate::parser::HeaderType;
+
+    use super::*;
+
+    fn load_save() -> Bl3Save {
+        Bl3Save::from_bytes(
+            Path::new("./test_files/19.sav"),
+            include_bytes!("../../test_files/19.sav"),
+            HeaderType::PcSave,
+        )
+        .expect("failed to read test save")
+    }
+
+    #[test]
+    fn test_split_object_index() {
+        assert_eq!(split_object_index("Ability"), ("Ability", 0));
+        assert_eq!(split_object_index("Ability_0"), ("Ability", 1));
+        assert_eq!(split_object_index("Ability_12"), ("Ability", 13));
+        assert_eq!(split_object_index("Ability_"), ("Ability_", 0));
+    }
+
+    #[test]
+    fn test_tree_name() {
+        assert_eq!(
+            tree_name("/Game/PlayerCharacters/SirenBrawler/_Shared/_Design/Character/Inventory/AbilityTree_Siren_MysticalAssault.AbilityTree_Siren_MysticalAssault:Tiers_OakPlayerAbilityTreeTierData_0.Items_OakPlayerAbilityTreeItemData_Ability_1"),
+            "Mystical Assault"
+        );
+    }
+
+    #[test]
+    fn test_skill_tree_items() {
+        let save = load_save();
+
+        let items = save.character_data.skill_tree_items();
+
+        assert!(!items.is_empty());
+        assert!(items.iter().all(|i| i.max_points > 0));
+        assert_eq!(
+            items.iter().map(|i| i.points).sum::<i32>(),
+            save.character_data.spent_ability_points()
+        );
+
+        let mystical_assault = items.iter().filter(|i| i.tree == 0).collect::<Vec<_>>();
+
+        assert!(mystical_assault
+            .iter()
+            .all(|i| i.tree_name == "Mystical Assault"));
+        assert_eq!(mystical_assault[0].tier, 0);
+        assert_eq!(mystical_assault[0].slot, 0);
+        assert_eq!(mystical_assault[0].name(), "Tier 1 Skill 1");
+    }
+
+    #[test]
+    fn test_set_skill_tree_item_points() {
+        let mut save = load_save();
+
+        let item = save.character_data.skill_tree_items()[0].clone();
+
+        save.character_data
+            .set_skill_tree_item_points(&item.path, item.max_points + 10)
+            .expect("failed to set skill points");
+
+        let updated = save
+            .character_data
+            .skill_tree_items()
+            .into_iter()
+            .find(|i| i.path == item.path)
+            .unwrap();
+
+        assert_eq!(updated.points, item.max_points);
+
+        save.character_data
+            .set_skill_tree_item_points(&item.path, -1)
+            .expect("failed to set skill points");
+
+        assert_eq!(save.character_data.skill_tree_items()[0].points, 0);
+
+        assert!(save
+            .character_data
+            .set_skill_tree_item_points("/Game/Not/A/Skill", 1)
+            .is_err());
+    }
+}
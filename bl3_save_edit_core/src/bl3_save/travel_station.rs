@@ -0,0 +1,169 @@
+use std::fmt::Formatter;
+
+use once_cell::sync::Lazy;
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+
+use crate::game_data::{GameDataKv, FAST_TRAVEL};
+
+// The save only stores hashed map ids, so each map is represented by one of its travel stations
+static MAP_LOCATIONS: Lazy<Vec<MapLocation>> = Lazy::new(|| {
+    let mut maps = FAST_TRAVEL
+        .iter()
+        .filter(|ft| ft.ident.contains("/fts_") && !ft.ident.ends_with("_sendonly"))
+        .map(|ft| MapLocation {
+            name: ft.name,
+            travel_station: ft.ident,
+        })
+        .collect::<Vec<_>>();
+
+    maps.sort();
+    maps.dedup_by(|a, b| a.name == b.name);
+
+    maps
+});
+
+#[derive(Debug, Clone, Default, Eq, PartialEq, Ord, PartialOrd)]
+pub struct TravelStation {
+    pub path: String,
+    pub name: Option<&'static str>,
+}
+
+impl TravelStation {
+    pub fn from_path(path: &str) -> Self {
+        let lower_path = path.to_lowercase();
+
+        let name = FAST_TRAVEL
+            .par_iter()
+            .find_first(|ft| ft.ident == lower_path)
+            .map(|ft| ft.name);
+
+        Self {
+            path: path.to_owned(),
+            name,
+        }
+    }
+
+    // Send only stations can be travelled from but never spawned at
+    pub fn all_spawnable() -> Vec<TravelStation> {
+        let mut stations = FAST_TRAVEL
+            .iter()
+            .filter(|ft| !ft.ident.ends_with("_sendonly"))
+            .map(TravelStation::from)
+            .collect::<Vec<_>>();
+
+        stations.sort_by(|a, b| a.name.cmp(&b.name).then_with(|| a.path.cmp(&b.path)));
+
+        stations
+    }
+
+    pub fn asset_name(&self) -> &str {
+        self.path.rsplit('.').next().unwrap_or(&self.path)
+    }
+
+    pub fn is_same_station(&self, path: &str) -> bool {
+        self.path.eq_ignore_ascii_case(path)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Ord, PartialOrd)]
+pub struct MapLocation {
+    pub name: &'static str,
+    pub travel_station: &'static str,
+}
+
+impl MapLocation {
+    pub fn all() -> &'static [MapLocation] {
+        &MAP_LOCATIONS
+    }
+
+    pub fn from_path(map_path: &str) -> Option<MapLocation> {
+        MAP_LOCATIONS
+            .iter()
+            .find(|m| m.travel_station.eq_ignore_ascii_case(map_path))
+            .copied()
+    }
+
+    /// The map a travel station is on, None if the station isn't in our game data.
+    pub fn from_travel_station(travel_station: &TravelStation) -> Option<MapLocation> {
+        let name = travel_station.name?;
+
+        MAP_LOCATIONS.iter().find(|m| m.name == name).copied()
+    }
+}
+
+impl std::fmt::Display for MapLocation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+impl std::convert::From<&GameDataKv> for TravelStation {
+    fn from(kv: &GameDataKv) -> Self {
+        Self {
+            path: kv.ident.to_owned(),
+            name: Some(kv.name),
+        }
+    }
+}
+
+impl std::fmt::Display for TravelStation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.name {
+            Some(name) => write!(f, "{} ({})", name, self.asset_name()),
+            None => write!(f, "{}", self.path),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_path() {
+        let station =
+            TravelStation::from_path("/Game/GameData/FastTravel/FTS_Sanctuary.FTS_Sanctuary");
+
+        assert_eq!(station.name, Some("Sanctuary"));
+        assert_eq!(station.to_string(), "Sanctuary (FTS_Sanctuary)");
+
+        let unknown_path = "/Game/PatchDLC/NewStuff/FTS_NotReal.FTS_NotReal";
+
+        let unknown_station = TravelStation::from_path(unknown_path);
+
+        assert_eq!(unknown_station.name, None);
+        assert_eq!(unknown_station.to_string(), unknown_path);
+    }
+
+    #[test]
+    fn test_map_locations() {
+        let maps = MapLocation::all();
+
+        assert!(!maps.is_empty());
+
+        let sanctuary = maps
+            .iter()
+            .find(|m| m.name == "Sanctuary")
+            .expect("failed to find Sanctuary");
+
+        assert_eq!(
+            sanctuary.travel_station,
+            "/game/gamedata/fasttravel/fts_sanctuary.fts_sanctuary"
+        );
+        assert_eq!(maps.iter().filter(|m| m.name == "Sanctuary").count(), 1);
+
+        assert_eq!(
+            MapLocation::from_path("/Game/GameData/FastTravel/FTS_Sanctuary.FTS_Sanctuary"),
+            Some(*sanctuary)
+        );
+        assert_eq!(MapLocation::from_path("/Game/Maps/NotReal"), None);
+    }
+
+    #[test]
+    fn test_all_spawnable() {
+        let stations = TravelStation::all_spawnable();
+
+        assert!(!stations.is_empty());
+        assert!(stations.iter().all(|s| !s.path.ends_with("_sendonly")));
+    }
+}
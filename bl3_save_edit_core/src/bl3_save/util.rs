@@ -1,12 +1,21 @@
 use anyhow::{Context, Result};
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 
+use crate::bl3_save::character_data::MAX_CHARACTER_LEVEL;
 use crate::bl3_save::models::Currency;
 use crate::game_data::GameDataKv;
 use crate::protos::oak_save::{
     Character, MissionPlaythroughSaveGameData, MissionStatusPlayerSaveGameData_MissionState,
 };
 
+pub const MAX_MAYHEM_LEVEL: u32 = 11;
+
+// Where a new character starts, taken from a fresh save
+pub const FIRST_MISSION: &str =
+    "/Game/Missions/Plot/Mission_Ep01_ChildrenOfTheVault.Mission_Ep01_ChildrenOfTheVault_C";
+
+pub const STARTING_TRAVEL_STATION: &str = "/Game/GameData/FastTravel/ResurrectTravelStations/AutoGen/Recruitment/RTS_AUTOGEN_ResurrectTravelStationObject_CoVCamp.RTS_AUTOGEN_ResurrectTravelStationObject_CoVCamp";
+
 pub const REQUIRED_XP_LIST: [[i32; 2]; 80] = [
     [0, 1],
     [358, 2],
@@ -120,6 +129,8 @@ pub fn currency_amount_from_character(character: &Character, currency: &Currency
         .unwrap_or(0)
 }
 
+// Saves only keep the current experience and time played, there's no experience history or
+// mission completion times to work out when each level was reached
 pub fn experience_to_level(experience: i32) -> Result<i32> {
     REQUIRED_XP_LIST
         .iter()
@@ -134,6 +145,72 @@ pub fn experience_to_level(experience: i32) -> Result<i32> {
         })
 }
 
+pub fn clamp_level(level: i32) -> i32 {
+    level.clamp(1, MAX_CHARACTER_LEVEL as i32)
+}
+
+/// Experience needed for `level`, clamped between 1 and the max level.
+/// Returns None if the level is missing from REQUIRED_XP_LIST.
+pub fn level_to_xp(level: i32) -> Option<i32> {
+    let level = clamp_level(level);
+
+    REQUIRED_XP_LIST
+        .iter()
+        .find(|[_, l]| *l == level)
+        .map(|[xp, _]| *xp)
+}
+
+pub fn ability_points_for_level(level: i32) -> i32 {
+    (level - 2).max(0)
+}
+
+const ZERO_WIDTH_JOINER: char = '\u{200D}';
+
+// Pictographic blocks, skin tones, flags, the emoji variation selector and the keycap mark
+fn is_emoji(c: char) -> bool {
+    matches!(
+        c,
+        '\u{1F000}'..='\u{1FAFF}'
+            | '\u{2600}'..='\u{27BF}'
+            | '\u{2B50}'
+            | '\u{2B55}'
+            | '\u{FE0F}'
+            | '\u{20E3}'
+    )
+}
+
+/// The characters of `name` that can't be shown in game and are removed when saving: control
+/// characters and emoji. A joiner is only removed along with the emoji it joins, other scripts
+/// need it to shape their letters.
+pub fn unsupported_name_chars(name: &str) -> Vec<char> {
+    partition_character_name(name).1
+}
+
+/// `name` without the characters from [`unsupported_name_chars`].
+pub fn sanitize_character_name(name: &str) -> String {
+    partition_character_name(name).0
+}
+
+fn partition_character_name(name: &str) -> (String, Vec<char>) {
+    let mut kept = String::with_capacity(name.len());
+    let mut removed = Vec::new();
+    let mut previous_removed = false;
+
+    for c in name.chars() {
+        let remove = c.is_control() || is_emoji(c) || (c == ZERO_WIDTH_JOINER && previous_removed);
+
+        if remove {
+            removed.push(c);
+        } else {
+            kept.push(c);
+        }
+
+        previous_removed = remove;
+    }
+
+    (kept, removed)
+}
+
 pub fn get_filtered_mission_list<const LENGTH: usize>(
     all_missions: [GameDataKv; LENGTH],
     m: &MissionPlaythroughSaveGameData,
@@ -151,3 +228,86 @@ pub fn get_filtered_mission_list<const LENGTH: usize>(
         })
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::bl3_save::character_data::MAX_CHARACTER_LEVEL;
+    use crate::bl3_save::util::{
+        ability_points_for_level, level_to_xp, sanitize_character_name, unsupported_name_chars,
+    };
+
+    #[test]
+    fn test_ability_points_for_level() {
+        assert_eq!(ability_points_for_level(0), 0);
+        assert_eq!(ability_points_for_level(1), 0);
+        assert_eq!(ability_points_for_level(2), 0);
+        assert_eq!(ability_points_for_level(3), 1);
+        assert_eq!(ability_points_for_level(72), 70);
+    }
+
+    #[test]
+    fn test_level_to_xp() {
+        let max_level = MAX_CHARACTER_LEVEL as i32;
+
+        assert_eq!(level_to_xp(0), Some(0));
+        assert_eq!(level_to_xp(1), Some(0));
+        assert_eq!(level_to_xp(2), Some(358));
+        assert_eq!(level_to_xp(max_level), Some(9520932));
+        assert_eq!(level_to_xp(max_level + 1), level_to_xp(max_level));
+        assert_eq!(level_to_xp(i32::MAX), level_to_xp(max_level));
+    }
+
+    #[test]
+    fn test_sanitize_character_name() {
+        assert_eq!(sanitize_character_name("FL4K"), "FL4K");
+        assert_eq!(sanitize_character_name("Zer0\n"), "Zer0");
+        assert_eq!(sanitize_character_name("Moze\t\u{0}\u{1B}"), "Moze");
+        assert_eq!(sanitize_character_name(""), "");
+    }
+
+    #[test]
+    fn test_sanitize_character_name_keeps_multi_byte_characters() {
+        for name in [
+            "Amélie",
+            "Амара",
+            "\u{30A2}\u{30DE}\u{30E9}",
+            "Ζερο",
+            "\u{4E9A}\u{739B}\u{62C9}",
+        ] {
+            assert_eq!(sanitize_character_name(name), name);
+        }
+
+        // Removing a control character doesn't split the characters around it
+        assert_eq!(sanitize_character_name("Ам\u{7}ара"), "Амара");
+    }
+
+    #[test]
+    fn test_sanitize_character_name_removes_emoji() {
+        assert_eq!(sanitize_character_name("Moze\u{1F525}"), "Moze");
+        assert_eq!(sanitize_character_name("\u{2764}\u{FE0F}FL4K"), "FL4K");
+        assert_eq!(sanitize_character_name("Amara\u{1F44D}\u{1F3FD}"), "Amara");
+        assert_eq!(sanitize_character_name("\u{1F1E9}\u{1F1EA}Zane"), "Zane");
+
+        // Family emoji built from several emoji and joiners
+        assert_eq!(
+            unsupported_name_chars("Zer0\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}"),
+            vec![
+                '\u{1F468}',
+                '\u{200D}',
+                '\u{1F469}',
+                '\u{200D}',
+                '\u{1F467}'
+            ]
+        );
+        assert_eq!(
+            sanitize_character_name("Zer0\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}"),
+            "Zer0"
+        );
+
+        // Joiners that aren't part of an emoji are kept
+        let sinhala = "\u{0DC1}\u{0DCA}\u{200D}\u{0DBB}\u{0DD3}";
+
+        assert_eq!(sanitize_character_name(sinhala), sinhala);
+        assert!(unsupported_name_chars(sinhala).is_empty());
+    }
+}
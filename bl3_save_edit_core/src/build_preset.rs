@@ -0,0 +1,406 @@
+use std::str::FromStr;
+
+use anyhow::{bail, Context, Result};
+use strum::EnumMessage;
+
+use crate::bl3_item::Bl3Item;
+use crate::bl3_save::character_data::CharacterData;
+use crate::bl3_save::inventory_slot::InventorySlot;
+use crate::bl3_save::player_class::PlayerClass;
+use crate::bl3_save::util::level_to_xp;
+
+pub const BUILD_PRESET_VERSION: u32 = 1;
+
+/// A shareable character build: the class, level, skill allocation and equipped items.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct BuildPreset {
+    pub player_class: PlayerClass,
+    pub level: i32,
+    pub skills: Vec<BuildPresetSkill>,
+    pub equipped_items: Vec<BuildPresetItem>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct BuildPresetSkill {
+    pub path: String,
+    pub points: i32,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct BuildPresetItem {
+    pub slot: InventorySlot,
+    pub serial: String,
+}
+
+pub(crate) fn item_name(item: &Bl3Item) -> String {
+    let balance_part = item.balance_part();
+
+    balance_part.name.clone().unwrap_or_else(|| {
+        balance_part
+            .short_ident
+            .clone()
+            .unwrap_or_else(|| balance_part.ident.clone())
+    })
+}
+
+impl BuildPreset {
+    pub fn from_character(character_data: &CharacterData) -> Result<Self> {
+        let skills = character_data
+            .skill_tree_items()
+            .into_iter()
+            .filter(|s| s.points > 0)
+            .map(|s| BuildPresetSkill {
+                path: s.path,
+                points: s.points,
+            })
+            .collect();
+
+        let equipped_items = character_data
+            .unlockable_inventory_slots()
+            .iter()
+            .filter_map(|s| {
+                let item = character_data
+                    .inventory_items()
+                    .get(character_data.equipped_inventory_index(&s.slot)?)?;
+
+                Some((s.slot.clone(), item))
+            })
+            .map(|(slot, item)| {
+                Ok(BuildPresetItem {
+                    slot,
+                    serial: item.get_serial_number_base64(false)?,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(BuildPreset {
+            player_class: character_data.player_class(),
+            level: character_data.player_level(),
+            skills,
+            equipped_items,
+        })
+    }
+
+    pub fn to_json(&self) -> String {
+        let skills = self
+            .skills
+            .iter()
+            .map(|s| {
+                json::object! {
+                    path: s.path.as_str(),
+                    points: s.points,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let equipped_items = self
+            .equipped_items
+            .iter()
+            .map(|i| {
+                json::object! {
+                    slot: i.slot.get_serializations()[0],
+                    serial: i.serial.as_str(),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        json::object! {
+            version: BUILD_PRESET_VERSION,
+            class: self.player_class.to_string(),
+            level: self.level,
+            skills: skills,
+            equipped_items: equipped_items,
+        }
+        .pretty(4)
+    }
+
+    pub fn from_json(data: &str) -> Result<Self> {
+        let data = json::parse(data).context("failed to parse build file")?;
+
+        let version = data["version"]
+            .as_u32()
+            .context("build file was missing a version")?;
+
+        if version > BUILD_PRESET_VERSION {
+            bail!(
+                "build file version {} is newer than this editor supports ({})",
+                version,
+                BUILD_PRESET_VERSION
+            );
+        }
+
+        let class_name = data["class"]
+            .as_str()
+            .context("build file was missing a class")?;
+
+        let player_class = PlayerClass::ALL
+            .iter()
+            .find(|c| c.to_string() == class_name)
+            .copied()
+            .with_context(|| format!("build file has an unknown class: {}", class_name))?;
+
+        let level = data["level"]
+            .as_i32()
+            .context("build file was missing a level")?;
+
+        let skills = data["skills"]
+            .members()
+            .map(|s| {
+                Ok(BuildPresetSkill {
+                    path: s["path"]
+                        .as_str()
+                        .context("build file skill was missing a path")?
+                        .to_owned(),
+                    points: s["points"]
+                        .as_i32()
+                        .context("build file skill was missing points")?,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let equipped_items = data["equipped_items"]
+            .members()
+            .map(|i| {
+                let slot_path = i["slot"]
+                    .as_str()
+                    .context("build file item was missing a slot")?;
+
+                Ok(BuildPresetItem {
+                    slot: InventorySlot::from_str(slot_path).with_context(|| {
+                        format!("build file item has an unknown slot: {}", slot_path)
+                    })?,
+                    serial: i["serial"]
+                        .as_str()
+                        .context("build file item was missing a serial")?
+                        .to_owned(),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(BuildPreset {
+            player_class,
+            level,
+            skills,
+            equipped_items,
+        })
+    }
+
+    fn check_class(&self, character_data: &CharacterData) -> Result<()> {
+        if self.player_class != character_data.player_class() {
+            bail!(
+                "this build is for a {} but the character is a {}",
+                self.player_class,
+                character_data.player_class()
+            );
+        }
+
+        Ok(())
+    }
+
+    fn skill_points(&self, path: &str) -> i32 {
+        self.skills
+            .iter()
+            .find(|s| s.path == path)
+            .map(|s| s.points)
+            .unwrap_or(0)
+    }
+
+    /// Describes everything `apply` would change on the character.
+    pub fn changes(&self, character_data: &CharacterData) -> Result<Vec<String>> {
+        self.check_class(character_data)?;
+
+        let mut changes = Vec::new();
+
+        if self.level != character_data.player_level() {
+            changes.push(format!(
+                "Level: {} -> {}",
+                character_data.player_level(),
+                self.level
+            ));
+        }
+
+        for skill in character_data.skill_tree_items() {
+            let points = self.skill_points(&skill.path);
+
+            if points != skill.points {
+                changes.push(format!(
+                    "{} {}: {} -> {}",
+                    skill.tree_name,
+                    skill.name(),
+                    skill.points,
+                    points
+                ));
+            }
+        }
+
+        for build_item in &self.equipped_items {
+            let item = Bl3Item::from_serial_base64(&build_item.serial)
+                .with_context(|| format!("failed to read item for {}", build_item.slot))?;
+
+            changes.push(format!(
+                "{}: add and equip {}",
+                build_item.slot,
+                item_name(&item)
+            ));
+        }
+
+        Ok(changes)
+    }
+
+    /// Sets the level and skill tree and adds the build's items to the inventory, equipping them.
+    /// Fails without changing anything if the build is for a different class.
+    pub fn apply(&self, character_data: &mut CharacterData) -> Result<()> {
+        self.check_class(character_data)?;
+
+        let items = self
+            .equipped_items
+            .iter()
+            .map(|i| {
+                Bl3Item::from_serial_base64(&i.serial)
+                    .map(|item| (i.slot.clone(), item))
+                    .with_context(|| format!("failed to read item for {}", i.slot))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let experience_points = level_to_xp(self.level)
+            .with_context(|| format!("build file has an invalid level: {}", self.level))?;
+
+        character_data.set_player_level(experience_points)?;
+
+        for skill in character_data.skill_tree_items() {
+            character_data
+                .set_skill_tree_item_points(&skill.path, self.skill_points(&skill.path))?;
+        }
+
+        character_data.set_ability_points(character_data.expected_ability_points())?;
+
+        for (slot, item) in items {
+            let item_index = character_data.inventory_items().len();
+
+            character_data.add_inventory_item(item_index as i32, &item)?;
+            character_data.unlock_inventory_slot(&slot)?;
+            character_data.equip_inventory_item(&slot, item_index)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crate::bl3_save::Bl3Save;
+    use crate::parser::HeaderType;
+
+    use super::*;
+
+    fn load_save(file_name: &str, data: &[u8]) -> Bl3Save {
+        Bl3Save::from_bytes(Path::new(file_name), data, HeaderType::PcSave)
+            .expect("failed to read test save")
+    }
+
+    #[test]
+    fn test_build_preset_json_round_trip() {
+        let save = load_save(
+            "./test_files/19.sav",
+            include_bytes!("../test_files/19.sav"),
+        );
+
+        let build =
+            BuildPreset::from_character(&save.character_data).expect("failed to export build");
+
+        assert_eq!(build.player_class, save.character_data.player_class());
+        assert!(!build.skills.is_empty());
+        assert!(!build.equipped_items.is_empty());
+
+        let imported = BuildPreset::from_json(&build.to_json()).expect("failed to import build");
+
+        assert_eq!(imported, build);
+    }
+
+    #[test]
+    fn test_build_preset_apply() {
+        let from = load_save(
+            "./test_files/19.sav",
+            include_bytes!("../test_files/19.sav"),
+        );
+
+        let build =
+            BuildPreset::from_character(&from.character_data).expect("failed to export build");
+
+        let mut to = from.clone();
+
+        to.character_data
+            .set_player_level(level_to_xp(build.level - 1).unwrap())
+            .unwrap();
+
+        let changes = build
+            .changes(&to.character_data)
+            .expect("failed to list changes");
+
+        assert!(changes
+            .iter()
+            .any(|c| c == &format!("Level: {} -> {}", build.level - 1, build.level)));
+
+        let items_before = to.character_data.inventory_items().len();
+
+        build
+            .apply(&mut to.character_data)
+            .expect("failed to apply build");
+
+        assert_eq!(to.character_data.player_level(), build.level);
+        assert_eq!(
+            to.character_data.spent_ability_points(),
+            from.character_data.spent_ability_points()
+        );
+        assert_eq!(
+            to.character_data.inventory_items().len(),
+            items_before + build.equipped_items.len()
+        );
+
+        // Exporting again gives the same build back
+        assert_eq!(
+            BuildPreset::from_character(&to.character_data).unwrap(),
+            build
+        );
+    }
+
+    #[test]
+    fn test_build_preset_rejects_other_class() {
+        let mut save = load_save(
+            "./test_files/19.sav",
+            include_bytes!("../test_files/19.sav"),
+        );
+
+        let mut build =
+            BuildPreset::from_character(&save.character_data).expect("failed to export build");
+
+        build.player_class = PlayerClass::ALL
+            .iter()
+            .copied()
+            .find(|c| *c != save.character_data.player_class())
+            .unwrap();
+
+        let original = save.clone();
+
+        let err = build.apply(&mut save.character_data).unwrap_err();
+
+        assert!(err
+            .to_string()
+            .contains(&format!("this build is for a {}", build.player_class)));
+        assert!(build.changes(&save.character_data).is_err());
+        assert_eq!(save, original);
+    }
+
+    #[test]
+    fn test_build_preset_from_invalid_json() {
+        assert!(BuildPreset::from_json("not json").is_err());
+        assert!(
+            BuildPreset::from_json(r#"{"version": 1, "class": "Claptrap", "level": 1}"#).is_err()
+        );
+        assert!(
+            BuildPreset::from_json(r#"{"version": 99, "class": "Siren", "level": 1}"#).is_err()
+        );
+    }
+}
@@ -0,0 +1,289 @@
+use std::collections::BTreeSet;
+
+use anyhow::{bail, Context, Result};
+
+use crate::bl3_item::{Bl3Item, Bl3Part, ItemType};
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum BulkItemEdit {
+    Level {
+        level: usize,
+        keep_mayhem_part: bool,
+    },
+    Anointment(Bl3Part),
+}
+
+impl BulkItemEdit {
+    /// Applies the edit to `item` without changing any of the parts in `locked_parts` (matched by ident).
+    /// Returns false if the item was skipped.
+    pub fn apply(&self, item: &mut Bl3Item, locked_parts: &BTreeSet<String>) -> Result<bool> {
+        match self {
+            BulkItemEdit::Level {
+                level,
+                keep_mayhem_part,
+            } => {
+                item.relevel(*level, *keep_mayhem_part)?;
+
+                Ok(true)
+            }
+            BulkItemEdit::Anointment(anointment) => {
+                if !matches!(
+                    item.item_type,
+                    ItemType::Weapon | ItemType::Shield | ItemType::GrenadeMod
+                ) {
+                    return Ok(false);
+                }
+
+                let current_anointments = match &item.item_parts {
+                    Some(item_parts) => item_parts
+                        .generic_parts()
+                        .iter()
+                        .filter(|p| !p.is_mayhem_part())
+                        .cloned()
+                        .collect::<Vec<_>>(),
+                    None => return Ok(false),
+                };
+
+                // A locked anointment is kept as is rather than being joined by another one
+                if current_anointments
+                    .iter()
+                    .any(|p| locked_parts.contains(&p.ident))
+                {
+                    return Ok(false);
+                }
+
+                for p in current_anointments
+                    .iter()
+                    .filter(|p| p.ident != anointment.ident)
+                {
+                    item.remove_generic_part(p)?;
+                }
+
+                if !current_anointments
+                    .iter()
+                    .any(|p| p.ident == anointment.ident)
+                {
+                    item.add_generic_part(anointment.clone())?;
+                }
+
+                Ok(true)
+            }
+        }
+    }
+}
+
+/// Applies the edit to each item, leaving the parts locked for that item untouched.
+/// Returns how many items were changed.
+pub fn bulk_edit_items<'a, I>(items: I, edit: &BulkItemEdit) -> Result<usize>
+where
+    I: IntoIterator<Item = (&'a mut Bl3Item, &'a BTreeSet<String>)>,
+{
+    let mut changed = 0;
+
+    for (i, (item, locked_parts)) in items.into_iter().enumerate() {
+        if edit
+            .apply(item, locked_parts)
+            .with_context(|| format!("failed to edit item number: {}", i + 1))?
+        {
+            changed += 1;
+        }
+    }
+
+    Ok(changed)
+}
+
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct LevelRangeSummary {
+    pub clamped_up: usize,
+    pub clamped_down: usize,
+    pub in_range: usize,
+}
+
+/// Clamps the level of each item to between `min_level` and `max_level` (inclusive).
+pub fn normalize_item_levels<'a, I>(
+    items: I,
+    min_level: usize,
+    max_level: usize,
+) -> Result<LevelRangeSummary>
+where
+    I: IntoIterator<Item = &'a mut Bl3Item>,
+{
+    if min_level > max_level {
+        bail!(
+            "the minimum level ({}) is higher than the maximum level ({})",
+            min_level,
+            max_level
+        );
+    }
+
+    let mut summary = LevelRangeSummary::default();
+
+    for (i, item) in items.into_iter().enumerate() {
+        let level = item.level();
+
+        let new_level = if level < min_level {
+            summary.clamped_up += 1;
+            min_level
+        } else if level > max_level {
+            summary.clamped_down += 1;
+            max_level
+        } else {
+            summary.in_range += 1;
+            continue;
+        };
+
+        item.set_level(new_level)
+            .with_context(|| format!("failed to set level of item number: {}", i + 1))?;
+    }
+
+    Ok(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crate::bl3_save::Bl3Save;
+    use crate::parser::HeaderType;
+    use crate::resources::INVENTORY_SERIAL_DB;
+
+    use super::*;
+
+    fn anointment(name: &str) -> Bl3Part {
+        INVENTORY_SERIAL_DB
+            .get_part_by_short_name("InventoryGenericPartData", name)
+            .expect("failed to find anointment")
+    }
+
+    fn anointments(item: &Bl3Item) -> Vec<String> {
+        item.item_parts
+            .as_ref()
+            .map(|ip| {
+                ip.generic_parts()
+                    .iter()
+                    .filter(|p| !p.is_mayhem_part())
+                    .map(|p| p.ident.clone())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    fn load_items() -> Vec<Bl3Item> {
+        let save = Bl3Save::from_bytes(
+            Path::new("./test_files/19.sav"),
+            include_bytes!("../test_files/19.sav"),
+            HeaderType::PcSave,
+        )
+        .expect("failed to read test save");
+
+        save.character_data.inventory_items().clone()
+    }
+
+    #[test]
+    fn test_bulk_set_level_keeps_locked_parts() {
+        let mut items = load_items();
+
+        let locked: BTreeSet<String> = items[0]
+            .item_parts
+            .as_ref()
+            .map(|ip| ip.parts().iter().map(|p| p.ident.clone()).collect())
+            .unwrap_or_default();
+
+        let parts_before = items[0].item_parts.clone();
+
+        let unlocked = BTreeSet::new();
+
+        let changed = bulk_edit_items(
+            items
+                .iter_mut()
+                .enumerate()
+                .map(|(i, item)| (item, if i == 0 { &locked } else { &unlocked })),
+            &BulkItemEdit::Level {
+                level: 50,
+                keep_mayhem_part: true,
+            },
+        )
+        .expect("failed to bulk edit items");
+
+        assert_eq!(changed, items.len());
+        assert!(items.iter().all(|i| i.level() == 50));
+        assert_eq!(items[0].item_parts, parts_before);
+    }
+
+    #[test]
+    fn test_bulk_set_anointment() {
+        let mut items = load_items()
+            .into_iter()
+            .filter(|i| i.item_type == ItemType::Weapon)
+            .take(3)
+            .collect::<Vec<_>>();
+
+        assert_eq!(items.len(), 3);
+
+        let locked_anointment = anointment("GPart_EG_SkillEndBonusEleDamage_Radiation");
+        let new_anointment = anointment("GPart_All_SkillEnd_SplashDamage");
+
+        for item in items.iter_mut().take(2) {
+            BulkItemEdit::Anointment(locked_anointment.clone())
+                .apply(item, &BTreeSet::new())
+                .expect("failed to set anointment");
+        }
+
+        let mut locked = BTreeSet::new();
+        locked.insert(locked_anointment.ident.clone());
+
+        let unlocked = BTreeSet::new();
+
+        let changed = bulk_edit_items(
+            items
+                .iter_mut()
+                .enumerate()
+                .map(|(i, item)| (item, if i == 0 { &locked } else { &unlocked })),
+            &BulkItemEdit::Anointment(new_anointment.clone()),
+        )
+        .expect("failed to bulk edit items");
+
+        assert_eq!(changed, 2);
+        assert_eq!(anointments(&items[0]), vec![locked_anointment.ident]);
+        assert_eq!(anointments(&items[1]), vec![new_anointment.ident.clone()]);
+        assert_eq!(anointments(&items[2]), vec![new_anointment.ident]);
+
+        let serial = items[1]
+            .get_serial_number_base64(false)
+            .expect("failed to serialize item");
+
+        assert_eq!(
+            anointments(&Bl3Item::from_serial_base64(&serial).expect("failed to read item")),
+            anointments(&items[1])
+        );
+    }
+
+    #[test]
+    fn test_normalize_item_levels() {
+        let mut items = load_items();
+
+        let levels = [10, 55, 72, 80];
+
+        for (item, level) in items.iter_mut().zip(levels.iter().cycle()) {
+            item.set_level(*level).expect("failed to set item level");
+        }
+
+        let expected_up = items.iter().filter(|i| i.level() < 55).count();
+        let expected_down = items.iter().filter(|i| i.level() > 72).count();
+
+        let summary =
+            normalize_item_levels(items.iter_mut(), 55, 72).expect("failed to normalize levels");
+
+        assert_eq!(
+            summary,
+            LevelRangeSummary {
+                clamped_up: expected_up,
+                clamped_down: expected_down,
+                in_range: items.len() - expected_up - expected_down,
+            }
+        );
+        assert!(items.iter().all(|i| (55..=72).contains(&i.level())));
+
+        assert!(normalize_item_levels(items.iter_mut(), 72, 55).is_err());
+    }
+}
@@ -0,0 +1,228 @@
+use std::fmt::Write;
+
+use crate::bl3_item::Bl3Item;
+use crate::bl3_save::Bl3Save;
+use crate::build_preset::item_name;
+
+fn escape(text: &str) -> String {
+    text.replace('|', "\\|")
+}
+
+fn table<const N: usize>(report: &mut String, headers: [&str; N], rows: Vec<[String; N]>) {
+    let _ = writeln!(report, "| {} |", headers.join(" | "));
+    let _ = writeln!(report, "|{}", "---|".repeat(N));
+
+    for row in rows {
+        let row = row.iter().map(|c| escape(c)).collect::<Vec<_>>();
+
+        let _ = writeln!(report, "| {} |", row.join(" | "));
+    }
+
+    report.push('\n');
+}
+
+fn item_row(item: &Bl3Item) -> [String; 3] {
+    [
+        item_name(item),
+        item.level().to_string(),
+        item.header().rarity.to_string(),
+    ]
+}
+
+/// A Markdown summary of the character: who they are, what they have equipped, their build and
+/// what they are carrying.
+pub fn character_report(save: &Bl3Save) -> String {
+    let character_data = &save.character_data;
+
+    let mut report = String::new();
+
+    let _ = writeln!(
+        report,
+        "# {}\n",
+        character_data.character.preferred_character_name
+    );
+
+    table(
+        &mut report,
+        ["Detail", "Value"],
+        vec![
+            [
+                "Class".to_owned(),
+                character_data.player_class().to_string(),
+            ],
+            [
+                "Level".to_owned(),
+                character_data.player_level().to_string(),
+            ],
+            [
+                "Experience Points".to_owned(),
+                character_data.character.experience_points.to_string(),
+            ],
+            [
+                "Guardian Rank".to_owned(),
+                character_data.guardian_rank().to_string(),
+            ],
+            [
+                "Mayhem Level".to_owned(),
+                character_data.mayhem_level().to_string(),
+            ],
+        ],
+    );
+
+    report.push_str("## Equipment\n\n");
+
+    let equipment = character_data
+        .unlockable_inventory_slots()
+        .iter()
+        .filter_map(|s| {
+            let item = character_data
+                .inventory_items()
+                .get(character_data.equipped_inventory_index(&s.slot)?)?;
+
+            let [name, level, rarity] = item_row(item);
+
+            Some([s.slot.to_string(), name, level, rarity])
+        })
+        .collect::<Vec<_>>();
+
+    if equipment.is_empty() {
+        report.push_str("Nothing equipped.\n\n");
+    } else {
+        table(&mut report, ["Slot", "Item", "Level", "Rarity"], equipment);
+    }
+
+    report.push_str("## Build\n\n");
+
+    let _ = writeln!(
+        report,
+        "Unspent ability points: {}\n",
+        character_data.ability_points()
+    );
+
+    let skills = character_data
+        .skill_tree_items()
+        .into_iter()
+        .filter(|s| s.points > 0)
+        .map(|s| {
+            [
+                s.tree_name.clone(),
+                s.name(),
+                format!("{}/{}", s.points, s.max_points),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    if !skills.is_empty() {
+        table(&mut report, ["Tree", "Skill", "Points"], skills);
+    }
+
+    report.push_str("## Currencies\n\n");
+
+    table(
+        &mut report,
+        ["Currency", "Amount"],
+        vec![
+            ["Money".to_owned(), character_data.money().to_string()],
+            ["Eridium".to_owned(), character_data.eridium().to_string()],
+        ],
+    );
+
+    report.push_str("## SDU Levels\n\n");
+
+    table(
+        &mut report,
+        ["SDU", "Level", "Max"],
+        character_data
+            .sdu_slots()
+            .iter()
+            .map(|s| [s.sdu.to_string(), s.current.to_string(), s.max.to_string()])
+            .collect(),
+    );
+
+    report.push_str("## Ammo\n\n");
+
+    table(
+        &mut report,
+        ["Pool", "Amount", "Max"],
+        character_data
+            .ammo_pools()
+            .iter()
+            .map(|a| [a.pool.to_string(), a.current.to_string(), a.max.to_string()])
+            .collect(),
+    );
+
+    let _ = writeln!(
+        report,
+        "## Inventory ({} items)\n",
+        character_data.inventory_items().len()
+    );
+
+    table(
+        &mut report,
+        ["Item", "Type", "Level", "Rarity"],
+        character_data
+            .inventory_items()
+            .iter()
+            .map(|i| {
+                let [name, level, rarity] = item_row(i);
+
+                [name, i.item_type.to_string(), level, rarity]
+            })
+            .collect(),
+    );
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crate::parser::HeaderType;
+
+    use super::*;
+
+    #[test]
+    fn test_character_report() {
+        let save = Bl3Save::from_bytes(
+            Path::new("./test_files/19.sav"),
+            include_bytes!("../test_files/19.sav"),
+            HeaderType::PcSave,
+        )
+        .expect("failed to read test save");
+
+        let report = character_report(&save);
+
+        assert!(report.starts_with("# Ricochet Witch 5.0\n"));
+        assert!(report.contains("| Class | Siren |"));
+        assert!(report.contains("| Level | 65 |"));
+
+        for section in [
+            "## Equipment",
+            "## Build",
+            "## Currencies",
+            "## SDU Levels",
+            "## Inventory",
+        ] {
+            assert!(report.contains(section), "missing section: {}", section);
+        }
+
+        assert!(report.contains("| Money | 36575378 |"));
+        assert!(report.contains("| Weapon 1 | "));
+
+        let inventory = report
+            .split("## Inventory")
+            .nth(1)
+            .expect("failed to find inventory");
+
+        assert_eq!(
+            inventory.lines().filter(|l| l.starts_with("| ")).count(),
+            save.character_data.inventory_items().len() + 1
+        );
+    }
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(escape("Hyper|ion"), "Hyper\\|ion");
+    }
+}
@@ -0,0 +1,171 @@
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumIter, IntoEnumIterator};
+
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    Display,
+    EnumIter,
+    Serialize,
+    Deserialize,
+    Default,
+)]
+pub enum ContentSource {
+    #[strum(to_string = "Base Game")]
+    BaseGame,
+    #[strum(to_string = "DLC1 - Moxxi's Heist of the Handsome Jackpot")]
+    Dlc1,
+    #[strum(to_string = "DLC2 - Guns, Love, and Tentacles")]
+    Dlc2,
+    #[strum(to_string = "DLC3 - Bounty of Blood")]
+    Dlc3,
+    #[strum(to_string = "DLC4 - Psycho Krieg and the Fantastic Fustercluck")]
+    Dlc4,
+    #[strum(to_string = "DLC5 - Designer's Cut")]
+    Dlc5,
+    #[strum(to_string = "DLC6 - Director's Cut")]
+    Dlc6,
+    #[strum(to_string = "Event")]
+    Event,
+    #[default]
+    Unknown,
+}
+
+impl ContentSource {
+    pub fn from_asset_path(path: &str) -> Self {
+        let mut segments = path.trim_start_matches('/').split('/');
+
+        if segments.next() != Some("Game") {
+            return ContentSource::Unknown;
+        }
+
+        match segments.next() {
+            Some("PatchDLC") => match segments.next() {
+                Some("Dandelion") => ContentSource::Dlc1,
+                Some("Hibiscus") => ContentSource::Dlc2,
+                Some("Geranium") => ContentSource::Dlc3,
+                Some("Alisma") => ContentSource::Dlc4,
+                Some("Ixora") => ContentSource::Dlc5,
+                Some("Ixora2") => ContentSource::Dlc6,
+                Some(
+                    "BloodyHarvest" | "CitizenScience" | "Event2" | "Event3" | "EventVDay"
+                    | "Mayhem2" | "Raid1" | "Takedown2" | "VaultCard" | "VaultCard2" | "VaultCard3",
+                ) => ContentSource::Event,
+                _ => ContentSource::Unknown,
+            },
+            Some(_) => ContentSource::BaseGame,
+            None => ContentSource::Unknown,
+        }
+    }
+
+    pub fn short_name(&self) -> &'static str {
+        match self {
+            ContentSource::BaseGame => "Base Game",
+            ContentSource::Dlc1 => "DLC1",
+            ContentSource::Dlc2 => "DLC2",
+            ContentSource::Dlc3 => "DLC3",
+            ContentSource::Dlc4 => "DLC4",
+            ContentSource::Dlc5 => "DLC5",
+            ContentSource::Dlc6 => "DLC6",
+            ContentSource::Event => "Event",
+            ContentSource::Unknown => "Unknown",
+        }
+    }
+
+    pub fn is_dlc(&self) -> bool {
+        matches!(
+            self,
+            ContentSource::Dlc1
+                | ContentSource::Dlc2
+                | ContentSource::Dlc3
+                | ContentSource::Dlc4
+                | ContentSource::Dlc5
+                | ContentSource::Dlc6
+        )
+    }
+
+    pub fn all_dlc() -> Vec<ContentSource> {
+        ContentSource::iter().filter(|c| c.is_dlc()).collect()
+    }
+
+    pub fn is_owned(&self, owned_dlc: &[ContentSource]) -> bool {
+        !self.is_dlc() || owned_dlc.contains(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::resources::INVENTORY_SERIAL_DB;
+
+    use super::*;
+
+    #[test]
+    fn test_from_asset_path() {
+        assert_eq!(
+            ContentSource::from_asset_path(
+                "/Game/Gear/Weapons/Pistols/Jakobs/_Shared/_Design/_Unique/Maggie/Balance/Balance_PS_JAK_Maggie.Balance_PS_JAK_Maggie"
+            ),
+            ContentSource::BaseGame
+        );
+        assert_eq!(
+            ContentSource::from_asset_path("/Game/PatchDLC/Hibiscus/Gear/Shields/_Unique/OldGod/Balance/InvBalD_Shield_OldGod.InvBalD_Shield_OldGod"),
+            ContentSource::Dlc2
+        );
+        assert_eq!(
+            ContentSource::from_asset_path("/Game/PatchDLC/Ixora/Gear/Weapons/_Unique/Ember/Balance/Balance_PS_COV_Ember.Balance_PS_COV_Ember"),
+            ContentSource::Dlc5
+        );
+        assert_eq!(
+            ContentSource::from_asset_path("/Game/PatchDLC/Ixora2/Gear/Weapons/_Unique/Frequency/Balance/Balance_SR_HYP_Frequency.Balance_SR_HYP_Frequency"),
+            ContentSource::Dlc6
+        );
+        assert_eq!(
+            ContentSource::from_asset_path("/Game/PatchDLC/Raid1/Gear/Anointed/Generic/SkillEnd_BonusEleDamage_Radiation/GPart_EG_SkillEndBonusEleDamage_Radiation.GPart_EG_SkillEndBonusEleDamage_Radiation"),
+            ContentSource::Event
+        );
+        assert_eq!(
+            ContentSource::from_asset_path("/Game/PatchDLC/SomethingNew/Gear/Thing.Thing"),
+            ContentSource::Unknown
+        );
+        assert_eq!(
+            ContentSource::from_asset_path("Unknown part"),
+            ContentSource::Unknown
+        );
+        assert_eq!(ContentSource::from_asset_path(""), ContentSource::Unknown);
+    }
+
+    #[test]
+    fn test_is_owned() {
+        let owned_dlc = vec![ContentSource::Dlc1];
+
+        assert!(ContentSource::BaseGame.is_owned(&owned_dlc));
+        assert!(ContentSource::Event.is_owned(&owned_dlc));
+        assert!(ContentSource::Unknown.is_owned(&owned_dlc));
+        assert!(ContentSource::Dlc1.is_owned(&owned_dlc));
+        assert!(!ContentSource::Dlc2.is_owned(&owned_dlc));
+
+        assert_eq!(ContentSource::all_dlc().len(), 6);
+    }
+
+    #[test]
+    fn test_serial_db_content_source() {
+        assert_eq!(
+            INVENTORY_SERIAL_DB.get_content_source("Part_Shield_Aug_OldGod"),
+            ContentSource::Dlc2
+        );
+        assert_eq!(
+            INVENTORY_SERIAL_DB.get_content_source("Shield_Part_Element_Fire"),
+            ContentSource::BaseGame
+        );
+        assert_eq!(
+            INVENTORY_SERIAL_DB.get_content_source("Not_A_Real_Part"),
+            ContentSource::Unknown
+        );
+    }
+}
@@ -0,0 +1,241 @@
+use std::collections::HashMap;
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::RwLock;
+
+use anyhow::{bail, Context, Result};
+use once_cell::sync::Lazy;
+
+use crate::bl3_item::{ItemRarity, BALANCE_NAMES_BY_SHORT_IDENT};
+use crate::resources::INVENTORY_PARTS_ALL_CATEGORIZED;
+
+pub const DATA_OVERRIDES_FILE: &str = "data_overrides.json";
+
+// Items read their balance name and rarity from here when they are parsed.
+static DATA_OVERRIDES: Lazy<RwLock<DataOverrides>> = Lazy::new(Default::default);
+
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct BalanceOverride {
+    pub name: Option<String>,
+    pub rarity: Option<ItemRarity>,
+}
+
+/// Names for parts and balances that the embedded game data doesn't have, such as the ones added
+/// by hotfix mods. Entries take precedence over the embedded data.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct DataOverrides {
+    // Keyed by the lowercase name after the last '.' of the path
+    parts: HashMap<String, String>,
+    balances: HashMap<String, BalanceOverride>,
+}
+
+fn override_key(ident: &str) -> String {
+    ident.rsplit('.').next().unwrap_or(ident).to_lowercase()
+}
+
+impl DataOverrides {
+    /// Reads `{ "parts": { "<part>": "Name" }, "balances": { "<balance>": { "name": "Name",
+    /// "rarity": "Legendary" } } }`. Parts and balances can be given as a full path or as the name
+    /// after the last `.`, both sections are optional.
+    pub fn from_json(data: &str) -> Result<Self> {
+        let data = json::parse(data).context("failed to parse data overrides")?;
+
+        if !data.is_object() {
+            bail!("data overrides must be an object");
+        }
+
+        let parts = data["parts"]
+            .entries()
+            .map(|(part, name)| {
+                let name = name
+                    .as_str()
+                    .with_context(|| format!("part {} must have a name", part))?;
+
+                Ok((override_key(part), name.to_owned()))
+            })
+            .collect::<Result<HashMap<_, _>>>()?;
+
+        let balances = data["balances"]
+            .entries()
+            .map(|(balance, balance_override)| {
+                if !balance_override.is_object() {
+                    bail!("balance {} must be an object", balance);
+                }
+
+                let name = balance_override["name"].as_str().map(|n| n.to_owned());
+
+                let rarity = balance_override["rarity"]
+                    .as_str()
+                    .map(|r| {
+                        ItemRarity::from_str(r).with_context(|| {
+                            format!("balance {} has an unknown rarity: {}", balance, r)
+                        })
+                    })
+                    .transpose()?;
+
+                Ok((override_key(balance), BalanceOverride { name, rarity }))
+            })
+            .collect::<Result<HashMap<_, _>>>()?;
+
+        Ok(Self { parts, balances })
+    }
+
+    /// An empty set of overrides when the file doesn't exist.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let data = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+
+        Self::from_json(&data)
+    }
+
+    pub fn len(&self) -> usize {
+        self.parts.len() + self.balances.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn part_name(&self, part_ident: &str) -> Option<&str> {
+        self.parts
+            .get(&override_key(part_ident))
+            .map(|n| n.as_str())
+    }
+
+    pub fn balance_name(&self, balance_ident: &str) -> Option<String> {
+        let key = override_key(balance_ident);
+
+        self.balances
+            .get(&key)
+            .and_then(|b| b.name.clone())
+            .or_else(|| {
+                BALANCE_NAMES_BY_SHORT_IDENT
+                    .get(key.as_str())
+                    .map(|name| (*name).to_owned())
+            })
+    }
+
+    pub fn balance_rarity(&self, balance_ident: &str) -> Option<ItemRarity> {
+        self.balances
+            .get(&override_key(balance_ident))
+            .and_then(|b| b.rarity.clone())
+            .or_else(|| {
+                let short_ident = balance_ident.rsplit('.').next().unwrap_or(balance_ident);
+
+                INVENTORY_PARTS_ALL_CATEGORIZED
+                    .get(short_ident)
+                    .and_then(|info| ItemRarity::from_str(&info.rarity).ok())
+            })
+    }
+}
+
+/// Replaces the overrides used for items read from now on.
+pub fn set_data_overrides(data_overrides: DataOverrides) {
+    *DATA_OVERRIDES
+        .write()
+        .expect("data overrides lock was poisoned") = data_overrides;
+}
+
+pub fn part_name(part_ident: &str) -> Option<String> {
+    DATA_OVERRIDES
+        .read()
+        .expect("data overrides lock was poisoned")
+        .part_name(part_ident)
+        .map(|n| n.to_owned())
+}
+
+pub fn balance_name(balance_ident: &str) -> Option<String> {
+    DATA_OVERRIDES
+        .read()
+        .expect("data overrides lock was poisoned")
+        .balance_name(balance_ident)
+}
+
+pub fn balance_rarity(balance_ident: &str) -> Option<ItemRarity> {
+    DATA_OVERRIDES
+        .read()
+        .expect("data overrides lock was poisoned")
+        .balance_rarity(balance_ident)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CROSSROAD_BALANCE: &str =
+        "/Game/Gear/Weapons/SMGs/Hyperion/_Shared/_Design/_Unique/Crossroad/Balance/Balance_SM_HYP_Crossroad.Balance_SM_HYP_Crossroad";
+
+    #[test]
+    fn test_embedded_data_without_overrides() {
+        let data_overrides = DataOverrides::default();
+
+        assert!(data_overrides.is_empty());
+        assert_eq!(
+            data_overrides.balance_name(CROSSROAD_BALANCE),
+            Some("Crossroad".to_owned())
+        );
+        assert_eq!(data_overrides.balance_name("Balance_Modded_Gun"), None);
+        assert_eq!(data_overrides.part_name("Part_Modded_Barrel"), None);
+    }
+
+    #[test]
+    fn test_overrides_take_precedence() {
+        let data_overrides = DataOverrides::from_json(&format!(
+            r#"{{
+                "parts": {{ "/Game/Mods/Part_Modded_Barrel.Part_Modded_Barrel": "Modded Barrel" }},
+                "balances": {{
+                    "Balance_Modded_Gun": {{ "name": "Modded Gun", "rarity": "Legendary" }},
+                    "{}": {{ "name": "Renamed" }}
+                }}
+            }}"#,
+            CROSSROAD_BALANCE
+        ))
+        .unwrap();
+
+        assert_eq!(data_overrides.len(), 3);
+        assert_eq!(
+            data_overrides.part_name("part_modded_barrel"),
+            Some("Modded Barrel")
+        );
+        assert_eq!(
+            data_overrides.balance_name("/Game/Mods/Balance_Modded_Gun.Balance_Modded_Gun"),
+            Some("Modded Gun".to_owned())
+        );
+        assert_eq!(
+            data_overrides.balance_rarity("Balance_Modded_Gun"),
+            Some(ItemRarity::Legendary)
+        );
+        assert_eq!(
+            data_overrides.balance_name(CROSSROAD_BALANCE),
+            Some("Renamed".to_owned())
+        );
+        // Only the name was overridden so the rarity still comes from the embedded data
+        assert_eq!(
+            data_overrides.balance_rarity(CROSSROAD_BALANCE),
+            DataOverrides::default().balance_rarity(CROSSROAD_BALANCE)
+        );
+    }
+
+    #[test]
+    fn test_malformed_overrides() {
+        assert!(DataOverrides::from_json("not json").is_err());
+        assert!(DataOverrides::from_json("[]").is_err());
+        assert!(DataOverrides::from_json(r#"{ "parts": { "Part_A": 1 } }"#).is_err());
+        assert!(DataOverrides::from_json(r#"{ "balances": { "Balance_A": "Name" } }"#).is_err());
+        assert!(DataOverrides::from_json(
+            r#"{ "balances": { "Balance_A": { "rarity": "Mythic" } } }"#
+        )
+        .is_err());
+
+        assert!(DataOverrides::from_json("{}").unwrap().is_empty());
+        assert!(
+            DataOverrides::load(Path::new("./test_files/missing_overrides.json"))
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
@@ -0,0 +1,60 @@
+use crate::bl3_item::Bl3Part;
+use crate::resources::INVENTORY_SERIAL_DB;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum FavoriteAnointment {
+    Available(Bl3Part),
+    /// The serial db no longer contains this part so it can only be removed.
+    Stale(String),
+}
+
+impl FavoriteAnointment {
+    pub fn ident(&self) -> &str {
+        match self {
+            FavoriteAnointment::Available(part) => &part.ident,
+            FavoriteAnointment::Stale(ident) => ident,
+        }
+    }
+
+    pub fn short_ident(&self) -> Option<&str> {
+        match self {
+            FavoriteAnointment::Available(part) => part.short_ident.as_deref(),
+            FavoriteAnointment::Stale(_) => None,
+        }
+    }
+}
+
+pub fn resolve_favorite_anointments(idents: &[String]) -> Vec<FavoriteAnointment> {
+    idents
+        .iter()
+        .map(|ident| {
+            INVENTORY_SERIAL_DB
+                .get_part_by_ident("InventoryGenericPartData", ident)
+                .map(FavoriteAnointment::Available)
+                .unwrap_or_else(|_| FavoriteAnointment::Stale(ident.to_owned()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_favorite_anointments() {
+        let available = "/Game/PatchDLC/Raid1/Gear/Anointed/Generic/SkillEnd_BonusEleDamage_Radiation/GPart_EG_SkillEndBonusEleDamage_Radiation.GPart_EG_SkillEndBonusEleDamage_Radiation";
+        let stale = "/Game/Gear/Anointed/Removed/GPart_Removed.GPart_Removed";
+
+        let favorites = resolve_favorite_anointments(&[available.to_owned(), stale.to_owned()]);
+
+        assert_eq!(favorites.len(), 2);
+        assert!(matches!(&favorites[0], FavoriteAnointment::Available(p) if p.ident == available));
+        assert_eq!(
+            favorites[0].short_ident(),
+            Some("GPart_EG_SkillEndBonusEleDamage_Radiation")
+        );
+        assert_eq!(favorites[1], FavoriteAnointment::Stale(stale.to_owned()));
+        assert_eq!(favorites[1].ident(), stale);
+        assert_eq!(favorites[1].short_ident(), None);
+    }
+}
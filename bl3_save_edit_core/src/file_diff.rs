@@ -0,0 +1,381 @@
+use crate::bl3_item::Bl3Item;
+use crate::bl3_profile::Bl3Profile;
+use crate::bl3_save::Bl3Save;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Change {
+    Value {
+        name: String,
+        before: i64,
+        after: i64,
+        max: Option<i64>,
+    },
+    Text {
+        name: String,
+        before: String,
+        after: String,
+    },
+    Items {
+        name: String,
+        added: usize,
+        removed: usize,
+    },
+}
+
+impl Change {
+    fn value<S: Into<String>, V: Into<i64>>(
+        name: S,
+        before: V,
+        after: V,
+        max: Option<V>,
+    ) -> Option<Self> {
+        let (before, after) = (before.into(), after.into());
+
+        if before == after {
+            return None;
+        }
+
+        Some(Change::Value {
+            name: name.into(),
+            before,
+            after,
+            max: max.map(|m| m.into()),
+        })
+    }
+
+    fn text<S: Into<String>, T: ToString>(name: S, before: T, after: T) -> Option<Self> {
+        let (before, after) = (before.to_string(), after.to_string());
+
+        if before == after {
+            return None;
+        }
+
+        Some(Change::Text {
+            name: name.into(),
+            before,
+            after,
+        })
+    }
+
+    fn items<S: Into<String>>(name: S, before: &[Bl3Item], after: &[Bl3Item]) -> Option<Self> {
+        let mut unmatched = before.iter().collect::<Vec<_>>();
+
+        let added = after
+            .iter()
+            .filter(|item| match unmatched.iter().position(|b| b == item) {
+                Some(i) => {
+                    unmatched.swap_remove(i);
+                    false
+                }
+                None => true,
+            })
+            .count();
+
+        let removed = unmatched.len();
+
+        if added == 0 && removed == 0 {
+            return None;
+        }
+
+        Some(Change::Items {
+            name: name.into(),
+            added,
+            removed,
+        })
+    }
+
+    pub fn summary(&self) -> String {
+        match self {
+            Change::Value {
+                name,
+                before,
+                after,
+                max,
+            } => {
+                let after = if Some(*after) == *max {
+                    "cap".to_owned()
+                } else {
+                    short_number(*after)
+                };
+
+                format!("{} {}\u{2192}{}", name, short_number(*before), after)
+            }
+            Change::Text {
+                name,
+                before,
+                after,
+            } => format!("{} {}\u{2192}{}", name, before, after),
+            Change::Items {
+                name,
+                added,
+                removed,
+            } => match (added, removed) {
+                (added, 0) => format!("+{} {}", added, name),
+                (0, removed) => format!("-{} {}", removed, name),
+                (added, removed) => format!("+{}/-{} {}", added, removed, name),
+            },
+        }
+    }
+}
+
+fn short_number(n: i64) -> String {
+    let units = [(1_000_000_000, "B"), (1_000_000, "M"), (1_000, "K")];
+
+    match units.iter().find(|(size, _)| n.abs() >= *size) {
+        Some((size, unit)) => {
+            let short = format!("{:.1}", n as f64 / *size as f64);
+
+            format!("{}{}", short.trim_end_matches(".0"), unit)
+        }
+        None => n.to_string(),
+    }
+}
+
+/// Lists up to `max_shown` changes, the rest are counted at the end.
+pub fn short_summary(changes: &[Change], max_shown: usize) -> String {
+    if changes.is_empty() {
+        return "No changes".to_owned();
+    }
+
+    let mut summary = changes
+        .iter()
+        .take(max_shown)
+        .map(|c| c.summary())
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    if changes.len() > max_shown {
+        summary.push_str(&format!(", +{} more", changes.len() - max_shown));
+    }
+
+    summary
+}
+
+pub fn diff_saves(before: &Bl3Save, after: &Bl3Save) -> Vec<Change> {
+    let (before, after) = (&before.character_data, &after.character_data);
+
+    let mut changes = vec![
+        Change::value::<_, i32>("Level", before.player_level(), after.player_level(), None),
+        Change::value("Money", before.money(), after.money(), Some(i32::MAX)),
+        Change::value("Eridium", before.eridium(), after.eridium(), Some(i32::MAX)),
+    ];
+
+    changes.extend(after.sdu_slots().iter().map(|a| {
+        let current = before
+            .sdu_slots()
+            .iter()
+            .find(|b| b.sdu == a.sdu)
+            .map(|b| b.current)
+            .unwrap_or(0);
+
+        Change::value(format!("{} SDU", a.sdu), current, a.current, Some(a.max))
+    }));
+
+    changes.extend(after.ammo_pools().iter().map(|a| {
+        let current = before
+            .ammo_pools()
+            .iter()
+            .find(|b| b.pool == a.pool)
+            .map(|b| b.current)
+            .unwrap_or(0);
+
+        Change::value(format!("{} Ammo", a.pool), current, a.current, Some(a.max))
+    }));
+
+    changes.push(Change::items(
+        "items",
+        before.inventory_items(),
+        after.inventory_items(),
+    ));
+
+    changes.into_iter().flatten().collect()
+}
+
+pub fn diff_profiles(before: &Bl3Profile, after: &Bl3Profile) -> Vec<Change> {
+    let (before, after) = (&before.profile_data, &after.profile_data);
+
+    let mut changes = after
+        .currencies()
+        .iter()
+        .map(|a| {
+            let current = before
+                .currencies()
+                .iter()
+                .find(|b| b.hash == a.hash)
+                .map(|b| b.current)
+                .unwrap_or(0);
+
+            Change::value(a.name(), current, a.current, Some(a.max()))
+        })
+        .collect::<Vec<_>>();
+
+    changes.push(Change::value::<_, i32>(
+        "Guardian Tokens",
+        before.guardian_tokens(),
+        after.guardian_tokens(),
+        None,
+    ));
+
+    changes.push(Change::text(
+        "Science Level",
+        before.borderlands_science_info().science_level,
+        after.borderlands_science_info().science_level,
+    ));
+
+    changes.push(Change::value::<_, i32>(
+        "Science Tokens",
+        before.borderlands_science_info().tokens,
+        after.borderlands_science_info().tokens,
+        None,
+    ));
+
+    changes.extend(after.sdu_slots().iter().map(|a| {
+        let current = before
+            .sdu_slots()
+            .iter()
+            .find(|b| b.sdu == a.sdu)
+            .map(|b| b.current)
+            .unwrap_or(0);
+
+        Change::value(format!("{} SDU", a.sdu), current, a.current, Some(a.max))
+    }));
+
+    changes.push(Change::items(
+        "bank items",
+        before.bank_items(),
+        after.bank_items(),
+    ));
+
+    changes.into_iter().flatten().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crate::bl3_profile::sdu::ProfileSduSlot;
+    use crate::bl3_save::ammo::AmmoPool;
+    use crate::bl3_save::sdu::SaveSduSlot;
+    use crate::parser::HeaderType;
+
+    use super::*;
+
+    fn load_save() -> Bl3Save {
+        Bl3Save::from_bytes(
+            Path::new("./test_files/19.sav"),
+            include_bytes!("../test_files/19.sav"),
+            HeaderType::PcSave,
+        )
+        .expect("failed to read test save")
+    }
+
+    fn load_profile() -> Bl3Profile {
+        Bl3Profile::from_bytes(
+            Path::new("./test_files/1prof.sav"),
+            include_bytes!("../test_files/1prof.sav"),
+            HeaderType::PcProfile,
+        )
+        .expect("failed to read test profile")
+    }
+
+    #[test]
+    fn test_short_number() {
+        assert_eq!(short_number(72), "72");
+        assert_eq!(short_number(1_000), "1K");
+        assert_eq!(short_number(12_345), "12.3K");
+        assert_eq!(short_number(1_234_567), "1.2M");
+        assert_eq!(short_number(-2_000_000_000), "-2B");
+    }
+
+    #[test]
+    fn test_short_summary() {
+        let changes = vec![
+            Change::Value {
+                name: "Level".to_owned(),
+                before: 57,
+                after: 72,
+                max: None,
+            },
+            Change::Value {
+                name: "Money".to_owned(),
+                before: 1_200_000,
+                after: i32::MAX as i64,
+                max: Some(i32::MAX as i64),
+            },
+            Change::Items {
+                name: "items".to_owned(),
+                added: 14,
+                removed: 0,
+            },
+            Change::Items {
+                name: "bank items".to_owned(),
+                added: 1,
+                removed: 2,
+            },
+        ];
+
+        assert_eq!(
+            short_summary(&changes, 3),
+            "Level 57\u{2192}72, Money 1.2M\u{2192}cap, +14 items, +1 more"
+        );
+        assert_eq!(changes[3].summary(), "+1/-2 bank items");
+        assert_eq!(short_summary(&[], 3), "No changes");
+    }
+
+    #[test]
+    fn test_diff_saves() {
+        let before = load_save();
+
+        assert!(diff_saves(&before, &before).is_empty());
+
+        let mut after = before.clone();
+
+        after.character_data.set_money(i32::MAX).unwrap();
+        after
+            .character_data
+            .set_sdu_slot(&SaveSduSlot::Backpack, SaveSduSlot::Backpack.maximum());
+        after
+            .character_data
+            .set_ammo_pool(&AmmoPool::Grenade, AmmoPool::Grenade.maximum())
+            .unwrap();
+        after.character_data.remove_inventory_item(0);
+
+        let changes = diff_saves(&before, &after);
+
+        assert!(changes
+            .iter()
+            .any(|c| c.summary().starts_with("Money ") && c.summary().ends_with("\u{2192}cap")));
+        assert!(changes.contains(&Change::Items {
+            name: "items".to_owned(),
+            added: 0,
+            removed: 1,
+        }));
+        assert!(changes.iter().all(|c| !c.summary().starts_with("Level ")));
+    }
+
+    #[test]
+    fn test_diff_profiles() {
+        let before = load_profile();
+
+        assert!(diff_profiles(&before, &before).is_empty());
+
+        let mut after = before.clone();
+
+        after
+            .profile_data
+            .set_sdu_slot(&ProfileSduSlot::Bank, ProfileSduSlot::Bank.maximum());
+        after
+            .profile_data
+            .add_bank_item(&before.profile_data.bank_items()[0])
+            .unwrap();
+
+        let changes = diff_profiles(&before, &after);
+
+        assert!(changes.iter().any(|c| c.summary().starts_with("Bank SDU ")));
+        assert!(changes.contains(&Change::Items {
+            name: "bank items".to_owned(),
+            added: 1,
+            removed: 0,
+        }));
+    }
+}
@@ -1,8 +1,15 @@
+use std::ffi::OsStr;
 use std::fmt::Formatter;
-use std::path::Path;
+use std::fs::File;
+use std::io::{Cursor, Read, Write};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
 
-use anyhow::{bail, Result};
+use anyhow::{bail, Context, Result};
 use nom::Finish;
+use strum::Display;
+use tracing::warn;
+use zip::ZipArchive;
 
 use crate::bl3_profile::Bl3Profile;
 use crate::bl3_save::Bl3Save;
@@ -11,6 +18,143 @@ use crate::parser::{
     read_custom_format_data, read_header, read_int, read_short, read_str, HeaderType,
 };
 
+pub const MAX_SUPPORTED_SAVE_GAME_VERSION: u32 = 2;
+pub const MAX_SUPPORTED_PACKAGE_VERSION: u32 = 516;
+
+pub fn is_version_newer_than_supported(save_game_version: u32, package_version: u32) -> bool {
+    save_game_version > MAX_SUPPORTED_SAVE_GAME_VERSION
+        || package_version > MAX_SUPPORTED_PACKAGE_VERSION
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Display, Default)]
+pub enum GameStore {
+    Steam,
+    Epic,
+    #[default]
+    Unknown,
+}
+
+fn is_steam_id(segment: &str) -> bool {
+    segment.len() == 17
+        && segment.starts_with("7656119")
+        && segment.chars().all(|c| c.is_ascii_digit())
+}
+
+fn is_epic_account_id(segment: &str) -> bool {
+    segment.len() == 32 && segment.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+/// Guesses which store a PC save folder belongs to. Steam names the folder after the SteamID64 and
+/// Epic after the 32 character account id, anything else is `Unknown`.
+pub fn infer_game_store(path: &Path) -> GameStore {
+    path.components()
+        .rev()
+        .filter_map(|c| c.as_os_str().to_str())
+        .find_map(|segment| {
+            if is_steam_id(segment) || segment.eq_ignore_ascii_case("steamapps") {
+                Some(GameStore::Steam)
+            } else if is_epic_account_id(segment) {
+                Some(GameStore::Epic)
+            } else {
+                None
+            }
+        })
+        .unwrap_or_default()
+}
+
+pub const DEFAULT_PROFILE_FILE_NAME: &str = "profile.sav";
+
+/// The folder `file_location` is in relative to `base_dir`, empty when it is directly inside it.
+pub fn relative_dir(base_dir: &Path, file_location: &Path) -> PathBuf {
+    file_location
+        .parent()
+        .and_then(|p| p.strip_prefix(base_dir).ok())
+        .map(Path::to_path_buf)
+        .unwrap_or_default()
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ProfileLocation {
+    pub relative_path: PathBuf,
+    pub modified: Option<SystemTime>,
+}
+
+/// Guesses which of several profiles in one folder the game uses. The game rewrites its profile
+/// every time it saves so the newest one wins, `profile.sav` directly in the folder breaks ties.
+pub fn most_likely_profile(profiles: &[ProfileLocation]) -> Option<usize> {
+    profiles
+        .iter()
+        .enumerate()
+        .max_by_key(|(_, p)| {
+            (
+                p.modified,
+                p.relative_path == Path::new(DEFAULT_PROFILE_FILE_NAME),
+            )
+        })
+        .map(|(i, _)| i)
+}
+
+/// Where a loaded save was read from.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Default)]
+pub enum FileOrigin {
+    #[default]
+    Directory,
+    /// Read from inside the named archive, it can't be saved back in place.
+    Archive(String),
+}
+
+/// Reads the saves inside a zip archive, entries that aren't saves are skipped.
+pub fn read_saves_in_archive(archive_location: &Path, data: &[u8]) -> Result<Vec<Bl3FileType>> {
+    let archive_name = archive_location
+        .file_name()
+        .and_then(OsStr::to_str)
+        .with_context(|| format!("{} has an invalid file name.", archive_location.display()))?
+        .to_owned();
+
+    let mut archive = ZipArchive::new(Cursor::new(data))
+        .with_context(|| format!("failed to open archive {}", archive_location.display()))?;
+
+    let mut saves = Vec::new();
+
+    for i in 0..archive.len() {
+        let mut entry = match archive.by_index(i) {
+            Ok(entry) => entry,
+            Err(e) => {
+                warn!("Skipping entry in {}: {}", archive_name, e);
+                continue;
+            }
+        };
+
+        if !entry.is_file() || !entry.name().ends_with(".sav") {
+            continue;
+        }
+
+        let entry_name = entry.name().to_owned();
+        let mut entry_data = Vec::with_capacity(entry.size() as usize);
+
+        if let Err(e) = entry.read_to_end(&mut entry_data) {
+            warn!("Failed to read {} in {}: {}", entry_name, archive_name, e);
+            continue;
+        }
+
+        // Only saves are listed, a profile from an archive could be mistaken for the real one
+        match Bl3FileType::from_unknown_data(Path::new(&entry_name), &entry_data) {
+            Ok(Bl3FileType::PcSave(mut save)) => {
+                save.origin = FileOrigin::Archive(archive_name.clone());
+                saves.push(Bl3FileType::PcSave(save));
+            }
+            Ok(Bl3FileType::Ps4Save(mut save)) => {
+                save.origin = FileOrigin::Archive(archive_name.clone());
+                saves.push(Bl3FileType::Ps4Save(save));
+            }
+            Ok(_) => (),
+            Err(e) => warn!("Skipping {} in {}: {}", entry_name, archive_name, e),
+        }
+    }
+
+    Ok(saves)
+}
+
 #[derive(Debug, Clone)]
 pub struct FileData<'a> {
     pub file_location: &'a Path,
@@ -25,9 +169,130 @@ pub struct FileData<'a> {
     pub custom_format_data_count: u32,
     pub custom_format_data: Vec<CustomFormatData>,
     pub save_game_type: String,
+    pub file_size: usize,
     pub remaining_data: &'a [u8],
 }
 
+/// Which game build wrote a file and how it was read, for looking into compatibility issues.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct FileMetadata {
+    pub save_game_version: u32,
+    pub package_version: u32,
+    pub engine_version: String,
+    pub build_id: String,
+    pub header_type: HeaderType,
+    pub file_size: usize,
+}
+
+impl FileMetadata {
+    pub fn file_size_text(&self) -> String {
+        let units = [(1024 * 1024, "MB"), (1024, "KB")];
+
+        match units.iter().find(|(size, _)| self.file_size >= *size) {
+            Some((size, unit)) => format!("{:.1} {}", self.file_size as f64 / *size as f64, unit),
+            None => format!("{} bytes", self.file_size),
+        }
+    }
+}
+
+pub fn engine_version_text(major: u16, minor: u16, patch: u16, build: u32) -> String {
+    format!("{}.{}.{}.{}", major, minor, patch, build)
+}
+
+/// Validates a file passed to the editor on startup and splits it into the folder to load and the file name to select.
+pub fn startup_file_location(path: &Path) -> Result<(PathBuf, String)> {
+    if !path.is_file() {
+        bail!("{} is not a file.", path.display());
+    }
+
+    if path.extension().and_then(OsStr::to_str) != Some("sav") {
+        bail!("{} is not a .sav file.", path.display());
+    }
+
+    let path = if path.is_relative() {
+        std::env::current_dir()?.join(path)
+    } else {
+        path.to_path_buf()
+    };
+
+    let file_name = path
+        .file_name()
+        .and_then(OsStr::to_str)
+        .with_context(|| format!("{} has an invalid file name.", path.display()))?
+        .to_owned();
+
+    let dir = path
+        .parent()
+        .with_context(|| format!("{} has no parent folder.", path.display()))?
+        .to_path_buf();
+
+    Ok((dir, file_name))
+}
+
+/// Writes `data` to `destination` through a temporary file in the same folder so a failed write
+/// never leaves the destination half written.
+pub fn write_file_atomic(destination: &Path, data: &[u8]) -> Result<()> {
+    let file_name = destination
+        .file_name()
+        .and_then(OsStr::to_str)
+        .with_context(|| format!("{} has an invalid file name.", destination.display()))?;
+
+    let temp_file = destination.with_file_name(format!(".{}.tmp", file_name));
+
+    let write_temp_file = || -> std::io::Result<()> {
+        let mut file = File::create(&temp_file)?;
+        file.write_all(data)?;
+        file.sync_all()
+    };
+
+    if let Err(e) = write_temp_file() {
+        let _ = std::fs::remove_file(&temp_file);
+
+        bail!(
+            "failed to write to a temporary file, the original file was not changed: {}",
+            e
+        );
+    }
+
+    if std::fs::rename(&temp_file, destination).is_ok() {
+        return Ok(());
+    }
+
+    // Some filesystems can't rename over an existing file, copying isn't atomic but the new data is
+    // kept in the temporary file if it fails.
+    let copy_over_destination = || -> std::io::Result<()> {
+        std::fs::copy(&temp_file, destination)?;
+        File::open(destination)?.sync_all()
+    };
+
+    if let Err(e) = copy_over_destination() {
+        bail!(
+            "failed to replace the original file, it may be incomplete but the new data was kept in {}: {}",
+            temp_file.display(),
+            e
+        );
+    }
+
+    if let Err(e) = std::fs::remove_file(&temp_file) {
+        warn!(
+            "Saved {} but failed to remove temporary file {}: {}",
+            destination.display(),
+            temp_file.display(),
+            e
+        );
+    }
+
+    Ok(())
+}
+
+/// Copies `backup` back over `target`, undoing a save that made the backup.
+pub fn restore_backup(backup: &Path, target: &Path) -> Result<()> {
+    let data = std::fs::read(backup)
+        .with_context(|| format!("failed to read backup {}", backup.display()))?;
+
+    write_file_atomic(target, &data)
+}
+
 pub fn read_bytes<'a>(file_location: &'a Path, data: &'a [u8]) -> Result<FileData<'a>> {
     let (r, _) = read_header(data).finish()?;
     let (r, file_version) = read_int(r).finish()?;
@@ -64,6 +329,7 @@ pub fn read_bytes<'a>(file_location: &'a Path, data: &'a [u8]) -> Result<FileDat
         custom_format_data_count,
         custom_format_data,
         save_game_type,
+        file_size: data.len(),
         remaining_data,
     })
 }
@@ -85,21 +351,37 @@ impl std::default::Default for Bl3FileType {
 impl std::fmt::Display for Bl3FileType {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
-            Bl3FileType::PcSave(save) | Bl3FileType::Ps4Save(save) => write!(
-                f,
-                "[{}] {} ({}) - Level {}",
-                save.header_type,
-                save.character_data.character.preferred_character_name,
-                save.character_data.player_class(),
-                save.character_data.player_level()
-            ),
-            Bl3FileType::PcProfile(profile) | Bl3FileType::Ps4Profile(profile) => {
+            Bl3FileType::PcSave(save) | Bl3FileType::Ps4Save(save) => {
                 write!(
                     f,
-                    "[{}] Golden Keys: {}",
-                    profile.header_type,
-                    profile.profile_data.golden_keys(),
-                )
+                    "[{}] {} ({}) - Level {}",
+                    save.header_type,
+                    save.character_data.character.preferred_character_name,
+                    save.character_data.player_class(),
+                    save.character_data.player_level()
+                )?;
+
+                if let FileOrigin::Archive(archive_name) = &save.origin {
+                    write!(f, " ({}, read-only)", archive_name)?;
+                }
+
+                Ok(())
+            }
+            Bl3FileType::PcProfile(profile) | Bl3FileType::Ps4Profile(profile) => {
+                match profile.game_store {
+                    GameStore::Unknown => write!(f, "[{}]", profile.header_type)?,
+                    store => write!(f, "[{} - {}]", profile.header_type, store)?,
+                }
+
+                write!(f, " Golden Keys: {}", profile.profile_data.golden_keys())?;
+
+                let relative_path = profile.relative_path();
+
+                if relative_path != Path::new(DEFAULT_PROFILE_FILE_NAME) {
+                    write!(f, " ({})", relative_path.display())?;
+                }
+
+                Ok(())
             }
         }
     }
@@ -122,6 +404,22 @@ impl Bl3FileType {
         }
     }
 
+    pub fn is_version_newer_than_supported(&self) -> bool {
+        match self {
+            Bl3FileType::PcSave(s) | Bl3FileType::Ps4Save(s) => s.is_version_newer_than_supported(),
+            Bl3FileType::PcProfile(p) | Bl3FileType::Ps4Profile(p) => {
+                p.is_version_newer_than_supported()
+            }
+        }
+    }
+
+    pub fn origin(&self) -> FileOrigin {
+        match self {
+            Bl3FileType::PcSave(s) | Bl3FileType::Ps4Save(s) => s.origin.clone(),
+            Bl3FileType::PcProfile(_) | Bl3FileType::Ps4Profile(_) => FileOrigin::Directory,
+        }
+    }
+
     pub fn filename(&self) -> &str {
         match self {
             Bl3FileType::PcSave(s) => &s.file_name,
@@ -131,3 +429,261 @@ impl Bl3FileType {
         }
     }
 }
+
+#[cfg(test)]
+pub(crate) fn temp_test_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("bl3_save_edit_{}_{}", name, std::process::id()));
+
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).expect("failed to create temp dir");
+
+    dir
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_version_newer_than_supported() {
+        assert!(!is_version_newer_than_supported(
+            MAX_SUPPORTED_SAVE_GAME_VERSION,
+            MAX_SUPPORTED_PACKAGE_VERSION
+        ));
+        assert!(!is_version_newer_than_supported(
+            MAX_SUPPORTED_SAVE_GAME_VERSION - 1,
+            MAX_SUPPORTED_PACKAGE_VERSION - 1
+        ));
+        assert!(is_version_newer_than_supported(
+            MAX_SUPPORTED_SAVE_GAME_VERSION + 1,
+            MAX_SUPPORTED_PACKAGE_VERSION
+        ));
+        assert!(is_version_newer_than_supported(
+            MAX_SUPPORTED_SAVE_GAME_VERSION,
+            MAX_SUPPORTED_PACKAGE_VERSION + 1
+        ));
+    }
+
+    #[test]
+    fn test_file_size_text() {
+        let metadata = |file_size| FileMetadata {
+            file_size,
+            ..FileMetadata::default()
+        };
+
+        assert_eq!(metadata(512).file_size_text(), "512 bytes");
+        assert_eq!(metadata(20_480).file_size_text(), "20.0 KB");
+        assert_eq!(metadata(3 * 1024 * 1024 / 2).file_size_text(), "1.5 MB");
+    }
+
+    #[test]
+    fn test_startup_file_location() {
+        let (dir, file_name) = startup_file_location(Path::new("./test_files/19.sav"))
+            .expect("failed to read startup file location");
+
+        assert!(dir.is_absolute());
+        assert!(dir.ends_with("test_files"));
+        assert_eq!(file_name, "19.sav");
+
+        assert!(startup_file_location(Path::new("./test_files/not_a_file.sav")).is_err());
+        assert!(startup_file_location(Path::new("./test_files")).is_err());
+        assert!(startup_file_location(Path::new("./Cargo.toml")).is_err());
+    }
+
+    #[test]
+    fn test_fixtures_are_supported_versions() {
+        let filename = Path::new("./test_files/19.sav");
+        let data = include_bytes!("../test_files/19.sav");
+
+        let save = Bl3FileType::from_unknown_data(filename, data).expect("failed to read save");
+
+        assert!(!save.is_version_newer_than_supported());
+
+        let filename = Path::new("./test_files/1prof.sav");
+        let data = include_bytes!("../test_files/1prof.sav");
+
+        let profile =
+            Bl3FileType::from_unknown_data(filename, data).expect("failed to read profile");
+
+        assert!(!profile.is_version_newer_than_supported());
+    }
+
+    #[test]
+    fn test_infer_game_store() {
+        assert_eq!(
+            infer_game_store(Path::new(
+                "/home/user/Documents/My Games/Borderlands 3/Saved/SaveGames/76561198012345678/profile.sav"
+            )),
+            GameStore::Steam
+        );
+        assert_eq!(
+            infer_game_store(Path::new(
+                "/home/user/.local/share/Steam/steamapps/compatdata/397540/pfx/drive_c/users/steamuser/Documents/My Games/Borderlands 3/Saved/SaveGames"
+            )),
+            GameStore::Steam
+        );
+        assert_eq!(
+            infer_game_store(Path::new(
+                "/home/user/Documents/My Games/Borderlands 3/Saved/SaveGames/0a1b2c3d4e5f60718293a4b5c6d7e8f9"
+            )),
+            GameStore::Epic
+        );
+        assert_eq!(
+            infer_game_store(Path::new("/home/user/saves/profile.sav")),
+            GameStore::Unknown
+        );
+        assert_eq!(
+            infer_game_store(Path::new("/home/user/saves/12345678901234567")),
+            GameStore::Unknown
+        );
+        assert_eq!(infer_game_store(Path::new("")), GameStore::Unknown);
+    }
+
+    #[test]
+    fn test_relative_dir() {
+        let base_dir = Path::new("/saves/76561198012345678");
+
+        assert_eq!(
+            relative_dir(base_dir, &base_dir.join("profile.sav")),
+            PathBuf::new()
+        );
+        assert_eq!(
+            relative_dir(base_dir, &base_dir.join("old").join("profile.sav")),
+            PathBuf::from("old")
+        );
+        assert_eq!(
+            relative_dir(base_dir, Path::new("/elsewhere/profile.sav")),
+            PathBuf::new()
+        );
+    }
+
+    #[test]
+    fn test_most_likely_profile() {
+        let older = SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(100);
+        let newer = SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(200);
+
+        let location = |path: &str, modified| ProfileLocation {
+            relative_path: PathBuf::from(path),
+            modified,
+        };
+
+        assert_eq!(most_likely_profile(&[]), None);
+
+        assert_eq!(
+            most_likely_profile(&[
+                location("profile.sav", Some(older)),
+                location("profile.sav.bak", Some(newer)),
+                location("old/profile.sav", None),
+            ]),
+            Some(1)
+        );
+
+        assert_eq!(
+            most_likely_profile(&[
+                location("old/profile.sav", Some(newer)),
+                location("profile.sav", Some(newer)),
+                location("profile.sav.bak", Some(newer)),
+            ]),
+            Some(1)
+        );
+
+        assert_eq!(
+            most_likely_profile(&[
+                location("old/profile.sav", None),
+                location("profile.sav", None)
+            ]),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn test_read_saves_in_archive() {
+        let archive_location = Path::new("./test_files/saves.zip");
+
+        let saves =
+            read_saves_in_archive(archive_location, include_bytes!("../test_files/saves.zip"))
+                .expect("failed to read archive");
+
+        // The profile, the broken save and the text file are skipped
+        assert_eq!(saves.len(), 2);
+
+        assert!(matches!(saves[0], Bl3FileType::PcSave(_)));
+        assert_eq!(saves[0].filename(), "5.sav");
+
+        assert!(matches!(saves[1], Bl3FileType::Ps4Save(_)));
+        assert_eq!(saves[1].filename(), "1ps4_v100.sav");
+
+        for save in &saves {
+            assert_eq!(save.origin(), FileOrigin::Archive("saves.zip".to_owned()));
+            assert!(save.to_string().ends_with("(saves.zip, read-only)"));
+        }
+
+        assert!(read_saves_in_archive(archive_location, b"not an archive").is_err());
+    }
+
+    #[test]
+    fn test_write_file_atomic() {
+        let dir = temp_test_dir("write_file_atomic");
+        let destination = dir.join("1.sav");
+
+        std::fs::write(&destination, b"original").unwrap();
+
+        write_file_atomic(&destination, b"updated").expect("failed to write file");
+
+        assert_eq!(std::fs::read(&destination).unwrap(), b"updated");
+
+        // Only the destination should be left, the temporary file was renamed over it
+        let files = std::fs::read_dir(&dir)
+            .unwrap()
+            .map(|e| e.unwrap().file_name())
+            .collect::<Vec<_>>();
+
+        assert_eq!(files, vec![OsStr::new("1.sav")]);
+
+        let new_destination = dir.join("2.sav");
+
+        write_file_atomic(&new_destination, b"new").expect("failed to write new file");
+
+        assert_eq!(std::fs::read(&new_destination).unwrap(), b"new");
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_restore_backup() {
+        let dir = temp_test_dir("restore_backup");
+        let target = dir.join("1.sav");
+        let backup = dir.join("1-backup.sav");
+
+        std::fs::write(&target, b"saved").unwrap();
+        std::fs::write(&backup, b"original").unwrap();
+
+        restore_backup(&backup, &target).expect("failed to restore backup");
+
+        assert_eq!(std::fs::read(&target).unwrap(), b"original");
+        assert_eq!(std::fs::read(&backup).unwrap(), b"original");
+
+        std::fs::remove_file(&backup).unwrap();
+        std::fs::write(&target, b"saved").unwrap();
+
+        assert!(restore_backup(&backup, &target).is_err());
+        assert_eq!(std::fs::read(&target).unwrap(), b"saved");
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_write_file_atomic_missing_dir() {
+        let dir = temp_test_dir("write_file_atomic_missing_dir");
+        let destination = dir.join("missing").join("1.sav");
+
+        let err = write_file_atomic(&destination, b"updated").unwrap_err();
+
+        assert!(err
+            .to_string()
+            .contains("the original file was not changed"));
+        assert!(!destination.exists());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
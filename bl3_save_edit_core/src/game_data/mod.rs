@@ -1983,6 +1983,58 @@ pub const PROFILE_SKINS_DEFAULTS: [GameDataKv; 4] = [
     GameDataKv { ident: "/Game/PlayerCharacters/_Customizations/SirenBrawler/Skins/CustomSkin_Siren_Default.CustomSkin_Siren_Default", name: "Siren Skin Default" },
 ];
 
+pub const PROFILE_EVENT_COSMETICS: [GameDataKv; 49] = [
+    GameDataKv { ident: "/Game/PatchDLC/EventVDay/PlayerCharacters/_Shared/ECHODevice/EchoTheme_Valentines_02.EchoTheme_Valentines_02", name: "Affection Connection" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/PlayerCharacters/_Customizations/EchoDevice/ECHOTheme_40.ECHOTheme_40", name: "Cosmic Ring" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/PlayerCharacters/_Customizations/Beastmaster/Skins/CustomSkin_Beastmaster_47.CustomSkin_Beastmaster_47", name: "Death by Filigrees" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/PlayerCharacters/_Customizations/Gunner/Skins/CustomSkin_Gunner_47.CustomSkin_Gunner_47", name: "Death by Filigrees" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/PlayerCharacters/_Customizations/Operative/Skins/CustomSkin_Operative_47.CustomSkin_Operative_47", name: "Death by Filigrees" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/PlayerCharacters/_Customizations/SirenBrawler/Skins/CustomSkin_Siren_47.CustomSkin_Siren_47", name: "Death by Filigrees" },
+    GameDataKv { ident: "/Game/PatchDLC/EventVDay/PlayerCharacters/_Shared/ECHODevice/EchoTheme_Valentines_01.EchoTheme_Valentines_01", name: "ECHOcardiogram" },
+    GameDataKv { ident: "/Game/PatchDLC/EventVDay/TwitchDrops/PlayerCharacters/_Shared/ECHODevice/ECHOTheme_46.ECHOTheme_46", name: "Field Trip" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/PlayerCharacters/_Customizations/Beastmaster/Skins/CustomSkin_Beastmaster_48.CustomSkin_Beastmaster_48", name: "Grid Runner" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/PlayerCharacters/_Customizations/Gunner/Skins/CustomSkin_Gunner_48.CustomSkin_Gunner_48", name: "Grid Runner" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/PlayerCharacters/_Customizations/Operative/Skins/CustomSkin_Operative_48.CustomSkin_Operative_48", name: "Grid Runner" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/PlayerCharacters/_Customizations/SirenBrawler/Skins/CustomSkin_Siren_48.CustomSkin_Siren_48", name: "Grid Runner" },
+    GameDataKv { ident: "/Game/PatchDLC/EventVDay/PlayerCharacters/_Shared/CustomSkin_Beastmaster_65.CustomSkin_Beastmaster_65", name: "Heart Attacker" },
+    GameDataKv { ident: "/Game/PatchDLC/EventVDay/PlayerCharacters/_Shared/CustomSkin_Gunner_65.CustomSkin_Gunner_65", name: "Heart Attacker" },
+    GameDataKv { ident: "/Game/PatchDLC/EventVDay/PlayerCharacters/_Shared/CustomSkin_Operative_65.CustomSkin_Operative_65", name: "Heart Attacker" },
+    GameDataKv { ident: "/Game/PatchDLC/EventVDay/PlayerCharacters/_Shared/CustomSkin_Siren_65.CustomSkin_Siren_65", name: "Heart Attacker" },
+    GameDataKv { ident: "/Game/PatchDLC/EventVDay/PlayerCharacters/_Shared/CustomSkin_Beastmaster_50.CustomSkin_Beastmaster_50", name: "Heartbreaker" },
+    GameDataKv { ident: "/Game/PatchDLC/EventVDay/PlayerCharacters/_Shared/CustomSkin_Gunner_50.CustomSkin_Gunner_50", name: "Heartbreaker" },
+    GameDataKv { ident: "/Game/PatchDLC/EventVDay/PlayerCharacters/_Shared/CustomSkin_Operative_50.CustomSkin_Operative_50", name: "Heartbreaker" },
+    GameDataKv { ident: "/Game/PatchDLC/EventVDay/PlayerCharacters/_Shared/CustomSkin_Siren_50.CustomSkin_Siren_50", name: "Heartbreaker" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/PlayerCharacters/_Customizations/Beastmaster/Heads/CustomHead_Beastmaster_34.CustomHead_Beastmaster_34", name: "Hotline Pandora" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/PlayerCharacters/_Customizations/Gunner/Heads/CustomHead_Gunner_34.CustomHead_Gunner_34", name: "Hotline Pandora" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/PlayerCharacters/_Customizations/Operative/Heads/CustomHead_Operative_34.CustomHead_Operative_34", name: "Hotline Pandora" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/PlayerCharacters/_Customizations/SirenBrawler/Heads/CustomHead_Siren_34.CustomHead_Siren_34", name: "Hotline Pandora" },
+    GameDataKv { ident: "/Game/PatchDLC/EventVDay/TwitchDrops/PlayerCharacters/_Customizations/Beastmaster/Skins/CustomSkin_Beastmaster_61.CustomSkin_Beastmaster_61", name: "Popsychle" },
+    GameDataKv { ident: "/Game/PatchDLC/EventVDay/TwitchDrops/PlayerCharacters/_Customizations/Gunner/Skins/CustomSkin_Gunner_61.CustomSkin_Gunner_61", name: "Popsychle" },
+    GameDataKv { ident: "/Game/PatchDLC/EventVDay/TwitchDrops/PlayerCharacters/_Customizations/Operative/Skins/CustomSkin_Operative_61.CustomSkin_Operative_61", name: "Popsychle" },
+    GameDataKv { ident: "/Game/PatchDLC/EventVDay/TwitchDrops/PlayerCharacters/_Customizations/Siren/Skins/CustomSkin_Siren_61.CustomSkin_Siren_61", name: "Popsychle" },
+    GameDataKv { ident: "/Game/PatchDLC/EventVDay/TwitchDrops/PlayerCharacters/_Customizations/Beastmaster/Heads/DA_BMHead33.DA_BMHead33", name: "Ratch Rider" },
+    GameDataKv { ident: "/Game/PatchDLC/EventVDay/TwitchDrops/PlayerCharacters/_Customizations/Gunner/Heads/DA_GNRHead33.DA_GNRHead33", name: "Ratch Rider" },
+    GameDataKv { ident: "/Game/PatchDLC/EventVDay/TwitchDrops/PlayerCharacters/_Customizations/Operative/Heads/DA_OPHead33.DA_OPHead33", name: "Ratch Rider" },
+    GameDataKv { ident: "/Game/PatchDLC/EventVDay/TwitchDrops/PlayerCharacters/_Customizations/Siren/Heads/DA_SRNHead33.DA_SRNHead33", name: "Ratch Rider" },
+    GameDataKv { ident: "/Game/PatchDLC/EventVDay/PlayerCharacters/_Shared/CustomHead_BeastMaster_Twitch.CustomHead_BeastMaster_Twitch", name: "Roll Player" },
+    GameDataKv { ident: "/Game/PatchDLC/EventVDay/PlayerCharacters/_Shared/CustomHead_Gunner_Twitch.CustomHead_Gunner_Twitch", name: "Roll Player" },
+    GameDataKv { ident: "/Game/PatchDLC/EventVDay/PlayerCharacters/_Shared/CustomHead_Operative_Twitch.CustomHead_Operative_Twitch", name: "Roll Player" },
+    GameDataKv { ident: "/Game/PatchDLC/EventVDay/PlayerCharacters/_Shared/CustomHead_Siren_Twitch.CustomHead_Siren_Twitch", name: "Roll Player" },
+    GameDataKv { ident: "/Game/PatchDLC/EventVDay/TwitchDrops/PlayerCharacters/_Customizations/Beastmaster/Heads/DA_BMHead35.DA_BMHead35", name: "Saurian Synth" },
+    GameDataKv { ident: "/Game/PatchDLC/EventVDay/TwitchDrops/PlayerCharacters/_Customizations/Gunner/Heads/DA_GNRHead35.DA_GNRHead35", name: "Saurian Synth" },
+    GameDataKv { ident: "/Game/PatchDLC/EventVDay/TwitchDrops/PlayerCharacters/_Customizations/Operative/Heads/DA_OPHead35.DA_OPHead35", name: "Saurian Synth" },
+    GameDataKv { ident: "/Game/PatchDLC/EventVDay/TwitchDrops/PlayerCharacters/_Customizations/Siren/Heads/DA_SRNHead35.DA_SRNHead35", name: "Saurian Synth" },
+    GameDataKv { ident: "/Game/PatchDLC/EventVDay/TwitchDrops/PlayerCharacters/_Customizations/Beastmaster/Heads/DA_BMHead36.DA_BMHead36", name: "Skagwave" },
+    GameDataKv { ident: "/Game/PatchDLC/EventVDay/TwitchDrops/PlayerCharacters/_Customizations/Gunner/Heads/DA_GNRHead36.DA_GNRHead36", name: "Skagwave" },
+    GameDataKv { ident: "/Game/PatchDLC/EventVDay/TwitchDrops/PlayerCharacters/_Customizations/Operative/Heads/DA_OPHead36.DA_OPHead36", name: "Skagwave" },
+    GameDataKv { ident: "/Game/PatchDLC/EventVDay/TwitchDrops/PlayerCharacters/_Customizations/Siren/Heads/DA_SRNHead36.DA_SRNHead36", name: "Skagwave" },
+    GameDataKv { ident: "/Game/PatchDLC/EventVDay/TwitchDrops/PlayerCharacters/_Customizations/Beastmaster/Heads/DA_BMHead37.DA_BMHead37", name: "Spiderpunk" },
+    GameDataKv { ident: "/Game/PatchDLC/EventVDay/TwitchDrops/PlayerCharacters/_Customizations/Gunner/Heads/DA_GNRHead37.DA_GNRHead37", name: "Spiderpunk" },
+    GameDataKv { ident: "/Game/PatchDLC/EventVDay/TwitchDrops/PlayerCharacters/_Customizations/Operative/Heads/DA_OPHead37.DA_OPHead37", name: "Spiderpunk" },
+    GameDataKv { ident: "/Game/PatchDLC/EventVDay/TwitchDrops/PlayerCharacters/_Customizations/Siren/Heads/DA_SRNHead37.DA_SRNHead37", name: "Spiderpunk" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/PlayerCharacters/_Customizations/EchoDevice/ECHOTheme_44.ECHOTheme_44", name: "VECH-tor Graphics" },
+];
+
 pub const BALANCE_NAME_MAPPING: [GameDataKv; 774] = [
     GameDataKv { ident: "/game/gear/artifacts/_design/balancedefs/invbald_artifact_01_common", name: "White Artifact" },
     GameDataKv { ident: "/game/gear/artifacts/_design/balancedefs/invbald_artifact_02_uncommon", name: "Green Artifact" },
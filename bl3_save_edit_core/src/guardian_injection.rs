@@ -0,0 +1,158 @@
+use std::ffi::OsStr;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use rayon::prelude::*;
+
+use crate::bl3_profile::guardian_reward::GuardianRewardData;
+use crate::bl3_profile::profile_data::ProfileData;
+use crate::bl3_save::Bl3Save;
+use crate::file_helper::Bl3FileType;
+
+/// The guardian rank of a profile, which the game expects every character save to agree with.
+#[derive(Debug, Clone)]
+pub struct GuardianInjection {
+    pub rank: i32,
+    pub tokens: i32,
+    pub rewards: Vec<GuardianRewardData>,
+}
+
+impl GuardianInjection {
+    pub fn from_profile_data(profile_data: &ProfileData) -> Self {
+        GuardianInjection {
+            rank: profile_data.guardian_rank(),
+            tokens: profile_data.guardian_tokens(),
+            rewards: profile_data.guardian_rewards().clone(),
+        }
+    }
+
+    /// Copies the guardian data into the save, returning `false` if it already matched.
+    pub fn apply(&self, save: &mut Bl3Save) -> Result<bool> {
+        let existing_character = save.character_data.character.clone();
+
+        save.character_data
+            .set_guardian_rank(self.rank, Some(self.tokens));
+
+        for g in &self.rewards {
+            save.character_data
+                .set_guardian_reward(&g.reward, g.current)?;
+        }
+
+        Ok(save.character_data.character != existing_character)
+    }
+}
+
+/// Reads every character save in `saves_dir` and returns the ones `injection` would change, sorted
+/// by file name. The saves are returned unmodified so they can be backed up before being written.
+pub fn saves_requiring_injection(
+    saves_dir: &Path,
+    injection: &GuardianInjection,
+) -> Result<Vec<Bl3Save>> {
+    let mut save_paths = std::fs::read_dir(saves_dir)
+        .with_context(|| format!("failed to read saves folder: {}", saves_dir.display()))?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.is_file() && path.extension().and_then(OsStr::to_str) == Some("sav"))
+        .collect::<Vec<_>>();
+
+    save_paths.sort();
+
+    save_paths
+        .par_iter()
+        .filter_map(|path| {
+            let data = std::fs::read(path).ok()?;
+
+            match Bl3FileType::from_unknown_data(path, &data).ok()? {
+                Bl3FileType::PcSave(save) | Bl3FileType::Ps4Save(save) => Some(save),
+                _ => None,
+            }
+        })
+        .filter_map(|save| {
+            let mut injected_save = save.clone();
+
+            match injection.apply(&mut injected_save) {
+                Ok(true) => Some(Ok(save)),
+                Ok(false) => None,
+                Err(e) => Some(Err(e)),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::file_helper::temp_test_dir;
+
+    use super::*;
+
+    fn copy_fixture(dir: &Path, file_name: &str) {
+        std::fs::copy(
+            Path::new("./test_files").join(file_name),
+            dir.join(file_name),
+        )
+        .expect("failed to copy fixture");
+    }
+
+    fn file_names(saves: &[Bl3Save]) -> Vec<&str> {
+        saves.iter().map(|s| s.file_name.as_str()).collect()
+    }
+
+    #[test]
+    fn test_saves_requiring_injection() {
+        let dir = temp_test_dir("saves_requiring_injection");
+
+        copy_fixture(&dir, "19.sav");
+        copy_fixture(&dir, "1.sav");
+        copy_fixture(&dir, "1prof.sav");
+        std::fs::write(dir.join("notes.txt"), b"not a save").unwrap();
+
+        let profile_path = dir.join("1prof.sav");
+        let profile = match Bl3FileType::from_unknown_data(
+            &profile_path,
+            &std::fs::read(&profile_path).unwrap(),
+        )
+        .expect("failed to read profile")
+        {
+            Bl3FileType::PcProfile(p) | Bl3FileType::Ps4Profile(p) => p,
+            _ => panic!("expected a profile"),
+        };
+
+        let mut injection = GuardianInjection::from_profile_data(&profile.profile_data);
+        injection.rank = injection.rank.wrapping_add(1);
+        injection.tokens = injection.tokens.wrapping_add(1);
+
+        let saves = saves_requiring_injection(&dir, &injection).expect("failed to find saves");
+
+        // The profile and the text file are never touched
+        assert_eq!(file_names(&saves), vec!["1.sav", "19.sav"]);
+
+        // Saves that already match the profile are left alone
+        let mut injected_save = saves[1].clone();
+
+        assert!(injection.apply(&mut injected_save).unwrap());
+        assert!(!injection.apply(&mut injected_save).unwrap());
+
+        let (output, _) = injected_save.as_bytes().unwrap();
+        std::fs::write(dir.join("19.sav"), output).unwrap();
+
+        let saves = saves_requiring_injection(&dir, &injection).expect("failed to find saves");
+
+        assert_eq!(file_names(&saves), vec!["1.sav"]);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_saves_requiring_injection_missing_dir() {
+        let dir = temp_test_dir("saves_requiring_injection_missing_dir");
+
+        let injection = GuardianInjection {
+            rank: 0,
+            tokens: 0,
+            rewards: Vec::new(),
+        };
+
+        assert!(saves_requiring_injection(&dir.join("missing"), &injection).is_err());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,215 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+
+use crate::bl3_item::Bl3Item;
+
+const ITEM_SOURCES_VERSION: u32 = 1;
+
+/// Which character each bank item was moved over from. Items are keyed by a hash of their serial
+/// so a record still matches after the bank has been sorted or reordered.
+///
+/// This is only ever kept beside the profile, it is never written into the profile itself.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct ItemSources {
+    sources: BTreeMap<u32, String>,
+}
+
+impl ItemSources {
+    pub fn item_key(item: &Bl3Item) -> Result<u32> {
+        Ok(crc32fast::hash(&item.get_serial_number(false)?))
+    }
+
+    pub fn record(&mut self, item: &Bl3Item, source: &str) -> Result<()> {
+        self.sources
+            .insert(Self::item_key(item)?, source.to_owned());
+
+        Ok(())
+    }
+
+    pub fn source_of(&self, item: &Bl3Item) -> Option<&str> {
+        let key = Self::item_key(item).ok()?;
+
+        self.sources.get(&key).map(|s| s.as_str())
+    }
+
+    pub fn len(&self) -> usize {
+        self.sources.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sources.is_empty()
+    }
+
+    /// Adds the records from `other`, where both know about an item the one from `other` is kept.
+    pub fn merge(&mut self, other: ItemSources) {
+        self.sources.extend(other.sources);
+    }
+
+    /// Forgets any items that are no longer in `items`.
+    pub fn retain_items(&mut self, items: &[Bl3Item]) {
+        let keys = items
+            .iter()
+            .filter_map(|i| Self::item_key(i).ok())
+            .collect::<Vec<_>>();
+
+        self.sources.retain(|k, _| keys.contains(k));
+    }
+
+    pub fn to_json(&self) -> String {
+        let items = self
+            .sources
+            .iter()
+            .map(|(key, source)| {
+                json::object! {
+                    serial_hash: *key,
+                    source: source.as_str(),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        json::object! {
+            version: ITEM_SOURCES_VERSION,
+            items: items,
+        }
+        .pretty(4)
+    }
+
+    pub fn from_json(data: &str) -> Result<Self> {
+        let data = json::parse(data).context("failed to parse item sources")?;
+
+        let sources = data["items"]
+            .members()
+            .map(|i| {
+                let key = i["serial_hash"]
+                    .as_u32()
+                    .context("item source was missing a serial hash")?;
+
+                let source = i["source"]
+                    .as_str()
+                    .context("item source was missing a source")?;
+
+                Ok((key, source.to_owned()))
+            })
+            .collect::<Result<BTreeMap<_, _>>>()?;
+
+        Ok(Self { sources })
+    }
+
+    /// Loads the sources kept at `path`, there are none yet if the file doesn't exist.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let data = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+
+        Self::from_json(&data)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        std::fs::write(path, self.to_json())
+            .with_context(|| format!("failed to write {}", path.display()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crate::bl3_profile::Bl3Profile;
+    use crate::parser::HeaderType;
+
+    use super::*;
+
+    fn load_bank_items() -> Vec<Bl3Item> {
+        Bl3Profile::from_bytes(
+            Path::new("./test_files/1prof.sav"),
+            include_bytes!("../test_files/1prof.sav"),
+            HeaderType::PcProfile,
+        )
+        .expect("failed to read test profile")
+        .profile_data
+        .bank_items()
+        .clone()
+    }
+
+    #[test]
+    fn test_record_survives_reordering() {
+        let mut items = load_bank_items();
+
+        let mut sources = ItemSources::default();
+        sources.record(&items[0], "Moze").unwrap();
+        sources.record(&items[1], "Zane").unwrap();
+
+        items.reverse();
+
+        let last = items.len() - 1;
+
+        assert_eq!(sources.source_of(&items[last]), Some("Moze"));
+        assert_eq!(sources.source_of(&items[last - 1]), Some("Zane"));
+        assert_eq!(sources.source_of(&items[0]), None);
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let items = load_bank_items();
+
+        let mut sources = ItemSources::default();
+        sources.record(&items[0], "FL4K").unwrap();
+        sources.record(&items[2], "Amara \"Tiger\"").unwrap();
+
+        let read = ItemSources::from_json(&sources.to_json()).unwrap();
+
+        assert_eq!(read, sources);
+        assert_eq!(read.source_of(&items[2]), Some("Amara \"Tiger\""));
+        assert!(ItemSources::from_json("not json").is_err());
+    }
+
+    #[test]
+    fn test_merge_and_retain() {
+        let items = load_bank_items();
+
+        let mut sources = ItemSources::default();
+        sources.record(&items[0], "Moze").unwrap();
+        sources.record(&items[1], "Zane").unwrap();
+
+        let mut newer = ItemSources::default();
+        newer.record(&items[1], "FL4K").unwrap();
+        newer.record(&items[2], "Amara").unwrap();
+
+        sources.merge(newer);
+
+        assert_eq!(sources.len(), 3);
+        assert_eq!(sources.source_of(&items[1]), Some("FL4K"));
+
+        sources.retain_items(&items[1..]);
+
+        assert_eq!(sources.len(), 2);
+        assert_eq!(sources.source_of(&items[0]), None);
+    }
+
+    #[test]
+    fn test_load_missing_and_save() {
+        let dir = std::env::temp_dir().join(format!("bl3_item_sources_{}", std::process::id()));
+        let path = dir.join("profile.json");
+
+        assert!(ItemSources::load(&path).unwrap().is_empty());
+
+        let items = load_bank_items();
+
+        let mut sources = ItemSources::default();
+        sources.record(&items[0], "Moze").unwrap();
+        sources.save(&path).unwrap();
+
+        assert_eq!(ItemSources::load(&path).unwrap(), sources);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,196 @@
+use anyhow::{bail, Result};
+
+use crate::bl3_item::Bl3Item;
+use crate::bl3_profile::profile_data::ProfileData;
+use crate::bl3_save::character_data::CharacterData;
+
+pub trait ItemContainer {
+    fn items(&self) -> &Vec<Bl3Item>;
+
+    fn take_item(&mut self, index: usize) -> Option<Bl3Item>;
+
+    fn put_item(&mut self, item: &Bl3Item) -> Result<()>;
+}
+
+impl ItemContainer for CharacterData {
+    fn items(&self) -> &Vec<Bl3Item> {
+        self.inventory_items()
+    }
+
+    fn take_item(&mut self, index: usize) -> Option<Bl3Item> {
+        self.take_inventory_item(index)
+    }
+
+    fn put_item(&mut self, item: &Bl3Item) -> Result<()> {
+        let pickup_order_index = self.inventory_items().len() as i32;
+
+        self.add_inventory_item(pickup_order_index, item)
+    }
+}
+
+impl ItemContainer for ProfileData {
+    fn items(&self) -> &Vec<Bl3Item> {
+        self.bank_items()
+    }
+
+    fn take_item(&mut self, index: usize) -> Option<Bl3Item> {
+        self.take_bank_item(index)
+    }
+
+    fn put_item(&mut self, item: &Bl3Item) -> Result<()> {
+        self.add_bank_item(item)
+    }
+}
+
+/// Moves the items at `indexes` from one container to the end of another, keeping their order.
+/// Nothing is moved if any of the indexes are invalid.
+pub fn transfer_items<F, T>(from: &mut F, to: &mut T, indexes: &[usize]) -> Result<usize>
+where
+    F: ItemContainer,
+    T: ItemContainer,
+{
+    let mut indexes = indexes.to_vec();
+    indexes.sort_unstable();
+    indexes.dedup();
+
+    if let Some(invalid) = indexes.iter().find(|i| **i >= from.items().len()) {
+        bail!("Could not find item number {} to transfer.", invalid + 1);
+    }
+
+    // Make sure every item can be written before we remove anything
+    for i in &indexes {
+        from.items()[*i].get_serial_number(true)?;
+    }
+
+    let mut moved = indexes
+        .iter()
+        .rev()
+        .filter_map(|i| from.take_item(*i))
+        .collect::<Vec<_>>();
+
+    moved.reverse();
+
+    for item in &moved {
+        to.put_item(item)?;
+    }
+
+    Ok(moved.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crate::bl3_profile::Bl3Profile;
+    use crate::bl3_save::inventory_slot::InventorySlot;
+    use crate::bl3_save::Bl3Save;
+    use crate::parser::HeaderType;
+
+    use super::*;
+
+    fn load_save(file_name: &str, data: &[u8]) -> Bl3Save {
+        Bl3Save::from_bytes(Path::new(file_name), data, HeaderType::PcSave)
+            .expect("failed to read test save")
+    }
+
+    #[test]
+    fn test_transfer_items_between_saves() {
+        let mut from = load_save(
+            "./test_files/19.sav",
+            include_bytes!("../test_files/19.sav"),
+        );
+        let mut to = load_save("./test_files/1.sav", include_bytes!("../test_files/1.sav"));
+
+        let from_count = from.character_data.inventory_items().len();
+        let to_count = to.character_data.inventory_items().len();
+
+        let weapon_1 = from
+            .character_data
+            .equipped_inventory_index(&InventorySlot::Weapon1)
+            .expect("no weapon equipped");
+
+        let moved_item = from.character_data.inventory_items()[weapon_1].clone();
+
+        let equipped_after = InventorySlot::Weapon2;
+        let equipped_after_index = from
+            .character_data
+            .equipped_inventory_index(&equipped_after)
+            .expect("no weapon equipped");
+        let equipped_after_item =
+            from.character_data.inventory_items()[equipped_after_index].clone();
+
+        let moved = transfer_items(
+            &mut from.character_data,
+            &mut to.character_data,
+            &[weapon_1],
+        )
+        .expect("failed to transfer items");
+
+        assert_eq!(moved, 1);
+        assert_eq!(from.character_data.inventory_items().len(), from_count - 1);
+        assert_eq!(to.character_data.inventory_items().len(), to_count + 1);
+        assert_eq!(
+            to.character_data.inventory_items().last(),
+            Some(&moved_item)
+        );
+
+        assert_eq!(
+            from.character_data
+                .equipped_inventory_index(&InventorySlot::Weapon1),
+            None
+        );
+
+        let new_equipped_index = from
+            .character_data
+            .equipped_inventory_index(&equipped_after)
+            .expect("weapon was unequipped");
+
+        assert_eq!(
+            from.character_data.inventory_items()[new_equipped_index],
+            equipped_after_item
+        );
+
+        let (_, from) = from.as_bytes().expect("failed to write save");
+        let (_, to) = to.as_bytes().expect("failed to write save");
+
+        assert_eq!(from.character_data.inventory_items().len(), from_count - 1);
+        assert_eq!(to.character_data.inventory_items().len(), to_count + 1);
+    }
+
+    #[test]
+    fn test_transfer_items_to_bank() {
+        let mut from = load_save(
+            "./test_files/19.sav",
+            include_bytes!("../test_files/19.sav"),
+        );
+        let mut to = Bl3Profile::from_bytes(
+            Path::new("./test_files/1prof.sav"),
+            include_bytes!("../test_files/1prof.sav"),
+            HeaderType::PcProfile,
+        )
+        .expect("failed to read test profile");
+
+        let from_count = from.character_data.inventory_items().len();
+        let bank_count = to.profile_data.bank_items().len();
+
+        let expected = vec![
+            from.character_data.inventory_items()[0].clone(),
+            from.character_data.inventory_items()[2].clone(),
+        ];
+
+        let moved = transfer_items(&mut from.character_data, &mut to.profile_data, &[2, 0, 2])
+            .expect("failed to transfer items");
+
+        assert_eq!(moved, 2);
+        assert_eq!(from.character_data.inventory_items().len(), from_count - 2);
+        assert_eq!(
+            to.profile_data.bank_items()[bank_count..].to_vec(),
+            expected
+        );
+
+        assert!(
+            transfer_items(&mut from.character_data, &mut to.profile_data, &[0, 10000]).is_err()
+        );
+        assert_eq!(from.character_data.inventory_items().len(), from_count - 2);
+    }
+}
@@ -3,11 +3,24 @@ pub mod arbitrary_bits;
 pub mod bl3_item;
 pub mod bl3_profile;
 pub mod bl3_save;
+pub mod build_preset;
+pub mod bulk_item_edit;
+pub mod character_report;
+pub mod content_source;
+pub mod data_overrides;
 pub mod error;
+pub mod favorite_anointments;
+pub mod file_diff;
 pub mod file_helper;
 pub mod game_data;
+pub mod guardian_injection;
+pub mod item_sources;
+pub mod item_transfer;
 pub mod models;
 pub mod parser;
 pub mod protos;
 pub mod resources;
+pub mod round_trip;
+pub mod stats_export;
+pub mod validation;
 pub mod vehicle_data;
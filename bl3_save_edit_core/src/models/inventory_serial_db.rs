@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::convert::TryInto;
 use std::io::Read;
 
@@ -6,11 +7,13 @@ use json::JsonValue;
 use rayon::iter::{ParallelBridge, ParallelIterator};
 
 use crate::bl3_item::Bl3Part;
+use crate::content_source::ContentSource;
 use crate::resources::INVENTORY_SERIAL_DB_JSON_COMPRESSED;
 
 pub struct InventorySerialDb {
     pub data: JsonValue,
     pub max_version: usize,
+    content_sources: HashMap<String, ContentSource>,
 }
 
 impl InventorySerialDb {
@@ -40,7 +43,25 @@ impl InventorySerialDb {
             .and_then(|v| v.try_into().ok())
             .context("failed to read inventory serial db max version")?;
 
-        Ok(Self { data, max_version })
+        let content_sources = data
+            .entries()
+            .flat_map(|(_, category)| category["assets"].members())
+            .filter_map(|ident| ident.as_str())
+            .filter_map(|ident| {
+                ident.rsplit('.').next().map(|short_ident| {
+                    (
+                        short_ident.to_lowercase(),
+                        ContentSource::from_asset_path(ident),
+                    )
+                })
+            })
+            .collect();
+
+        Ok(Self {
+            data,
+            max_version,
+            content_sources,
+        })
     }
 
     pub fn get_num_bits(&self, category: &str, version: usize) -> Result<usize> {
@@ -77,6 +98,13 @@ impl InventorySerialDb {
         }
     }
 
+    pub fn get_content_source(&self, short_ident: &str) -> ContentSource {
+        self.content_sources
+            .get(&short_ident.to_lowercase())
+            .copied()
+            .unwrap_or_default()
+    }
+
     pub fn get_part_by_short_name(&self, category: &str, name: &str) -> Result<Bl3Part> {
         // Make sure that when we are searching for the part we are looking for the name up to the full stop
         // Otherwise our 'contains' method could return the wrong part
@@ -107,4 +135,29 @@ impl InventorySerialDb {
             )
         }
     }
+
+    pub fn get_part_by_ident(&self, category: &str, ident: &str) -> Result<Bl3Part> {
+        let ident_lower = ident.to_lowercase();
+
+        let part_info = self.data[category]["assets"]
+            .members()
+            .enumerate()
+            .find(|(_, p)| p.to_string().to_lowercase() == ident_lower);
+
+        if let Some((idx, p)) = part_info {
+            let ident = p.to_string();
+
+            Ok(Bl3Part {
+                short_ident: ident.rsplit('.').next().map(|s| s.to_owned()),
+                ident,
+                idx: idx + 1,
+            })
+        } else {
+            bail!(
+                "Failed to find part from inventory serial db - category: {}, ident: {}",
+                category,
+                ident
+            )
+        }
+    }
 }
@@ -137,6 +137,15 @@ pub fn read_guid(i: &[u8]) -> nom::IResult<&[u8], &[u8], BL3ParserError<String>>
 }
 
 pub fn decrypt<T: protobuf::Message>(data: &[u8], header_type: &HeaderType) -> Result<T> {
+    let data = decrypt_bytes(data, header_type)?;
+
+    let result: T = protobuf::Message::parse_from_bytes(&data)?;
+
+    Ok(result)
+}
+
+/// Decrypts the protobuf payload of a file without parsing it.
+pub fn decrypt_bytes(data: &[u8], header_type: &HeaderType) -> Result<Vec<u8>> {
     let (prefix_magic, xor_magic) = match header_type {
         HeaderType::PcSave => (PC_SAVE_PREFIX_MAGIC, PC_SAVE_XOR_MAGIC),
         HeaderType::PcProfile => (PC_PROFILE_PREFIX_MAGIC, PC_PROFILE_XOR_MAGIC),
@@ -145,8 +154,8 @@ pub fn decrypt<T: protobuf::Message>(data: &[u8], header_type: &HeaderType) -> R
     };
 
     // Clone data so we can decrypt multiple times (when we don't know save type)
-    let mut data = data.to_vec();
-    let data = data.as_mut_slice();
+    let mut output = data.to_vec();
+    let data = output.as_mut_slice();
 
     for i in (0..data.len()).rev() {
         let b = if i < 32 {
@@ -168,9 +177,7 @@ pub fn decrypt<T: protobuf::Message>(data: &[u8], header_type: &HeaderType) -> R
         })?;
     }
 
-    let result: T = protobuf::Message::parse_from_bytes(data)?;
-
-    Ok(result)
+    Ok(output)
 }
 
 pub fn encrypt(data: &mut [u8], header_type: HeaderType) -> Result<()> {
@@ -7,7 +7,7 @@ use serde::de::DeserializeOwned;
 use serde::Deserialize;
 use tracing::info;
 
-use crate::bl3_item::{BalancePart, Bl3Item, InvDataPart, ManufacturerPart};
+use crate::bl3_item::{BalancePart, Bl3Item, Bl3Part, InvDataPart, ManufacturerPart};
 use crate::models::inventory_serial_db::InventorySerialDb;
 
 type InventoryPartsAll = HashMap<String, ResourceItem>;
@@ -31,6 +31,10 @@ const INVENTORY_INV_DATA_COMPRESSED: &[u8] =
 const INVENTORY_MANUFACTURER_PARTS_COMPRESSED: &[u8] =
     include_bytes!("../../resources/INVENTORY_MANUFACTURER_PARTS.ron.sz");
 
+// A PC profile with default settings and no progress, see `Bl3Profile::template`. It is
+// test_files/1prof.sav with its progress removed, `test_template_from_fixture` rebuilds it
+pub const PROFILE_TEMPLATE: &[u8] = include_bytes!("../../resources/PROFILE_TEMPLATE.sav");
+
 const LOOTLEMON_ITEMS_COMPRESSED: &[u8] = include_bytes!("../../resources/LOOTLEMON_ITEMS.ron.sz");
 
 pub static INVENTORY_SERIAL_DB: Lazy<InventorySerialDb> =
@@ -96,6 +100,21 @@ pub struct ResourceCategorizedParts {
     pub parts: Vec<ResourcePart>,
 }
 
+impl ResourceCategorizedParts {
+    pub fn find_part(&self, part: &Bl3Part) -> Option<&ResourcePart> {
+        self.parts.iter().find(|rp| rp.matches(part))
+    }
+
+    // Parts in the same category don't always agree on their limits, so the loosest is used.
+    pub fn min_parts(&self) -> u8 {
+        self.parts.iter().map(|p| p.min_parts).min().unwrap_or(0)
+    }
+
+    pub fn max_parts(&self) -> u8 {
+        self.parts.iter().map(|p| p.max_parts).max().unwrap_or(0)
+    }
+}
+
 #[derive(Debug, Default, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Deserialize)]
 pub struct ResourcePart {
     pub name: String,
@@ -106,6 +125,18 @@ pub struct ResourcePart {
     pub info: ResourcePartInfo,
 }
 
+impl ResourcePart {
+    pub fn matches(&self, part: &Bl3Part) -> bool {
+        if let Some(short_ident) = &part.short_ident {
+            self.name.eq_ignore_ascii_case(short_ident)
+        } else {
+            let name_with_stop = format!("{}.", self.name.to_lowercase());
+
+            part.ident.to_lowercase().contains(&name_with_stop)
+        }
+    }
+}
+
 #[derive(Debug, Default, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Deserialize)]
 pub struct ResourcePartInfo {
     pub positives: Option<String>,
@@ -124,3 +155,42 @@ pub struct LootlemonItem {
     pub item: Bl3Item,
     pub link: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resource_part(name: &str, min_parts: u8, max_parts: u8) -> ResourcePart {
+        ResourcePart {
+            name: name.to_owned(),
+            min_parts,
+            max_parts,
+            ..ResourcePart::default()
+        }
+    }
+
+    #[test]
+    fn test_categorized_parts_limits() {
+        let categorized_parts = ResourceCategorizedParts {
+            category: "Barrel Accessory".to_owned(),
+            parts: vec![
+                resource_part("Part_AR_COV_Barrel_Acc_01", 1, 1),
+                resource_part("Part_AR_COV_Barrel_Acc_02", 0, 3),
+            ],
+        };
+
+        assert_eq!(categorized_parts.min_parts(), 0);
+        assert_eq!(categorized_parts.max_parts(), 3);
+
+        let part = Bl3Part {
+            ident: "/Game/Gear/Part_AR_COV_Barrel_Acc_02.Part_AR_COV_Barrel_Acc_02".to_owned(),
+            short_ident: None,
+            idx: 1,
+        };
+
+        assert_eq!(
+            categorized_parts.find_part(&part).map(|p| p.name.as_str()),
+            Some("Part_AR_COV_Barrel_Acc_02")
+        );
+    }
+}
@@ -0,0 +1,257 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::{bail, Result};
+use protobuf::reflect::MessageDescriptor;
+use protobuf::Message;
+
+use crate::file_helper::{read_bytes, Bl3FileType};
+use crate::parser::{decrypt_bytes, HeaderType};
+use crate::protos::oak_profile::Profile;
+use crate::protos::oak_save::Character;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum RoundTripOutcome {
+    Faithful,
+    // The path of the first field that changed, when the payloads could be compared field by field
+    Differs(Option<String>),
+    Failed(String),
+}
+
+/// Whether reading a file and writing it back gives the same decrypted data.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct RoundTripReport {
+    pub file_name: String,
+    pub outcome: RoundTripOutcome,
+}
+
+impl RoundTripReport {
+    pub fn is_faithful(&self) -> bool {
+        self.outcome == RoundTripOutcome::Faithful
+    }
+}
+
+impl std::fmt::Display for RoundTripReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.outcome {
+            RoundTripOutcome::Faithful => write!(f, "{}: OK", self.file_name),
+            RoundTripOutcome::Differs(Some(path)) => {
+                write!(f, "{}: differs, first at {}", self.file_name, path)
+            }
+            RoundTripOutcome::Differs(None) => write!(f, "{}: differs", self.file_name),
+            RoundTripOutcome::Failed(e) => write!(f, "{}: failed, {}", self.file_name, e),
+        }
+    }
+}
+
+/// Decrypts and decodes `data`, then encodes and encrypts it again in memory and compares the
+/// decrypted payloads. Nothing is written to disk.
+pub fn verify_round_trip(file_location: &Path, data: &[u8]) -> RoundTripReport {
+    let file_name = file_location
+        .file_name()
+        .map(|f| f.to_string_lossy().to_string())
+        .unwrap_or_else(|| file_location.display().to_string());
+
+    let outcome = match round_trip_difference(file_location, data) {
+        Ok(None) => RoundTripOutcome::Faithful,
+        Ok(Some(path)) => RoundTripOutcome::Differs(path),
+        Err(e) => RoundTripOutcome::Failed(e.to_string()),
+    };
+
+    RoundTripReport { file_name, outcome }
+}
+
+/// Plain text version of the reports that can be attached to a bug report.
+pub fn round_trip_report_text(reports: &[RoundTripReport]) -> String {
+    let faithful = reports.iter().filter(|r| r.is_faithful()).count();
+
+    let mut text = format!(
+        "{} of {} files were read and written back without changes.\n\n",
+        faithful,
+        reports.len()
+    );
+
+    for report in reports {
+        let _ = writeln!(text, "{}", report);
+    }
+
+    text
+}
+
+// Ok(None) when the payloads match, otherwise Ok(Some(first differing field))
+fn round_trip_difference(file_location: &Path, data: &[u8]) -> Result<Option<Option<String>>> {
+    let (header_type, output, descriptor) =
+        match Bl3FileType::from_unknown_data(file_location, data)? {
+            Bl3FileType::PcSave(save) | Bl3FileType::Ps4Save(save) => (
+                save.header_type,
+                save.as_bytes()?.0,
+                Character::descriptor_static(),
+            ),
+            Bl3FileType::PcProfile(profile) | Bl3FileType::Ps4Profile(profile) => (
+                profile.header_type,
+                profile.as_bytes()?.0,
+                Profile::descriptor_static(),
+            ),
+        };
+
+    let before = decrypted_payload(file_location, data, &header_type)?;
+    let after = decrypted_payload(file_location, &output, &header_type)?;
+
+    if before == after {
+        Ok(None)
+    } else {
+        Ok(Some(first_difference(&before, &after, descriptor)))
+    }
+}
+
+fn decrypted_payload(
+    file_location: &Path,
+    data: &[u8],
+    header_type: &HeaderType,
+) -> Result<Vec<u8>> {
+    let file_data = read_bytes(file_location, data)?;
+
+    decrypt_bytes(file_data.remaining_data, header_type)
+}
+
+/// Path of the first top level field that isn't the same in both payloads, such as
+/// `inventory_items[3]`. None if either payload isn't valid protobuf.
+pub fn first_difference(
+    before: &[u8],
+    after: &[u8],
+    descriptor: &MessageDescriptor,
+) -> Option<String> {
+    let before = wire_fields(before).ok()?;
+    let after = wire_fields(after).ok()?;
+
+    let index = before
+        .iter()
+        .zip(&after)
+        .position(|(b, a)| b != a)
+        .unwrap_or_else(|| before.len().min(after.len()));
+
+    let fields = if index < before.len() {
+        &before
+    } else {
+        &after
+    };
+
+    let (number, _) = fields.get(index)?;
+
+    let occurrence = fields[..index].iter().filter(|(n, _)| n == number).count();
+
+    let field = descriptor
+        .fields()
+        .iter()
+        .find(|f| f.proto().get_number() == *number as i32);
+
+    let path = match field {
+        Some(field) if field.is_repeated() => format!("{}[{}]", field.name(), occurrence),
+        Some(field) => field.name().to_owned(),
+        None => format!("unknown field {}", number),
+    };
+
+    Some(path)
+}
+
+fn read_varint(data: &[u8], pos: &mut usize) -> Result<u64> {
+    let mut value = 0;
+
+    for shift in (0..64).step_by(7) {
+        let byte = match data.get(*pos) {
+            Some(byte) => *byte,
+            None => bail!("varint ran past the end of the data"),
+        };
+
+        *pos += 1;
+
+        value |= ((byte & 0x7f) as u64) << shift;
+
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+
+    bail!("varint is too long")
+}
+
+// The top level fields of a protobuf message in the order they were written, with their raw bytes
+fn wire_fields(data: &[u8]) -> Result<Vec<(u32, &[u8])>> {
+    let mut fields = Vec::new();
+    let mut pos = 0;
+
+    while pos < data.len() {
+        let start = pos;
+        let key = read_varint(data, &mut pos)?;
+
+        let len = match key & 0x7 {
+            0 => {
+                read_varint(data, &mut pos)?;
+                0
+            }
+            1 => 8,
+            2 => read_varint(data, &mut pos)? as usize,
+            5 => 4,
+            wire_type => bail!("unsupported wire type {}", wire_type),
+        };
+
+        pos = match pos.checked_add(len) {
+            Some(end) if end <= data.len() => end,
+            _ => bail!("field ran past the end of the data"),
+        };
+
+        fields.push(((key >> 3) as u32, &data[start..pos]));
+    }
+
+    Ok(fields)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    #[test]
+    fn test_first_difference() {
+        let mut character = Character::new();
+        character.preferred_character_name = "Moze".to_owned();
+        character.experience_points = 100;
+
+        let before = character
+            .write_to_bytes()
+            .expect("failed to write character");
+
+        character.experience_points = 200;
+
+        let after = character
+            .write_to_bytes()
+            .expect("failed to write character");
+
+        assert_eq!(
+            first_difference(&before, &after, Character::descriptor_static()),
+            Some("experience_points".to_owned())
+        );
+        assert_eq!(
+            first_difference(&before, &[0xff], Character::descriptor_static()),
+            None
+        );
+    }
+
+    #[test]
+    fn test_verify_round_trip() {
+        let filename = Path::new("./test_files/19.sav");
+
+        let save_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let report = verify_round_trip(filename, &save_file_data);
+
+        assert_eq!(report.file_name, "19.sav");
+        assert!(!matches!(report.outcome, RoundTripOutcome::Failed(_)));
+
+        let report = verify_round_trip(Path::new("broken.sav"), b"not a save");
+
+        assert!(matches!(report.outcome, RoundTripOutcome::Failed(_)));
+        assert!(round_trip_report_text(&[report]).starts_with("0 of 1 files"));
+    }
+}
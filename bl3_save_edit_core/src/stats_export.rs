@@ -0,0 +1,156 @@
+use crate::bl3_profile::Bl3Profile;
+use crate::bl3_save::Bl3Save;
+use crate::file_helper::FileMetadata;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Stat {
+    pub name: String,
+    pub value: i64,
+}
+
+impl Stat {
+    fn new<S: Into<String>, V: Into<i64>>(name: S, value: V) -> Self {
+        Self {
+            name: name.into(),
+            value: value.into(),
+        }
+    }
+}
+
+pub fn character_stats(save: &Bl3Save) -> Vec<Stat> {
+    let character_data = &save.character_data;
+
+    let mut stats = vec![
+        Stat::new("Level", character_data.player_level()),
+        Stat::new(
+            "Experience Points",
+            character_data.character.experience_points,
+        ),
+        Stat::new("Ability Points", character_data.ability_points()),
+    ];
+
+    stats.extend(
+        character_data
+            .sdu_slots()
+            .iter()
+            .map(|s| Stat::new(format!("{} SDU", s.sdu), s.current)),
+    );
+
+    stats.extend(
+        character_data
+            .ammo_pools()
+            .iter()
+            .map(|a| Stat::new(format!("{} Ammo", a.pool), a.current)),
+    );
+
+    stats.extend(file_stats(&save.metadata()));
+
+    stats
+}
+
+fn file_stats(metadata: &FileMetadata) -> Vec<Stat> {
+    vec![
+        Stat::new("Save Game Version", metadata.save_game_version),
+        Stat::new("Package Version", metadata.package_version),
+        Stat::new("File Size (bytes)", metadata.file_size as i64),
+    ]
+}
+
+pub fn currency_stats(save: &Bl3Save) -> Vec<Stat> {
+    vec![
+        Stat::new("Money", save.character_data.money()),
+        Stat::new("Eridium", save.character_data.eridium()),
+    ]
+}
+
+pub fn guardian_reward_stats(profile: &Bl3Profile) -> Vec<Stat> {
+    let mut stats = vec![Stat::new(
+        "Guardian Rank Tokens",
+        profile.profile_data.guardian_tokens(),
+    )];
+
+    stats.extend(
+        profile
+            .profile_data
+            .guardian_rewards()
+            .iter()
+            .map(|r| Stat::new(r.reward.to_string(), r.current)),
+    );
+
+    stats
+}
+
+/// Two columns, Name and Value, ready to open in a spreadsheet.
+pub fn to_csv(stats: &[Stat]) -> String {
+    let mut csv = "Name,Value\n".to_owned();
+
+    for stat in stats {
+        csv.push_str(&format!(
+            "{},{}\n",
+            escape_csv_field(&stat.name),
+            stat.value
+        ));
+    }
+
+    csv
+}
+
+fn escape_csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crate::parser::HeaderType;
+
+    use super::*;
+
+    #[test]
+    fn test_to_csv() {
+        let stats = vec![
+            Stat::new("Level", 72),
+            Stat::new("Money, Total", 1_000),
+            Stat::new("\"Quoted\"", -1),
+        ];
+
+        assert_eq!(
+            to_csv(&stats),
+            "Name,Value\nLevel,72\n\"Money, Total\",1000\n\"\"\"Quoted\"\"\",-1\n"
+        );
+        assert_eq!(to_csv(&[]), "Name,Value\n");
+    }
+
+    #[test]
+    fn test_character_stats() {
+        let save = Bl3Save::from_bytes(
+            Path::new("./test_files/19.sav"),
+            include_bytes!("../test_files/19.sav"),
+            HeaderType::PcSave,
+        )
+        .expect("failed to read test save");
+
+        let stats = character_stats(&save);
+
+        assert_eq!(
+            stats[0],
+            Stat::new("Level", save.character_data.player_level())
+        );
+        assert!(stats.iter().any(|s| s.name == "Backpack SDU"));
+        assert!(stats.iter().any(|s| s.name == "Pistol Ammo"));
+        assert_eq!(
+            stats.last(),
+            Some(&Stat::new("File Size (bytes)", save.file_size as i64))
+        );
+
+        assert_eq!(
+            currency_stats(&save)[0],
+            Stat::new("Money", save.character_data.money())
+        );
+    }
+}
@@ -0,0 +1,995 @@
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use strum::Display;
+
+use crate::bl3_item::{Bl3Item, ItemType};
+use crate::bl3_profile::Bl3Profile;
+use crate::bl3_save::Bl3Save;
+use crate::resources::INVENTORY_PARTS_ALL_CATEGORIZED;
+
+#[derive(Debug, Display, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+#[derive(Debug, Display, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub enum FindingKind {
+    Currency,
+    Ammo,
+    #[strum(to_string = "SDU")]
+    Sdu,
+    #[strum(to_string = "Unknown Item")]
+    UnknownItem,
+    #[strum(to_string = "Guardian Tokens")]
+    GuardianTokens,
+    #[strum(to_string = "Equipped Item")]
+    EquippedItem,
+    #[strum(to_string = "Duplicate Slot")]
+    DuplicateSlot,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Finding {
+    pub severity: Severity,
+    pub kind: FindingKind,
+    pub message: String,
+}
+
+impl Finding {
+    fn warning<S: Into<String>>(kind: FindingKind, message: S) -> Self {
+        Finding {
+            severity: Severity::Warning,
+            kind,
+            message: message.into(),
+        }
+    }
+
+    fn error<S: Into<String>>(kind: FindingKind, message: S) -> Self {
+        Finding {
+            severity: Severity::Error,
+            kind,
+            message: message.into(),
+        }
+    }
+}
+
+pub type SaveRule = fn(&Bl3Save) -> Vec<Finding>;
+
+pub type ProfileRule = fn(&Bl3Profile) -> Vec<Finding>;
+
+// New checks only need an entry here to be run before saving.
+pub const SAVE_RULES: &[SaveRule] = &[
+    save_currency,
+    save_ammo,
+    save_sdu,
+    save_unknown_items,
+    save_guardian_tokens,
+    save_equipped_items,
+    save_duplicate_equipped_slots,
+];
+
+pub const PROFILE_RULES: &[ProfileRule] = &[
+    profile_currency,
+    profile_sdu,
+    profile_unknown_items,
+    profile_guardian_tokens,
+];
+
+/// Runs every rule in `SAVE_RULES`, errors are listed first.
+pub fn validate_save(save: &Bl3Save) -> Vec<Finding> {
+    sorted_findings(SAVE_RULES.iter().flat_map(|rule| rule(save)))
+}
+
+/// Runs every rule in `PROFILE_RULES`, errors are listed first.
+pub fn validate_profile(profile: &Bl3Profile) -> Vec<Finding> {
+    sorted_findings(PROFILE_RULES.iter().flat_map(|rule| rule(profile)))
+}
+
+pub fn has_errors(findings: &[Finding]) -> bool {
+    findings.iter().any(|f| f.severity == Severity::Error)
+}
+
+fn sorted_findings<I: Iterator<Item = Finding>>(findings: I) -> Vec<Finding> {
+    let mut findings = findings.collect::<Vec<_>>();
+
+    findings.sort_by_key(|f| Reverse(f.severity));
+
+    findings
+}
+
+fn reward_name(reward_data_path: &str) -> &str {
+    reward_data_path
+        .rsplit('.')
+        .next()
+        .unwrap_or(reward_data_path)
+}
+
+fn item_label(index: usize, item: &Bl3Item) -> String {
+    let balance_part = item.balance_part();
+
+    let name = balance_part.name.clone().unwrap_or_else(|| {
+        balance_part
+            .short_ident
+            .clone()
+            .unwrap_or_else(|| balance_part.ident.clone())
+    });
+
+    format!("Item {} ({})", index + 1, name)
+}
+
+fn currency_findings(name: &str, amount: i32, max: i32) -> Option<Finding> {
+    if amount < 0 {
+        Some(Finding::error(
+            FindingKind::Currency,
+            format!("{} is negative ({}).", name, amount),
+        ))
+    } else if amount > max {
+        Some(Finding::warning(
+            FindingKind::Currency,
+            format!("{} ({}) is above the maximum of {}.", name, amount, max),
+        ))
+    } else {
+        None
+    }
+}
+
+fn sdu_findings<S: std::fmt::Display>(sdu: &S, current: i32, max: i32) -> Option<Finding> {
+    if current > max || current < 0 {
+        Some(Finding::warning(
+            FindingKind::Sdu,
+            format!("{} SDU level {} is outside of 0 to {}.", sdu, current, max),
+        ))
+    } else {
+        None
+    }
+}
+
+fn unknown_item_findings(items: &[Bl3Item]) -> Vec<Finding> {
+    items
+        .iter()
+        .enumerate()
+        .filter(|(_, item)| {
+            let balance_part = item.balance_part();
+
+            // Currency and other pickups aren't gear so they are never in the parts data
+            item.item_type != ItemType::Other
+                && balance_part.name.is_none()
+                && balance_part
+                    .short_ident
+                    .as_ref()
+                    .and_then(|s| INVENTORY_PARTS_ALL_CATEGORIZED.get(s))
+                    .is_none()
+        })
+        .map(|(i, item)| {
+            Finding::warning(
+                FindingKind::UnknownItem,
+                format!(
+                    "{} has a balance this editor doesn't recognise, its parts can't be checked.",
+                    item_label(i, item)
+                ),
+            )
+        })
+        .collect()
+}
+
+fn guardian_token_findings<I>(available: i32, rewards: I) -> Vec<Finding>
+where
+    I: IntoIterator<Item = (String, i32)>,
+{
+    let mut findings = Vec::new();
+
+    if available < 0 {
+        findings.push(Finding::error(
+            FindingKind::GuardianTokens,
+            format!("Available guardian tokens are negative ({}).", available),
+        ));
+    }
+
+    findings.extend(rewards.into_iter().filter(|(_, tokens)| *tokens < 0).map(
+        |(reward, tokens)| {
+            Finding::error(
+                FindingKind::GuardianTokens,
+                format!(
+                    "{} has a negative number of guardian tokens ({}).",
+                    reward, tokens
+                ),
+            )
+        },
+    ));
+
+    findings
+}
+
+fn save_currency(save: &Bl3Save) -> Vec<Finding> {
+    let character_data = &save.character_data;
+
+    vec![
+        currency_findings("Money", character_data.money(), i32::MAX),
+        currency_findings("Eridium", character_data.eridium(), i32::MAX),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+fn save_ammo(save: &Bl3Save) -> Vec<Finding> {
+    save.character_data
+        .ammo_pools()
+        .iter()
+        .filter_map(|a| {
+            if a.current < 0 {
+                Some(Finding::error(
+                    FindingKind::Ammo,
+                    format!("{} ammo is negative ({}).", a.pool, a.current),
+                ))
+            } else if a.current > a.max {
+                Some(Finding::warning(
+                    FindingKind::Ammo,
+                    format!(
+                        "{} ammo ({}) is above the capacity of a fully upgraded SDU ({}).",
+                        a.pool, a.current, a.max
+                    ),
+                ))
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+fn save_sdu(save: &Bl3Save) -> Vec<Finding> {
+    save.character_data
+        .sdu_slots()
+        .iter()
+        .filter_map(|s| sdu_findings(&s.sdu, s.current, s.max))
+        .collect()
+}
+
+fn save_unknown_items(save: &Bl3Save) -> Vec<Finding> {
+    unknown_item_findings(save.character_data.inventory_items())
+}
+
+fn save_guardian_tokens(save: &Bl3Save) -> Vec<Finding> {
+    let guardian_data = match save
+        .character_data
+        .character
+        .guardian_rank_character_data
+        .as_ref()
+    {
+        Some(guardian_data) => guardian_data,
+        None => return Vec::new(),
+    };
+
+    let mut findings = guardian_token_findings(
+        guardian_data.guardian_available_tokens,
+        guardian_data
+            .rank_rewards
+            .iter()
+            .map(|r| (reward_name(&r.reward_data_path).to_owned(), r.num_tokens)),
+    );
+
+    if guardian_data.guardian_rank != save.character_data.guardian_rank() {
+        findings.push(Finding::warning(
+            FindingKind::GuardianTokens,
+            format!(
+                "Guardian rank is {} in one part of the save and {} in another.",
+                save.character_data.guardian_rank(),
+                guardian_data.guardian_rank
+            ),
+        ));
+    }
+
+    findings
+}
+
+fn save_equipped_items(save: &Bl3Save) -> Vec<Finding> {
+    let items = save.character_data.inventory_items();
+
+    let mut slots_by_item = BTreeMap::<usize, Vec<&str>>::new();
+    let mut findings = Vec::new();
+
+    for slot in &save.character_data.character.equipped_inventory_list {
+        let slot_name = slot
+            .slot_data_path
+            .rsplit('.')
+            .next()
+            .unwrap_or(&slot.slot_data_path);
+
+        // Empty slots are stored as -1
+        let index = match usize::try_from(slot.inventory_list_index) {
+            Ok(index) => index,
+            Err(_) => continue,
+        };
+
+        if index >= items.len() {
+            findings.push(Finding::error(
+                FindingKind::EquippedItem,
+                format!(
+                    "{} has item {} equipped but the inventory only has {} items.",
+                    slot_name,
+                    index + 1,
+                    items.len()
+                ),
+            ));
+        } else {
+            slots_by_item.entry(index).or_default().push(slot_name);
+        }
+    }
+
+    findings.extend(
+        slots_by_item
+            .into_iter()
+            .filter(|(_, slots)| slots.len() > 1)
+            .map(|(index, slots)| {
+                Finding::warning(
+                    FindingKind::EquippedItem,
+                    format!(
+                        "{} is equipped in more than one slot: {}.",
+                        item_label(index, &items[index]),
+                        slots.join(", ")
+                    ),
+                )
+            }),
+    );
+
+    findings
+}
+
+fn save_duplicate_equipped_slots(save: &Bl3Save) -> Vec<Finding> {
+    let mut slot_counts = BTreeMap::<&str, usize>::new();
+
+    for slot in &save.character_data.character.equipped_inventory_list {
+        *slot_counts.entry(&slot.slot_data_path).or_default() += 1;
+    }
+
+    slot_counts
+        .into_iter()
+        .filter(|(_, count)| *count > 1)
+        .map(|(slot_path, count)| {
+            Finding::warning(
+                FindingKind::DuplicateSlot,
+                format!(
+                    "{} is listed {} times in the equipped slots.",
+                    slot_path.rsplit('.').next().unwrap_or(slot_path),
+                    count
+                ),
+            )
+        })
+        .collect()
+}
+
+fn profile_currency(profile: &Bl3Profile) -> Vec<Finding> {
+    profile
+        .profile_data
+        .currencies()
+        .iter()
+        .filter_map(|c| currency_findings(&c.name(), c.current, c.max()))
+        .collect()
+}
+
+fn profile_sdu(profile: &Bl3Profile) -> Vec<Finding> {
+    profile
+        .profile_data
+        .sdu_slots()
+        .iter()
+        .filter_map(|s| sdu_findings(&s.sdu, s.current, s.max))
+        .collect()
+}
+
+fn profile_unknown_items(profile: &Bl3Profile) -> Vec<Finding> {
+    unknown_item_findings(profile.profile_data.bank_items())
+}
+
+fn profile_guardian_tokens(profile: &Bl3Profile) -> Vec<Finding> {
+    let profile_data = &profile.profile_data;
+
+    guardian_token_findings(
+        profile_data.guardian_tokens(),
+        profile_data
+            .guardian_rewards()
+            .iter()
+            .map(|r| (r.reward.to_string(), r.current)),
+    )
+}
+
+/// A field that was negative when the file was loaded and has been set to 0.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Adjustment {
+    pub kind: FindingKind,
+    pub field: String,
+    pub from: i32,
+}
+
+impl std::fmt::Display for Adjustment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} was {}, set to 0", self.field, self.from)
+    }
+}
+
+fn negative_adjustment<S: Into<String>>(
+    kind: FindingKind,
+    field: S,
+    value: i32,
+) -> Option<Adjustment> {
+    if value < 0 {
+        Some(Adjustment {
+            kind,
+            field: field.into(),
+            from: value,
+        })
+    } else {
+        None
+    }
+}
+
+/// Sets every field that can't be negative in game (currency, ammo, SDUs and guardian tokens)
+/// to 0 if it is, returning what was changed.
+pub fn sanitize_save(save: &mut Bl3Save) -> Result<Vec<Adjustment>> {
+    let mut adjustments = Vec::new();
+
+    adjustments.extend(sanitize_save_currency(save)?);
+    adjustments.extend(sanitize_save_ammo(save)?);
+    adjustments.extend(sanitize_save_sdu(save));
+    adjustments.extend(sanitize_save_guardian_tokens(save));
+
+    Ok(adjustments)
+}
+
+/// Profile version of `sanitize_save`.
+pub fn sanitize_profile(profile: &mut Bl3Profile) -> Result<Vec<Adjustment>> {
+    let mut adjustments = Vec::new();
+
+    adjustments.extend(sanitize_profile_currency(profile));
+    adjustments.extend(sanitize_profile_sdu(profile));
+    adjustments.extend(sanitize_profile_guardian_tokens(profile)?);
+
+    Ok(adjustments)
+}
+
+fn sanitize_save_currency(save: &mut Bl3Save) -> Result<Vec<Adjustment>> {
+    let character_data = &mut save.character_data;
+
+    let money = negative_adjustment(FindingKind::Currency, "Money", character_data.money());
+    let eridium = negative_adjustment(FindingKind::Currency, "Eridium", character_data.eridium());
+
+    if money.is_some() {
+        character_data.set_money(0)?;
+    }
+
+    if eridium.is_some() {
+        character_data.set_eridium(0)?;
+    }
+
+    Ok(money.into_iter().chain(eridium).collect())
+}
+
+fn sanitize_save_ammo(save: &mut Bl3Save) -> Result<Vec<Adjustment>> {
+    let negative_pools = save
+        .character_data
+        .ammo_pools()
+        .iter()
+        .filter(|a| a.current < 0)
+        .cloned()
+        .collect::<Vec<_>>();
+
+    let mut adjustments = Vec::new();
+
+    for a in negative_pools {
+        save.character_data.set_ammo_pool(&a.pool, 0)?;
+
+        adjustments.extend(negative_adjustment(
+            FindingKind::Ammo,
+            format!("{} ammo", a.pool),
+            a.current,
+        ));
+    }
+
+    Ok(adjustments)
+}
+
+fn sanitize_save_sdu(save: &mut Bl3Save) -> Vec<Adjustment> {
+    let negative_slots = save
+        .character_data
+        .sdu_slots()
+        .iter()
+        .filter(|s| s.current < 0)
+        .cloned()
+        .collect::<Vec<_>>();
+
+    negative_slots
+        .into_iter()
+        .filter_map(|s| {
+            save.character_data.set_sdu_slot(&s.sdu, 0);
+
+            negative_adjustment(FindingKind::Sdu, format!("{} SDU", s.sdu), s.current)
+        })
+        .collect()
+}
+
+fn sanitize_save_guardian_tokens(save: &mut Bl3Save) -> Vec<Adjustment> {
+    let guardian_data = match save
+        .character_data
+        .character
+        .guardian_rank_character_data
+        .as_mut()
+    {
+        Some(guardian_data) => guardian_data,
+        None => return Vec::new(),
+    };
+
+    let mut adjustments = Vec::new();
+
+    if let Some(adjustment) = negative_adjustment(
+        FindingKind::GuardianTokens,
+        "Available guardian tokens",
+        guardian_data.guardian_available_tokens,
+    ) {
+        guardian_data.guardian_available_tokens = 0;
+        adjustments.push(adjustment);
+    }
+
+    for reward in guardian_data.rank_rewards.iter_mut() {
+        if let Some(adjustment) = negative_adjustment(
+            FindingKind::GuardianTokens,
+            format!("{} tokens", reward_name(&reward.reward_data_path)),
+            reward.num_tokens,
+        ) {
+            reward.num_tokens = 0;
+            adjustments.push(adjustment);
+        }
+    }
+
+    adjustments
+}
+
+fn sanitize_profile_currency(profile: &mut Bl3Profile) -> Vec<Adjustment> {
+    let negative_currencies = profile
+        .profile_data
+        .currencies()
+        .iter()
+        .filter(|c| c.current < 0)
+        .map(|c| (c.hash, c.name(), c.current))
+        .collect::<Vec<_>>();
+
+    negative_currencies
+        .into_iter()
+        .filter_map(|(hash, name, current)| {
+            profile.profile_data.set_currency_by_hash(hash, 0);
+
+            negative_adjustment(FindingKind::Currency, name, current)
+        })
+        .collect()
+}
+
+fn sanitize_profile_sdu(profile: &mut Bl3Profile) -> Vec<Adjustment> {
+    let negative_slots = profile
+        .profile_data
+        .sdu_slots()
+        .iter()
+        .filter(|s| s.current < 0)
+        .cloned()
+        .collect::<Vec<_>>();
+
+    negative_slots
+        .into_iter()
+        .filter_map(|s| {
+            profile.profile_data.set_sdu_slot(&s.sdu, 0);
+
+            negative_adjustment(FindingKind::Sdu, format!("{} SDU", s.sdu), s.current)
+        })
+        .collect()
+}
+
+fn sanitize_profile_guardian_tokens(profile: &mut Bl3Profile) -> Result<Vec<Adjustment>> {
+    let profile_data = &mut profile.profile_data;
+
+    let mut adjustments = Vec::new();
+
+    if let Some(adjustment) = negative_adjustment(
+        FindingKind::GuardianTokens,
+        "Available guardian tokens",
+        profile_data.guardian_tokens(),
+    ) {
+        profile_data.set_guardian_rank(profile_data.guardian_rank(), Some(0));
+        adjustments.push(adjustment);
+    }
+
+    let negative_rewards = profile_data
+        .guardian_rewards()
+        .iter()
+        .filter(|r| r.current < 0)
+        .cloned()
+        .collect::<Vec<_>>();
+
+    for r in negative_rewards {
+        profile_data.set_guardian_reward(&r.reward, 0)?;
+
+        adjustments.extend(negative_adjustment(
+            FindingKind::GuardianTokens,
+            format!("{} tokens", r.reward),
+            r.current,
+        ));
+    }
+
+    Ok(adjustments)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crate::bl3_save::ammo::AmmoPool;
+    use crate::bl3_save::sdu::SaveSduSlot;
+    use crate::parser::HeaderType;
+
+    use super::*;
+
+    fn load_save() -> Bl3Save {
+        Bl3Save::from_bytes(
+            Path::new("./test_files/19.sav"),
+            include_bytes!("../test_files/19.sav"),
+            HeaderType::PcSave,
+        )
+        .expect("failed to read test save")
+    }
+
+    fn load_profile() -> Bl3Profile {
+        Bl3Profile::from_bytes(
+            Path::new("./test_files/1prof.sav"),
+            include_bytes!("../test_files/1prof.sav"),
+            HeaderType::PcProfile,
+        )
+        .expect("failed to read test profile")
+    }
+
+    fn kinds(findings: &[Finding]) -> Vec<(Severity, FindingKind)> {
+        findings.iter().map(|f| (f.severity, f.kind)).collect()
+    }
+
+    #[test]
+    fn test_unmodified_files_have_no_errors() {
+        assert!(!has_errors(&validate_save(&load_save())));
+        assert!(!has_errors(&validate_profile(&load_profile())));
+    }
+
+    #[test]
+    fn test_errors_are_listed_first() {
+        let mut save = load_save();
+
+        save.character_data.set_money(-1).unwrap();
+        save.character_data
+            .set_sdu_slot(&SaveSduSlot::Backpack, SaveSduSlot::Backpack.maximum() + 1);
+
+        let findings = validate_save(&save);
+
+        assert!(has_errors(&findings));
+        assert_eq!(findings[0].severity, Severity::Error);
+    }
+
+    #[test]
+    fn test_save_currency() {
+        let mut save = load_save();
+
+        assert!(save_currency(&save).is_empty());
+
+        save.character_data.set_eridium(-5).unwrap();
+
+        assert_eq!(
+            kinds(&save_currency(&save)),
+            vec![(Severity::Error, FindingKind::Currency)]
+        );
+    }
+
+    #[test]
+    fn test_save_ammo() {
+        let mut save = load_save();
+
+        save.character_data
+            .set_ammo_pool(&AmmoPool::Grenade, AmmoPool::Grenade.maximum())
+            .unwrap();
+
+        assert!(save_ammo(&save).is_empty());
+
+        save.character_data
+            .set_ammo_pool(&AmmoPool::Grenade, AmmoPool::Grenade.maximum() + 1)
+            .unwrap();
+
+        assert_eq!(
+            kinds(&save_ammo(&save)),
+            vec![(Severity::Warning, FindingKind::Ammo)]
+        );
+
+        save.character_data
+            .set_ammo_pool(&AmmoPool::Grenade, -1)
+            .unwrap();
+
+        assert_eq!(
+            kinds(&save_ammo(&save)),
+            vec![(Severity::Error, FindingKind::Ammo)]
+        );
+    }
+
+    #[test]
+    fn test_save_sdu() {
+        let mut save = load_save();
+
+        save.character_data
+            .set_sdu_slot(&SaveSduSlot::Heavy, SaveSduSlot::Heavy.maximum());
+
+        assert!(save_sdu(&save).is_empty());
+
+        save.character_data
+            .set_sdu_slot(&SaveSduSlot::Heavy, SaveSduSlot::Heavy.maximum() + 1);
+
+        assert_eq!(
+            kinds(&save_sdu(&save)),
+            vec![(Severity::Warning, FindingKind::Sdu)]
+        );
+    }
+
+    #[test]
+    fn test_save_unknown_items() {
+        let save = load_save();
+
+        assert!(save_unknown_items(&save).is_empty());
+        let mut unknown_item = Bl3Item::default();
+        unknown_item.item_type = ItemType::Weapon;
+
+        assert_eq!(
+            kinds(&unknown_item_findings(&[unknown_item])),
+            vec![(Severity::Warning, FindingKind::UnknownItem)]
+        );
+    }
+
+    #[test]
+    fn test_save_guardian_tokens() {
+        let mut save = load_save();
+
+        assert!(save_guardian_tokens(&save).is_empty());
+
+        let rank = save.character_data.guardian_rank();
+
+        save.character_data.set_guardian_rank(rank, Some(-1));
+
+        assert_eq!(
+            kinds(&save_guardian_tokens(&save)),
+            vec![(Severity::Error, FindingKind::GuardianTokens)]
+        );
+
+        save.character_data.set_guardian_rank(rank, Some(0));
+        save.character_data
+            .character
+            .guardian_rank_character_data
+            .as_mut()
+            .unwrap()
+            .guardian_rank = rank + 1;
+
+        assert_eq!(
+            kinds(&save_guardian_tokens(&save)),
+            vec![(Severity::Warning, FindingKind::GuardianTokens)]
+        );
+    }
+
+    #[test]
+    fn test_save_equipped_items() {
+        let mut save = load_save();
+
+        assert!(save_equipped_items(&save).is_empty());
+
+        let slots = save
+            .character_data
+            .character
+            .equipped_inventory_list
+            .iter()
+            .filter(|s| s.inventory_list_index >= 0)
+            .count();
+
+        assert!(slots >= 2);
+
+        save.character_data.character.equipped_inventory_list[1].inventory_list_index =
+            save.character_data.character.equipped_inventory_list[0].inventory_list_index;
+
+        assert_eq!(
+            kinds(&save_equipped_items(&save)),
+            vec![(Severity::Warning, FindingKind::EquippedItem)]
+        );
+
+        save.character_data.character.equipped_inventory_list[0].inventory_list_index =
+            save.character_data.inventory_items().len() as i32;
+
+        assert!(has_errors(&save_equipped_items(&save)));
+    }
+
+    #[test]
+    fn test_save_duplicate_equipped_slots() {
+        let mut save = load_save();
+
+        assert!(save_duplicate_equipped_slots(&save).is_empty());
+
+        let duplicate_slot = save.character_data.character.equipped_inventory_list[0].clone();
+
+        save.character_data
+            .character
+            .equipped_inventory_list
+            .push(duplicate_slot);
+
+        assert_eq!(
+            kinds(&save_duplicate_equipped_slots(&save)),
+            vec![(Severity::Warning, FindingKind::DuplicateSlot)]
+        );
+    }
+
+    #[test]
+    fn test_profile_currency() {
+        let mut profile = load_profile();
+
+        assert!(profile_currency(&profile).is_empty());
+
+        profile
+            .profile_data
+            .set_currency(&crate::bl3_profile::profile_currency::GOLDEN_KEY, -1)
+            .unwrap();
+
+        assert_eq!(
+            kinds(&profile_currency(&profile)),
+            vec![(Severity::Error, FindingKind::Currency)]
+        );
+    }
+
+    #[test]
+    fn test_profile_sdu() {
+        let mut profile = load_profile();
+
+        assert!(profile_sdu(&profile).is_empty());
+
+        let sdu = profile.profile_data.sdu_slots()[0].sdu.clone();
+
+        profile.profile_data.set_sdu_slot(&sdu, sdu.maximum() + 1);
+
+        assert_eq!(
+            kinds(&profile_sdu(&profile)),
+            vec![(Severity::Warning, FindingKind::Sdu)]
+        );
+    }
+
+    #[test]
+    fn test_profile_items() {
+        let profile = load_profile();
+
+        assert!(profile_unknown_items(&profile).is_empty());
+    }
+
+    #[test]
+    fn test_profile_guardian_tokens() {
+        let mut profile = load_profile();
+
+        assert!(profile_guardian_tokens(&profile).is_empty());
+
+        let reward = profile.profile_data.guardian_rewards()[0].reward.clone();
+
+        profile
+            .profile_data
+            .set_guardian_reward(&reward, -1)
+            .unwrap();
+
+        assert_eq!(
+            kinds(&profile_guardian_tokens(&profile)),
+            vec![(Severity::Error, FindingKind::GuardianTokens)]
+        );
+
+        let rank = profile.profile_data.guardian_rank();
+
+        profile.profile_data.set_guardian_rank(rank, Some(-1));
+
+        assert_eq!(profile_guardian_tokens(&profile).len(), 2);
+    }
+
+    #[test]
+    fn test_sanitize_unmodified_files() {
+        assert!(sanitize_save(&mut load_save()).unwrap().is_empty());
+        assert!(sanitize_profile(&mut load_profile()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_sanitize_save_currency() {
+        let mut save = load_save();
+
+        save.character_data.set_money(-10).unwrap();
+
+        let adjustments = sanitize_save(&mut save).unwrap();
+
+        assert_eq!(
+            adjustments,
+            vec![Adjustment {
+                kind: FindingKind::Currency,
+                field: "Money".to_owned(),
+                from: -10,
+            }]
+        );
+        assert_eq!(save.character_data.money(), 0);
+        assert!(save_currency(&save).is_empty());
+    }
+
+    #[test]
+    fn test_sanitize_save_ammo() {
+        let mut save = load_save();
+
+        save.character_data
+            .set_ammo_pool(&AmmoPool::Sniper, -3)
+            .unwrap();
+
+        assert_eq!(sanitize_save(&mut save).unwrap().len(), 1);
+        assert!(save_ammo(&save).is_empty());
+    }
+
+    #[test]
+    fn test_sanitize_save_sdu() {
+        let mut save = load_save();
+
+        save.character_data.set_sdu_slot(&SaveSduSlot::Pistol, -1);
+
+        assert_eq!(
+            sanitize_save(&mut save).unwrap()[0].to_string(),
+            "Pistol SDU was -1, set to 0"
+        );
+        assert!(save_sdu(&save).is_empty());
+    }
+
+    #[test]
+    fn test_sanitize_save_guardian_tokens() {
+        let mut save = load_save();
+
+        let rank = save.character_data.guardian_rank();
+
+        save.character_data.set_guardian_rank(rank, Some(-2));
+
+        assert_eq!(sanitize_save(&mut save).unwrap().len(), 1);
+        assert!(save_guardian_tokens(&save).is_empty());
+    }
+
+    #[test]
+    fn test_sanitize_profile_currency() {
+        let mut profile = load_profile();
+
+        profile
+            .profile_data
+            .set_currency(&crate::bl3_profile::profile_currency::GOLDEN_KEY, -1)
+            .unwrap();
+
+        assert_eq!(sanitize_profile(&mut profile).unwrap().len(), 1);
+        assert!(profile_currency(&profile).is_empty());
+    }
+
+    #[test]
+    fn test_sanitize_profile_sdu() {
+        let mut profile = load_profile();
+
+        let sdu = profile.profile_data.sdu_slots()[0].sdu.clone();
+
+        profile.profile_data.set_sdu_slot(&sdu, -4);
+
+        assert_eq!(sanitize_profile(&mut profile).unwrap().len(), 1);
+        assert!(profile_sdu(&profile).is_empty());
+    }
+
+    #[test]
+    fn test_sanitize_profile_guardian_tokens() {
+        let mut profile = load_profile();
+
+        let reward = profile.profile_data.guardian_rewards()[0].reward.clone();
+        let rank = profile.profile_data.guardian_rank();
+
+        profile
+            .profile_data
+            .set_guardian_reward(&reward, -1)
+            .unwrap();
+        profile.profile_data.set_guardian_rank(rank, Some(-1));
+
+        assert_eq!(sanitize_profile(&mut profile).unwrap().len(), 2);
+        assert!(profile_guardian_tokens(&profile).is_empty());
+        assert_eq!(profile.profile_data.guardian_rank(), rank);
+    }
+}
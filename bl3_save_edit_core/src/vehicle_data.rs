@@ -1,3 +1,5 @@
+use std::fmt;
+
 use strum::Display;
 
 use crate::game_data::{
@@ -106,3 +108,272 @@ impl VehicleType {
         }
     }
 }
+
+// Loadouts point at the Catch-A-Ride menu assets rather than the unlocked parts, so the paths are
+// worked out from the unlock names. Wheels that don't follow the naming are listed here.
+const LOADOUT_WHEEL_NAMES: [(&str, &str); 1] = [("WT_Outrunner_BuggyWheels", "DuneBuggy")];
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Display, Default)]
+pub enum Vehicle {
+    #[default]
+    Outrunner,
+    Jetbeast,
+    Technical,
+    Cyclone,
+}
+
+impl Vehicle {
+    pub const ALL: [Vehicle; 4] = [
+        Vehicle::Outrunner,
+        Vehicle::Jetbeast,
+        Vehicle::Technical,
+        Vehicle::Cyclone,
+    ];
+
+    // The name the game uses for the vehicle in asset paths
+    fn asset_name(&self) -> &'static str {
+        match self {
+            Vehicle::Outrunner => "Outrunner",
+            Vehicle::Jetbeast => "Horse",
+            Vehicle::Technical => "Technical",
+            Vehicle::Cyclone => "Revolver",
+        }
+    }
+
+    fn loadout_dir(&self) -> &'static str {
+        match self {
+            Vehicle::Jetbeast => "/Geranium/UI/CatchARideMenu/VehiclesAndParts",
+            _ => "/Game/UI/CatchARideMenu/VehicleAndParts",
+        }
+    }
+
+    fn loadout_asset_path(&self, asset: &str) -> String {
+        format!(
+            "{}/{}/{}.{}",
+            self.loadout_dir(),
+            self.asset_name(),
+            asset,
+            asset
+        )
+    }
+
+    pub fn body_asset_path(&self) -> String {
+        let asset = format!("CAR_Vehicle_{}", self.asset_name());
+
+        format!("{}/{}.{}", self.loadout_dir(), asset, asset)
+    }
+
+    pub fn vehicle_type(&self, sub_type: VehicleSubType) -> VehicleType {
+        match self {
+            Vehicle::Outrunner => VehicleType::Outrunner(sub_type),
+            Vehicle::Jetbeast => VehicleType::Jetbeast(sub_type),
+            Vehicle::Technical => VehicleType::Technical(sub_type),
+            Vehicle::Cyclone => VehicleType::Cyclone(sub_type),
+        }
+    }
+
+    pub fn chassis_type(&self) -> VehicleType {
+        match self {
+            Vehicle::Outrunner => VehicleType::Outrunner(VehicleSubType::Chassis),
+            Vehicle::Jetbeast => VehicleType::Jetbeast(VehicleSubType::Chassis),
+            Vehicle::Technical => VehicleType::Technical(VehicleSubType::Chassis),
+            Vehicle::Cyclone => VehicleType::Cyclone(VehicleSubType::Chassis),
+        }
+    }
+
+    pub fn skins_type(&self) -> VehicleType {
+        match self {
+            Vehicle::Outrunner => VehicleType::Outrunner(VehicleSubType::Skins),
+            Vehicle::Jetbeast => VehicleType::Jetbeast(VehicleSubType::Skins),
+            Vehicle::Technical => VehicleType::Technical(VehicleSubType::Skins),
+            Vehicle::Cyclone => VehicleType::Cyclone(VehicleSubType::Skins),
+        }
+    }
+
+    pub fn skin_options(&self) -> Vec<VehicleLoadoutOption> {
+        let prefix = format!("VehiclePart_Mat_VehiclePart_{}_", self.asset_name());
+
+        self.skins_type()
+            .data_set()
+            .into_iter()
+            .filter_map(|unlock_path| {
+                let name = asset_name(unlock_path).strip_prefix(&prefix)?;
+
+                Some(VehicleLoadoutOption {
+                    name: name.to_owned(),
+                    unlock_asset_path: Some(unlock_path.to_owned()),
+                    loadout_asset_path: self.loadout_asset_path(&format!(
+                        "CAR_Material_{}_{}",
+                        self.asset_name(),
+                        name
+                    )),
+                })
+            })
+            .collect()
+    }
+
+    pub fn wheel_options(&self) -> Vec<VehicleLoadoutOption> {
+        let prefix = format!("WT_{}_", self.asset_name());
+
+        self.chassis_type()
+            .data_set()
+            .into_iter()
+            .filter_map(|unlock_path| {
+                let asset = asset_name(unlock_path);
+
+                let name = match LOADOUT_WHEEL_NAMES.iter().find(|(a, _)| *a == asset) {
+                    Some((_, name)) => name,
+                    None => {
+                        let name = asset.strip_prefix(&prefix)?;
+
+                        match name.strip_suffix("Wheels") {
+                            Some(stripped) if !stripped.is_empty() => stripped,
+                            _ => name,
+                        }
+                    }
+                };
+
+                Some(VehicleLoadoutOption {
+                    name: name.to_owned(),
+                    unlock_asset_path: Some(unlock_path.to_owned()),
+                    loadout_asset_path: self.loadout_asset_path(&format!(
+                        "CAR_Wheel_{}_{}",
+                        self.asset_name(),
+                        name
+                    )),
+                })
+            })
+            .collect()
+    }
+
+    /// Finds the option for a loadout path, paths that aren't known still get an option so they can
+    /// be kept as they are.
+    pub fn loadout_option(
+        options: &[VehicleLoadoutOption],
+        loadout_asset_path: &str,
+    ) -> VehicleLoadoutOption {
+        options
+            .iter()
+            .find(|o| {
+                o.loadout_asset_path
+                    .eq_ignore_ascii_case(loadout_asset_path)
+            })
+            .cloned()
+            .unwrap_or_else(|| VehicleLoadoutOption {
+                name: asset_name(loadout_asset_path)
+                    .rsplit('_')
+                    .next()
+                    .unwrap_or(loadout_asset_path)
+                    .to_owned(),
+                unlock_asset_path: None,
+                loadout_asset_path: loadout_asset_path.to_owned(),
+            })
+    }
+
+    pub fn stock_loadout(&self) -> VehicleLoadout {
+        let stock_wheels = match self {
+            Vehicle::Outrunner => "DuneBuggy",
+            Vehicle::Jetbeast => "Base",
+            Vehicle::Technical => "AllTerrain",
+            Vehicle::Cyclone => "DualWheel",
+        };
+
+        VehicleLoadout {
+            vehicle: *self,
+            skin: self.loadout_asset_path(&format!("CAR_Material_{}_Default", self.asset_name())),
+            wheels: self.loadout_asset_path(&format!(
+                "CAR_Wheel_{}_{}",
+                self.asset_name(),
+                stock_wheels
+            )),
+        }
+    }
+}
+
+fn asset_name(asset_path: &str) -> &str {
+    asset_path.rsplit('.').next().unwrap_or(asset_path)
+}
+
+/// The skin and wheels a vehicle uses when it is called from a Catch-A-Ride station.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct VehicleLoadout {
+    pub vehicle: Vehicle,
+    pub skin: String,
+    pub wheels: String,
+}
+
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct VehicleLoadoutOption {
+    pub name: String,
+    pub unlock_asset_path: Option<String>,
+    pub loadout_asset_path: String,
+}
+
+impl fmt::Display for VehicleLoadoutOption {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_skin_options() {
+        let options = Vehicle::Outrunner.skin_options();
+
+        assert_eq!(
+            options.len(),
+            VehicleType::Outrunner(VehicleSubType::Skins).maximum()
+        );
+
+        let default = options.iter().find(|o| o.name == "Default").unwrap();
+
+        assert_eq!(
+            default.loadout_asset_path,
+            "/Game/UI/CatchARideMenu/VehicleAndParts/Outrunner/CAR_Material_Outrunner_Default.CAR_Material_Outrunner_Default"
+        );
+        assert_eq!(
+            default.loadout_asset_path,
+            Vehicle::Outrunner.stock_loadout().skin
+        );
+
+        let horse_skin = Vehicle::Jetbeast
+            .skin_options()
+            .into_iter()
+            .find(|o| o.name == "Skin2")
+            .unwrap();
+
+        assert_eq!(
+            horse_skin.loadout_asset_path,
+            "/Geranium/UI/CatchARideMenu/VehiclesAndParts/Horse/CAR_Material_Horse_Skin2.CAR_Material_Horse_Skin2"
+        );
+    }
+
+    #[test]
+    fn test_wheel_options() {
+        for vehicle in Vehicle::ALL {
+            let options = vehicle.wheel_options();
+
+            assert_eq!(options.len(), vehicle.chassis_type().maximum());
+
+            let stock = Vehicle::loadout_option(&options, &vehicle.stock_loadout().wheels);
+
+            assert!(stock.unlock_asset_path.is_some(), "{}", vehicle);
+        }
+
+        let options = Vehicle::Technical.wheel_options();
+
+        assert!(options.iter().any(|o| o.loadout_asset_path
+            == "/Game/UI/CatchARideMenu/VehicleAndParts/Technical/CAR_Wheel_Technical_AllTerrain.CAR_Wheel_Technical_AllTerrain"));
+
+        let unknown = Vehicle::loadout_option(
+            &options,
+            "/Game/UI/CatchARideMenu/VehicleAndParts/Technical/CAR_Wheel_Technical_Custom.CAR_Wheel_Technical_Custom",
+        );
+
+        assert_eq!(unknown.name, "Custom");
+        assert_eq!(unknown.unlock_asset_path, None);
+    }
+}
@@ -0,0 +1,355 @@
+use std::path::Path;
+
+use bl3_save_edit_core::bl3_profile::guardian_reward::GuardianReward;
+use bl3_save_edit_core::bl3_profile::profile_currency::{DIAMOND_KEY, GOLDEN_KEY};
+use bl3_save_edit_core::bl3_profile::sdu::ProfileSduSlot;
+use bl3_save_edit_core::bl3_profile::skins::{ProfileSkinType, SkinSet};
+use bl3_save_edit_core::bl3_profile::Bl3Profile;
+use bl3_save_edit_core::bl3_save::ammo::AmmoPool;
+use bl3_save_edit_core::bl3_save::sdu::SaveSduSlot;
+use bl3_save_edit_core::bl3_save::util::experience_to_level;
+use bl3_save_edit_core::bl3_save::Bl3Save;
+use bl3_save_edit_core::game_data::{PROFILE_HEADS, PROFILE_HEADS_DEFAULTS};
+use bl3_save_edit_core::parser::HeaderType;
+use bl3_save_edit_core::vehicle_data::{VehicleSubType, VehicleType};
+use strum::EnumMessage;
+
+const SAVES: [(&str, &[u8], HeaderType); 2] = [
+    (
+        "19.sav",
+        include_bytes!("../test_files/19.sav"),
+        HeaderType::PcSave,
+    ),
+    (
+        "1ps4_v100.sav",
+        include_bytes!("../test_files/1ps4_v100.sav"),
+        HeaderType::Ps4Save,
+    ),
+];
+
+const PROFILES: [(&str, &[u8], HeaderType); 2] = [
+    (
+        "1prof.sav",
+        include_bytes!("../test_files/1prof.sav"),
+        HeaderType::PcProfile,
+    ),
+    (
+        "2profps4.sav",
+        include_bytes!("../test_files/2profps4.sav"),
+        HeaderType::Ps4Profile,
+    ),
+];
+
+/// Applies `edit` to every fixture save, writes it back out and reads it in again.
+fn edit_saves<E, C>(edit: E, check: C)
+where
+    E: Fn(&mut Bl3Save),
+    C: Fn(&Bl3Save, &Bl3Save),
+{
+    for (file_name, data, header_type) in SAVES {
+        let original = Bl3Save::from_bytes(Path::new(file_name), data, header_type)
+            .unwrap_or_else(|e| panic!("failed to read {}: {}", file_name, e));
+
+        let mut save = original.clone();
+
+        edit(&mut save);
+
+        let (output, _) = save
+            .as_bytes()
+            .unwrap_or_else(|e| panic!("failed to write {}: {}", file_name, e));
+
+        let reread = Bl3Save::from_bytes(Path::new(file_name), &output, header_type)
+            .unwrap_or_else(|e| panic!("failed to read edited {}: {}", file_name, e));
+
+        check(&original, &reread);
+    }
+}
+
+fn edit_profiles<E, C>(edit: E, check: C)
+where
+    E: Fn(&mut Bl3Profile),
+    C: Fn(&Bl3Profile, &Bl3Profile),
+{
+    for (file_name, data, header_type) in PROFILES {
+        let original = Bl3Profile::from_bytes(Path::new(file_name), data, header_type)
+            .unwrap_or_else(|e| panic!("failed to read {}: {}", file_name, e));
+
+        let mut profile = original.clone();
+
+        edit(&mut profile);
+
+        let (output, _) = profile
+            .as_bytes()
+            .unwrap_or_else(|e| panic!("failed to write {}: {}", file_name, e));
+
+        let reread = Bl3Profile::from_bytes(Path::new(file_name), &output, header_type)
+            .unwrap_or_else(|e| panic!("failed to read edited {}: {}", file_name, e));
+
+        check(&original, &reread);
+    }
+}
+
+#[test]
+fn test_set_character_name() {
+    edit_saves(
+        |save| save.set_character_name("Edited"),
+        |_, save| {
+            assert_eq!(
+                save.character_data.character.preferred_character_name,
+                "Edited"
+            )
+        },
+    );
+}
+
+#[test]
+fn test_set_level() {
+    edit_saves(
+        |save| save.set_level(72).unwrap(),
+        |_, save| assert_eq!(save.character_data.player_level(), 72),
+    );
+}
+
+#[test]
+fn test_set_experience_points() {
+    edit_saves(
+        |save| save.set_experience_points(2000).unwrap(),
+        |_, save| {
+            assert_eq!(save.character_data.character.experience_points, 2000);
+            assert_eq!(
+                save.character_data.player_level(),
+                experience_to_level(2000).unwrap()
+            );
+        },
+    );
+}
+
+#[test]
+fn test_set_currency() {
+    edit_saves(
+        |save| {
+            save.set_money(1_234_567).unwrap();
+            save.set_eridium(7_654).unwrap();
+        },
+        |_, save| {
+            assert_eq!(save.character_data.money(), 1_234_567);
+            assert_eq!(save.character_data.eridium(), 7_654);
+        },
+    );
+}
+
+#[test]
+fn test_set_sdu_slot() {
+    edit_saves(
+        |save| save.set_sdu_slot(&SaveSduSlot::Backpack, SaveSduSlot::Backpack.maximum()),
+        |_, save| {
+            let backpack = save
+                .character_data
+                .sdu_slots()
+                .iter()
+                .find(|s| s.sdu == SaveSduSlot::Backpack)
+                .expect("backpack sdu missing");
+
+            assert_eq!(backpack.current, backpack.max);
+        },
+    );
+}
+
+#[test]
+fn test_set_ammo_pool() {
+    edit_saves(
+        |save| save.set_ammo_pool(&AmmoPool::Pistol, 321).unwrap(),
+        |_, save| {
+            let pistol = save
+                .character_data
+                .ammo_pools()
+                .iter()
+                .find(|a| a.pool == AmmoPool::Pistol)
+                .expect("pistol ammo missing");
+
+            assert_eq!(pistol.current, 321);
+        },
+    );
+}
+
+#[test]
+fn test_unlock_vehicle_data() {
+    let vehicle_type = VehicleType::Outrunner(VehicleSubType::Chassis);
+
+    edit_saves(
+        |save| save.unlock_vehicle_data(&vehicle_type),
+        |_, save| {
+            let outrunner = save
+                .character_data
+                .vehicle_data()
+                .iter()
+                .find(|v| v.vehicle_type == vehicle_type)
+                .expect("outrunner chassis missing");
+
+            assert_eq!(outrunner.current, vehicle_type.maximum());
+        },
+    );
+}
+
+#[test]
+fn test_add_and_remove_inventory_item() {
+    let (file_name, data, header_type) = SAVES[0];
+
+    let item = Bl3Save::from_bytes(Path::new(file_name), data, header_type)
+        .unwrap()
+        .character_data
+        .inventory_items()[0]
+        .clone();
+
+    edit_saves(
+        |save| save.add_inventory_item(&item).unwrap(),
+        |original, save| {
+            let items = save.character_data.inventory_items();
+
+            assert_eq!(
+                items.len(),
+                original.character_data.inventory_items().len() + 1
+            );
+            assert_eq!(
+                items.last().unwrap().get_serial_number(false).unwrap(),
+                item.get_serial_number(false).unwrap()
+            );
+        },
+    );
+
+    edit_saves(
+        |save| save.remove_inventory_item(0),
+        |original, save| {
+            assert_eq!(
+                save.character_data.inventory_items().as_slice(),
+                original
+                    .character_data
+                    .inventory_items()
+                    .get(1..)
+                    .unwrap_or_default()
+            );
+        },
+    );
+}
+
+#[test]
+fn test_set_save_guardian_reward() {
+    edit_saves(
+        |save| {
+            save.set_guardian_rank(25, Some(0));
+            save.set_guardian_reward(&GuardianReward::Accuracy, 25)
+                .unwrap();
+        },
+        |_, save| {
+            let reward_path = GuardianReward::Accuracy.get_serializations()[0];
+
+            let reward = save
+                .character_data
+                .character
+                .guardian_rank_character_data
+                .get_ref()
+                .rank_rewards
+                .iter()
+                .find(|r| r.reward_data_path == reward_path)
+                .expect("accuracy reward missing");
+
+            assert_eq!(reward.num_tokens, 25);
+        },
+    );
+}
+
+#[test]
+fn test_set_keys() {
+    edit_profiles(
+        |profile| {
+            profile.set_currency(&GOLDEN_KEY, 500).unwrap();
+            profile.set_currency(&DIAMOND_KEY, 50).unwrap();
+            profile.set_vault_card_chests(1, 12);
+        },
+        |_, profile| {
+            assert_eq!(profile.profile_data.golden_keys(), 500);
+            assert_eq!(profile.profile_data.diamond_keys(), 50);
+            assert_eq!(profile.profile_data.vault_card_chests(1), 12);
+        },
+    );
+}
+
+#[test]
+fn test_set_profile_sdu_slot() {
+    edit_profiles(
+        |profile| profile.set_sdu_slot(&ProfileSduSlot::Bank, ProfileSduSlot::Bank.maximum()),
+        |_, profile| {
+            let bank = profile
+                .profile_data
+                .sdu_slots()
+                .iter()
+                .find(|s| s.sdu == ProfileSduSlot::Bank)
+                .expect("bank sdu missing");
+
+            assert_eq!(bank.current, bank.max);
+        },
+    );
+}
+
+#[test]
+fn test_unlock_skin_set() {
+    edit_profiles(
+        |profile| profile.unlock_skin_set(&ProfileSkinType::Regular(SkinSet::CharacterHeads)),
+        |_, profile| {
+            assert_eq!(
+                profile.profile_data.character_heads_unlocked(),
+                PROFILE_HEADS.len() + PROFILE_HEADS_DEFAULTS.len()
+            )
+        },
+    );
+}
+
+#[test]
+fn test_set_profile_guardian_reward() {
+    edit_profiles(
+        |profile| {
+            profile
+                .set_guardian_reward(&GuardianReward::Accuracy, 25)
+                .unwrap();
+            profile.set_guardian_rank(25, Some(0));
+        },
+        |_, profile| {
+            let reward = profile
+                .profile_data
+                .guardian_rewards()
+                .iter()
+                .find(|r| r.reward == GuardianReward::Accuracy)
+                .expect("accuracy reward missing");
+
+            assert_eq!(reward.current, 25);
+            assert_eq!(profile.profile_data.guardian_tokens(), 0);
+        },
+    );
+}
+
+#[test]
+fn test_add_and_remove_bank_item() {
+    edit_profiles(
+        |profile| {
+            let item = profile.profile_data.bank_items()[0].clone();
+
+            profile.add_bank_item(&item).unwrap();
+        },
+        |original, profile| {
+            assert_eq!(
+                profile.profile_data.bank_items().len(),
+                original.profile_data.bank_items().len() + 1
+            );
+        },
+    );
+
+    edit_profiles(
+        |profile| profile.remove_bank_item(0),
+        |original, profile| {
+            assert_eq!(
+                profile.profile_data.bank_items().as_slice(),
+                &original.profile_data.bank_items()[1..]
+            );
+        },
+    );
+}
@@ -1,25 +1,51 @@
 use std::mem;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures::channel::mpsc::{self, UnboundedReceiver};
+use futures::lock::Mutex;
+use futures::StreamExt;
 
 use iced::alignment::Horizontal;
 use iced::{
-    button, pick_list, svg, tooltip, Alignment, Application, Button, Color, Column, Command,
-    Container, Element, Length, PickList, Row, Svg, Text, Tooltip,
+    button, keyboard, pick_list, svg, tooltip, Alignment, Application, Button, Color, Column,
+    Command, Container, Element, Length, PickList, ProgressBar, Row, Subscription, Svg, Text,
+    Tooltip,
 };
-use tracing::{error, info};
+use tracing::{error, info, warn};
 
+use bl3_save_edit_core::bl3_item::parse_shift_inventory_code;
 use bl3_save_edit_core::bl3_profile::sdu::ProfileSduSlot;
 use bl3_save_edit_core::bl3_profile::Bl3Profile;
 use bl3_save_edit_core::bl3_save::ammo::AmmoPool;
 use bl3_save_edit_core::bl3_save::sdu::SaveSduSlot;
-use bl3_save_edit_core::bl3_save::util::{experience_to_level, REQUIRED_XP_LIST};
+use bl3_save_edit_core::bl3_save::travel_station::{MapLocation, TravelStation};
+use bl3_save_edit_core::bl3_save::util::{
+    clamp_level, experience_to_level, level_to_xp, unsupported_name_chars,
+};
 use bl3_save_edit_core::bl3_save::Bl3Save;
-use bl3_save_edit_core::file_helper::Bl3FileType;
+use bl3_save_edit_core::build_preset::BuildPreset;
+use bl3_save_edit_core::character_report::character_report;
+use bl3_save_edit_core::favorite_anointments::{resolve_favorite_anointments, FavoriteAnointment};
+use bl3_save_edit_core::file_diff::{diff_profiles, diff_saves, short_summary};
+use bl3_save_edit_core::file_helper::{
+    infer_game_store, most_likely_profile, startup_file_location, Bl3FileType, FileOrigin,
+    GameStore, ProfileLocation,
+};
+use bl3_save_edit_core::guardian_injection::GuardianInjection;
+use bl3_save_edit_core::item_transfer::transfer_items;
 use bl3_save_edit_core::parser::HeaderType;
+use bl3_save_edit_core::stats_export;
+use bl3_save_edit_core::validation::{
+    has_errors, validate_profile, validate_save, Finding, Severity,
+};
 
 use crate::bl3_ui_style::{
-    Bl3UiContentStyle, Bl3UiMenuBarStyle, Bl3UiPositiveButtonStyle, Bl3UiStyle, Bl3UiTooltipStyle,
+    Bl3UiContentStyle, Bl3UiMenuBarStyle, Bl3UiPositiveButtonStyle, Bl3UiProgressBarStyle,
+    Bl3UiStyle, Bl3UiTooltipStyle,
 };
+use crate::commands::interaction::file_save::UndoSave;
 use crate::commands::{initialization, interaction};
 use crate::config::{Bl3Config, ConfigMessage};
 use crate::resources::fonts::{
@@ -29,13 +55,24 @@ use crate::resources::svgs::REFRESH;
 use crate::state_mappers::{manage_profile, manage_save};
 use crate::update::Release;
 use crate::util::ErrorExt;
+use crate::views::backup_history::BackupHistoryInteractionMessage;
 use crate::views::choose_save_directory::{
     ChooseSaveDirectoryState, ChooseSaveInteractionMessage, ChooseSaveMessage,
 };
+use crate::views::first_run_wizard::{
+    FirstRunWizardInteractionMessage, FirstRunWizardState, FirstRunWizardStep,
+};
 use crate::views::initialization::InitializationMessage;
-use crate::views::item_editor::ItemEditorFileType;
+use crate::views::item_editor::favorite_item::FavoriteItem;
+use crate::views::item_editor::{
+    InventoryColumns, InventoryLayout, ItemEditorFileType, ItemEditorInteractionMessage,
+    ItemEditorStateExt, ItemListTabType,
+};
 use crate::views::manage_profile::bank::ProfileBankInteractionMessage;
 use crate::views::manage_profile::general::ProfileGeneralInteractionMessage;
+use crate::views::manage_profile::guardian_injection::{
+    GuardianInjectionMessage, PendingProfileSave,
+};
 use crate::views::manage_profile::keys::ProfileKeysInteractionMessage;
 use crate::views::manage_profile::main::{ProfileTabBarInteractionMessage, ProfileTabBarView};
 use crate::views::manage_profile::profile::{
@@ -44,26 +81,33 @@ use crate::views::manage_profile::profile::{
 use crate::views::manage_profile::{
     ManageProfileInteractionMessage, ManageProfileState, ManageProfileView,
 };
+use crate::views::manage_save::challenges::SaveChallengesInteractionMessage;
 use crate::views::manage_save::character::{
     CharacterAmmoMessage, CharacterGearUnlockedMessage, CharacterSduMessage,
     CharacterSkinSelectedMessage, SaveCharacterInteractionMessage,
 };
 use crate::views::manage_save::currency::SaveCurrencyInteractionMessage;
 use crate::views::manage_save::general::SaveGeneralInteractionMessage;
-use crate::views::manage_save::inventory::SaveInventoryInteractionMessage;
+use crate::views::manage_save::inventory::{SaveInventoryInteractionMessage, ShiftCodeImportState};
 use crate::views::manage_save::main::{SaveTabBarInteractionMessage, SaveTabBarView};
+use crate::views::manage_save::missions::SaveMissionsInteractionMessage;
+use crate::views::manage_save::transfer::{TransferInteractionMessage, TransferState};
 use crate::views::manage_save::vehicle::{SaveVehicleInteractionMessage, VehicleUnlockedMessage};
 use crate::views::manage_save::{ManageSaveInteractionMessage, ManageSaveState, ManageSaveView};
 use crate::views::settings::{SettingsInteractionMessage, SettingsState};
+use crate::views::validation_report::{ValidationReportMessage, ValidationReportState};
 use crate::views::InteractionExt;
+use crate::widgets::confirmation_dialog::{ConfirmationDialog, ConfirmationDialogMessage};
 use crate::widgets::notification::{Notification, NotificationSentiment};
-use crate::{state_mappers, update, views, VERSION};
+use crate::widgets::skill_tree::SkillTreeInteractionMessage;
+use crate::{recovery, state_mappers, update, views, VERSION};
 
 #[derive(Debug, Default)]
 pub struct Bl3Application {
     pub config: Bl3Config,
     pub view_state: ViewState,
     choose_save_directory_state: ChooseSaveDirectoryState,
+    first_run_wizard_state: FirstRunWizardState,
     pub manage_save_state: ManageSaveState,
     pub manage_profile_state: ManageProfileState,
     loaded_files_selector: pick_list::State<Bl3FileType>,
@@ -72,11 +116,22 @@ pub struct Bl3Application {
     refresh_button_state: button::State,
     update_button_state: button::State,
     save_file_button_state: button::State,
+    preview_changes_button_state: button::State,
+    undo_save_button_state: button::State,
+    last_save_undo: Option<UndoSave>,
     notification: Option<Notification>,
+    confirmation_dialog: Option<ConfirmationDialog>,
     latest_release: Option<Release>,
     is_updating: bool,
+    update_progress: f32,
+    update_progress_receiver: Option<Arc<Mutex<UnboundedReceiver<f32>>>>,
     is_reloading_saves: bool,
+    multiple_profiles_warned_dir: Option<PathBuf>,
     settings_state: SettingsState,
+    current_modifiers: keyboard::Modifiers,
+    last_selected_file_changes: usize,
+    edits_since_recovery: usize,
+    tab_badges_stale: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -85,13 +140,29 @@ pub enum Bl3Message {
     LatestRelease(MessageResult<Release>),
     UpdateToLatestRelease,
     UpdateToLatestReleaseCompleted(MessageResult<()>),
+    UpdateProgress(f32),
     Config(ConfigMessage),
     Interaction(InteractionMessage),
     ChooseSave(ChooseSaveMessage),
-    SaveFileCompleted(MessageResult<Bl3Save>),
-    SaveProfileCompleted(MessageResult<Bl3Profile>),
+    SaveFileCompleted(MessageResult<(Bl3Save, UndoSave)>),
+    SaveNotesCompleted(MessageResult<()>),
+    SaveProfileCompleted(MessageResult<(Bl3Profile, UndoSave)>),
+    CreateBlankProfileCompleted(MessageResult<(Bl3Profile, Option<UndoSave>)>),
+    UndoSaveCompleted(MessageResult<()>),
     FilesLoadedAfterSave(MessageResult<(Bl3FileType, Vec<Bl3FileType>)>),
+    TransferFilesCompleted(MessageResult<()>),
+    FilesLoadedAfterTransfer(MessageResult<(Bl3FileType, Vec<Bl3FileType>)>),
     ClearNotification,
+    ConfirmationDialog(ConfirmationDialogMessage),
+    ModifiersChanged(keyboard::Modifiers),
+    DeleteKeyPressed,
+    SaveLastSelectedFile(usize),
+    ItemVerifySpinnerTick,
+    RecoveryTick,
+    RecoveryFileSaved(MessageResult<()>),
+    RestoreRecoveryFile(Vec<u8>),
+    DiscardRecoveryFile,
+    TabBadgesTick,
 }
 
 #[derive(Debug, Clone)]
@@ -127,8 +198,11 @@ pub enum InteractionMessage {
     ManageSaveInteraction(ManageSaveInteractionMessage),
     ManageProfileInteraction(ManageProfileInteractionMessage),
     SettingsInteraction(SettingsInteractionMessage),
+    FirstRunWizardInteraction(FirstRunWizardInteractionMessage),
     LoadedFileSelected(Box<Bl3FileType>),
+    DiscardChangesAndSelect(Box<Bl3FileType>),
     RefreshSavesDirectory,
+    UndoLastSavePressed,
     Ignore,
 }
 
@@ -137,6 +211,7 @@ pub enum ViewState {
     Initializing,
     Loading,
     ChooseSaveDirectory,
+    FirstRunWizard,
     ManageSave(ManageSaveView),
     ManageProfile(ManageProfileView),
 }
@@ -147,15 +222,710 @@ impl std::default::Default for ViewState {
     }
 }
 
+const UNSUPPORTED_VERSION_LOAD_MESSAGE: &str = "This file was made by a newer version of the game than this editor supports, editing it may corrupt it. Make sure you have a backup before saving.";
+
+fn export_notification(res: MessageResult<PathBuf>, export_type: &str) -> Notification {
+    match res {
+        MessageResult::Success(output_file) => Notification::new(
+            format!("Exported to {}.", output_file.display()),
+            NotificationSentiment::Positive,
+        ),
+        MessageResult::Error(e) => {
+            let msg = format!("Failed to export {}: {}", export_type, e);
+
+            error!("{}", msg);
+
+            Notification::new(msg, NotificationSentiment::Negative)
+        }
+    }
+}
+
+fn validation_report_notification(findings: &[Finding]) -> Notification {
+    let errors = findings
+        .iter()
+        .filter(|f| f.severity == Severity::Error)
+        .count();
+
+    let msg = if errors > 0 {
+        format!(
+            "Found {} error(s) that must be fixed before saving, see the list above.",
+            errors
+        )
+    } else {
+        format!(
+            "Found {} warning(s), see the list above. Acknowledge them to save anyway.",
+            findings.len()
+        )
+    };
+
+    Notification::new(msg, NotificationSentiment::Negative)
+}
+
+const CHANGES_SUMMARY_LENGTH: usize = 5;
+
+const LAST_SELECTED_FILE_SAVE_DELAY: Duration = Duration::from_secs(2);
+
+// Tab badges map every tab back to the file, so they are only updated this often while editing
+const TAB_BADGES_INTERVAL: Duration = Duration::from_millis(500);
+
+const UNSUPPORTED_VERSION_SAVE_MESSAGE: &str = "This file was made by a newer version of the game than this editor supports, saving it may corrupt it.";
+
+fn save_file_confirmation<B: AsRef<str>>(body: B) -> ConfirmationDialog {
+    ConfirmationDialog::new(
+        "Confirm Save",
+        body,
+        "Save Anyway",
+        Bl3Message::Interaction(InteractionMessage::ManageSaveInteraction(
+            ManageSaveInteractionMessage::SaveFilePressed,
+        )),
+    )
+    .on_cancel(Bl3Message::Interaction(
+        InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::SaveCancelled),
+    ))
+}
+
+fn save_profile_confirmation<B: AsRef<str>>(body: B) -> ConfirmationDialog {
+    ConfirmationDialog::new(
+        "Confirm Save",
+        body,
+        "Save Anyway",
+        Bl3Message::Interaction(InteractionMessage::ManageProfileInteraction(
+            ManageProfileInteractionMessage::SaveProfilePressed,
+        )),
+    )
+    .on_cancel(Bl3Message::Interaction(
+        InteractionMessage::ManageProfileInteraction(
+            ManageProfileInteractionMessage::SaveCancelled,
+        ),
+    ))
+}
+
+#[derive(Debug, Default)]
+pub struct Bl3Flags {
+    pub config: Bl3Config,
+    pub startup_file: Option<PathBuf>,
+}
+
+impl Bl3Application {
+    fn files_loaded(
+        &mut self,
+        dir: PathBuf,
+        mut files: Vec<Bl3FileType>,
+        requested_file_name: Option<&str>,
+    ) -> Command<Bl3Message> {
+        files.sort();
+        self.loaded_files = files;
+
+        let file_name_to_select = requested_file_name
+            .filter(|file_name| self.loaded_files.iter().any(|f| f.filename() == *file_name));
+
+        if let (Some(file_name), None) = (requested_file_name, file_name_to_select) {
+            let msg = format!(
+                "Failed to open {}, it is not a Save or Profile that can be read.",
+                file_name
+            );
+
+            error!("{}", msg);
+
+            self.notification = Some(Notification::new(msg, NotificationSentiment::Negative));
+        }
+
+        let last_selected_file = self.config.last_selected_file().map(str::to_owned);
+
+        self.loaded_files_selected = Box::new(
+            state_mappers::file_to_select(
+                &self.loaded_files,
+                file_name_to_select.or(last_selected_file.as_deref()),
+            )
+            .cloned()
+            .expect("loaded_files was empty"),
+        );
+
+        self.config
+            .set_last_selected_file(Some(self.loaded_files_selected.filename().to_owned()));
+
+        let mapped = state_mappers::map_loaded_file_to_state(self);
+
+        mapped.handle_ui_error(
+            "Failed to map loaded file to editor",
+            &mut self.notification,
+        );
+
+        self.warn_if_unsupported_version();
+
+        if mapped.is_ok() {
+            self.notify_if_sanitized_on_load();
+        }
+        self.warn_if_multiple_profiles(&dir);
+
+        self.config.add_recent_save_dir(dir.clone());
+        self.config.set_saves_dir(dir);
+        self.settings_state.saves_dir_input = self.config.saves_dir().to_string_lossy().to_string();
+
+        self.offer_recovery();
+
+        Command::perform(self.config.clone().save(), |r| {
+            Bl3Message::Config(ConfigMessage::SaveCompleted(MessageResult::handle_result(
+                r,
+            )))
+        })
+    }
+
+    fn set_inventory_layout(&mut self, inventory_layout: InventoryLayout) -> Command<Bl3Message> {
+        self.manage_save_state
+            .save_view_state
+            .inventory_state
+            .item_editor_state
+            .inventory_layout = inventory_layout;
+
+        self.manage_profile_state
+            .profile_view_state
+            .bank_state
+            .item_editor_state
+            .inventory_layout = inventory_layout;
+
+        self.config.set_inventory_layout(inventory_layout);
+
+        Command::perform(self.config.clone().save(), |r| {
+            Bl3Message::Config(ConfigMessage::SaveCompleted(MessageResult::handle_result(
+                r,
+            )))
+        })
+    }
+
+    fn set_favorite_items(&mut self, favorite_items: Vec<String>) -> Command<Bl3Message> {
+        self.manage_save_state
+            .save_view_state
+            .inventory_state
+            .item_editor_state
+            .favorite_items = FavoriteItem::from_serials(&favorite_items);
+
+        self.manage_profile_state
+            .profile_view_state
+            .bank_state
+            .item_editor_state
+            .favorite_items = FavoriteItem::from_serials(&favorite_items);
+
+        self.config.set_favorite_items(favorite_items);
+
+        Command::perform(self.config.clone().save(), |r| {
+            Bl3Message::Config(ConfigMessage::SaveCompleted(MessageResult::handle_result(
+                r,
+            )))
+        })
+    }
+
+    fn set_favorite_anointments(
+        &mut self,
+        favorite_anointments: Vec<FavoriteAnointment>,
+    ) -> Command<Bl3Message> {
+        self.config.set_favorite_anointments(
+            favorite_anointments
+                .iter()
+                .map(|f| f.ident().to_owned())
+                .collect(),
+        );
+
+        self.manage_save_state
+            .save_view_state
+            .inventory_state
+            .item_editor_state
+            .favorite_anointments = favorite_anointments.clone();
+
+        self.manage_profile_state
+            .profile_view_state
+            .bank_state
+            .item_editor_state
+            .favorite_anointments = favorite_anointments;
+
+        Command::perform(self.config.clone().save(), |r| {
+            Bl3Message::Config(ConfigMessage::SaveCompleted(MessageResult::handle_result(
+                r,
+            )))
+        })
+    }
+
+    // Waits for the next progress update from the download, once the download is finished or has
+    // failed the channel is closed and this reports the download as done
+    fn next_update_progress(&self) -> Command<Bl3Message> {
+        match &self.update_progress_receiver {
+            Some(progress_receiver) => {
+                let progress_receiver = progress_receiver.clone();
+
+                Command::perform(
+                    async move { progress_receiver.lock().await.next().await.unwrap_or(1.0) },
+                    Bl3Message::UpdateProgress,
+                )
+            }
+            None => Command::none(),
+        }
+    }
+
+    fn set_inventory_columns(
+        &mut self,
+        inventory_columns: InventoryColumns,
+    ) -> Command<Bl3Message> {
+        self.settings_state.inventory_columns = inventory_columns;
+
+        self.manage_save_state
+            .save_view_state
+            .inventory_state
+            .item_editor_state
+            .inventory_columns = inventory_columns;
+
+        self.manage_profile_state
+            .profile_view_state
+            .bank_state
+            .item_editor_state
+            .inventory_columns = inventory_columns;
+
+        self.config.set_inventory_columns(inventory_columns);
+
+        Command::perform(self.config.clone().save(), |r| {
+            Bl3Message::Config(ConfigMessage::SaveCompleted(MessageResult::handle_result(
+                r,
+            )))
+        })
+    }
+
+    fn set_item_verification(
+        &mut self,
+        item_verification_enabled: bool,
+        item_api_url: String,
+    ) -> Command<Bl3Message> {
+        self.config
+            .set_item_verification_enabled(item_verification_enabled);
+        self.config.set_item_api_url(item_api_url);
+
+        self.settings_state.item_verification_enabled = item_verification_enabled;
+
+        let item_api_url = self.config.item_verification_api_url();
+
+        self.manage_save_state
+            .save_view_state
+            .inventory_state
+            .item_editor_state
+            .item_api_url = item_api_url.clone();
+
+        self.manage_profile_state
+            .profile_view_state
+            .bank_state
+            .item_editor_state
+            .item_api_url = item_api_url;
+
+        Command::perform(self.config.clone().save(), |r| {
+            Bl3Message::Config(ConfigMessage::SaveCompleted(MessageResult::handle_result(
+                r,
+            )))
+        })
+    }
+
+    fn apply_config(&mut self, config: Bl3Config) -> Command<Bl3Message> {
+        self.config = config;
+
+        self.settings_state.config_dir_input =
+            self.config.config_dir().to_string_lossy().to_string();
+        self.settings_state.backup_dir_input =
+            self.config.backup_dir().to_string_lossy().to_string();
+        self.settings_state.saves_dir_input = self.config.saves_dir().to_string_lossy().to_string();
+        self.settings_state.ui_scale_factor = self.config.ui_scale_factor();
+        self.settings_state.owned_dlc = self.config.owned_dlc().clone();
+        self.settings_state.inventory_columns = self.config.inventory_columns();
+        self.settings_state.item_verification_enabled = self.config.item_verification_enabled();
+        self.settings_state.item_api_url_input = self.config.item_api_url().to_owned();
+        self.settings_state.sanitize_negative_values = self.config.sanitize_negative_values();
+
+        let save_item_editor_state = &mut self
+            .manage_save_state
+            .save_view_state
+            .inventory_state
+            .item_editor_state;
+
+        save_item_editor_state.owned_dlc = self.config.owned_dlc().clone();
+        save_item_editor_state.inventory_layout = self.config.inventory_layout();
+        save_item_editor_state.inventory_columns = self.config.inventory_columns();
+        save_item_editor_state.favorite_anointments =
+            resolve_favorite_anointments(self.config.favorite_anointments());
+        save_item_editor_state.favorite_items =
+            FavoriteItem::from_serials(self.config.favorite_items());
+        save_item_editor_state.item_api_url = self.config.item_verification_api_url();
+
+        let bank_item_editor_state = &mut self
+            .manage_profile_state
+            .profile_view_state
+            .bank_state
+            .item_editor_state;
+
+        bank_item_editor_state.owned_dlc = self.config.owned_dlc().clone();
+        bank_item_editor_state.inventory_layout = self.config.inventory_layout();
+        bank_item_editor_state.inventory_columns = self.config.inventory_columns();
+        bank_item_editor_state.favorite_anointments =
+            resolve_favorite_anointments(self.config.favorite_anointments());
+        bank_item_editor_state.favorite_items =
+            FavoriteItem::from_serials(self.config.favorite_items());
+        bank_item_editor_state.item_api_url = self.config.item_verification_api_url();
+
+        if self.config.saves_dir().exists() {
+            self.view_state = ViewState::Loading;
+
+            Command::perform(
+                interaction::choose_save_directory::load_files_in_directory(
+                    self.config.saves_dir().to_path_buf(),
+                ),
+                |r| {
+                    Bl3Message::ChooseSave(ChooseSaveMessage::FilesLoaded(
+                        MessageResult::handle_result(r),
+                    ))
+                },
+            )
+        } else {
+            Command::none()
+        }
+    }
+
+    fn save_profile(
+        &self,
+        pending_save: PendingProfileSave,
+        inject_guardian_data: bool,
+    ) -> Command<Bl3Message> {
+        Command::perform(
+            interaction::file_save::save_profile(
+                self.config.backup_dir().to_path_buf(),
+                self.config.saves_dir().to_path_buf(),
+                pending_save.output_file,
+                pending_save.output,
+                self.manage_profile_state.current_file.clone(),
+                pending_save.profile,
+                inject_guardian_data.then(|| pending_save.injection),
+            ),
+            |r| Bl3Message::SaveProfileCompleted(MessageResult::handle_result(r)),
+        )
+    }
+
+    /// Reloads the saves folder after a profile was written, then selects it again.
+    fn reload_saved_profile(&mut self, profile: Bl3Profile) -> Command<Bl3Message> {
+        self.is_reloading_saves = true;
+
+        let bl3_file_type = match profile.header_type {
+            HeaderType::PcProfile => Bl3FileType::PcProfile(profile),
+            HeaderType::Ps4Profile => Bl3FileType::Ps4Profile(profile),
+            _ => {
+                let msg = "Unexpected Bl3FileType when reloading profile";
+
+                error!("{}", msg);
+                panic!("{}", msg);
+            }
+        };
+
+        Command::perform(
+            interaction::file_save::load_files_after_save(
+                self.config.saves_dir().to_path_buf(),
+                bl3_file_type,
+            ),
+            |r| Bl3Message::FilesLoadedAfterSave(MessageResult::handle_result(r)),
+        )
+    }
+
+    /// Whether the editor holds changes to the selected file that haven't been saved yet.
+    fn has_unsaved_changes(&mut self) -> bool {
+        match &*self.loaded_files_selected {
+            Bl3FileType::PcSave(_) | Bl3FileType::Ps4Save(_) => {
+                let mut output_file = self.manage_save_state.current_file.clone();
+
+                // If the editor can't be mapped back then the changes can't be checked either
+                manage_save::map_all_states_to_save(&mut self.manage_save_state, &mut output_file)
+                    .map(|_| {
+                        !diff_saves(&self.manage_save_state.current_file, &output_file).is_empty()
+                    })
+                    .unwrap_or(true)
+            }
+            Bl3FileType::PcProfile(_) | Bl3FileType::Ps4Profile(_) => {
+                let mut output_file = self.manage_profile_state.current_file.clone();
+
+                manage_profile::map_all_states_to_profile(
+                    &mut self.manage_profile_state,
+                    &mut output_file,
+                )
+                .map(|_| {
+                    !diff_profiles(&self.manage_profile_state.current_file, &output_file).is_empty()
+                })
+                .unwrap_or(true)
+            }
+        }
+    }
+
+    fn selected_file_path(&self) -> PathBuf {
+        self.file_path(&self.loaded_files_selected)
+    }
+
+    fn file_path(&self, file: &Bl3FileType) -> PathBuf {
+        match file {
+            Bl3FileType::PcSave(save) | Bl3FileType::Ps4Save(save) => {
+                self.config.saves_dir().join(&save.file_name)
+            }
+            Bl3FileType::PcProfile(profile) | Bl3FileType::Ps4Profile(profile) => {
+                self.config.saves_dir().join(profile.relative_path())
+            }
+        }
+    }
+
+    /// Writes the edits to the selected file that haven't been saved yet to its recovery file, or
+    /// removes the recovery file when there aren't any.
+    fn write_recovery_file(&mut self) -> Command<Bl3Message> {
+        self.edits_since_recovery = 0;
+
+        if self.is_reloading_saves
+            || !matches!(
+                self.view_state,
+                ViewState::ManageSave(_) | ViewState::ManageProfile(_)
+            )
+        {
+            return Command::none();
+        }
+
+        // Compared to the loaded file rather than the current file as that is what's on disk
+        let recovery_data = match &*self.loaded_files_selected {
+            Bl3FileType::PcSave(save) | Bl3FileType::Ps4Save(save) => {
+                let mut output_file = self.manage_save_state.current_file.clone();
+
+                manage_save::map_all_states_to_save(&mut self.manage_save_state, &mut output_file)
+                    .and_then(|_| {
+                        if diff_saves(save, &output_file).is_empty() {
+                            Ok(None)
+                        } else {
+                            output_file.as_bytes().map(|(data, _)| Some(data))
+                        }
+                    })
+            }
+            Bl3FileType::PcProfile(profile) | Bl3FileType::Ps4Profile(profile) => {
+                let mut output_file = self.manage_profile_state.current_file.clone();
+
+                manage_profile::map_all_states_to_profile(
+                    &mut self.manage_profile_state,
+                    &mut output_file,
+                )
+                .and_then(|_| {
+                    if diff_profiles(profile, &output_file).is_empty() {
+                        Ok(None)
+                    } else {
+                        output_file.as_bytes().map(|(data, _)| Some(data))
+                    }
+                })
+            }
+        };
+
+        let config_dir = self.config.config_dir().to_path_buf();
+        let file_path = self.selected_file_path();
+
+        match recovery_data {
+            Ok(Some(data)) => Command::perform(recovery::save(config_dir, file_path, data), |r| {
+                Bl3Message::RecoveryFileSaved(MessageResult::handle_result(r))
+            }),
+            Ok(None) => {
+                self.remove_recovery_file();
+
+                Command::none()
+            }
+            Err(e) => {
+                warn!("Failed to map editor to a recovery file: {}", e);
+
+                Command::none()
+            }
+        }
+    }
+
+    fn refresh_tab_badges(&mut self) {
+        self.tab_badges_stale = false;
+
+        match &*self.loaded_files_selected {
+            Bl3FileType::PcSave(_) | Bl3FileType::Ps4Save(_) => {
+                manage_save::map_state_to_tab_badges(&mut self.manage_save_state)
+            }
+            Bl3FileType::PcProfile(_) | Bl3FileType::Ps4Profile(_) => {
+                manage_profile::map_state_to_tab_badges(&mut self.manage_profile_state)
+            }
+        }
+    }
+
+    fn remove_recovery_file(&self) {
+        if let Err(e) = recovery::remove(self.config.config_dir(), &self.selected_file_path()) {
+            warn!("Failed to remove recovery file: {}", e);
+        }
+    }
+
+    /// Offers to restore edits to the selected file that were never saved, most likely because the
+    /// editor closed before they could be.
+    fn offer_recovery(&mut self) {
+        let file_path = self.selected_file_path();
+
+        match recovery::load(self.config.config_dir(), &file_path) {
+            Ok(Some(data)) => {
+                self.confirmation_dialog = Some(
+                    ConfirmationDialog::new(
+                        "Restore Edits",
+                        format!(
+                            "{} has edits that were never saved, the editor may have closed unexpectedly. Do you want to restore them?",
+                            self.loaded_files_selected.filename()
+                        ),
+                        "Restore",
+                        Bl3Message::RestoreRecoveryFile(data),
+                    )
+                    .on_cancel(Bl3Message::DiscardRecoveryFile),
+                );
+            }
+            Ok(None) => (),
+            Err(e) => warn!(
+                "Failed to load recovery file for {}: {}",
+                file_path.display(),
+                e
+            ),
+        }
+    }
+
+    fn notify_if_sanitized_on_load(&mut self) {
+        let adjustments = match &*self.loaded_files_selected {
+            Bl3FileType::PcSave(_) | Bl3FileType::Ps4Save(_) => {
+                &self.manage_save_state.sanitized_on_load
+            }
+            Bl3FileType::PcProfile(_) | Bl3FileType::Ps4Profile(_) => {
+                &self.manage_profile_state.sanitized_on_load
+            }
+        };
+
+        if adjustments.is_empty() {
+            return;
+        }
+
+        let adjusted = adjustments
+            .iter()
+            .map(|a| a.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        info!("Set negative values to 0 when loading: {}", adjusted);
+
+        let message = format!(
+            "Negative values were set to 0 when loading: {}. Save the file to keep these changes.",
+            adjusted
+        );
+
+        // Runs after the unsupported version warning so both end up in one notification
+        self.notification = Some(
+            if self.loaded_files_selected.is_version_newer_than_supported() {
+                Notification::new(
+                    format!("{} {}", UNSUPPORTED_VERSION_LOAD_MESSAGE, message),
+                    NotificationSentiment::Negative,
+                )
+            } else {
+                Notification::new(message, NotificationSentiment::Info)
+            },
+        );
+    }
+
+    fn warn_if_unsupported_version(&mut self) {
+        if self.loaded_files_selected.is_version_newer_than_supported() {
+            self.notification = Some(Notification::new(
+                UNSUPPORTED_VERSION_LOAD_MESSAGE,
+                NotificationSentiment::Negative,
+            ));
+        }
+    }
+
+    fn loaded_profile_locations(&self, dir: &Path) -> (Vec<&Bl3Profile>, Vec<ProfileLocation>) {
+        self.loaded_files
+            .iter()
+            .filter_map(|f| match f {
+                Bl3FileType::PcProfile(p) | Bl3FileType::Ps4Profile(p) => {
+                    let relative_path = p.relative_path();
+
+                    let modified = std::fs::metadata(dir.join(&relative_path))
+                        .and_then(|m| m.modified())
+                        .ok();
+
+                    Some((
+                        p,
+                        ProfileLocation {
+                            relative_path,
+                            modified,
+                        },
+                    ))
+                }
+                _ => None,
+            })
+            .unzip()
+    }
+
+    fn most_likely_loaded_profile(&self) -> Option<&Bl3Profile> {
+        let (profiles, locations) = self.loaded_profile_locations(self.config.saves_dir());
+
+        most_likely_profile(&locations).map(|i| profiles[i])
+    }
+
+    fn warn_if_multiple_profiles(&mut self, dir: &Path) {
+        if self.notification.is_some() || self.multiple_profiles_warned_dir.as_deref() == Some(dir)
+        {
+            return;
+        }
+
+        let (_, profiles) = self.loaded_profile_locations(dir);
+
+        if profiles.len() < 2 {
+            return;
+        }
+
+        if let Some(most_likely) = most_likely_profile(&profiles) {
+            let msg = format!(
+                "Found {} profiles in this folder, make sure you edit the one the game uses. {} was written most recently so it is most likely the one in use.",
+                profiles.len(),
+                profiles[most_likely].relative_path.display()
+            );
+
+            self.notification = Some(Notification::new(msg, NotificationSentiment::Negative));
+
+            self.multiple_profiles_warned_dir = Some(dir.to_path_buf());
+        }
+    }
+}
+
 impl Application for Bl3Application {
     type Executor = tokio::runtime::Runtime;
     type Message = Bl3Message;
-    type Flags = Bl3Config;
+    type Flags = Bl3Flags;
+
+    fn new(flags: Self::Flags) -> (Self, Command<Self::Message>) {
+        let Bl3Flags {
+            config,
+            startup_file,
+        } = flags;
+
+        let mut notification = None;
+
+        // Overrides have to be in place before any items are read
+        if let Err(e) = initialization::load_data_overrides(config.config_dir()) {
+            let msg = format!("Ignored the data overrides file: {}", e);
+
+            error!("{}", msg);
+
+            notification = Some(Notification::new(msg, NotificationSentiment::Warning));
+        }
+
+        let startup_message = match startup_file.map(|f| startup_file_location(&f)) {
+            Some(Ok((dir, file_name))) => InitializationMessage::LoadSaveFile(dir, file_name),
+            Some(Err(e)) => {
+                let msg = format!("Failed to open file: {}", e);
+
+                error!("{}", msg);
+
+                notification = Some(Notification::new(msg, NotificationSentiment::Negative));
+
+                InitializationMessage::LoadSaves
+            }
+            None => InitializationMessage::LoadSaves,
+        };
 
-    fn new(config: Self::Flags) -> (Self, Command<Self::Message>) {
         let startup_commands = [
             Command::perform(initialization::load_lazy_data(), |_| {
-                Bl3Message::Initialization(InitializationMessage::LoadSaves)
+                Bl3Message::Initialization(startup_message)
             }),
             Command::perform(update::get_latest_release(), |r| {
                 Bl3Message::LatestRelease(MessageResult::handle_result(r))
@@ -166,6 +936,84 @@ impl Application for Bl3Application {
         let saves_dir_input = config.saves_dir().to_string_lossy().to_string();
         let backup_dir_input = config.backup_dir().to_string_lossy().to_string();
         let ui_scale_factor = config.ui_scale_factor();
+        let owned_dlc = config.owned_dlc().clone();
+        let inventory_layout = config.inventory_layout();
+        let inventory_columns = config.inventory_columns();
+        let favorite_anointments = resolve_favorite_anointments(config.favorite_anointments());
+        let item_verification_enabled = config.item_verification_enabled();
+        let item_api_url_input = config.item_api_url().to_owned();
+        let item_api_url = config.item_verification_api_url();
+        let sanitize_negative_values = config.sanitize_negative_values();
+
+        let first_run_wizard_state = FirstRunWizardState {
+            saves_dir: config.saves_dir().to_path_buf(),
+            backup_dir: config.backup_dir().to_path_buf(),
+            ..FirstRunWizardState::default()
+        };
+
+        let mut manage_save_state = ManageSaveState::default();
+        manage_save_state
+            .save_view_state
+            .inventory_state
+            .item_editor_state
+            .owned_dlc = owned_dlc.clone();
+        manage_save_state
+            .save_view_state
+            .inventory_state
+            .item_editor_state
+            .inventory_layout = inventory_layout;
+        manage_save_state
+            .save_view_state
+            .inventory_state
+            .item_editor_state
+            .inventory_columns = inventory_columns;
+        manage_save_state
+            .save_view_state
+            .inventory_state
+            .item_editor_state
+            .favorite_anointments = favorite_anointments.clone();
+        manage_save_state
+            .save_view_state
+            .inventory_state
+            .item_editor_state
+            .favorite_items = FavoriteItem::from_serials(config.favorite_items());
+        manage_save_state
+            .save_view_state
+            .inventory_state
+            .item_editor_state
+            .item_api_url = item_api_url.clone();
+
+        let mut manage_profile_state = ManageProfileState::default();
+        manage_profile_state
+            .profile_view_state
+            .bank_state
+            .item_editor_state
+            .owned_dlc = owned_dlc.clone();
+        manage_profile_state
+            .profile_view_state
+            .bank_state
+            .item_editor_state
+            .inventory_layout = inventory_layout;
+        manage_profile_state
+            .profile_view_state
+            .bank_state
+            .item_editor_state
+            .inventory_columns = inventory_columns;
+        manage_profile_state
+            .profile_view_state
+            .bank_state
+            .item_editor_state
+            .favorite_anointments = favorite_anointments;
+        manage_profile_state
+            .profile_view_state
+            .bank_state
+            .item_editor_state
+            .favorite_items = FavoriteItem::from_serials(config.favorite_items());
+        manage_profile_state
+            .profile_view_state
+            .bank_state
+            .item_editor_state
+            .item_api_url = item_api_url;
 
         (
             Bl3Application {
@@ -176,8 +1024,17 @@ impl Application for Bl3Application {
                     backup_dir_input,
                     saves_dir_input,
                     ui_scale_factor,
+                    owned_dlc,
+                    inventory_columns,
+                    item_verification_enabled,
+                    item_api_url_input,
+                    sanitize_negative_values,
                     ..SettingsState::default()
                 },
+                first_run_wizard_state,
+                manage_save_state,
+                manage_profile_state,
+                notification,
                 ..Bl3Application::default()
             },
             Command::batch(startup_commands),
@@ -189,9 +1046,26 @@ impl Application for Bl3Application {
     }
 
     fn update(&mut self, message: Self::Message) -> Command<Self::Message> {
+        if matches!(
+            message,
+            Bl3Message::Interaction(
+                InteractionMessage::ManageSaveInteraction(_)
+                    | InteractionMessage::ManageProfileInteraction(_)
+            )
+        ) {
+            self.edits_since_recovery += 1;
+            self.tab_badges_stale = true;
+        }
+
         match message {
             Bl3Message::Initialization(initialization_msg) => match initialization_msg {
                 InitializationMessage::LoadSaves => {
+                    if !self.config.first_run_complete() {
+                        self.view_state = ViewState::FirstRunWizard;
+
+                        return Command::none();
+                    }
+
                     if self.config.saves_dir().exists() {
                         return Command::perform(
                             interaction::choose_save_directory::load_files_in_directory(
@@ -212,6 +1086,33 @@ impl Application for Bl3Application {
 
                     self.view_state = ViewState::ChooseSaveDirectory;
                 }
+                InitializationMessage::LoadSaveFile(dir, file_name) => {
+                    return Command::perform(
+                        interaction::choose_save_directory::load_files_in_directory(dir),
+                        move |r| {
+                            Bl3Message::Initialization(InitializationMessage::SaveFileLoaded(
+                                file_name,
+                                MessageResult::handle_result(r),
+                            ))
+                        },
+                    );
+                }
+                InitializationMessage::SaveFileLoaded(file_name, res) => match res {
+                    MessageResult::Success((dir, files)) => {
+                        return self.files_loaded(dir, files, Some(&file_name));
+                    }
+                    MessageResult::Error(e) => {
+                        let msg = format!("Failed to open {}: {}", file_name, e);
+
+                        error!("{}", msg);
+
+                        self.notification =
+                            Some(Notification::new(msg, NotificationSentiment::Negative));
+
+                        return self
+                            .update(Bl3Message::Initialization(InitializationMessage::LoadSaves));
+                    }
+                },
             },
             Bl3Message::LatestRelease(res) => match res {
                 MessageResult::Success(r) => {
@@ -224,21 +1125,39 @@ impl Application for Bl3Application {
             Bl3Message::UpdateToLatestRelease => {
                 if let Some(latest_release) = &self.latest_release {
                     self.is_updating = true;
+                    self.update_progress = 0.0;
 
-                    return Command::perform(
-                        update::download_release(latest_release.clone()),
-                        |r| {
-                            Bl3Message::UpdateToLatestReleaseCompleted(
-                                MessageResult::handle_result(r),
-                            )
-                        },
-                    );
+                    let (progress_sender, progress_receiver) = mpsc::unbounded();
+
+                    self.update_progress_receiver = Some(Arc::new(Mutex::new(progress_receiver)));
+
+                    return Command::batch(vec![
+                        Command::perform(
+                            update::download_release(latest_release.clone(), progress_sender),
+                            |r| {
+                                Bl3Message::UpdateToLatestReleaseCompleted(
+                                    MessageResult::handle_result(r),
+                                )
+                            },
+                        ),
+                        self.next_update_progress(),
+                    ]);
                 } else {
                     error!("Expected latest release to not be None when updating.");
                 }
             }
+            Bl3Message::UpdateProgress(progress) => {
+                if self.is_updating {
+                    self.update_progress = progress;
+
+                    if progress < 1.0 {
+                        return self.next_update_progress();
+                    }
+                }
+            }
             Bl3Message::UpdateToLatestReleaseCompleted(res) => {
                 self.is_updating = false;
+                self.update_progress_receiver = None;
 
                 match res {
                     MessageResult::Success(_) => {
@@ -286,6 +1205,20 @@ impl Application for Bl3Application {
                                     },
                                 )
                             }
+                            ChooseSaveInteractionMessage::RecentDirPressed(dir) => {
+                                self.view_state = ViewState::Loading;
+
+                                Command::perform(
+                                    interaction::choose_save_directory::load_files_in_directory(
+                                        dir,
+                                    ),
+                                    |r| {
+                                        Bl3Message::ChooseSave(ChooseSaveMessage::FilesLoaded(
+                                            MessageResult::handle_result(r),
+                                        ))
+                                    },
+                                )
+                            }
                         };
                     }
                     InteractionMessage::ManageSaveInteraction(manage_save_msg) => {
@@ -317,11 +1250,48 @@ impl Application for Bl3Application {
                                             ManageSaveView::TabBar(SaveTabBarView::Vehicle),
                                         )
                                     }
+                                    SaveTabBarInteractionMessage::Missions => {
+                                        self.view_state = ViewState::ManageSave(
+                                            ManageSaveView::TabBar(SaveTabBarView::Missions),
+                                        )
+                                    }
+                                    SaveTabBarInteractionMessage::Challenges => {
+                                        self.view_state = ViewState::ManageSave(
+                                            ManageSaveView::TabBar(SaveTabBarView::Challenges),
+                                        )
+                                    }
                                     SaveTabBarInteractionMessage::Settings => {
                                         self.view_state = ViewState::ManageSave(
                                             ManageSaveView::TabBar(SaveTabBarView::Settings),
                                         )
                                     }
+                                    SaveTabBarInteractionMessage::ResetTab => {
+                                        if let ViewState::ManageSave(ManageSaveView::TabBar(
+                                            tab_bar_view,
+                                        )) = &self.view_state
+                                        {
+                                            let tab_bar_view = tab_bar_view.clone();
+
+                                            match state_mappers::map_loaded_save_tab_to_state(
+                                                self,
+                                                &tab_bar_view,
+                                            ) {
+                                                Ok(_) => {
+                                                    self.notification = Some(Notification::new(
+                                                        format!(
+                                                            "Reset the {} tab to the values in the file.",
+                                                            tab_bar_view
+                                                        ),
+                                                        NotificationSentiment::Positive,
+                                                    ));
+                                                }
+                                                Err(e) => e.handle_ui_error(
+                                                    "Failed to reset tab",
+                                                    &mut self.notification,
+                                                ),
+                                            }
+                                        }
+                                    }
                                 }
                             }
                             ManageSaveInteractionMessage::General(general_msg) => match general_msg
@@ -332,20 +1302,266 @@ impl Application for Bl3Application {
                                         .general_state
                                         .guid_input = guid;
                                 }
-                                SaveGeneralInteractionMessage::Slot(slot) => {
-                                    let filename = format!("{:x}.sav", slot);
+                                SaveGeneralInteractionMessage::TravelStationSelected(
+                                    playthrough_index,
+                                    travel_station,
+                                ) => {
+                                    let is_unlocked = self
+                                        .manage_save_state
+                                        .current_file
+                                        .character_data
+                                        .is_travel_station_unlocked(
+                                            playthrough_index,
+                                            &travel_station,
+                                        );
 
+                                    if let Some(travel_station_state) = self
+                                        .manage_save_state
+                                        .save_view_state
+                                        .general_state
+                                        .travel_station_states
+                                        .get_mut(playthrough_index)
+                                    {
+                                        travel_station_state.selected = Some(travel_station);
+                                        travel_station_state.is_unlocked = is_unlocked;
+                                    }
+                                }
+                                SaveGeneralInteractionMessage::UnlockTravelStation(unlock) => {
                                     self.manage_save_state
                                         .save_view_state
                                         .general_state
-                                        .slot_input = slot;
-
+                                        .unlock_travel_station = unlock;
+                                }
+                                SaveGeneralInteractionMessage::SetPlaythrough(playthrough) => {
                                     self.manage_save_state
                                         .save_view_state
                                         .general_state
-                                        .filename_input = filename.clone();
-
-                                    self.manage_save_state.current_file.file_name = filename;
+                                        .playthrough_selected = playthrough;
+                                }
+                                SaveGeneralInteractionMessage::ResetPlaythrough(playthrough) => {
+                                    let general_state =
+                                        &mut self.manage_save_state.save_view_state.general_state;
+
+                                    general_state.reset_playthrough =
+                                        if general_state.reset_playthrough == Some(playthrough) {
+                                            None
+                                        } else {
+                                            Some(playthrough)
+                                        };
+
+                                    self.manage_save_state.reset_playthrough_save_pending = false;
+                                }
+                                SaveGeneralInteractionMessage::LegacyBadassRank(rank) => {
+                                    self.manage_save_state
+                                        .save_view_state
+                                        .general_state
+                                        .legacy_badass_rank_input =
+                                        rank.chars().filter(|c| c.is_ascii_digit()).collect();
+                                }
+                                SaveGeneralInteractionMessage::SetLastMap(map_path) => {
+                                    if let Some(map) = MapLocation::from_path(&map_path) {
+                                        let playthrough_index = self
+                                            .manage_save_state
+                                            .save_view_state
+                                            .general_state
+                                            .playthrough_selected
+                                            .index();
+
+                                        return self.update(Bl3Message::Interaction(
+                                            InteractionMessage::ManageSaveInteraction(
+                                                ManageSaveInteractionMessage::General(
+                                                    SaveGeneralInteractionMessage::TravelStationSelected(
+                                                        playthrough_index,
+                                                        TravelStation::from_path(map.travel_station),
+                                                    ),
+                                                ),
+                                            ),
+                                        ));
+                                    }
+                                }
+                                SaveGeneralInteractionMessage::MayhemLevel(level) => {
+                                    self.manage_save_state
+                                        .save_view_state
+                                        .general_state
+                                        .mayhem_level_input = level;
+                                }
+                                SaveGeneralInteractionMessage::Slot(slot) => {
+                                    let filename = format!("{:x}.sav", slot);
+
+                                    self.manage_save_state
+                                        .save_view_state
+                                        .general_state
+                                        .slot_input = slot;
+
+                                    self.manage_save_state
+                                        .save_view_state
+                                        .general_state
+                                        .filename_input = filename.clone();
+
+                                    self.manage_save_state.current_file.file_name = filename;
+                                }
+                                SaveGeneralInteractionMessage::GenerateShareQr => {
+                                    let mut current_file =
+                                        self.manage_save_state.current_file.clone();
+
+                                    let share_qr_svg = manage_save::map_all_states_to_save(
+                                        &mut self.manage_save_state,
+                                        &mut current_file,
+                                    )
+                                    .and_then(|_| {
+                                        interaction::manage_save::general::generate_share_qr_svg(
+                                            &current_file,
+                                        )
+                                    });
+
+                                    match share_qr_svg {
+                                        Ok(share_qr_svg) => {
+                                            self.manage_save_state
+                                                .save_view_state
+                                                .general_state
+                                                .share_qr_svg = Some(share_qr_svg);
+                                        }
+                                        Err(e) => {
+                                            let msg =
+                                                format!("Failed to generate share QR code: {}", e);
+
+                                            error!("{}", msg);
+
+                                            self.notification = Some(Notification::new(
+                                                msg,
+                                                NotificationSentiment::Negative,
+                                            ));
+                                        }
+                                    }
+                                }
+                                SaveGeneralInteractionMessage::ExportCharacterReport => {
+                                    let mut current_file =
+                                        self.manage_save_state.current_file.clone();
+
+                                    match manage_save::map_all_states_to_save(
+                                        &mut self.manage_save_state,
+                                        &mut current_file,
+                                    ) {
+                                        Ok(_) => {
+                                            let report = character_report(&current_file);
+
+                                            let file_name = format!(
+                                                "{} Report.md",
+                                                current_file
+                                                    .character_data
+                                                    .character
+                                                    .preferred_character_name
+                                            );
+
+                                            return Command::perform(
+                                                interaction::manage_save::general::export_character_report(
+                                                    report, file_name,
+                                                ),
+                                                |r| {
+                                                    Bl3Message::Interaction(
+                                                        InteractionMessage::ManageSaveInteraction(
+                                                            ManageSaveInteractionMessage::General(
+                                                                SaveGeneralInteractionMessage::ExportCharacterReportCompleted(
+                                                                    MessageResult::handle_result(r),
+                                                                ),
+                                                            ),
+                                                        ),
+                                                    )
+                                                },
+                                            );
+                                        }
+                                        Err(e) => e.handle_ui_error(
+                                            "Failed to export character report",
+                                            &mut self.notification,
+                                        ),
+                                    }
+                                }
+                                SaveGeneralInteractionMessage::ExportCharacterReportCompleted(
+                                    res,
+                                ) => {
+                                    self.notification = Some(export_notification(res, "report"));
+                                }
+                                SaveGeneralInteractionMessage::TakeSnapshot => {
+                                    let mut current_file =
+                                        self.manage_save_state.current_file.clone();
+
+                                    let snapshot = manage_save::map_all_states_to_save(
+                                        &mut self.manage_save_state,
+                                        &mut current_file,
+                                    )
+                                    .and_then(|_| current_file.as_bytes());
+
+                                    match snapshot {
+                                        Ok((output, save)) => {
+                                            return Command::perform(
+                                                interaction::manage_save::general::take_snapshot(
+                                                    self.config.backup_dir().to_path_buf(),
+                                                    save,
+                                                    output,
+                                                ),
+                                                |r| {
+                                                    Bl3Message::Interaction(
+                                                        InteractionMessage::ManageSaveInteraction(
+                                                            ManageSaveInteractionMessage::General(
+                                                                SaveGeneralInteractionMessage::TakeSnapshotCompleted(
+                                                                    MessageResult::handle_result(r),
+                                                                ),
+                                                            ),
+                                                        ),
+                                                    )
+                                                },
+                                            );
+                                        }
+                                        Err(e) => e.handle_ui_error(
+                                            "Failed to take snapshot",
+                                            &mut self.notification,
+                                        ),
+                                    }
+                                }
+                                SaveGeneralInteractionMessage::TakeSnapshotCompleted(res) => {
+                                    self.notification = Some(match res {
+                                        MessageResult::Success(snapshot_file) => Notification::new(
+                                            format!("Took snapshot {}.", snapshot_file.display()),
+                                            NotificationSentiment::Positive,
+                                        ),
+                                        MessageResult::Error(e) => {
+                                            let msg = format!("Failed to take snapshot: {}", e);
+
+                                            error!("{}", msg);
+
+                                            Notification::new(msg, NotificationSentiment::Negative)
+                                        }
+                                    });
+                                }
+                                SaveGeneralInteractionMessage::RevealFile => {
+                                    return Command::perform(
+                                        interaction::settings::reveal_file(
+                                            self.selected_file_path(),
+                                        ),
+                                        |r| {
+                                            Bl3Message::Interaction(
+                                                InteractionMessage::ManageSaveInteraction(
+                                                    ManageSaveInteractionMessage::General(
+                                                        SaveGeneralInteractionMessage::RevealFileCompleted(
+                                                            MessageResult::handle_result(r),
+                                                        ),
+                                                    ),
+                                                ),
+                                            )
+                                        },
+                                    );
+                                }
+                                SaveGeneralInteractionMessage::RevealFileCompleted(res) => {
+                                    res.handle_ui_error(
+                                        "Failed to reveal file",
+                                        &mut self.notification,
+                                    );
+                                }
+                                SaveGeneralInteractionMessage::CloseShareQr => {
+                                    self.manage_save_state
+                                        .save_view_state
+                                        .general_state
+                                        .share_qr_svg = None;
                                 }
                                 SaveGeneralInteractionMessage::GenerateGuidPressed => {
                                     let guid =
@@ -372,11 +1588,9 @@ impl Application for Bl3Application {
                                             .name_input = name_input;
                                     }
                                     SaveCharacterInteractionMessage::Level(level) => {
-                                        let xp_points = if level > 0 {
-                                            REQUIRED_XP_LIST[level as usize - 1][0]
-                                        } else {
-                                            0
-                                        };
+                                        let level = clamp_level(level);
+
+                                        let xp_points = level_to_xp(level).unwrap_or(0);
 
                                         let character_state = &mut self
                                             .manage_save_state
@@ -386,6 +1600,10 @@ impl Application for Bl3Application {
                                         character_state.level_input = level;
 
                                         character_state.experience_points_input = xp_points;
+
+                                        manage_save::character::map_save_to_spent_ability_points(
+                                            &mut self.manage_save_state,
+                                        );
                                     }
                                     SaveCharacterInteractionMessage::ExperiencePoints(xp) => {
                                         let level = experience_to_level(xp).unwrap_or(1);
@@ -398,6 +1616,10 @@ impl Application for Bl3Application {
                                         character_state.experience_points_input = xp;
 
                                         character_state.level_input = level;
+
+                                        manage_save::character::map_save_to_spent_ability_points(
+                                            &mut self.manage_save_state,
+                                        );
                                     }
                                     SaveCharacterInteractionMessage::AbilityPoints(points) => {
                                         self.manage_save_state
@@ -405,6 +1627,289 @@ impl Application for Bl3Application {
                                             .character_state
                                             .ability_points_input = points;
                                     }
+                                    SaveCharacterInteractionMessage::SetExpectedAbilityPointsPressed => {
+                                        let character_state = &mut self
+                                            .manage_save_state
+                                            .save_view_state
+                                            .character_state;
+
+                                        character_state.ability_points_input =
+                                            character_state.expected_ability_points();
+                                    }
+                                    SaveCharacterInteractionMessage::SkillTree(
+                                        SkillTreeInteractionMessage::SetSkillRank(node_id, rank),
+                                    ) => {
+                                        self.manage_save_state
+                                            .save_view_state
+                                            .character_state
+                                            .skill_tree
+                                            .set_skill_rank(node_id, rank);
+
+                                        manage_save::character::map_save_to_spent_ability_points(
+                                            &mut self.manage_save_state,
+                                        );
+                                    }
+                                    SaveCharacterInteractionMessage::ExportStatsAsCsv => {
+                                        let mut current_file =
+                                            self.manage_save_state.current_file.clone();
+
+                                        match manage_save::map_all_states_to_save(
+                                            &mut self.manage_save_state,
+                                            &mut current_file,
+                                        ) {
+                                            Ok(_) => {
+                                                let csv = stats_export::to_csv(
+                                                    &stats_export::character_stats(&current_file),
+                                                );
+
+                                                let file_name = format!(
+                                                    "{} Stats.csv",
+                                                    current_file
+                                                        .character_data
+                                                        .character
+                                                        .preferred_character_name
+                                                );
+
+                                                return Command::perform(
+                                                    interaction::export_csv(csv, file_name),
+                                                    |r| {
+                                                        Bl3Message::Interaction(
+                                                            InteractionMessage::ManageSaveInteraction(
+                                                                ManageSaveInteractionMessage::Character(
+                                                                    SaveCharacterInteractionMessage::ExportStatsAsCsvCompleted(
+                                                                        MessageResult::handle_result(r),
+                                                                    ),
+                                                                ),
+                                                            ),
+                                                        )
+                                                    },
+                                                );
+                                            }
+                                            Err(e) => e.handle_ui_error(
+                                                "Failed to export stats",
+                                                &mut self.notification,
+                                            ),
+                                        }
+                                    }
+                                    SaveCharacterInteractionMessage::ExportStatsAsCsvCompleted(
+                                        res,
+                                    ) => {
+                                        self.notification = Some(export_notification(res, "CSV"));
+                                    }
+                                    SaveCharacterInteractionMessage::ExportBuildPressed => {
+                                        let mut current_file =
+                                            self.manage_save_state.current_file.clone();
+
+                                        let build = manage_save::map_all_states_to_save(
+                                            &mut self.manage_save_state,
+                                            &mut current_file,
+                                        )
+                                        .and_then(|_| {
+                                            BuildPreset::from_character(
+                                                &current_file.character_data,
+                                            )
+                                        });
+
+                                        match build {
+                                            Ok(build) => {
+                                                let file_name = format!(
+                                                    "{} Build.json",
+                                                    build.player_class
+                                                );
+
+                                                return Command::perform(
+                                                    interaction::manage_save::character::export_build(
+                                                        build, file_name,
+                                                    ),
+                                                    |r| {
+                                                        Bl3Message::Interaction(
+                                                            InteractionMessage::ManageSaveInteraction(
+                                                                ManageSaveInteractionMessage::Character(
+                                                                    SaveCharacterInteractionMessage::ExportBuildCompleted(
+                                                                        MessageResult::handle_result(r),
+                                                                    ),
+                                                                ),
+                                                            ),
+                                                        )
+                                                    },
+                                                );
+                                            }
+                                            Err(e) => e.handle_ui_error(
+                                                "Failed to export build",
+                                                &mut self.notification,
+                                            ),
+                                        }
+                                    }
+                                    SaveCharacterInteractionMessage::ExportBuildCompleted(res) => {
+                                        match res {
+                                            MessageResult::Success(output_file) => {
+                                                self.notification = Some(Notification::new(
+                                                    format!(
+                                                        "Exported build to {}.",
+                                                        output_file.display()
+                                                    ),
+                                                    NotificationSentiment::Positive,
+                                                ));
+                                            }
+                                            MessageResult::Error(e) => {
+                                                let msg = format!("Failed to export build: {}", e);
+
+                                                error!("{}", msg);
+
+                                                self.notification = Some(Notification::new(
+                                                    msg,
+                                                    NotificationSentiment::Negative,
+                                                ));
+                                            }
+                                        }
+                                    }
+                                    SaveCharacterInteractionMessage::ImportBuildPressed => {
+                                        return Command::perform(
+                                            interaction::manage_save::character::import_build(),
+                                            |r| {
+                                                Bl3Message::Interaction(
+                                                    InteractionMessage::ManageSaveInteraction(
+                                                        ManageSaveInteractionMessage::Character(
+                                                            SaveCharacterInteractionMessage::BuildImported(
+                                                                MessageResult::handle_result(r),
+                                                            ),
+                                                        ),
+                                                    ),
+                                                )
+                                            },
+                                        );
+                                    }
+                                    SaveCharacterInteractionMessage::BuildImported(res) => {
+                                        let build = match res {
+                                            MessageResult::Success(build) => build,
+                                            MessageResult::Error(e) => {
+                                                let msg = format!("Failed to import build: {}", e);
+
+                                                error!("{}", msg);
+
+                                                self.notification = Some(Notification::new(
+                                                    msg,
+                                                    NotificationSentiment::Negative,
+                                                ));
+
+                                                return Command::none();
+                                            }
+                                        };
+
+                                        let mut current_file =
+                                            self.manage_save_state.current_file.clone();
+
+                                        let changes = manage_save::map_all_states_to_save(
+                                            &mut self.manage_save_state,
+                                            &mut current_file,
+                                        )
+                                        .and_then(|_| build.changes(&current_file.character_data));
+
+                                        match changes {
+                                            Ok(changes) if changes.is_empty() => {
+                                                self.notification = Some(Notification::new(
+                                                    "This character already matches the build.",
+                                                    NotificationSentiment::Positive,
+                                                ));
+                                            }
+                                            Ok(changes) => {
+                                                self.manage_save_state
+                                                    .save_view_state
+                                                    .character_state
+                                                    .pending_build = Some(build);
+
+                                                return Command::perform(
+                                                    interaction::manage_save::character::confirm_import_build(
+                                                        changes,
+                                                    ),
+                                                    |r| {
+                                                        Bl3Message::Interaction(
+                                                            InteractionMessage::ManageSaveInteraction(
+                                                                ManageSaveInteractionMessage::Character(
+                                                                    SaveCharacterInteractionMessage::ImportBuildConfirmed(
+                                                                        MessageResult::handle_result(r),
+                                                                    ),
+                                                                ),
+                                                            ),
+                                                        )
+                                                    },
+                                                );
+                                            }
+                                            Err(e) => e.handle_ui_error(
+                                                "Failed to import build",
+                                                &mut self.notification,
+                                            ),
+                                        }
+                                    }
+                                    SaveCharacterInteractionMessage::ImportBuildConfirmed(res) => {
+                                        let build = match self
+                                            .manage_save_state
+                                            .save_view_state
+                                            .character_state
+                                            .pending_build
+                                            .take()
+                                        {
+                                            Some(build) => build,
+                                            None => return Command::none(),
+                                        };
+
+                                        match res {
+                                            MessageResult::Success(true) => {
+                                                let mut current_file =
+                                                    self.manage_save_state.current_file.clone();
+
+                                                if let Err(e) = manage_save::map_all_states_to_save(
+                                                    &mut self.manage_save_state,
+                                                    &mut current_file,
+                                                )
+                                                .and_then(|_| {
+                                                    build.apply(&mut current_file.character_data)
+                                                }) {
+                                                    e.handle_ui_error(
+                                                        "Failed to import build",
+                                                        &mut self.notification,
+                                                    );
+
+                                                    return Command::none();
+                                                }
+
+                                                self.manage_save_state.current_file = current_file;
+
+                                                manage_save::character::map_save_to_character_state(
+                                                    &mut self.manage_save_state,
+                                                );
+
+                                                if let Err(e) =
+                                                    manage_save::inventory::map_save_to_inventory_state(
+                                                        &mut self.manage_save_state,
+                                                    )
+                                                {
+                                                    e.handle_ui_error(
+                                                        "Failed to map imported items to inventory",
+                                                        &mut self.notification,
+                                                    );
+
+                                                    return Command::none();
+                                                }
+
+                                                self.notification = Some(Notification::new(
+                                                    "Imported build, press Save to keep the changes.",
+                                                    NotificationSentiment::Positive,
+                                                ));
+                                            }
+                                            MessageResult::Success(false) => (),
+                                            MessageResult::Error(e) => {
+                                                let msg = format!("Failed to import build: {}", e);
+
+                                                error!("{}", msg);
+
+                                                self.notification = Some(Notification::new(
+                                                    msg,
+                                                    NotificationSentiment::Negative,
+                                                ));
+                                            }
+                                        }
+                                    }
                                     SaveCharacterInteractionMessage::SduMessage(sdu_message) => {
                                         let sdu_unlocker = &mut self
                                             .manage_save_state
@@ -437,6 +1942,10 @@ impl Application for Bl3Application {
                                             CharacterSduMessage::Heavy(level) => {
                                                 sdu_unlocker.heavy.input = level;
                                             }
+                                            CharacterSduMessage::Max(sdu_slot) => {
+                                                sdu_unlocker.field_mut(&sdu_slot).input =
+                                                    sdu_slot.maximum();
+                                            }
                                         }
                                     }
                                     SaveCharacterInteractionMessage::MaxSduSlotsPressed => {
@@ -493,6 +2002,59 @@ impl Application for Bl3Application {
                                             CharacterAmmoMessage::Heavy(amount) => {
                                                 ammo_setter.heavy.input = amount;
                                             }
+                                            CharacterAmmoMessage::Max(ammo_pool) => {
+                                                ammo_setter.field_mut(&ammo_pool).input =
+                                                    ammo_pool.maximum();
+                                            }
+                                        }
+                                    }
+                                    SaveCharacterInteractionMessage::JumpToEquippedItem(
+                                        inventory_index,
+                                    ) => {
+                                        let item_editor_state = &mut self
+                                            .manage_save_state
+                                            .save_view_state
+                                            .inventory_state
+                                            .item_editor_state;
+
+                                        let item_pos = item_editor_state
+                                            .items()
+                                            .iter()
+                                            .position(|i| i.index == inventory_index);
+
+                                        if let Some(item_pos) = item_pos {
+                                            // The item may be hidden by a search otherwise
+                                            item_editor_state.search_items_input.clear();
+                                            item_editor_state.item_list_tab_type =
+                                                ItemListTabType::Items;
+
+                                            self.view_state = ViewState::ManageSave(
+                                                ManageSaveView::TabBar(SaveTabBarView::Inventory),
+                                            );
+
+                                            return self.update(Bl3Message::Interaction(
+                                                InteractionMessage::ManageSaveInteraction(
+                                                    ManageSaveInteractionMessage::Inventory(
+                                                        SaveInventoryInteractionMessage::Editor(
+                                                            ItemEditorInteractionMessage::ItemPressed(
+                                                                item_pos,
+                                                            ),
+                                                        ),
+                                                    ),
+                                                ),
+                                            ));
+                                        } else {
+                                            let msg = format!(
+                                                "Failed to jump to item: couldn't find an item with index {}.",
+                                                inventory_index
+                                            );
+
+                                            error!("{}", msg);
+
+                                            self.notification = Some(Notification::new(
+                                                msg,
+                                                NotificationSentiment::Negative,
+                                            ));
                                         }
                                     }
                                     SaveCharacterInteractionMessage::MaxAmmoAmountsPressed => {
@@ -523,6 +2085,16 @@ impl Application for Bl3Application {
                                             .save_view_state
                                             .character_state
                                             .player_class_selected_class = player_class;
+
+                                        self.manage_save_state
+                                            .save_view_state
+                                            .character_state
+                                            .skin_selectors
+                                            .set_player_class(player_class);
+
+                                        manage_save::character::map_save_to_spent_ability_points(
+                                            &mut self.manage_save_state,
+                                        );
                                     }
                                     SaveCharacterInteractionMessage::SkinMessage(skin_message) => {
                                         let skin_selectors = &mut self
@@ -586,6 +2158,15 @@ impl Application for Bl3Application {
                                     SaveInventoryInteractionMessage::Editor(
                                         item_editor_message,
                                     ) => {
+                                        let selected_layout =
+                                            item_editor_message.inventory_layout_selected();
+
+                                        let favorite_anointments_changed =
+                                            item_editor_message.favorite_anointments_changed();
+
+                                        let favorite_items_changed =
+                                            item_editor_message.favorite_items_changed();
+
                                         let res = item_editor_message.update_state(
                                             &mut self
                                                 .manage_save_state
@@ -599,6 +2180,34 @@ impl Application for Bl3Application {
 
                                         self.notification = res.notification;
 
+                                        if let Some(layout) = selected_layout {
+                                            return self.set_inventory_layout(layout);
+                                        }
+
+                                        if favorite_items_changed {
+                                            let favorite_items = self
+                                                .manage_save_state
+                                                .save_view_state
+                                                .inventory_state
+                                                .item_editor_state
+                                                .favorite_item_serials();
+
+                                            return self.set_favorite_items(favorite_items);
+                                        }
+
+                                        if favorite_anointments_changed {
+                                            let favorite_anointments = self
+                                                .manage_save_state
+                                                .save_view_state
+                                                .inventory_state
+                                                .item_editor_state
+                                                .favorite_anointments
+                                                .clone();
+
+                                            return self
+                                                .set_favorite_anointments(favorite_anointments);
+                                        }
+
                                         if let Some(command) = res.command {
                                             return command.map(|m| {
                                                 Bl3Message::Interaction(
@@ -613,33 +2222,617 @@ impl Application for Bl3Application {
                                             });
                                         }
                                     }
-                                }
-                            }
-                            ManageSaveInteractionMessage::Currency(currency_msg) => {
-                                match currency_msg {
-                                    SaveCurrencyInteractionMessage::Money(money) => {
-                                        self.manage_save_state
-                                            .save_view_state
-                                            .currency_state
-                                            .money_input = money;
-                                    }
-                                    SaveCurrencyInteractionMessage::Eridium(eridium) => {
-                                        self.manage_save_state
+                                    SaveInventoryInteractionMessage::EquipBestItems => {
+                                        let mut items = self
+                                            .manage_save_state
                                             .save_view_state
-                                            .currency_state
-                                            .eridium_input = eridium;
-                                    }
-                                    SaveCurrencyInteractionMessage::MaxMoneyPressed => {
-                                        self.manage_save_state
+                                            .inventory_state
+                                            .item_editor_state
+                                            .items()
+                                            .iter()
+                                            .map(|i| (i.index, i.item.clone()))
+                                            .collect::<Vec<_>>();
+
+                                        items.sort_by_key(|(i, _)| *i);
+
+                                        let (indexes, items): (Vec<_>, Vec<_>) =
+                                            items.into_iter().unzip();
+
+                                        // The class picked on the Character tab, it may not be saved yet
+                                        let player_class = self
+                                            .manage_save_state
                                             .save_view_state
-                                            .currency_state
-                                            .money_input = i32::MAX;
-                                    }
-                                    SaveCurrencyInteractionMessage::MaxEridiumPressed => {
+                                            .character_state
+                                            .player_class_selected_class;
+
+                                        let best_items = self
+                                            .manage_save_state
+                                            .current_file
+                                            .character_data
+                                            .best_items_for_slots(&items, player_class)
+                                            .into_iter()
+                                            .map(|(slot, i)| (slot, indexes[i]))
+                                            .collect::<Vec<_>>();
+
+                                        let msg = format!(
+                                            "Equipped the best items for {} gear slots, they will be saved with the inventory.",
+                                            best_items.len()
+                                        );
+
                                         self.manage_save_state
                                             .save_view_state
-                                            .currency_state
-                                            .eridium_input = i32::MAX;
+                                            .inventory_state
+                                            .best_items = best_items;
+
+                                        self.notification = Some(Notification::new(
+                                            msg,
+                                            NotificationSentiment::Positive,
+                                        ));
+                                    }
+                                    SaveInventoryInteractionMessage::DeduplicateInventory => {
+                                        let mut current_file =
+                                            self.manage_save_state.current_file.clone();
+
+                                        let removed = match manage_save::map_all_states_to_save(
+                                            &mut self.manage_save_state,
+                                            &mut current_file,
+                                        )
+                                        .and_then(|_| {
+                                            current_file
+                                                .character_data
+                                                .remove_duplicate_inventory_items()
+                                        }) {
+                                            Ok(removed) => removed,
+                                            Err(e) => {
+                                                e.handle_ui_error(
+                                                    "Failed to remove duplicate items",
+                                                    &mut self.notification,
+                                                );
+
+                                                return Command::none();
+                                            }
+                                        };
+
+                                        self.manage_save_state.current_file = current_file;
+
+                                        if let Err(e) =
+                                            manage_save::inventory::map_save_to_inventory_state(
+                                                &mut self.manage_save_state,
+                                            )
+                                        {
+                                            e.handle_ui_error(
+                                                "Failed to map inventory after removing duplicates",
+                                                &mut self.notification,
+                                            );
+
+                                            return Command::none();
+                                        }
+
+                                        self.notification = Some(Notification::new(
+                                            format!("Removed {} duplicate items", removed),
+                                            NotificationSentiment::Positive,
+                                        ));
+                                    }
+                                    SaveInventoryInteractionMessage::ClearInventory => {
+                                        let count = self
+                                            .manage_save_state
+                                            .save_view_state
+                                            .inventory_state
+                                            .item_editor_state
+                                            .items()
+                                            .len();
+
+                                        if count == 0 {
+                                            self.notification = Some(Notification::new(
+                                                "The inventory is already empty.",
+                                                NotificationSentiment::Info,
+                                            ));
+
+                                            return Command::none();
+                                        }
+
+                                        self.confirmation_dialog = Some(ConfirmationDialog::new(
+                                            "Remove All Items",
+                                            format!(
+                                                "All {} items will be removed from the inventory, including equipped items. The file isn't changed until you save.",
+                                                count
+                                            ),
+                                            "Remove All",
+                                            Bl3Message::Interaction(
+                                                InteractionMessage::ManageSaveInteraction(
+                                                    ManageSaveInteractionMessage::Inventory(
+                                                        SaveInventoryInteractionMessage::ClearInventoryConfirmed,
+                                                    ),
+                                                ),
+                                            ),
+                                        ));
+                                    }
+                                    SaveInventoryInteractionMessage::ClearInventoryConfirmed => {
+                                        let removed = self
+                                            .manage_save_state
+                                            .save_view_state
+                                            .inventory_state
+                                            .item_editor_state
+                                            .items()
+                                            .len();
+
+                                        self.manage_save_state
+                                            .current_file
+                                            .character_data
+                                            .clear_inventory_items();
+
+                                        if let Err(e) =
+                                            manage_save::inventory::map_save_to_inventory_state(
+                                                &mut self.manage_save_state,
+                                            )
+                                        {
+                                            e.handle_ui_error(
+                                                "Failed to map inventory after removing all items",
+                                                &mut self.notification,
+                                            );
+
+                                            return Command::none();
+                                        }
+
+                                        self.manage_save_state
+                                            .save_view_state
+                                            .character_state
+                                            .equipped_items
+                                            .set_items(Vec::new());
+
+                                        *self
+                                            .manage_save_state
+                                            .save_view_state
+                                            .inventory_state
+                                            .removed_all_items
+                                            .get_or_insert(0) += removed;
+                                    }
+                                    SaveInventoryInteractionMessage::LevelRangeMinLevel(level) => {
+                                        self.manage_save_state
+                                            .save_view_state
+                                            .inventory_state
+                                            .level_range_state
+                                            .min_level_input = level;
+                                    }
+                                    SaveInventoryInteractionMessage::LevelRangeMaxLevel(level) => {
+                                        self.manage_save_state
+                                            .save_view_state
+                                            .inventory_state
+                                            .level_range_state
+                                            .max_level_input = level;
+                                    }
+                                    SaveInventoryInteractionMessage::NormalizeItemLevelRange {
+                                        min,
+                                        max,
+                                    } => {
+                                        match self
+                                            .manage_save_state
+                                            .save_view_state
+                                            .inventory_state
+                                            .item_editor_state
+                                            .normalize_item_levels(min as usize, max as usize)
+                                        {
+                                            Ok(summary) => {
+                                                let msg = format!(
+                                                    "Clamped {} items up and {} items down to levels {}-{}, {} were already in range.",
+                                                    summary.clamped_up,
+                                                    summary.clamped_down,
+                                                    min,
+                                                    max,
+                                                    summary.in_range
+                                                );
+
+                                                self.notification = Some(Notification::new(
+                                                    msg,
+                                                    NotificationSentiment::Positive,
+                                                ));
+                                            }
+                                            Err(e) => e.handle_ui_error(
+                                                "Failed to normalize item levels",
+                                                &mut self.notification,
+                                            ),
+                                        }
+                                    }
+                                    SaveInventoryInteractionMessage::ShiftCodeInputChanged(
+                                        code,
+                                    ) => {
+                                        let shift_code_import_state = &mut self
+                                            .manage_save_state
+                                            .save_view_state
+                                            .inventory_state
+                                            .shift_code_import_state;
+
+                                        shift_code_import_state.shift_code_input = code;
+
+                                        shift_code_import_state.shift_code_preview.clear();
+                                    }
+                                    SaveInventoryInteractionMessage::PreviewShiftCodePressed => {
+                                        let shift_code_import_state = &mut self
+                                            .manage_save_state
+                                            .save_view_state
+                                            .inventory_state
+                                            .shift_code_import_state;
+
+                                        match parse_shift_inventory_code(
+                                            &shift_code_import_state.shift_code_input,
+                                        ) {
+                                            Ok(items) => {
+                                                shift_code_import_state.shift_code_preview = items;
+                                            }
+                                            Err(e) => e.handle_ui_error(
+                                                "Failed to read SHiFT inventory code",
+                                                &mut self.notification,
+                                            ),
+                                        }
+                                    }
+                                    SaveInventoryInteractionMessage::ImportShiftInventoryCode(
+                                        code,
+                                    ) => match parse_shift_inventory_code(&code) {
+                                        Ok(items) => {
+                                            let inventory_state = &mut self
+                                                .manage_save_state
+                                                .save_view_state
+                                                .inventory_state;
+
+                                            let number_of_items = items.len();
+
+                                            let mut item_pos = 0;
+
+                                            for item in items {
+                                                item_pos = inventory_state
+                                                    .item_editor_state
+                                                    .add_item(item);
+                                            }
+
+                                            inventory_state.item_editor_state.selected_item_index =
+                                                item_pos;
+
+                                            inventory_state
+                                                .item_editor_state
+                                                .map_current_item_if_exists_to_editor_state()
+                                                .handle_ui_error(
+                                                    "Failed to map imported item to editor",
+                                                    &mut self.notification,
+                                                );
+
+                                            inventory_state.shift_code_import_state =
+                                                ShiftCodeImportState::default();
+
+                                            let msg = format!(
+                                                "Imported {} items from SHiFT inventory code.",
+                                                number_of_items
+                                            );
+
+                                            self.notification = Some(Notification::new(
+                                                msg,
+                                                NotificationSentiment::Positive,
+                                            ));
+                                        }
+                                        Err(e) => e.handle_ui_error(
+                                            "Failed to import SHiFT inventory code",
+                                            &mut self.notification,
+                                        ),
+                                    },
+                                    SaveInventoryInteractionMessage::CancelShiftCodeImport => {
+                                        self.manage_save_state
+                                            .save_view_state
+                                            .inventory_state
+                                            .shift_code_import_state
+                                            .shift_code_preview
+                                            .clear();
+                                    }
+                                    SaveInventoryInteractionMessage::Transfer(transfer_msg) => {
+                                        match transfer_msg {
+                                            TransferInteractionMessage::Open => {
+                                                let current_file_name =
+                                                    &self.manage_save_state.current_file.file_name;
+
+                                                let targets = self
+                                                    .loaded_files
+                                                    .iter()
+                                                    .filter(|f| match f {
+                                                        Bl3FileType::PcSave(s)
+                                                        | Bl3FileType::Ps4Save(s) => {
+                                                            s.file_name != *current_file_name
+                                                        }
+                                                        _ => false,
+                                                    })
+                                                    .cloned()
+                                                    .collect::<Vec<_>>();
+
+                                                if targets.is_empty() {
+                                                    self.notification = Some(Notification::new(
+                                                        "There are no other saves to transfer items with.",
+                                                        NotificationSentiment::Negative,
+                                                    ));
+
+                                                    return Command::none();
+                                                }
+
+                                                let mut current_file =
+                                                    self.manage_save_state.current_file.clone();
+
+                                                if let Err(e) = manage_save::map_all_states_to_save(
+                                                    &mut self.manage_save_state,
+                                                    &mut current_file,
+                                                ) {
+                                                    e.handle_ui_error(
+                                                        "Failed to prepare save for transfer",
+                                                        &mut self.notification,
+                                                    );
+
+                                                    return Command::none();
+                                                }
+
+                                                self.manage_save_state
+                                                    .save_view_state
+                                                    .inventory_state
+                                                    .transfer_state = TransferState {
+                                                    left: Some(current_file),
+                                                    targets,
+                                                    ..TransferState::default()
+                                                };
+                                            }
+                                            TransferInteractionMessage::TargetSelected(target) => {
+                                                let transfer_state = &mut self
+                                                    .manage_save_state
+                                                    .save_view_state
+                                                    .inventory_state
+                                                    .transfer_state;
+
+                                                if let Bl3FileType::PcSave(save)
+                                                | Bl3FileType::Ps4Save(save) = &target
+                                                {
+                                                    // Undo any moves made with the previous target
+                                                    if transfer_state.has_moved_items {
+                                                        let mut current_file = self
+                                                            .manage_save_state
+                                                            .current_file
+                                                            .clone();
+
+                                                        if let Err(e) =
+                                                            manage_save::map_all_states_to_save(
+                                                                &mut self.manage_save_state,
+                                                                &mut current_file,
+                                                            )
+                                                        {
+                                                            e.handle_ui_error(
+                                                                "Failed to prepare save for transfer",
+                                                                &mut self.notification,
+                                                            );
+
+                                                            return Command::none();
+                                                        }
+
+                                                        self.manage_save_state
+                                                            .save_view_state
+                                                            .inventory_state
+                                                            .transfer_state
+                                                            .left = Some(current_file);
+                                                    }
+
+                                                    let transfer_state = &mut self
+                                                        .manage_save_state
+                                                        .save_view_state
+                                                        .inventory_state
+                                                        .transfer_state;
+
+                                                    transfer_state.right = Some(save.clone());
+                                                    transfer_state.target_selected =
+                                                        Some(target.clone());
+                                                    transfer_state.left_selected.clear();
+                                                    transfer_state.right_selected.clear();
+                                                    transfer_state.has_moved_items = false;
+                                                }
+                                            }
+                                            TransferInteractionMessage::LeftItemToggled(
+                                                index,
+                                                selected,
+                                            ) => {
+                                                let left_selected = &mut self
+                                                    .manage_save_state
+                                                    .save_view_state
+                                                    .inventory_state
+                                                    .transfer_state
+                                                    .left_selected;
+
+                                                if selected {
+                                                    left_selected.insert(index);
+                                                } else {
+                                                    left_selected.remove(&index);
+                                                }
+                                            }
+                                            TransferInteractionMessage::RightItemToggled(
+                                                index,
+                                                selected,
+                                            ) => {
+                                                let right_selected = &mut self
+                                                    .manage_save_state
+                                                    .save_view_state
+                                                    .inventory_state
+                                                    .transfer_state
+                                                    .right_selected;
+
+                                                if selected {
+                                                    right_selected.insert(index);
+                                                } else {
+                                                    right_selected.remove(&index);
+                                                }
+                                            }
+                                            TransferInteractionMessage::MoveRight
+                                            | TransferInteractionMessage::MoveLeft => {
+                                                let transfer_state = &mut self
+                                                    .manage_save_state
+                                                    .save_view_state
+                                                    .inventory_state
+                                                    .transfer_state;
+
+                                                if let (Some(left), Some(right)) = (
+                                                    &mut transfer_state.left,
+                                                    &mut transfer_state.right,
+                                                ) {
+                                                    let res = if matches!(
+                                                        transfer_msg,
+                                                        TransferInteractionMessage::MoveRight
+                                                    ) {
+                                                        let indexes = transfer_state
+                                                            .left_selected
+                                                            .iter()
+                                                            .copied()
+                                                            .collect::<Vec<_>>();
+
+                                                        transfer_items(
+                                                            &mut left.character_data,
+                                                            &mut right.character_data,
+                                                            &indexes,
+                                                        )
+                                                    } else {
+                                                        let indexes = transfer_state
+                                                            .right_selected
+                                                            .iter()
+                                                            .copied()
+                                                            .collect::<Vec<_>>();
+
+                                                        transfer_items(
+                                                            &mut right.character_data,
+                                                            &mut left.character_data,
+                                                            &indexes,
+                                                        )
+                                                    };
+
+                                                    match res {
+                                                        Ok(_) => {
+                                                            transfer_state.left_selected.clear();
+                                                            transfer_state.right_selected.clear();
+                                                            transfer_state.has_moved_items = true;
+                                                        }
+                                                        Err(e) => e.handle_ui_error(
+                                                            "Failed to move items",
+                                                            &mut self.notification,
+                                                        ),
+                                                    }
+                                                }
+                                            }
+                                            TransferInteractionMessage::Confirm => {
+                                                let transfer_state = mem::take(
+                                                    &mut self
+                                                        .manage_save_state
+                                                        .save_view_state
+                                                        .inventory_state
+                                                        .transfer_state,
+                                                );
+
+                                                let existing_right =
+                                                    match transfer_state.target_selected {
+                                                        Some(
+                                                            Bl3FileType::PcSave(save)
+                                                            | Bl3FileType::Ps4Save(save),
+                                                        ) => save,
+                                                        _ => return Command::none(),
+                                                    };
+
+                                                if let (Some(new_left), Some(new_right)) =
+                                                    (transfer_state.left, transfer_state.right)
+                                                {
+                                                    return Command::perform(
+                                                        interaction::file_save::save_transferred_files(
+                                                            self.config.backup_dir().to_path_buf(),
+                                                            self.config.saves_dir().to_path_buf(),
+                                                            self.manage_save_state.current_file.clone(),
+                                                            new_left,
+                                                            existing_right,
+                                                            new_right,
+                                                        ),
+                                                        |r| {
+                                                            Bl3Message::TransferFilesCompleted(
+                                                                MessageResult::handle_result(r),
+                                                            )
+                                                        },
+                                                    );
+                                                }
+                                            }
+                                            TransferInteractionMessage::Cancel => {
+                                                self.manage_save_state
+                                                    .save_view_state
+                                                    .inventory_state
+                                                    .transfer_state = TransferState::default();
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                            ManageSaveInteractionMessage::Currency(currency_msg) => {
+                                match currency_msg {
+                                    SaveCurrencyInteractionMessage::Money(money) => {
+                                        self.manage_save_state
+                                            .save_view_state
+                                            .currency_state
+                                            .money_input = money;
+                                    }
+                                    SaveCurrencyInteractionMessage::Eridium(eridium) => {
+                                        self.manage_save_state
+                                            .save_view_state
+                                            .currency_state
+                                            .eridium_input = eridium;
+                                    }
+                                    // No amount where the game's menus stop showing currency
+                                    // correctly is known, so Max writes the largest valid value
+                                    SaveCurrencyInteractionMessage::MaxMoneyPressed => {
+                                        self.manage_save_state
+                                            .save_view_state
+                                            .currency_state
+                                            .money_input = i32::MAX;
+                                    }
+                                    SaveCurrencyInteractionMessage::MaxEridiumPressed => {
+                                        self.manage_save_state
+                                            .save_view_state
+                                            .currency_state
+                                            .eridium_input = i32::MAX;
+                                    }
+                                    SaveCurrencyInteractionMessage::ExportAsCsv => {
+                                        let mut current_file =
+                                            self.manage_save_state.current_file.clone();
+
+                                        match manage_save::map_all_states_to_save(
+                                            &mut self.manage_save_state,
+                                            &mut current_file,
+                                        ) {
+                                            Ok(_) => {
+                                                let csv = stats_export::to_csv(
+                                                    &stats_export::currency_stats(&current_file),
+                                                );
+
+                                                let file_name = format!(
+                                                    "{} Currency.csv",
+                                                    current_file
+                                                        .character_data
+                                                        .character
+                                                        .preferred_character_name
+                                                );
+
+                                                return Command::perform(
+                                                    interaction::export_csv(csv, file_name),
+                                                    |r| {
+                                                        Bl3Message::Interaction(
+                                                            InteractionMessage::ManageSaveInteraction(
+                                                                ManageSaveInteractionMessage::Currency(
+                                                                    SaveCurrencyInteractionMessage::ExportAsCsvCompleted(
+                                                                        MessageResult::handle_result(r),
+                                                                    ),
+                                                                ),
+                                                            ),
+                                                        )
+                                                    },
+                                                );
+                                            }
+                                            Err(e) => e.handle_ui_error(
+                                                "Failed to export currency",
+                                                &mut self.notification,
+                                            ),
+                                        }
+                                    }
+                                    SaveCurrencyInteractionMessage::ExportAsCsvCompleted(res) => {
+                                        self.notification = Some(export_notification(res, "CSV"));
                                     }
                                 }
                             }
@@ -694,8 +2887,270 @@ impl Application for Bl3Application {
                                         }
                                     }
                                 }
+                                SaveVehicleInteractionMessage::ApplyPreset(preset) => {
+                                    self.manage_save_state
+                                        .save_view_state
+                                        .vehicle_state
+                                        .unlocker
+                                        .apply_preset(preset);
+                                }
+                                SaveVehicleInteractionMessage::LoadoutSkinSelected(
+                                    vehicle,
+                                    skin,
+                                ) => {
+                                    let vehicle_state =
+                                        &mut self.manage_save_state.save_view_state.vehicle_state;
+
+                                    let character_data =
+                                        &self.manage_save_state.current_file.character_data;
+
+                                    // Picking a skin the character doesn't have unlocks it too
+                                    if let Some(unlock_asset_path) = &skin.unlock_asset_path {
+                                        if !character_data
+                                            .is_vehicle_asset_unlocked(unlock_asset_path)
+                                        {
+                                            vehicle_state
+                                                .unlocker
+                                                .checkbox_mut(&vehicle.skins_type())
+                                                .is_unlocked = true;
+                                        }
+                                    }
+
+                                    if let Some(picker) = vehicle_state
+                                        .loadout_pickers
+                                        .iter_mut()
+                                        .find(|p| p.vehicle == vehicle)
+                                    {
+                                        picker.skin_selected = skin;
+                                    }
+                                }
+                                SaveVehicleInteractionMessage::LoadoutWheelsSelected(
+                                    vehicle,
+                                    wheels,
+                                ) => {
+                                    let vehicle_state =
+                                        &mut self.manage_save_state.save_view_state.vehicle_state;
+
+                                    let character_data =
+                                        &self.manage_save_state.current_file.character_data;
+
+                                    if let Some(unlock_asset_path) = &wheels.unlock_asset_path {
+                                        if !character_data
+                                            .is_vehicle_asset_unlocked(unlock_asset_path)
+                                        {
+                                            vehicle_state
+                                                .unlocker
+                                                .checkbox_mut(&vehicle.chassis_type())
+                                                .is_unlocked = true;
+                                        }
+                                    }
+
+                                    if let Some(picker) = vehicle_state
+                                        .loadout_pickers
+                                        .iter_mut()
+                                        .find(|p| p.vehicle == vehicle)
+                                    {
+                                        picker.wheels_selected = wheels;
+                                    }
+                                }
                             },
+                            ManageSaveInteractionMessage::Missions(missions_msg) => {
+                                let missions_state =
+                                    &mut self.manage_save_state.save_view_state.missions_state;
+
+                                match missions_msg {
+                                    SaveMissionsInteractionMessage::PlaythroughSelected(
+                                        playthrough,
+                                    ) => {
+                                        missions_state.playthrough_selected = playthrough;
+                                    }
+                                    SaveMissionsInteractionMessage::TrackMission(mission_index) => {
+                                        missions_state.track_mission(mission_index);
+                                    }
+                                    SaveMissionsInteractionMessage::ObjectiveProgress(
+                                        mission_index,
+                                        objective_index,
+                                        progress,
+                                    ) => {
+                                        missions_state.set_objective_progress(
+                                            mission_index,
+                                            objective_index,
+                                            progress,
+                                        );
+                                    }
+                                }
+                            }
+                            ManageSaveInteractionMessage::Challenges(challenges_msg) => {
+                                let challenges_state =
+                                    &mut self.manage_save_state.save_view_state.challenges_state;
+
+                                match challenges_msg {
+                                    SaveChallengesInteractionMessage::IncludeDlcToggled(
+                                        include_dlc,
+                                    ) => {
+                                        // What will be completed is fixed once it was confirmed
+                                        if !challenges_state.complete_all {
+                                            challenges_state.include_dlc = include_dlc;
+                                        }
+                                    }
+                                    SaveChallengesInteractionMessage::CompleteAllPressed => {
+                                        let msg = if challenges_state.include_dlc {
+                                            "Every challenge including DLC and event challenges will be marked as complete when you save, the tier each challenge has reached is kept."
+                                        } else {
+                                            "Every base game challenge will be marked as complete when you save, the tier each challenge has reached is kept."
+                                        };
+
+                                        self.confirmation_dialog = Some(ConfirmationDialog::new(
+                                            "Complete All Challenges",
+                                            msg,
+                                            "Complete All",
+                                            Bl3Message::Interaction(
+                                                InteractionMessage::ManageSaveInteraction(
+                                                    ManageSaveInteractionMessage::Challenges(
+                                                        SaveChallengesInteractionMessage::CompleteAll,
+                                                    ),
+                                                ),
+                                            ),
+                                        ));
+                                    }
+                                    SaveChallengesInteractionMessage::CompleteAll => {
+                                        challenges_state.complete_all();
+                                    }
+                                }
+                            }
+                            ManageSaveInteractionMessage::PreviewChangesPressed => {
+                                let mut output_file = self.manage_save_state.current_file.clone();
+
+                                match manage_save::map_all_states_to_save(
+                                    &mut self.manage_save_state,
+                                    &mut output_file,
+                                ) {
+                                    Ok(()) => {
+                                        let changes = diff_saves(
+                                            &self.manage_save_state.current_file,
+                                            &output_file,
+                                        );
+
+                                        self.manage_save_state.changes_summary =
+                                            Some(short_summary(&changes, CHANGES_SUMMARY_LENGTH));
+                                    }
+                                    Err(e) => {
+                                        let msg = format!("Failed to preview changes: {}", e);
+
+                                        error!("{}", msg);
+
+                                        self.notification = Some(Notification::new(
+                                            msg,
+                                            NotificationSentiment::Negative,
+                                        ));
+                                    }
+                                }
+                            }
+                            ManageSaveInteractionMessage::ValidationReport(report_msg) => {
+                                match report_msg {
+                                    ValidationReportMessage::AcknowledgePressed => {
+                                        self.manage_save_state
+                                            .validation_report_state
+                                            .acknowledged = true;
+
+                                        return self.update(Bl3Message::Interaction(
+                                            InteractionMessage::ManageSaveInteraction(
+                                                ManageSaveInteractionMessage::SaveFilePressed,
+                                            ),
+                                        ));
+                                    }
+                                    ValidationReportMessage::DismissPressed => {
+                                        self.manage_save_state.validation_report_state =
+                                            ValidationReportState::default();
+                                    }
+                                }
+                            }
+                            ManageSaveInteractionMessage::SaveCancelled => {
+                                self.manage_save_state.unsupported_version_save_pending = false;
+                                self.manage_save_state.reset_playthrough_save_pending = false;
+                                self.manage_save_state.unsupported_name_save_pending = false;
+                            }
                             ManageSaveInteractionMessage::SaveFilePressed => {
+                                if self
+                                    .manage_save_state
+                                    .current_file
+                                    .is_version_newer_than_supported()
+                                    && !self.manage_save_state.unsupported_version_save_pending
+                                {
+                                    self.manage_save_state.unsupported_version_save_pending = true;
+
+                                    self.confirmation_dialog = Some(save_file_confirmation(
+                                        UNSUPPORTED_VERSION_SAVE_MESSAGE,
+                                    ));
+
+                                    return Command::none();
+                                }
+
+                                if let Some(reset_playthrough) = self
+                                    .manage_save_state
+                                    .save_view_state
+                                    .general_state
+                                    .reset_playthrough
+                                {
+                                    if !self.manage_save_state.reset_playthrough_save_pending {
+                                        self.manage_save_state.reset_playthrough_save_pending =
+                                            true;
+
+                                        self.confirmation_dialog =
+                                            Some(save_file_confirmation(format!(
+                                                "Saving will permanently clear all mission progress and discovered fast travel stations in {}.",
+                                                reset_playthrough
+                                            )));
+
+                                        return Command::none();
+                                    }
+                                }
+
+                                let name_input = &self
+                                    .manage_save_state
+                                    .save_view_state
+                                    .character_state
+                                    .name_input;
+
+                                // Names that weren't edited are saved as they were loaded
+                                let removed_name_chars = if *name_input
+                                    != self
+                                        .manage_save_state
+                                        .current_file
+                                        .character_data
+                                        .character
+                                        .preferred_character_name
+                                {
+                                    unsupported_name_chars(name_input)
+                                } else {
+                                    Vec::new()
+                                };
+
+                                if !removed_name_chars.is_empty()
+                                    && !self.manage_save_state.unsupported_name_save_pending
+                                {
+                                    self.manage_save_state.unsupported_name_save_pending = true;
+
+                                    let chars = removed_name_chars
+                                        .iter()
+                                        .map(|c| format!("U+{:04X}", *c as u32))
+                                        .collect::<Vec<_>>()
+                                        .join(", ");
+
+                                    self.confirmation_dialog =
+                                        Some(save_file_confirmation(format!(
+                                            "The game can't show these characters in the character name, they will be removed when saving: {}.",
+                                            chars
+                                        )));
+
+                                    return Command::none();
+                                }
+
+                                // Every prompt has been confirmed by now, so the next save asks again
+                                self.manage_save_state.unsupported_version_save_pending = false;
+                                self.manage_save_state.reset_playthrough_save_pending = false;
+                                self.manage_save_state.unsupported_name_save_pending = false;
+
                                 //Lets not make any modifications to the current file just in case we have any errors
                                 let mut current_file = self.manage_save_state.current_file.clone();
 
@@ -715,14 +3170,78 @@ impl Application for Bl3Application {
                                     return Command::none();
                                 }
 
+                                let findings = validate_save(&current_file);
+
+                                if !findings.is_empty()
+                                    && (has_errors(&findings)
+                                        || !self
+                                            .manage_save_state
+                                            .validation_report_state
+                                            .is_acknowledged(&findings))
+                                {
+                                    self.notification =
+                                        Some(validation_report_notification(&findings));
+
+                                    self.manage_save_state
+                                        .validation_report_state
+                                        .open(findings);
+
+                                    return Command::none();
+                                }
+
+                                self.manage_save_state.validation_report_state =
+                                    ValidationReportState::default();
+
                                 let output_file = self
                                     .config
                                     .saves_dir()
                                     .join(&self.manage_save_state.current_file.file_name);
 
+                                let save_notes =
+                                    manage_save::general::map_general_state_to_save_notes(
+                                        &self.manage_save_state,
+                                    );
+
+                                let save_notes_command = Command::perform(
+                                    save_notes.save(
+                                        self.config.config_dir().to_path_buf(),
+                                        current_file
+                                            .character_data
+                                            .character
+                                            .save_game_guid
+                                            .clone(),
+                                    ),
+                                    |r| {
+                                        Bl3Message::SaveNotesCompleted(
+                                            MessageResult::handle_result(r),
+                                        )
+                                    },
+                                );
+
+                                if current_file.origin != FileOrigin::Directory {
+                                    let save_file_command = Command::perform(
+                                        interaction::file_save::save_file_as(
+                                            self.config.backup_dir().to_path_buf(),
+                                            self.config.saves_dir().to_path_buf(),
+                                            self.manage_save_state.current_file.clone(),
+                                            current_file,
+                                        ),
+                                        |r| {
+                                            Bl3Message::SaveFileCompleted(
+                                                MessageResult::handle_result(r),
+                                            )
+                                        },
+                                    );
+
+                                    return Command::batch(vec![
+                                        save_file_command,
+                                        save_notes_command,
+                                    ]);
+                                }
+
                                 match current_file.as_bytes() {
                                     Ok((output, save_file)) => {
-                                        return Command::perform(
+                                        let save_file_command = Command::perform(
                                             interaction::file_save::save_file(
                                                 self.config.backup_dir().to_path_buf(),
                                                 output_file,
@@ -736,6 +3255,11 @@ impl Application for Bl3Application {
                                                 )
                                             },
                                         );
+
+                                        return Command::batch(vec![
+                                            save_file_command,
+                                            save_notes_command,
+                                        ]);
                                     }
                                     Err(e) => {
                                         let msg = format!("Failed to save file: {}", e);
@@ -780,6 +3304,33 @@ impl Application for Bl3Application {
                                             ManageProfileView::TabBar(ProfileTabBarView::Settings),
                                         )
                                     }
+                                    ProfileTabBarInteractionMessage::ResetTab => {
+                                        if let ViewState::ManageProfile(
+                                            ManageProfileView::TabBar(tab_bar_view),
+                                        ) = &self.view_state
+                                        {
+                                            let tab_bar_view = tab_bar_view.clone();
+
+                                            match state_mappers::map_loaded_profile_tab_to_state(
+                                                self,
+                                                &tab_bar_view,
+                                            ) {
+                                                Ok(_) => {
+                                                    self.notification = Some(Notification::new(
+                                                        format!(
+                                                            "Reset the {} tab to the values in the file.",
+                                                            tab_bar_view
+                                                        ),
+                                                        NotificationSentiment::Positive,
+                                                    ));
+                                                }
+                                                Err(e) => e.handle_ui_error(
+                                                    "Failed to reset tab",
+                                                    &mut self.notification,
+                                                ),
+                                            }
+                                        }
+                                    }
                                 }
                             }
                             ManageProfileInteractionMessage::General(general_msg) => {
@@ -792,25 +3343,170 @@ impl Application for Bl3Application {
                                             .general_state
                                             .profile_type_selected = profile_type;
                                     }
-                                }
-                            }
-                            ManageProfileInteractionMessage::Profile(profile_msg) => {
-                                match profile_msg {
-                                    ProfileInteractionMessage::GuardianRankTokens(
-                                        guardian_rank_tokens,
+                                    ProfileGeneralInteractionMessage::GuardianExperience(
+                                        experience,
                                     ) => {
                                         self.manage_profile_state
                                             .profile_view_state
-                                            .profile_state
-                                            .guardian_rank_tokens_input = guardian_rank_tokens;
+                                            .general_state
+                                            .guardian_experience_input = experience;
+                                    }
+                                    ProfileGeneralInteractionMessage::NewGuardianExperience(
+                                        experience,
+                                    ) => {
+                                        self.manage_profile_state
+                                            .profile_view_state
+                                            .general_state
+                                            .new_guardian_experience_input = experience;
+                                    }
+                                    ProfileGeneralInteractionMessage::ClearReadMailPressed => {
+                                        self.manage_profile_state
+                                            .profile_view_state
+                                            .general_state
+                                            .clear_read_mail = true;
+                                    }
+                                    ProfileGeneralInteractionMessage::ClearAllMailPressed => {
+                                        self.manage_profile_state
+                                            .profile_view_state
+                                            .general_state
+                                            .clear_all_mail = true;
+                                    }
+                                    ProfileGeneralInteractionMessage::ShiftEntitlementConsumed(
+                                        index,
+                                        consumed,
+                                    ) => {
+                                        if let Some(entitlement) = self
+                                            .manage_profile_state
+                                            .profile_view_state
+                                            .general_state
+                                            .shift_entitlements
+                                            .get_mut(index)
+                                        {
+                                            entitlement.consumed = consumed;
+                                        }
+                                    }
+                                    ProfileGeneralInteractionMessage::CreateBlankProfilePressed => {
+                                        let create_blank_profile = |header_type| {
+                                            Bl3Message::Interaction(InteractionMessage::ManageProfileInteraction(
+                                                ManageProfileInteractionMessage::General(
+                                                    ProfileGeneralInteractionMessage::CreateBlankProfile(header_type),
+                                                ),
+                                            ))
+                                        };
+
+                                        self.confirmation_dialog = Some(
+                                            ConfirmationDialog::new(
+                                                "Create Blank Profile",
+                                                "Which platform is the new profile for? You will be asked where to save it, an existing file is backed up first.",
+                                                HeaderType::PcProfile.to_string(),
+                                                create_blank_profile(HeaderType::PcProfile),
+                                            )
+                                            .extra_button(
+                                                HeaderType::Ps4Profile.to_string(),
+                                                create_blank_profile(HeaderType::Ps4Profile),
+                                            ),
+                                        );
+                                    }
+                                    ProfileGeneralInteractionMessage::CreateBlankProfile(header_type) => {
+                                        return Command::perform(
+                                            interaction::file_save::create_blank_profile(
+                                                self.config.backup_dir().to_path_buf(),
+                                                self.config.saves_dir().to_path_buf(),
+                                                header_type,
+                                            ),
+                                            |r| {
+                                                Bl3Message::CreateBlankProfileCompleted(
+                                                    MessageResult::handle_result(r),
+                                                )
+                                            },
+                                        );
+                                    }
+                                    ProfileGeneralInteractionMessage::ResetToDefaultsPressed => {
+                                        let msg = format!(
+                                            "This replaces all progress and settings in {} with a blank profile and saves it, a backup is made first.",
+                                            self.manage_profile_state.current_file.file_name
+                                        );
+
+                                        self.confirmation_dialog = Some(ConfirmationDialog::new(
+                                            "Reset Profile to Defaults",
+                                            msg,
+                                            "Reset and Save",
+                                            Bl3Message::Interaction(InteractionMessage::ManageProfileInteraction(
+                                                ManageProfileInteractionMessage::General(
+                                                    ProfileGeneralInteractionMessage::ResetToDefaultsConfirmed,
+                                                ),
+                                            )),
+                                        ));
+                                    }
+                                    ProfileGeneralInteractionMessage::ResetToDefaultsConfirmed => {
+                                        let mut profile = self.manage_profile_state.current_file.clone();
+
+                                        let output_file = self
+                                            .config
+                                            .saves_dir()
+                                            .join(profile.relative_path());
+
+                                        match profile
+                                            .reset_to_template()
+                                            .and_then(|_| profile.as_bytes())
+                                        {
+                                            Ok((output, profile)) => {
+                                                let injection =
+                                                    GuardianInjection::from_profile_data(&profile.profile_data);
+
+                                                return self.save_profile(
+                                                    PendingProfileSave {
+                                                        output_file,
+                                                        output,
+                                                        profile,
+                                                        injection,
+                                                    },
+                                                    false,
+                                                );
+                                            }
+                                            Err(e) => {
+                                                let msg = format!("Failed to reset profile: {}", e);
+
+                                                error!("{}", msg);
+
+                                                self.notification = Some(Notification::new(
+                                                    msg,
+                                                    NotificationSentiment::Negative,
+                                                ));
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                            ManageProfileInteractionMessage::Profile(profile_msg) => {
+                                match profile_msg {
+                                    ProfileInteractionMessage::GuardianRankTokens(
+                                        guardian_rank_tokens,
+                                    ) => {
+                                        self.manage_profile_state
+                                            .profile_view_state
+                                            .profile_state
+                                            .guardian_rank_tokens_input = guardian_rank_tokens;
                                     }
                                     ProfileInteractionMessage::ScienceLevelSelected(
                                         science_level,
                                     ) => {
+                                        let profile_state = &mut self
+                                            .manage_profile_state
+                                            .profile_view_state
+                                            .profile_state;
+
+                                        profile_state.science_level_selected = science_level;
+
+                                        profile_state.science_xp_input = profile_state
+                                            .science_xp_input
+                                            .min(science_level.xp_to_next_tier().saturating_sub(1));
+                                    }
+                                    ProfileInteractionMessage::ScienceXp(science_xp) => {
                                         self.manage_profile_state
                                             .profile_view_state
                                             .profile_state
-                                            .science_level_selected = science_level;
+                                            .science_xp_input = science_xp;
                                     }
                                     ProfileInteractionMessage::ScienceTokens(
                                         science_level_tokens,
@@ -853,6 +3549,9 @@ impl Application for Bl3Application {
                                                 skin_unlocker.weapon_trinkets.is_unlocked =
                                                     selected;
                                             }
+                                            SkinUnlockedMessage::EventCosmetics(selected) => {
+                                                skin_unlocker.include_event_cosmetics = selected;
+                                            }
                                         }
                                     }
                                     ProfileInteractionMessage::SduMessage(sdu_message) => {
@@ -957,6 +3656,47 @@ impl Application for Bl3Application {
                                             }
                                         }
                                     }
+                                    ProfileInteractionMessage::DistributeGuardianRewardTokens(
+                                        tokens,
+                                    ) => {
+                                        self.manage_profile_state
+                                            .profile_view_state
+                                            .profile_state
+                                            .guardian_reward_unlocker
+                                            .distribute_total_input = tokens;
+                                    }
+                                    ProfileInteractionMessage::DistributeGuardianRewardPrioritySelected(
+                                        reward,
+                                    ) => {
+                                        self.manage_profile_state
+                                            .profile_view_state
+                                            .profile_state
+                                            .guardian_reward_unlocker
+                                            .distribute_priority_selected = reward;
+                                    }
+                                    ProfileInteractionMessage::DistributeGuardianRewardsPressed => {
+                                        let distribute_messages = self
+                                            .manage_profile_state
+                                            .profile_view_state
+                                            .profile_state
+                                            .guardian_reward_unlocker
+                                            .distribute_messages();
+
+                                        let commands = distribute_messages
+                                            .into_iter()
+                                            .map(|m| {
+                                                self.update(Bl3Message::Interaction(
+                                                    InteractionMessage::ManageProfileInteraction(
+                                                        ManageProfileInteractionMessage::Profile(
+                                                            ProfileInteractionMessage::GuardianRewardMessage(m),
+                                                        ),
+                                                    ),
+                                                ))
+                                            })
+                                            .collect::<Vec<_>>();
+
+                                        return Command::batch(commands);
+                                    }
                                     ProfileInteractionMessage::MaxGuardianRewardsPressed => {
                                         let guardian_reward_unlocker = &mut self
                                             .manage_profile_state
@@ -988,6 +3728,50 @@ impl Application for Bl3Application {
                                             tokens;
                                         guardian_reward_unlocker.vehicle_damage.input = tokens;
                                     }
+                                    ProfileInteractionMessage::ExportGuardianRewardsAsCsv => {
+                                        let mut current_file =
+                                            self.manage_profile_state.current_file.clone();
+
+                                        match manage_profile::map_all_states_to_profile(
+                                            &mut self.manage_profile_state,
+                                            &mut current_file,
+                                        ) {
+                                            Ok(_) => {
+                                                let csv = stats_export::to_csv(
+                                                    &stats_export::guardian_reward_stats(
+                                                        &current_file,
+                                                    ),
+                                                );
+
+                                                return Command::perform(
+                                                    interaction::export_csv(
+                                                        csv,
+                                                        "Guardian Rewards.csv".to_owned(),
+                                                    ),
+                                                    |r| {
+                                                        Bl3Message::Interaction(
+                                                            InteractionMessage::ManageProfileInteraction(
+                                                                ManageProfileInteractionMessage::Profile(
+                                                                    ProfileInteractionMessage::ExportGuardianRewardsAsCsvCompleted(
+                                                                        MessageResult::handle_result(r),
+                                                                    ),
+                                                                ),
+                                                            ),
+                                                        )
+                                                    },
+                                                );
+                                            }
+                                            Err(e) => e.handle_ui_error(
+                                                "Failed to export guardian rewards",
+                                                &mut self.notification,
+                                            ),
+                                        }
+                                    }
+                                    ProfileInteractionMessage::ExportGuardianRewardsAsCsvCompleted(
+                                        res,
+                                    ) => {
+                                        self.notification = Some(export_notification(res, "CSV"));
+                                    }
                                 }
                             }
                             ManageProfileInteractionMessage::Keys(keys_message) => {
@@ -995,71 +3779,75 @@ impl Application for Bl3Application {
                                     &mut self.manage_profile_state.profile_view_state.keys_state;
 
                                 match keys_message {
-                                    ProfileKeysInteractionMessage::GoldenKeys(golden_keys) => {
-                                        keys_state.golden_keys_input = golden_keys;
-                                    }
-                                    ProfileKeysInteractionMessage::DiamondKeys(diamond_keys) => {
-                                        keys_state.diamond_keys_input = diamond_keys;
-                                    }
-                                    ProfileKeysInteractionMessage::VaultCard1Keys(
-                                        vault_card_1_keys,
-                                    ) => {
-                                        keys_state.vault_card_1_keys_input = vault_card_1_keys;
-                                    }
-                                    ProfileKeysInteractionMessage::VaultCard1Chests(
-                                        vault_card_1_chests,
-                                    ) => {
-                                        keys_state.vault_card_1_chests_input = vault_card_1_chests;
-                                    }
-                                    ProfileKeysInteractionMessage::VaultCard2Keys(
-                                        vault_card_2_keys,
-                                    ) => {
-                                        keys_state.vault_card_2_keys_input = vault_card_2_keys;
+                                    ProfileKeysInteractionMessage::Currency { hash, quantity } => {
+                                        if let Some(currency) = keys_state
+                                            .currencies
+                                            .iter_mut()
+                                            .find(|c| c.hash == hash)
+                                        {
+                                            currency.input = quantity;
+                                        }
                                     }
-                                    ProfileKeysInteractionMessage::VaultCard2Chests(
-                                        vault_card_2_chests,
-                                    ) => {
-                                        keys_state.vault_card_2_chests_input = vault_card_2_chests;
+                                    ProfileKeysInteractionMessage::MaxCurrencyPressed(hash) => {
+                                        if let Some(currency) = keys_state
+                                            .currencies
+                                            .iter_mut()
+                                            .find(|c| c.hash == hash)
+                                        {
+                                            currency.input = currency.max;
+                                        }
                                     }
-                                    ProfileKeysInteractionMessage::VaultCard3Keys(
-                                        vault_card_3_keys,
-                                    ) => {
-                                        keys_state.vault_card_3_keys_input = vault_card_3_keys;
+                                    ProfileKeysInteractionMessage::VaultCardChests {
+                                        vault_card_id,
+                                        chests,
+                                    } => {
+                                        if let Some(vault_card_chests) = keys_state
+                                            .vault_card_chests
+                                            .iter_mut()
+                                            .find(|c| c.vault_card_id == vault_card_id)
+                                        {
+                                            vault_card_chests.input = chests;
+                                        }
                                     }
-                                    ProfileKeysInteractionMessage::VaultCard3Chests(
-                                        vault_card_3_chests,
+                                    ProfileKeysInteractionMessage::MaxVaultCardChestsPressed(
+                                        vault_card_id,
                                     ) => {
-                                        keys_state.vault_card_3_chests_input = vault_card_3_chests;
-                                    }
-                                    ProfileKeysInteractionMessage::MaxGoldenKeysPressed => {
-                                        keys_state.golden_keys_input = i32::MAX;
-                                    }
-                                    ProfileKeysInteractionMessage::MaxDiamondKeysPressed => {
-                                        keys_state.diamond_keys_input = i32::MAX;
-                                    }
-                                    ProfileKeysInteractionMessage::MaxVaultCard1KeysPressed => {
-                                        keys_state.vault_card_1_keys_input = i32::MAX;
-                                    }
-                                    ProfileKeysInteractionMessage::MaxVaultCard1ChestsPressed => {
-                                        keys_state.vault_card_1_chests_input = i32::MAX;
-                                    }
-                                    ProfileKeysInteractionMessage::MaxVaultCard2KeysPressed => {
-                                        keys_state.vault_card_2_keys_input = i32::MAX;
-                                    }
-                                    ProfileKeysInteractionMessage::MaxVaultCard2ChestsPressed => {
-                                        keys_state.vault_card_2_chests_input = i32::MAX;
+                                        if let Some(vault_card_chests) = keys_state
+                                            .vault_card_chests
+                                            .iter_mut()
+                                            .find(|c| c.vault_card_id == vault_card_id)
+                                        {
+                                            vault_card_chests.input = i32::MAX;
+                                        }
                                     }
-                                    ProfileKeysInteractionMessage::MaxVaultCard3KeysPressed => {
-                                        keys_state.vault_card_3_keys_input = i32::MAX;
+                                    ProfileKeysInteractionMessage::MaxAllKeysAndChests => {
+                                        keys_state
+                                            .currencies
+                                            .iter_mut()
+                                            .for_each(|c| c.input = c.max);
+
+                                        keys_state
+                                            .vault_card_chests
+                                            .iter_mut()
+                                            .for_each(|c| c.input = i32::MAX);
                                     }
-                                    ProfileKeysInteractionMessage::MaxVaultCard3ChestsPressed => {
-                                        keys_state.vault_card_3_chests_input = i32::MAX;
+                                    ProfileKeysInteractionMessage::RerollVaultCardChallenges => {
+                                        keys_state.reroll_vault_card_challenges = true;
                                     }
                                 }
                             }
                             ManageProfileInteractionMessage::Bank(bank_message) => {
                                 match bank_message {
                                     ProfileBankInteractionMessage::Editor(item_editor_message) => {
+                                        let selected_layout =
+                                            item_editor_message.inventory_layout_selected();
+
+                                        let favorite_anointments_changed =
+                                            item_editor_message.favorite_anointments_changed();
+
+                                        let favorite_items_changed =
+                                            item_editor_message.favorite_items_changed();
+
                                         let res = item_editor_message.update_state(
                                             &mut self
                                                 .manage_profile_state
@@ -1073,6 +3861,34 @@ impl Application for Bl3Application {
 
                                         self.notification = res.notification;
 
+                                        if let Some(layout) = selected_layout {
+                                            return self.set_inventory_layout(layout);
+                                        }
+
+                                        if favorite_items_changed {
+                                            let favorite_items = self
+                                                .manage_profile_state
+                                                .profile_view_state
+                                                .bank_state
+                                                .item_editor_state
+                                                .favorite_item_serials();
+
+                                            return self.set_favorite_items(favorite_items);
+                                        }
+
+                                        if favorite_anointments_changed {
+                                            let favorite_anointments = self
+                                                .manage_profile_state
+                                                .profile_view_state
+                                                .bank_state
+                                                .item_editor_state
+                                                .favorite_anointments
+                                                .clone();
+
+                                            return self
+                                                .set_favorite_anointments(favorite_anointments);
+                                        }
+
                                         if let Some(command) = res.command {
                                             return command.map(|m| {
                                                 Bl3Message::Interaction(
@@ -1087,9 +3903,166 @@ impl Application for Bl3Application {
                                             });
                                         }
                                     }
+                                    ProfileBankInteractionMessage::ClearBank => {
+                                        let count = self
+                                            .manage_profile_state
+                                            .profile_view_state
+                                            .bank_state
+                                            .item_editor_state
+                                            .items()
+                                            .len();
+
+                                        if count == 0 {
+                                            self.notification = Some(Notification::new(
+                                                "The bank is already empty.",
+                                                NotificationSentiment::Info,
+                                            ));
+
+                                            return Command::none();
+                                        }
+
+                                        self.confirmation_dialog = Some(ConfirmationDialog::new(
+                                            "Remove All Items",
+                                            format!(
+                                                "All {} items will be removed from the bank. The profile isn't changed until you save.",
+                                                count
+                                            ),
+                                            "Remove All",
+                                            Bl3Message::Interaction(
+                                                InteractionMessage::ManageProfileInteraction(
+                                                    ManageProfileInteractionMessage::Bank(
+                                                        ProfileBankInteractionMessage::ClearBankConfirmed,
+                                                    ),
+                                                ),
+                                            ),
+                                        ));
+                                    }
+                                    ProfileBankInteractionMessage::ClearBankConfirmed => {
+                                        let removed = self
+                                            .manage_profile_state
+                                            .profile_view_state
+                                            .bank_state
+                                            .item_editor_state
+                                            .items()
+                                            .len();
+
+                                        self.manage_profile_state
+                                            .current_file
+                                            .profile_data
+                                            .clear_bank_items();
+
+                                        if let Err(e) =
+                                            manage_profile::bank::map_profile_to_bank_state(
+                                                &mut self.manage_profile_state,
+                                            )
+                                        {
+                                            e.handle_ui_error(
+                                                "Failed to map bank after removing all items",
+                                                &mut self.notification,
+                                            );
+
+                                            return Command::none();
+                                        }
+
+                                        *self
+                                            .manage_profile_state
+                                            .profile_view_state
+                                            .bank_state
+                                            .removed_all_items
+                                            .get_or_insert(0) += removed;
+                                    }
+                                }
+                            }
+                            ManageProfileInteractionMessage::PreviewChangesPressed => {
+                                let mut output_file =
+                                    self.manage_profile_state.current_file.clone();
+
+                                match manage_profile::map_all_states_to_profile(
+                                    &mut self.manage_profile_state,
+                                    &mut output_file,
+                                ) {
+                                    Ok(_) => {
+                                        let changes = diff_profiles(
+                                            &self.manage_profile_state.current_file,
+                                            &output_file,
+                                        );
+
+                                        self.manage_profile_state.changes_summary =
+                                            Some(short_summary(&changes, CHANGES_SUMMARY_LENGTH));
+                                    }
+                                    Err(e) => {
+                                        let msg = format!("Failed to preview changes: {}", e);
+
+                                        error!("{}", msg);
+
+                                        self.notification = Some(Notification::new(
+                                            msg,
+                                            NotificationSentiment::Negative,
+                                        ));
+                                    }
+                                }
+                            }
+                            ManageProfileInteractionMessage::ValidationReport(report_msg) => {
+                                match report_msg {
+                                    ValidationReportMessage::AcknowledgePressed => {
+                                        self.manage_profile_state
+                                            .validation_report_state
+                                            .acknowledged = true;
+
+                                        return self.update(Bl3Message::Interaction(
+                                            InteractionMessage::ManageProfileInteraction(
+                                                ManageProfileInteractionMessage::SaveProfilePressed,
+                                            ),
+                                        ));
+                                    }
+                                    ValidationReportMessage::DismissPressed => {
+                                        self.manage_profile_state.validation_report_state =
+                                            ValidationReportState::default();
+                                    }
                                 }
                             }
+                            ManageProfileInteractionMessage::SaveCancelled => {
+                                self.manage_profile_state.unsupported_version_save_pending = false;
+                                self.manage_profile_state.game_store_save_pending = false;
+                            }
                             ManageProfileInteractionMessage::SaveProfilePressed => {
+                                if self
+                                    .manage_profile_state
+                                    .current_file
+                                    .is_version_newer_than_supported()
+                                    && !self.manage_profile_state.unsupported_version_save_pending
+                                {
+                                    self.manage_profile_state.unsupported_version_save_pending =
+                                        true;
+
+                                    self.confirmation_dialog = Some(save_profile_confirmation(
+                                        UNSUPPORTED_VERSION_SAVE_MESSAGE,
+                                    ));
+
+                                    return Command::none();
+                                }
+
+                                let profile_store =
+                                    self.manage_profile_state.current_file.game_store;
+                                let saves_dir_store = infer_game_store(self.config.saves_dir());
+
+                                if profile_store != GameStore::Unknown
+                                    && saves_dir_store != GameStore::Unknown
+                                    && profile_store != saves_dir_store
+                                    && !self.manage_profile_state.game_store_save_pending
+                                {
+                                    self.manage_profile_state.game_store_save_pending = true;
+
+                                    let msg = format!(
+                                        "This looks like a {} profile but the saves folder looks like a {} folder, the game may not load it.",
+                                        profile_store, saves_dir_store
+                                    );
+
+                                    self.confirmation_dialog = Some(save_profile_confirmation(msg));
+
+                                    return Command::none();
+                                }
+
                                 //Lets not make any modifications to the current file just in case we have any errors
                                 let mut current_file =
                                     self.manage_profile_state.current_file.clone();
@@ -1114,26 +4087,72 @@ impl Application for Bl3Application {
                                         }
                                     };
 
+                                let findings = validate_profile(&current_file);
+
+                                if !findings.is_empty()
+                                    && (has_errors(&findings)
+                                        || !self
+                                            .manage_profile_state
+                                            .validation_report_state
+                                            .is_acknowledged(&findings))
+                                {
+                                    self.notification =
+                                        Some(validation_report_notification(&findings));
+
+                                    self.manage_profile_state
+                                        .validation_report_state
+                                        .open(findings);
+
+                                    return Command::none();
+                                }
+
+                                self.manage_profile_state.validation_report_state =
+                                    ValidationReportState::default();
+
+                                // Every prompt has been confirmed by now, so the next save asks again
+                                self.manage_profile_state.unsupported_version_save_pending = false;
+                                self.manage_profile_state.game_store_save_pending = false;
+
                                 let output_file = self
                                     .config
                                     .saves_dir()
-                                    .join(&self.manage_profile_state.current_file.file_name);
+                                    .join(self.manage_profile_state.current_file.relative_path());
 
                                 match current_file.as_bytes() {
                                     Ok((output, profile)) => {
+                                        let injection = GuardianInjection::from_profile_data(
+                                            &profile.profile_data,
+                                        );
+
+                                        let pending_save = PendingProfileSave {
+                                            output_file,
+                                            output,
+                                            profile,
+                                            injection: injection.clone(),
+                                        };
+
+                                        if !guardian_data_injection_required {
+                                            return self.save_profile(pending_save, false);
+                                        }
+
+                                        self.manage_profile_state
+                                            .guardian_injection_state
+                                            .pending_save = Some(pending_save);
+
                                         return Command::perform(
-                                            interaction::file_save::save_profile(
-                                                self.config.backup_dir().to_path_buf(),
+                                            interaction::file_save::saves_requiring_guardian_injection(
                                                 self.config.saves_dir().to_path_buf(),
-                                                output_file,
-                                                output,
-                                                self.manage_profile_state.current_file.clone(),
-                                                profile,
-                                                guardian_data_injection_required,
+                                                injection,
                                             ),
                                             |r| {
-                                                Bl3Message::SaveProfileCompleted(
-                                                    MessageResult::handle_result(r),
+                                                Bl3Message::Interaction(
+                                                    InteractionMessage::ManageProfileInteraction(
+                                                        ManageProfileInteractionMessage::GuardianInjection(
+                                                            GuardianInjectionMessage::SavesFound(
+                                                                MessageResult::handle_result(r),
+                                                            ),
+                                                        ),
+                                                    ),
                                                 )
                                             },
                                         );
@@ -1150,6 +4169,66 @@ impl Application for Bl3Application {
                                     }
                                 };
                             }
+                            ManageProfileInteractionMessage::GuardianInjection(
+                                guardian_injection_msg,
+                            ) => {
+                                let guardian_injection_state =
+                                    &mut self.manage_profile_state.guardian_injection_state;
+
+                                match guardian_injection_msg {
+                                    GuardianInjectionMessage::SavesFound(res) => match res {
+                                        MessageResult::Success(saves) => {
+                                            if saves.is_empty() {
+                                                if let Some(pending_save) =
+                                                    guardian_injection_state.pending_save.take()
+                                                {
+                                                    return self.save_profile(pending_save, false);
+                                                }
+                                            } else {
+                                                guardian_injection_state.saves_to_modify =
+                                                    Some(saves);
+
+                                                self.notification = None;
+                                            }
+                                        }
+                                        MessageResult::Error(e) => {
+                                            guardian_injection_state.pending_save = None;
+
+                                            let msg = format!(
+                                                "Failed to find the saves that need the new guardian rank: {}",
+                                                e
+                                            );
+
+                                            error!("{}", msg);
+
+                                            self.notification = Some(Notification::new(
+                                                msg,
+                                                NotificationSentiment::Negative,
+                                            ));
+                                        }
+                                    },
+                                    GuardianInjectionMessage::ProceedPressed
+                                    | GuardianInjectionMessage::ProfileOnlyPressed => {
+                                        guardian_injection_state.saves_to_modify = None;
+
+                                        let inject_guardian_data = matches!(
+                                            guardian_injection_msg,
+                                            GuardianInjectionMessage::ProceedPressed
+                                        );
+
+                                        if let Some(pending_save) =
+                                            guardian_injection_state.pending_save.take()
+                                        {
+                                            return self
+                                                .save_profile(pending_save, inject_guardian_data);
+                                        }
+                                    }
+                                    GuardianInjectionMessage::CancelPressed => {
+                                        guardian_injection_state.saves_to_modify = None;
+                                        guardian_injection_state.pending_save = None;
+                                    }
+                                }
+                            }
                         }
                     }
                     InteractionMessage::SettingsInteraction(settings_msg) => match settings_msg {
@@ -1175,15 +4254,17 @@ impl Application for Bl3Application {
                                 &mut self.notification,
                             );
                         }
-                        SettingsInteractionMessage::OpenBackupDir => {
+                        SettingsInteractionMessage::MigrateConfig => {
+                            self.settings_state.choose_migrate_config_dir_window_open = true;
+
                             return Command::perform(
-                                interaction::settings::open_dir(
-                                    self.config.backup_dir().to_path_buf(),
+                                interaction::settings::migrate_config(
+                                    self.config.config_dir().to_path_buf(),
                                 ),
                                 |r| {
                                     Bl3Message::Interaction(
                                         InteractionMessage::SettingsInteraction(
-                                            SettingsInteractionMessage::OpenBackupDirCompleted(
+                                            SettingsInteractionMessage::MigrateConfigCompleted(
                                                 MessageResult::handle_result(r),
                                             ),
                                         ),
@@ -1191,17 +4272,57 @@ impl Application for Bl3Application {
                                 },
                             );
                         }
-                        SettingsInteractionMessage::OpenBackupDirCompleted(res) => {
-                            res.handle_ui_error(
-                                "Failed to open backups folder",
-                                &mut self.notification,
-                            );
-                        }
-                        SettingsInteractionMessage::ChangeBackupDir => {
-                            self.settings_state.choose_backup_dir_window_open = true;
+                        SettingsInteractionMessage::MigrateConfigCompleted(res) => {
+                            self.settings_state.choose_migrate_config_dir_window_open = false;
 
-                            return Command::perform(
-                                interaction::choose_dir(self.config.backup_dir().to_path_buf()),
+                            match res {
+                                MessageResult::Success(config) => {
+                                    self.notification = Some(Notification::new(
+                                        "Migrated config.",
+                                        NotificationSentiment::Positive,
+                                    ));
+
+                                    return self.apply_config(config);
+                                }
+                                MessageResult::Error(e) => {
+                                    let msg = format!("Failed to migrate config: {}", e);
+
+                                    error!("{}", msg);
+
+                                    self.notification = Some(Notification::new(
+                                        msg,
+                                        NotificationSentiment::Negative,
+                                    ));
+                                }
+                            }
+                        }
+                        SettingsInteractionMessage::OpenBackupDir => {
+                            return Command::perform(
+                                interaction::settings::open_dir(
+                                    self.config.backup_dir().to_path_buf(),
+                                ),
+                                |r| {
+                                    Bl3Message::Interaction(
+                                        InteractionMessage::SettingsInteraction(
+                                            SettingsInteractionMessage::OpenBackupDirCompleted(
+                                                MessageResult::handle_result(r),
+                                            ),
+                                        ),
+                                    )
+                                },
+                            );
+                        }
+                        SettingsInteractionMessage::OpenBackupDirCompleted(res) => {
+                            res.handle_ui_error(
+                                "Failed to open backups folder",
+                                &mut self.notification,
+                            );
+                        }
+                        SettingsInteractionMessage::ChangeBackupDir => {
+                            self.settings_state.choose_backup_dir_window_open = true;
+
+                            return Command::perform(
+                                interaction::choose_dir(self.config.backup_dir().to_path_buf()),
                                 |r| {
                                     Bl3Message::Interaction(
                                         InteractionMessage::SettingsInteraction(
@@ -1326,24 +4447,602 @@ impl Application for Bl3Application {
                             if self.settings_state.ui_scale_factor < 2.0 {
                                 self.settings_state.ui_scale_factor += 0.05;
 
-                                self.config
-                                    .set_ui_scale_factor(self.settings_state.ui_scale_factor);
+                                self.config
+                                    .set_ui_scale_factor(self.settings_state.ui_scale_factor);
+
+                                return Command::perform(self.config.clone().save(), |r| {
+                                    Bl3Message::Config(ConfigMessage::SaveCompleted(
+                                        MessageResult::handle_result(r),
+                                    ))
+                                });
+                            }
+                        }
+                        SettingsInteractionMessage::OwnedDlcSelected(dlc, owned) => {
+                            let owned_dlc = &mut self.settings_state.owned_dlc;
+
+                            if owned && !owned_dlc.contains(&dlc) {
+                                owned_dlc.push(dlc);
+                                owned_dlc.sort();
+                            } else if !owned {
+                                owned_dlc.retain(|d| *d != dlc);
+                            }
+
+                            self.manage_save_state
+                                .save_view_state
+                                .inventory_state
+                                .item_editor_state
+                                .owned_dlc = owned_dlc.clone();
+
+                            self.manage_profile_state
+                                .profile_view_state
+                                .bank_state
+                                .item_editor_state
+                                .owned_dlc = owned_dlc.clone();
+
+                            self.config.set_owned_dlc(owned_dlc.clone());
+
+                            return Command::perform(self.config.clone().save(), |r| {
+                                Bl3Message::Config(ConfigMessage::SaveCompleted(
+                                    MessageResult::handle_result(r),
+                                ))
+                            });
+                        }
+                        SettingsInteractionMessage::InventoryColumnsChanged(inventory_columns) => {
+                            return self.set_inventory_columns(inventory_columns);
+                        }
+                        SettingsInteractionMessage::ItemVerificationToggled(enabled) => {
+                            let item_api_url =
+                                self.settings_state.item_api_url_input.trim().to_owned();
+
+                            return self.set_item_verification(enabled, item_api_url);
+                        }
+                        SettingsInteractionMessage::ItemApiUrlInputChanged(item_api_url) => {
+                            self.settings_state.item_api_url_input = item_api_url;
+                        }
+                        SettingsInteractionMessage::ItemApiUrlSubmitted => {
+                            let item_api_url =
+                                self.settings_state.item_api_url_input.trim().to_owned();
+
+                            self.settings_state.item_api_url_input = item_api_url.clone();
+
+                            self.notification = Some(Notification::new(
+                                "Item API URL was saved.",
+                                NotificationSentiment::Info,
+                            ));
+
+                            return self.set_item_verification(
+                                self.settings_state.item_verification_enabled,
+                                item_api_url,
+                            );
+                        }
+                        SettingsInteractionMessage::SanitizeNegativeValuesToggled(enabled) => {
+                            self.config.set_sanitize_negative_values(enabled);
+
+                            self.settings_state.sanitize_negative_values = enabled;
+
+                            return Command::perform(self.config.clone().save(), |r| {
+                                Bl3Message::Config(ConfigMessage::SaveCompleted(
+                                    MessageResult::handle_result(r),
+                                ))
+                            });
+                        }
+                        SettingsInteractionMessage::ReloadDataOverridesPressed => {
+                            return Command::perform(
+                                interaction::settings::reload_data_overrides(
+                                    self.config.config_dir().to_path_buf(),
+                                ),
+                                |r| {
+                                    Bl3Message::Interaction(
+                                        InteractionMessage::SettingsInteraction(
+                                            SettingsInteractionMessage::ReloadDataOverridesCompleted(
+                                                MessageResult::handle_result(r),
+                                            ),
+                                        ),
+                                    )
+                                },
+                            );
+                        }
+                        SettingsInteractionMessage::ReloadDataOverridesCompleted(res) => {
+                            match res {
+                                MessageResult::Success(len) => {
+                                    self.notification = Some(Notification::new(
+                                    format!(
+                                        "Loaded {} data overrides. Refresh the save folder to update items that are already loaded.",
+                                        len
+                                    ),
+                                    NotificationSentiment::Positive,
+                                ));
+                                }
+                                MessageResult::Error(e) => {
+                                    let msg = format!("Ignored the data overrides file: {}", e);
+
+                                    error!("{}", msg);
+
+                                    self.notification = Some(Notification::new(
+                                        msg,
+                                        NotificationSentiment::Warning,
+                                    ));
+                                }
+                            }
+                        }
+                        SettingsInteractionMessage::ResetToDefaultsPressed => {
+                            self.settings_state.reset_to_defaults_dialog_open = true;
+
+                            return Command::perform(
+                                interaction::settings::confirm_reset_to_defaults(),
+                                |r| {
+                                    Bl3Message::Interaction(
+                                        InteractionMessage::SettingsInteraction(
+                                            SettingsInteractionMessage::ResetToDefaults(
+                                                MessageResult::handle_result(r),
+                                            ),
+                                        ),
+                                    )
+                                },
+                            );
+                        }
+                        SettingsInteractionMessage::ResetToDefaults(res) => {
+                            self.settings_state.reset_to_defaults_dialog_open = false;
+
+                            match res {
+                                MessageResult::Success(true) => {
+                                    let mut config = Bl3Config::default();
+                                    config.set_first_run_complete(true);
+
+                                    let reload_command = self.apply_config(config);
+
+                                    let save_command =
+                                        Command::perform(self.config.clone().save(), |r| {
+                                            Bl3Message::Config(ConfigMessage::SaveCompleted(
+                                                MessageResult::handle_result(r),
+                                            ))
+                                        });
+
+                                    self.notification = Some(Notification::new(
+                                        "Reset all settings to their defaults.",
+                                        NotificationSentiment::Positive,
+                                    ));
+
+                                    return Command::batch(vec![reload_command, save_command]);
+                                }
+                                MessageResult::Success(false) => (),
+                                MessageResult::Error(e) => {
+                                    let msg = format!("Failed to reset settings: {}", e);
+
+                                    error!("{}", msg);
+
+                                    self.notification = Some(Notification::new(
+                                        msg,
+                                        NotificationSentiment::Negative,
+                                    ));
+                                }
+                            }
+                        }
+                        SettingsInteractionMessage::VerifyFilesPressed => {
+                            // Files read from an archive aren't on disk to read again
+                            let files = self
+                                .loaded_files
+                                .iter()
+                                .filter(|f| f.origin() == FileOrigin::Directory)
+                                .map(|f| self.file_path(f))
+                                .collect::<Vec<_>>();
+
+                            if files.is_empty() {
+                                self.notification = Some(Notification::new(
+                                    "There are no loaded files to verify.",
+                                    NotificationSentiment::Info,
+                                ));
+
+                                return Command::none();
+                            }
+
+                            self.settings_state.is_verifying_files = true;
+
+                            return Command::perform(
+                                interaction::settings::verify_files(files),
+                                |r| {
+                                    Bl3Message::Interaction(
+                                        InteractionMessage::SettingsInteraction(
+                                            SettingsInteractionMessage::VerifyFilesCompleted(
+                                                MessageResult::handle_result(r),
+                                            ),
+                                        ),
+                                    )
+                                },
+                            );
+                        }
+                        SettingsInteractionMessage::VerifyFilesCompleted(res) => {
+                            self.settings_state.is_verifying_files = false;
+
+                            match res {
+                                MessageResult::Success(reports) => {
+                                    let not_faithful =
+                                        reports.iter().filter(|r| !r.is_faithful()).count();
+
+                                    self.notification = Some(if not_faithful == 0 {
+                                        Notification::new(
+                                            format!(
+                                                "All {} files were read and written back without changes.",
+                                                reports.len()
+                                            ),
+                                            NotificationSentiment::Positive,
+                                        )
+                                    } else {
+                                        Notification::new(
+                                            format!(
+                                                "{} of {} files changed when written back, see the report in Settings.",
+                                                not_faithful,
+                                                reports.len()
+                                            ),
+                                            NotificationSentiment::Negative,
+                                        )
+                                    });
+
+                                    self.settings_state.round_trip_reports = reports;
+                                }
+                                MessageResult::Error(e) => {
+                                    let msg = format!("Failed to verify files: {}", e);
+
+                                    error!("{}", msg);
+
+                                    self.notification = Some(Notification::new(
+                                        msg,
+                                        NotificationSentiment::Negative,
+                                    ));
+                                }
+                            }
+                        }
+                        SettingsInteractionMessage::SaveRoundTripReportPressed => {
+                            return Command::perform(
+                                interaction::settings::export_round_trip_report(
+                                    self.settings_state.round_trip_reports.clone(),
+                                ),
+                                |r| {
+                                    Bl3Message::Interaction(
+                                        InteractionMessage::SettingsInteraction(
+                                            SettingsInteractionMessage::SaveRoundTripReportCompleted(
+                                                MessageResult::handle_result(r),
+                                            ),
+                                        ),
+                                    )
+                                },
+                            );
+                        }
+                        SettingsInteractionMessage::SaveRoundTripReportCompleted(res) => {
+                            self.notification = Some(export_notification(res, "report"));
+                        }
+                        SettingsInteractionMessage::SyncGuardianRankPressed => {
+                            let profile = match self.most_likely_loaded_profile() {
+                                Some(profile) => profile.clone(),
+                                None => {
+                                    self.notification = Some(Notification::new(
+                                        "There is no loaded profile to sync the guardian rank from.",
+                                        NotificationSentiment::Negative,
+                                    ));
+
+                                    return Command::none();
+                                }
+                            };
+
+                            let saves = self
+                                .loaded_files
+                                .iter()
+                                .filter(|f| {
+                                    matches!(f, Bl3FileType::PcSave(_) | Bl3FileType::Ps4Save(_))
+                                        && f.origin() == FileOrigin::Directory
+                                })
+                                .map(|f| self.file_path(f))
+                                .collect::<Vec<_>>();
+
+                            self.settings_state.is_syncing_guardian_rank = true;
+
+                            return Command::perform(
+                                interaction::bulk::sync_guardian_rank_to_saves(
+                                    self.config.backup_dir().to_path_buf(),
+                                    saves,
+                                    profile,
+                                ),
+                                |r| {
+                                    Bl3Message::Interaction(
+                                        InteractionMessage::SettingsInteraction(
+                                            SettingsInteractionMessage::SyncGuardianRankCompleted(
+                                                MessageResult::handle_result(r),
+                                            ),
+                                        ),
+                                    )
+                                },
+                            );
+                        }
+                        SettingsInteractionMessage::SyncGuardianRankCompleted(res) => {
+                            self.settings_state.is_syncing_guardian_rank = false;
+
+                            match res {
+                                MessageResult::Success(report) => {
+                                    self.notification = Some(Notification::new(
+                                        report.to_string(),
+                                        if report.failed.is_empty() {
+                                            NotificationSentiment::Positive
+                                        } else {
+                                            NotificationSentiment::Warning
+                                        },
+                                    ));
+
+                                    let updated = !report.updated.is_empty();
+
+                                    self.settings_state.guardian_rank_sync_report = Some(report);
+
+                                    // The loaded saves no longer match what is on disk
+                                    if updated {
+                                        self.is_reloading_saves = true;
+
+                                        return Command::perform(
+                                            interaction::file_save::load_files_after_save(
+                                                self.config.saves_dir().to_path_buf(),
+                                                (*self.loaded_files_selected).clone(),
+                                            ),
+                                            |r| {
+                                                Bl3Message::FilesLoadedAfterSave(
+                                                    MessageResult::handle_result(r),
+                                                )
+                                            },
+                                        );
+                                    }
+                                }
+                                MessageResult::Error(e) => {
+                                    let msg = format!("Failed to sync guardian rank: {}", e);
+
+                                    error!("{}", msg);
+
+                                    self.notification = Some(Notification::new(
+                                        msg,
+                                        NotificationSentiment::Negative,
+                                    ));
+                                }
+                            }
+                        }
+                        SettingsInteractionMessage::BackupHistory(backup_history_msg) => {
+                            let backup_history_state =
+                                &mut self.settings_state.backup_history_state;
+
+                            match backup_history_msg {
+                                BackupHistoryInteractionMessage::RefreshPressed => {
+                                    backup_history_state.is_loading = true;
+
+                                    return Command::perform(
+                                        interaction::settings::load_backups(
+                                            self.config.backup_dir().to_path_buf(),
+                                        ),
+                                        |r| {
+                                            Bl3Message::Interaction(
+                                                InteractionMessage::SettingsInteraction(
+                                                    SettingsInteractionMessage::BackupHistory(
+                                                        BackupHistoryInteractionMessage::BackupsLoaded(
+                                                            MessageResult::handle_result(r),
+                                                        ),
+                                                    ),
+                                                ),
+                                            )
+                                        },
+                                    );
+                                }
+                                BackupHistoryInteractionMessage::BackupsLoaded(res) => {
+                                    backup_history_state.is_loading = false;
+
+                                    match res {
+                                        MessageResult::Success(backups) => {
+                                            backup_history_state.backups = backups;
+                                        }
+                                        MessageResult::Error(e) => {
+                                            let msg = format!("Failed to load backups: {}", e);
+
+                                            error!("{}", msg);
+
+                                            self.notification = Some(Notification::new(
+                                                msg,
+                                                NotificationSentiment::Negative,
+                                            ));
+                                        }
+                                    }
+                                }
+                                BackupHistoryInteractionMessage::SearchInputChanged(search) => {
+                                    backup_history_state.filter.search = search.to_lowercase();
+                                }
+                                BackupHistoryInteractionMessage::SortPressed(sort) => {
+                                    backup_history_state.filter.sort_pressed(sort);
+                                }
+                            }
+                        }
+                    },
+                    InteractionMessage::LoadedFileSelected(loaded_file) => {
+                        if self.loaded_files_selected.filename() != loaded_file.filename()
+                            && self.has_unsaved_changes()
+                        {
+                            self.confirmation_dialog = Some(ConfirmationDialog::new(
+                                "Discard Changes",
+                                format!(
+                                    "{} has changes that haven't been saved, opening {} will discard them.",
+                                    self.loaded_files_selected.filename(),
+                                    loaded_file.filename()
+                                ),
+                                "Discard Changes",
+                                Bl3Message::Interaction(
+                                    InteractionMessage::DiscardChangesAndSelect(loaded_file),
+                                ),
+                            ));
+
+                            return Command::none();
+                        }
+
+                        return self.update(Bl3Message::Interaction(
+                            InteractionMessage::DiscardChangesAndSelect(loaded_file),
+                        ));
+                    }
+                    InteractionMessage::DiscardChangesAndSelect(loaded_file) => {
+                        let file_changed =
+                            self.loaded_files_selected.filename() != loaded_file.filename();
+
+                        if file_changed {
+                            self.last_save_undo = None;
+
+                            // The changes to the previous file were discarded on purpose
+                            self.remove_recovery_file();
+                        }
+
+                        self.loaded_files_selected = loaded_file;
+
+                        let mapped = state_mappers::map_loaded_file_to_state(self);
+
+                        mapped.handle_ui_error(
+                            "Failed to map loaded file to editor",
+                            &mut self.notification,
+                        );
+
+                        self.warn_if_unsupported_version();
+
+                        if mapped.is_ok() {
+                            self.notify_if_sanitized_on_load();
+                        }
+
+                        if file_changed {
+                            self.offer_recovery();
+
+                            self.config.set_last_selected_file(Some(
+                                self.loaded_files_selected.filename().to_owned(),
+                            ));
+
+                            // Only write the config once the selection has settled
+                            self.last_selected_file_changes += 1;
+
+                            let change = self.last_selected_file_changes;
+
+                            return Command::perform(
+                                tokio::time::sleep(LAST_SELECTED_FILE_SAVE_DELAY),
+                                move |_| Bl3Message::SaveLastSelectedFile(change),
+                            );
+                        }
+                    }
+                    InteractionMessage::FirstRunWizardInteraction(wizard_msg) => {
+                        let wizard_state = &mut self.first_run_wizard_state;
+
+                        match wizard_msg {
+                            FirstRunWizardInteractionMessage::BackPressed => {
+                                wizard_state.step = wizard_state.step.previous();
+                            }
+                            FirstRunWizardInteractionMessage::NextPressed => {
+                                wizard_state.step = wizard_state.step.next();
+                            }
+                            FirstRunWizardInteractionMessage::UseDefaultSavesDirPressed => {
+                                let saves_dir =
+                                    interaction::choose_save_directory::default_saves_dir();
+
+                                if saves_dir.is_dir() {
+                                    wizard_state.saves_dir = saves_dir;
+                                } else {
+                                    self.notification = Some(Notification::new(
+                                        "Could not find the default Borderlands 3 saves folder, please select it instead.",
+                                        NotificationSentiment::Negative,
+                                    ));
+                                }
+                            }
+                            FirstRunWizardInteractionMessage::ChooseSavesDirPressed => {
+                                wizard_state.choose_dir_window_open = true;
+
+                                return Command::perform(
+                                    interaction::choose_save_directory::choose(
+                                        wizard_state.saves_dir.clone(),
+                                    ),
+                                    |r| {
+                                        Bl3Message::Interaction(
+                                            InteractionMessage::FirstRunWizardInteraction(
+                                                FirstRunWizardInteractionMessage::ChooseSavesDirCompleted(
+                                                    MessageResult::handle_result(r),
+                                                ),
+                                            ),
+                                        )
+                                    },
+                                );
+                            }
+                            FirstRunWizardInteractionMessage::ChooseSavesDirCompleted(res) => {
+                                wizard_state.choose_dir_window_open = false;
+
+                                match res {
+                                    MessageResult::Success(dir) => wizard_state.saves_dir = dir,
+                                    MessageResult::Error(e) => {
+                                        let msg = format!("Failed to choose saves folder: {}", e);
+
+                                        error!("{}", msg);
+
+                                        self.notification = Some(Notification::new(
+                                            msg,
+                                            NotificationSentiment::Negative,
+                                        ));
+                                    }
+                                }
+                            }
+                            FirstRunWizardInteractionMessage::UseDefaultBackupDirPressed => {
+                                wizard_state.backup_dir =
+                                    Bl3Config::default().backup_dir().to_path_buf();
+                            }
+                            FirstRunWizardInteractionMessage::ChooseBackupDirPressed => {
+                                wizard_state.choose_dir_window_open = true;
+
+                                return Command::perform(
+                                    interaction::choose_dir(wizard_state.backup_dir.clone()),
+                                    |r| {
+                                        Bl3Message::Interaction(
+                                            InteractionMessage::FirstRunWizardInteraction(
+                                                FirstRunWizardInteractionMessage::ChooseBackupDirCompleted(
+                                                    MessageResult::handle_result(r),
+                                                ),
+                                            ),
+                                        )
+                                    },
+                                );
+                            }
+                            FirstRunWizardInteractionMessage::ChooseBackupDirCompleted(res) => {
+                                wizard_state.choose_dir_window_open = false;
+
+                                match res {
+                                    MessageResult::Success(dir) => wizard_state.backup_dir = dir,
+                                    MessageResult::Error(e) => {
+                                        let msg = format!("Failed to choose backups folder: {}", e);
+
+                                        error!("{}", msg);
+
+                                        self.notification = Some(Notification::new(
+                                            msg,
+                                            NotificationSentiment::Negative,
+                                        ));
+                                    }
+                                }
+                            }
+                            FirstRunWizardInteractionMessage::FinishPressed => {
+                                self.config.set_saves_dir(wizard_state.saves_dir.clone());
+                                self.config.set_backup_dir(wizard_state.backup_dir.clone());
+                                self.config.set_first_run_complete(true);
+
+                                self.settings_state.saves_dir_input =
+                                    self.config.saves_dir().to_string_lossy().to_string();
+                                self.settings_state.backup_dir_input =
+                                    self.config.backup_dir().to_string_lossy().to_string();
 
-                                return Command::perform(self.config.clone().save(), |r| {
-                                    Bl3Message::Config(ConfigMessage::SaveCompleted(
-                                        MessageResult::handle_result(r),
-                                    ))
-                                });
+                                self.first_run_wizard_state.step = FirstRunWizardStep::default();
+                                self.view_state = ViewState::Loading;
+
+                                let save_command =
+                                    Command::perform(self.config.clone().save(), |r| {
+                                        Bl3Message::Config(ConfigMessage::SaveCompleted(
+                                            MessageResult::handle_result(r),
+                                        ))
+                                    });
+
+                                let load_command = self.update(Bl3Message::Initialization(
+                                    InitializationMessage::LoadSaves,
+                                ));
+
+                                return Command::batch(vec![save_command, load_command]);
                             }
                         }
-                    },
-                    InteractionMessage::LoadedFileSelected(loaded_file) => {
-                        self.loaded_files_selected = loaded_file;
-
-                        state_mappers::map_loaded_file_to_state(self).handle_ui_error(
-                            "Failed to map loaded file to editor",
-                            &mut self.notification,
-                        );
                     }
                     InteractionMessage::RefreshSavesDirectory => {
                         self.view_state = ViewState::Loading;
@@ -1359,6 +5058,14 @@ impl Application for Bl3Application {
                             },
                         );
                     }
+                    InteractionMessage::UndoLastSavePressed => {
+                        if let Some(undo) = self.last_save_undo.clone() {
+                            return Command::perform(
+                                interaction::file_save::undo_save(undo),
+                                |r| Bl3Message::UndoSaveCompleted(MessageResult::handle_result(r)),
+                            );
+                        }
+                    }
                     InteractionMessage::Ignore => {}
                 }
             }
@@ -1390,31 +5097,8 @@ impl Application for Bl3Application {
                     }
                 }
                 ChooseSaveMessage::FilesLoaded(res) => match res {
-                    MessageResult::Success((dir, mut files)) => {
-                        files.sort();
-                        self.loaded_files = files;
-
-                        self.loaded_files_selected = Box::new(
-                            self.loaded_files
-                                .first()
-                                .expect("loaded_files was empty")
-                                .clone(),
-                        );
-
-                        state_mappers::map_loaded_file_to_state(self).handle_ui_error(
-                            "Failed to map loaded file to editor",
-                            &mut self.notification,
-                        );
-
-                        self.config.set_saves_dir(dir);
-                        self.settings_state.saves_dir_input =
-                            self.config.saves_dir().to_string_lossy().to_string();
-
-                        return Command::perform(self.config.clone().save(), |r| {
-                            Bl3Message::Config(ConfigMessage::SaveCompleted(
-                                MessageResult::handle_result(r),
-                            ))
-                        });
+                    MessageResult::Success((dir, files)) => {
+                        return self.files_loaded(dir, files, None);
                     }
                     MessageResult::Error(e) => {
                         let msg = format!("Failed to load save folder: {}", e);
@@ -1428,12 +5112,44 @@ impl Application for Bl3Application {
                     }
                 },
             },
+            Bl3Message::SaveNotesCompleted(res) => {
+                if let MessageResult::Error(e) = res {
+                    let msg = format!("Failed to save notes for this save: {}", e);
+
+                    error!("{}", msg);
+
+                    self.notification =
+                        Some(Notification::new(msg, NotificationSentiment::Negative));
+                }
+            }
             Bl3Message::SaveFileCompleted(res) => match res {
-                MessageResult::Success(save) => {
-                    self.notification = Some(Notification::new(
-                        "Successfully saved file!",
-                        NotificationSentiment::Positive,
-                    ));
+                MessageResult::Success((save, undo)) => {
+                    self.last_save_undo = Some(undo);
+
+                    self.remove_recovery_file();
+
+                    self.notification = Some(
+                        if let Some(removed) = self
+                            .manage_save_state
+                            .save_view_state
+                            .inventory_state
+                            .removed_all_items
+                            .take()
+                        {
+                            Notification::new(
+                                format!(
+                                    "Saved file, removed {} items from the inventory.",
+                                    removed
+                                ),
+                                NotificationSentiment::Positive,
+                            )
+                        } else {
+                            Notification::new(
+                                "Successfully saved file!",
+                                NotificationSentiment::Positive,
+                            )
+                        },
+                    );
 
                     self.is_reloading_saves = true;
 
@@ -1466,35 +5182,54 @@ impl Application for Bl3Application {
                 }
             },
             Bl3Message::SaveProfileCompleted(res) => match res {
-                MessageResult::Success(profile) => {
-                    self.notification = Some(Notification::new(
-                        "Successfully saved profile!",
-                        NotificationSentiment::Positive,
-                    ));
+                MessageResult::Success((profile, undo)) => {
+                    self.last_save_undo = Some(undo);
+
+                    self.remove_recovery_file();
+
+                    self.notification = Some(
+                        match self
+                            .manage_profile_state
+                            .profile_view_state
+                            .bank_state
+                            .removed_all_items
+                            .take()
+                        {
+                            Some(removed) => Notification::new(
+                                format!("Saved profile, removed {} items from the bank.", removed),
+                                NotificationSentiment::Positive,
+                            ),
+                            None => Notification::new(
+                                "Successfully saved profile!",
+                                NotificationSentiment::Positive,
+                            ),
+                        },
+                    );
 
-                    self.is_reloading_saves = true;
+                    return self.reload_saved_profile(profile);
+                }
+                MessageResult::Error(e) => {
+                    let msg = format!("Failed to save profile: {}", e);
 
-                    let bl3_file_type = match profile.header_type {
-                        HeaderType::PcProfile => Bl3FileType::PcProfile(profile),
-                        HeaderType::Ps4Profile => Bl3FileType::Ps4Profile(profile),
-                        _ => {
-                            let msg = "Unexpected Bl3FileType when reloading profile";
+                    error!("{}", msg);
 
-                            error!("{}", msg);
-                            panic!("{}", msg);
-                        }
-                    };
+                    self.notification =
+                        Some(Notification::new(msg, NotificationSentiment::Negative));
+                }
+            },
+            Bl3Message::CreateBlankProfileCompleted(res) => match res {
+                MessageResult::Success((profile, undo)) => {
+                    self.last_save_undo = undo;
 
-                    return Command::perform(
-                        interaction::file_save::load_files_after_save(
-                            self.config.saves_dir().to_path_buf(),
-                            bl3_file_type,
-                        ),
-                        |r| Bl3Message::FilesLoadedAfterSave(MessageResult::handle_result(r)),
-                    );
+                    self.notification = Some(Notification::new(
+                        format!("Created blank profile {}.", profile.file_name),
+                        NotificationSentiment::Positive,
+                    ));
+
+                    return self.reload_saved_profile(profile);
                 }
                 MessageResult::Error(e) => {
-                    let msg = format!("Failed to save profile: {}", e);
+                    let msg = format!("Failed to create blank profile: {}", e);
 
                     error!("{}", msg);
 
@@ -1524,12 +5259,19 @@ impl Application for Bl3Application {
                                 _ => (),
                             }
                         } else {
-                            self.loaded_files_selected = Box::new(
-                                self.loaded_files
-                                    .first()
-                                    .expect("loaded_files was empty")
-                                    .clone(),
-                            );
+                            // The file was changed on disk by something else, such as a bulk
+                            // operation, so it is mapped again from what was read
+                            let saved_file_path = self.file_path(&saved_file);
+
+                            let selected_file = self
+                                .loaded_files
+                                .iter()
+                                .find(|f| self.file_path(f) == saved_file_path)
+                                .or_else(|| self.loaded_files.first())
+                                .expect("loaded_files was empty")
+                                .clone();
+
+                            self.loaded_files_selected = Box::new(selected_file);
 
                             state_mappers::map_loaded_file_to_state(self).handle_ui_error(
                                 "Failed to map loaded file to editor",
@@ -1551,9 +5293,255 @@ impl Application for Bl3Application {
 
                 self.is_reloading_saves = false;
             }
+            Bl3Message::TransferFilesCompleted(res) => {
+                match res {
+                    MessageResult::Success(_) => {
+                        self.notification = Some(Notification::new(
+                            "Successfully transferred items and saved both files!",
+                            NotificationSentiment::Positive,
+                        ));
+                    }
+                    MessageResult::Error(e) => {
+                        let msg = format!("Failed to transfer items: {}", e);
+
+                        error!("{}", msg);
+
+                        self.notification =
+                            Some(Notification::new(msg, NotificationSentiment::Negative));
+                    }
+                }
+
+                self.is_reloading_saves = true;
+
+                let current_file = self.manage_save_state.current_file.clone();
+
+                let bl3_file_type = match current_file.header_type {
+                    HeaderType::Ps4Save => Bl3FileType::Ps4Save(current_file),
+                    _ => Bl3FileType::PcSave(current_file),
+                };
+
+                return Command::perform(
+                    interaction::file_save::load_files_after_save(
+                        self.config.saves_dir().to_path_buf(),
+                        bl3_file_type,
+                    ),
+                    |r| Bl3Message::FilesLoadedAfterTransfer(MessageResult::handle_result(r)),
+                );
+            }
+            Bl3Message::FilesLoadedAfterTransfer(res) => {
+                match res {
+                    MessageResult::Success((transferred_file, mut files)) => {
+                        files.sort();
+
+                        self.loaded_files = files;
+
+                        let transferred_file_name = match &transferred_file {
+                            Bl3FileType::PcSave(save) | Bl3FileType::Ps4Save(save) => {
+                                save.file_name.clone()
+                            }
+                            _ => String::new(),
+                        };
+
+                        let selected_file = self
+                            .loaded_files
+                            .iter()
+                            .find(|f| match f {
+                                Bl3FileType::PcSave(save) | Bl3FileType::Ps4Save(save) => {
+                                    save.file_name == transferred_file_name
+                                }
+                                _ => false,
+                            })
+                            .or_else(|| self.loaded_files.first())
+                            .expect("loaded_files was empty")
+                            .clone();
+
+                        self.loaded_files_selected = Box::new(selected_file);
+
+                        state_mappers::map_loaded_file_to_state(self).handle_ui_error(
+                            "Failed to map loaded file to editor",
+                            &mut self.notification,
+                        );
+                    }
+                    MessageResult::Error(e) => {
+                        let msg = format!("Failed to load save folder: {}", e);
+
+                        error!("{}", msg);
+
+                        self.view_state = ViewState::ChooseSaveDirectory;
+
+                        self.notification =
+                            Some(Notification::new(msg, NotificationSentiment::Negative));
+                    }
+                }
+
+                self.is_reloading_saves = false;
+            }
+            Bl3Message::UndoSaveCompleted(res) => match res {
+                MessageResult::Success(()) => {
+                    if let Some(undo) = self.last_save_undo.take() {
+                        let file_name = undo
+                            .target
+                            .file_name()
+                            .map(|f| f.to_string_lossy().to_string())
+                            .unwrap_or_default();
+
+                        self.notification = Some(Notification::new(
+                            format!("Restored {} to how it was before the last save.", file_name),
+                            NotificationSentiment::Positive,
+                        ));
+
+                        return Command::perform(
+                            interaction::choose_save_directory::load_files_in_directory(
+                                self.config.saves_dir().to_path_buf(),
+                            ),
+                            move |r| {
+                                Bl3Message::Initialization(InitializationMessage::SaveFileLoaded(
+                                    file_name,
+                                    MessageResult::handle_result(r),
+                                ))
+                            },
+                        );
+                    }
+                }
+                MessageResult::Error(e) => {
+                    let msg = format!("Failed to undo the last save: {}", e);
+
+                    error!("{}", msg);
+
+                    self.notification =
+                        Some(Notification::new(msg, NotificationSentiment::Negative));
+                }
+            },
             Bl3Message::ClearNotification => {
                 self.notification = None;
             }
+            Bl3Message::ConfirmationDialog(dialog_msg) => {
+                if let Some(message) = self
+                    .confirmation_dialog
+                    .take()
+                    .and_then(|d| d.into_message(dialog_msg))
+                {
+                    return self.update(message);
+                }
+            }
+            Bl3Message::SaveLastSelectedFile(change) => {
+                if change == self.last_selected_file_changes {
+                    return Command::perform(self.config.clone().save(), |r| {
+                        Bl3Message::Config(ConfigMessage::SaveCompleted(
+                            MessageResult::handle_result(r),
+                        ))
+                    });
+                }
+            }
+            Bl3Message::ModifiersChanged(modifiers) => {
+                self.current_modifiers = modifiers;
+            }
+            Bl3Message::RecoveryTick => {
+                return self.write_recovery_file();
+            }
+            Bl3Message::RecoveryFileSaved(res) => {
+                if let MessageResult::Error(e) = res {
+                    warn!("Failed to write recovery file: {}", e);
+                }
+            }
+            Bl3Message::RestoreRecoveryFile(data) => {
+                match state_mappers::map_recovered_file_to_state(self, &data) {
+                    Ok(_) => {
+                        self.tab_badges_stale = true;
+
+                        self.notification = Some(Notification::new(
+                            "Restored edits that were never saved, save the file to keep them.",
+                            NotificationSentiment::Positive,
+                        ));
+                    }
+                    Err(e) => {
+                        let msg = format!("Failed to restore edits: {}", e);
+
+                        error!("{}", msg);
+
+                        self.notification =
+                            Some(Notification::new(msg, NotificationSentiment::Negative));
+
+                        // Don't leave the editor half restored
+                        state_mappers::map_loaded_file_to_state(self).handle_ui_error(
+                            "Failed to map loaded file to editor",
+                            &mut self.notification,
+                        );
+                    }
+                }
+            }
+            Bl3Message::DiscardRecoveryFile => {
+                self.remove_recovery_file();
+            }
+            Bl3Message::TabBadgesTick => {
+                self.refresh_tab_badges();
+            }
+            Bl3Message::ItemVerifySpinnerTick => {
+                self.manage_save_state
+                    .save_view_state
+                    .inventory_state
+                    .item_editor_state
+                    .advance_verify_spinners();
+
+                self.manage_profile_state
+                    .profile_view_state
+                    .bank_state
+                    .item_editor_state
+                    .advance_verify_spinners();
+            }
+            Bl3Message::DeleteKeyPressed => {
+                let delete_message = match &self.view_state {
+                    ViewState::ManageSave(ManageSaveView::TabBar(SaveTabBarView::Inventory)) => {
+                        let item_editor_state = &self
+                            .manage_save_state
+                            .save_view_state
+                            .inventory_state
+                            .item_editor_state;
+
+                        (item_editor_state.item_list_tab_type == ItemListTabType::Items).then(
+                            || {
+                                InteractionMessage::ManageSaveInteraction(
+                                    ManageSaveInteractionMessage::Inventory(
+                                        SaveInventoryInteractionMessage::Editor(
+                                            ItemEditorInteractionMessage::DeleteItem(
+                                                item_editor_state.selected_item_index,
+                                            ),
+                                        ),
+                                    ),
+                                )
+                            },
+                        )
+                    }
+                    ViewState::ManageProfile(ManageProfileView::TabBar(
+                        ProfileTabBarView::Bank,
+                    )) => {
+                        let item_editor_state = &self
+                            .manage_profile_state
+                            .profile_view_state
+                            .bank_state
+                            .item_editor_state;
+
+                        (item_editor_state.item_list_tab_type == ItemListTabType::Items).then(
+                            || {
+                                InteractionMessage::ManageProfileInteraction(
+                                    ManageProfileInteractionMessage::Bank(
+                                        ProfileBankInteractionMessage::Editor(
+                                            ItemEditorInteractionMessage::DeleteItem(
+                                                item_editor_state.selected_item_index,
+                                            ),
+                                        ),
+                                    ),
+                                )
+                            },
+                        )
+                    }
+                    _ => None,
+                };
+
+                if let Some(delete_message) = delete_message {
+                    return self.update(Bl3Message::Interaction(delete_message));
+                }
+            }
         };
 
         Command::none()
@@ -1622,21 +5610,54 @@ impl Application for Bl3Application {
             ManageProfileView::TabBar(ProfileTabBarView::General),
         ));
 
+        let is_read_only = view_state_discrim == manage_save_discrim
+            && self.manage_save_state.current_file.origin != FileOrigin::Directory;
+
         let mut save_button = Button::new(
             &mut self.save_file_button_state,
-            Text::new("Save").font(JETBRAINS_MONO_BOLD).size(17),
+            Text::new(if is_read_only { "Save As..." } else { "Save" })
+                .font(JETBRAINS_MONO_BOLD)
+                .size(17),
+        )
+        .padding(10)
+        .style(Bl3UiStyle);
+
+        let mut preview_changes_button = Button::new(
+            &mut self.preview_changes_button_state,
+            Text::new("Preview Changes")
+                .font(JETBRAINS_MONO_BOLD)
+                .size(17),
         )
         .padding(10)
         .style(Bl3UiStyle);
 
-        if view_state_discrim == manage_save_discrim {
+        let mut changes_summary = None;
+
+        // The dialog's own buttons decide whether to save while it is open
+        let dialog_open = self.confirmation_dialog.is_some();
+
+        if view_state_discrim == manage_save_discrim && !dialog_open {
             save_button = save_button.on_press(InteractionMessage::ManageSaveInteraction(
                 ManageSaveInteractionMessage::SaveFilePressed,
             ));
-        } else if view_state_discrim == manage_profile_discrim {
+
+            preview_changes_button =
+                preview_changes_button.on_press(InteractionMessage::ManageSaveInteraction(
+                    ManageSaveInteractionMessage::PreviewChangesPressed,
+                ));
+
+            changes_summary = self.manage_save_state.changes_summary.as_ref();
+        } else if view_state_discrim == manage_profile_discrim && !dialog_open {
             save_button = save_button.on_press(InteractionMessage::ManageProfileInteraction(
                 ManageProfileInteractionMessage::SaveProfilePressed,
             ));
+
+            preview_changes_button =
+                preview_changes_button.on_press(InteractionMessage::ManageProfileInteraction(
+                    ManageProfileInteractionMessage::PreviewChangesPressed,
+                ));
+
+            changes_summary = self.manage_profile_state.changes_summary.as_ref();
         }
 
         let mut menu_bar_editor_content = Row::new()
@@ -1648,39 +5669,81 @@ impl Application for Bl3Application {
         {
             menu_bar_editor_content = menu_bar_editor_content.push(refresh_button);
             menu_bar_editor_content = menu_bar_editor_content.push(all_saves_picklist);
+            if self.last_save_undo.is_some() {
+                menu_bar_editor_content = menu_bar_editor_content.push(
+                    Button::new(
+                        &mut self.undo_save_button_state,
+                        Text::new("Undo Last Save")
+                            .font(JETBRAINS_MONO_BOLD)
+                            .size(17),
+                    )
+                    .on_press(InteractionMessage::UndoLastSavePressed)
+                    .padding(10)
+                    .style(Bl3UiStyle)
+                    .into_element(),
+                );
+            }
+
+            menu_bar_editor_content =
+                menu_bar_editor_content.push(preview_changes_button.into_element());
             menu_bar_editor_content = menu_bar_editor_content.push(save_button.into_element());
         }
 
         let mut menu_bar_content = Column::new().push(menu_bar_editor_content).spacing(10);
 
-        if let Some(latest_release) = &self.latest_release {
-            let mut update_button = Button::new(
-                &mut self.update_button_state,
-                Text::new(match self.is_updating {
-                    true => "Updating...".to_string(),
-                    false => format!(
-                        "Click here to update to version: {}",
-                        latest_release.tag_name
-                    ),
-                })
-                .font(JETBRAINS_MONO_BOLD)
-                .size(17),
+        if let Some(changes_summary) = changes_summary {
+            let changes_summary = Container::new(
+                Text::new(format!("Changes: {}", changes_summary))
+                    .font(JETBRAINS_MONO)
+                    .size(17)
+                    .color(Color::from_rgb8(220, 220, 220)),
             )
-            .padding(10)
-            .style(Bl3UiPositiveButtonStyle);
+            .width(Length::Fill)
+            .align_x(Horizontal::Right);
 
-            if !self.is_updating {
-                update_button = update_button.on_press(Bl3Message::UpdateToLatestRelease);
-            }
+            menu_bar_content = menu_bar_content.push(changes_summary);
+        }
 
-            let update_content = Container::new(
+        if let Some(latest_release) = &self.latest_release {
+            let update_row = if self.is_updating {
                 Row::new()
-                    .push(update_button)
-                    .spacing(10)
-                    .align_items(Alignment::Center),
-            )
-            .width(Length::Fill)
-            .align_x(Horizontal::Left);
+                    .push(
+                        Text::new(format!(
+                            "Downloading {}: {:.0}%",
+                            latest_release.tag_name,
+                            self.update_progress * 100.0
+                        ))
+                        .font(JETBRAINS_MONO_BOLD)
+                        .size(17)
+                        .color(Color::from_rgb8(149, 240, 172)),
+                    )
+                    .push(
+                        ProgressBar::new(0.0..=1.0, self.update_progress)
+                            .width(Length::Units(200))
+                            .height(Length::Units(12))
+                            .style(Bl3UiProgressBarStyle),
+                    )
+            } else {
+                Row::new().push(
+                    Button::new(
+                        &mut self.update_button_state,
+                        Text::new(format!(
+                            "Click here to update to version: {}",
+                            latest_release.tag_name
+                        ))
+                        .font(JETBRAINS_MONO_BOLD)
+                        .size(17),
+                    )
+                    .on_press(Bl3Message::UpdateToLatestRelease)
+                    .padding(10)
+                    .style(Bl3UiPositiveButtonStyle),
+                )
+            };
+
+            let update_content =
+                Container::new(update_row.spacing(10).align_items(Alignment::Center))
+                    .width(Length::Fill)
+                    .align_x(Horizontal::Left);
 
             menu_bar_content = menu_bar_content.push(update_content);
         }
@@ -1690,26 +5753,32 @@ impl Application for Bl3Application {
             .width(Length::Fill)
             .style(Bl3UiMenuBarStyle);
 
-        let content = match &self.view_state {
-            ViewState::Initializing => views::initialization::view(),
-            ViewState::Loading => views::loading::view(),
-            ViewState::ChooseSaveDirectory => {
-                views::choose_save_directory::view(&mut self.choose_save_directory_state)
-            }
-            ViewState::ManageSave(manage_save_view) => match manage_save_view {
-                ManageSaveView::TabBar(main_tab_bar_view) => views::manage_save::main::view(
-                    &mut self.settings_state,
-                    &mut self.manage_save_state,
-                    main_tab_bar_view,
+        let content = if let Some(confirmation_dialog) = &mut self.confirmation_dialog {
+            confirmation_dialog.view()
+        } else {
+            match &self.view_state {
+                ViewState::Initializing => views::initialization::view(),
+                ViewState::Loading => views::loading::view(),
+                ViewState::ChooseSaveDirectory => views::choose_save_directory::view(
+                    &mut self.choose_save_directory_state,
+                    self.config.recent_save_dirs(),
                 ),
-            },
-            ViewState::ManageProfile(manage_profile_view) => match manage_profile_view {
-                ManageProfileView::TabBar(main_tab_bar_view) => views::manage_profile::main::view(
+                ViewState::FirstRunWizard => {
+                    views::first_run_wizard::view(&mut self.first_run_wizard_state)
+                }
+                ViewState::ManageSave(manage_save_view) => match manage_save_view {
+                    ManageSaveView::TabBar(main_tab_bar_view) => views::manage_save::main::view(
+                        &mut self.settings_state,
+                        &mut self.manage_save_state,
+                        main_tab_bar_view,
+                    ),
+                },
+                ViewState::ManageProfile(manage_profile_view) => views::manage_profile::main::view(
                     &mut self.settings_state,
                     &mut self.manage_profile_state,
-                    main_tab_bar_view,
+                    manage_profile_view,
                 ),
-            },
+            }
         };
 
         let mut all_content = Column::new().push(menu_bar);
@@ -1718,7 +5787,9 @@ impl Application for Bl3Application {
             all_content = all_content.push(notification.view());
         }
 
-        all_content = all_content.push(content);
+        all_content = all_content
+            .push(content)
+            .push(views::status_bar::view(self.current_modifiers));
 
         Container::new(all_content)
             .width(Length::Fill)
@@ -1727,6 +5798,75 @@ impl Application for Bl3Application {
             .into()
     }
 
+    fn subscription(&self) -> Subscription<Self::Message> {
+        let is_verifying_items = self
+            .manage_save_state
+            .save_view_state
+            .inventory_state
+            .item_editor_state
+            .is_verifying_items()
+            || self
+                .manage_profile_state
+                .profile_view_state
+                .bank_state
+                .item_editor_state
+                .is_verifying_items();
+
+        let verify_spinner = if is_verifying_items {
+            iced::time::every(Duration::from_millis(150)).map(|_| Bl3Message::ItemVerifySpinnerTick)
+        } else {
+            Subscription::none()
+        };
+
+        let events = iced_native::subscription::events_with(|event, status| match event {
+            iced_native::Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                Some(Bl3Message::ModifiersChanged(modifiers))
+            }
+            // Only when no widget used the key, so deleting text in an input keeps the item
+            iced_native::Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Delete,
+                ..
+            }) if status == iced_native::event::Status::Ignored => {
+                Some(Bl3Message::DeleteKeyPressed)
+            }
+            iced_native::Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Enter,
+                ..
+            }) if status == iced_native::event::Status::Ignored => Some(
+                Bl3Message::ConfirmationDialog(ConfirmationDialogMessage::ConfirmPressed),
+            ),
+            iced_native::Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Escape,
+                ..
+            }) if status == iced_native::event::Status::Ignored => Some(
+                Bl3Message::ConfirmationDialog(ConfirmationDialogMessage::CancelPressed),
+            ),
+            _ => None,
+        });
+
+        let recovery_interval = if self.edits_since_recovery >= recovery::RECOVERY_EDIT_THRESHOLD {
+            recovery::RECOVERY_EDIT_DELAY
+        } else {
+            recovery::RECOVERY_INTERVAL
+        };
+
+        let recovery_tick = match self.view_state {
+            ViewState::ManageSave(_) | ViewState::ManageProfile(_) => {
+                iced::time::every(recovery_interval).map(|_| Bl3Message::RecoveryTick)
+            }
+            _ => Subscription::none(),
+        };
+
+        let tab_badges_tick = match self.view_state {
+            ViewState::ManageSave(_) | ViewState::ManageProfile(_) if self.tab_badges_stale => {
+                iced::time::every(TAB_BADGES_INTERVAL).map(|_| Bl3Message::TabBadgesTick)
+            }
+            _ => Subscription::none(),
+        };
+
+        Subscription::batch(vec![events, verify_spinner, recovery_tick, tab_badges_tick])
+    }
+
     fn background_color(&self) -> Color {
         Color::from_rgb8(23, 23, 23)
     }
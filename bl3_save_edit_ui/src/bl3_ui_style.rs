@@ -1,5 +1,8 @@
 use iced::pick_list::Menu;
-use iced::{button, checkbox, container, pick_list, searchable_pick_list, text_input, Color};
+use iced::{
+    button, checkbox, container, pick_list, progress_bar, radio, searchable_pick_list, text_input,
+    Color,
+};
 
 pub struct Bl3UiStyleNoBorder;
 
@@ -199,6 +202,26 @@ impl checkbox::StyleSheet for Bl3UiStyle {
     }
 }
 
+impl radio::StyleSheet for Bl3UiStyle {
+    fn active(&self) -> radio::Style {
+        radio::Style {
+            background: Color::from_rgb8(35, 35, 35).into(),
+            dot_color: Color::from_rgb8(220, 220, 220),
+            border_width: 0.0,
+            border_color: Default::default(),
+        }
+    }
+
+    fn hovered(&self) -> radio::Style {
+        radio::Style {
+            background: Color::from_rgb8(35, 35, 35).into(),
+            dot_color: Color::from_rgb8(220, 220, 220),
+            border_width: 1.0,
+            border_color: Color::from_rgb8(45, 45, 45),
+        }
+    }
+}
+
 impl button::StyleSheet for Bl3UiStyle {
     fn active(&self) -> button::Style {
         button::Style {
@@ -272,6 +295,18 @@ impl container::StyleSheet for Bl3UiTooltipStyle {
     }
 }
 
+pub struct Bl3UiProgressBarStyle;
+
+impl progress_bar::StyleSheet for Bl3UiProgressBarStyle {
+    fn style(&self) -> progress_bar::Style {
+        progress_bar::Style {
+            background: Color::from_rgb8(29, 54, 35).into(),
+            bar: Color::from_rgb8(149, 240, 172).into(),
+            border_radius: 1.0,
+        }
+    }
+}
+
 pub struct Bl3UiPositiveButtonStyle;
 
 impl button::StyleSheet for Bl3UiPositiveButtonStyle {
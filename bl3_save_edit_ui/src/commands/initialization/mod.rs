@@ -1,5 +1,9 @@
+use std::path::Path;
+
+use anyhow::Result;
 use tracing::info;
 
+use bl3_save_edit_core::data_overrides::{set_data_overrides, DataOverrides, DATA_OVERRIDES_FILE};
 use bl3_save_edit_core::resources::{
     INVENTORY_BALANCE_PARTS, INVENTORY_INV_DATA_PARTS, INVENTORY_MANUFACTURER_PARTS,
     INVENTORY_PARTS_ALL_CATEGORIZED, INVENTORY_SERIAL_DB, INVENTORY_SERIAL_DB_PARTS_CATEGORIZED,
@@ -17,3 +21,24 @@ pub async fn load_lazy_data() {
     let _ = &*INVENTORY_MANUFACTURER_PARTS;
     let _ = &*LOOTLEMON_ITEMS;
 }
+
+/// Reads the data overrides in the config folder and uses them for items read from now on,
+/// returning how many entries there are. A file that can't be read is ignored.
+pub fn load_data_overrides(config_dir: &Path) -> Result<usize> {
+    match DataOverrides::load(&config_dir.join(DATA_OVERRIDES_FILE)) {
+        Ok(data_overrides) => {
+            let len = data_overrides.len();
+
+            info!("Loaded {} data overrides", len);
+
+            set_data_overrides(data_overrides);
+
+            Ok(len)
+        }
+        Err(e) => {
+            set_data_overrides(DataOverrides::default());
+
+            Err(e)
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use tracing::{error, info};
+
+use bl3_save_edit_core::bl3_profile::Bl3Profile;
+use bl3_save_edit_core::file_helper::Bl3FileType;
+use bl3_save_edit_core::guardian_injection::GuardianInjection;
+
+use crate::commands::interaction::file_save;
+
+/// Which saves were changed by a bulk operation, by file name.
+#[derive(Debug, Clone, Default)]
+pub struct SyncReport {
+    pub updated: Vec<String>,
+    pub unchanged: Vec<String>,
+    pub failed: Vec<String>,
+}
+
+impl std::fmt::Display for SyncReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Updated {} save(s), {} already matched",
+            self.updated.len(),
+            self.unchanged.len()
+        )?;
+
+        if !self.failed.is_empty() {
+            write!(f, ", {} failed", self.failed.len())?;
+        }
+
+        write!(f, ".")
+    }
+}
+
+/// Copies the guardian rank, tokens and rewards from `profile` into each of `saves`, backing up
+/// every save that is changed. Files that aren't character saves are skipped.
+pub async fn sync_guardian_rank_to_saves(
+    backup_dir: PathBuf,
+    saves: Vec<PathBuf>,
+    profile: Bl3Profile,
+) -> Result<SyncReport> {
+    let injection = GuardianInjection::from_profile_data(&profile.profile_data);
+
+    let mut report = SyncReport::default();
+
+    for path in saves {
+        let data = match tokio::fs::read(&path).await {
+            Ok(data) => data,
+            Err(e) => {
+                error!("Failed to read {}: {}", path.display(), e);
+                report.failed.push(path.display().to_string());
+                continue;
+            }
+        };
+
+        let existing_save = match Bl3FileType::from_unknown_data(&path, &data) {
+            Ok(Bl3FileType::PcSave(save)) | Ok(Bl3FileType::Ps4Save(save)) => save,
+            Ok(_) => continue,
+            Err(e) => {
+                error!("Failed to read {}: {}", path.display(), e);
+                report.failed.push(path.display().to_string());
+                continue;
+            }
+        };
+
+        let mut new_save = existing_save.clone();
+
+        if !injection.apply(&mut new_save)? {
+            report.unchanged.push(existing_save.file_name);
+            continue;
+        }
+
+        info!(
+            "Syncing guardian rank {} to {}",
+            injection.rank, existing_save.file_name
+        );
+
+        let file_name = existing_save.file_name.clone();
+
+        let (output, new_save) = new_save.as_bytes()?;
+
+        match file_save::save_file(backup_dir.clone(), path, output, existing_save, new_save).await
+        {
+            Ok(_) => report.updated.push(file_name),
+            Err(e) => {
+                error!("Failed to save {}: {}", file_name, e);
+                report.failed.push(file_name);
+            }
+        }
+    }
+
+    Ok(report)
+}
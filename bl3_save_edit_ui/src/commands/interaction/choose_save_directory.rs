@@ -1,11 +1,11 @@
 use std::ffi::OsStr;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use anyhow::{bail, Result};
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use tracing::{error, info};
 
-use bl3_save_edit_core::file_helper::Bl3FileType;
+use bl3_save_edit_core::file_helper::{read_saves_in_archive, relative_dir, Bl3FileType};
 
 use crate::commands::interaction::choose_dir;
 
@@ -39,23 +39,74 @@ pub async fn choose(existing_dir: PathBuf) -> Result<PathBuf> {
     choose_dir(default_dir).await
 }
 
+/// Where the game keeps saves on this platform. Saves are kept in a folder per account so the first
+/// account folder is used when there is one.
+pub fn default_saves_dir() -> PathBuf {
+    let home_dir = dirs::home_dir().unwrap_or_default();
+
+    #[cfg(target_os = "windows")]
+    let saves_dir = home_dir.join("Documents/My Games/Borderlands 3/Saved/SaveGames");
+
+    #[cfg(target_os = "linux")]
+    let saves_dir = home_dir.join(
+        ".local/share/Steam/steamapps/compatdata/397540/pfx/drive_c/users/steamuser/Documents/My Games/Borderlands 3/Saved/SaveGames",
+    );
+
+    #[cfg(target_os = "macos")]
+    let saves_dir =
+        home_dir.join("Library/Application Support/GearboxSoftware/OakGame/Saved/SaveGames");
+
+    std::fs::read_dir(&saves_dir)
+        .ok()
+        .and_then(|entries| {
+            entries
+                .filter_map(|e| e.ok())
+                .map(|e| e.path())
+                .find(|p| p.is_dir())
+        })
+        .unwrap_or(saves_dir)
+}
+
+fn is_sav_file(path: &Path) -> bool {
+    path.extension().and_then(OsStr::to_str) == Some("sav")
+}
+
+// Steam cloud can leave copies of the profile behind as .sav.bak files or in nested folders
+fn is_sav_backup_file(path: &Path) -> bool {
+    path.file_name()
+        .and_then(OsStr::to_str)
+        .map(|f| f.ends_with(".sav.bak"))
+        .unwrap_or(false)
+}
+
+fn is_zip_file(path: &Path) -> bool {
+    path.extension()
+        .and_then(OsStr::to_str)
+        .map(|e| e.eq_ignore_ascii_case("zip"))
+        .unwrap_or(false)
+}
+
 pub async fn load_files_in_directory(dir: PathBuf) -> Result<(PathBuf, Vec<Bl3FileType>)> {
     let start_time = tokio::time::Instant::now();
 
     let mut dirs = tokio::fs::read_dir(&*dir).await?;
 
     let mut all_data = vec![];
+    let mut archive_data = vec![];
+    let mut sub_dirs = vec![];
 
     while let Ok(entry) = dirs.next_entry().await {
         if let Some(entry) = entry {
             let path = entry.path();
-            if !path.is_dir()
-                && path
-                    .extension()
-                    .and_then(OsStr::to_str)
-                    .and_then(|p| if p == "sav" { Some(()) } else { None })
-                    .is_some()
-            {
+
+            if path.is_dir() {
+                sub_dirs.push(path);
+            } else if is_zip_file(&path) {
+                match tokio::fs::read(&path).await {
+                    Ok(data) => archive_data.push((path, data)),
+                    Err(e) => error!("{}", e),
+                }
+            } else if is_sav_file(&path) || is_sav_backup_file(&path) {
                 match tokio::fs::read(&path).await {
                     Ok(data) => all_data.push((path, data)),
                     Err(e) => error!("{}", e),
@@ -66,10 +117,68 @@ pub async fn load_files_in_directory(dir: PathBuf) -> Result<(PathBuf, Vec<Bl3Fi
         }
     }
 
+    for sub_dir in sub_dirs {
+        let mut entries = match tokio::fs::read_dir(&sub_dir).await {
+            Ok(entries) => entries,
+            Err(e) => {
+                error!("{}", e);
+                continue;
+            }
+        };
+
+        while let Ok(Some(entry)) = entries.next_entry().await {
+            let path = entry.path();
+
+            if !path.is_dir() && (is_sav_file(&path) || is_sav_backup_file(&path)) {
+                match tokio::fs::read(&path).await {
+                    Ok(data) => all_data.push((path, data)),
+                    Err(e) => error!("{}", e),
+                }
+            }
+        }
+    }
+
+    let base_dir = dir.clone();
+
     let all_files: Vec<Bl3FileType> = tokio_rayon::spawn(move || {
+        let archive_files = archive_data
+            .par_iter()
+            .filter_map(
+                |(archive, data)| match read_saves_in_archive(archive, data) {
+                    Ok(saves) => Some(saves),
+                    Err(e) => {
+                        error!("{}", e);
+                        None
+                    }
+                },
+            )
+            .flatten()
+            .collect::<Vec<_>>();
+
         all_data
             .par_iter()
-            .filter_map(|(file_name, data)| Bl3FileType::from_unknown_data(file_name, data).ok())
+            .filter_map(|(file_name, data)| {
+                let file = Bl3FileType::from_unknown_data(file_name, data).ok()?;
+
+                let relative_dir = relative_dir(&base_dir, file_name);
+
+                match file {
+                    Bl3FileType::PcProfile(mut profile) => {
+                        profile.relative_dir = relative_dir;
+                        Some(Bl3FileType::PcProfile(profile))
+                    }
+                    Bl3FileType::Ps4Profile(mut profile) => {
+                        profile.relative_dir = relative_dir;
+                        Some(Bl3FileType::Ps4Profile(profile))
+                    }
+                    // Only profiles are looked for in backups and nested folders
+                    save if relative_dir.as_os_str().is_empty() && is_sav_file(file_name) => {
+                        Some(save)
+                    }
+                    _ => None,
+                }
+            })
+            .chain(archive_files)
             .collect::<Vec<_>>()
     })
     .await;
@@ -1,23 +1,33 @@
+use std::ffi::OsStr;
 use std::path::PathBuf;
 
-use anyhow::Result;
+use anyhow::{anyhow, Context, Result};
 use chrono::Local;
 use tracing::info;
 
 use bl3_save_edit_core::bl3_profile::Bl3Profile;
 use bl3_save_edit_core::bl3_save::Bl3Save;
-use bl3_save_edit_core::file_helper::Bl3FileType;
+use bl3_save_edit_core::file_helper::{restore_backup, write_file_atomic, Bl3FileType, FileOrigin};
+use bl3_save_edit_core::guardian_injection::{saves_requiring_injection, GuardianInjection};
+use bl3_save_edit_core::parser::HeaderType;
 
-use crate::commands::interaction::choose_save_directory;
+use crate::commands::interaction::{choose_save_directory, choose_save_file};
 use crate::state_mappers;
 
+/// A file that was just saved and the backup taken of it beforehand.
+#[derive(Debug, Clone)]
+pub struct UndoSave {
+    pub target: PathBuf,
+    pub backup: PathBuf,
+}
+
 pub async fn save_file(
     backup_dir: PathBuf,
     output_file: PathBuf,
     output: Vec<u8>,
     existing_save: Bl3Save,
     new_save: Bl3Save,
-) -> Result<Bl3Save> {
+) -> Result<(Bl3Save, UndoSave)> {
     info!(
         "Making a backup of existing save: {}",
         existing_save.file_name
@@ -35,17 +45,97 @@ pub async fn save_file(
         current_time
     );
 
-    let backup_name = sanitize_filename::sanitize(backup_name);
+    let backup_file = backup_dir.join(sanitize_filename::sanitize(backup_name));
 
     let (existing_save_output, _) = existing_save.as_bytes()?;
 
-    tokio::fs::write(backup_dir.join(backup_name), existing_save_output).await?;
+    tokio::fs::write(&backup_file, existing_save_output)
+        .await
+        .context("failed to write a backup, the original file was not changed")?;
 
     info!("Saving file: {}", new_save.file_name);
 
-    tokio::fs::write(output_file, output).await?;
+    let undo = UndoSave {
+        target: output_file.clone(),
+        backup: backup_file,
+    };
+
+    tokio_rayon::spawn(move || write_file_atomic(&output_file, &output)).await?;
+
+    Ok((new_save, undo))
+}
+
+/// Saves read from inside an archive can't be written back, so they are extracted to a file that
+/// is picked instead.
+pub async fn save_file_as(
+    backup_dir: PathBuf,
+    saves_dir: PathBuf,
+    existing_save: Bl3Save,
+    mut new_save: Bl3Save,
+) -> Result<(Bl3Save, UndoSave)> {
+    let output_file = choose_save_file(saves_dir, new_save.file_name.clone()).await?;
 
-    Ok(new_save)
+    new_save.file_name = output_file
+        .file_name()
+        .and_then(OsStr::to_str)
+        .with_context(|| format!("{} has an invalid file name.", output_file.display()))?
+        .to_owned();
+    new_save.origin = FileOrigin::Directory;
+
+    let (output, new_save) = new_save.as_bytes()?;
+
+    save_file(backup_dir, output_file, output, existing_save, new_save).await
+}
+
+pub async fn save_transferred_files(
+    backup_dir: PathBuf,
+    saves_dir: PathBuf,
+    existing_left: Bl3Save,
+    new_left: Bl3Save,
+    existing_right: Bl3Save,
+    new_right: Bl3Save,
+) -> Result<()> {
+    let left_file_name = new_left.file_name.clone();
+    let right_file_name = new_right.file_name.clone();
+
+    let (left_output, new_left) = new_left.as_bytes()?;
+    let (right_output, new_right) = new_right.as_bytes()?;
+
+    save_file(
+        backup_dir.clone(),
+        saves_dir.join(&left_file_name),
+        left_output,
+        existing_left,
+        new_left,
+    )
+    .await
+    .map_err(|e| {
+        anyhow!(
+            "failed to save {}, no files were changed: {}",
+            left_file_name,
+            e
+        )
+    })?;
+
+    save_file(
+        backup_dir,
+        saves_dir.join(&right_file_name),
+        right_output,
+        existing_right,
+        new_right,
+    )
+    .await
+    .map_err(|e| {
+        anyhow!(
+            "{} was saved but {} was not, restore {} from the backups folder to undo the transfer: {}",
+            left_file_name,
+            right_file_name,
+            left_file_name,
+            e
+        )
+    })?;
+
+    Ok(())
 }
 
 pub async fn save_profile(
@@ -55,8 +145,8 @@ pub async fn save_profile(
     output: Vec<u8>,
     existing_profile: Bl3Profile,
     new_profile: Bl3Profile,
-    guardian_data_injection_required: bool,
-) -> Result<Bl3Profile> {
+    guardian_injection: Option<GuardianInjection>,
+) -> Result<(Bl3Profile, UndoSave)> {
     info!(
         "Making a backup of existing profile: {}",
         existing_profile.file_name
@@ -70,34 +160,101 @@ pub async fn save_profile(
         current_time
     );
 
-    let backup_name = sanitize_filename::sanitize(backup_name);
+    let backup_file = backup_dir.join(sanitize_filename::sanitize(backup_name));
 
     let (existing_profile_output, _) = existing_profile.as_bytes()?;
 
-    tokio::fs::write(&backup_dir.join(backup_name), existing_profile_output).await?;
+    tokio::fs::write(&backup_file, existing_profile_output)
+        .await
+        .context("failed to write a backup, the original file was not changed")?;
 
     info!("Saving profile: {}", new_profile.file_name);
 
-    tokio::fs::write(output_file, output).await?;
+    let undo = UndoSave {
+        target: output_file.clone(),
+        backup: backup_file,
+    };
+
+    tokio_rayon::spawn(move || write_file_atomic(&output_file, &output)).await?;
+
+    if let Some(guardian_injection) = guardian_injection {
+        state_mappers::inject_guardian_data_into_saves(backup_dir, saves_dir, guardian_injection)
+            .await?;
+    }
 
-    if guardian_data_injection_required {
-        let guardian_rank = new_profile.profile_data.guardian_rank();
+    Ok((new_profile, undo))
+}
 
-        let guardian_tokens = new_profile.profile_data.guardian_tokens();
+/// Writes a blank profile to a file that is picked, backing up the file first if it exists.
+pub async fn create_blank_profile(
+    backup_dir: PathBuf,
+    saves_dir: PathBuf,
+    header_type: HeaderType,
+) -> Result<(Bl3Profile, Option<UndoSave>)> {
+    let output_file = choose_save_file(saves_dir, "profile.sav".to_owned()).await?;
 
-        let guardian_rewards = new_profile.profile_data.guardian_rewards();
+    let file_name = output_file
+        .file_name()
+        .and_then(OsStr::to_str)
+        .with_context(|| format!("{} has an invalid file name.", output_file.display()))?;
 
-        state_mappers::inject_guardian_data_into_saves(
-            backup_dir,
-            saves_dir,
-            guardian_rank,
-            guardian_tokens,
-            guardian_rewards,
-        )
-        .await?;
-    }
+    let (output, new_profile) = Bl3Profile::template(file_name, header_type)?.as_bytes()?;
+
+    let undo = if output_file.exists() {
+        info!("Making a backup of existing file: {}", file_name);
+
+        let current_time = Local::now().format("%d-%m-%Y_%H.%M.%S");
+
+        let backup_name = format!("{}-{}.sav", file_name.replace(".sav", ""), current_time);
+
+        let backup_file = backup_dir.join(sanitize_filename::sanitize(backup_name));
+
+        tokio::fs::copy(&output_file, &backup_file)
+            .await
+            .context("failed to write a backup, the original file was not changed")?;
+
+        Some(UndoSave {
+            target: output_file.clone(),
+            backup: backup_file,
+        })
+    } else {
+        None
+    };
+
+    info!("Creating blank profile: {}", new_profile.file_name);
+
+    tokio_rayon::spawn(move || write_file_atomic(&output_file, &output)).await?;
+
+    Ok((new_profile, undo))
+}
+
+pub async fn undo_save(undo: UndoSave) -> Result<()> {
+    info!(
+        "Restoring {} from backup: {}",
+        undo.target.display(),
+        undo.backup.display()
+    );
+
+    tokio_rayon::spawn(move || restore_backup(&undo.backup, &undo.target)).await
+}
+
+pub async fn saves_requiring_guardian_injection(
+    saves_dir: PathBuf,
+    guardian_injection: GuardianInjection,
+) -> Result<Vec<String>> {
+    let saves =
+        tokio_rayon::spawn(move || saves_requiring_injection(&saves_dir, &guardian_injection))
+            .await?;
 
-    Ok(new_profile)
+    Ok(saves
+        .iter()
+        .map(|s| {
+            format!(
+                "{} ({})",
+                s.character_data.character.preferred_character_name, s.file_name
+            )
+        })
+        .collect())
 }
 
 pub async fn load_files_after_save(
@@ -0,0 +1,37 @@
+use anyhow::{bail, Context, Result};
+use serde::Deserialize;
+
+use crate::update::create_download_client;
+
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize)]
+pub struct ItemVerifyResult {
+    pub valid: bool,
+    #[serde(default)]
+    pub message: Option<String>,
+}
+
+/// Asks the item API whether `serial` is an item that can drop in game.
+///
+/// The API is expected to answer `GET <api_url>?serial=<serial>` with
+/// `{"valid": bool, "message": optional string}`.
+pub async fn verify_item_serial(api_url: &str, serial: &str) -> Result<ItemVerifyResult> {
+    let api_url = api_url.trim();
+
+    if api_url.is_empty() {
+        bail!("no item API URL has been set in the settings");
+    }
+
+    let client = create_download_client()?;
+
+    let res = client
+        .get(api_url)
+        .query(&[("serial", serial)])
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+
+    serde_json::from_str::<ItemVerifyResult>(&res)
+        .context("failed to read the response from the item API")
+}
@@ -0,0 +1,47 @@
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+
+use bl3_save_edit_core::build_preset::BuildPreset;
+
+pub async fn export_build(build: BuildPreset, file_name: String) -> Result<PathBuf> {
+    use native_dialog::FileDialog;
+
+    let output_file = FileDialog::new()
+        .set_filename(&file_name)
+        .add_filter("Build", &["json"])
+        .show_save_single_file()?
+        .context("No file was selected.")?;
+
+    tokio::fs::write(&output_file, build.to_json()).await?;
+
+    Ok(output_file)
+}
+
+pub async fn import_build() -> Result<BuildPreset> {
+    use native_dialog::FileDialog;
+
+    let input_file = FileDialog::new()
+        .add_filter("Build", &["json"])
+        .show_open_single_file()?
+        .context("No file was selected.")?;
+
+    let data = tokio::fs::read_to_string(&input_file).await?;
+
+    BuildPreset::from_json(&data)
+}
+
+pub async fn confirm_import_build(changes: Vec<String>) -> Result<bool> {
+    use native_dialog::{MessageDialog, MessageType};
+
+    let confirmed = MessageDialog::new()
+        .set_type(MessageType::Warning)
+        .set_title("Import Build")
+        .set_text(&format!(
+            "Importing this build will make the following changes:\n\n{}\n\nDo you want to continue?",
+            changes.join("\n")
+        ))
+        .show_confirm()?;
+
+    Ok(confirmed)
+}
@@ -1,6 +1,151 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use chrono::Local;
+use qrcode::render::svg;
+use qrcode::QrCode;
+use serde::Serialize;
+use tracing::info;
 use uuid::Uuid;
 
+use bl3_save_edit_core::bl3_save::inventory_slot::InventorySlot;
+use bl3_save_edit_core::bl3_save::Bl3Save;
+
+use crate::VERSION;
+
+const SHARE_QR_VERSION: u8 = 1;
+
+#[derive(Debug, Serialize)]
+struct ShareQrPayload<'a> {
+    #[serde(rename = "v")]
+    version: u8,
+    #[serde(rename = "n")]
+    name: &'a str,
+    #[serde(rename = "c")]
+    class: String,
+    #[serde(rename = "l")]
+    level: i32,
+    #[serde(rename = "i")]
+    item_serials: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct SnapshotMetadata<'a> {
+    source_file: &'a str,
+    character_name: &'a str,
+    class: String,
+    level: i32,
+    save_game_guid: &'a str,
+    taken_at: String,
+    editor_version: &'a str,
+}
+
 pub fn generate_random_guid() -> String {
     let hex = format!("{:X}", Uuid::new_v4());
     hex.replace("-", "")
 }
+
+pub async fn export_character_report(report: String, file_name: String) -> Result<PathBuf> {
+    use native_dialog::FileDialog;
+
+    let output_file = FileDialog::new()
+        .set_filename(&file_name)
+        .add_filter("Markdown", &["md"])
+        .show_save_single_file()?
+        .context("No file was selected.")?;
+
+    tokio::fs::write(&output_file, report).await?;
+
+    Ok(output_file)
+}
+
+/// Writes a read only copy of the save to the backups folder, along with a JSON file describing
+/// it. Snapshots are named differently to the backups taken when saving so they are never
+/// replaced by one.
+pub async fn take_snapshot(backup_dir: PathBuf, save: Bl3Save, output: Vec<u8>) -> Result<PathBuf> {
+    let now = Local::now();
+
+    let original_name = Path::new(&save.file_name)
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_else(|| save.file_name.clone());
+
+    let snapshot_name = sanitize_filename::sanitize(format!(
+        "snapshot_{}_{}",
+        now.format("%Y%m%d_%H%M%S"),
+        original_name
+    ));
+
+    let snapshot_file = backup_dir.join(format!("{}.sav", snapshot_name));
+    let metadata_file = backup_dir.join(format!("{}.json", snapshot_name));
+
+    info!(
+        "Taking snapshot of {}: {}",
+        save.file_name,
+        snapshot_file.display()
+    );
+
+    let character_data = &save.character_data;
+
+    let metadata = SnapshotMetadata {
+        source_file: &save.file_name,
+        character_name: &character_data.character.preferred_character_name,
+        class: character_data.player_class().to_string(),
+        level: character_data.player_level(),
+        save_game_guid: &character_data.character.save_game_guid,
+        taken_at: now.to_rfc3339(),
+        editor_version: VERSION,
+    };
+
+    let metadata = serde_json::to_string_pretty(&metadata)?;
+
+    tokio::fs::write(&snapshot_file, output)
+        .await
+        .context("failed to write the snapshot")?;
+
+    tokio::fs::write(&metadata_file, metadata)
+        .await
+        .context("failed to write the snapshot metadata")?;
+
+    for file in [&snapshot_file, &metadata_file] {
+        let mut permissions = tokio::fs::metadata(file).await?.permissions();
+        permissions.set_readonly(true);
+        tokio::fs::set_permissions(file, permissions).await?;
+    }
+
+    Ok(snapshot_file)
+}
+
+pub fn generate_share_qr_svg(save: &Bl3Save) -> Result<String> {
+    let character_data = &save.character_data;
+
+    let item_serials = [
+        InventorySlot::Weapon1,
+        InventorySlot::Weapon2,
+        InventorySlot::Weapon3,
+        InventorySlot::Weapon4,
+    ]
+    .iter()
+    .filter_map(|slot| character_data.equipped_inventory_index(slot))
+    .filter_map(|i| character_data.inventory_items().get(i))
+    .filter_map(|item| item.get_serial_number_base64(false).ok())
+    .collect();
+
+    let payload = ShareQrPayload {
+        version: SHARE_QR_VERSION,
+        name: &character_data.character.preferred_character_name,
+        class: character_data.player_class().to_string(),
+        level: character_data.player_level(),
+        item_serials,
+    };
+
+    let payload = serde_json::to_string(&payload)?;
+
+    let svg = QrCode::new(payload.as_bytes())?
+        .render::<svg::Color>()
+        .min_dimensions(300, 300)
+        .quiet_zone(true)
+        .build();
+
+    Ok(svg)
+}
@@ -1,5 +1,13 @@
 use anyhow::Result;
 
+use bl3_save_edit_core::bl3_item::Bl3Item;
+
+use crate::views::item_editor::parts::current_parts::DecodedItemParts;
+
 pub async fn open_website(url: String) -> Result<()> {
     open::that(url).map_err(anyhow::Error::new)
 }
+
+pub async fn decode_item_parts(item: Bl3Item) -> DecodedItemParts {
+    tokio_rayon::spawn(move || DecodedItemParts::new(item)).await
+}
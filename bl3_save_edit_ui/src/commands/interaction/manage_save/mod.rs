@@ -1,2 +1,3 @@
+pub mod character;
 pub mod general;
 pub mod item_editor;
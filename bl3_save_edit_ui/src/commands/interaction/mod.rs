@@ -2,8 +2,10 @@ use std::path::PathBuf;
 
 use anyhow::{Context, Result};
 
+pub mod bulk;
 pub mod choose_save_directory;
 pub mod file_save;
+pub mod item_verify;
 pub mod manage_save;
 pub mod settings;
 
@@ -24,6 +26,39 @@ pub async fn choose_dir(existing_dir: PathBuf) -> Result<PathBuf> {
     Ok(res)
 }
 
+#[cfg(not(target_os = "macos"))]
+pub async fn choose_save_file(dir: PathBuf, file_name: String) -> Result<PathBuf> {
+    use native_dialog::FileDialog;
+
+    let mut file_dialog = FileDialog::new()
+        .set_filename(&file_name)
+        .add_filter("Borderlands 3 Save", &["sav"]);
+
+    if dir.exists() {
+        file_dialog = file_dialog.set_location(&dir);
+    }
+
+    let res = file_dialog
+        .show_save_single_file()?
+        .context("No file was selected.")?;
+
+    Ok(res)
+}
+
+pub async fn export_csv(csv: String, file_name: String) -> Result<PathBuf> {
+    use native_dialog::FileDialog;
+
+    let output_file = FileDialog::new()
+        .set_filename(&file_name)
+        .add_filter("CSV", &["csv"])
+        .show_save_single_file()?
+        .context("No file was selected.")?;
+
+    tokio::fs::write(&output_file, csv).await?;
+
+    Ok(output_file)
+}
+
 #[cfg(target_os = "macos")]
 pub async fn choose_dir(existing_dir: PathBuf) -> Result<PathBuf> {
     use native_dialog::{Dialog, OpenSingleDir};
@@ -44,3 +79,11 @@ pub async fn choose_dir(existing_dir: PathBuf) -> Result<PathBuf> {
 
     Ok(res)
 }
+
+// 0.4 has no save dialog, so the folder is picked and the file keeps the suggested name
+#[cfg(target_os = "macos")]
+pub async fn choose_save_file(dir: PathBuf, file_name: String) -> Result<PathBuf> {
+    let output_dir = choose_dir(dir).await?;
+
+    Ok(output_dir.join(file_name))
+}
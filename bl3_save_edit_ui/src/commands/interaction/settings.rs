@@ -1,6 +1,25 @@
-use std::path::PathBuf;
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
+use std::process::Command;
 
-use anyhow::{bail, Result};
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Local};
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+use tracing::{error, warn};
+
+use bl3_save_edit_core::file_helper::Bl3FileType;
+use bl3_save_edit_core::round_trip::{
+    round_trip_report_text, verify_round_trip, RoundTripOutcome, RoundTripReport,
+};
+
+use crate::commands::initialization;
+use crate::commands::interaction::choose_dir;
+use crate::config::Bl3Config;
+use crate::views::backup_history::BackupEntry;
+
+pub async fn reload_data_overrides(config_dir: PathBuf) -> Result<usize> {
+    initialization::load_data_overrides(&config_dir)
+}
 
 pub async fn open_dir(dir: PathBuf) -> Result<()> {
     if dir.exists() {
@@ -9,3 +28,312 @@ pub async fn open_dir(dir: PathBuf) -> Result<()> {
         bail!("Folder does not exist.")
     }
 }
+
+/// What gets run to show a file selected in the platform's file manager.
+#[derive(Debug, PartialEq)]
+pub struct RevealCommand {
+    pub program: &'static str,
+    pub args: Vec<String>,
+}
+
+impl RevealCommand {
+    fn run(&self) -> Result<()> {
+        let mut command = Command::new(self.program);
+
+        // Explorer wants the path quoted after the comma, which the usual argument quoting breaks
+        #[cfg(target_os = "windows")]
+        {
+            use std::os::windows::process::CommandExt;
+
+            for arg in &self.args {
+                command.raw_arg(arg);
+            }
+        }
+
+        #[cfg(not(target_os = "windows"))]
+        command.args(&self.args);
+
+        let status = command.status()?;
+
+        // Explorer exits with an error code even when it worked
+        if !status.success() && self.program != "explorer" {
+            bail!("{} exited with {}", self.program, status);
+        }
+
+        Ok(())
+    }
+}
+
+fn file_uri(file: &Path) -> String {
+    let path = file.to_string_lossy();
+
+    let mut uri = String::from("file://");
+
+    for b in path.bytes() {
+        match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' | b'/' => {
+                uri.push(b as char)
+            }
+            _ => uri.push_str(&format!("%{:02X}", b)),
+        }
+    }
+
+    uri
+}
+
+/// `os` is one of the values of [`std::env::consts::OS`], anything that isn't Windows or macOS
+/// asks the freedesktop file manager interface.
+pub fn reveal_command(os: &str, file: &Path) -> RevealCommand {
+    match os {
+        "windows" => RevealCommand {
+            program: "explorer",
+            args: vec![format!("/select,\"{}\"", file.display())],
+        },
+        "macos" => RevealCommand {
+            program: "open",
+            args: vec!["-R".to_owned(), file.to_string_lossy().to_string()],
+        },
+        _ => RevealCommand {
+            program: "dbus-send",
+            args: vec![
+                "--session".to_owned(),
+                "--print-reply".to_owned(),
+                "--dest=org.freedesktop.FileManager1".to_owned(),
+                "--type=method_call".to_owned(),
+                "/org/freedesktop/FileManager1".to_owned(),
+                "org.freedesktop.FileManager1.ShowItems".to_owned(),
+                format!("array:string:{}", file_uri(file)),
+                "string:".to_owned(),
+            ],
+        },
+    }
+}
+
+pub async fn reveal_file(file: PathBuf) -> Result<()> {
+    if !file.exists() {
+        bail!("File does not exist.")
+    }
+
+    tokio_rayon::spawn(move || {
+        let command = reveal_command(std::env::consts::OS, &file);
+
+        match command.run() {
+            Ok(_) => Ok(()),
+            // Not every Linux desktop has a file manager that can select a file
+            Err(e) if command.program == "dbus-send" => {
+                warn!(
+                    "Failed to select {} in the file manager, opening its folder instead: {}",
+                    file.display(),
+                    e
+                );
+
+                let dir = file.parent().unwrap_or(&file);
+
+                open::that(dir).map_err(anyhow::Error::new)
+            }
+            Err(e) => Err(e),
+        }
+    })
+    .await
+}
+
+pub async fn migrate_config(config_dir: PathBuf) -> Result<Bl3Config> {
+    let old_config_dir = choose_dir(dirs::config_dir().unwrap_or_default()).await?;
+
+    tokio_rayon::spawn(move || Bl3Config::migrate(&old_config_dir, &config_dir)).await?;
+
+    Bl3Config::load()
+}
+
+pub async fn confirm_reset_to_defaults() -> Result<bool> {
+    use native_dialog::{MessageDialog, MessageType};
+
+    let confirmed = MessageDialog::new()
+        .set_type(MessageType::Warning)
+        .set_title("Reset Settings")
+        .set_text("This will reset all settings to their defaults, including the saves and backups folders. Do you want to continue?")
+        .show_confirm()?;
+
+    Ok(confirmed)
+}
+
+pub async fn load_backups(backup_dir: PathBuf) -> Result<Vec<BackupEntry>> {
+    let mut dirs = tokio::fs::read_dir(&*backup_dir).await?;
+
+    let mut all_data = vec![];
+
+    while let Ok(Some(entry)) = dirs.next_entry().await {
+        let path = entry.path();
+
+        if path.is_dir() || path.extension().and_then(OsStr::to_str) != Some("sav") {
+            continue;
+        }
+
+        let metadata = match entry.metadata().await {
+            Ok(metadata) => metadata,
+            Err(e) => {
+                error!("{}", e);
+                continue;
+            }
+        };
+
+        let modified = match metadata.modified() {
+            Ok(modified) => DateTime::<Local>::from(modified),
+            Err(e) => {
+                error!("{}", e);
+                continue;
+            }
+        };
+
+        match tokio::fs::read(&path).await {
+            Ok(data) => all_data.push((path, data, metadata.len(), modified)),
+            Err(e) => error!("{}", e),
+        }
+    }
+
+    let backups = tokio_rayon::spawn(move || {
+        all_data
+            .into_par_iter()
+            .filter_map(|(path, data, file_size, modified)| {
+                let file_type = match Bl3FileType::from_unknown_data(&path, &data) {
+                    Ok(file_type) => file_type,
+                    Err(e) => {
+                        error!("Failed to read backup {}: {}", path.display(), e);
+                        return None;
+                    }
+                };
+
+                let (character_name, level, money) = match file_type {
+                    Bl3FileType::PcSave(save) | Bl3FileType::Ps4Save(save) => (
+                        save.character_data
+                            .character
+                            .preferred_character_name
+                            .clone(),
+                        Some(save.character_data.player_level()),
+                        Some(save.character_data.money()),
+                    ),
+                    Bl3FileType::PcProfile(_) | Bl3FileType::Ps4Profile(_) => {
+                        ("Profile".to_owned(), None, None)
+                    }
+                };
+
+                let file_name = path
+                    .file_name()
+                    .map(|f| f.to_string_lossy().to_string())
+                    .unwrap_or_default();
+
+                let is_snapshot = file_name.starts_with("snapshot_");
+
+                Some(BackupEntry {
+                    path,
+                    file_name,
+                    character_name,
+                    level,
+                    money,
+                    file_size,
+                    modified,
+                    is_snapshot,
+                })
+            })
+            .collect::<Vec<_>>()
+    })
+    .await;
+
+    Ok(backups)
+}
+
+pub async fn verify_files(files: Vec<PathBuf>) -> Result<Vec<RoundTripReport>> {
+    let mut all_data = Vec::with_capacity(files.len());
+
+    for file in files {
+        let data = tokio::fs::read(&file).await;
+        all_data.push((file, data));
+    }
+
+    let reports = tokio_rayon::spawn(move || {
+        all_data
+            .into_par_iter()
+            .map(|(path, data)| match data {
+                Ok(data) => verify_round_trip(&path, &data),
+                Err(e) => RoundTripReport {
+                    file_name: path
+                        .file_name()
+                        .map(|f| f.to_string_lossy().to_string())
+                        .unwrap_or_else(|| path.display().to_string()),
+                    outcome: RoundTripOutcome::Failed(e.to_string()),
+                },
+            })
+            .collect::<Vec<_>>()
+    })
+    .await;
+
+    Ok(reports)
+}
+
+pub async fn export_round_trip_report(reports: Vec<RoundTripReport>) -> Result<PathBuf> {
+    use native_dialog::FileDialog;
+
+    let output_file = FileDialog::new()
+        .set_filename("round_trip_report.txt")
+        .add_filter("Text", &["txt"])
+        .show_save_single_file()?
+        .context("No file was selected.")?;
+
+    tokio::fs::write(&output_file, round_trip_report_text(&reports)).await?;
+
+    Ok(output_file)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reveal_command_windows() {
+        let command = reveal_command("windows", Path::new(r"C:\Saves\1.sav"));
+
+        assert_eq!(
+            command,
+            RevealCommand {
+                program: "explorer",
+                args: vec![r#"/select,"C:\Saves\1.sav""#.to_owned()],
+            }
+        );
+    }
+
+    #[test]
+    fn test_reveal_command_macos() {
+        let command = reveal_command("macos", Path::new("/Users/me/Saves/1.sav"));
+
+        assert_eq!(
+            command,
+            RevealCommand {
+                program: "open",
+                args: vec!["-R".to_owned(), "/Users/me/Saves/1.sav".to_owned()],
+            }
+        );
+    }
+
+    #[test]
+    fn test_reveal_command_linux() {
+        let command = reveal_command("linux", Path::new("/home/me/My Saves/1.sav"));
+
+        assert_eq!(command.program, "dbus-send");
+        assert!(command
+            .args
+            .contains(&"org.freedesktop.FileManager1.ShowItems".to_owned()));
+        assert!(command
+            .args
+            .contains(&"array:string:file:///home/me/My%20Saves/1.sav".to_owned()));
+    }
+
+    #[test]
+    fn test_reveal_command_other_os_uses_linux() {
+        let file = Path::new("/home/me/1.sav");
+
+        assert_eq!(
+            reveal_command("freebsd", file),
+            reveal_command("linux", file)
+        );
+    }
+}
@@ -1,36 +1,91 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::str;
 
-use anyhow::Result;
+use anyhow::{bail, Result};
 use serde::{Deserialize, Serialize};
-use tokio::io::AsyncWriteExt;
 use tracing::info;
 
+use bl3_save_edit_core::content_source::ContentSource;
+use bl3_save_edit_core::file_helper::write_file_atomic;
+
 use crate::bl3_ui::MessageResult;
+use crate::item_sources::ITEM_SOURCES_DIR;
+use crate::save_notes::SAVE_NOTES_DIR;
+use crate::views::item_editor::{InventoryColumns, InventoryLayout};
 
 const CONFIG_DIR: &str = "bl3_save_editor";
 const BACKUP_DIR: &str = "backups";
 const CONFIG_NAME: &str = "config.toml";
+pub const MAX_RECENT_SAVE_DIRS: usize = 5;
 
 #[derive(Debug, Clone)]
 pub enum ConfigMessage {
     SaveCompleted(MessageResult<()>),
 }
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Bl3Config {
     config_dir: PathBuf,
     #[serde(default = "default_backup_dir")]
     backup_dir: PathBuf,
     saves_dir: PathBuf,
+    #[serde(default)]
+    recent_save_dirs: Vec<PathBuf>,
     #[serde(default = "default_scale_factor")]
     ui_scale_factor: f64,
+    #[serde(default = "ContentSource::all_dlc")]
+    owned_dlc: Vec<ContentSource>,
+    #[serde(default)]
+    inventory_layout: InventoryLayout,
+    #[serde(default)]
+    inventory_columns: InventoryColumns,
+    #[serde(default)]
+    favorite_anointments: Vec<String>,
+    // Item serials that are pinned to the inventory
+    #[serde(default)]
+    favorite_items: Vec<String>,
+    #[serde(default = "default_first_run_complete")]
+    first_run_complete: bool,
+    #[serde(default)]
+    last_selected_file: Option<String>,
+    #[serde(default)]
+    item_verification_enabled: bool,
+    #[serde(default)]
+    item_api_url: String,
+    #[serde(default = "default_sanitize_negative_values")]
+    sanitize_negative_values: bool,
+}
+
+fn copy_dir_all(from: &Path, to: &Path) -> Result<()> {
+    std::fs::create_dir_all(to)?;
+
+    for entry in std::fs::read_dir(from)? {
+        let entry = entry?;
+        let path = entry.path();
+
+        if path.is_dir() {
+            copy_dir_all(&path, &to.join(entry.file_name()))?;
+        } else {
+            std::fs::copy(&path, to.join(entry.file_name()))?;
+        }
+    }
+
+    Ok(())
 }
 
 fn default_scale_factor() -> f64 {
     1.0
 }
 
+// Configs saved before the setup wizard existed belong to users that have already set things up
+fn default_first_run_complete() -> bool {
+    true
+}
+
+fn default_sanitize_negative_values() -> bool {
+    true
+}
+
 fn default_backup_dir() -> PathBuf {
     let backup_dir = dirs::config_dir()
         .unwrap_or_default()
@@ -44,13 +99,40 @@ fn default_backup_dir() -> PathBuf {
     }
 }
 
+impl std::default::Default for Bl3Config {
+    fn default() -> Self {
+        let config_dir = dirs::config_dir().unwrap_or_default().join(CONFIG_DIR);
+        let backup_dir = config_dir.join(BACKUP_DIR);
+
+        Self {
+            config_dir,
+            backup_dir,
+            saves_dir: Default::default(),
+            recent_save_dirs: Vec::new(),
+            ui_scale_factor: default_scale_factor(),
+            owned_dlc: ContentSource::all_dlc(),
+            inventory_layout: InventoryLayout::default(),
+            inventory_columns: InventoryColumns::default(),
+            favorite_anointments: Vec::new(),
+            favorite_items: Vec::new(),
+            first_run_complete: false,
+            last_selected_file: None,
+            item_verification_enabled: false,
+            item_api_url: String::new(),
+            sanitize_negative_values: default_sanitize_negative_values(),
+        }
+    }
+}
+
 impl Bl3Config {
     pub fn load() -> Result<Self> {
         let config_dir = dirs::config_dir().unwrap_or_default().join(CONFIG_DIR);
-        let backup_dir = dirs::config_dir()
-            .unwrap_or_default()
-            .join(CONFIG_DIR)
-            .join(BACKUP_DIR);
+
+        if !config_dir.join(CONFIG_NAME).exists() {
+            info!("No config found, creating default config");
+
+            return Ok(Self::default());
+        }
 
         if let Ok(mut config) = toml::from_str::<Bl3Config>(str::from_utf8(&std::fs::read(
             config_dir.join(CONFIG_NAME),
@@ -64,13 +146,59 @@ impl Bl3Config {
         } else {
             info!("Creating default config");
 
-            Ok(Self {
-                config_dir,
-                backup_dir,
-                saves_dir: Default::default(),
-                ui_scale_factor: default_scale_factor(),
-            })
+            Ok(Self::default())
+        }
+    }
+
+    /// Copies the config in `old_path` to `new_path`. Folders that lived inside the old config
+    /// folder (backups, save notes) are copied along and the config is pointed at the new copies.
+    pub fn migrate(old_path: &Path, new_path: &Path) -> Result<()> {
+        let old_config_file = old_path.join(CONFIG_NAME);
+
+        if !old_config_file.is_file() {
+            bail!("No config was found in {}.", old_path.display());
+        }
+
+        if old_path == new_path {
+            bail!("The config is already in {}.", new_path.display());
         }
+
+        info!(
+            "Migrating config from {} to {}",
+            old_path.display(),
+            new_path.display()
+        );
+
+        let mut config =
+            toml::from_str::<Bl3Config>(str::from_utf8(&std::fs::read(old_config_file)?)?)?;
+
+        std::fs::create_dir_all(new_path)?;
+
+        for dir in [&mut config.backup_dir, &mut config.saves_dir] {
+            if let Ok(relative_dir) = dir.strip_prefix(old_path) {
+                let new_dir = new_path.join(relative_dir);
+
+                if dir.is_dir() {
+                    copy_dir_all(dir, &new_dir)?;
+                }
+
+                *dir = new_dir;
+            }
+        }
+
+        for sidecar_dir in [SAVE_NOTES_DIR, ITEM_SOURCES_DIR] {
+            let old_sidecar_dir = old_path.join(sidecar_dir);
+
+            if old_sidecar_dir.is_dir() {
+                copy_dir_all(&old_sidecar_dir, &new_path.join(sidecar_dir))?;
+            }
+        }
+
+        config.config_dir = new_path.to_path_buf();
+
+        std::fs::write(new_path.join(CONFIG_NAME), toml::to_string(&config)?)?;
+
+        Ok(())
     }
 
     pub async fn save(self) -> Result<()> {
@@ -84,16 +212,10 @@ impl Bl3Config {
 
         let output = toml::to_string(&self)?;
 
-        let mut config_file = tokio::fs::OpenOptions::new()
-            .write(true)
-            .create(true)
-            .truncate(true)
-            .open(config_dir.join(CONFIG_NAME))
-            .await?;
-
-        config_file.write_all(output.as_bytes()).await?;
-
-        Ok(())
+        tokio_rayon::spawn(move || {
+            write_file_atomic(&config_dir.join(CONFIG_NAME), output.as_bytes())
+        })
+        .await
     }
 
     pub fn config_dir(&self) -> &PathBuf {
@@ -116,6 +238,18 @@ impl Bl3Config {
         self.saves_dir = dir;
     }
 
+    pub fn recent_save_dirs(&self) -> &Vec<PathBuf> {
+        &self.recent_save_dirs
+    }
+
+    /// Moves the folder to the top of the recent folders, dropping the oldest when there are too
+    /// many.
+    pub fn add_recent_save_dir(&mut self, dir: PathBuf) {
+        self.recent_save_dirs.retain(|d| *d != dir);
+        self.recent_save_dirs.insert(0, dir);
+        self.recent_save_dirs.truncate(MAX_RECENT_SAVE_DIRS);
+    }
+
     pub fn ui_scale_factor(&self) -> f64 {
         self.ui_scale_factor
     }
@@ -123,4 +257,153 @@ impl Bl3Config {
     pub fn set_ui_scale_factor(&mut self, ui_scale_factor: f64) {
         self.ui_scale_factor = ui_scale_factor;
     }
+
+    pub fn owned_dlc(&self) -> &Vec<ContentSource> {
+        &self.owned_dlc
+    }
+
+    pub fn set_owned_dlc(&mut self, owned_dlc: Vec<ContentSource>) {
+        self.owned_dlc = owned_dlc;
+    }
+
+    pub fn inventory_layout(&self) -> InventoryLayout {
+        self.inventory_layout
+    }
+
+    pub fn set_inventory_layout(&mut self, inventory_layout: InventoryLayout) {
+        self.inventory_layout = inventory_layout;
+    }
+
+    pub fn inventory_columns(&self) -> InventoryColumns {
+        self.inventory_columns
+    }
+
+    pub fn set_inventory_columns(&mut self, inventory_columns: InventoryColumns) {
+        self.inventory_columns = inventory_columns;
+    }
+
+    pub fn favorite_anointments(&self) -> &Vec<String> {
+        &self.favorite_anointments
+    }
+
+    pub fn set_favorite_anointments(&mut self, favorite_anointments: Vec<String>) {
+        self.favorite_anointments = favorite_anointments;
+    }
+
+    pub fn favorite_items(&self) -> &Vec<String> {
+        &self.favorite_items
+    }
+
+    pub fn set_favorite_items(&mut self, favorite_items: Vec<String>) {
+        self.favorite_items = favorite_items;
+    }
+
+    pub fn first_run_complete(&self) -> bool {
+        self.first_run_complete
+    }
+
+    pub fn set_first_run_complete(&mut self, first_run_complete: bool) {
+        self.first_run_complete = first_run_complete;
+    }
+
+    pub fn last_selected_file(&self) -> Option<&str> {
+        self.last_selected_file.as_deref()
+    }
+
+    pub fn set_last_selected_file(&mut self, last_selected_file: Option<String>) {
+        self.last_selected_file = last_selected_file;
+    }
+
+    pub fn item_verification_enabled(&self) -> bool {
+        self.item_verification_enabled
+    }
+
+    pub fn set_item_verification_enabled(&mut self, item_verification_enabled: bool) {
+        self.item_verification_enabled = item_verification_enabled;
+    }
+
+    pub fn item_api_url(&self) -> &str {
+        &self.item_api_url
+    }
+
+    pub fn set_item_api_url(&mut self, item_api_url: String) {
+        self.item_api_url = item_api_url;
+    }
+
+    pub fn sanitize_negative_values(&self) -> bool {
+        self.sanitize_negative_values
+    }
+
+    pub fn set_sanitize_negative_values(&mut self, sanitize_negative_values: bool) {
+        self.sanitize_negative_values = sanitize_negative_values;
+    }
+
+    /// The URL items are verified against, only set when item verification has been turned on.
+    pub fn item_verification_api_url(&self) -> Option<String> {
+        if self.item_verification_enabled {
+            Some(self.item_api_url.clone())
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_recent_save_dir() {
+        let mut config = Bl3Config::default();
+
+        for i in 0..MAX_RECENT_SAVE_DIRS + 1 {
+            config.add_recent_save_dir(PathBuf::from(format!("saves_{}", i)));
+        }
+
+        assert_eq!(config.recent_save_dirs().len(), MAX_RECENT_SAVE_DIRS);
+        assert_eq!(config.recent_save_dirs()[0], PathBuf::from("saves_5"));
+        assert!(!config
+            .recent_save_dirs()
+            .contains(&PathBuf::from("saves_0")));
+
+        config.add_recent_save_dir(PathBuf::from("saves_3"));
+
+        assert_eq!(config.recent_save_dirs().len(), MAX_RECENT_SAVE_DIRS);
+        assert_eq!(config.recent_save_dirs()[0], PathBuf::from("saves_3"));
+        assert_eq!(config.recent_save_dirs()[1], PathBuf::from("saves_5"));
+    }
+
+    #[test]
+    fn test_favorite_anointments_round_trip() {
+        let favorite_anointments = vec![
+            "/Game/Gear/Weapons/_Shared/_Design/EndGameParts/Part_WeaponAnointment_SkillEnd_Damage.Part_WeaponAnointment_SkillEnd_Damage".to_owned(),
+            "/Game/Gear/_Shared/_Design/EndGameParts/Part_Anointment_Missing.Part_Anointment_Missing".to_owned(),
+        ];
+
+        let mut config = Bl3Config::default();
+        config.set_favorite_anointments(favorite_anointments.clone());
+
+        // The same conversion save and load use
+        let output = toml::to_string(&config).expect("failed to write config");
+        let loaded = toml::from_str::<Bl3Config>(&output).expect("failed to read config");
+
+        assert_eq!(loaded.favorite_anointments(), &favorite_anointments);
+    }
+
+    #[test]
+    fn test_favorite_anointments_missing_from_config() {
+        let mut config =
+            toml::Value::try_from(Bl3Config::default()).expect("failed to write config");
+
+        config
+            .as_table_mut()
+            .expect("config should be a table")
+            .remove("favorite_anointments");
+
+        let loaded = config
+            .try_into::<Bl3Config>()
+            .expect("failed to read config without favorite anointments");
+
+        assert!(loaded.favorite_anointments().is_empty());
+    }
 }
@@ -0,0 +1,19 @@
+use std::path::{Path, PathBuf};
+
+use bl3_save_edit_core::bl3_profile::Bl3Profile;
+
+pub const ITEM_SOURCES_DIR: &str = "item_sources";
+
+/// Where the sources of a profile's bank items are kept, next to the config rather than the
+/// profile. Profiles in nested folders share a file name so the folder is part of the name.
+pub fn item_sources_path(config_dir: &Path, profile: &Bl3Profile) -> PathBuf {
+    let name = profile
+        .relative_dir
+        .join(&profile.file_name)
+        .to_string_lossy()
+        .replace(['/', '\\', ':'], "_");
+
+    config_dir
+        .join(ITEM_SOURCES_DIR)
+        .join(format!("{}.json", name))
+}
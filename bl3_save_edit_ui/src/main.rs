@@ -2,6 +2,7 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
 use std::env;
+use std::path::PathBuf;
 
 use anyhow::{bail, Context, Result};
 use iced::window::icon::Icon;
@@ -9,7 +10,7 @@ use iced::{window, Application, Settings};
 use image::ImageFormat;
 use tracing::{error, info};
 
-use crate::bl3_ui::Bl3Application;
+use crate::bl3_ui::{Bl3Application, Bl3Flags};
 use crate::config::Bl3Config;
 use crate::update::remove_file;
 
@@ -17,7 +18,10 @@ mod bl3_ui;
 mod bl3_ui_style;
 mod commands;
 mod config;
+mod item_sources;
+mod recovery;
 mod resources;
+mod save_notes;
 mod state_mappers;
 mod update;
 mod util;
@@ -65,6 +69,15 @@ fn main() -> Result<()> {
         }
     }
 
+    let startup_file = match pargs.opt_free_from_str::<PathBuf>() {
+        Ok(f) => f,
+        Err(e) => {
+            error!("Failed to read file to open from arguments: {}", e);
+
+            None
+        }
+    };
+
     let window_icon = match image::load_from_memory_with_format(WINDOW_ICON, ImageFormat::Ico)
         .map(|i| (i.to_rgba8().into_raw(), i.width(), i.height()))
         .map_err(anyhow::Error::new)
@@ -81,7 +94,10 @@ fn main() -> Result<()> {
     };
 
     let settings = Settings {
-        flags: config,
+        flags: Bl3Flags {
+            config,
+            startup_file,
+        },
         window: window::Settings {
             min_size: Some((1320, 750)),
             size: (1650, 800),
@@ -0,0 +1,210 @@
+use std::collections::hash_map::DefaultHasher;
+use std::ffi::OsStr;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use anyhow::Result;
+use tracing::{info, warn};
+
+use bl3_save_edit_core::file_helper::write_file_atomic;
+
+pub const RECOVERY_DIR: &str = "recovery";
+
+/// How often unsaved edits are written to a recovery file.
+pub const RECOVERY_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Edits made since the last recovery file was written before one is written straight away.
+pub const RECOVERY_EDIT_THRESHOLD: usize = 25;
+
+/// Delay before writing a recovery file once enough edits were made.
+pub const RECOVERY_EDIT_DELAY: Duration = Duration::from_secs(2);
+
+/// The recovery file for `file_path`, the hash keeps files with the same name in different
+/// folders apart.
+pub fn recovery_path(config_dir: &Path, file_path: &Path) -> PathBuf {
+    let file_name = file_path
+        .file_name()
+        .and_then(OsStr::to_str)
+        .unwrap_or_default();
+
+    let mut hasher = DefaultHasher::new();
+    file_path.hash(&mut hasher);
+
+    config_dir.join(RECOVERY_DIR).join(format!(
+        "{}-{:016x}.recovery",
+        sanitize_filename::sanitize(file_name),
+        hasher.finish()
+    ))
+}
+
+/// A recovery file is stale once the file it was made for has been written since.
+fn is_stale(recovery_modified: SystemTime, file_modified: Option<SystemTime>) -> bool {
+    file_modified.is_some_and(|m| m >= recovery_modified)
+}
+
+pub fn write(config_dir: &Path, file_path: &Path, data: &[u8]) -> Result<()> {
+    let recovery_file = recovery_path(config_dir, file_path);
+
+    if let Some(recovery_dir) = recovery_file.parent() {
+        std::fs::create_dir_all(recovery_dir)?;
+    }
+
+    write_file_atomic(&recovery_file, data)
+}
+
+/// Removes the recovery file for `file_path`, it is fine if there isn't one.
+pub fn remove(config_dir: &Path, file_path: &Path) -> Result<()> {
+    let recovery_file = recovery_path(config_dir, file_path);
+
+    match std::fs::remove_file(&recovery_file) {
+        Ok(_) => {
+            info!("Removed recovery file: {}", recovery_file.display());
+
+            Ok(())
+        }
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Reads the recovery file for `file_path` if there is one that is newer than the file itself,
+/// stale recovery files are removed.
+pub fn load(config_dir: &Path, file_path: &Path) -> Result<Option<Vec<u8>>> {
+    let recovery_file = recovery_path(config_dir, file_path);
+
+    let recovery_modified = match std::fs::metadata(&recovery_file) {
+        Ok(metadata) => metadata.modified()?,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e.into()),
+    };
+
+    let file_modified = std::fs::metadata(file_path).and_then(|m| m.modified()).ok();
+
+    if is_stale(recovery_modified, file_modified) {
+        warn!(
+            "Removing stale recovery file as {} was saved after it: {}",
+            file_path.display(),
+            recovery_file.display()
+        );
+
+        remove(config_dir, file_path)?;
+
+        return Ok(None);
+    }
+
+    Ok(Some(std::fs::read(recovery_file)?))
+}
+
+pub async fn save(config_dir: PathBuf, file_path: PathBuf, data: Vec<u8>) -> Result<()> {
+    info!("Writing recovery file for: {}", file_path.display());
+
+    tokio_rayon::spawn(move || write(&config_dir, &file_path, &data)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+
+    use super::*;
+
+    fn temp_dir() -> PathBuf {
+        let dir = std::env::temp_dir().join(uuid::Uuid::new_v4().to_string());
+
+        std::fs::create_dir_all(&dir).expect("failed to create temp dir");
+
+        dir
+    }
+
+    fn set_modified(path: &Path, modified: SystemTime) {
+        File::options()
+            .write(true)
+            .open(path)
+            .and_then(|f| f.set_modified(modified))
+            .expect("failed to set modified time");
+    }
+
+    #[test]
+    fn test_recovery_path_is_unique_per_folder() {
+        let config_dir = Path::new("config");
+
+        let first = recovery_path(config_dir, Path::new("first/1.sav"));
+        let second = recovery_path(config_dir, Path::new("second/1.sav"));
+
+        assert_ne!(first, second);
+        assert_eq!(
+            first.parent(),
+            Some(config_dir.join(RECOVERY_DIR).as_path())
+        );
+        assert!(first
+            .file_name()
+            .and_then(OsStr::to_str)
+            .unwrap()
+            .starts_with("1.sav-"));
+    }
+
+    #[test]
+    fn test_is_stale() {
+        let recovery_modified = SystemTime::UNIX_EPOCH + Duration::from_secs(100);
+
+        assert!(!is_stale(recovery_modified, None));
+        assert!(!is_stale(
+            recovery_modified,
+            Some(recovery_modified - Duration::from_secs(1))
+        ));
+        assert!(is_stale(recovery_modified, Some(recovery_modified)));
+        assert!(is_stale(
+            recovery_modified,
+            Some(recovery_modified + Duration::from_secs(1))
+        ));
+    }
+
+    #[test]
+    fn test_write_then_load() {
+        let dir = temp_dir();
+        let file_path = dir.join("1.sav");
+
+        std::fs::write(&file_path, b"original").unwrap();
+        set_modified(&file_path, SystemTime::now() - Duration::from_secs(60));
+
+        write(&dir, &file_path, b"edited").unwrap();
+
+        assert_eq!(load(&dir, &file_path).unwrap(), Some(b"edited".to_vec()));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_load_removes_stale_recovery_file() {
+        let dir = temp_dir();
+        let file_path = dir.join("1.sav");
+
+        std::fs::write(&file_path, b"original").unwrap();
+
+        write(&dir, &file_path, b"edited").unwrap();
+
+        let recovery_file = recovery_path(&dir, &file_path);
+        set_modified(&recovery_file, SystemTime::now() - Duration::from_secs(60));
+
+        assert_eq!(load(&dir, &file_path).unwrap(), None);
+        assert!(!recovery_file.exists());
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_remove() {
+        let dir = temp_dir();
+        let file_path = dir.join("1.sav");
+
+        remove(&dir, &file_path).unwrap();
+
+        write(&dir, &file_path, b"edited").unwrap();
+        remove(&dir, &file_path).unwrap();
+
+        assert!(!recovery_path(&dir, &file_path).exists());
+        assert_eq!(load(&dir, &file_path).unwrap(), None);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -12,6 +12,8 @@ pub const CHARACTER: &[u8] = include_bytes!("../../resources/svg/character.svg")
 pub const INVENTORY: &[u8] = include_bytes!("../../resources/svg/inventory.svg");
 pub const CURRENCY: &[u8] = include_bytes!("../../resources/svg/currency.svg");
 pub const VEHICLE: &[u8] = include_bytes!("../../resources/svg/vehicle.svg");
+pub const MISSIONS: &[u8] = include_bytes!("../../resources/svg/missions.svg");
+pub const CHALLENGES: &[u8] = include_bytes!("../../resources/svg/challenges.svg");
 pub const FAVORITE: &[u8] = include_bytes!("../../resources/svg/favorite.svg");
 pub const JUNK: &[u8] = include_bytes!("../../resources/svg/junk.svg");
 
@@ -20,7 +22,22 @@ pub const PROFILE: &[u8] = include_bytes!("../../resources/svg/profile.svg");
 pub const KEYS: &[u8] = include_bytes!("../../resources/svg/keys.svg");
 pub const BANK: &[u8] = include_bytes!("../../resources/svg/bank.svg");
 
+// Manufacturers
+pub const ANSHIN: &[u8] = include_bytes!("../../resources/svg/manufacturers/anshin.svg");
+pub const ATLAS: &[u8] = include_bytes!("../../resources/svg/manufacturers/atlas.svg");
+pub const COV: &[u8] = include_bytes!("../../resources/svg/manufacturers/cov.svg");
+pub const DAHL: &[u8] = include_bytes!("../../resources/svg/manufacturers/dahl.svg");
+pub const ERIDIAN: &[u8] = include_bytes!("../../resources/svg/manufacturers/eridian.svg");
+pub const HYPERION: &[u8] = include_bytes!("../../resources/svg/manufacturers/hyperion.svg");
+pub const JAKOBS: &[u8] = include_bytes!("../../resources/svg/manufacturers/jakobs.svg");
+pub const MALIWAN: &[u8] = include_bytes!("../../resources/svg/manufacturers/maliwan.svg");
+pub const PANGOLIN: &[u8] = include_bytes!("../../resources/svg/manufacturers/pangolin.svg");
+pub const TEDIORE: &[u8] = include_bytes!("../../resources/svg/manufacturers/tediore.svg");
+pub const TORGUE: &[u8] = include_bytes!("../../resources/svg/manufacturers/torgue.svg");
+pub const VLADOF: &[u8] = include_bytes!("../../resources/svg/manufacturers/vladof.svg");
+
 // Notification
 pub const POSITIVE_CLOSE: &[u8] = include_bytes!("../../resources/svg/positive_close.svg");
 pub const INFO_CLOSE: &[u8] = include_bytes!("../../resources/svg/info_close.svg");
 pub const NEGATIVE_CLOSE: &[u8] = include_bytes!("../../resources/svg/negative_close.svg");
+pub const WARNING_CLOSE: &[u8] = include_bytes!("../../resources/svg/warning_close.svg");
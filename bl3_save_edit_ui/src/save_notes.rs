@@ -0,0 +1,59 @@
+use std::path::{Path, PathBuf};
+use std::str;
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use tokio::io::AsyncWriteExt;
+use tracing::info;
+
+pub const SAVE_NOTES_DIR: &str = "save_notes";
+
+/// Extra information about a save that is stored next to the config, never inside the save itself.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SaveNotes {
+    #[serde(default)]
+    pub legacy_badass_rank: Option<u32>,
+}
+
+fn save_notes_path(config_dir: &Path, save_game_guid: &str) -> PathBuf {
+    config_dir
+        .join(SAVE_NOTES_DIR)
+        .join(format!("{}.toml", save_game_guid))
+}
+
+impl SaveNotes {
+    pub fn load(config_dir: &Path, save_game_guid: &str) -> Result<Self> {
+        let notes_file = save_notes_path(config_dir, save_game_guid);
+
+        if !notes_file.exists() {
+            return Ok(Self::default());
+        }
+
+        let notes = toml::from_str::<SaveNotes>(str::from_utf8(&std::fs::read(notes_file)?)?)?;
+
+        Ok(notes)
+    }
+
+    pub async fn save(self, config_dir: PathBuf, save_game_guid: String) -> Result<()> {
+        info!("Saving notes for save: {}", save_game_guid);
+
+        let notes_dir = config_dir.join(SAVE_NOTES_DIR);
+
+        if !notes_dir.exists() {
+            tokio::fs::create_dir_all(&notes_dir).await?;
+        }
+
+        let output = toml::to_string(&self)?;
+
+        let mut notes_file = tokio::fs::OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(save_notes_path(&config_dir, &save_game_guid))
+            .await?;
+
+        notes_file.write_all(output.as_bytes()).await?;
+
+        Ok(())
+    }
+}
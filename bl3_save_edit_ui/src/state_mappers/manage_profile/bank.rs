@@ -10,6 +10,10 @@ use crate::views::manage_profile::ManageProfileState;
 
 pub fn map_profile_to_bank_state(manage_profile_state: &mut ManageProfileState) -> Result<()> {
     let profile = &mut manage_profile_state.current_file;
+    let item_sources = &manage_profile_state
+        .profile_view_state
+        .bank_state
+        .item_sources;
 
     manage_profile_state
         .profile_view_state
@@ -23,7 +27,14 @@ pub fn map_profile_to_bank_state(manage_profile_state: &mut ManageProfileState)
         .iter()
         .cloned()
         .enumerate()
-        .map(|(i, item)| ItemEditorListItem::new(i, item))
+        .map(|(i, item)| {
+            let source = item_sources.source_of(&item).map(|s| s.to_owned());
+
+            let mut list_item = ItemEditorListItem::new(i, item);
+            list_item.summary.source = source;
+
+            list_item
+        })
         .collect::<Vec<_>>();
 
     bank_items.par_sort_by(|a, b| {
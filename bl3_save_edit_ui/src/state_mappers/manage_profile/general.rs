@@ -14,6 +14,29 @@ pub fn map_profile_to_general_state(manage_profile_state: &mut ManageProfileStat
         .profile_view_state
         .general_state
         .profile_type_selected = profile.header_type;
+
+    let general_state = &mut manage_profile_state.profile_view_state.general_state;
+
+    general_state.game_store = profile.game_store;
+
+    general_state.file_metadata = profile.metadata();
+
+    general_state.guardian_experience_input = profile.profile_data.guardian_experience();
+
+    general_state.new_guardian_experience_input = profile
+        .profile_data
+        .new_guardian_experience()
+        .min(i32::MAX as i64) as i32;
+
+    general_state.mail_count = profile.profile_data.mail_items().len();
+
+    general_state.read_mail_count = profile.profile_data.read_mail_count();
+
+    general_state.clear_read_mail = false;
+
+    general_state.clear_all_mail = false;
+
+    general_state.shift_entitlements = profile.profile_data.shift_entitlements();
 }
 
 pub fn map_general_state_to_profile(
@@ -30,4 +53,25 @@ pub fn map_general_state_to_profile(
         .profile_view_state
         .general_state
         .profile_type_selected;
+
+    let general_state = &manage_profile_state.profile_view_state.general_state;
+
+    profile.profile_data.set_guardian_experience(
+        general_state.guardian_experience_input,
+        general_state.new_guardian_experience_input as i64,
+    );
+
+    if general_state.clear_all_mail {
+        profile.profile_data.clear_mail(false);
+    } else if general_state.clear_read_mail {
+        profile.profile_data.clear_mail(true);
+    }
+
+    for entitlement in &general_state.shift_entitlements {
+        profile.profile_data.set_shift_entitlement_consumed(
+            &entitlement.source,
+            entitlement.id,
+            entitlement.consumed,
+        );
+    }
 }
@@ -1,52 +1,40 @@
 use anyhow::Result;
 
-use bl3_save_edit_core::bl3_profile::profile_currency::ProfileCurrency;
+use bl3_save_edit_core::bl3_profile::profile_currency::VAULT_CARD_IDS;
 use bl3_save_edit_core::bl3_profile::Bl3Profile;
 
+use crate::views::manage_profile::keys::{CurrencyField, VaultCardChestsField};
 use crate::views::manage_profile::ManageProfileState;
 
 pub fn map_profile_to_keys_state(manage_profile_state: &mut ManageProfileState) {
-    let profile = &manage_profile_state.current_file;
-
-    manage_profile_state
-        .profile_view_state
-        .keys_state
-        .golden_keys_input = profile.profile_data.golden_keys();
-
-    manage_profile_state
-        .profile_view_state
-        .keys_state
-        .diamond_keys_input = profile.profile_data.diamond_keys();
-
-    manage_profile_state
-        .profile_view_state
-        .keys_state
-        .vault_card_1_keys_input = profile.profile_data.vault_card_1_keys();
-
-    manage_profile_state
-        .profile_view_state
-        .keys_state
-        .vault_card_1_chests_input = profile.profile_data.vault_card_1_chests();
-
-    manage_profile_state
-        .profile_view_state
-        .keys_state
-        .vault_card_2_keys_input = profile.profile_data.vault_card_2_keys();
-
-    manage_profile_state
-        .profile_view_state
-        .keys_state
-        .vault_card_2_chests_input = profile.profile_data.vault_card_2_chests();
-
-    manage_profile_state
-        .profile_view_state
-        .keys_state
-        .vault_card_3_keys_input = profile.profile_data.vault_card_3_keys();
-
-    manage_profile_state
-        .profile_view_state
-        .keys_state
-        .vault_card_3_chests_input = profile.profile_data.vault_card_3_chests();
+    let profile_data = &manage_profile_state.current_file.profile_data;
+
+    let keys_state = &mut manage_profile_state.profile_view_state.keys_state;
+
+    keys_state.currencies = profile_data
+        .currencies()
+        .iter()
+        .map(|c| CurrencyField {
+            hash: c.hash,
+            name: c.name(),
+            path: c.path(),
+            max: c.max(),
+            input: c.current,
+            ..Default::default()
+        })
+        .collect();
+
+    keys_state.vault_card_chests = VAULT_CARD_IDS
+        .iter()
+        .map(|id| VaultCardChestsField {
+            vault_card_id: *id,
+            input: profile_data.vault_card_chests(*id),
+            ..Default::default()
+        })
+        .collect();
+
+    keys_state.vault_card_challenge_seeds = profile_data.vault_card_challenge_seeds();
+    keys_state.reroll_vault_card_challenges = false;
 }
 
 pub fn map_keys_state_to_profile(
@@ -55,40 +43,17 @@ pub fn map_keys_state_to_profile(
 ) -> Result<()> {
     let keys_state = &manage_profile_state.profile_view_state.keys_state;
 
-    profile
-        .profile_data
-        .set_currency(&ProfileCurrency::GoldenKey, keys_state.golden_keys_input)?;
-
-    profile
-        .profile_data
-        .set_currency(&ProfileCurrency::DiamondKey, keys_state.diamond_keys_input)?;
-
-    profile.profile_data.set_currency(
-        &ProfileCurrency::VaultCardOneId,
-        keys_state.vault_card_1_keys_input,
-    )?;
-
-    profile
-        .profile_data
-        .set_vault_card_chests(1, keys_state.vault_card_1_chests_input);
-
-    profile.profile_data.set_currency(
-        &ProfileCurrency::VaultCardTwoId,
-        keys_state.vault_card_2_keys_input,
-    )?;
-
-    profile
-        .profile_data
-        .set_vault_card_chests(2, keys_state.vault_card_2_chests_input);
+    for currency in &keys_state.currencies {
+        profile.set_currency_by_hash(currency.hash, currency.input);
+    }
 
-    profile.profile_data.set_currency(
-        &ProfileCurrency::VaultCardThreeId,
-        keys_state.vault_card_3_keys_input,
-    )?;
+    for chests in &keys_state.vault_card_chests {
+        profile.set_vault_card_chests(chests.vault_card_id, chests.input);
+    }
 
-    profile
-        .profile_data
-        .set_vault_card_chests(3, keys_state.vault_card_3_chests_input);
+    if keys_state.reroll_vault_card_challenges {
+        profile.reroll_vault_card_challenges();
+    }
 
     Ok(())
 }
@@ -1,27 +1,180 @@
 use anyhow::Result;
 
 use bl3_save_edit_core::bl3_profile::Bl3Profile;
+use bl3_save_edit_core::validation::{validate_profile, Finding, FindingKind};
 
 use crate::state_mappers::manage_profile;
+use crate::views::manage_profile::main::ProfileTabBarView;
 use crate::views::manage_profile::ManageProfileState;
 
 pub mod bank;
 pub mod general;
 pub mod keys;
 pub mod profile;
+pub mod summary;
 
-pub fn map_all_states_to_profile(
+/// Tabs that edit the profile, in the order they are mapped back to it.
+pub const PROFILE_TABS: [ProfileTabBarView; 4] = [
+    ProfileTabBarView::General,
+    ProfileTabBarView::Profile,
+    ProfileTabBarView::Keys,
+    ProfileTabBarView::Bank,
+];
+
+pub fn map_profile_to_tab_state(
+    manage_profile_state: &mut ManageProfileState,
+    tab_bar_view: &ProfileTabBarView,
+) -> Result<()> {
+    match tab_bar_view {
+        ProfileTabBarView::General => {
+            manage_profile::general::map_profile_to_general_state(manage_profile_state)
+        }
+        ProfileTabBarView::Profile => {
+            manage_profile::profile::map_profile_to_profile_state(manage_profile_state)
+        }
+        ProfileTabBarView::Keys => {
+            manage_profile::keys::map_profile_to_keys_state(manage_profile_state)
+        }
+        ProfileTabBarView::Bank => {
+            manage_profile::bank::map_profile_to_bank_state(manage_profile_state)?
+        }
+        ProfileTabBarView::Settings => (),
+    }
+
+    Ok(())
+}
+
+/// Returns whether guardian data has to be injected into the saves.
+pub fn map_tab_state_to_profile(
     manage_profile_state: &mut ManageProfileState,
+    tab_bar_view: &ProfileTabBarView,
     current_file: &mut Bl3Profile,
 ) -> Result<bool> {
-    manage_profile::general::map_general_state_to_profile(manage_profile_state, current_file);
+    let guardian_data_injection_required = match tab_bar_view {
+        ProfileTabBarView::General => {
+            manage_profile::general::map_general_state_to_profile(
+                manage_profile_state,
+                current_file,
+            );
+
+            false
+        }
+        ProfileTabBarView::Profile => manage_profile::profile::map_profile_state_to_profile(
+            manage_profile_state,
+            current_file,
+        )?,
+        ProfileTabBarView::Keys => {
+            manage_profile::keys::map_keys_state_to_profile(manage_profile_state, current_file)?;
+
+            false
+        }
+        ProfileTabBarView::Bank => {
+            manage_profile::bank::map_bank_state_to_profile(manage_profile_state, current_file)?;
 
-    let guardian_data_injection_required =
-        manage_profile::profile::map_profile_state_to_profile(manage_profile_state, current_file)?;
+            false
+        }
+        ProfileTabBarView::Settings => false,
+    };
 
-    manage_profile::keys::map_keys_state_to_profile(manage_profile_state, current_file)?;
+    Ok(guardian_data_injection_required)
+}
+
+pub fn map_all_states_to_profile(
+    manage_profile_state: &mut ManageProfileState,
+    current_file: &mut Bl3Profile,
+) -> Result<bool> {
+    let mut guardian_data_injection_required = false;
 
-    manage_profile::bank::map_bank_state_to_profile(manage_profile_state, current_file)?;
+    for tab_bar_view in &PROFILE_TABS {
+        guardian_data_injection_required |=
+            map_tab_state_to_profile(manage_profile_state, tab_bar_view, current_file)?;
+    }
 
     Ok(guardian_data_injection_required)
 }
+
+/// The tab that edits what `finding` is about, None if there isn't one.
+pub fn tab_for_finding(finding: &Finding) -> Option<ProfileTabBarView> {
+    match finding.kind {
+        FindingKind::Currency => Some(ProfileTabBarView::Keys),
+        FindingKind::Sdu | FindingKind::GuardianTokens => Some(ProfileTabBarView::Profile),
+        FindingKind::UnknownItem => Some(ProfileTabBarView::Bank),
+        FindingKind::Ammo | FindingKind::EquippedItem | FindingKind::DuplicateSlot => None,
+    }
+}
+
+/// Works out which tabs have edits that haven't been saved and which tabs edit something that
+/// validation would report when saving.
+pub fn map_state_to_tab_badges(manage_profile_state: &mut ManageProfileState) {
+    let mut dirty = Vec::new();
+    let mut warnings = Vec::new();
+
+    for tab_bar_view in &PROFILE_TABS {
+        let mut tab_file = manage_profile_state.current_file.clone();
+
+        match map_tab_state_to_profile(manage_profile_state, tab_bar_view, &mut tab_file) {
+            // Profiles are compared by their data only, so a new file name is checked separately
+            Ok(_)
+                if tab_file != manage_profile_state.current_file
+                    || tab_file.file_name != manage_profile_state.current_file.file_name =>
+            {
+                dirty.push(tab_bar_view.clone())
+            }
+            Ok(_) => (),
+            // Saving would fail because of this tab
+            Err(_) => warnings.push(tab_bar_view.clone()),
+        }
+    }
+
+    let mut output_file = manage_profile_state.current_file.clone();
+
+    if map_all_states_to_profile(manage_profile_state, &mut output_file).is_ok() {
+        for tab_bar_view in validate_profile(&output_file)
+            .iter()
+            .filter_map(tab_for_finding)
+        {
+            if !warnings.contains(&tab_bar_view) {
+                warnings.push(tab_bar_view);
+            }
+        }
+    }
+
+    manage_profile_state.tab_badges.dirty = dirty;
+    manage_profile_state.tab_badges.warnings = warnings;
+}
+
+#[cfg(test)]
+mod tests {
+    use bl3_save_edit_core::validation::Severity;
+
+    use super::*;
+
+    fn finding(kind: FindingKind) -> Finding {
+        Finding {
+            severity: Severity::Error,
+            kind,
+            message: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_tab_for_finding() {
+        assert_eq!(
+            tab_for_finding(&finding(FindingKind::Currency)),
+            Some(ProfileTabBarView::Keys)
+        );
+        assert_eq!(
+            tab_for_finding(&finding(FindingKind::Sdu)),
+            Some(ProfileTabBarView::Profile)
+        );
+        assert_eq!(
+            tab_for_finding(&finding(FindingKind::GuardianTokens)),
+            Some(ProfileTabBarView::Profile)
+        );
+        assert_eq!(
+            tab_for_finding(&finding(FindingKind::UnknownItem)),
+            Some(ProfileTabBarView::Bank)
+        );
+        assert_eq!(tab_for_finding(&finding(FindingKind::Ammo)), None);
+    }
+}
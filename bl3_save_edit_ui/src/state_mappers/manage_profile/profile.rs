@@ -1,5 +1,5 @@
 use anyhow::Result;
-use tracing::info;
+use tracing::{info, warn};
 
 use bl3_save_edit_core::bl3_profile::guardian_reward::GuardianReward;
 use bl3_save_edit_core::bl3_profile::sdu::ProfileSduSlot;
@@ -24,6 +24,11 @@ pub fn map_profile_to_profile_state(manage_profile_state: &mut ManageProfileStat
         .borderlands_science_info()
         .science_level;
 
+    manage_profile_state
+        .profile_view_state
+        .profile_state
+        .science_xp_input = profile.profile_data.borderlands_science_info().xp;
+
     manage_profile_state
         .profile_view_state
         .profile_state
@@ -147,6 +152,10 @@ pub fn map_profile_state_to_profile(
         .profile_data
         .set_borderlands_science_level(&profile_state.science_level_selected);
 
+    profile
+        .profile_data
+        .set_borderlands_science_xp(profile_state.science_xp_input);
+
     profile
         .profile_data
         .set_borderlands_science_tokens(profile_state.science_tokens_input);
@@ -165,10 +174,16 @@ pub fn map_profile_state_to_profile(
 
     for s in all_skin_unlock_boxes {
         if s.is_unlocked {
-            profile.profile_data.unlock_skin_set(&s.skin_data.skin_type)
+            profile.unlock_skin_set(&s.skin_data.skin_type)
         }
     }
 
+    if skin_unlocker.include_event_cosmetics {
+        let unlocked = profile.unlock_event_cosmetics();
+
+        info!("Unlocked {} event cosmetics", unlocked);
+    }
+
     let guardian_reward_unlocker = &profile_state.guardian_reward_unlocker;
 
     let total_guardian_rewards = guardian_reward_unlocker
@@ -208,12 +223,10 @@ pub fn map_profile_state_to_profile(
         info!("Setting guardian rank and injecting into all saves...");
 
         for g in guardian_reward_unlocker.all_rewards() {
-            profile
-                .profile_data
-                .set_guardian_reward(&g.guardian_reward, g.input)?;
+            profile.set_guardian_reward(&g.guardian_reward, g.input)?;
         }
 
-        profile.profile_data.set_guardian_rank(
+        profile.set_guardian_rank(
             guardian_rank as i32,
             Some(profile_state.guardian_rank_tokens_input),
         );
@@ -226,7 +239,16 @@ pub fn map_profile_state_to_profile(
     let all_sdu_slots = [&sdu_unlocker.lost_loot, &sdu_unlocker.bank];
 
     for s in all_sdu_slots {
-        profile.profile_data.set_sdu_slot(&s.sdu_slot, s.input);
+        let level = s.input.clamp(0, s.sdu_slot.maximum());
+
+        if level != s.input {
+            warn!(
+                "{} SDU level {} is out of range, saving it as {}",
+                s.sdu_slot, s.input, level
+            );
+        }
+
+        profile.set_sdu_slot(&s.sdu_slot, level);
     }
 
     Ok(guardian_data_injection_required)
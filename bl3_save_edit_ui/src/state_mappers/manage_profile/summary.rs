@@ -0,0 +1,71 @@
+use bl3_save_edit_core::bl3_profile::skins::{ProfileSkinType, SkinSet, WeaponSkinSet};
+
+use crate::views::manage_profile::summary::SkinCategorySummary;
+use crate::views::manage_profile::ManageProfileState;
+
+pub fn map_profile_to_summary_state(manage_profile_state: &mut ManageProfileState) {
+    let profile_data = &manage_profile_state.current_file.profile_data;
+
+    let summary_state = &mut manage_profile_state.profile_view_state.summary_state;
+
+    summary_state.guardian_rank = profile_data.guardian_rank();
+
+    summary_state.bank_item_count = profile_data.bank_items().len();
+
+    summary_state.keys = profile_data
+        .currencies()
+        .iter()
+        .map(|c| (c.name(), c.current))
+        .collect();
+
+    summary_state.sdu_levels = profile_data
+        .sdu_slots()
+        .iter()
+        .map(|s| (s.sdu.to_string(), s.current, s.max))
+        .collect();
+
+    summary_state.skin_categories = [
+        (
+            "Character Skins",
+            ProfileSkinType::Regular(SkinSet::CharacterSkins),
+            profile_data.character_skins_unlocked(),
+        ),
+        (
+            "Character Heads",
+            ProfileSkinType::Regular(SkinSet::CharacterHeads),
+            profile_data.character_heads_unlocked(),
+        ),
+        (
+            "Echo Themes",
+            ProfileSkinType::Regular(SkinSet::EchoThemes),
+            profile_data.echo_themes_unlocked(),
+        ),
+        (
+            "Emotes",
+            ProfileSkinType::Regular(SkinSet::Emotes),
+            profile_data.profile_emotes_unlocked(),
+        ),
+        (
+            "Room Decorations",
+            ProfileSkinType::Regular(SkinSet::RoomDecorations),
+            profile_data.room_decorations_unlocked(),
+        ),
+        (
+            "Weapon Skins",
+            ProfileSkinType::Weapon(WeaponSkinSet::WeaponSkins),
+            profile_data.weapon_skins_unlocked(),
+        ),
+        (
+            "Weapon Trinkets",
+            ProfileSkinType::Weapon(WeaponSkinSet::WeaponTrinkets),
+            profile_data.weapon_trinkets_unlocked(),
+        ),
+    ]
+    .into_iter()
+    .map(|(name, skin_type, current)| SkinCategorySummary {
+        name: name.to_owned(),
+        current,
+        maximum: skin_type.maximum(),
+    })
+    .collect();
+}
@@ -0,0 +1,29 @@
+use anyhow::Result;
+
+use bl3_save_edit_core::bl3_save::Bl3Save;
+
+use crate::views::manage_save::ManageSaveState;
+
+pub fn map_save_to_challenges_state(manage_save_state: &mut ManageSaveState) {
+    let challenges_state = &mut manage_save_state.save_view_state.challenges_state;
+
+    challenges_state.categories = manage_save_state
+        .current_file
+        .character_data
+        .challenge_completion();
+    challenges_state.complete_all = false;
+}
+
+pub fn map_challenges_state_to_save(
+    manage_save_state: &mut ManageSaveState,
+    save: &mut Bl3Save,
+) -> Result<()> {
+    let challenges_state = &manage_save_state.save_view_state.challenges_state;
+
+    if challenges_state.complete_all {
+        save.character_data
+            .complete_all_challenges(challenges_state.include_dlc)?;
+    }
+
+    Ok(())
+}
@@ -1,8 +1,10 @@
 use anyhow::Result;
+use tracing::warn;
 
 use bl3_save_edit_core::bl3_save::ammo::AmmoPool;
 use bl3_save_edit_core::bl3_save::inventory_slot::InventorySlot;
 use bl3_save_edit_core::bl3_save::sdu::SaveSduSlot;
+use bl3_save_edit_core::bl3_save::util::sanitize_character_name;
 use bl3_save_edit_core::bl3_save::Bl3Save;
 
 use crate::views::manage_save::ManageSaveState;
@@ -36,26 +38,48 @@ pub fn map_save_to_character_state(manage_save_state: &mut ManageSaveState) {
         .character_state
         .ability_points_input = save.character_data.ability_points();
 
+    manage_save_state
+        .save_view_state
+        .character_state
+        .equipped_items
+        .set_items(save.character_data.equipped_items());
+
+    manage_save_state
+        .save_view_state
+        .character_state
+        .skill_tree
+        .set_items(save.character_data.skill_tree_items());
+
+    map_save_to_spent_ability_points(manage_save_state);
+
+    let save = &manage_save_state.current_file;
+
+    manage_save_state
+        .save_view_state
+        .character_state
+        .skin_selectors
+        .set_player_class(save.character_data.player_class());
+
     manage_save_state
         .save_view_state
         .character_state
         .skin_selectors
         .head_skin
-        .selected = save.character_data.head_skin_selected();
+        .set_current(save.character_data.head_skin_selected());
 
     manage_save_state
         .save_view_state
         .character_state
         .skin_selectors
         .character_skin
-        .selected = save.character_data.character_skin_selected();
+        .set_current(save.character_data.character_skin_selected());
 
     manage_save_state
         .save_view_state
         .character_state
         .skin_selectors
         .echo_theme
-        .selected = save.character_data.echo_theme_selected();
+        .set_current(save.character_data.echo_theme_selected());
 
     let mut gear_unlocker = std::mem::take(
         &mut manage_save_state
@@ -127,6 +151,8 @@ pub fn map_save_to_character_state(manage_save_state: &mut ManageSaveState) {
             AmmoPool::Heavy => ammo_setter.heavy.input = s.current,
         });
 
+    ammo_setter.unknown_pools = save.character_data.unknown_ammo_pools().clone();
+
     manage_save_state
         .save_view_state
         .character_state
@@ -159,17 +185,46 @@ pub fn map_save_to_character_state(manage_save_state: &mut ManageSaveState) {
         .sdu_unlocker = sdu_unlocker;
 }
 
+pub fn map_save_to_spent_ability_points(manage_save_state: &mut ManageSaveState) {
+    let save = &manage_save_state.current_file;
+
+    let character_state = &mut manage_save_state.save_view_state.character_state;
+
+    //Saving a new level or class resets the skill tree
+    let skill_tree_reset = character_state.experience_points_input
+        != save.character_data.character.experience_points
+        || character_state.player_class_selected_class != save.character_data.player_class();
+
+    character_state.spent_ability_points = if skill_tree_reset {
+        0
+    } else {
+        character_state.skill_tree.spent_points()
+    };
+}
+
 pub fn map_character_state_to_save(
     manage_save_state: &mut ManageSaveState,
     save: &mut Bl3Save,
 ) -> Result<()> {
-    save.character_data.character.preferred_character_name = manage_save_state
+    let character_state = &mut manage_save_state.save_view_state.character_state;
+
+    // Names that weren't edited are written back as they were loaded
+    if character_state.name_input != save.character_data.character.preferred_character_name {
+        save.set_character_name(&sanitize_character_name(&character_state.name_input));
+    }
+
+    // Changing the level or class below resets the skill tree, so this has to happen first
+    for skill in manage_save_state
         .save_view_state
         .character_state
-        .name_input
-        .clone();
+        .skill_tree
+        .items()
+    {
+        save.character_data
+            .set_skill_tree_item_points(&skill.path, skill.points)?;
+    }
 
-    save.character_data.set_player_level(
+    save.set_experience_points(
         manage_save_state
             .save_view_state
             .character_state
@@ -258,7 +313,7 @@ pub fn map_character_state_to_save(
     ];
 
     for a in all_ammo_pools {
-        save.character_data.set_ammo_pool(&a.ammo_pool, a.input)?;
+        save.set_ammo_pool(&a.ammo_pool, a.input)?;
     }
 
     let sdu_unlocker = &manage_save_state
@@ -278,7 +333,17 @@ pub fn map_character_state_to_save(
     ];
 
     for s in all_sdu_slots {
-        save.character_data.set_sdu_slot(&s.sdu_slot, s.input);
+        // The game may reject a save with a level it can't reach
+        let level = s.input.clamp(0, s.sdu_slot.maximum());
+
+        if level != s.input {
+            warn!(
+                "{} SDU level {} is out of range, saving it as {}",
+                s.sdu_slot, s.input, level
+            );
+        }
+
+        save.set_sdu_slot(&s.sdu_slot, level);
     }
 
     Ok(())
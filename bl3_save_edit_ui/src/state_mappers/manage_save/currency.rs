@@ -19,10 +19,9 @@ pub fn map_currrency_state_to_save(
     manage_save_state: &mut ManageSaveState,
     save: &mut Bl3Save,
 ) -> Result<()> {
-    save.character_data
-        .set_money(manage_save_state.save_view_state.currency_state.money_input)?;
+    save.set_money(manage_save_state.save_view_state.currency_state.money_input)?;
 
-    save.character_data.set_eridium(
+    save.set_eridium(
         manage_save_state
             .save_view_state
             .currency_state
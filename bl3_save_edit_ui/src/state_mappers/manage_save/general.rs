@@ -1,5 +1,11 @@
+use anyhow::Result;
+
+use bl3_save_edit_core::bl3_save::playthrough::PlaythroughType;
+use bl3_save_edit_core::bl3_save::travel_station::TravelStation;
 use bl3_save_edit_core::bl3_save::Bl3Save;
 
+use crate::save_notes::SaveNotes;
+use crate::views::manage_save::general::TravelStationState;
 use crate::views::manage_save::ManageSaveState;
 
 pub fn map_save_to_general_state(manage_save_state: &mut ManageSaveState) {
@@ -20,9 +26,98 @@ pub fn map_save_to_general_state(manage_save_state: &mut ManageSaveState) {
         .save_view_state
         .general_state
         .save_type_selected = save.header_type;
+
+    manage_save_state
+        .save_view_state
+        .general_state
+        .file_metadata = save.metadata();
+
+    manage_save_state
+        .save_view_state
+        .general_state
+        .current_mayhem_level = save.mayhem_level();
+
+    manage_save_state
+        .save_view_state
+        .general_state
+        .mayhem_level_input = save.mayhem_level();
+
+    let mut travel_station_options = TravelStation::all_spawnable();
+
+    let travel_station_states = save
+        .character_data
+        .playthroughs()
+        .iter()
+        .enumerate()
+        .map(|(i, playthrough)| {
+            let selected = playthrough.last_travel_station.clone();
+
+            // Stations missing from our game data should still be selectable
+            if let Some(selected) = &selected {
+                if !travel_station_options.contains(selected) {
+                    travel_station_options.push(selected.clone());
+                }
+            }
+
+            let is_unlocked = selected
+                .as_ref()
+                .map(|s| save.character_data.is_travel_station_unlocked(i, s))
+                .unwrap_or(true);
+
+            TravelStationState {
+                selected,
+                is_unlocked,
+                ..TravelStationState::default()
+            }
+        })
+        .collect::<Vec<_>>();
+
+    manage_save_state
+        .save_view_state
+        .general_state
+        .travel_station_options = travel_station_options;
+
+    manage_save_state
+        .save_view_state
+        .general_state
+        .travel_station_states = travel_station_states;
+
+    manage_save_state
+        .save_view_state
+        .general_state
+        .unlock_travel_station = false;
+
+    manage_save_state
+        .save_view_state
+        .general_state
+        .playthrough_options = PlaythroughType::ALL
+        .iter()
+        .take(save.character_data.playthroughs().len())
+        .copied()
+        .collect();
+
+    manage_save_state
+        .save_view_state
+        .general_state
+        .playthrough_selected = save.playthrough_type();
+
+    manage_save_state
+        .save_view_state
+        .general_state
+        .current_playthrough = save.playthrough_type();
+
+    manage_save_state
+        .save_view_state
+        .general_state
+        .reset_playthrough = None;
+
+    manage_save_state.save_view_state.general_state.share_qr_svg = None;
 }
 
-pub fn map_general_state_to_save(manage_save_state: &mut ManageSaveState, save: &mut Bl3Save) {
+pub fn map_general_state_to_save(
+    manage_save_state: &mut ManageSaveState,
+    save: &mut Bl3Save,
+) -> Result<()> {
     save.file_name = manage_save_state
         .save_view_state
         .general_state
@@ -42,4 +137,77 @@ pub fn map_general_state_to_save(manage_save_state: &mut ManageSaveState, save:
         .save_view_state
         .general_state
         .save_type_selected;
+
+    save.set_mayhem_level(
+        manage_save_state
+            .save_view_state
+            .general_state
+            .mayhem_level_input,
+    )?;
+
+    let unlock_travel_station = manage_save_state
+        .save_view_state
+        .general_state
+        .unlock_travel_station;
+
+    for (i, travel_station_state) in manage_save_state
+        .save_view_state
+        .general_state
+        .travel_station_states
+        .iter()
+        .enumerate()
+    {
+        let current_station = save
+            .character_data
+            .playthroughs()
+            .get(i)
+            .and_then(|p| p.last_travel_station.as_ref());
+
+        if let Some(selected) = &travel_station_state.selected {
+            if current_station != Some(selected) {
+                save.character_data
+                    .set_last_travel_station(i, selected, unlock_travel_station)?;
+            }
+        }
+    }
+
+    // Reset after the other edits so they can't bring back any of the cleared progress
+    if let Some(reset_playthrough) = manage_save_state
+        .save_view_state
+        .general_state
+        .reset_playthrough
+    {
+        save.reset_playthrough(reset_playthrough)?;
+    }
+
+    // Switched last so the edits above apply to the playthrough they were made for
+    save.set_playthrough_type(
+        manage_save_state
+            .save_view_state
+            .general_state
+            .playthrough_selected,
+    )?;
+
+    Ok(())
+}
+
+pub fn map_save_notes_to_general_state(manage_save_state: &mut ManageSaveState, notes: SaveNotes) {
+    manage_save_state
+        .save_view_state
+        .general_state
+        .legacy_badass_rank_input = notes
+        .legacy_badass_rank
+        .map(|r| r.to_string())
+        .unwrap_or_default();
+}
+
+pub fn map_general_state_to_save_notes(manage_save_state: &ManageSaveState) -> SaveNotes {
+    SaveNotes {
+        legacy_badass_rank: manage_save_state
+            .save_view_state
+            .general_state
+            .legacy_badass_rank_input
+            .parse()
+            .ok(),
+    }
 }
@@ -6,6 +6,7 @@ use bl3_save_edit_core::bl3_save::Bl3Save;
 
 use crate::views::item_editor::item_editor_list_item::ItemEditorListItem;
 use crate::views::item_editor::{sort_items, ItemEditorStateExt};
+use crate::views::manage_save::transfer::TransferState;
 use crate::views::manage_save::ManageSaveState;
 
 pub fn map_save_to_inventory_state(manage_save_state: &mut ManageSaveState) -> Result<()> {
@@ -17,6 +18,17 @@ pub fn map_save_to_inventory_state(manage_save_state: &mut ManageSaveState) -> R
         .item_editor_state
         .selected_item_index = 0;
 
+    manage_save_state
+        .save_view_state
+        .inventory_state
+        .transfer_state = TransferState::default();
+
+    manage_save_state
+        .save_view_state
+        .inventory_state
+        .best_items
+        .clear();
+
     let mut inventory_items = save
         .character_data
         .inventory_items()
@@ -105,5 +117,13 @@ pub fn map_inventory_state_to_save(
         }
     }
 
+    // Equipped after the items are written so the indexes point at the edited inventory
+    for (slot, item_index) in &manage_save_state.save_view_state.inventory_state.best_items {
+        if save.character_data.equipped_inventory_index(slot) != Some(*item_index) {
+            save.character_data
+                .equip_inventory_item(slot, *item_index)?;
+        }
+    }
+
     Ok(())
 }
@@ -0,0 +1,92 @@
+use anyhow::Result;
+
+use bl3_save_edit_core::bl3_save::playthrough::PlaythroughType;
+use bl3_save_edit_core::bl3_save::Bl3Save;
+
+use crate::views::manage_save::missions::{MissionEditor, PlaythroughMissions};
+use crate::views::manage_save::ManageSaveState;
+
+pub fn map_save_to_missions_state(manage_save_state: &mut ManageSaveState) {
+    let character_data = &manage_save_state.current_file.character_data;
+
+    let playthrough_options: Vec<PlaythroughType> = PlaythroughType::ALL
+        .iter()
+        .copied()
+        .take(character_data.playthroughs().len())
+        .collect();
+
+    let playthroughs = playthrough_options
+        .iter()
+        .map(|playthrough| PlaythroughMissions {
+            tracked_mission: character_data
+                .tracked_mission(*playthrough)
+                .map(str::to_owned),
+            missions: character_data
+                .active_missions(*playthrough)
+                .into_iter()
+                .map(MissionEditor::new)
+                .collect(),
+        })
+        .collect();
+
+    let missions_state = &mut manage_save_state.save_view_state.missions_state;
+
+    missions_state.playthrough_selected = character_data.playthrough_type();
+    missions_state.playthrough_options = playthrough_options;
+    missions_state.playthroughs = playthroughs;
+}
+
+pub fn map_missions_state_to_save(
+    manage_save_state: &mut ManageSaveState,
+    save: &mut Bl3Save,
+) -> Result<()> {
+    let missions_state = &manage_save_state.save_view_state.missions_state;
+
+    for (playthrough, playthrough_missions) in missions_state
+        .playthrough_options
+        .iter()
+        .zip(&missions_state.playthroughs)
+    {
+        // Missions that aren't active anymore (such as after resetting the playthrough) are left
+        // alone, only objectives that were changed are written
+        let saved_missions = save.character_data.active_missions(*playthrough);
+
+        for mission_editor in &playthrough_missions.missions {
+            let mission = &mission_editor.mission;
+
+            let saved_mission = match saved_missions.iter().find(|m| m.path == mission.path) {
+                Some(saved_mission) => saved_mission,
+                None => continue,
+            };
+
+            for (objective_index, (objective, saved_objective)) in mission
+                .objectives
+                .iter()
+                .zip(&saved_mission.objectives)
+                .enumerate()
+            {
+                if objective.progress != saved_objective.progress {
+                    save.set_objective_progress(
+                        *playthrough,
+                        &mission.path,
+                        objective_index,
+                        objective.progress,
+                    )?;
+                }
+            }
+        }
+
+        if let Some(tracked_mission) = &playthrough_missions.tracked_mission {
+            let is_active = saved_missions.iter().any(|m| &m.path == tracked_mission);
+
+            if is_active
+                && save.character_data.tracked_mission(*playthrough)
+                    != Some(tracked_mission.as_str())
+            {
+                save.set_tracked_mission(*playthrough, tracked_mission)?;
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -1,29 +1,191 @@
 use anyhow::Result;
 
 use bl3_save_edit_core::bl3_save::Bl3Save;
+use bl3_save_edit_core::validation::{validate_save, Finding, FindingKind};
 
 use crate::state_mappers::manage_save;
+use crate::views::manage_save::main::SaveTabBarView;
 use crate::views::manage_save::ManageSaveState;
 
+pub mod challenges;
 pub mod character;
 pub mod currency;
 pub mod general;
 pub mod inventory;
+pub mod missions;
 pub mod vehicle;
 
+/// Tabs that edit the save, in the order they are mapped back to it.
+pub const SAVE_TABS: [SaveTabBarView; 7] = [
+    SaveTabBarView::General,
+    SaveTabBarView::Character,
+    SaveTabBarView::Inventory,
+    SaveTabBarView::Currency,
+    SaveTabBarView::Vehicle,
+    SaveTabBarView::Missions,
+    SaveTabBarView::Challenges,
+];
+
+pub fn map_save_to_tab_state(
+    manage_save_state: &mut ManageSaveState,
+    tab_bar_view: &SaveTabBarView,
+) -> Result<()> {
+    match tab_bar_view {
+        SaveTabBarView::General => {
+            manage_save::general::map_save_to_general_state(manage_save_state)
+        }
+        SaveTabBarView::Character => {
+            manage_save::character::map_save_to_character_state(manage_save_state)
+        }
+        SaveTabBarView::Inventory => {
+            manage_save::inventory::map_save_to_inventory_state(manage_save_state)?
+        }
+        SaveTabBarView::Currency => {
+            manage_save::currency::map_save_to_currency_state(manage_save_state)
+        }
+        SaveTabBarView::Vehicle => {
+            manage_save::vehicle::map_save_to_vehicle_state(manage_save_state)
+        }
+        SaveTabBarView::Missions => {
+            manage_save::missions::map_save_to_missions_state(manage_save_state)
+        }
+        SaveTabBarView::Challenges => {
+            manage_save::challenges::map_save_to_challenges_state(manage_save_state)
+        }
+        SaveTabBarView::Settings => (),
+    }
+
+    Ok(())
+}
+
+pub fn map_tab_state_to_save(
+    manage_save_state: &mut ManageSaveState,
+    tab_bar_view: &SaveTabBarView,
+    current_file: &mut Bl3Save,
+) -> Result<()> {
+    match tab_bar_view {
+        SaveTabBarView::General => {
+            manage_save::general::map_general_state_to_save(manage_save_state, current_file)?
+        }
+        SaveTabBarView::Character => {
+            manage_save::character::map_character_state_to_save(manage_save_state, current_file)?
+        }
+        SaveTabBarView::Inventory => {
+            manage_save::inventory::map_inventory_state_to_save(manage_save_state, current_file)?
+        }
+        SaveTabBarView::Currency => {
+            manage_save::currency::map_currrency_state_to_save(manage_save_state, current_file)?
+        }
+        SaveTabBarView::Vehicle => {
+            manage_save::vehicle::map_vehicle_state_to_save(manage_save_state, current_file)
+        }
+        SaveTabBarView::Missions => {
+            manage_save::missions::map_missions_state_to_save(manage_save_state, current_file)?
+        }
+        SaveTabBarView::Challenges => {
+            manage_save::challenges::map_challenges_state_to_save(manage_save_state, current_file)?
+        }
+        SaveTabBarView::Settings => (),
+    }
+
+    Ok(())
+}
+
 pub fn map_all_states_to_save(
     manage_save_state: &mut ManageSaveState,
     current_file: &mut Bl3Save,
 ) -> Result<()> {
-    manage_save::general::map_general_state_to_save(manage_save_state, current_file);
+    SAVE_TABS.iter().try_for_each(|tab_bar_view| {
+        map_tab_state_to_save(manage_save_state, tab_bar_view, current_file)
+    })
+}
 
-    manage_save::character::map_character_state_to_save(manage_save_state, current_file)?;
+/// The tab that edits what `finding` is about, None if there isn't one.
+pub fn tab_for_finding(finding: &Finding) -> Option<SaveTabBarView> {
+    match finding.kind {
+        FindingKind::Currency => Some(SaveTabBarView::Currency),
+        FindingKind::Ammo | FindingKind::Sdu => Some(SaveTabBarView::Character),
+        FindingKind::UnknownItem | FindingKind::EquippedItem | FindingKind::DuplicateSlot => {
+            Some(SaveTabBarView::Inventory)
+        }
+        FindingKind::GuardianTokens => None,
+    }
+}
 
-    manage_save::inventory::map_inventory_state_to_save(manage_save_state, current_file)?;
+/// Works out which tabs have edits that haven't been saved and which tabs edit something that
+/// validation would report when saving.
+pub fn map_state_to_tab_badges(manage_save_state: &mut ManageSaveState) {
+    let mut dirty = Vec::new();
+    let mut warnings = Vec::new();
 
-    manage_save::currency::map_currrency_state_to_save(manage_save_state, current_file)?;
+    for tab_bar_view in &SAVE_TABS {
+        let mut tab_file = manage_save_state.current_file.clone();
 
-    manage_save::vehicle::map_vehicle_state_to_save(manage_save_state, current_file);
+        match map_tab_state_to_save(manage_save_state, tab_bar_view, &mut tab_file) {
+            Ok(_) if tab_file != manage_save_state.current_file => dirty.push(tab_bar_view.clone()),
+            Ok(_) => (),
+            // Saving would fail because of this tab
+            Err(_) => warnings.push(tab_bar_view.clone()),
+        }
+    }
 
-    Ok(())
+    let mut output_file = manage_save_state.current_file.clone();
+
+    if map_all_states_to_save(manage_save_state, &mut output_file).is_ok() {
+        for tab_bar_view in validate_save(&output_file)
+            .iter()
+            .filter_map(tab_for_finding)
+        {
+            if !warnings.contains(&tab_bar_view) {
+                warnings.push(tab_bar_view);
+            }
+        }
+    }
+
+    manage_save_state.tab_badges.dirty = dirty;
+    manage_save_state.tab_badges.warnings = warnings;
+}
+
+#[cfg(test)]
+mod tests {
+    use bl3_save_edit_core::validation::Severity;
+
+    use super::*;
+
+    fn finding(kind: FindingKind) -> Finding {
+        Finding {
+            severity: Severity::Warning,
+            kind,
+            message: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_tab_for_finding() {
+        assert_eq!(
+            tab_for_finding(&finding(FindingKind::Currency)),
+            Some(SaveTabBarView::Currency)
+        );
+        assert_eq!(
+            tab_for_finding(&finding(FindingKind::Ammo)),
+            Some(SaveTabBarView::Character)
+        );
+        assert_eq!(
+            tab_for_finding(&finding(FindingKind::Sdu)),
+            Some(SaveTabBarView::Character)
+        );
+
+        for kind in [
+            FindingKind::UnknownItem,
+            FindingKind::EquippedItem,
+            FindingKind::DuplicateSlot,
+        ] {
+            assert_eq!(
+                tab_for_finding(&finding(kind)),
+                Some(SaveTabBarView::Inventory)
+            );
+        }
+
+        assert_eq!(tab_for_finding(&finding(FindingKind::GuardianTokens)), None);
+    }
 }
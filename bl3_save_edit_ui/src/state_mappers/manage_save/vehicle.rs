@@ -1,6 +1,7 @@
 use bl3_save_edit_core::bl3_save::Bl3Save;
-use bl3_save_edit_core::vehicle_data::{VehicleSubType, VehicleType};
+use bl3_save_edit_core::vehicle_data::{Vehicle, VehicleSubType, VehicleType};
 
+use crate::views::manage_save::vehicle::vehicle_loadout::VehicleLoadoutPicker;
 use crate::views::manage_save::vehicle::vehicle_unlocker::VehicleUnlocker;
 use crate::views::manage_save::ManageSaveState;
 
@@ -43,6 +44,14 @@ pub fn map_save_to_vehicle_state(manage_save_state: &mut ManageSaveState) {
     }
 
     manage_save_state.save_view_state.vehicle_state.unlocker = unlocker;
+
+    manage_save_state
+        .save_view_state
+        .vehicle_state
+        .loadout_pickers = Vehicle::ALL
+        .iter()
+        .map(|v| VehicleLoadoutPicker::new(&save.character_data.vehicle_loadout(v)))
+        .collect();
 }
 
 pub fn map_vehicle_state_to_save(manage_save_state: &mut ManageSaveState, save: &mut Bl3Save) {
@@ -67,8 +76,17 @@ pub fn map_vehicle_state_to_save(manage_save_state: &mut ManageSaveState, save:
 
     for vd in all_vehicle_unlock_boxes {
         if vd.is_unlocked {
-            save.character_data
-                .unlock_vehicle_data(&vd.vehicle_data.vehicle_type)
+            save.unlock_vehicle_data(&vd.vehicle_data.vehicle_type)
+        }
+    }
+
+    // Only loadouts that were changed are written so vehicles the save has never called stay as
+    // they are
+    for picker in &vehicle_state.loadout_pickers {
+        let vehicle_loadout = picker.loadout();
+
+        if save.character_data.vehicle_loadout(&picker.vehicle) != vehicle_loadout {
+            save.set_vehicle_loadout(&vehicle_loadout);
         }
     }
 }
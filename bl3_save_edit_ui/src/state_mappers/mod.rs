@@ -1,15 +1,22 @@
 use std::mem;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-use anyhow::Result;
+use anyhow::{bail, Result};
+use tracing::warn;
 
-use bl3_save_edit_core::bl3_profile::guardian_reward::GuardianRewardData;
+use bl3_save_edit_core::bl3_profile::Bl3Profile;
+use bl3_save_edit_core::bl3_save::Bl3Save;
 use bl3_save_edit_core::file_helper::Bl3FileType;
+use bl3_save_edit_core::guardian_injection::{saves_requiring_injection, GuardianInjection};
+use bl3_save_edit_core::item_sources::ItemSources;
+use bl3_save_edit_core::validation::{sanitize_profile, sanitize_save};
 
 use crate::bl3_ui::Bl3Application;
 use crate::bl3_ui::ViewState;
 use crate::commands::interaction;
-use crate::commands::interaction::choose_save_directory;
+use crate::item_sources::item_sources_path;
+use crate::save_notes::SaveNotes;
+use crate::views::manage_profile::guardian_injection::GuardianInjectionState;
 use crate::views::manage_profile::main::ProfileTabBarView;
 use crate::views::manage_profile::ManageProfileView;
 use crate::views::manage_save::main::SaveTabBarView;
@@ -23,16 +30,59 @@ pub fn map_loaded_file_to_state(main_state: &mut Bl3Application) -> Result<()> {
         Bl3FileType::PcSave(save) | Bl3FileType::Ps4Save(save) => {
             //This file will be the one that gets modified when we press save.
             main_state.manage_save_state.current_file = save.clone();
+            main_state.manage_save_state.sanitized_on_load =
+                if main_state.config.sanitize_negative_values() {
+                    sanitize_save(&mut main_state.manage_save_state.current_file)?
+                } else {
+                    Vec::new()
+                };
+            main_state
+                .manage_save_state
+                .unsupported_version_save_pending = false;
+            main_state.manage_save_state.validation_report_state = Default::default();
+            main_state.manage_save_state.changes_summary = None;
+            main_state.manage_save_state.tab_badges = Default::default();
+            main_state.manage_save_state.reset_playthrough_save_pending = false;
+            main_state.manage_save_state.unsupported_name_save_pending = false;
+            main_state
+                .manage_save_state
+                .save_view_state
+                .character_state
+                .pending_build = None;
+            main_state
+                .manage_save_state
+                .save_view_state
+                .inventory_state
+                .removed_all_items = None;
 
-            manage_save::general::map_save_to_general_state(&mut main_state.manage_save_state);
-
-            manage_save::character::map_save_to_character_state(&mut main_state.manage_save_state);
-
-            manage_save::inventory::map_save_to_inventory_state(&mut main_state.manage_save_state)?;
+            for tab_bar_view in [
+                SaveTabBarView::General,
+                SaveTabBarView::Character,
+                SaveTabBarView::Inventory,
+                SaveTabBarView::Currency,
+                SaveTabBarView::Vehicle,
+                SaveTabBarView::Missions,
+                SaveTabBarView::Challenges,
+            ] {
+                manage_save::map_save_to_tab_state(
+                    &mut main_state.manage_save_state,
+                    &tab_bar_view,
+                )?;
+            }
 
-            manage_save::currency::map_save_to_currency_state(&mut main_state.manage_save_state);
+            let save_notes = SaveNotes::load(
+                main_state.config.config_dir(),
+                &save.character_data.character.save_game_guid,
+            )
+            .unwrap_or_else(|e| {
+                warn!("Failed to load save notes: {}", e);
+                SaveNotes::default()
+            });
 
-            manage_save::vehicle::map_save_to_vehicle_state(&mut main_state.manage_save_state);
+            manage_save::general::map_save_notes_to_general_state(
+                &mut main_state.manage_save_state,
+                save_notes,
+            );
 
             if mem::discriminant(&main_state.view_state)
                 != mem::discriminant(&ViewState::ManageSave(ManageSaveView::TabBar(
@@ -45,26 +95,57 @@ pub fn map_loaded_file_to_state(main_state: &mut Bl3Application) -> Result<()> {
         }
         Bl3FileType::PcProfile(profile) | Bl3FileType::Ps4Profile(profile) => {
             main_state.manage_profile_state.current_file = profile.clone();
+            main_state.manage_profile_state.sanitized_on_load =
+                if main_state.config.sanitize_negative_values() {
+                    sanitize_profile(&mut main_state.manage_profile_state.current_file)?
+                } else {
+                    Vec::new()
+                };
+            main_state
+                .manage_profile_state
+                .unsupported_version_save_pending = false;
+            main_state.manage_profile_state.validation_report_state = Default::default();
+            main_state.manage_profile_state.changes_summary = None;
+            main_state.manage_profile_state.tab_badges = Default::default();
+            main_state.manage_profile_state.game_store_save_pending = false;
+            main_state
+                .manage_profile_state
+                .profile_view_state
+                .bank_state
+                .removed_all_items = None;
+            main_state.manage_profile_state.guardian_injection_state =
+                GuardianInjectionState::default();
+            main_state
+                .manage_profile_state
+                .profile_view_state
+                .bank_state
+                .item_sources =
+                ItemSources::load(&item_sources_path(main_state.config.config_dir(), profile))
+                    .unwrap_or_else(|e| {
+                        warn!("Failed to load bank item sources: {}", e);
+                        ItemSources::default()
+                    });
 
-            manage_profile::general::map_profile_to_general_state(
-                &mut main_state.manage_profile_state,
-            );
+            for tab_bar_view in [
+                ProfileTabBarView::General,
+                ProfileTabBarView::Profile,
+                ProfileTabBarView::Keys,
+                ProfileTabBarView::Bank,
+            ] {
+                manage_profile::map_profile_to_tab_state(
+                    &mut main_state.manage_profile_state,
+                    &tab_bar_view,
+                )?;
+            }
 
-            manage_profile::profile::map_profile_to_profile_state(
+            manage_profile::summary::map_profile_to_summary_state(
                 &mut main_state.manage_profile_state,
             );
 
-            manage_profile::keys::map_profile_to_keys_state(&mut main_state.manage_profile_state);
-
-            manage_profile::bank::map_profile_to_bank_state(&mut main_state.manage_profile_state)?;
-
             if mem::discriminant(&main_state.view_state)
-                != mem::discriminant(&ViewState::ManageProfile(ManageProfileView::TabBar(
-                    ProfileTabBarView::General,
-                )))
+                != mem::discriminant(&ViewState::ManageProfile(ManageProfileView::Summary))
             {
-                main_state.view_state =
-                    ViewState::ManageProfile(ManageProfileView::TabBar(ProfileTabBarView::General));
+                main_state.view_state = ViewState::ManageProfile(ManageProfileView::Summary);
             }
         }
     }
@@ -72,43 +153,226 @@ pub fn map_loaded_file_to_state(main_state: &mut Bl3Application) -> Result<()> {
     Ok(())
 }
 
+/// Finds the file to select after loading a folder, falling back to the first file when
+/// `file_name` isn't given or isn't there anymore. Files are matched by name so one that has been
+/// saved since still matches.
+pub fn file_to_select<'a>(
+    files: &'a [Bl3FileType],
+    file_name: Option<&str>,
+) -> Option<&'a Bl3FileType> {
+    file_name
+        .and_then(|file_name| files.iter().find(|f| f.filename() == file_name))
+        .or_else(|| files.first())
+}
+
+/// Maps a single save tab back to what the selected file contains, leaving the other tabs as they
+/// are.
+pub fn map_loaded_save_tab_to_state(
+    main_state: &mut Bl3Application,
+    tab_bar_view: &SaveTabBarView,
+) -> Result<()> {
+    let loaded_save = match &*main_state.loaded_files_selected {
+        Bl3FileType::PcSave(save) | Bl3FileType::Ps4Save(save) => save,
+        _ => bail!("the selected file is not a save"),
+    };
+
+    let manage_save_state = &mut main_state.manage_save_state;
+
+    // The tab mappers read from the current file, so the loaded file stands in for it while mapping
+    let current_file = mem::replace(&mut manage_save_state.current_file, loaded_save.clone());
+
+    let result = manage_save::map_save_to_tab_state(manage_save_state, tab_bar_view);
+
+    manage_save_state.current_file = current_file;
+
+    // Inventory items are matched to the file by index when saving
+    if *tab_bar_view == SaveTabBarView::Inventory {
+        manage_save_state
+            .current_file
+            .character_data
+            .set_inventory_from(&loaded_save.character_data);
+    }
+
+    result
+}
+
+/// Maps a single profile tab back to what the selected file contains, leaving the other tabs as
+/// they are.
+pub fn map_loaded_profile_tab_to_state(
+    main_state: &mut Bl3Application,
+    tab_bar_view: &ProfileTabBarView,
+) -> Result<()> {
+    let loaded_profile = match &*main_state.loaded_files_selected {
+        Bl3FileType::PcProfile(profile) | Bl3FileType::Ps4Profile(profile) => profile,
+        _ => bail!("the selected file is not a profile"),
+    };
+
+    let manage_profile_state = &mut main_state.manage_profile_state;
+
+    let current_file = mem::replace(
+        &mut manage_profile_state.current_file,
+        loaded_profile.clone(),
+    );
+
+    let result = manage_profile::map_profile_to_tab_state(manage_profile_state, tab_bar_view);
+
+    manage_profile_state.current_file = current_file;
+
+    if *tab_bar_view == ProfileTabBarView::Bank {
+        manage_profile_state
+            .current_file
+            .profile_data
+            .set_bank_from(&loaded_profile.profile_data);
+    }
+
+    result
+}
+
+/// Maps a recovery file of the selected file to every tab, the selected file stays the current
+/// file so the recovered edits show as changes that haven't been saved yet.
+pub fn map_recovered_file_to_state(main_state: &mut Bl3Application, data: &[u8]) -> Result<()> {
+    match &*main_state.loaded_files_selected {
+        Bl3FileType::PcSave(save) | Bl3FileType::Ps4Save(save) => {
+            let recovered =
+                Bl3Save::from_bytes(Path::new(&save.file_name), data, save.header_type)?;
+
+            let manage_save_state = &mut main_state.manage_save_state;
+
+            let current_file = mem::replace(&mut manage_save_state.current_file, recovered);
+
+            let result = [
+                SaveTabBarView::General,
+                SaveTabBarView::Character,
+                SaveTabBarView::Inventory,
+                SaveTabBarView::Currency,
+                SaveTabBarView::Vehicle,
+                SaveTabBarView::Missions,
+                SaveTabBarView::Challenges,
+            ]
+            .iter()
+            .try_for_each(|tab_bar_view| {
+                manage_save::map_save_to_tab_state(manage_save_state, tab_bar_view)
+            });
+
+            let recovered = mem::replace(&mut manage_save_state.current_file, current_file);
+
+            // Inventory items are matched to the file by index when saving
+            manage_save_state
+                .current_file
+                .character_data
+                .set_inventory_from(&recovered.character_data);
+
+            result
+        }
+        Bl3FileType::PcProfile(profile) | Bl3FileType::Ps4Profile(profile) => {
+            let recovered =
+                Bl3Profile::from_bytes(&profile.relative_path(), data, profile.header_type)?;
+
+            let manage_profile_state = &mut main_state.manage_profile_state;
+
+            let current_file = mem::replace(&mut manage_profile_state.current_file, recovered);
+
+            let result = [
+                ProfileTabBarView::General,
+                ProfileTabBarView::Profile,
+                ProfileTabBarView::Keys,
+                ProfileTabBarView::Bank,
+            ]
+            .iter()
+            .try_for_each(|tab_bar_view| {
+                manage_profile::map_profile_to_tab_state(manage_profile_state, tab_bar_view)
+            });
+
+            manage_profile::summary::map_profile_to_summary_state(manage_profile_state);
+
+            let recovered = mem::replace(&mut manage_profile_state.current_file, current_file);
+
+            manage_profile_state
+                .current_file
+                .profile_data
+                .set_bank_from(&recovered.profile_data);
+
+            result
+        }
+    }
+}
+
 pub async fn inject_guardian_data_into_saves(
     backup_dir: PathBuf,
     saves_dir: PathBuf,
-    guardian_rank: i32,
-    guardian_tokens: i32,
-    guardian_rewards: &[GuardianRewardData],
+    guardian_injection: GuardianInjection,
 ) -> Result<()> {
-    let (_, all_files) = choose_save_directory::load_files_in_directory(saves_dir.clone()).await?;
+    let saves_dir_clone = saves_dir.clone();
 
-    for file in all_files {
-        match file {
-            Bl3FileType::PcSave(mut s) | Bl3FileType::Ps4Save(mut s) => {
-                s.character_data
-                    .set_guardian_rank(guardian_rank, Some(guardian_tokens));
+    let (saves, guardian_injection) = tokio_rayon::spawn(move || {
+        saves_requiring_injection(&saves_dir_clone, &guardian_injection)
+            .map(|saves| (saves, guardian_injection))
+    })
+    .await?;
 
-                for g in guardian_rewards {
-                    s.character_data.set_guardian_reward(&g.reward, g.current)?;
-                }
+    for existing_save in saves {
+        let mut s = existing_save.clone();
 
-                let output_file = saves_dir.join(&s.file_name);
+        guardian_injection.apply(&mut s)?;
 
-                let (output, new_save) = s.as_bytes()?;
+        let output_file = saves_dir.join(&s.file_name);
 
-                let existing_save = s.clone();
+        let (output, new_save) = s.as_bytes()?;
 
-                interaction::file_save::save_file(
-                    backup_dir.clone(),
-                    output_file,
-                    output,
-                    existing_save,
-                    new_save,
-                )
-                .await?;
-            }
-            _ => (),
-        }
+        interaction::file_save::save_file(
+            backup_dir.clone(),
+            output_file,
+            output,
+            existing_save,
+            new_save,
+        )
+        .await?;
     }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use bl3_save_edit_core::bl3_save::Bl3Save;
+
+    use super::*;
+
+    fn save_named(file_name: &str, level: i32) -> Bl3FileType {
+        let mut save = Bl3Save {
+            file_name: file_name.to_owned(),
+            ..Bl3Save::default()
+        };
+
+        save.character_data.character.experience_points = level;
+
+        Bl3FileType::PcSave(save)
+    }
+
+    #[test]
+    fn test_file_to_select() {
+        let files = [save_named("1.sav", 1), save_named("2.sav", 1)];
+
+        assert_eq!(
+            file_to_select(&files, Some("2.sav")).unwrap().filename(),
+            "2.sav"
+        );
+        assert_eq!(
+            file_to_select(&files, Some("3.sav")).unwrap().filename(),
+            "1.sav"
+        );
+        assert_eq!(file_to_select(&files, None).unwrap().filename(), "1.sav");
+        assert!(file_to_select(&[], Some("1.sav")).is_none());
+    }
+
+    #[test]
+    fn test_file_to_select_after_resave() {
+        let files = [save_named("1.sav", 1), save_named("2.sav", 50_000)];
+
+        // The contents changed since the name was remembered but the name still matches
+        assert_eq!(
+            file_to_select(&files, Some("2.sav")).unwrap().filename(),
+            "2.sav"
+        );
+    }
+}
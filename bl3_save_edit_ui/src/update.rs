@@ -1,6 +1,7 @@
 use std::path::{Path, PathBuf};
 
 use anyhow::{bail, Context, Result};
+use futures::channel::mpsc::UnboundedSender;
 use reqwest::header::{HeaderMap, HeaderValue};
 use reqwest::{Client, ClientBuilder};
 use retry::delay::Fibonacci;
@@ -49,6 +50,7 @@ impl Release {
         &self,
         client: &Client,
         new_release_executable_path: PathBuf,
+        progress: &UnboundedSender<f32>,
     ) -> Result<()> {
         let asset = self
             .assets
@@ -56,12 +58,25 @@ impl Release {
             .find(|a| a.name == ASSET_ARCHIVE)
             .with_context(|| format!("failed to find Asset - {}", ASSET_ARCHIVE))?;
 
-        let asset_bytes = client
-            .get(&asset.browser_download_url)
-            .send()
-            .await?
-            .bytes()
-            .await?;
+        let mut response = client.get(&asset.browser_download_url).send().await?;
+
+        let total_size = response.content_length();
+
+        let mut asset_bytes = Vec::with_capacity(total_size.unwrap_or_default() as usize);
+        let mut last_progress = None;
+
+        while let Some(chunk) = response.chunk().await? {
+            asset_bytes.extend_from_slice(&chunk);
+
+            if let Some(curr_progress) = download_progress(asset_bytes.len() as u64, total_size) {
+                if last_progress != Some(curr_progress) {
+                    last_progress = Some(curr_progress);
+
+                    // The UI may have stopped listening, which shouldn't stop the download
+                    let _ = progress.unbounded_send(curr_progress);
+                }
+            }
+        }
 
         let asset_path = new_release_executable_path
             .parent()
@@ -143,6 +158,16 @@ impl Release {
     }
 }
 
+/// How much of the download is done from 0.0 to 1.0, rounded down to whole percentages so the UI
+/// isn't sent an update for every chunk. This is `None` when the server didn't send the size.
+pub fn download_progress(downloaded: u64, total_size: Option<u64>) -> Option<f32> {
+    let total_size = total_size.filter(|t| *t > 0)?;
+
+    let percent = (downloaded.min(total_size) * 100) / total_size;
+
+    Some(percent as f32 / 100.0)
+}
+
 #[derive(Debug, Deserialize, Clone)]
 pub struct ReleaseAsset {
     pub name: String,
@@ -184,7 +209,7 @@ pub async fn get_latest_release() -> Result<Release> {
     Ok(latest_release)
 }
 
-pub async fn download_release(release: Release) -> Result<()> {
+pub async fn download_release(release: Release, progress: UnboundedSender<f32>) -> Result<()> {
     info!("Downloading release: {}", release.tag_name);
 
     #[cfg(target_os = "windows")]
@@ -214,7 +239,11 @@ pub async fn download_release(release: Release) -> Result<()> {
     let client = create_download_client()?;
 
     release
-        .download_asset(&client, new_release_executable_path.to_path_buf())
+        .download_asset(
+            &client,
+            new_release_executable_path.to_path_buf(),
+            &progress,
+        )
         .await?;
 
     #[cfg(not(target_os = "windows"))]
@@ -315,3 +344,23 @@ where
     )
     .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e.error))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_download_progress() {
+        assert_eq!(download_progress(0, Some(200)), Some(0.0));
+        assert_eq!(download_progress(101, Some(200)), Some(0.5));
+        assert_eq!(download_progress(199, Some(200)), Some(0.99));
+        assert_eq!(download_progress(200, Some(200)), Some(1.0));
+        assert_eq!(download_progress(300, Some(200)), Some(1.0));
+    }
+
+    #[test]
+    fn test_download_progress_unknown_size() {
+        assert_eq!(download_progress(100, None), None);
+        assert_eq!(download_progress(100, Some(0)), None);
+    }
+}
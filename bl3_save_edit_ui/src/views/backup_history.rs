@@ -0,0 +1,329 @@
+use std::path::PathBuf;
+
+use chrono::{DateTime, Local};
+use iced::alignment::{Horizontal, Vertical};
+use iced::{
+    button, scrollable, text_input, Alignment, Button, Color, Column, Container, Length, Row,
+    Scrollable, Text,
+};
+use strum::Display;
+
+use crate::bl3_ui::{Bl3Message, InteractionMessage, MessageResult};
+use crate::bl3_ui_style::{Bl3UiStyle, Bl3UiStyleNoBorder};
+use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
+use crate::views::settings::SettingsInteractionMessage;
+use crate::views::{InteractionExt, NO_SEARCH_RESULTS_FOUND_MESSAGE};
+use crate::widgets::text_input_limited::TextInputLimited;
+
+#[derive(Debug, Default)]
+pub struct BackupHistoryState {
+    pub backups: Vec<BackupEntry>,
+    pub filter: BackupFilterState,
+    pub is_loading: bool,
+    pub search_input_state: text_input::State,
+    pub refresh_button_state: button::State,
+    pub sort_date_button_state: button::State,
+    pub sort_level_button_state: button::State,
+    pub sort_money_button_state: button::State,
+    pub sort_file_size_button_state: button::State,
+    pub scrollable_state: scrollable::State,
+}
+
+#[derive(Debug, Clone)]
+pub struct BackupEntry {
+    pub path: PathBuf,
+    pub file_name: String,
+    pub character_name: String,
+    pub level: Option<i32>,
+    pub money: Option<i32>,
+    pub file_size: u64,
+    pub modified: DateTime<Local>,
+    pub is_snapshot: bool,
+}
+
+#[derive(Debug, Display, Copy, Clone, Eq, PartialEq, Default)]
+pub enum BackupSortField {
+    #[default]
+    Date,
+    Level,
+    Money,
+    #[strum(to_string = "Size")]
+    FileSize,
+}
+
+#[derive(Debug, Default)]
+pub struct BackupFilterState {
+    pub search: String,
+    pub sort: BackupSortField,
+    pub ascending: bool,
+}
+
+impl BackupFilterState {
+    pub fn sort_pressed(&mut self, sort: BackupSortField) {
+        if self.sort == sort {
+            self.ascending = !self.ascending;
+        } else {
+            self.sort = sort;
+            self.ascending = false;
+        }
+    }
+
+    pub fn filtered_and_sorted<'a>(&self, backups: &'a [BackupEntry]) -> Vec<&'a BackupEntry> {
+        let search = self.search.trim();
+
+        let mut backups = backups
+            .iter()
+            .filter(|b| search.is_empty() || b.character_name.to_lowercase().contains(search))
+            .collect::<Vec<_>>();
+
+        backups.sort_by(|a, b| {
+            let ordering = match self.sort {
+                BackupSortField::Date => a.modified.cmp(&b.modified),
+                BackupSortField::Level => a.level.cmp(&b.level),
+                BackupSortField::Money => a.money.cmp(&b.money),
+                BackupSortField::FileSize => a.file_size.cmp(&b.file_size),
+            }
+            .then_with(|| a.modified.cmp(&b.modified));
+
+            if self.ascending {
+                ordering
+            } else {
+                ordering.reverse()
+            }
+        });
+
+        backups
+    }
+
+    fn header_title(&self, sort: BackupSortField) -> String {
+        match (self.sort == sort, self.ascending) {
+            (true, true) => format!("{} ▲", sort),
+            (true, false) => format!("{} ▼", sort),
+            (false, _) => sort.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum BackupHistoryInteractionMessage {
+    RefreshPressed,
+    BackupsLoaded(MessageResult<Vec<BackupEntry>>),
+    SearchInputChanged(String),
+    SortPressed(BackupSortField),
+}
+
+fn backup_history_message(message: BackupHistoryInteractionMessage) -> InteractionMessage {
+    InteractionMessage::SettingsInteraction(SettingsInteractionMessage::BackupHistory(message))
+}
+
+fn header_text(title: String) -> Text {
+    Text::new(title)
+        .font(JETBRAINS_MONO_BOLD)
+        .size(17)
+        .color(Color::from_rgb8(242, 203, 5))
+}
+
+fn sort_header_button<'a>(
+    state: &'a mut button::State,
+    filter: &BackupFilterState,
+    sort: BackupSortField,
+) -> Button<'a, InteractionMessage> {
+    Button::new(state, header_text(filter.header_title(sort)))
+        .on_press(backup_history_message(
+            BackupHistoryInteractionMessage::SortPressed(sort),
+        ))
+        .padding(5)
+        .style(Bl3UiStyle)
+}
+
+fn row_text<'a, T: Into<String>>(text: T, portion: u16) -> Container<'a, Bl3Message> {
+    Container::new(
+        Text::new(text)
+            .font(JETBRAINS_MONO)
+            .size(16)
+            .color(Color::from_rgb8(220, 220, 220)),
+    )
+    .width(Length::FillPortion(portion))
+}
+
+fn backup_rows<'a>(backups: &[&BackupEntry]) -> Column<'a, Bl3Message> {
+    backups.iter().fold(Column::new(), |curr, backup| {
+        curr.push(
+            Container::new(
+                Row::new()
+                    .push(row_text(
+                        backup.modified.format("%d-%m-%Y %H:%M:%S").to_string(),
+                        3,
+                    ))
+                    .push(row_text(backup.character_name.clone(), 4))
+                    .push(row_text(
+                        backup.level.map(|l| l.to_string()).unwrap_or_default(),
+                        1,
+                    ))
+                    .push(row_text(
+                        backup.money.map(|m| m.to_string()).unwrap_or_default(),
+                        2,
+                    ))
+                    .push(row_text(
+                        format!("{:.1} KB", backup.file_size as f64 / 1024.0),
+                        1,
+                    ))
+                    .spacing(10)
+                    .align_items(Alignment::Center),
+            )
+            .width(Length::Fill)
+            .padding(8)
+            .style(Bl3UiStyleNoBorder),
+        )
+    })
+}
+
+pub fn view(backup_history_state: &mut BackupHistoryState) -> Container<Bl3Message> {
+    let filter = &backup_history_state.filter;
+
+    let search_input = TextInputLimited::new(
+        &mut backup_history_state.search_input_state,
+        "Search backups by character name...",
+        &filter.search,
+        500,
+        |s| backup_history_message(BackupHistoryInteractionMessage::SearchInputChanged(s)),
+    )
+    .0
+    .font(JETBRAINS_MONO)
+    .padding(10)
+    .size(17)
+    .style(Bl3UiStyle)
+    .into_element();
+
+    let mut refresh_button = Button::new(
+        &mut backup_history_state.refresh_button_state,
+        Text::new(if backup_history_state.is_loading {
+            "Loading..."
+        } else {
+            "Load Backups"
+        })
+        .font(JETBRAINS_MONO_BOLD)
+        .size(17),
+    )
+    .padding(10)
+    .style(Bl3UiStyle);
+
+    if !backup_history_state.is_loading {
+        refresh_button = refresh_button.on_press(backup_history_message(
+            BackupHistoryInteractionMessage::RefreshPressed,
+        ));
+    }
+
+    let search_row = Row::new()
+        .push(search_input)
+        .push(refresh_button.into_element())
+        .spacing(20)
+        .align_items(Alignment::Center);
+
+    let header_row = Row::new()
+        .push(
+            Container::new(
+                sort_header_button(
+                    &mut backup_history_state.sort_date_button_state,
+                    filter,
+                    BackupSortField::Date,
+                )
+                .into_element(),
+            )
+            .width(Length::FillPortion(3)),
+        )
+        .push(Container::new(header_text("Character".to_owned())).width(Length::FillPortion(4)))
+        .push(
+            Container::new(
+                sort_header_button(
+                    &mut backup_history_state.sort_level_button_state,
+                    filter,
+                    BackupSortField::Level,
+                )
+                .into_element(),
+            )
+            .width(Length::FillPortion(1)),
+        )
+        .push(
+            Container::new(
+                sort_header_button(
+                    &mut backup_history_state.sort_money_button_state,
+                    filter,
+                    BackupSortField::Money,
+                )
+                .into_element(),
+            )
+            .width(Length::FillPortion(2)),
+        )
+        .push(
+            Container::new(
+                sort_header_button(
+                    &mut backup_history_state.sort_file_size_button_state,
+                    filter,
+                    BackupSortField::FileSize,
+                )
+                .into_element(),
+            )
+            .width(Length::FillPortion(1)),
+        )
+        .spacing(10)
+        .align_items(Alignment::Center);
+
+    let backups = filter.filtered_and_sorted(&backup_history_state.backups);
+
+    let backup_list: Container<Bl3Message> = if backups.is_empty() {
+        let message = if backup_history_state.backups.is_empty() {
+            "No backups loaded."
+        } else {
+            NO_SEARCH_RESULTS_FOUND_MESSAGE
+        };
+
+        Container::new(
+            Text::new(message)
+                .font(JETBRAINS_MONO_BOLD)
+                .size(17)
+                .color(Color::from_rgb8(220, 220, 220)),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .align_x(Horizontal::Center)
+        .align_y(Vertical::Center)
+    } else {
+        // Snapshots are taken on purpose so they are kept apart from the backups made when saving
+        let (snapshots, backups): (Vec<_>, Vec<_>) =
+            backups.into_iter().partition(|b| b.is_snapshot);
+
+        let mut rows = Column::new().spacing(10);
+
+        if !snapshots.is_empty() {
+            rows = rows
+                .push(header_text(format!("Snapshots ({})", snapshots.len())))
+                .push(backup_rows(&snapshots));
+        }
+
+        if !backups.is_empty() {
+            rows = rows
+                .push(header_text(format!("Backups ({})", backups.len())))
+                .push(backup_rows(&backups));
+        }
+
+        Container::new(
+            Scrollable::new(&mut backup_history_state.scrollable_state)
+                .push(rows)
+                .height(Length::Fill),
+        )
+        .padding(1)
+    };
+
+    let all_contents = Column::new()
+        .push(search_row)
+        .push(header_row)
+        .push(backup_list)
+        .spacing(15);
+
+    Container::new(all_contents)
+        .padding(15)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .style(Bl3UiStyle)
+}
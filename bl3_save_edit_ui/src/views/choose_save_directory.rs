@@ -1,18 +1,20 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use iced::alignment::{Horizontal, Vertical};
-use iced::{button, Alignment, Button, Color, Column, Container, Length, Text};
+use iced::{button, Alignment, Button, Color, Column, Container, Element, Length, Text};
 
 use bl3_save_edit_core::file_helper::Bl3FileType;
 
 use crate::bl3_ui::{Bl3Message, InteractionMessage, MessageResult};
 use crate::bl3_ui_style::Bl3UiStyle;
+use crate::config::MAX_RECENT_SAVE_DIRS;
 use crate::resources::fonts::JETBRAINS_MONO;
 use crate::views::InteractionExt;
 
 #[derive(Debug, Default)]
 pub struct ChooseSaveDirectoryState {
     choose_dir_button_state: button::State,
+    recent_dir_button_states: [button::State; MAX_RECENT_SAVE_DIRS],
     pub choose_dir_window_open: bool,
 }
 
@@ -25,9 +27,36 @@ pub enum ChooseSaveMessage {
 #[derive(Debug, Clone)]
 pub enum ChooseSaveInteractionMessage {
     ChooseDirPressed,
+    RecentDirPressed(PathBuf),
 }
 
-pub fn view(choose_save_directory_state: &mut ChooseSaveDirectoryState) -> Container<Bl3Message> {
+fn recent_dir_button<'a>(
+    button_state: &'a mut button::State,
+    dir: &Path,
+    choose_dir_window_open: bool,
+) -> Element<'a, Bl3Message> {
+    let mut button = Button::new(
+        button_state,
+        Text::new(dir.to_string_lossy())
+            .font(JETBRAINS_MONO)
+            .size(16),
+    )
+    .padding(10)
+    .style(Bl3UiStyle);
+
+    if !choose_dir_window_open {
+        button = button.on_press(InteractionMessage::ChooseSaveInteraction(
+            ChooseSaveInteractionMessage::RecentDirPressed(dir.to_path_buf()),
+        ));
+    }
+
+    button.into_element()
+}
+
+pub fn view<'a>(
+    choose_save_directory_state: &'a mut ChooseSaveDirectoryState,
+    recent_save_dirs: &[PathBuf],
+) -> Container<'a, Bl3Message> {
     let dir_button_text = Text::new("Select Borderlands 3 Save/Profile folder")
         .font(JETBRAINS_MONO)
         .size(20)
@@ -49,12 +78,39 @@ pub fn view(choose_save_directory_state: &mut ChooseSaveDirectoryState) -> Conta
         ));
     }
 
-    let contents = Column::new()
+    let mut contents = Column::new()
         .push(dir_button_text)
         .push(dir_button.into_element())
         .spacing(20)
         .align_items(Alignment::Center);
 
+    if !recent_save_dirs.is_empty() {
+        let choose_dir_window_open = choose_save_directory_state.choose_dir_window_open;
+
+        let recent_dirs = recent_save_dirs
+            .iter()
+            .zip(
+                choose_save_directory_state
+                    .recent_dir_button_states
+                    .iter_mut(),
+            )
+            .fold(
+                Column::new().spacing(10).align_items(Alignment::Center),
+                |curr, (dir, button_state)| {
+                    curr.push(recent_dir_button(button_state, dir, choose_dir_window_open))
+                },
+            );
+
+        contents = contents
+            .push(
+                Text::new("Recent folders")
+                    .font(JETBRAINS_MONO)
+                    .size(17)
+                    .color(Color::from_rgb8(242, 203, 5)),
+            )
+            .push(recent_dirs);
+    }
+
     Container::new(contents)
         .width(Length::Fill)
         .height(Length::Fill)
@@ -0,0 +1,41 @@
+use iced::{Color, Column, Container, Length, Text};
+
+use bl3_save_edit_core::file_helper::FileMetadata;
+
+use crate::bl3_ui::Bl3Message;
+use crate::bl3_ui_style::Bl3UiStyle;
+use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
+
+/// Read only details of the game build that wrote the file.
+pub fn view<'a>(metadata: &FileMetadata) -> Container<'a, Bl3Message> {
+    let details = [
+        ("Save Game Version", metadata.save_game_version.to_string()),
+        ("Package Version", metadata.package_version.to_string()),
+        ("Engine Version", metadata.engine_version.clone()),
+        ("Build ID", metadata.build_id.clone()),
+        ("Read As", metadata.header_type.to_string()),
+        ("File Size", metadata.file_size_text()),
+    ];
+
+    let contents = details.into_iter().fold(
+        Column::new().push(
+            Text::new("File Details")
+                .font(JETBRAINS_MONO_BOLD)
+                .size(17)
+                .color(Color::from_rgb8(242, 203, 5)),
+        ),
+        |column, (label, value)| {
+            column.push(
+                Text::new(format!("{}: {}", label, value))
+                    .font(JETBRAINS_MONO)
+                    .size(17)
+                    .color(Color::from_rgb8(220, 220, 220)),
+            )
+        },
+    );
+
+    Container::new(contents.spacing(10))
+        .padding(15)
+        .width(Length::Fill)
+        .style(Bl3UiStyle)
+}
@@ -0,0 +1,235 @@
+use std::path::{Path, PathBuf};
+
+use iced::alignment::{Horizontal, Vertical};
+use iced::{button, Alignment, Button, Color, Column, Container, Length, Row, Text};
+
+use crate::bl3_ui::{Bl3Message, InteractionMessage, MessageResult};
+use crate::bl3_ui_style::{Bl3UiPositiveButtonStyle, Bl3UiStyle};
+use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
+use crate::views::InteractionExt;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum FirstRunWizardStep {
+    #[default]
+    Welcome,
+    SavesDir,
+    BackupDir,
+}
+
+impl FirstRunWizardStep {
+    fn number(&self) -> usize {
+        match self {
+            FirstRunWizardStep::Welcome => 1,
+            FirstRunWizardStep::SavesDir => 2,
+            FirstRunWizardStep::BackupDir => 3,
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        match self {
+            FirstRunWizardStep::Welcome => FirstRunWizardStep::SavesDir,
+            FirstRunWizardStep::SavesDir | FirstRunWizardStep::BackupDir => {
+                FirstRunWizardStep::BackupDir
+            }
+        }
+    }
+
+    pub fn previous(&self) -> Self {
+        match self {
+            FirstRunWizardStep::Welcome | FirstRunWizardStep::SavesDir => {
+                FirstRunWizardStep::Welcome
+            }
+            FirstRunWizardStep::BackupDir => FirstRunWizardStep::SavesDir,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct FirstRunWizardState {
+    pub step: FirstRunWizardStep,
+    pub saves_dir: PathBuf,
+    pub backup_dir: PathBuf,
+    pub choose_dir_window_open: bool,
+    back_button_state: button::State,
+    next_button_state: button::State,
+    use_default_dir_button_state: button::State,
+    choose_dir_button_state: button::State,
+}
+
+#[derive(Debug, Clone)]
+pub enum FirstRunWizardInteractionMessage {
+    BackPressed,
+    NextPressed,
+    UseDefaultSavesDirPressed,
+    ChooseSavesDirPressed,
+    ChooseSavesDirCompleted(MessageResult<PathBuf>),
+    UseDefaultBackupDirPressed,
+    ChooseBackupDirPressed,
+    ChooseBackupDirCompleted(MessageResult<PathBuf>),
+    FinishPressed,
+}
+
+fn wizard_message(message: FirstRunWizardInteractionMessage) -> InteractionMessage {
+    InteractionMessage::FirstRunWizardInteraction(message)
+}
+
+fn description(text: &str) -> Text {
+    Text::new(text)
+        .font(JETBRAINS_MONO)
+        .size(17)
+        .color(Color::from_rgb8(220, 220, 220))
+}
+
+fn dir_text(dir: &Path) -> Text {
+    let dir = if dir.as_os_str().is_empty() {
+        "No folder selected".to_owned()
+    } else {
+        dir.to_string_lossy().to_string()
+    };
+
+    Text::new(dir)
+        .font(JETBRAINS_MONO_BOLD)
+        .size(17)
+        .color(Color::from_rgb8(242, 203, 5))
+}
+
+fn dir_buttons<'a>(
+    use_default_dir_button_state: &'a mut button::State,
+    choose_dir_button_state: &'a mut button::State,
+    choose_dir_window_open: bool,
+    use_default_message: FirstRunWizardInteractionMessage,
+    choose_message: FirstRunWizardInteractionMessage,
+) -> Row<'a, Bl3Message> {
+    let use_default_button = Button::new(
+        use_default_dir_button_state,
+        Text::new("Use Default").font(JETBRAINS_MONO_BOLD).size(17),
+    )
+    .on_press(wizard_message(use_default_message))
+    .padding(10)
+    .style(Bl3UiStyle);
+
+    let mut choose_button = Button::new(
+        choose_dir_button_state,
+        Text::new("Select...").font(JETBRAINS_MONO_BOLD).size(17),
+    )
+    .padding(10)
+    .style(Bl3UiStyle);
+
+    if !choose_dir_window_open {
+        choose_button = choose_button.on_press(wizard_message(choose_message));
+    }
+
+    Row::new()
+        .push(use_default_button.into_element())
+        .push(choose_button.into_element())
+        .spacing(15)
+        .align_items(Alignment::Center)
+}
+
+pub fn view(first_run_wizard_state: &mut FirstRunWizardState) -> Container<Bl3Message> {
+    let step = first_run_wizard_state.step;
+
+    let title = Text::new(format!("Setup - Step {} of 3", step.number()))
+        .font(JETBRAINS_MONO_BOLD)
+        .size(24)
+        .color(Color::from_rgb8(242, 203, 5));
+
+    let (step_contents, can_continue) = match step {
+        FirstRunWizardStep::Welcome => (
+            Column::new()
+                .push(description(
+                    "This editor lets you change your Borderlands 3 characters and profile: levels, skills, currency, items, vehicles and more.",
+                ))
+                .push(description(
+                    "A backup is made every time you save a file, so you can always go back to how it was before.",
+                ))
+                .push(description(
+                    "The next steps will help you find your saves and choose where backups are kept.",
+                ))
+                .spacing(15),
+            true,
+        ),
+        FirstRunWizardStep::SavesDir => (
+            Column::new()
+                .push(description(
+                    "Choose the folder that contains your save and profile files. Use Default will look in the folder the game uses on this computer.",
+                ))
+                .push(dir_text(&first_run_wizard_state.saves_dir))
+                .push(dir_buttons(
+                    &mut first_run_wizard_state.use_default_dir_button_state,
+                    &mut first_run_wizard_state.choose_dir_button_state,
+                    first_run_wizard_state.choose_dir_window_open,
+                    FirstRunWizardInteractionMessage::UseDefaultSavesDirPressed,
+                    FirstRunWizardInteractionMessage::ChooseSavesDirPressed,
+                ))
+                .spacing(15),
+            first_run_wizard_state.saves_dir.is_dir(),
+        ),
+        FirstRunWizardStep::BackupDir => (
+            Column::new()
+                .push(description(
+                    "Choose where backups of your files are kept. They can be restored later from the Settings tab.",
+                ))
+                .push(dir_text(&first_run_wizard_state.backup_dir))
+                .push(dir_buttons(
+                    &mut first_run_wizard_state.use_default_dir_button_state,
+                    &mut first_run_wizard_state.choose_dir_button_state,
+                    first_run_wizard_state.choose_dir_window_open,
+                    FirstRunWizardInteractionMessage::UseDefaultBackupDirPressed,
+                    FirstRunWizardInteractionMessage::ChooseBackupDirPressed,
+                ))
+                .spacing(15),
+            first_run_wizard_state.backup_dir != PathBuf::default(),
+        ),
+    };
+
+    let mut navigation = Row::new().spacing(15).align_items(Alignment::Center);
+
+    if step != FirstRunWizardStep::Welcome {
+        navigation = navigation.push(
+            Button::new(
+                &mut first_run_wizard_state.back_button_state,
+                Text::new("Back").font(JETBRAINS_MONO_BOLD).size(17),
+            )
+            .on_press(wizard_message(
+                FirstRunWizardInteractionMessage::BackPressed,
+            ))
+            .padding(10)
+            .style(Bl3UiStyle)
+            .into_element(),
+        );
+    }
+
+    let (next_text, next_message) = if step == FirstRunWizardStep::BackupDir {
+        ("Finish", FirstRunWizardInteractionMessage::FinishPressed)
+    } else {
+        ("Next", FirstRunWizardInteractionMessage::NextPressed)
+    };
+
+    let mut next_button = Button::new(
+        &mut first_run_wizard_state.next_button_state,
+        Text::new(next_text).font(JETBRAINS_MONO_BOLD).size(17),
+    )
+    .padding(10)
+    .style(Bl3UiPositiveButtonStyle);
+
+    if can_continue {
+        next_button = next_button.on_press(wizard_message(next_message));
+    }
+
+    navigation = navigation.push(next_button.into_element());
+
+    let contents = Column::new()
+        .push(title)
+        .push(step_contents)
+        .push(navigation)
+        .spacing(30)
+        .max_width(800)
+        .align_items(Alignment::Start);
+
+    Container::new(Container::new(contents).padding(30).style(Bl3UiStyle))
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .align_x(Horizontal::Center)
+        .align_y(Vertical::Center)
+}
@@ -1,12 +1,18 @@
+use std::path::PathBuf;
+
 use iced::alignment::{Horizontal, Vertical};
 use iced::{Color, Container, Length, Text};
 
-use crate::bl3_ui::Bl3Message;
+use bl3_save_edit_core::file_helper::Bl3FileType;
+
+use crate::bl3_ui::{Bl3Message, MessageResult};
 use crate::resources::fonts::JETBRAINS_MONO;
 
 #[derive(Debug, Clone)]
 pub enum InitializationMessage {
     LoadSaves,
+    LoadSaveFile(PathBuf, String),
+    SaveFileLoaded(String, MessageResult<(PathBuf, Vec<Bl3FileType>)>),
 }
 
 pub fn view<'a>() -> Container<'a, Bl3Message> {
@@ -1,11 +1,15 @@
+use std::collections::BTreeSet;
+
 use iced::{
-    button, searchable_pick_list, text_input, tooltip, Alignment, Column, Container, Length, Row,
-    SearchablePickList, TextInput, Tooltip,
+    button, searchable_pick_list, text_input, tooltip, Alignment, Checkbox, Color, Column,
+    Container, Length, Row, SearchablePickList, Text, TextInput, Tooltip,
 };
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 
 use bl3_save_edit_core::bl3_item::{BalancePart, Bl3Item, InvDataPart, ManufacturerPart};
 use bl3_save_edit_core::bl3_save::character_data::MAX_CHARACTER_LEVEL;
+use bl3_save_edit_core::content_source::ContentSource;
+use bl3_save_edit_core::favorite_anointments::FavoriteAnointment;
 use bl3_save_edit_core::resources::{
     INVENTORY_BALANCE_PARTS, INVENTORY_INV_DATA_PARTS, INVENTORY_MANUFACTURER_PARTS,
     INVENTORY_PARTS_ALL_CATEGORIZED, INVENTORY_SERIAL_DB_PARTS_CATEGORIZED,
@@ -13,9 +17,9 @@ use bl3_save_edit_core::resources::{
 
 use crate::bl3_ui::{Bl3Message, InteractionMessage};
 use crate::bl3_ui_style::{Bl3UiStyle, Bl3UiTooltipStyle};
-use crate::resources::fonts::JETBRAINS_MONO;
+use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
 use crate::views::item_editor::parts::available_parts::AvailableParts;
-use crate::views::item_editor::parts::current_parts::CurrentParts;
+use crate::views::item_editor::parts::current_parts::{CurrentParts, DecodedItemParts};
 use crate::views::item_editor::ItemEditorInteractionMessage;
 use crate::views::{InteractionExt, NO_SEARCH_RESULTS_FOUND_MESSAGE};
 use crate::widgets::labelled_element::LabelledElement;
@@ -30,9 +34,9 @@ pub struct Editor {
     pub serial_input_state: text_input::State,
     pub delete_item_button_state: button::State,
     pub duplicate_item_button_state: button::State,
-    pub balance_input_state: searchable_pick_list::State<BalancePart>,
+    pub balance_input_state: searchable_pick_list::State<BalancePartOption>,
     pub balance_search_input: String,
-    pub balance_parts_list: Vec<BalancePart>,
+    pub balance_parts_list: Vec<BalancePartOption>,
     pub balance_input_selected: BalancePart,
     pub inv_data_input_state: searchable_pick_list::State<InvDataPart>,
     pub inv_data_search_input: String,
@@ -46,8 +50,43 @@ pub struct Editor {
     pub current_parts: CurrentParts,
 }
 
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct BalancePartOption {
+    pub part: BalancePart,
+    is_owned: bool,
+}
+
+impl BalancePartOption {
+    pub fn new(part: BalancePart, owned_dlc: &[ContentSource]) -> Self {
+        let is_owned = part.content_source().is_owned(owned_dlc);
+
+        Self { part, is_owned }
+    }
+}
+
+impl std::fmt::Display for BalancePartOption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.part)?;
+
+        if !self.is_owned {
+            write!(f, " (Not Owned)")?;
+        }
+
+        Ok(())
+    }
+}
+
 impl Editor {
-    pub fn view<F>(&mut self, item: &Bl3Item, interaction_message: F) -> Container<Bl3Message>
+    pub fn view<F>(
+        &mut self,
+        item: &Bl3Item,
+        locked_parts: &BTreeSet<String>,
+        decoded_parts: Option<&DecodedItemParts>,
+        owned_dlc: &[ContentSource],
+        favorite_anointments: &[FavoriteAnointment],
+        keep_mayhem_level: bool,
+        interaction_message: F,
+    ) -> Container<Bl3Message>
     where
         F: Fn(ItemEditorInteractionMessage) -> InteractionMessage + 'static + Copy,
     {
@@ -71,39 +110,88 @@ impl Editor {
             .map(|ip| ip.part_inv_key.as_str())
             .and_then(|p| inventory_serial_db_parts_categorized.get(p));
 
-        let item_level_editor = Row::new()
-            .push(
-                LabelledElement::create(
-                    "Level",
-                    Length::Units(60),
-                    Tooltip::new(
-                        NumberInput::new(
-                            &mut self.item_level_input_state,
-                            self.item_level_input,
-                            1,
-                            Some(MAX_CHARACTER_LEVEL as i32),
-                            move |v| {
-                                interaction_message(ItemEditorInteractionMessage::ItemLevel(v))
-                            },
-                        )
-                        .0
-                        .font(JETBRAINS_MONO)
-                        .padding(10)
-                        .size(17)
-                        .style(Bl3UiStyle)
-                        .into_element(),
-                        format!("Level must be between 1 and {}", MAX_CHARACTER_LEVEL),
-                        tooltip::Position::Top,
+        let mut item_level_editor = Row::new().push(
+            LabelledElement::create(
+                "Level",
+                Length::Units(60),
+                Tooltip::new(
+                    NumberInput::new(
+                        &mut self.item_level_input_state,
+                        self.item_level_input,
+                        1,
+                        Some(MAX_CHARACTER_LEVEL as i32),
+                        move |v| interaction_message(ItemEditorInteractionMessage::ItemLevel(v)),
                     )
-                    .gap(10)
-                    .padding(10)
+                    .0
                     .font(JETBRAINS_MONO)
+                    .padding(10)
+                    .size(17)
+                    .style(Bl3UiStyle)
+                    .into_element(),
+                    format!("Level must be between 1 and {}", MAX_CHARACTER_LEVEL),
+                    tooltip::Position::Top,
+                )
+                .gap(10)
+                .padding(10)
+                .font(JETBRAINS_MONO)
+                .size(17)
+                .style(Bl3UiTooltipStyle),
+            )
+            .spacing(15)
+            .width(Length::FillPortion(9))
+            .align_items(Alignment::Center),
+        );
+
+        if let Some(mayhem_level) = item.mayhem_level() {
+            item_level_editor = item_level_editor.push(
+                Container::new(
+                    Text::new(format!("(M{})", mayhem_level))
+                        .font(JETBRAINS_MONO_BOLD)
+                        .size(17)
+                        .color(Color::from_rgb8(242, 203, 5)),
+                )
+                .padding(10),
+            );
+        }
+
+        let item_level_editor = item_level_editor
+            .push(
+                Tooltip::new(
+                    Checkbox::new(keep_mayhem_level, "Keep Mayhem", move |c| {
+                        interaction_message(ItemEditorInteractionMessage::KeepMayhemLevelToggled(c))
+                    })
                     .size(17)
-                    .style(Bl3UiTooltipStyle),
+                    .font(JETBRAINS_MONO)
+                    .text_color(Color::from_rgb8(220, 220, 220))
+                    .text_size(17)
+                    .style(Bl3UiStyle)
+                    .into_element(),
+                    "Keep the mayhem level of items when changing their level",
+                    tooltip::Position::Top,
                 )
-                .spacing(15)
-                .width(Length::FillPortion(9))
-                .align_items(Alignment::Center),
+                .gap(10)
+                .padding(10)
+                .font(JETBRAINS_MONO)
+                .size(17)
+                .style(Bl3UiTooltipStyle),
+            )
+            .push(
+                Tooltip::new(
+                    Container::new(
+                        Text::new(format!("{} bytes", item.encoded_len()))
+                            .font(JETBRAINS_MONO)
+                            .size(16)
+                            .color(Color::from_rgb8(220, 220, 220)),
+                    )
+                    .padding(10),
+                    "Length of the item's encrypted serial",
+                    tooltip::Position::Top,
+                )
+                .gap(10)
+                .padding(10)
+                .font(JETBRAINS_MONO)
+                .size(17)
+                .style(Bl3UiTooltipStyle),
             )
             .align_items(Alignment::Center);
 
@@ -153,7 +241,7 @@ impl Editor {
                             .map(|n| n.to_lowercase().contains(balance_search_query))
                             .unwrap_or(false)
                 })
-                .cloned()
+                .map(|i| BalancePartOption::new(i.clone(), owned_dlc))
                 .collect::<Vec<_>>();
 
             if !filtered_results.is_empty() {
@@ -162,7 +250,10 @@ impl Editor {
                 self.balance_parts_list.clear();
             }
         } else {
-            self.balance_parts_list = INVENTORY_BALANCE_PARTS.to_vec();
+            self.balance_parts_list = INVENTORY_BALANCE_PARTS
+                .par_iter()
+                .map(|i| BalancePartOption::new(i.clone(), owned_dlc))
+                .collect();
         }
 
         // Inventory Data search
@@ -203,41 +294,74 @@ impl Editor {
             self.manufacturer_parts_list = INVENTORY_MANUFACTURER_PARTS.to_vec();
         }
 
+        let selected_balance_source = self.balance_input_selected.content_source();
+
+        let balance_picker = SearchablePickList::new(
+            &mut self.balance_input_state,
+            &format!("Search {} Balance Parts...", self.inv_data_parts_list.len()),
+            &self.balance_search_input,
+            Some(BalancePartOption::new(
+                self.balance_input_selected.clone(),
+                owned_dlc,
+            )),
+            &self.balance_parts_list[..],
+            move |s| {
+                interaction_message(ItemEditorInteractionMessage::BalanceSearchInputChanged(s))
+            },
+            move |s: BalancePartOption| {
+                interaction_message(ItemEditorInteractionMessage::BalanceInputSelected(s.part))
+            },
+        )
+        .options_empty_message(NO_SEARCH_RESULTS_FOUND_MESSAGE.to_owned())
+        .font(JETBRAINS_MONO)
+        .size(16)
+        .padding(10)
+        .style(Bl3UiStyle)
+        .width(Length::Fill);
+
+        let balance_picker = if selected_balance_source.is_owned(owned_dlc) {
+            balance_picker.into_element()
+        } else {
+            Tooltip::new(
+                balance_picker,
+                format!(
+                    "This item is from {} which is not selected as owned in Settings, it will be invisible in-game without it",
+                    selected_balance_source
+                ),
+                tooltip::Position::Top,
+            )
+            .gap(10)
+            .padding(10)
+            .font(JETBRAINS_MONO)
+            .size(17)
+            .style(Bl3UiTooltipStyle)
+            .into_element()
+        };
+
+        let item_name = item
+            .display_name()
+            .unwrap_or(&item.balance_part().ident)
+            .to_owned();
+
+        let item_name_header = Container::new(
+            Text::new(item_name)
+                .font(JETBRAINS_MONO_BOLD)
+                .size(20)
+                .color(Color::from_rgb8(242, 203, 5)),
+        )
+        .padding(10)
+        .width(Length::Fill)
+        .style(Bl3UiStyle);
+
         let item_editor_contents = Column::new()
+            .push(item_name_header)
             .push(level_serial_delete_row)
             .push(
                 Container::new(
-                    LabelledElement::create(
-                        "Balance",
-                        Length::Units(130),
-                        SearchablePickList::new(
-                            &mut self.balance_input_state,
-                            &format!("Search {} Balance Parts...", self.inv_data_parts_list.len()),
-                            &self.balance_search_input,
-                            Some(self.balance_input_selected.clone()),
-                            &self.balance_parts_list[..],
-                            move |s| {
-                                interaction_message(
-                                    ItemEditorInteractionMessage::BalanceSearchInputChanged(s),
-                                )
-                            },
-                            move |s| {
-                                interaction_message(
-                                    ItemEditorInteractionMessage::BalanceInputSelected(s),
-                                )
-                            },
-                        )
-                        .options_empty_message(NO_SEARCH_RESULTS_FOUND_MESSAGE.to_owned())
-                        .font(JETBRAINS_MONO)
-                        .size(16)
-                        .padding(10)
-                        .style(Bl3UiStyle)
+                    LabelledElement::create("Balance", Length::Units(130), balance_picker)
+                        .spacing(15)
                         .width(Length::Fill)
-                        .into_element(),
-                    )
-                    .spacing(15)
-                    .width(Length::Fill)
-                    .align_items(Alignment::Center),
+                        .align_items(Alignment::Center),
                 )
                 .style(Bl3UiStyle),
             )
@@ -326,16 +450,14 @@ impl Editor {
             anointments_list,
             specific_parts_list,
             all_parts_list,
+            owned_dlc,
+            favorite_anointments,
             interaction_message,
         );
 
-        let current_parts_contents = self.current_parts.view(
-            item,
-            anointments_list,
-            specific_parts_list,
-            all_parts_list,
-            interaction_message,
-        );
+        let current_parts_contents =
+            self.current_parts
+                .view(item, locked_parts, decoded_parts, interaction_message);
 
         let parts_editor_contents = Container::new(
             Row::new()
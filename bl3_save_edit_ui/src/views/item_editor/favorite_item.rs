@@ -0,0 +1,127 @@
+use iced::alignment::Horizontal;
+use iced::{
+    button, tooltip, Alignment, Button, Color, Container, Element, Length, Row, Text, Tooltip,
+};
+
+use bl3_save_edit_core::bl3_item::Bl3Item;
+
+use crate::bl3_ui::{Bl3Message, InteractionMessage};
+use crate::bl3_ui_style::Bl3UiTooltipStyle;
+use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
+use crate::views::item_editor::item_button_style::{
+    ItemEditorListButtonStyle, ItemEditorListNegativeButtonStyle,
+};
+use crate::views::item_editor::list_item_contents::{level_label, ItemListSummary};
+use crate::views::item_editor::ItemEditorInteractionMessage;
+use crate::views::InteractionExt;
+
+pub const MAX_FAVORITE_ITEMS: usize = 20;
+
+/// An item serial pinned in the config so it can be added to any inventory.
+#[derive(Debug, Default)]
+pub struct FavoriteItem {
+    pub serial: String,
+    // None when the serial can't be read anymore, these can only be removed
+    pub item: Option<Bl3Item>,
+    name: String,
+    add_button_state: button::State,
+    remove_button_state: button::State,
+}
+
+impl FavoriteItem {
+    pub fn new(serial: String) -> Self {
+        let item = Bl3Item::from_serial_base64(&serial).ok();
+
+        let name = item
+            .as_ref()
+            .map(|item| {
+                let summary = ItemListSummary::new(item);
+
+                format!("{} ({})", summary.label, level_label(&summary))
+            })
+            .unwrap_or_else(|| serial.clone());
+
+        Self {
+            serial,
+            item,
+            name,
+            ..Self::default()
+        }
+    }
+
+    pub fn from_serials(serials: &[String]) -> Vec<FavoriteItem> {
+        serials
+            .iter()
+            .take(MAX_FAVORITE_ITEMS)
+            .cloned()
+            .map(FavoriteItem::new)
+            .collect()
+    }
+
+    pub fn view<F>(&mut self, id: usize, interaction_message: F) -> Element<Bl3Message>
+    where
+        F: Fn(ItemEditorInteractionMessage) -> InteractionMessage + 'static + Copy,
+    {
+        let name: Element<Bl3Message> = if self.item.is_some() {
+            Text::new(&self.name)
+                .font(JETBRAINS_MONO)
+                .size(16)
+                .color(Color::from_rgb8(220, 220, 220))
+                .into()
+        } else {
+            Tooltip::new(
+                Text::new(&self.name)
+                    .font(JETBRAINS_MONO)
+                    .size(16)
+                    .color(Color::from_rgb8(120, 120, 120)),
+                "This serial could not be read",
+                tooltip::Position::Top,
+            )
+            .gap(10)
+            .padding(10)
+            .font(JETBRAINS_MONO)
+            .size(17)
+            .style(Bl3UiTooltipStyle)
+            .into()
+        };
+
+        let mut add_button = Button::new(
+            &mut self.add_button_state,
+            Text::new("Add to Inventory")
+                .font(JETBRAINS_MONO_BOLD)
+                .size(16)
+                .horizontal_alignment(Horizontal::Center),
+        )
+        .padding(5)
+        .width(Length::Units(165))
+        .style(ItemEditorListButtonStyle);
+
+        if self.item.is_some() {
+            add_button = add_button.on_press(interaction_message(
+                ItemEditorInteractionMessage::FavoriteItemAddPressed(id),
+            ));
+        }
+
+        Row::new()
+            .push(Container::new(name).width(Length::Fill))
+            .push(add_button)
+            .push(
+                Button::new(
+                    &mut self.remove_button_state,
+                    Text::new("Remove")
+                        .font(JETBRAINS_MONO_BOLD)
+                        .size(16)
+                        .horizontal_alignment(Horizontal::Center),
+                )
+                .on_press(interaction_message(
+                    ItemEditorInteractionMessage::FavoriteItemRemoved(id),
+                ))
+                .padding(5)
+                .width(Length::Units(85))
+                .style(ItemEditorListNegativeButtonStyle),
+            )
+            .align_items(Alignment::Center)
+            .spacing(10)
+            .into_element()
+    }
+}
@@ -1,41 +1,166 @@
+use std::collections::BTreeSet;
 use std::convert::TryInto;
 
 use anyhow::{bail, Result};
 use iced::alignment::Horizontal;
-use iced::{button, Button, Container, Element, Length, Row, Text};
+use iced::{button, tooltip, Alignment, Button, Container, Element, Length, Row, Text, Tooltip};
 
 use bl3_save_edit_core::bl3_item::Bl3Item;
+use bl3_save_edit_core::content_source::ContentSource;
+use bl3_save_edit_core::favorite_anointments::FavoriteAnointment;
 
-use crate::bl3_ui::{Bl3Message, InteractionMessage};
-use crate::resources::fonts::JETBRAINS_MONO_BOLD;
+use crate::bl3_ui::{Bl3Message, InteractionMessage, MessageResult};
+use crate::bl3_ui_style::Bl3UiTooltipStyle;
+use crate::commands::interaction::item_verify::ItemVerifyResult;
+use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
 use crate::views::item_editor::editor::Editor;
 use crate::views::item_editor::item_button_style::{
     ItemEditorButtonStyle, ItemEditorListButtonStyle, ItemEditorListNegativeButtonStyle,
 };
-use crate::views::item_editor::{list_item_contents, ItemEditorInteractionMessage};
+use crate::views::item_editor::list_item_contents::ItemListSummary;
+use crate::views::item_editor::parts::current_parts::DecodedItemParts;
+use crate::views::item_editor::{
+    list_item_contents, InventoryColumns, ItemEditorInteractionMessage,
+};
 use crate::views::InteractionExt;
 
+const ITEM_CARD_WIDTH: u16 = 175;
+const ITEM_CARD_HEIGHT: u16 = 115;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum ItemVerifyStatus {
+    // The frame of the spinner shown while waiting on the item API
+    Verifying(usize),
+    Valid,
+    Invalid(Option<String>),
+    Failed(String),
+}
+
+impl ItemVerifyStatus {
+    pub fn from_result(result: MessageResult<ItemVerifyResult>) -> Self {
+        match result {
+            MessageResult::Success(r) if r.valid => ItemVerifyStatus::Valid,
+            MessageResult::Success(r) => ItemVerifyStatus::Invalid(r.message),
+            MessageResult::Error(e) => ItemVerifyStatus::Failed(e),
+        }
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct ItemEditorListItem {
     pub index: usize,
     pub item: Bl3Item,
+    pub summary: ItemListSummary,
+    decoded_parts: Option<DecodedItemParts>,
+    // The item as it was when a background decode was started for it
+    decoding: Option<Bl3Item>,
+    // The item as it was when it was last sent off for verification
+    verification: Option<(Bl3Item, ItemVerifyStatus)>,
     list_button_state: button::State,
     duplicate_button_state: button::State,
     share_button_state: button::State,
+    favorite_button_state: button::State,
     delete_button_state: button::State,
     pub editor: Editor,
+    pub locked_parts: BTreeSet<String>,
 }
 
 impl ItemEditorListItem {
     pub fn new(index: usize, item: Bl3Item) -> Self {
         ItemEditorListItem {
             index,
+            summary: ItemListSummary::new(&item),
             item,
             ..Default::default()
         }
     }
 
+    pub fn refresh_summary(&mut self) {
+        self.summary = ItemListSummary {
+            source: self.summary.source.take(),
+            ..ItemListSummary::new(&self.item)
+        };
+    }
+
+    pub fn decoded_parts(&self) -> Option<&DecodedItemParts> {
+        self.decoded_parts
+            .as_ref()
+            .filter(|decoded_parts| decoded_parts.is_for(&self.item))
+    }
+
+    /// Returns the item to decode in the background if its parts haven't been decoded yet.
+    pub fn start_decoding_parts(&mut self) -> Option<Bl3Item> {
+        if self.decoded_parts().is_some() || self.decoding.as_ref() == Some(&self.item) {
+            return None;
+        }
+
+        self.decoding = Some(self.item.clone());
+
+        self.decoding.clone()
+    }
+
+    pub fn finish_decoding_parts(&mut self, decoded_parts: &DecodedItemParts) {
+        if self
+            .decoding
+            .as_ref()
+            .map(|item| decoded_parts.is_for(item))
+            .unwrap_or(false)
+        {
+            self.decoding = None;
+        }
+
+        if decoded_parts.is_for(&self.item) {
+            self.decoded_parts = Some(decoded_parts.clone());
+        }
+    }
+
+    pub fn verify_status(&self) -> Option<&ItemVerifyStatus> {
+        self.verification
+            .as_ref()
+            .filter(|(item, _)| item == &self.item)
+            .map(|(_, status)| status)
+    }
+
+    pub fn is_verifying(&self) -> bool {
+        matches!(self.verify_status(), Some(ItemVerifyStatus::Verifying(_)))
+    }
+
+    /// Returns the item to verify if it isn't already being verified.
+    pub fn start_verifying(&mut self) -> Option<Bl3Item> {
+        if self.is_verifying() {
+            return None;
+        }
+
+        self.verification = Some((self.item.clone(), ItemVerifyStatus::Verifying(0)));
+
+        Some(self.item.clone())
+    }
+
+    pub fn finish_verifying(&mut self, item: &Bl3Item, status: ItemVerifyStatus) {
+        if let Some((verifying_item, current_status)) = &mut self.verification {
+            if verifying_item == item {
+                *current_status = status;
+            }
+        }
+    }
+
+    pub fn advance_verify_spinner(&mut self) {
+        if let Some((_, ItemVerifyStatus::Verifying(frame))) = &mut self.verification {
+            *frame = frame.wrapping_add(1);
+        }
+    }
+
+    // Items that were edited after being selected have no decode running, there's only one item so
+    // it's decoded here instead.
+    fn decode_parts_if_needed(&mut self) {
+        if self.decoded_parts().is_none() && self.decoding.as_ref() != Some(&self.item) {
+            self.decoded_parts = Some(DecodedItemParts::new(self.item.clone()));
+        }
+    }
+
     pub fn map_item_to_editor(&mut self) -> Result<()> {
+        self.refresh_summary();
+
         if let Ok(serial) = self.item.get_serial_number_base64(false) {
             self.editor.item_level_input = self.item.level().try_into().unwrap_or(1);
             self.editor.serial_input = serial;
@@ -54,12 +179,23 @@ impl ItemEditorListItem {
         &mut self,
         id: usize,
         is_active: bool,
+        is_favorite: bool,
+        owned_dlc: &[ContentSource],
+        favorite_anointments: &[FavoriteAnointment],
+        keep_mayhem_level: bool,
+        inventory_columns: &InventoryColumns,
         interaction_message: F,
     ) -> (Element<Bl3Message>, Option<Container<Bl3Message>>)
     where
         F: Fn(ItemEditorInteractionMessage) -> InteractionMessage + 'static + Copy,
     {
-        let action_row = Row::new()
+        if is_active {
+            self.decode_parts_if_needed();
+        }
+
+        let verify_badge = self.verify_status().map(list_item_contents::verify_badge);
+
+        let mut action_row = Row::new()
             .push(
                 Button::new(
                     &mut self.duplicate_button_state,
@@ -90,6 +226,21 @@ impl ItemEditorListItem {
                 .width(Length::Units(85))
                 .style(ItemEditorListButtonStyle),
             )
+            .push(
+                Button::new(
+                    &mut self.favorite_button_state,
+                    Text::new(if is_favorite { "Unstar" } else { "Star" })
+                        .font(JETBRAINS_MONO_BOLD)
+                        .size(16)
+                        .horizontal_alignment(Horizontal::Center),
+                )
+                .on_press(interaction_message(
+                    ItemEditorInteractionMessage::FavoriteItemToggled(id),
+                ))
+                .padding(5)
+                .width(Length::Units(85))
+                .style(ItemEditorListButtonStyle),
+            )
             .push(
                 Button::new(
                     &mut self.delete_button_state,
@@ -106,23 +257,104 @@ impl ItemEditorListItem {
                 .style(ItemEditorListNegativeButtonStyle),
             )
             .width(Length::Fill)
+            .align_items(Alignment::Center)
             .spacing(10);
 
-        let item_content = list_item_contents::view(&self.item).push(action_row);
+        if let Some(verify_badge) = verify_badge {
+            action_row = action_row.push(verify_badge);
+        }
+
+        let item_content = list_item_contents::with_rarity_band(
+            &self.summary.header.rarity,
+            list_item_contents::view(&self.summary, inventory_columns).push(action_row),
+        );
 
         let item_editor = if is_active {
-            Some(self.editor.view(&self.item, interaction_message))
+            let item = &self.item;
+
+            Some(self.editor.view(
+                item,
+                &self.locked_parts,
+                self.decoded_parts.as_ref().filter(|d| d.is_for(item)),
+                owned_dlc,
+                favorite_anointments,
+                keep_mayhem_level,
+                interaction_message,
+            ))
         } else {
             None
         };
 
         (
-            Button::new(&mut self.list_button_state, Container::new(item_content))
+            Tooltip::new(
+                Button::new(&mut self.list_button_state, Container::new(item_content))
+                    .on_press(interaction_message(
+                        ItemEditorInteractionMessage::ItemPressed(id),
+                    ))
+                    .padding(9)
+                    .width(Length::Fill)
+                    .style(ItemEditorButtonStyle { is_active }),
+                &self.summary.inspect,
+                tooltip::Position::Right,
+            )
+            .gap(10)
+            .padding(10)
+            .font(JETBRAINS_MONO)
+            .size(16)
+            .style(Bl3UiTooltipStyle)
+            .into_element(),
+            item_editor,
+        )
+    }
+
+    pub fn card_view<F>(
+        &mut self,
+        id: usize,
+        is_active: bool,
+        owned_dlc: &[ContentSource],
+        favorite_anointments: &[FavoriteAnointment],
+        keep_mayhem_level: bool,
+        interaction_message: F,
+    ) -> (Element<Bl3Message>, Option<Container<Bl3Message>>)
+    where
+        F: Fn(ItemEditorInteractionMessage) -> InteractionMessage + 'static + Copy,
+    {
+        if is_active {
+            self.decode_parts_if_needed();
+        }
+
+        let verify_badge = self.verify_status().map(list_item_contents::verify_badge);
+
+        let item_editor = if is_active {
+            let item = &self.item;
+
+            Some(self.editor.view(
+                item,
+                &self.locked_parts,
+                self.decoded_parts.as_ref().filter(|d| d.is_for(item)),
+                owned_dlc,
+                favorite_anointments,
+                keep_mayhem_level,
+                interaction_message,
+            ))
+        } else {
+            None
+        };
+
+        let mut card_content = list_item_contents::card_view(&self.summary);
+
+        if let Some(verify_badge) = verify_badge {
+            card_content = card_content.push(verify_badge);
+        }
+
+        (
+            Button::new(&mut self.list_button_state, Container::new(card_content))
                 .on_press(interaction_message(
                     ItemEditorInteractionMessage::ItemPressed(id),
                 ))
                 .padding(9)
-                .width(Length::Fill)
+                .width(Length::Units(ITEM_CARD_WIDTH))
+                .height(Length::Units(ITEM_CARD_HEIGHT))
                 .style(ItemEditorButtonStyle { is_active })
                 .into_element(),
             item_editor,
@@ -7,13 +7,17 @@ use crate::bl3_ui::{Bl3Message, InteractionMessage};
 use crate::bl3_ui_style::Bl3UiStyleCustomNoBorder;
 use crate::resources::fonts::JETBRAINS_MONO_BOLD;
 use crate::views::item_editor::item_button_style::ItemEditorListButtonStyle;
-use crate::views::item_editor::{list_item_contents, ItemEditorInteractionMessage};
+use crate::views::item_editor::list_item_contents::ItemListSummary;
+use crate::views::item_editor::{
+    list_item_contents, InventoryColumns, ItemEditorInteractionMessage,
+};
 use crate::views::InteractionExt;
 
 #[derive(Debug, Default)]
 pub struct ItemEditorLootlemonItem {
     pub id: usize,
     pub item: Bl3Item,
+    pub summary: ItemListSummary,
     pub link: String,
     pub import_button_state: button::State,
     pub open_lootlemon_button_state: button::State,
@@ -23,13 +27,19 @@ impl ItemEditorLootlemonItem {
     pub fn new(id: usize, link: String, item: Bl3Item) -> Self {
         ItemEditorLootlemonItem {
             id,
+            summary: ItemListSummary::new(&item),
             item,
             link,
             ..Self::default()
         }
     }
 
-    pub fn view<F>(&mut self, view_index: usize, interaction_message: F) -> Element<Bl3Message>
+    pub fn view<F>(
+        &mut self,
+        view_index: usize,
+        inventory_columns: &InventoryColumns,
+        interaction_message: F,
+    ) -> Element<Bl3Message>
     where
         F: Fn(ItemEditorInteractionMessage) -> InteractionMessage + 'static + Copy,
     {
@@ -67,7 +77,10 @@ impl ItemEditorLootlemonItem {
             .width(Length::Fill)
             .spacing(10);
 
-        let item_content = list_item_contents::view(&self.item).push(action_row);
+        let item_content = list_item_contents::with_rarity_band(
+            &self.summary.header.rarity,
+            list_item_contents::view(&self.summary, inventory_columns).push(action_row),
+        );
 
         let mut view = Container::new(item_content).padding(9).width(Length::Fill);
 
@@ -1,40 +1,233 @@
 use heck::ToTitleCase;
-use iced::{container, svg, Color, Column, Container, Length, Row, Svg, Text};
+use iced::{
+    container, svg, tooltip, Alignment, Color, Column, Container, Length, Row, Svg, Text, Tooltip,
+};
 
-use bl3_save_edit_core::bl3_item::{Bl3Item, ItemFlags, ItemRarity, ItemType};
+use bl3_save_edit_core::bl3_item::{
+    BalancePart, Bl3Item, Bl3ItemHeader, ItemFlags, ItemRarity, ItemType,
+};
+use bl3_save_edit_core::content_source::ContentSource;
 
 use crate::bl3_ui::InteractionMessage;
+use crate::bl3_ui_style::{Bl3UiStyleCustomNoBorder, Bl3UiTooltipStyle};
 use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
-use crate::resources::svgs::{FAVORITE, JUNK};
+use crate::resources::svgs::{
+    ANSHIN, ATLAS, COV, DAHL, ERIDIAN, FAVORITE, HYPERION, JAKOBS, JUNK, MALIWAN, PANGOLIN,
+    TEDIORE, TORGUE, VLADOF,
+};
+use crate::views::item_editor::item_editor_list_item::ItemVerifyStatus;
+use crate::views::item_editor::InventoryColumns;
 
-pub fn view(item: &Bl3Item) -> Column<InteractionMessage> {
-    let balance_part = item.balance_part();
+const CARD_NAME_PREVIEW_LENGTH: usize = 14;
 
-    let label = balance_part.name.clone().unwrap_or_else(|| {
+const VERIFY_SPINNER_FRAMES: [&str; 4] = ["|", "/", "-", "\\"];
+
+/// What an item's row in the list shows, worked out once instead of on every redraw.
+#[derive(Debug, Clone, Default)]
+pub struct ItemListSummary {
+    pub header: Bl3ItemHeader,
+    pub label: String,
+    pub item_type: ItemType,
+    pub mayhem_level: Option<usize>,
+    pub anointment: Option<String>,
+    // The character the item was moved to the bank from
+    pub source: Option<String>,
+    // Key stats shown when hovering over the item's row
+    pub inspect: String,
+}
+
+impl ItemListSummary {
+    pub fn new(item: &Bl3Item) -> Self {
+        let header = item.header();
+        let label = item_label(&header.balance_part);
+
+        let mut summary = Self {
+            header,
+            label,
+            item_type: item.item_type,
+            mayhem_level: item.mayhem_level(),
+            anointment: item_anointment(item),
+            source: None,
+            inspect: String::new(),
+        };
+
+        let number_of_parts = item.item_parts.as_ref().map(|p| p.parts().len());
+
+        summary.inspect = inspect_text(&summary, number_of_parts);
+
+        summary
+    }
+}
+
+fn item_type_label(summary: &ItemListSummary) -> String {
+    summary
+        .header
+        .weapon_type
+        .as_ref()
+        .filter(|_| summary.item_type == ItemType::Weapon)
+        .map(|wt| wt.to_string())
+        .unwrap_or_else(|| summary.item_type.to_string())
+}
+
+fn manufacturer_label(header: &Bl3ItemHeader) -> Option<String> {
+    let manufacturer_short = header.manufacturer_part.short_ident.clone()?;
+
+    if manufacturer_short != "CoV" && manufacturer_short != "Class Mod" {
+        Some(manufacturer_short.to_title_case())
+    } else {
+        Some(manufacturer_short)
+    }
+}
+
+fn inspect_text(summary: &ItemListSummary, number_of_parts: Option<usize>) -> String {
+    let header = &summary.header;
+
+    let mut lines = vec![
+        summary.label.clone(),
+        format!("{} - {}", item_type_label(summary), header.rarity),
+        level_label(summary),
+    ];
+
+    if let Some(manufacturer) = manufacturer_label(header) {
+        lines.push(format!("Manufacturer: {}", manufacturer));
+    }
+
+    if let Some(anointment) = &summary.anointment {
+        lines.push(format!("Anointment: {}", anointment));
+    }
+
+    if let Some(number_of_parts) = number_of_parts {
+        lines.push(format!("Parts: {}", number_of_parts));
+    }
+
+    let content_source = header.balance_part.content_source();
+
+    if content_source != ContentSource::BaseGame {
+        lines.push(format!("From: {}", content_source));
+    }
+
+    lines.join("\n")
+}
+
+/// The item's level along with its mayhem level if it has one, e.g. "Level 57 (M10)".
+pub fn level_label(summary: &ItemListSummary) -> String {
+    match summary.mayhem_level {
+        Some(mayhem_level) => format!("Level {} (M{})", summary.header.level, mayhem_level),
+        None => format!("Level {}", summary.header.level),
+    }
+}
+
+/// The level tag of a list row, only showing the parts that the inventory columns ask for.
+pub fn list_level_label(summary: &ItemListSummary, columns: &InventoryColumns) -> Option<String> {
+    let mayhem_level = summary.mayhem_level.filter(|_| columns.show_mayhem);
+
+    match (columns.show_level, mayhem_level) {
+        (true, Some(mayhem_level)) => Some(format!(
+            "Level {} (M{})",
+            summary.header.level, mayhem_level
+        )),
+        (true, None) => Some(format!("Level {}", summary.header.level)),
+        (false, Some(mayhem_level)) => Some(format!("Mayhem {}", mayhem_level)),
+        (false, None) => None,
+    }
+}
+
+// Mayhem levels are also generic parts, anything else in there is the anointment
+fn item_anointment(item: &Bl3Item) -> Option<String> {
+    item.item_parts
+        .as_ref()?
+        .generic_parts()
+        .iter()
+        .find(|p| !p.is_mayhem_part())
+        .map(|p| p.short_ident.clone().unwrap_or_else(|| p.ident.clone()))
+}
+
+fn item_label(balance_part: &BalancePart) -> String {
+    balance_part.name.clone().unwrap_or_else(|| {
         balance_part
             .short_ident
             .clone()
             .unwrap_or_else(|| balance_part.ident.clone())
-    });
+    })
+}
+
+// Some manufacturers have variants such as Eridian_NoMinGamestage, so the prefix is matched
+fn manufacturer_icon(manufacturer_short: &str) -> Option<&'static [u8]> {
+    let manufacturer_icons = [
+        ("Anshin", ANSHIN),
+        ("Atlas", ATLAS),
+        ("CoV", COV),
+        ("Dahl", DAHL),
+        ("Eridian", ERIDIAN),
+        ("Hyperion", HYPERION),
+        ("Jakobs", JAKOBS),
+        ("Maliwan", MALIWAN),
+        ("Pangolin", PANGOLIN),
+        ("Tediore", TEDIORE),
+        ("Torgue", TORGUE),
+        ("Vladof", VLADOF),
+    ];
+
+    manufacturer_icons
+        .iter()
+        .find(|(prefix, _)| manufacturer_short.starts_with(prefix))
+        .map(|(_, icon)| *icon)
+}
+
+pub trait RarityColor {
+    fn color(&self) -> Color;
+}
 
-    let mut tags_row = Row::new()
+impl RarityColor for ItemRarity {
+    fn color(&self) -> Color {
+        match self {
+            ItemRarity::Common => Color::from_rgb8(242, 233, 218),
+            ItemRarity::Uncommon => Color::from_rgb8(87, 204, 58),
+            ItemRarity::Rare => Color::from_rgb8(52, 143, 235),
+            ItemRarity::VeryRare => Color::from_rgb8(168, 74, 235),
+            ItemRarity::Legendary => Color::from_rgb8(245, 148, 30),
+            ItemRarity::NamedWeapon => Color::from_rgb8(19, 232, 240),
+            ItemRarity::Unknown => Color::from_rgb8(46, 46, 46),
+        }
+    }
+}
+
+/// Puts a thin bar in the item's rarity color down the left edge of a list row.
+pub fn with_rarity_band<'a>(
+    rarity: &ItemRarity,
+    contents: Column<'a, InteractionMessage>,
+) -> Row<'a, InteractionMessage> {
+    Row::new()
         .push(
-            Container::new(
-                Text::new(format!("Level {}", item.level()))
-                    .font(JETBRAINS_MONO)
-                    .size(15),
-            )
-            .padding(5)
-            .style(ItemInfoStyle),
+            Container::new(Text::new(""))
+                .width(Length::Units(4))
+                .height(Length::Fill)
+                .style(Bl3UiStyleCustomNoBorder(rarity.color())),
         )
-        .width(Length::Fill)
-        .spacing(10);
+        .push(contents)
+        .align_items(Alignment::Fill)
+        .spacing(9)
+}
 
-    if let Some(mut manufacturer_short) = item.manufacturer_part().short_ident.clone() {
-        if manufacturer_short != "CoV" && manufacturer_short != "Class Mod" {
-            manufacturer_short = manufacturer_short.to_title_case();
-        }
+pub fn view<'a>(
+    summary: &'a ItemListSummary,
+    columns: &InventoryColumns,
+) -> Column<'a, InteractionMessage> {
+    let header = &summary.header;
+
+    let mut tags_row = Row::new().width(Length::Fill).spacing(10);
+
+    if let Some(level_label) = list_level_label(summary, columns) {
+        tags_row = tags_row.push(
+            Container::new(Text::new(level_label).font(JETBRAINS_MONO).size(15))
+                .padding(5)
+                .style(ItemInfoStyle),
+        );
+    }
 
+    if let Some(manufacturer_short) =
+        manufacturer_label(header).filter(|_| columns.show_manufacturer)
+    {
         tags_row = tags_row.push(
             Container::new(Text::new(manufacturer_short).font(JETBRAINS_MONO).size(15))
                 .padding(5)
@@ -42,37 +235,75 @@ pub fn view(item: &Bl3Item) -> Column<InteractionMessage> {
         )
     }
 
-    if let Some(item_parts) = &item.item_parts {
-        if item.item_type == ItemType::Weapon {
-            if let Some(weapon_type) = &item_parts.weapon_type {
-                tags_row = tags_row.push(
-                    Container::new(
-                        Text::new(weapon_type.to_string())
-                            .font(JETBRAINS_MONO)
-                            .size(15),
-                    )
-                    .padding(5)
-                    .style(ItemInfoStyle),
-                )
-            }
-        }
-
-        if item_parts.rarity != ItemRarity::Unknown {
+    if summary.item_type == ItemType::Weapon {
+        if let Some(weapon_type) = &header.weapon_type {
             tags_row = tags_row.push(
                 Container::new(
-                    Text::new(item_parts.rarity.to_string())
+                    Text::new(weapon_type.to_string())
                         .font(JETBRAINS_MONO)
                         .size(15),
                 )
                 .padding(5)
-                .style(ItemRarityStyle {
-                    rarity: item_parts.rarity.clone(),
-                }),
-            );
+                .style(ItemInfoStyle),
+            )
         }
     }
 
-    if let Some(flags) = item.flags {
+    if columns.show_rarity && header.rarity != ItemRarity::Unknown {
+        tags_row = tags_row.push(
+            Container::new(
+                Text::new(header.rarity.to_string())
+                    .font(JETBRAINS_MONO)
+                    .size(15),
+            )
+            .padding(5)
+            .style(ItemRarityStyle {
+                rarity: header.rarity.clone(),
+            }),
+        );
+    }
+
+    if let Some(anointment) = summary
+        .anointment
+        .as_ref()
+        .filter(|_| columns.show_anointment)
+    {
+        tags_row = tags_row.push(
+            Container::new(Text::new(anointment).font(JETBRAINS_MONO).size(15))
+                .padding(5)
+                .style(ItemInfoStyle),
+        );
+    }
+
+    // There's no sourced list of the parts each patch changed, so items aren't tagged as
+    // patched or worth farming again
+    let content_source = header.balance_part.content_source();
+
+    if content_source != ContentSource::BaseGame {
+        tags_row = tags_row.push(
+            Container::new(
+                Text::new(content_source.short_name())
+                    .font(JETBRAINS_MONO)
+                    .size(15),
+            )
+            .padding(5)
+            .style(ItemInfoStyle),
+        );
+    }
+
+    if let Some(source) = &summary.source {
+        tags_row = tags_row.push(
+            Container::new(
+                Text::new(format!("From {}", source))
+                    .font(JETBRAINS_MONO)
+                    .size(15),
+            )
+            .padding(5)
+            .style(ItemInfoStyle),
+        );
+    }
+
+    if let Some(flags) = header.flags {
         let fav_or_trash = if flags.contains(ItemFlags::FAVORITE) {
             let favorite_icon_handle = svg::Handle::from_memory(FAVORITE);
 
@@ -108,15 +339,126 @@ pub fn view(item: &Bl3Item) -> Column<InteractionMessage> {
         }
     }
 
+    let mut label_row = Row::new().align_items(Alignment::Center).spacing(10);
+
+    if let Some(icon) = header
+        .manufacturer_part
+        .short_ident
+        .as_deref()
+        .and_then(manufacturer_icon)
+    {
+        label_row = label_row.push(
+            Svg::new(svg::Handle::from_memory(icon))
+                .height(Length::Units(20))
+                .width(Length::Units(20)),
+        );
+    }
+
+    label_row = label_row.push(
+        Text::new(&summary.label)
+            .font(JETBRAINS_MONO_BOLD)
+            .size(18)
+            .color(Color::from_rgb8(224, 224, 224)),
+    );
+
+    Column::new().push(label_row).push(tags_row).spacing(10)
+}
+
+pub fn card_view(summary: &ItemListSummary) -> Column<InteractionMessage> {
+    let header = &summary.header;
+    let label = &summary.label;
+
+    let name_preview = if label.chars().count() > CARD_NAME_PREVIEW_LENGTH {
+        format!(
+            "{}...",
+            label
+                .chars()
+                .take(CARD_NAME_PREVIEW_LENGTH - 3)
+                .collect::<String>()
+        )
+    } else {
+        label.clone()
+    };
+
+    let rarity = header.rarity.clone();
+
+    let item_type = item_type_label(summary);
+
     Column::new()
         .push(
-            Text::new(&label)
-                .font(JETBRAINS_MONO_BOLD)
-                .size(18)
-                .color(Color::from_rgb8(224, 224, 224)),
+            Row::new()
+                .push(
+                    Container::new(Text::new(""))
+                        .width(Length::Units(14))
+                        .height(Length::Units(14))
+                        .style(ItemRarityStyle { rarity }),
+                )
+                .push(
+                    Text::new(name_preview)
+                        .font(JETBRAINS_MONO_BOLD)
+                        .size(15)
+                        .color(Color::from_rgb8(224, 224, 224)),
+                )
+                .align_items(Alignment::Center)
+                .spacing(8),
+        )
+        .push(
+            Text::new(item_type)
+                .font(JETBRAINS_MONO)
+                .size(14)
+                .color(Color::from_rgb8(220, 220, 220)),
+        )
+        .push(
+            Text::new(level_label(summary))
+                .font(JETBRAINS_MONO)
+                .size(14)
+                .color(Color::from_rgb8(220, 220, 220)),
         )
-        .push(tags_row)
-        .spacing(10)
+        .spacing(6)
+}
+
+/// A small tag showing how the item API answered for this item.
+pub fn verify_badge<'a>(status: &ItemVerifyStatus) -> Tooltip<'a, InteractionMessage> {
+    let (label, tooltip_msg, style) = match status {
+        ItemVerifyStatus::Verifying(frame) => (
+            format!(
+                "{} Verifying",
+                VERIFY_SPINNER_FRAMES[frame % VERIFY_SPINNER_FRAMES.len()]
+            ),
+            "Waiting on the item API".to_owned(),
+            ItemVerifyStyle::Pending,
+        ),
+        ItemVerifyStatus::Valid => (
+            "✓ Verified".to_owned(),
+            "The item API recognised this item".to_owned(),
+            ItemVerifyStyle::Valid,
+        ),
+        ItemVerifyStatus::Invalid(message) => (
+            "✗ Invalid".to_owned(),
+            message
+                .clone()
+                .unwrap_or_else(|| "The item API did not recognise this item".to_owned()),
+            ItemVerifyStyle::Invalid,
+        ),
+        ItemVerifyStatus::Failed(e) => (
+            "✗ Not Verified".to_owned(),
+            format!("Failed to verify item: {}", e),
+            ItemVerifyStyle::Pending,
+        ),
+    };
+
+    Tooltip::new(
+        Container::new(Text::new(label).font(JETBRAINS_MONO_BOLD).size(15))
+            .padding(5)
+            .style(style),
+        tooltip_msg,
+        tooltip::Position::Top,
+    )
+    .gap(10)
+    .padding(10)
+    .font(JETBRAINS_MONO)
+    .size(17)
+    .style(Bl3UiTooltipStyle)
 }
 
 pub struct ItemInfoStyle;
@@ -215,3 +557,39 @@ impl container::StyleSheet for ItemRarityStyle {
         }
     }
 }
+
+pub enum ItemVerifyStyle {
+    Pending,
+    Valid,
+    Invalid,
+}
+
+impl container::StyleSheet for ItemVerifyStyle {
+    fn style(&self) -> container::Style {
+        let (text_color, background, border_color) = match self {
+            ItemVerifyStyle::Pending => (
+                Color::from_rgb8(224, 224, 224),
+                Color::from_rgb8(38, 38, 38),
+                Color::from_rgb8(46, 46, 46),
+            ),
+            ItemVerifyStyle::Valid => (
+                Color::from_rgb8(172, 240, 149),
+                Color::from_rgb8(35, 54, 29),
+                Color::from_rgb8(42, 61, 36),
+            ),
+            ItemVerifyStyle::Invalid => (
+                Color::from_rgb8(240, 149, 149),
+                Color::from_rgb8(54, 29, 29),
+                Color::from_rgb8(61, 36, 36),
+            ),
+        };
+
+        container::Style {
+            text_color: Some(text_color),
+            background: Some(background.into()),
+            border_radius: 3.0,
+            border_width: 1.0,
+            border_color,
+        }
+    }
+}
@@ -7,32 +7,40 @@ use heck::ToTitleCase;
 use iced::alignment::{Horizontal, Vertical};
 use iced::{
     button, scrollable, text_input, tooltip, Alignment, Button, Color, Column, Command, Container,
-    Length, Row, Scrollable, Text, Tooltip,
+    Element, Length, Row, Scrollable, Text, Tooltip,
 };
 use rayon::iter::{IndexedParallelIterator, IntoParallelRefIterator, ParallelIterator};
 use rayon::slice::ParallelSliceMut;
+use serde::{Deserialize, Serialize};
 use strum::Display;
 use tracing::error;
 
 use bl3_save_edit_core::bl3_item::{
-    BalancePart, Bl3Item, InvDataPart, ItemFlags, ManufacturerPart, MAX_BL3_ITEM_ANOINTMENTS,
-    MAX_BL3_ITEM_PARTS,
+    BalancePart, Bl3Item, Bl3Part, InvDataPart, ItemFlags, ManufacturerPart,
+    MAX_BL3_ITEM_ANOINTMENTS, MAX_BL3_ITEM_PARTS,
 };
 use bl3_save_edit_core::bl3_profile::Bl3Profile;
 use bl3_save_edit_core::bl3_save::character_data::MAX_CHARACTER_LEVEL;
 use bl3_save_edit_core::bl3_save::Bl3Save;
+use bl3_save_edit_core::bulk_item_edit::{
+    bulk_edit_items, normalize_item_levels, BulkItemEdit, LevelRangeSummary,
+};
+use bl3_save_edit_core::content_source::ContentSource;
+use bl3_save_edit_core::favorite_anointments::FavoriteAnointment;
 use bl3_save_edit_core::resources::{INVENTORY_SERIAL_DB, LOOTLEMON_ITEMS};
 use parts::available_parts;
 use parts::available_parts::AvailablePartTypeIndex;
-use parts::current_parts::CurrentPartTypeIndex;
+use parts::current_parts::{CurrentPartTypeIndex, DecodedItemParts};
 
 use crate::bl3_ui::{Bl3Message, InteractionMessage, MessageResult};
 use crate::bl3_ui_style::{Bl3UiStyle, Bl3UiStyleNoBorder, Bl3UiTooltipStyle};
 use crate::commands::interaction;
+use crate::commands::interaction::item_verify::ItemVerifyResult;
 use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
 use crate::util;
 use crate::util::ErrorExt;
-use crate::views::item_editor::item_editor_list_item::ItemEditorListItem;
+use crate::views::item_editor::favorite_item::{FavoriteItem, MAX_FAVORITE_ITEMS};
+use crate::views::item_editor::item_editor_list_item::{ItemEditorListItem, ItemVerifyStatus};
 use crate::views::item_editor::item_editor_lootlemon_item::ItemEditorLootlemonItem;
 use crate::views::item_editor::parts_tab_bar::{AvailablePartType, CurrentPartType};
 use crate::views::tab_bar_button::tab_bar_button;
@@ -44,6 +52,7 @@ use crate::widgets::text_input_limited::TextInputLimited;
 
 pub mod editor;
 pub mod extra_part_info;
+pub mod favorite_item;
 pub mod item_button_style;
 pub mod item_editor_list_item;
 pub mod item_editor_lootlemon_item;
@@ -51,6 +60,8 @@ pub mod list_item_contents;
 pub mod parts;
 pub mod parts_tab_bar;
 
+const ITEM_CARDS_PER_ROW: usize = 2;
+
 #[derive(Derivative)]
 #[derivative(Debug, Default)]
 pub struct ItemEditorState {
@@ -62,6 +73,8 @@ pub struct ItemEditorState {
     pub all_item_levels_input: i32,
     pub all_item_levels_input_state: text_input::State,
     pub all_item_levels_button_state: button::State,
+    #[derivative(Default(value = "true"))]
+    pub keep_mayhem_level: bool,
     pub import_serial_button_state: button::State,
     items: Vec<ItemEditorListItem>,
     lootlemon_items: ItemEditorLootlemonItems,
@@ -74,6 +87,17 @@ pub struct ItemEditorState {
     pub item_list_tab_type: ItemListTabType,
     pub item_list_items_tab_button_state: button::State,
     pub item_list_lootlemon_tab_button_state: button::State,
+    #[derivative(Default(value = "ContentSource::all_dlc()"))]
+    pub owned_dlc: Vec<ContentSource>,
+    pub inventory_layout: InventoryLayout,
+    pub inventory_layout_button_state: button::State,
+    pub inventory_columns: InventoryColumns,
+    pub favorite_anointments: Vec<FavoriteAnointment>,
+    pub favorite_items: Vec<FavoriteItem>,
+    pub favorite_items_scrollable_state: scrollable::State,
+    // Only set when item verification has been turned on in the settings
+    pub item_api_url: Option<String>,
+    pub verify_items_button_state: button::State,
 }
 
 #[derive(Debug)]
@@ -114,6 +138,56 @@ impl ItemEditorState {
         });
     }
 
+    pub fn bulk_edit_items(&mut self, edit: &BulkItemEdit) -> Result<usize> {
+        let changed = bulk_edit_items(
+            self.items
+                .iter_mut()
+                .map(|i| (&mut i.item, &i.locked_parts)),
+            edit,
+        )?;
+
+        self.items.iter_mut().for_each(|i| i.refresh_summary());
+
+        self.map_current_item_if_exists_to_editor_state()?;
+
+        Ok(changed)
+    }
+
+    pub fn normalize_item_levels(
+        &mut self,
+        min_level: usize,
+        max_level: usize,
+    ) -> Result<LevelRangeSummary> {
+        let summary = normalize_item_levels(
+            self.items.iter_mut().map(|i| &mut i.item),
+            min_level,
+            max_level,
+        )?;
+
+        self.items.iter_mut().for_each(|i| i.refresh_summary());
+
+        self.map_current_item_if_exists_to_editor_state()?;
+
+        Ok(summary)
+    }
+
+    pub fn favorite_item_serials(&self) -> Vec<String> {
+        self.favorite_items
+            .iter()
+            .map(|f| f.serial.clone())
+            .collect()
+    }
+
+    pub fn is_verifying_items(&self) -> bool {
+        self.items.iter().any(|i| i.is_verifying())
+    }
+
+    pub fn advance_verify_spinners(&mut self) {
+        self.items
+            .iter_mut()
+            .for_each(|i| i.advance_verify_spinner());
+    }
+
     pub fn items(&mut self) -> &Vec<ItemEditorListItem> {
         &self.items
     }
@@ -253,6 +327,47 @@ impl std::default::Default for ItemListTabType {
     }
 }
 
+#[derive(Debug, Display, Copy, Clone, Eq, PartialEq, Serialize, Deserialize, Default)]
+pub enum InventoryLayout {
+    #[default]
+    #[strum(to_string = "List")]
+    List,
+    #[strum(to_string = "Card")]
+    Card,
+}
+
+impl InventoryLayout {
+    pub fn toggled(&self) -> Self {
+        match self {
+            InventoryLayout::List => InventoryLayout::Card,
+            InventoryLayout::Card => InventoryLayout::List,
+        }
+    }
+}
+
+/// Which tags are shown on each row of the inventory list.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct InventoryColumns {
+    pub show_level: bool,
+    pub show_mayhem: bool,
+    pub show_manufacturer: bool,
+    pub show_rarity: bool,
+    pub show_anointment: bool,
+}
+
+impl std::default::Default for InventoryColumns {
+    fn default() -> Self {
+        Self {
+            show_level: true,
+            show_mayhem: true,
+            show_manufacturer: true,
+            show_rarity: true,
+            show_anointment: true,
+        }
+    }
+}
+
 #[derive(Debug)]
 pub enum ItemEditorFileType<'a> {
     Save(&'a mut Bl3Save),
@@ -263,10 +378,12 @@ pub enum ItemEditorFileType<'a> {
 #[derive(Debug, Clone)]
 pub enum ItemEditorInteractionMessage {
     ItemPressed(usize),
+    ItemPartsDecoded(Box<DecodedItemParts>),
     ItemsSearchInputChanged(String),
     ItemsLootLemonSearchInputChanged(String),
     ItemListItemTabPressed,
     ItemListLootlemonTabPressed,
+    InventoryLayoutSelected(InventoryLayout),
     ItemListLootlemonImportPressed(usize),
     ItemListLootlemonOpenWebsitePressed(usize),
     ItemListLootlemonOpenWebsiteCompleted(MessageResult<()>),
@@ -274,6 +391,7 @@ pub enum ItemEditorInteractionMessage {
     AvailablePartsSearchInputChanged(String),
     AvailablePartsTabPressed,
     AvailableAnointmentsTabPressed,
+    AvailablePartsCategorySelected(Option<String>),
     CurrentPartsSearchInputChanged(String),
     CurrentPartsTabPressed,
     CurrentAnointmentsTabPressed,
@@ -286,11 +404,22 @@ pub enum ItemEditorInteractionMessage {
     AvailableAnointmentPressed(AvailablePartTypeIndex),
     CurrentPartPressed(bool, CurrentPartTypeIndex),
     CurrentAnointmentPressed(CurrentPartTypeIndex),
+    CurrentPartLockToggled(String),
+    ApplyAnointmentToAllPressed(Bl3Part),
+    AvailableAnointmentFavoriteToggled(String),
+    FavoriteAnointmentPressed(Bl3Part),
+    FavoriteAnointmentRemoved(String),
+    FavoriteItemToggled(usize),
+    FavoriteItemAddPressed(usize),
+    FavoriteItemRemoved(usize),
     ImportSerialInputChanged(String),
     CreateItemPressed,
     ImportItemFromSerialPressed,
+    VerifyItemsPressed,
+    ItemVerified(Box<Bl3Item>, MessageResult<ItemVerifyResult>),
     AllItemLevel(i32),
     SetAllItemLevelsPressed,
+    KeepMayhemLevelToggled(bool),
     ItemLevel(i32),
     DeleteItem(usize),
     DuplicateItem(usize),
@@ -310,6 +439,31 @@ pub struct ItemEditorInteractionResponse {
 }
 
 impl ItemEditorInteractionMessage {
+    pub fn inventory_layout_selected(&self) -> Option<InventoryLayout> {
+        match self {
+            ItemEditorInteractionMessage::InventoryLayoutSelected(inventory_layout) => {
+                Some(*inventory_layout)
+            }
+            _ => None,
+        }
+    }
+
+    pub fn favorite_anointments_changed(&self) -> bool {
+        matches!(
+            self,
+            ItemEditorInteractionMessage::AvailableAnointmentFavoriteToggled(_)
+                | ItemEditorInteractionMessage::FavoriteAnointmentRemoved(_)
+        )
+    }
+
+    pub fn favorite_items_changed(&self) -> bool {
+        matches!(
+            self,
+            ItemEditorInteractionMessage::FavoriteItemToggled(_)
+                | ItemEditorInteractionMessage::FavoriteItemRemoved(_)
+        )
+    }
+
     pub fn update_state(
         self,
         item_editor_state: &mut ItemEditorState,
@@ -331,6 +485,25 @@ impl ItemEditorInteractionMessage {
                             }
                     })
                     .handle_ui_error("Failed to map selected item to editor", &mut notification);
+
+                if let Some(item) = item_editor_state
+                    .items
+                    .get_mut(item_index)
+                    .and_then(|i| i.start_decoding_parts())
+                {
+                    command = Some(Command::perform(
+                        interaction::manage_save::item_editor::decode_item_parts(item),
+                        |decoded_parts| {
+                            ItemEditorInteractionMessage::ItemPartsDecoded(Box::new(decoded_parts))
+                        },
+                    ));
+                }
+            }
+            ItemEditorInteractionMessage::ItemPartsDecoded(decoded_parts) => {
+                item_editor_state
+                    .items
+                    .iter_mut()
+                    .for_each(|i| i.finish_decoding_parts(&decoded_parts));
             }
             ItemEditorInteractionMessage::ItemsSearchInputChanged(search_items_query) => {
                 item_editor_state.search_items_input = search_items_query.to_lowercase();
@@ -345,6 +518,9 @@ impl ItemEditorInteractionMessage {
                 item_editor_state.search_items_input_state.focus();
                 item_editor_state.item_list_tab_type = ItemListTabType::Items;
             }
+            ItemEditorInteractionMessage::InventoryLayoutSelected(inventory_layout) => {
+                item_editor_state.inventory_layout = inventory_layout;
+            }
             ItemEditorInteractionMessage::ItemListLootlemonTabPressed => {
                 item_editor_state.search_lootlemon_items_input_state.focus();
                 item_editor_state.item_list_tab_type = ItemListTabType::Lootlemon;
@@ -451,6 +627,20 @@ impl ItemEditorInteractionMessage {
                         &mut notification,
                     );
             }
+            ItemEditorInteractionMessage::AvailablePartsCategorySelected(category) => {
+                item_editor_state
+                    .map_current_item_if_exists(|i| {
+                        i.editor.available_parts.scrollable_state.snap_to(0.0);
+                        i.editor.available_parts.search_input = "".to_owned();
+                        i.editor.available_parts.part_type_index =
+                            available_parts::AvailablePartTypeIndex::default();
+                        i.editor.available_parts.selected_category = category;
+                    })
+                    .handle_ui_error(
+                        "Failed to map item to editor when selecting a part category",
+                        &mut notification,
+                    );
+            }
             ItemEditorInteractionMessage::AvailablePartPressed(available_part_type_index) => {
                 let selected_item_index = item_editor_state.selected_item_index;
 
@@ -716,6 +906,8 @@ impl ItemEditorInteractionMessage {
             ItemEditorInteractionMessage::ImportSerialInputChanged(s) => {
                 item_editor_state.import_serial_input = s;
             }
+            // Created items always start from the same blank serial. The game's enemy drop
+            // tables aren't in the game data here, so items can't be rolled from a loot source
             ItemEditorInteractionMessage::CreateItemPressed => {
                 let item = Bl3Item::from_serial_base64("BL3(BAAAAAD2aoA+P1vAEgA=)").unwrap();
 
@@ -758,39 +950,209 @@ impl ItemEditorInteractionMessage {
                     }
                 }
             }
+            ItemEditorInteractionMessage::VerifyItemsPressed => {
+                if let Some(api_url) = item_editor_state.item_api_url.clone() {
+                    let mut verify_commands = Vec::new();
+
+                    for list_item in item_editor_state.items.iter_mut() {
+                        let serial = match list_item.item.get_serial_number_base64(false) {
+                            Ok(serial) => serial,
+                            Err(_) => continue,
+                        };
+
+                        if let Some(item) = list_item.start_verifying() {
+                            let api_url = api_url.clone();
+
+                            verify_commands.push(Command::perform(
+                                async move {
+                                    interaction::item_verify::verify_item_serial(&api_url, &serial)
+                                        .await
+                                },
+                                move |r| {
+                                    ItemEditorInteractionMessage::ItemVerified(
+                                        Box::new(item.clone()),
+                                        MessageResult::handle_result(r),
+                                    )
+                                },
+                            ));
+                        }
+                    }
+
+                    if !verify_commands.is_empty() {
+                        command = Some(Command::batch(verify_commands));
+                    }
+                }
+            }
+            ItemEditorInteractionMessage::ItemVerified(item, res) => {
+                let status = ItemVerifyStatus::from_result(res);
+
+                item_editor_state
+                    .items
+                    .iter_mut()
+                    .for_each(|i| i.finish_verifying(&item, status.clone()));
+            }
             ItemEditorInteractionMessage::AllItemLevel(item_level_input) => {
                 item_editor_state.all_item_levels_input = item_level_input;
             }
             ItemEditorInteractionMessage::SetAllItemLevelsPressed => {
-                let item_level = item_editor_state.all_item_levels_input as usize;
+                let edit = BulkItemEdit::Level {
+                    level: item_editor_state.all_item_levels_input as usize,
+                    keep_mayhem_part: item_editor_state.keep_mayhem_level,
+                };
 
-                let mut failed = false;
+                item_editor_state
+                    .bulk_edit_items(&edit)
+                    .handle_ui_error("Failed to set level for all items", &mut notification);
+            }
+            ItemEditorInteractionMessage::KeepMayhemLevelToggled(keep_mayhem_level) => {
+                item_editor_state.keep_mayhem_level = keep_mayhem_level;
+            }
+            ItemEditorInteractionMessage::ApplyAnointmentToAllPressed(anointment) => {
+                match item_editor_state.bulk_edit_items(&BulkItemEdit::Anointment(anointment)) {
+                    Ok(changed) => {
+                        notification = Some(Notification::new(
+                            format!(
+                                "Applied anointment to {} items, locked anointments were kept.",
+                                changed
+                            ),
+                            NotificationSentiment::Positive,
+                        ));
+                    }
+                    Err(e) => e.handle_ui_error(
+                        "Failed to apply anointment to all items",
+                        &mut notification,
+                    ),
+                }
+            }
+            ItemEditorInteractionMessage::AvailableAnointmentFavoriteToggled(name) => {
+                let favorite_anointments = &mut item_editor_state.favorite_anointments;
 
-                for (i, item) in item_editor_state.items_mut().iter_mut().enumerate() {
-                    if let Err(e) = item.item.set_level(item_level) {
-                        let msg = format!("Failed to set level for item number: {} - {}", i, e);
+                if let Some(pos) = favorite_anointments
+                    .iter()
+                    .position(|f| f.short_ident() == Some(name.as_str()))
+                {
+                    favorite_anointments.remove(pos);
+                } else {
+                    match INVENTORY_SERIAL_DB
+                        .get_part_by_short_name("InventoryGenericPartData", &name)
+                    {
+                        Ok(bl3_part) => {
+                            favorite_anointments.push(FavoriteAnointment::Available(bl3_part))
+                        }
+                        Err(e) => e.handle_ui_error(
+                            "Failed to add anointment to favorites",
+                            &mut notification,
+                        ),
+                    }
+                }
+            }
+            ItemEditorInteractionMessage::FavoriteAnointmentRemoved(ident) => {
+                item_editor_state
+                    .favorite_anointments
+                    .retain(|f| f.ident() != ident);
+            }
+            ItemEditorInteractionMessage::FavoriteAnointmentPressed(anointment) => {
+                let selected_item_index = item_editor_state.selected_item_index;
 
-                        e.handle_ui_error(&msg, &mut notification);
+                if let Some(current_item) =
+                    item_editor_state.items_mut().get_mut(selected_item_index)
+                {
+                    if let Some(item_parts) = &current_item.item.item_parts {
+                        if item_parts.generic_parts().len() < MAX_BL3_ITEM_ANOINTMENTS
+                            && !item_parts
+                                .generic_parts()
+                                .iter()
+                                .any(|p| p.ident == anointment.ident)
+                        {
+                            if let Err(e) = current_item.item.add_generic_part(anointment) {
+                                e.handle_ui_error(
+                                    "Failed to add anointment to item",
+                                    &mut notification,
+                                );
+                            } else {
+                                item_editor_state
+                                    .map_current_item_if_exists_to_editor_state()
+                                    .handle_ui_error(
+                                        "Failed to map item to editor after adding anointment to item",
+                                        &mut notification,
+                                    );
+                            }
+                        }
+                    }
+                }
+            }
+            ItemEditorInteractionMessage::FavoriteItemToggled(id) => {
+                if let Some(list_item) = item_editor_state.items.get(id) {
+                    let favorite_items = &mut item_editor_state.favorite_items;
 
-                        failed = true;
+                    if let Some(pos) = favorite_items
+                        .iter()
+                        .position(|f| f.item.as_ref() == Some(&list_item.item))
+                    {
+                        favorite_items.remove(pos);
+                    } else if favorite_items.len() >= MAX_FAVORITE_ITEMS {
+                        let msg = format!(
+                            "Failed to add item to favorites: only {} items can be favorited, please remove one first.",
+                            MAX_FAVORITE_ITEMS
+                        );
 
-                        break;
+                        notification =
+                            Some(Notification::new(msg, NotificationSentiment::Negative));
+                    } else {
+                        match list_item.item.get_serial_number_base64(false) {
+                            Ok(serial) => favorite_items.push(FavoriteItem::new(serial)),
+                            Err(e) => e.handle_ui_error(
+                                "Failed to add item to favorites",
+                                &mut notification,
+                            ),
+                        }
                     }
                 }
+            }
+            ItemEditorInteractionMessage::FavoriteItemAddPressed(id) => {
+                if let Some(item) = item_editor_state
+                    .favorite_items
+                    .get(id)
+                    .and_then(|f| f.item.clone())
+                {
+                    let item_pos = item_editor_state.add_item(item);
+
+                    item_editor_state.selected_item_index = item_pos;
+
+                    item_editor_state.search_items_input_state.focus();
+
+                    item_editor_state.item_list_tab_type = ItemListTabType::Items;
 
-                if !failed {
                     item_editor_state
                         .map_current_item_if_exists_to_editor_state()
                         .handle_ui_error(
-                            "Failed to map previously selected item to editor after updating all item levels",
+                            "Failed to map favorite item to editor",
                             &mut notification,
                         );
                 }
             }
+            ItemEditorInteractionMessage::FavoriteItemRemoved(id) => {
+                if id < item_editor_state.favorite_items.len() {
+                    item_editor_state.favorite_items.remove(id);
+                }
+            }
+            ItemEditorInteractionMessage::CurrentPartLockToggled(part_ident) => {
+                let selected_item_index = item_editor_state.selected_item_index;
+
+                if let Some(current_item) =
+                    item_editor_state.items_mut().get_mut(selected_item_index)
+                {
+                    if !current_item.locked_parts.remove(&part_ident) {
+                        current_item.locked_parts.insert(part_ident);
+                    }
+                }
+            }
             ItemEditorInteractionMessage::ItemLevel(item_level_input) => {
+                let keep_mayhem_level = item_editor_state.keep_mayhem_level;
+
                 item_editor_state
                     .map_current_item_if_exists_result(|i| {
-                        i.item.set_level(item_level_input as usize)
+                        i.item.relevel(item_level_input as usize, keep_mayhem_level)
                     })
                     .handle_ui_error("Failed to set level for item", &mut notification);
 
@@ -802,12 +1164,8 @@ impl ItemEditorInteractionMessage {
                     let original_index = item.index;
 
                     match item_editor_file_type {
-                        ItemEditorFileType::Save(s) => {
-                            s.character_data.remove_inventory_item(original_index)
-                        }
-                        ItemEditorFileType::ProfileBank(p) => {
-                            p.profile_data.remove_bank_item(original_index)
-                        }
+                        ItemEditorFileType::Save(s) => s.remove_inventory_item(original_index),
+                        ItemEditorFileType::ProfileBank(p) => p.remove_bank_item(original_index),
                     }
 
                     item_editor_state.remove_item(id);
@@ -961,10 +1319,12 @@ impl ItemEditorInteractionMessage {
     }
 }
 
-pub fn view<F>(
-    item_editor_state: &mut ItemEditorState,
+pub fn view<'a, F>(
+    item_editor_state: &'a mut ItemEditorState,
+    extra_options: Option<Element<'a, Bl3Message>>,
+    extra_row: Option<Element<'a, Bl3Message>>,
     interaction_message: F,
-) -> Container<Bl3Message>
+) -> Container<'a, Bl3Message>
 where
     F: Fn(ItemEditorInteractionMessage) -> InteractionMessage + 'static + Copy,
 {
@@ -1042,6 +1402,52 @@ where
         .into_element(),
     );
 
+    let inventory_layout_button = Container::new(
+        Button::new(
+            &mut item_editor_state.inventory_layout_button_state,
+            Text::new(format!(
+                "{} View",
+                item_editor_state.inventory_layout.toggled()
+            ))
+            .font(JETBRAINS_MONO_BOLD)
+            .size(17),
+        )
+        .on_press(interaction_message(
+            ItemEditorInteractionMessage::InventoryLayoutSelected(
+                item_editor_state.inventory_layout.toggled(),
+            ),
+        ))
+        .padding(10)
+        .style(Bl3UiStyle)
+        .into_element(),
+    );
+
+    let verify_items_button = if item_editor_state.item_api_url.is_some() {
+        Some(Container::new(
+            Tooltip::new(
+                Button::new(
+                    &mut item_editor_state.verify_items_button_state,
+                    Text::new("Verify Items").font(JETBRAINS_MONO_BOLD).size(17),
+                )
+                .on_press(interaction_message(
+                    ItemEditorInteractionMessage::VerifyItemsPressed,
+                ))
+                .padding(10)
+                .style(Bl3UiStyle)
+                .into_element(),
+                "Check every item against the item API set in the settings",
+                tooltip::Position::Top,
+            )
+            .gap(10)
+            .padding(10)
+            .font(JETBRAINS_MONO)
+            .size(17)
+            .style(Bl3UiTooltipStyle),
+        ))
+    } else {
+        None
+    };
+
     let edit_all_item_levels_input = Container::new(
         Row::new()
             .push(
@@ -1094,8 +1500,19 @@ where
     .width(Length::Fill)
     .style(Bl3UiStyle);
 
-    let general_options_row = Row::new()
+    let mut general_options_row = Row::new()
         .push(create_item_button)
+        .push(inventory_layout_button);
+
+    if let Some(verify_items_button) = verify_items_button {
+        general_options_row = general_options_row.push(verify_items_button);
+    }
+
+    if let Some(extra_options) = extra_options {
+        general_options_row = general_options_row.push(extra_options);
+    }
+
+    let general_options_row = general_options_row
         .push(
             Container::new(serial_importer)
                 .width(Length::FillPortion(8))
@@ -1193,6 +1610,17 @@ where
 
     let mut inventory_item_categories = HashSet::new();
 
+    let owned_dlc = &item_editor_state.owned_dlc;
+
+    let favorite_anointments = &item_editor_state.favorite_anointments;
+    let favorite_items = &item_editor_state.favorite_items;
+    let keep_mayhem_level = item_editor_state.keep_mayhem_level;
+
+    let is_card_layout = item_editor_state.inventory_layout == InventoryLayout::Card;
+    let inventory_columns = item_editor_state.inventory_columns;
+
+    let mut pending_item_cards = Vec::new();
+
     // Keeping this here as we want the "editor" to show in both ItemListTabType views
     let inventory_items = item_editor_state.items.iter_mut().enumerate().fold(
         Column::new().align_items(Alignment::Start),
@@ -1206,7 +1634,7 @@ where
                     .par_iter()
                     .any(|(_, i)| i.item_type == item_type)
             {
-                curr = curr.push(
+                curr = push_item_cards(curr, &mut pending_item_cards).push(
                     Container::new(
                         Text::new(format!("{}s", item_type))
                             .font(JETBRAINS_MONO_BOLD)
@@ -1223,7 +1651,31 @@ where
 
             let is_active = i == selected_item_index;
 
-            let (list_item_button, curr_item_editor) = item.view(i, is_active, interaction_message);
+            let (list_item_button, curr_item_editor) = if is_card_layout {
+                item.card_view(
+                    i,
+                    is_active,
+                    owned_dlc,
+                    favorite_anointments,
+                    keep_mayhem_level,
+                    interaction_message,
+                )
+            } else {
+                let is_favorite = favorite_items
+                    .iter()
+                    .any(|f| f.item.as_ref() == Some(&item.item));
+
+                item.view(
+                    i,
+                    is_active,
+                    is_favorite,
+                    owned_dlc,
+                    favorite_anointments,
+                    keep_mayhem_level,
+                    &inventory_columns,
+                    interaction_message,
+                )
+            };
 
             // Check if the curr item index is in our filtered_items to decide whether to show the
             // list item button or not.
@@ -1232,7 +1684,11 @@ where
                     .par_iter()
                     .any(|(fi_index, _)| *fi_index == i)
             {
-                curr = curr.push(list_item_button);
+                if is_card_layout {
+                    pending_item_cards.push(list_item_button);
+                } else {
+                    curr = curr.push(list_item_button);
+                }
             }
 
             if is_active {
@@ -1243,6 +1699,8 @@ where
         },
     );
 
+    let inventory_items = push_item_cards(inventory_items, &mut pending_item_cards);
+
     match item_editor_state.item_list_tab_type {
         ItemListTabType::Items => {
             if number_of_items > 0 {
@@ -1321,7 +1779,8 @@ where
                             lootlemon_item_categories.insert(item_type);
                         }
 
-                        let lootlemon_item_view = item.view(i, interaction_message);
+                        let lootlemon_item_view =
+                            item.view(i, &inventory_columns, interaction_message);
 
                         // Check if the curr item index is in our filtered_items to decide whether to show the
                         // list item button or not.
@@ -1365,10 +1824,52 @@ where
     };
 
     let item_list = Container::new(item_list_contents)
-        .width(Length::FillPortion(3))
+        .width(Length::Fill)
         .height(Length::Fill)
         .style(Bl3UiStyle);
 
+    let mut item_list = Column::new()
+        .push(item_list)
+        .width(Length::FillPortion(3))
+        .spacing(20);
+
+    if !item_editor_state.favorite_items.is_empty() {
+        let number_of_favorite_items = item_editor_state.favorite_items.len();
+
+        let favorite_items = item_editor_state
+            .favorite_items
+            .iter_mut()
+            .enumerate()
+            .fold(Column::new().spacing(10), |curr, (i, favorite_item)| {
+                curr.push(favorite_item.view(i, interaction_message))
+            });
+
+        item_list = item_list.push(
+            Container::new(
+                Column::new()
+                    .push(
+                        Text::new(format!(
+                            "Favorites ({}/{})",
+                            number_of_favorite_items, MAX_FAVORITE_ITEMS
+                        ))
+                        .font(JETBRAINS_MONO_BOLD)
+                        .size(17)
+                        .color(Color::from_rgb8(242, 203, 5)),
+                    )
+                    .push(
+                        Scrollable::new(&mut item_editor_state.favorite_items_scrollable_state)
+                            .push(favorite_items)
+                            .height(Length::Fill),
+                    )
+                    .spacing(10),
+            )
+            .width(Length::Fill)
+            .height(Length::Units(220))
+            .padding(10)
+            .style(Bl3UiStyle),
+        );
+    }
+
     let mut item_list_and_editor = Row::new().push(item_list).spacing(20);
 
     if let Some(item_editor) = item_editor {
@@ -1379,14 +1880,37 @@ where
         );
     }
 
-    let all_contents = Column::new()
-        .push(general_options_row)
-        .push(item_list_and_editor)
-        .spacing(20);
+    let mut all_contents = Column::new().push(general_options_row).spacing(20);
+
+    if let Some(extra_row) = extra_row {
+        all_contents = all_contents.push(extra_row);
+    }
+
+    let all_contents = all_contents.push(item_list_and_editor);
 
     Container::new(all_contents).padding(30)
 }
 
+fn push_item_cards<'a>(
+    column: Column<'a, Bl3Message>,
+    item_cards: &mut Vec<Element<'a, Bl3Message>>,
+) -> Column<'a, Bl3Message> {
+    let mut item_cards = item_cards.drain(..).peekable();
+
+    let mut column = column;
+
+    while item_cards.peek().is_some() {
+        let row = item_cards
+            .by_ref()
+            .take(ITEM_CARDS_PER_ROW)
+            .fold(Row::new().spacing(10), |row, card| row.push(card));
+
+        column = column.push(Container::new(row).padding(5));
+    }
+
+    column
+}
+
 pub fn get_filtered_items(
     search_items_query: &str,
     item_list_tab_type: &ItemListTabType,
@@ -1,20 +1,24 @@
 use iced::alignment::{Horizontal, Vertical};
 use iced::{
-    button, scrollable, text_input, Alignment, Button, Checkbox, Color, Column, Container, Element,
-    Length, Row, Scrollable, Text,
+    button, scrollable, text_input, tooltip, Alignment, Button, Checkbox, Color, Column, Container,
+    Element, Length, Row, Scrollable, Text, Tooltip,
 };
 use rayon::iter::IntoParallelRefIterator;
 use rayon::iter::ParallelIterator;
 
 use bl3_save_edit_core::bl3_item::Bl3Item;
-use bl3_save_edit_core::resources::{ResourceCategorizedParts, ResourcePart};
+use bl3_save_edit_core::content_source::ContentSource;
+use bl3_save_edit_core::favorite_anointments::FavoriteAnointment;
+use bl3_save_edit_core::resources::{ResourceCategorizedParts, ResourcePart, INVENTORY_SERIAL_DB};
 
 use crate::bl3_ui::{Bl3Message, InteractionMessage};
-use crate::bl3_ui_style::{Bl3UiStyle, Bl3UiStyleNoBorder};
+use crate::bl3_ui_style::{Bl3UiStyle, Bl3UiStyleNoBorder, Bl3UiTooltipStyle};
 use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
 use crate::views::item_editor::extra_part_info::add_extra_part_info;
-use crate::views::item_editor::item_button_style::ItemEditorButtonStyle;
-use crate::views::item_editor::parts::filter_parts;
+use crate::views::item_editor::item_button_style::{
+    ItemEditorButtonStyle, ItemEditorListButtonStyle,
+};
+use crate::views::item_editor::parts::{filter_parts, part_label};
 use crate::views::item_editor::parts_tab_bar::AvailablePartType;
 use crate::views::item_editor::ItemEditorInteractionMessage;
 use crate::views::tab_bar_button::tab_bar_button;
@@ -76,6 +80,7 @@ pub struct AvailableResourcePart {
     part_type: AvailablePartType,
     pub part: ResourcePart,
     button_state: button::State,
+    favorite_button_state: button::State,
 }
 
 impl AvailableResourcePart {
@@ -91,16 +96,32 @@ impl AvailableResourcePart {
             part_type,
             part,
             button_state: button::State::new(),
+            favorite_button_state: button::State::new(),
         }
     }
 
-    pub fn view<F>(&mut self, is_active: bool, interaction_message: F) -> Element<Bl3Message>
+    pub fn view<F>(
+        &mut self,
+        is_active: bool,
+        is_favorite: bool,
+        owned_dlc: &[ContentSource],
+        interaction_message: F,
+    ) -> Element<Bl3Message>
     where
         F: Fn(ItemEditorInteractionMessage) -> InteractionMessage + 'static + Copy,
     {
-        let part_contents_col = Column::new()
-            .push(Text::new(&self.part.name).font(JETBRAINS_MONO).size(16))
-            .spacing(10);
+        let content_source = INVENTORY_SERIAL_DB.get_content_source(&self.part.name);
+        let is_owned = content_source.is_owned(owned_dlc);
+
+        let mut part_name = Text::new(part_label(&self.part.name))
+            .font(JETBRAINS_MONO)
+            .size(16);
+
+        if !is_owned {
+            part_name = part_name.color(Color::from_rgb8(120, 120, 120));
+        }
+
+        let part_contents_col = Column::new().push(part_name).spacing(10);
 
         let part_contents_col = add_extra_part_info(part_contents_col, &self.part.info);
 
@@ -111,7 +132,7 @@ impl AvailableResourcePart {
             part_index: self.part_index,
         };
 
-        Button::new(&mut self.button_state, part_contents)
+        let part_button = Button::new(&mut self.button_state, part_contents)
             .on_press(interaction_message(match self.part_type {
                 AvailablePartType::Parts => {
                     ItemEditorInteractionMessage::AvailablePartPressed(index)
@@ -122,8 +143,53 @@ impl AvailableResourcePart {
             }))
             .padding(10)
             .width(Length::Fill)
-            .style(ItemEditorButtonStyle { is_active })
+            .style(ItemEditorButtonStyle { is_active });
+
+        let part_button = if is_owned {
+            part_button.into_element()
+        } else {
+            Tooltip::new(
+                part_button,
+                format!(
+                    "From {}, which is not selected as owned in Settings",
+                    content_source
+                ),
+                tooltip::Position::Top,
+            )
+            .gap(10)
+            .padding(10)
+            .font(JETBRAINS_MONO)
+            .size(17)
+            .style(Bl3UiTooltipStyle)
             .into_element()
+        };
+
+        if self.part_type == AvailablePartType::Anointments {
+            Row::new()
+                .push(Container::new(part_button).width(Length::Fill))
+                .push(
+                    Button::new(
+                        &mut self.favorite_button_state,
+                        Text::new(if is_favorite { "Unstar" } else { "Star" })
+                            .font(JETBRAINS_MONO_BOLD)
+                            .size(16)
+                            .horizontal_alignment(Horizontal::Center),
+                    )
+                    .on_press(interaction_message(
+                        ItemEditorInteractionMessage::AvailableAnointmentFavoriteToggled(
+                            self.part.name.clone(),
+                        ),
+                    ))
+                    .padding(5)
+                    .width(Length::Units(85))
+                    .style(ItemEditorListButtonStyle),
+                )
+                .align_items(Alignment::Center)
+                .spacing(10)
+                .into_element()
+        } else {
+            part_button
+        }
     }
 }
 
@@ -138,6 +204,10 @@ pub struct AvailableParts {
     pub available_anointments_tab_button_state: button::State,
     pub search_input: String,
     pub search_input_state: text_input::State,
+    pub favorite_button_states: Vec<[button::State; 2]>,
+    pub selected_category: Option<String>,
+    pub category_button_states: Vec<button::State>,
+    pub change_category_button_state: button::State,
 }
 
 impl AvailableParts {
@@ -147,6 +217,8 @@ impl AvailableParts {
         anointments_list: &[ResourceCategorizedParts],
         specific_parts_list: Option<&Vec<ResourceCategorizedParts>>,
         all_parts_list: Option<&Vec<ResourceCategorizedParts>>,
+        owned_dlc: &[ContentSource],
+        favorite_anointments: &[FavoriteAnointment],
         interaction_message: F,
     ) -> Container<Bl3Message>
     where
@@ -186,20 +258,6 @@ impl AvailableParts {
         let available_parts = if item.item_parts.is_some() {
             match self.parts_tab_type {
                 AvailablePartType::Parts => {
-                    let specific_parts = specific_parts_list.map(|i| {
-                        AvailableCategorizedPart::from_resource_categorized_parts(
-                            AvailablePartType::Parts,
-                            i,
-                        )
-                    });
-
-                    let all_parts = all_parts_list.map(|i| {
-                        AvailableCategorizedPart::from_resource_categorized_parts(
-                            AvailablePartType::Parts,
-                            i,
-                        )
-                    });
-
                     let show_all_parts_checkbox =
                         Checkbox::new(self.show_all_available_parts, "Show All Parts", move |c| {
                             interaction_message(
@@ -213,7 +271,7 @@ impl AvailableParts {
                         .style(Bl3UiStyle)
                         .into_element();
 
-                    if specific_parts.is_some() {
+                    if specific_parts_list.is_some() {
                         available_parts_column = available_parts_column.push(
                             Container::new(
                                 Container::new(show_all_parts_checkbox)
@@ -225,10 +283,116 @@ impl AvailableParts {
                         );
                     }
 
-                    if self.show_all_available_parts || specific_parts.is_none() {
-                        all_parts
-                    } else {
-                        specific_parts
+                    let parts_list =
+                        if self.show_all_available_parts || specific_parts_list.is_none() {
+                            all_parts_list
+                        } else {
+                            specific_parts_list
+                        };
+
+                    // Parts are added one category at a time so the category has to be picked first
+                    let selected_category = parts_list.and_then(|parts_list| {
+                        parts_list
+                            .iter()
+                            .find(|c| Some(&c.category) == self.selected_category.as_ref())
+                    });
+
+                    match (parts_list, selected_category) {
+                        (Some(_), Some(selected_category)) => {
+                            let change_category_button = Button::new(
+                                &mut self.change_category_button_state,
+                                Text::new("Change Category")
+                                    .font(JETBRAINS_MONO_BOLD)
+                                    .size(16)
+                                    .horizontal_alignment(Horizontal::Center),
+                            )
+                            .on_press(interaction_message(
+                                ItemEditorInteractionMessage::AvailablePartsCategorySelected(None),
+                            ))
+                            .padding(5)
+                            .style(ItemEditorListButtonStyle);
+
+                            let category_row = Row::new()
+                                .push(
+                                    Text::new(category_title(item, selected_category))
+                                        .font(JETBRAINS_MONO_BOLD)
+                                        .size(17)
+                                        .color(Color::from_rgb8(242, 203, 5))
+                                        .width(Length::Fill),
+                                )
+                                .push(change_category_button)
+                                .spacing(15)
+                                .align_items(Alignment::Center);
+
+                            available_parts_column = available_parts_column.push(
+                                Container::new(
+                                    Container::new(category_row)
+                                        .padding(15)
+                                        .width(Length::Fill)
+                                        .style(Bl3UiStyleNoBorder),
+                                )
+                                .padding(1),
+                            );
+
+                            Some(AvailableCategorizedPart::from_resource_categorized_parts(
+                                AvailablePartType::Parts,
+                                &[selected_category.clone()],
+                            ))
+                        }
+                        (Some(parts_list), None) => {
+                            self.category_button_states
+                                .resize_with(parts_list.len(), Default::default);
+
+                            let categories_list = parts_list
+                                .iter()
+                                .zip(self.category_button_states.iter_mut())
+                                .fold(Column::new(), |curr, (category, button_state)| {
+                                    curr.push(
+                                        Button::new(
+                                            button_state,
+                                            Text::new(category_title(item, category))
+                                                .font(JETBRAINS_MONO)
+                                                .size(16),
+                                        )
+                                        .on_press(interaction_message(
+                                            ItemEditorInteractionMessage::AvailablePartsCategorySelected(
+                                                Some(category.category.clone()),
+                                            ),
+                                        ))
+                                        .padding(10)
+                                        .width(Length::Fill)
+                                        .style(ItemEditorButtonStyle { is_active: false }),
+                                    )
+                                });
+
+                            available_parts_column = available_parts_column
+                                .push(
+                                    Container::new(
+                                        Text::new("Select a category to add parts from")
+                                            .font(JETBRAINS_MONO_BOLD)
+                                            .size(17)
+                                            .color(Color::from_rgb8(242, 203, 5)),
+                                    )
+                                    .width(Length::Fill)
+                                    .style(Bl3UiStyleNoBorder)
+                                    .padding(10),
+                                )
+                                .push(
+                                    Container::new(
+                                        Scrollable::new(&mut self.scrollable_state)
+                                            .push(categories_list)
+                                            .height(Length::Fill)
+                                            .width(Length::Fill),
+                                    )
+                                    .padding(1),
+                                );
+
+                            return Container::new(available_parts_column)
+                                .width(Length::FillPortion(2))
+                                .height(Length::Fill)
+                                .style(Bl3UiStyle);
+                        }
+                        (None, _) => None,
                     }
                 }
                 AvailablePartType::Anointments => {
@@ -242,6 +406,45 @@ impl AvailableParts {
             None
         };
 
+        if item.item_parts.is_some()
+            && self.parts_tab_type == AvailablePartType::Anointments
+            && !favorite_anointments.is_empty()
+        {
+            self.favorite_button_states
+                .resize_with(favorite_anointments.len(), Default::default);
+
+            let favorites = favorite_anointments
+                .iter()
+                .zip(self.favorite_button_states.iter_mut())
+                .fold(
+                    Column::new().push(
+                        Text::new("Favorites")
+                            .font(JETBRAINS_MONO_BOLD)
+                            .size(17)
+                            .color(Color::from_rgb8(242, 203, 5)),
+                    ),
+                    |curr, (favorite, [first_button_state, second_button_state])| {
+                        curr.push(favorite_anointment_row(
+                            favorite,
+                            first_button_state,
+                            second_button_state,
+                            interaction_message,
+                        ))
+                    },
+                )
+                .spacing(10);
+
+            available_parts_column = available_parts_column.push(
+                Container::new(
+                    Container::new(favorites)
+                        .padding(10)
+                        .width(Length::Fill)
+                        .style(Bl3UiStyleNoBorder),
+                )
+                .padding(1),
+            );
+        }
+
         if let Some(available_parts) = &available_parts {
             let amount: usize = available_parts.iter().map(|cat_p| cat_p.parts.len()).sum();
 
@@ -278,24 +481,22 @@ impl AvailableParts {
 
             let filtered_parts = filter_parts(&self.search_input, available_parts);
 
-            let category_name = if self.parts_tab_type == AvailablePartType::Anointments {
-                Some("Anointment")
-            } else {
-                None
-            };
+            let is_anointments = self.parts_tab_type == AvailablePartType::Anointments;
 
             if !filtered_parts.is_empty() {
                 let available_parts_list = self.parts.iter_mut().enumerate().fold(
                     Column::new(),
                     |mut curr, (cat_index, cat_parts)| {
-                        if cat_parts
-                            .parts
-                            .par_iter()
-                            .any(|cat_p| filtered_parts.contains(&cat_p))
+                        // The selected part category is already shown above the search
+                        if is_anointments
+                            && cat_parts
+                                .parts
+                                .par_iter()
+                                .any(|cat_p| filtered_parts.contains(&cat_p))
                         {
                             curr = curr.push(
                                 Container::new(
-                                    Text::new(category_name.unwrap_or(&cat_parts.category))
+                                    Text::new("Anointment")
                                         .font(JETBRAINS_MONO_BOLD)
                                         .size(17)
                                         .color(Color::from_rgb8(242, 203, 5)),
@@ -312,7 +513,16 @@ impl AvailableParts {
                                     == cat_index
                                     && selected_available_part_type_index.part_index == part_index;
 
-                                curr = curr.push(p.view(is_active, interaction_message));
+                                let is_favorite = favorite_anointments
+                                    .iter()
+                                    .any(|f| f.short_ident() == Some(p.part.name.as_str()));
+
+                                curr = curr.push(p.view(
+                                    is_active,
+                                    is_favorite,
+                                    owned_dlc,
+                                    interaction_message,
+                                ));
                             }
                         }
 
@@ -364,3 +574,85 @@ impl AvailableParts {
             .style(Bl3UiStyle)
     }
 }
+
+fn favorite_anointment_row<'a, F>(
+    favorite: &FavoriteAnointment,
+    first_button_state: &'a mut button::State,
+    second_button_state: &'a mut button::State,
+    interaction_message: F,
+) -> Row<'a, Bl3Message>
+where
+    F: Fn(ItemEditorInteractionMessage) -> InteractionMessage + 'static + Copy,
+{
+    let action_button = |state, label, message| {
+        Button::new(
+            state,
+            Text::new(label)
+                .font(JETBRAINS_MONO_BOLD)
+                .size(16)
+                .horizontal_alignment(Horizontal::Center),
+        )
+        .on_press(interaction_message(message))
+        .padding(5)
+        .width(Length::Units(85))
+        .style(ItemEditorListButtonStyle)
+        .into_element()
+    };
+
+    let row = match favorite {
+        FavoriteAnointment::Available(part) => Row::new()
+            .push(
+                Text::new(part.short_ident.as_deref().unwrap_or(&part.ident))
+                    .font(JETBRAINS_MONO)
+                    .size(16)
+                    .color(Color::from_rgb8(220, 220, 220))
+                    .width(Length::Fill),
+            )
+            .push(action_button(
+                first_button_state,
+                "Add",
+                ItemEditorInteractionMessage::FavoriteAnointmentPressed(part.clone()),
+            ))
+            .push(action_button(
+                second_button_state,
+                "Apply All",
+                ItemEditorInteractionMessage::ApplyAnointmentToAllPressed(part.clone()),
+            )),
+        FavoriteAnointment::Stale(ident) => Row::new()
+            .push(
+                Container::new(
+                    Tooltip::new(
+                        Text::new(ident)
+                            .font(JETBRAINS_MONO)
+                            .size(16)
+                            .color(Color::from_rgb8(120, 120, 120)),
+                        "This anointment no longer exists in the inventory serial database",
+                        tooltip::Position::Top,
+                    )
+                    .gap(10)
+                    .padding(10)
+                    .font(JETBRAINS_MONO)
+                    .size(17)
+                    .style(Bl3UiTooltipStyle),
+                )
+                .width(Length::Fill),
+            )
+            .push(action_button(
+                first_button_state,
+                "Remove",
+                ItemEditorInteractionMessage::FavoriteAnointmentRemoved(ident.clone()),
+            )),
+    };
+
+    row.align_items(Alignment::Center).spacing(10)
+}
+
+fn category_title(item: &Bl3Item, category: &ResourceCategorizedParts) -> String {
+    let count = item
+        .item_parts
+        .as_ref()
+        .map(|ip| ip.category_part_count(&category.category))
+        .unwrap_or(0);
+
+    format!("{} ({}/{})", category.category, count, category.max_parts())
+}
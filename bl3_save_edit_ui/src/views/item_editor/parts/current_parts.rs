@@ -1,4 +1,4 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 use iced::alignment::{Horizontal, Vertical};
 use iced::{
@@ -11,14 +11,18 @@ use rayon::prelude::ParallelSliceMut;
 use bl3_save_edit_core::bl3_item::{
     Bl3Item, Bl3Part, MAX_BL3_ITEM_ANOINTMENTS, MAX_BL3_ITEM_PARTS,
 };
-use bl3_save_edit_core::resources::{ResourceCategorizedParts, ResourcePart, ResourcePartInfo};
+use bl3_save_edit_core::resources::{
+    ResourceCategorizedParts, ResourcePart, ResourcePartInfo, INVENTORY_SERIAL_DB_PARTS_CATEGORIZED,
+};
 
 use crate::bl3_ui::{Bl3Message, InteractionMessage};
 use crate::bl3_ui_style::{Bl3UiStyle, Bl3UiStyleNoBorder, Bl3UiTooltipStyle};
 use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
 use crate::views::item_editor::extra_part_info::add_extra_part_info;
-use crate::views::item_editor::item_button_style::ItemEditorButtonStyle;
-use crate::views::item_editor::parts::filter_parts;
+use crate::views::item_editor::item_button_style::{
+    ItemEditorButtonStyle, ItemEditorListButtonStyle,
+};
+use crate::views::item_editor::parts::{filter_parts, part_label};
 use crate::views::item_editor::parts_tab_bar::CurrentPartType;
 use crate::views::item_editor::ItemEditorInteractionMessage;
 use crate::views::tab_bar_button::tab_bar_button;
@@ -34,6 +38,7 @@ pub struct CurrentPartTypeIndex {
 #[derive(Debug, Clone)]
 pub struct CurrentCategorizedPart {
     pub category: String,
+    pub max_parts: Option<u8>,
     pub parts: Vec<CurrentItemEditorPart>,
 }
 
@@ -41,6 +46,7 @@ impl CurrentCategorizedPart {
     pub fn new(
         category_id: usize,
         category: String,
+        max_parts: Option<u8>,
         part_type: CurrentPartType,
         parts: Vec<Bl3PartWithInfo>,
     ) -> Self {
@@ -50,7 +56,18 @@ impl CurrentCategorizedPart {
             .map(|(id, p)| CurrentItemEditorPart::new(category_id, id, part_type.clone(), p))
             .collect();
 
-        Self { category, parts }
+        Self {
+            category,
+            max_parts,
+            parts,
+        }
+    }
+
+    pub fn title(&self) -> String {
+        match self.max_parts {
+            Some(max_parts) => format!("{} ({}/{})", self.category, self.parts.len(), max_parts),
+            None => format!("{} ({})", self.category, self.parts.len()),
+        }
     }
 }
 
@@ -61,6 +78,8 @@ pub struct CurrentItemEditorPart {
     part_type: CurrentPartType,
     pub part: Bl3PartWithInfo,
     button_state: button::State,
+    lock_button_state: button::State,
+    apply_to_all_button_state: button::State,
 }
 
 impl CurrentItemEditorPart {
@@ -76,6 +95,8 @@ impl CurrentItemEditorPart {
             part_type,
             part,
             button_state: button::State::new(),
+            lock_button_state: button::State::new(),
+            apply_to_all_button_state: button::State::new(),
         }
     }
 
@@ -83,6 +104,7 @@ impl CurrentItemEditorPart {
         &mut self,
         reorder_parts: bool,
         is_active: bool,
+        is_locked: bool,
         interaction_message: F,
     ) -> Element<Bl3Message>
     where
@@ -92,13 +114,13 @@ impl CurrentItemEditorPart {
 
         let part_contents_col = Column::new()
             .push(
-                Text::new(
+                Text::new(part_label(
                     self.part
                         .part
                         .short_ident
                         .as_ref()
                         .unwrap_or(&self.part.part.ident),
-                )
+                ))
                 .font(JETBRAINS_MONO)
                 .size(16),
             )
@@ -113,7 +135,7 @@ impl CurrentItemEditorPart {
             part_index: self.part_index,
         };
 
-        Button::new(&mut self.button_state, part_contents)
+        let part_button = Button::new(&mut self.button_state, part_contents)
             .on_press(interaction_message(match self.part_type {
                 CurrentPartType::Parts => {
                     ItemEditorInteractionMessage::CurrentPartPressed(reorder_parts, index)
@@ -124,7 +146,67 @@ impl CurrentItemEditorPart {
             }))
             .padding(10)
             .width(Length::Fill)
-            .style(ItemEditorButtonStyle { is_active })
+            .style(ItemEditorButtonStyle { is_active });
+
+        let lock_button = Tooltip::new(
+            Button::new(
+                &mut self.lock_button_state,
+                Text::new(if is_locked { "Unlock" } else { "Lock" })
+                    .font(JETBRAINS_MONO_BOLD)
+                    .size(16)
+                    .horizontal_alignment(Horizontal::Center),
+            )
+            .on_press(interaction_message(
+                ItemEditorInteractionMessage::CurrentPartLockToggled(self.part.part.ident.clone()),
+            ))
+            .padding(5)
+            .width(Length::Units(85))
+            .style(ItemEditorListButtonStyle),
+            "Locked parts are not changed by edits to all items",
+            tooltip::Position::Left,
+        )
+        .gap(10)
+        .padding(10)
+        .font(JETBRAINS_MONO)
+        .size(17)
+        .style(Bl3UiTooltipStyle);
+
+        let mut part_actions = Column::new().push(lock_button).spacing(10);
+
+        if self.part_type == CurrentPartType::Anointments && !self.part.part.is_mayhem_part() {
+            part_actions = part_actions.push(
+                Button::new(
+                    &mut self.apply_to_all_button_state,
+                    Text::new("Apply All")
+                        .font(JETBRAINS_MONO_BOLD)
+                        .size(16)
+                        .horizontal_alignment(Horizontal::Center),
+                )
+                .on_press(interaction_message(
+                    ItemEditorInteractionMessage::ApplyAnointmentToAllPressed(
+                        self.part.part.clone(),
+                    ),
+                ))
+                .padding(5)
+                .width(Length::Units(85))
+                .style(ItemEditorListButtonStyle),
+            );
+        }
+
+        let mut part_row = Row::new().push(part_button).spacing(10);
+
+        if is_locked {
+            part_row = part_row.push(
+                Text::new("Locked")
+                    .font(JETBRAINS_MONO)
+                    .size(16)
+                    .color(Color::from_rgb8(242, 203, 5)),
+            );
+        }
+
+        part_row
+            .push(part_actions)
+            .align_items(Alignment::Center)
             .into_element()
     }
 }
@@ -132,6 +214,7 @@ impl CurrentItemEditorPart {
 #[derive(Debug, Clone)]
 pub struct ItemEditorCategorizedParts {
     pub category: String,
+    pub max_parts: Option<u8>,
     pub parts: Vec<Bl3PartWithInfo>,
 }
 
@@ -141,6 +224,43 @@ pub struct Bl3PartWithInfo {
     pub info: ResourcePartInfo,
 }
 
+/// An item's parts matched up with their resource info. This is too slow to redo on every redraw so
+/// it's kept until the item it was decoded from changes.
+#[derive(Debug, Clone, Default)]
+pub struct DecodedItemParts {
+    item: Bl3Item,
+    categorized_parts: Vec<CurrentCategorizedPart>,
+    regular_parts: Vec<CurrentCategorizedPart>,
+    anointments: Vec<CurrentCategorizedPart>,
+}
+
+impl DecodedItemParts {
+    pub fn new(item: Bl3Item) -> Self {
+        let inventory_serial_db_parts_categorized = &*INVENTORY_SERIAL_DB_PARTS_CATEGORIZED;
+
+        let anointments_list = inventory_serial_db_parts_categorized
+            .get("InventoryGenericPartData")
+            .expect("Missing generic part data.");
+
+        let all_parts_list = item
+            .item_parts
+            .as_ref()
+            .map(|ip| ip.part_inv_key.as_str())
+            .and_then(|p| inventory_serial_db_parts_categorized.get(p));
+
+        Self {
+            categorized_parts: CurrentParts::categorized_parts(&item, all_parts_list),
+            regular_parts: CurrentParts::regular_parts(&item, all_parts_list),
+            anointments: CurrentParts::regular_anointments(&item, anointments_list),
+            item,
+        }
+    }
+
+    pub fn is_for(&self, item: &Bl3Item) -> bool {
+        self.item == *item
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct CurrentParts {
     pub scrollable_state: scrollable::State,
@@ -162,10 +282,8 @@ impl CurrentParts {
     pub fn view<F>(
         &mut self,
         item: &Bl3Item,
-        anointments_list: &[ResourceCategorizedParts],
-        #[allow(unused_variables)] // TODO: remove if unneeded
-        specific_parts_list: Option<&Vec<ResourceCategorizedParts>>,
-        all_parts_list: Option<&Vec<ResourceCategorizedParts>>,
+        locked_parts: &BTreeSet<String>,
+        decoded_parts: Option<&DecodedItemParts>,
         interaction_message: F,
     ) -> Container<Bl3Message>
     where
@@ -219,11 +337,15 @@ impl CurrentParts {
 
         match self.parts_tab_type {
             CurrentPartType::Parts => {
-                if reorder_parts {
-                    parts = Self::regular_parts(item, all_parts_list);
-                } else {
-                    parts = Self::categorized_parts(item, all_parts_list);
-                }
+                parts = decoded_parts
+                    .map(|d| {
+                        if reorder_parts {
+                            d.regular_parts.clone()
+                        } else {
+                            d.categorized_parts.clone()
+                        }
+                    })
+                    .unwrap_or_default();
 
                 self.parts = parts.clone();
 
@@ -339,7 +461,9 @@ impl CurrentParts {
                 }
             }
             CurrentPartType::Anointments => {
-                parts = Self::regular_anointments(item, anointments_list);
+                parts = decoded_parts
+                    .map(|d| d.anointments.clone())
+                    .unwrap_or_default();
 
                 self.parts = parts.clone();
             }
@@ -391,7 +515,7 @@ impl CurrentParts {
                             {
                                 curr = curr.push(
                                     Container::new(
-                                        Text::new(&cat_parts.category)
+                                        Text::new(cat_parts.title())
                                             .font(JETBRAINS_MONO_BOLD)
                                             .size(17)
                                             .color(Color::from_rgb8(242, 203, 5)),
@@ -407,9 +531,12 @@ impl CurrentParts {
                                     let is_active =
                                         selected_current_part_index.part_index == part_index;
 
+                                    let is_locked = locked_parts.contains(&p.part.part.ident);
+
                                     curr = curr.push(p.view(
                                         reorder_parts,
                                         is_active,
+                                        is_locked,
                                         interaction_message,
                                     ))
                                 }
@@ -433,6 +560,7 @@ impl CurrentParts {
             }
         } else {
             let msg = match self.parts_tab_type {
+                _ if decoded_parts.is_none() => "Decoding parts...",
                 CurrentPartType::Parts => "This item has no parts.",
                 CurrentPartType::Anointments => "This item has no anointments.",
             };
@@ -455,17 +583,10 @@ impl CurrentParts {
         if let Some(all_parts_list) = all_parts_list {
             if let Some(item_parts) = &item.item_parts {
                 item_parts.parts().iter().for_each(|p| {
-                    let resource_part: Option<&ResourcePart> =
-                        //Find extra info about the part
-                        all_parts_list.iter().find_map(|cat_resource| {
-                            cat_resource.parts.par_iter().find_first(|cat_part| {
-                                part_contains(
-                                    p.short_ident.as_ref(),
-                                    &p.ident,
-                                    &cat_part.name,
-                                )
-                            })
-                        });
+                    //Find extra info about the part
+                    let resource_part: Option<&ResourcePart> = all_parts_list
+                        .iter()
+                        .find_map(|cat_resource| cat_resource.find_part(p));
 
                     parts.push(Bl3PartWithInfo {
                         part: p.to_owned(),
@@ -481,6 +602,7 @@ impl CurrentParts {
         vec![CurrentCategorizedPart::new(
             0,
             "".to_owned(),
+            None,
             CurrentPartType::Parts,
             parts,
         )]
@@ -490,35 +612,29 @@ impl CurrentParts {
         item: &Bl3Item,
         all_parts_list: Option<&Vec<ResourceCategorizedParts>>,
     ) -> Vec<CurrentCategorizedPart> {
-        let mut categorized_parts: BTreeMap<String, Vec<Bl3PartWithInfo>> = BTreeMap::new();
+        let mut categorized_parts: BTreeMap<String, (Option<u8>, Vec<Bl3PartWithInfo>)> =
+            BTreeMap::new();
 
         if let Some(all_parts_list) = all_parts_list {
             if let Some(item_parts) = &item.item_parts {
                 item_parts.parts().iter().for_each(|p| {
-                    let resource_part: Option<(&String, &ResourcePart)> =
-                        //Find extra info about the part
-                        all_parts_list.par_iter().find_map_any(|cat_resource| {
-                            let part = cat_resource.parts.par_iter().find_first(|cat_part| {
-                                part_contains(
-                                    p.short_ident.as_ref(),
-                                    &p.ident,
-                                    &cat_part.name,
-                                )
-                            });
-
-                            part.map(|part| (&cat_resource.category, part))
+                    //Find extra info about the part
+                    let resource_part: Option<(&ResourceCategorizedParts, &ResourcePart)> =
+                        all_parts_list.iter().find_map(|cat_resource| {
+                            cat_resource.find_part(p).map(|part| (cat_resource, part))
                         });
 
-                    if let Some((category, resource_part)) = resource_part {
-                        let curr_cat_parts =
-                            categorized_parts.entry(category.to_owned()).or_default();
+                    if let Some((cat_resource, resource_part)) = resource_part {
+                        let (_, curr_cat_parts) = categorized_parts
+                            .entry(cat_resource.category.to_owned())
+                            .or_insert_with(|| (Some(cat_resource.max_parts()), Vec::new()));
 
                         curr_cat_parts.push(Bl3PartWithInfo {
                             part: p.to_owned(),
                             info: resource_part.info.to_owned(),
                         });
                     } else {
-                        let curr_cat_parts = categorized_parts
+                        let (_, curr_cat_parts) = categorized_parts
                             .entry("Unknown Parts".to_owned())
                             .or_default();
 
@@ -531,7 +647,7 @@ impl CurrentParts {
             }
         } else if let Some(item_parts) = &item.item_parts {
             item_parts.parts().iter().for_each(|p| {
-                let curr_cat_parts = categorized_parts
+                let (_, curr_cat_parts) = categorized_parts
                     .entry("Unknown Parts".to_owned())
                     .or_default();
 
@@ -543,10 +659,16 @@ impl CurrentParts {
         }
 
         let inventory_categorized_parts =
-            categorized_parts.into_iter().map(|(category, mut parts)| {
-                parts.par_sort();
-                ItemEditorCategorizedParts { category, parts }
-            });
+            categorized_parts
+                .into_iter()
+                .map(|(category, (max_parts, mut parts))| {
+                    parts.par_sort();
+                    ItemEditorCategorizedParts {
+                        category,
+                        max_parts,
+                        parts,
+                    }
+                });
 
         inventory_categorized_parts
             .into_iter()
@@ -555,6 +677,7 @@ impl CurrentParts {
                 CurrentCategorizedPart::new(
                     cat_id,
                     cat_p.category,
+                    cat_p.max_parts,
                     CurrentPartType::Parts,
                     cat_p.parts,
                 )
@@ -575,14 +698,9 @@ impl CurrentParts {
                     .or_default();
 
                 //Find extra info about the anointment
-                let resource_part: Option<&ResourcePart> =
-                    anointments_list.par_iter().find_map_any(|cat_resource| {
-                        let part = cat_resource.parts.par_iter().find_first(|cat_part| {
-                            part_contains(p.short_ident.as_ref(), &p.ident, &cat_part.name)
-                        });
-
-                        part
-                    });
+                let resource_part: Option<&ResourcePart> = anointments_list
+                    .par_iter()
+                    .find_map_any(|cat_resource| cat_resource.find_part(p));
 
                 curr_cat_parts.push(Bl3PartWithInfo {
                     part: p.to_owned(),
@@ -596,7 +714,11 @@ impl CurrentParts {
         let inventory_categorized_anointments =
             categorized_parts.into_iter().map(|(category, mut parts)| {
                 parts.par_sort();
-                ItemEditorCategorizedParts { category, parts }
+                ItemEditorCategorizedParts {
+                    category,
+                    max_parts: Some(MAX_BL3_ITEM_ANOINTMENTS as u8),
+                    parts,
+                }
             });
 
         inventory_categorized_anointments
@@ -606,6 +728,7 @@ impl CurrentParts {
                 CurrentCategorizedPart::new(
                     cat_id,
                     cat_p.category,
+                    cat_p.max_parts,
                     CurrentPartType::Anointments,
                     cat_p.parts,
                 )
@@ -614,16 +737,6 @@ impl CurrentParts {
     }
 }
 
-fn part_contains(short_ident: Option<&String>, ident: &str, cat_part_name: &str) -> bool {
-    if let Some(short_ident) = short_ident {
-        cat_part_name.eq_ignore_ascii_case(short_ident)
-    } else {
-        let name_with_stop = format!("{}.", cat_part_name.to_lowercase());
-
-        ident.to_lowercase().contains(&name_with_stop)
-    }
-}
-
 fn no_parts_message<'a>(message: &str) -> Container<'a, Bl3Message> {
     Container::new(
         Text::new(message)
@@ -3,6 +3,7 @@ use std::slice::Iter;
 
 use rayon::iter::{IntoParallelIterator, IntoParallelRefIterator, ParallelIterator};
 
+use bl3_save_edit_core::data_overrides::part_name;
 use bl3_save_edit_core::resources::ResourcePartInfo;
 
 use crate::views::item_editor::parts::available_parts::{
@@ -29,6 +30,14 @@ pub trait SearchableCategorizedParts {
     fn parts(&self) -> Iter<Self::PartType>;
 }
 
+/// The ident of a part, with the name from the user's data overrides in front when there is one.
+pub fn part_label(ident: &str) -> String {
+    match part_name(ident) {
+        Some(name) => format!("{} ({})", name, ident),
+        None => ident.to_owned(),
+    }
+}
+
 pub fn filter_parts<'a, T>(
     search_query: &str,
     parts: &'a [T],
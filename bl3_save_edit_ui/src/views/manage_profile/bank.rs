@@ -1,24 +1,58 @@
-use iced::Container;
+use iced::{button, tooltip, Button, Container, Text, Tooltip};
+
+use bl3_save_edit_core::item_sources::ItemSources;
 
 use crate::bl3_ui::{Bl3Message, InteractionMessage};
+use crate::bl3_ui_style::{Bl3UiStyle, Bl3UiTooltipStyle};
+use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
 use crate::views::item_editor;
 use crate::views::item_editor::{ItemEditorInteractionMessage, ItemEditorState};
 use crate::views::manage_profile::ManageProfileInteractionMessage;
+use crate::views::InteractionExt;
 
 #[derive(Debug, Default)]
 pub struct BankState {
     pub item_editor_state: ItemEditorState,
+    pub item_sources: ItemSources,
+    pub remove_all_items_button_state: button::State,
+    // How many items Remove All took out since the profile was last loaded or saved
+    pub removed_all_items: Option<usize>,
 }
 
 #[derive(Debug, Clone)]
 pub enum ProfileBankInteractionMessage {
     Editor(ItemEditorInteractionMessage),
+    ClearBank,
+    ClearBankConfirmed,
+}
+
+fn bank_message(message: ProfileBankInteractionMessage) -> InteractionMessage {
+    InteractionMessage::ManageProfileInteraction(ManageProfileInteractionMessage::Bank(message))
 }
 
 pub fn view(bank_state: &mut BankState) -> Container<Bl3Message> {
-    item_editor::view(&mut bank_state.item_editor_state, |i| {
-        InteractionMessage::ManageProfileInteraction(ManageProfileInteractionMessage::Bank(
-            ProfileBankInteractionMessage::Editor(i),
-        ))
-    })
+    let remove_all_items_button = Tooltip::new(
+        Button::new(
+            &mut bank_state.remove_all_items_button_state,
+            Text::new("Remove All").font(JETBRAINS_MONO_BOLD).size(17),
+        )
+        .on_press(bank_message(ProfileBankInteractionMessage::ClearBank))
+        .padding(10)
+        .style(Bl3UiStyle)
+        .into_element(),
+        "Remove every item from the bank",
+        tooltip::Position::Bottom,
+    )
+    .gap(10)
+    .padding(10)
+    .font(JETBRAINS_MONO)
+    .size(17)
+    .style(Bl3UiTooltipStyle);
+
+    item_editor::view(
+        &mut bank_state.item_editor_state,
+        Some(remove_all_items_button.into()),
+        None,
+        |i| bank_message(ProfileBankInteractionMessage::Editor(i)),
+    )
 }
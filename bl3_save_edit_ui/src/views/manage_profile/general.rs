@@ -1,16 +1,20 @@
 use iced::{
-    pick_list, text_input, tooltip, Alignment, Column, Container, Length, PickList, TextInput,
-    Tooltip,
+    button, pick_list, scrollable, text_input, tooltip, Alignment, Button, Checkbox, Color, Column,
+    Container, Length, PickList, Row, Scrollable, Text, TextInput, Tooltip,
 };
 
+use bl3_save_edit_core::bl3_profile::profile_data::ShiftEntitlementData;
+use bl3_save_edit_core::file_helper::{FileMetadata, GameStore};
 use bl3_save_edit_core::parser::HeaderType;
 
 use crate::bl3_ui::{Bl3Message, InteractionMessage};
 use crate::bl3_ui_style::{Bl3UiStyle, Bl3UiTooltipStyle};
-use crate::resources::fonts::JETBRAINS_MONO;
+use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
+use crate::views::file_metadata;
 use crate::views::manage_profile::ManageProfileInteractionMessage;
 use crate::views::InteractionExt;
 use crate::widgets::labelled_element::LabelledElement;
+use crate::widgets::number_input::NumberInput;
 
 #[derive(Debug, Default)]
 pub struct GeneralState {
@@ -18,11 +22,48 @@ pub struct GeneralState {
     pub filename_input_state: text_input::State,
     pub profile_type_selector: pick_list::State<HeaderType>,
     pub profile_type_selected: HeaderType,
+    pub game_store: GameStore,
+    pub guardian_experience_input: i32,
+    pub guardian_experience_input_state: text_input::State,
+    pub new_guardian_experience_input: i32,
+    pub new_guardian_experience_input_state: text_input::State,
+    pub mail_count: usize,
+    pub read_mail_count: usize,
+    pub clear_read_mail: bool,
+    pub clear_all_mail: bool,
+    pub clear_read_mail_button_state: button::State,
+    pub clear_all_mail_button_state: button::State,
+    pub create_blank_profile_button_state: button::State,
+    pub reset_to_defaults_button_state: button::State,
+    pub shift_entitlements: Vec<ShiftEntitlementData>,
+    pub shift_entitlements_scrollable_state: scrollable::State,
+    pub file_metadata: FileMetadata,
+}
+
+impl GeneralState {
+    pub fn mail_remaining(&self) -> (usize, usize) {
+        if self.clear_all_mail {
+            (0, 0)
+        } else if self.clear_read_mail {
+            (self.mail_count - self.read_mail_count, 0)
+        } else {
+            (self.mail_count, self.read_mail_count)
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
 pub enum ProfileGeneralInteractionMessage {
     ProfileTypeSelected(HeaderType),
+    GuardianExperience(i32),
+    NewGuardianExperience(i32),
+    ClearReadMailPressed,
+    ClearAllMailPressed,
+    CreateBlankProfilePressed,
+    CreateBlankProfile(HeaderType),
+    ResetToDefaultsPressed,
+    ResetToDefaultsConfirmed,
+    ShiftEntitlementConsumed(usize, bool),
 }
 
 pub fn view(general_state: &mut GeneralState) -> Container<Bl3Message> {
@@ -89,7 +130,300 @@ pub fn view(general_state: &mut GeneralState) -> Container<Bl3Message> {
     .height(Length::Units(36))
     .style(Bl3UiStyle);
 
-    let all_contents = Column::new().push(file).push(profile_type).spacing(20);
+    let game_store = Container::new(
+        LabelledElement::create(
+            "Store",
+            Length::Units(110),
+            Tooltip::new(
+                Text::new(general_state.game_store.to_string())
+                    .font(JETBRAINS_MONO)
+                    .size(17)
+                    .color(Color::from_rgb8(220, 220, 220)),
+                "Guessed from the saves folder name, Steam and Epic profiles are not interchangeable",
+                tooltip::Position::Top,
+            )
+            .gap(10)
+            .padding(10)
+            .font(JETBRAINS_MONO)
+            .size(17)
+            .style(Bl3UiTooltipStyle),
+        )
+        .spacing(15)
+        .align_items(Alignment::Center),
+    )
+    .width(Length::Fill)
+    .height(Length::Units(36))
+    .style(Bl3UiStyle);
+
+    let guardian_experience = Container::new(
+        LabelledElement::create(
+            "Guardian XP",
+            Length::Units(110),
+            NumberInput::new(
+                &mut general_state.guardian_experience_input_state,
+                general_state.guardian_experience_input,
+                0,
+                None,
+                |v| {
+                    InteractionMessage::ManageProfileInteraction(
+                        ManageProfileInteractionMessage::General(
+                            ProfileGeneralInteractionMessage::GuardianExperience(v),
+                        ),
+                    )
+                },
+            )
+            .0
+            .font(JETBRAINS_MONO)
+            .padding(10)
+            .size(17)
+            .style(Bl3UiStyle)
+            .into_element(),
+        )
+        .spacing(15)
+        .align_items(Alignment::Center),
+    )
+    .width(Length::Fill)
+    .height(Length::Units(36))
+    .style(Bl3UiStyle);
+
+    let new_guardian_experience = Container::new(
+        LabelledElement::create(
+            "Pending XP",
+            Length::Units(110),
+            Tooltip::new(
+                NumberInput::new(
+                    &mut general_state.new_guardian_experience_input_state,
+                    general_state.new_guardian_experience_input,
+                    0,
+                    None,
+                    |v| {
+                        InteractionMessage::ManageProfileInteraction(
+                            ManageProfileInteractionMessage::General(
+                                ProfileGeneralInteractionMessage::NewGuardianExperience(v),
+                            ),
+                        )
+                    },
+                )
+                .0
+                .font(JETBRAINS_MONO)
+                .padding(10)
+                .size(17)
+                .style(Bl3UiStyle)
+                .into_element(),
+                "Guardian XP the game has not yet turned into ranks",
+                tooltip::Position::Top,
+            )
+            .gap(10)
+            .padding(10)
+            .font(JETBRAINS_MONO)
+            .size(17)
+            .style(Bl3UiTooltipStyle),
+        )
+        .spacing(15)
+        .align_items(Alignment::Center),
+    )
+    .width(Length::Fill)
+    .height(Length::Units(36))
+    .style(Bl3UiStyle);
+
+    let guardian_experience_row = Row::new()
+        .push(guardian_experience)
+        .push(new_guardian_experience)
+        .spacing(20);
+
+    let (mail_count, read_mail_count) = general_state.mail_remaining();
+
+    let mut clear_read_mail_button = Button::new(
+        &mut general_state.clear_read_mail_button_state,
+        Text::new("Clear Read Mail")
+            .font(JETBRAINS_MONO_BOLD)
+            .size(17),
+    )
+    .padding(10)
+    .style(Bl3UiStyle);
+
+    if read_mail_count > 0 {
+        clear_read_mail_button = clear_read_mail_button.on_press(
+            InteractionMessage::ManageProfileInteraction(ManageProfileInteractionMessage::General(
+                ProfileGeneralInteractionMessage::ClearReadMailPressed,
+            )),
+        );
+    }
+
+    let mut clear_all_mail_button = Button::new(
+        &mut general_state.clear_all_mail_button_state,
+        Text::new("Clear All Mail")
+            .font(JETBRAINS_MONO_BOLD)
+            .size(17),
+    )
+    .padding(10)
+    .style(Bl3UiStyle);
+
+    if mail_count > 0 {
+        clear_all_mail_button = clear_all_mail_button.on_press(
+            InteractionMessage::ManageProfileInteraction(ManageProfileInteractionMessage::General(
+                ProfileGeneralInteractionMessage::ClearAllMailPressed,
+            )),
+        );
+    }
+
+    let mail = Container::new(
+        Row::new()
+            .push(
+                LabelledElement::create(
+                    "Mail",
+                    Length::Units(110),
+                    Text::new(format!("{} items ({} read)", mail_count, read_mail_count))
+                        .font(JETBRAINS_MONO)
+                        .size(17)
+                        .color(Color::from_rgb8(220, 220, 220)),
+                )
+                .spacing(15)
+                .width(Length::Fill)
+                .align_items(Alignment::Center),
+            )
+            .push(clear_read_mail_button.into_element())
+            .push(clear_all_mail_button.into_element())
+            .align_items(Alignment::Center),
+    )
+    .width(Length::Fill)
+    .height(Length::Units(36))
+    .style(Bl3UiStyle);
+
+    let shift_entitlements_list = if general_state.shift_entitlements.is_empty() {
+        Column::new().push(
+            Text::new("No SHiFT rewards have been redeemed on this profile.")
+                .font(JETBRAINS_MONO)
+                .size(17)
+                .color(Color::from_rgb8(220, 220, 220)),
+        )
+    } else {
+        general_state.shift_entitlements.iter().enumerate().fold(
+            Column::new().spacing(10),
+            |curr, (i, e)| {
+                curr.push(
+                    Row::new()
+                        .push(
+                            Text::new(format!(
+                                "{} #{} - {}",
+                                e.source,
+                                e.id,
+                                if e.registered {
+                                    "Registered"
+                                } else {
+                                    "Not registered"
+                                },
+                            ))
+                            .font(JETBRAINS_MONO)
+                            .size(17)
+                            .color(Color::from_rgb8(220, 220, 220))
+                            .width(Length::Fill),
+                        )
+                        .push(
+                            Checkbox::new(e.consumed, "Consumed", move |c| {
+                                InteractionMessage::ManageProfileInteraction(
+                                    ManageProfileInteractionMessage::General(
+                                        ProfileGeneralInteractionMessage::ShiftEntitlementConsumed(
+                                            i, c,
+                                        ),
+                                    ),
+                                )
+                            })
+                            .size(20)
+                            .font(JETBRAINS_MONO)
+                            .text_color(Color::from_rgb8(220, 220, 220))
+                            .text_size(17)
+                            .style(Bl3UiStyle)
+                            .into_element(),
+                        )
+                        .align_items(Alignment::Center),
+                )
+            },
+        )
+    };
+
+    let shift_entitlements = Container::new(
+        Column::new()
+            .push(
+                Text::new("SHiFT Rewards")
+                    .font(JETBRAINS_MONO_BOLD)
+                    .size(17)
+                    .color(Color::from_rgb8(242, 203, 5)),
+            )
+            .push(
+                Scrollable::new(&mut general_state.shift_entitlements_scrollable_state)
+                    .push(shift_entitlements_list)
+                    .height(Length::Units(150)),
+            )
+            .spacing(15),
+    )
+    .padding(15)
+    .width(Length::Fill)
+    .style(Bl3UiStyle);
+
+    let blank_profile = Container::new(
+        Row::new()
+            .push(
+                LabelledElement::create(
+                    "Start Fresh",
+                    Length::Units(110),
+                    Text::new("Write a new blank profile, or reset this one to defaults")
+                        .font(JETBRAINS_MONO)
+                        .size(17)
+                        .color(Color::from_rgb8(220, 220, 220)),
+                )
+                .spacing(15)
+                .width(Length::Fill)
+                .align_items(Alignment::Center),
+            )
+            .push(
+                Button::new(
+                    &mut general_state.create_blank_profile_button_state,
+                    Text::new("Create Blank Profile")
+                        .font(JETBRAINS_MONO_BOLD)
+                        .size(17),
+                )
+                .on_press(InteractionMessage::ManageProfileInteraction(
+                    ManageProfileInteractionMessage::General(
+                        ProfileGeneralInteractionMessage::CreateBlankProfilePressed,
+                    ),
+                ))
+                .padding(10)
+                .style(Bl3UiStyle)
+                .into_element(),
+            )
+            .push(
+                Button::new(
+                    &mut general_state.reset_to_defaults_button_state,
+                    Text::new("Reset to Defaults")
+                        .font(JETBRAINS_MONO_BOLD)
+                        .size(17),
+                )
+                .on_press(InteractionMessage::ManageProfileInteraction(
+                    ManageProfileInteractionMessage::General(
+                        ProfileGeneralInteractionMessage::ResetToDefaultsPressed,
+                    ),
+                ))
+                .padding(10)
+                .style(Bl3UiStyle)
+                .into_element(),
+            )
+            .align_items(Alignment::Center),
+    )
+    .width(Length::Fill)
+    .height(Length::Units(36))
+    .style(Bl3UiStyle);
+
+    let all_contents = Column::new()
+        .push(file)
+        .push(profile_type)
+        .push(game_store)
+        .push(guardian_experience_row)
+        .push(mail)
+        .push(blank_profile)
+        .push(shift_entitlements)
+        .push(file_metadata::view(&general_state.file_metadata))
+        .spacing(20);
 
     Container::new(all_contents).padding(30)
 }
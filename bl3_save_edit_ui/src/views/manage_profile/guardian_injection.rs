@@ -0,0 +1,122 @@
+use std::path::PathBuf;
+
+use iced::{button, Alignment, Button, Color, Column, Container, Length, Row, Text};
+
+use bl3_save_edit_core::bl3_profile::Bl3Profile;
+use bl3_save_edit_core::guardian_injection::GuardianInjection;
+
+use crate::bl3_ui::{Bl3Message, InteractionMessage, MessageResult};
+use crate::bl3_ui_style::{Bl3UiPositiveButtonStyle, Bl3UiStyle};
+use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
+use crate::views::manage_profile::ManageProfileInteractionMessage;
+use crate::views::InteractionExt;
+
+/// A profile that is ready to be written but is waiting on a choice about its guardian data.
+#[derive(Debug)]
+pub struct PendingProfileSave {
+    pub output_file: PathBuf,
+    pub output: Vec<u8>,
+    pub profile: Bl3Profile,
+    pub injection: GuardianInjection,
+}
+
+#[derive(Debug, Default)]
+pub struct GuardianInjectionState {
+    pub pending_save: Option<PendingProfileSave>,
+    pub saves_to_modify: Option<Vec<String>>,
+    proceed_button_state: button::State,
+    profile_only_button_state: button::State,
+    cancel_button_state: button::State,
+}
+
+#[derive(Debug, Clone)]
+pub enum GuardianInjectionMessage {
+    SavesFound(MessageResult<Vec<String>>),
+    ProceedPressed,
+    ProfileOnlyPressed,
+    CancelPressed,
+}
+
+fn guardian_injection_message(message: GuardianInjectionMessage) -> InteractionMessage {
+    InteractionMessage::ManageProfileInteraction(
+        ManageProfileInteractionMessage::GuardianInjection(message),
+    )
+}
+
+pub fn view(
+    guardian_injection_state: &mut GuardianInjectionState,
+) -> Option<Container<Bl3Message>> {
+    let saves_to_modify = guardian_injection_state.saves_to_modify.as_ref()?;
+
+    let saves_list = saves_to_modify
+        .iter()
+        .fold(Column::new().spacing(5), |curr, save| {
+            curr.push(
+                Text::new(format!("- {}", save))
+                    .font(JETBRAINS_MONO)
+                    .size(16)
+                    .color(Color::from_rgb8(220, 220, 220)),
+            )
+        });
+
+    let description = Column::new()
+        .push(
+            Text::new(
+                "The guardian rank has changed, so these saves will also be modified to match the profile. A backup of each save will be made first.",
+            )
+            .font(JETBRAINS_MONO_BOLD)
+            .size(17)
+            .color(Color::from_rgb8(220, 220, 220)),
+        )
+        .push(saves_list)
+        .spacing(10)
+        .width(Length::Fill);
+
+    let proceed_button = Button::new(
+        &mut guardian_injection_state.proceed_button_state,
+        Text::new("Proceed").font(JETBRAINS_MONO_BOLD).size(17),
+    )
+    .on_press(guardian_injection_message(
+        GuardianInjectionMessage::ProceedPressed,
+    ))
+    .padding(10)
+    .style(Bl3UiPositiveButtonStyle)
+    .into_element();
+
+    let profile_only_button = Button::new(
+        &mut guardian_injection_state.profile_only_button_state,
+        Text::new("Profile Only").font(JETBRAINS_MONO_BOLD).size(17),
+    )
+    .on_press(guardian_injection_message(
+        GuardianInjectionMessage::ProfileOnlyPressed,
+    ))
+    .padding(10)
+    .style(Bl3UiStyle)
+    .into_element();
+
+    let cancel_button = Button::new(
+        &mut guardian_injection_state.cancel_button_state,
+        Text::new("Cancel").font(JETBRAINS_MONO_BOLD).size(17),
+    )
+    .on_press(guardian_injection_message(
+        GuardianInjectionMessage::CancelPressed,
+    ))
+    .padding(10)
+    .style(Bl3UiStyle)
+    .into_element();
+
+    let contents = Row::new()
+        .push(description)
+        .push(proceed_button)
+        .push(profile_only_button)
+        .push(cancel_button)
+        .spacing(15)
+        .align_items(Alignment::Center);
+
+    Some(
+        Container::new(contents)
+            .width(Length::Fill)
+            .padding(20)
+            .style(Bl3UiStyle),
+    )
+}
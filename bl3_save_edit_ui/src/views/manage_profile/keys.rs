@@ -1,202 +1,109 @@
-use iced::{button, text_input, Alignment, Button, Column, Container, Length, Row, Text};
+use std::fmt::Display;
+
+use iced::alignment::Horizontal;
+use iced::{
+    button, text_input, tooltip, Alignment, Button, Color, Column, Container, Element, Length, Row,
+    Text, Tooltip,
+};
+
+use bl3_save_edit_core::bl3_profile::profile_data::VaultCardChallengeSeeds;
 
 use crate::bl3_ui::{Bl3Message, InteractionMessage};
-use crate::bl3_ui_style::Bl3UiStyle;
+use crate::bl3_ui_style::{Bl3UiStyle, Bl3UiTooltipStyle};
 use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
 use crate::views::manage_profile::ManageProfileInteractionMessage;
 use crate::views::InteractionExt;
 use crate::widgets::labelled_element::LabelledElement;
 use crate::widgets::number_input::NumberInput;
 
+#[derive(Debug, Default)]
+pub struct CurrencyField {
+    pub hash: u32,
+    pub name: String,
+    pub path: String,
+    pub max: i32,
+    pub input: i32,
+    pub input_state: text_input::State,
+    pub max_button_state: button::State,
+}
+
+#[derive(Debug, Default)]
+pub struct VaultCardChestsField {
+    pub vault_card_id: u32,
+    pub input: i32,
+    pub input_state: text_input::State,
+    pub max_button_state: button::State,
+}
+
 #[derive(Debug, Default)]
 pub struct KeysState {
-    pub golden_keys_input: i32,
-    pub golden_keys_input_state: text_input::State,
-    pub diamond_keys_input: i32,
-    pub diamond_keys_input_state: text_input::State,
-    pub vault_card_1_keys_input: i32,
-    pub vault_card_1_keys_input_state: text_input::State,
-    pub vault_card_1_chests_input: i32,
-    pub vault_card_1_chests_input_state: text_input::State,
-    pub vault_card_2_keys_input: i32,
-    pub vault_card_2_keys_input_state: text_input::State,
-    pub vault_card_2_chests_input: i32,
-    pub vault_card_2_chests_input_state: text_input::State,
-    pub vault_card_3_keys_input: i32,
-    pub vault_card_3_keys_input_state: text_input::State,
-    pub vault_card_3_chests_input: i32,
-    pub vault_card_3_chests_input_state: text_input::State,
-    pub max_golden_keys_button_state: button::State,
-    pub max_diamond_keys_button_state: button::State,
-    pub max_vault_card_1_keys_button_state: button::State,
-    pub max_vault_card_1_chests_button_state: button::State,
-    pub max_vault_card_2_keys_button_state: button::State,
-    pub max_vault_card_2_chests_button_state: button::State,
-    pub max_vault_card_3_keys_button_state: button::State,
-    pub max_vault_card_3_chests_button_state: button::State,
+    pub currencies: Vec<CurrencyField>,
+    pub vault_card_chests: Vec<VaultCardChestsField>,
+    pub vault_card_challenge_seeds: VaultCardChallengeSeeds,
+    // The challenges are rerolled when the profile is saved
+    pub reroll_vault_card_challenges: bool,
+    pub reroll_vault_card_challenges_button_state: button::State,
+    pub max_all_button_state: button::State,
 }
 
 #[derive(Debug, Clone)]
 pub enum ProfileKeysInteractionMessage {
-    GoldenKeys(i32),
-    DiamondKeys(i32),
-    VaultCard1Keys(i32),
-    VaultCard1Chests(i32),
-    VaultCard2Keys(i32),
-    VaultCard2Chests(i32),
-    VaultCard3Keys(i32),
-    VaultCard3Chests(i32),
-    MaxGoldenKeysPressed,
-    MaxDiamondKeysPressed,
-    MaxVaultCard1KeysPressed,
-    MaxVaultCard1ChestsPressed,
-    MaxVaultCard2KeysPressed,
-    MaxVaultCard2ChestsPressed,
-    MaxVaultCard3KeysPressed,
-    MaxVaultCard3ChestsPressed,
+    Currency { hash: u32, quantity: i32 },
+    MaxCurrencyPressed(u32),
+    VaultCardChests { vault_card_id: u32, chests: i32 },
+    MaxVaultCardChestsPressed(u32),
+    MaxAllKeysAndChests,
+    RerollVaultCardChallenges,
 }
 
-pub fn view(keys_state: &mut KeysState) -> Container<Bl3Message> {
-    let golden_keys = Container::new(
-        Row::new()
-            .push(
-                LabelledElement::create(
-                    "Golden Keys",
-                    Length::Units(170),
-                    NumberInput::new(
-                        &mut keys_state.golden_keys_input_state,
-                        keys_state.golden_keys_input,
-                        0,
-                        None,
-                        |v| {
-                            InteractionMessage::ManageProfileInteraction(
-                                ManageProfileInteractionMessage::Keys(
-                                    ProfileKeysInteractionMessage::GoldenKeys(v),
-                                ),
-                            )
-                        },
-                    )
-                    .0
-                    .font(JETBRAINS_MONO)
-                    .padding(10)
-                    .size(17)
-                    .style(Bl3UiStyle)
-                    .into_element(),
-                )
-                .spacing(15)
-                .width(Length::FillPortion(9))
-                .align_items(Alignment::Center),
-            )
-            .push(
-                Button::new(
-                    &mut keys_state.max_golden_keys_button_state,
-                    Text::new("Max").font(JETBRAINS_MONO_BOLD).size(17),
-                )
-                .on_press(InteractionMessage::ManageProfileInteraction(
-                    ManageProfileInteractionMessage::Keys(
-                        ProfileKeysInteractionMessage::MaxGoldenKeysPressed,
-                    ),
-                ))
-                .padding(10)
-                .style(Bl3UiStyle)
-                .into_element(),
-            )
-            .align_items(Alignment::Center),
-    )
-    .width(Length::Fill)
-    .height(Length::Units(36))
-    .style(Bl3UiStyle);
+fn keys_message(message: ProfileKeysInteractionMessage) -> InteractionMessage {
+    InteractionMessage::ManageProfileInteraction(ManageProfileInteractionMessage::Keys(message))
+}
 
-    let diamond_keys = Container::new(
-        Row::new()
-            .push(
-                LabelledElement::create(
-                    "Diamond Keys",
-                    Length::Units(170),
-                    NumberInput::new(
-                        &mut keys_state.diamond_keys_input_state,
-                        keys_state.diamond_keys_input,
-                        0,
-                        None,
-                        |v| {
-                            InteractionMessage::ManageProfileInteraction(
-                                ManageProfileInteractionMessage::Keys(
-                                    ProfileKeysInteractionMessage::DiamondKeys(v),
-                                ),
-                            )
-                        },
-                    )
-                    .0
-                    .font(JETBRAINS_MONO)
-                    .padding(10)
-                    .size(17)
-                    .style(Bl3UiStyle)
-                    .into_element(),
-                )
-                .spacing(15)
-                .width(Length::FillPortion(9))
-                .align_items(Alignment::Center),
-            )
-            .push(
-                Button::new(
-                    &mut keys_state.max_diamond_keys_button_state,
-                    Text::new("Max").font(JETBRAINS_MONO_BOLD).size(17),
-                )
-                .on_press(InteractionMessage::ManageProfileInteraction(
-                    ManageProfileInteractionMessage::Keys(
-                        ProfileKeysInteractionMessage::MaxDiamondKeysPressed,
-                    ),
-                ))
-                .padding(10)
-                .style(Bl3UiStyle)
-                .into_element(),
-            )
-            .align_items(Alignment::Center),
-    )
-    .width(Length::Fill)
-    .height(Length::Units(36))
-    .style(Bl3UiStyle);
+fn number_input<'a, F>(
+    input_state: &'a mut text_input::State,
+    input: i32,
+    max: i32,
+    on_changed: F,
+) -> Element<'a, Bl3Message>
+where
+    F: 'static + Fn(i32) -> ProfileKeysInteractionMessage,
+{
+    NumberInput::new(input_state, input, 0, Some(max), move |v| {
+        keys_message(on_changed(v))
+    })
+    .0
+    .font(JETBRAINS_MONO)
+    .padding(10)
+    .size(17)
+    .style(Bl3UiStyle)
+    .into_element()
+}
 
-    let vault_card_1_keys = Container::new(
+fn key_row<'a, L, E>(
+    label: L,
+    input: E,
+    max_button_state: &'a mut button::State,
+    max_message: ProfileKeysInteractionMessage,
+) -> Container<'a, Bl3Message>
+where
+    L: Display,
+    E: Into<Element<'a, Bl3Message>>,
+{
+    Container::new(
         Row::new()
             .push(
-                LabelledElement::create(
-                    "Vault Card 1 Keys",
-                    Length::Units(170),
-                    NumberInput::new(
-                        &mut keys_state.vault_card_1_keys_input_state,
-                        keys_state.vault_card_1_keys_input,
-                        0,
-                        None,
-                        |v| {
-                            InteractionMessage::ManageProfileInteraction(
-                                ManageProfileInteractionMessage::Keys(
-                                    ProfileKeysInteractionMessage::VaultCard1Keys(v),
-                                ),
-                            )
-                        },
-                    )
-                    .0
-                    .font(JETBRAINS_MONO)
-                    .padding(10)
-                    .size(17)
-                    .style(Bl3UiStyle)
-                    .into_element(),
-                )
-                .spacing(15)
-                .width(Length::FillPortion(9))
-                .align_items(Alignment::Center),
+                LabelledElement::create(label, Length::Units(290), input)
+                    .spacing(15)
+                    .width(Length::FillPortion(9))
+                    .align_items(Alignment::Center),
             )
             .push(
                 Button::new(
-                    &mut keys_state.max_vault_card_1_keys_button_state,
+                    max_button_state,
                     Text::new("Max").font(JETBRAINS_MONO_BOLD).size(17),
                 )
-                .on_press(InteractionMessage::ManageProfileInteraction(
-                    ManageProfileInteractionMessage::Keys(
-                        ProfileKeysInteractionMessage::MaxVaultCard1KeysPressed,
-                    ),
-                ))
+                .on_press(keys_message(max_message))
                 .padding(10)
                 .style(Bl3UiStyle)
                 .into_element(),
@@ -205,267 +112,152 @@ pub fn view(keys_state: &mut KeysState) -> Container<Bl3Message> {
     )
     .width(Length::Fill)
     .height(Length::Units(36))
-    .style(Bl3UiStyle);
+    .style(Bl3UiStyle)
+}
 
-    let vault_card_1_chests = Container::new(
-        Row::new()
-            .push(
-                LabelledElement::create(
-                    "Vault Card 1 Chests",
-                    Length::Units(170),
-                    NumberInput::new(
-                        &mut keys_state.vault_card_1_chests_input_state,
-                        keys_state.vault_card_1_chests_input,
-                        0,
-                        None,
-                        |v| {
-                            InteractionMessage::ManageProfileInteraction(
-                                ManageProfileInteractionMessage::Keys(
-                                    ProfileKeysInteractionMessage::VaultCard1Chests(v),
-                                ),
-                            )
-                        },
-                    )
-                    .0
-                    .font(JETBRAINS_MONO)
-                    .padding(10)
-                    .size(17)
-                    .style(Bl3UiStyle)
-                    .into_element(),
-                )
-                .spacing(15)
-                .width(Length::FillPortion(9))
-                .align_items(Alignment::Center),
-            )
-            .push(
-                Button::new(
-                    &mut keys_state.max_vault_card_1_chests_button_state,
-                    Text::new("Max").font(JETBRAINS_MONO_BOLD).size(17),
-                )
-                .on_press(InteractionMessage::ManageProfileInteraction(
-                    ManageProfileInteractionMessage::Keys(
-                        ProfileKeysInteractionMessage::MaxVaultCard1ChestsPressed,
-                    ),
-                ))
-                .padding(10)
-                .style(Bl3UiStyle)
-                .into_element(),
-            )
-            .align_items(Alignment::Center),
-    )
-    .width(Length::Fill)
-    .height(Length::Units(36))
-    .style(Bl3UiStyle);
+impl CurrencyField {
+    pub fn view(&mut self) -> Container<Bl3Message> {
+        let hash = self.hash;
 
-    let vault_card_2_keys = Container::new(
-        Row::new()
-            .push(
-                LabelledElement::create(
-                    "Vault Card 2 Keys",
-                    Length::Units(170),
-                    NumberInput::new(
-                        &mut keys_state.vault_card_2_keys_input_state,
-                        keys_state.vault_card_2_keys_input,
-                        0,
-                        None,
-                        |v| {
-                            InteractionMessage::ManageProfileInteraction(
-                                ManageProfileInteractionMessage::Keys(
-                                    ProfileKeysInteractionMessage::VaultCard2Keys(v),
-                                ),
-                            )
-                        },
-                    )
-                    .0
-                    .font(JETBRAINS_MONO)
-                    .padding(10)
-                    .size(17)
-                    .style(Bl3UiStyle)
-                    .into_element(),
-                )
-                .spacing(15)
-                .width(Length::FillPortion(9))
-                .align_items(Alignment::Center),
-            )
-            .push(
-                Button::new(
-                    &mut keys_state.max_vault_card_2_keys_button_state,
-                    Text::new("Max").font(JETBRAINS_MONO_BOLD).size(17),
-                )
-                .on_press(InteractionMessage::ManageProfileInteraction(
-                    ManageProfileInteractionMessage::Keys(
-                        ProfileKeysInteractionMessage::MaxVaultCard2KeysPressed,
-                    ),
-                ))
-                .padding(10)
-                .style(Bl3UiStyle)
-                .into_element(),
-            )
-            .align_items(Alignment::Center),
-    )
-    .width(Length::Fill)
-    .height(Length::Units(36))
-    .style(Bl3UiStyle);
+        // Unknown currencies only have a hash, so the tooltip is the closest thing to a raw path
+        let input = Tooltip::new(
+            number_input(
+                &mut self.input_state,
+                self.input,
+                self.max,
+                move |quantity| ProfileKeysInteractionMessage::Currency { hash, quantity },
+            ),
+            &self.path,
+            tooltip::Position::Top,
+        )
+        .gap(10)
+        .padding(10)
+        .font(JETBRAINS_MONO)
+        .size(17)
+        .style(Bl3UiTooltipStyle);
 
-    let vault_card_2_chests = Container::new(
-        Row::new()
-            .push(
-                LabelledElement::create(
-                    "Vault Card 2 Chests",
-                    Length::Units(170),
-                    NumberInput::new(
-                        &mut keys_state.vault_card_2_chests_input_state,
-                        keys_state.vault_card_2_chests_input,
-                        0,
-                        None,
-                        |v| {
-                            InteractionMessage::ManageProfileInteraction(
-                                ManageProfileInteractionMessage::Keys(
-                                    ProfileKeysInteractionMessage::VaultCard2Chests(v),
-                                ),
-                            )
-                        },
-                    )
-                    .0
-                    .font(JETBRAINS_MONO)
-                    .padding(10)
-                    .size(17)
-                    .style(Bl3UiStyle)
-                    .into_element(),
-                )
-                .spacing(15)
-                .width(Length::FillPortion(9))
-                .align_items(Alignment::Center),
-            )
-            .push(
-                Button::new(
-                    &mut keys_state.max_vault_card_2_chests_button_state,
-                    Text::new("Max").font(JETBRAINS_MONO_BOLD).size(17),
-                )
-                .on_press(InteractionMessage::ManageProfileInteraction(
-                    ManageProfileInteractionMessage::Keys(
-                        ProfileKeysInteractionMessage::MaxVaultCard2ChestsPressed,
-                    ),
-                ))
-                .padding(10)
-                .style(Bl3UiStyle)
-                .into_element(),
-            )
-            .align_items(Alignment::Center),
+        key_row(
+            &self.name,
+            input,
+            &mut self.max_button_state,
+            ProfileKeysInteractionMessage::MaxCurrencyPressed(hash),
+        )
+    }
+}
+
+impl VaultCardChestsField {
+    pub fn view(&mut self) -> Container<Bl3Message> {
+        let vault_card_id = self.vault_card_id;
+
+        let input = number_input(&mut self.input_state, self.input, i32::MAX, move |chests| {
+            ProfileKeysInteractionMessage::VaultCardChests {
+                vault_card_id,
+                chests,
+            }
+        });
+
+        key_row(
+            format!("Vault Card {} Chests", vault_card_id),
+            input,
+            &mut self.max_button_state,
+            ProfileKeysInteractionMessage::MaxVaultCardChestsPressed(vault_card_id),
+        )
+    }
+}
+
+fn vault_card_challenges_row(keys_state: &mut KeysState) -> Container<Bl3Message> {
+    let seeds = if keys_state.reroll_vault_card_challenges {
+        "New challenges will be rolled when the profile is saved".to_owned()
+    } else {
+        format!(
+            "Daily seed: {}, Weekly seed: {}",
+            keys_state.vault_card_challenge_seeds.day_seed,
+            keys_state.vault_card_challenge_seeds.week_seed
+        )
+    };
+
+    let mut reroll_button = Button::new(
+        &mut keys_state.reroll_vault_card_challenges_button_state,
+        Text::new("Reroll").font(JETBRAINS_MONO_BOLD).size(17),
     )
-    .width(Length::Fill)
-    .height(Length::Units(36))
+    .padding(10)
     .style(Bl3UiStyle);
 
-    let vault_card_3_keys = Container::new(
+    if !keys_state.reroll_vault_card_challenges {
+        reroll_button = reroll_button.on_press(keys_message(
+            ProfileKeysInteractionMessage::RerollVaultCardChallenges,
+        ));
+    }
+
+    Container::new(
         Row::new()
             .push(
                 LabelledElement::create(
-                    "Vault Card 3 Keys",
-                    Length::Units(170),
-                    NumberInput::new(
-                        &mut keys_state.vault_card_3_keys_input_state,
-                        keys_state.vault_card_3_keys_input,
-                        0,
-                        None,
-                        |v| {
-                            InteractionMessage::ManageProfileInteraction(
-                                ManageProfileInteractionMessage::Keys(
-                                    ProfileKeysInteractionMessage::VaultCard3Keys(v),
-                                ),
-                            )
-                        },
-                    )
-                    .0
-                    .font(JETBRAINS_MONO)
-                    .padding(10)
-                    .size(17)
-                    .style(Bl3UiStyle)
-                    .into_element(),
+                    "Vault Card Challenges",
+                    Length::Units(290),
+                    Text::new(seeds)
+                        .font(JETBRAINS_MONO)
+                        .size(17)
+                        .color(Color::from_rgb8(220, 220, 220)),
                 )
                 .spacing(15)
                 .width(Length::FillPortion(9))
                 .align_items(Alignment::Center),
             )
             .push(
-                Button::new(
-                    &mut keys_state.max_vault_card_3_keys_button_state,
-                    Text::new("Max").font(JETBRAINS_MONO_BOLD).size(17),
+                Tooltip::new(
+                    reroll_button.into_element(),
+                    "Useful when a daily or weekly challenge needs DLC you don't own",
+                    tooltip::Position::Top,
                 )
-                .on_press(InteractionMessage::ManageProfileInteraction(
-                    ManageProfileInteractionMessage::Keys(
-                        ProfileKeysInteractionMessage::MaxVaultCard3KeysPressed,
-                    ),
-                ))
+                .gap(10)
                 .padding(10)
-                .style(Bl3UiStyle)
-                .into_element(),
+                .font(JETBRAINS_MONO)
+                .size(17)
+                .style(Bl3UiTooltipStyle),
             )
             .align_items(Alignment::Center),
     )
     .width(Length::Fill)
     .height(Length::Units(36))
-    .style(Bl3UiStyle);
+    .style(Bl3UiStyle)
+}
 
-    let vault_card_3_chests = Container::new(
-        Row::new()
-            .push(
-                LabelledElement::create(
-                    "Vault Card 3 Chests",
-                    Length::Units(170),
-                    NumberInput::new(
-                        &mut keys_state.vault_card_3_chests_input_state,
-                        keys_state.vault_card_3_chests_input,
-                        0,
-                        None,
-                        |v| {
-                            InteractionMessage::ManageProfileInteraction(
-                                ManageProfileInteractionMessage::Keys(
-                                    ProfileKeysInteractionMessage::VaultCard3Chests(v),
-                                ),
-                            )
-                        },
-                    )
-                    .0
-                    .font(JETBRAINS_MONO)
-                    .padding(10)
-                    .size(17)
-                    .style(Bl3UiStyle)
-                    .into_element(),
-                )
-                .spacing(15)
-                .width(Length::FillPortion(9))
-                .align_items(Alignment::Center),
-            )
-            .push(
-                Button::new(
-                    &mut keys_state.max_vault_card_3_chests_button_state,
-                    Text::new("Max").font(JETBRAINS_MONO_BOLD).size(17),
-                )
-                .on_press(InteractionMessage::ManageProfileInteraction(
-                    ManageProfileInteractionMessage::Keys(
-                        ProfileKeysInteractionMessage::MaxVaultCard3ChestsPressed,
-                    ),
-                ))
-                .padding(10)
-                .style(Bl3UiStyle)
-                .into_element(),
-            )
-            .align_items(Alignment::Center),
+pub fn view(keys_state: &mut KeysState) -> Container<Bl3Message> {
+    let max_all_button = Container::new(
+        Button::new(
+            &mut keys_state.max_all_button_state,
+            Text::new("Max All").font(JETBRAINS_MONO_BOLD).size(17),
+        )
+        .on_press(keys_message(
+            ProfileKeysInteractionMessage::MaxAllKeysAndChests,
+        ))
+        .padding(10)
+        .style(Bl3UiStyle)
+        .into_element(),
     )
     .width(Length::Fill)
-    .height(Length::Units(36))
-    .style(Bl3UiStyle);
+    .align_x(Horizontal::Right);
+
+    let currencies = keys_state
+        .currencies
+        .iter_mut()
+        .fold(Column::new().spacing(20), |curr, currency| {
+            curr.push(currency.view())
+        });
+
+    let vault_card_chests = keys_state
+        .vault_card_chests
+        .iter_mut()
+        .fold(Column::new().spacing(20), |curr, chests| {
+            curr.push(chests.view())
+        });
 
     let all_contents = Column::new()
-        .push(golden_keys)
-        .push(diamond_keys)
-        .push(vault_card_1_keys)
-        .push(vault_card_1_chests)
-        .push(vault_card_2_keys)
-        .push(vault_card_2_chests)
-        .push(vault_card_3_keys)
-        .push(vault_card_3_chests)
+        .push(max_all_button)
+        .push(currencies)
+        .push(vault_card_chests)
+        .push(vault_card_challenges_row(keys_state))
         .spacing(20);
 
     Container::new(all_contents).padding(30)
@@ -8,15 +8,19 @@ use crate::views::manage_profile::bank::BankState;
 use crate::views::manage_profile::general::GeneralState;
 use crate::views::manage_profile::keys::KeysState;
 use crate::views::manage_profile::profile::ProfileState;
+use crate::views::manage_profile::summary::SummaryState;
 use crate::views::manage_profile::{
-    bank, general, keys, profile, ManageProfileInteractionMessage, ManageProfileState,
+    bank, general, guardian_injection, keys, profile, summary, ManageProfileInteractionMessage,
+    ManageProfileState, ManageProfileView,
 };
 use crate::views::settings::SettingsState;
-use crate::views::{tab_bar_button, ManageTabBarStyle};
+use crate::views::validation_report;
+use crate::views::{reset_tab_button, tab_bar_button, ManageTabBarStyle};
 
 #[derive(Debug, Default)]
 pub struct ProfileViewState {
     tab_bar_state: ProfileTabBarState,
+    pub summary_state: SummaryState,
     pub general_state: GeneralState,
     pub profile_state: ProfileState,
     pub keys_state: KeysState,
@@ -30,6 +34,7 @@ pub struct ProfileTabBarState {
     keys_button_state: button::State,
     bank_button_state: button::State,
     settings_button_state: button::State,
+    reset_tab_button_state: button::State,
 }
 
 #[derive(Debug, Clone)]
@@ -39,9 +44,10 @@ pub enum ProfileTabBarInteractionMessage {
     Keys,
     Bank,
     Settings,
+    ResetTab,
 }
 
-#[derive(Debug, Display, PartialEq)]
+#[derive(Debug, Display, Clone, PartialEq)]
 #[strum(serialize_all = "title_case")]
 pub enum ProfileTabBarView {
     General,
@@ -54,8 +60,14 @@ pub enum ProfileTabBarView {
 pub fn view<'a>(
     settings_state: &'a mut SettingsState,
     manage_profile_state: &'a mut ManageProfileState,
-    tab_bar_view: &ProfileTabBarView,
+    manage_profile_view: &ManageProfileView,
 ) -> Container<'a, Bl3Message> {
+    // No tab is active while the summary is shown
+    let tab_bar_view = match manage_profile_view {
+        ManageProfileView::Summary => None,
+        ManageProfileView::TabBar(tab_bar_view) => Some(tab_bar_view),
+    };
+
     let general_button = tab_bar_button(
         &mut manage_profile_state
             .profile_view_state
@@ -68,6 +80,9 @@ pub fn view<'a>(
         )),
         svg::Handle::from_memory(GENERAL),
         100,
+        manage_profile_state
+            .tab_badges
+            .badge(&ProfileTabBarView::General),
     );
 
     let profile_button = tab_bar_button(
@@ -82,6 +97,9 @@ pub fn view<'a>(
         )),
         svg::Handle::from_memory(PROFILE),
         100,
+        manage_profile_state
+            .tab_badges
+            .badge(&ProfileTabBarView::Profile),
     );
 
     let keys_button = tab_bar_button(
@@ -96,6 +114,9 @@ pub fn view<'a>(
         )),
         svg::Handle::from_memory(KEYS),
         75,
+        manage_profile_state
+            .tab_badges
+            .badge(&ProfileTabBarView::Keys),
     );
 
     let bank_button = tab_bar_button(
@@ -110,6 +131,9 @@ pub fn view<'a>(
         )),
         svg::Handle::from_memory(BANK),
         75,
+        manage_profile_state
+            .tab_badges
+            .badge(&ProfileTabBarView::Bank),
     );
 
     let settings_button = tab_bar_button(
@@ -124,36 +148,68 @@ pub fn view<'a>(
         )),
         svg::Handle::from_memory(SETTINGS),
         105,
+        None,
     );
 
-    let tab_bar = Container::new(
-        Row::new()
-            .push(general_button)
-            .push(profile_button)
-            .push(keys_button)
-            .push(bank_button)
-            .push(settings_button),
-    )
-    .width(Length::Fill)
-    .style(ManageTabBarStyle);
+    let mut tab_bar_buttons = Row::new()
+        .push(general_button)
+        .push(profile_button)
+        .push(keys_button)
+        .push(bank_button)
+        .push(settings_button);
+
+    if matches!(tab_bar_view, Some(v) if *v != ProfileTabBarView::Settings) {
+        tab_bar_buttons = tab_bar_buttons.push(reset_tab_button(
+            &mut manage_profile_state
+                .profile_view_state
+                .tab_bar_state
+                .reset_tab_button_state,
+            InteractionMessage::ManageProfileInteraction(ManageProfileInteractionMessage::TabBar(
+                ProfileTabBarInteractionMessage::ResetTab,
+            )),
+        ));
+    }
+
+    let tab_bar = Container::new(tab_bar_buttons)
+        .width(Length::Fill)
+        .style(ManageTabBarStyle);
 
     let tab_content = match tab_bar_view {
-        ProfileTabBarView::General => {
+        None => summary::view(&manage_profile_state.profile_view_state.summary_state),
+        Some(ProfileTabBarView::General) => {
             general::view(&mut manage_profile_state.profile_view_state.general_state)
         }
-        ProfileTabBarView::Profile => {
+        Some(ProfileTabBarView::Profile) => {
             profile::view(&mut manage_profile_state.profile_view_state.profile_state)
         }
-        ProfileTabBarView::Keys => {
+        Some(ProfileTabBarView::Keys) => {
             keys::view(&mut manage_profile_state.profile_view_state.keys_state)
         }
-        ProfileTabBarView::Bank => {
+        Some(ProfileTabBarView::Bank) => {
             bank::view(&mut manage_profile_state.profile_view_state.bank_state)
         }
-        ProfileTabBarView::Settings => views::settings::view(settings_state),
+        Some(ProfileTabBarView::Settings) => views::settings::view(settings_state),
     };
 
-    let all_contents = Column::new().push(tab_bar).push(tab_content);
+    let mut all_contents = Column::new();
+
+    if let Some(guardian_injection_prompt) =
+        guardian_injection::view(&mut manage_profile_state.guardian_injection_state)
+    {
+        all_contents = all_contents.push(guardian_injection_prompt);
+    }
+
+    if let Some(validation_report) =
+        validation_report::view(&mut manage_profile_state.validation_report_state, |m| {
+            InteractionMessage::ManageProfileInteraction(
+                ManageProfileInteractionMessage::ValidationReport(m),
+            )
+        })
+    {
+        all_contents = all_contents.push(validation_report);
+    }
+
+    let all_contents = all_contents.push(tab_bar).push(tab_content);
 
     Container::new(all_contents)
         .width(Length::Fill)
@@ -1,23 +1,39 @@
 use bl3_save_edit_core::bl3_profile::Bl3Profile;
+use bl3_save_edit_core::validation::Adjustment;
 
 use crate::views::manage_profile::bank::ProfileBankInteractionMessage;
 use crate::views::manage_profile::general::ProfileGeneralInteractionMessage;
+use crate::views::manage_profile::guardian_injection::{
+    GuardianInjectionMessage, GuardianInjectionState,
+};
 use crate::views::manage_profile::keys::ProfileKeysInteractionMessage;
 use crate::views::manage_profile::main::{
     ProfileTabBarInteractionMessage, ProfileTabBarView, ProfileViewState,
 };
 use crate::views::manage_profile::profile::ProfileInteractionMessage;
+use crate::views::validation_report::{ValidationReportMessage, ValidationReportState};
+use crate::views::TabBadges;
 
 pub mod bank;
 pub mod general;
+pub mod guardian_injection;
 pub mod keys;
 pub mod main;
 pub mod profile;
+pub mod summary;
 
 #[derive(Debug, Default)]
 pub struct ManageProfileState {
     pub profile_view_state: ProfileViewState,
     pub current_file: Bl3Profile,
+    pub unsupported_version_save_pending: bool,
+    pub game_store_save_pending: bool,
+    pub guardian_injection_state: GuardianInjectionState,
+    pub validation_report_state: ValidationReportState,
+    pub changes_summary: Option<String>,
+    pub tab_badges: TabBadges<ProfileTabBarView>,
+    // Negative values that were set to 0 when the file was loaded
+    pub sanitized_on_load: Vec<Adjustment>,
 }
 
 #[derive(Debug, Clone)]
@@ -28,9 +44,14 @@ pub enum ManageProfileInteractionMessage {
     Keys(ProfileKeysInteractionMessage),
     Bank(ProfileBankInteractionMessage),
     SaveProfilePressed,
+    SaveCancelled,
+    PreviewChangesPressed,
+    GuardianInjection(GuardianInjectionMessage),
+    ValidationReport(ValidationReportMessage),
 }
 
 #[derive(Debug, PartialEq)]
 pub enum ManageProfileView {
+    Summary,
     TabBar(ProfileTabBarView),
 }
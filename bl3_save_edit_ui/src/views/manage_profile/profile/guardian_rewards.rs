@@ -3,11 +3,13 @@ use std::rc::Rc;
 use derivative::Derivative;
 use iced::alignment::{Horizontal, Vertical};
 use iced::{
-    button, text_input, tooltip, Alignment, Button, Color, Column, Container, Length, Row, Text,
-    Tooltip,
+    button, pick_list, text_input, tooltip, Alignment, Button, Color, Column, Container, Length,
+    PickList, Row, Text, Tooltip,
 };
 
-use bl3_save_edit_core::bl3_profile::guardian_reward::GuardianReward;
+use bl3_save_edit_core::bl3_profile::guardian_reward::{
+    distribute_tokens, GuardianReward, MAX_GUARDIAN_REWARD_TOKENS,
+};
 
 use crate::bl3_ui::{Bl3Message, InteractionMessage};
 use crate::bl3_ui_style::{Bl3UiStyle, Bl3UiTooltipStyle};
@@ -47,10 +49,14 @@ impl GuardianRewardField {
         }
     }
 
+    pub fn changed_message(&self, value: i32) -> GuardianRewardMessage {
+        (self.on_changed)(value)
+    }
+
     pub fn view(&mut self) -> Row<Bl3Message> {
         let on_changed = self.on_changed.clone();
         let minimum = 0;
-        let maximum = i32::MAX;
+        let maximum = MAX_GUARDIAN_REWARD_TOKENS;
 
         Row::new()
             .push(
@@ -117,7 +123,13 @@ pub struct GuardianRewardUnlocker {
     pub shield_recharge_delay: GuardianRewardField,
     pub shield_recharge_rate: GuardianRewardField,
     pub vehicle_damage: GuardianRewardField,
+    pub distribute_total_input: i32,
+    distribute_total_input_state: text_input::State,
+    pub distribute_priority_selected: GuardianReward,
+    distribute_priority_selector: pick_list::State<GuardianReward>,
+    distribute_button_state: button::State,
     unlock_all_button_state: button::State,
+    export_csv_button_state: button::State,
 }
 
 impl std::default::Default for GuardianRewardUnlocker {
@@ -213,13 +225,132 @@ impl std::default::Default for GuardianRewardUnlocker {
                 GuardianReward::VehicleDamage,
                 GuardianRewardMessage::VehicleDamage,
             ),
+            distribute_total_input: 0,
+            distribute_total_input_state: text_input::State::default(),
+            distribute_priority_selected: GuardianReward::default(),
+            distribute_priority_selector: pick_list::State::default(),
+            distribute_button_state: button::State::default(),
             unlock_all_button_state: button::State::default(),
+            export_csv_button_state: button::State::default(),
         }
     }
 }
 
 impl GuardianRewardUnlocker {
     pub fn view(&mut self) -> Container<Bl3Message> {
+        let distribute_total = Tooltip::new(
+            NumberInput::new(
+                &mut self.distribute_total_input_state,
+                self.distribute_total_input,
+                0,
+                None,
+                |v| {
+                    InteractionMessage::ManageProfileInteraction(
+                        ManageProfileInteractionMessage::Profile(
+                            ProfileInteractionMessage::DistributeGuardianRewardTokens(v),
+                        ),
+                    )
+                },
+            )
+            .0
+            .width(Length::Units(120))
+            .font(JETBRAINS_MONO)
+            .padding(10)
+            .size(17)
+            .style(Bl3UiStyle)
+            .into_element(),
+            "Total tokens to spread across all rewards",
+            tooltip::Position::Top,
+        )
+        .gap(10)
+        .padding(10)
+        .font(JETBRAINS_MONO)
+        .size(17)
+        .style(Bl3UiTooltipStyle);
+
+        let distribute_priority = Tooltip::new(
+            PickList::new(
+                &mut self.distribute_priority_selector,
+                &GuardianReward::ALL[..],
+                Some(self.distribute_priority_selected.clone()),
+                |r| {
+                    InteractionMessage::ManageProfileInteraction(
+                        ManageProfileInteractionMessage::Profile(
+                            ProfileInteractionMessage::DistributeGuardianRewardPrioritySelected(r),
+                        ),
+                    )
+                },
+            )
+            .font(JETBRAINS_MONO)
+            .text_size(17)
+            .width(Length::Units(240))
+            .padding(10)
+            .style(Bl3UiStyle)
+            .into_element(),
+            "Receives any tokens left over after dividing evenly",
+            tooltip::Position::Top,
+        )
+        .gap(10)
+        .padding(10)
+        .font(JETBRAINS_MONO)
+        .size(17)
+        .style(Bl3UiTooltipStyle);
+
+        let distribute_row = Row::new()
+            .push(distribute_total)
+            .push(distribute_priority)
+            .push(
+                Button::new(
+                    &mut self.distribute_button_state,
+                    Text::new("Distribute").font(JETBRAINS_MONO_BOLD).size(17),
+                )
+                .on_press(InteractionMessage::ManageProfileInteraction(
+                    ManageProfileInteractionMessage::Profile(
+                        ProfileInteractionMessage::DistributeGuardianRewardsPressed,
+                    ),
+                ))
+                .padding(10)
+                .style(Bl3UiStyle)
+                .into_element(),
+            )
+            .align_items(Alignment::Center)
+            .spacing(15);
+
+        let buttons_row = Row::new()
+            .push(
+                Button::new(
+                    &mut self.unlock_all_button_state,
+                    Text::new("Max All Guardian Rewards")
+                        .font(JETBRAINS_MONO_BOLD)
+                        .size(17),
+                )
+                .on_press(InteractionMessage::ManageProfileInteraction(
+                    ManageProfileInteractionMessage::Profile(
+                        ProfileInteractionMessage::MaxGuardianRewardsPressed,
+                    ),
+                ))
+                .padding(10)
+                .style(Bl3UiStyle)
+                .into_element(),
+            )
+            .push(
+                Button::new(
+                    &mut self.export_csv_button_state,
+                    Text::new("Export as CSV")
+                        .font(JETBRAINS_MONO_BOLD)
+                        .size(17),
+                )
+                .on_press(InteractionMessage::ManageProfileInteraction(
+                    ManageProfileInteractionMessage::Profile(
+                        ProfileInteractionMessage::ExportGuardianRewardsAsCsv,
+                    ),
+                ))
+                .padding(10)
+                .style(Bl3UiStyle)
+                .into_element(),
+            )
+            .spacing(15);
+
         Container::new(
             Column::new()
                 .push(
@@ -275,20 +406,11 @@ impl GuardianRewardUnlocker {
                             )
                             .push(
                                 Container::new(
-                                    Button::new(
-                                        &mut self.unlock_all_button_state,
-                                        Text::new("Max All Guardian Rewards")
-                                            .font(JETBRAINS_MONO_BOLD)
-                                            .size(17),
-                                    )
-                                    .on_press(InteractionMessage::ManageProfileInteraction(
-                                        ManageProfileInteractionMessage::Profile(
-                                            ProfileInteractionMessage::MaxGuardianRewardsPressed,
-                                        ),
-                                    ))
-                                    .padding(10)
-                                    .style(Bl3UiStyle)
-                                    .into_element(),
+                                    Column::new()
+                                        .push(distribute_row)
+                                        .push(buttons_row)
+                                        .align_items(Alignment::Center)
+                                        .spacing(15),
                                 )
                                 .height(Length::Fill)
                                 .align_y(Vertical::Bottom)
@@ -304,6 +426,29 @@ impl GuardianRewardUnlocker {
         )
     }
 
+    /// The per-field messages that spread the distribute total across every reward.
+    pub fn distribute_messages(&self) -> Vec<GuardianRewardMessage> {
+        let rewards = self.all_rewards();
+
+        let priority_index = rewards
+            .iter()
+            .position(|r| r.guardian_reward == self.distribute_priority_selected)
+            .unwrap_or(0);
+
+        let amounts = distribute_tokens(
+            self.distribute_total_input,
+            rewards.len(),
+            priority_index,
+            MAX_GUARDIAN_REWARD_TOKENS,
+        );
+
+        rewards
+            .iter()
+            .zip(amounts)
+            .map(|(r, amount)| r.changed_message(amount))
+            .collect()
+    }
+
     pub fn all_rewards(&self) -> [&GuardianRewardField; 18] {
         [
             &self.accuracy,
@@ -1,8 +1,13 @@
-use iced::{pick_list, text_input, Alignment, Column, Container, Length, PickList, Row};
+use std::path::PathBuf;
 
+use iced::{
+    pick_list, text_input, Alignment, Color, Column, Container, Length, PickList, Row, Text,
+};
+
+use bl3_save_edit_core::bl3_profile::guardian_reward::GuardianReward;
 use bl3_save_edit_core::bl3_profile::science_levels::BorderlandsScienceLevel;
 
-use crate::bl3_ui::{Bl3Message, InteractionMessage};
+use crate::bl3_ui::{Bl3Message, InteractionMessage, MessageResult};
 use crate::bl3_ui_style::Bl3UiStyle;
 use crate::resources::fonts::JETBRAINS_MONO;
 use crate::views::manage_profile::profile::guardian_rewards::GuardianRewardUnlocker;
@@ -23,6 +28,8 @@ pub struct ProfileState {
     pub guardian_rank_tokens_input_state: text_input::State,
     pub science_level_selector: pick_list::State<BorderlandsScienceLevel>,
     pub science_level_selected: BorderlandsScienceLevel,
+    pub science_xp_input: u32,
+    pub science_xp_input_state: text_input::State,
     pub science_tokens_input: i32,
     pub science_tokens_input_state: text_input::State,
     pub skin_unlocker: SkinUnlocker,
@@ -34,12 +41,18 @@ pub struct ProfileState {
 pub enum ProfileInteractionMessage {
     GuardianRankTokens(i32),
     ScienceLevelSelected(BorderlandsScienceLevel),
+    ScienceXp(u32),
     ScienceTokens(i32),
     SkinMessage(SkinUnlockedMessage),
     SduMessage(SduMessage),
     MaxSduSlotsPressed,
     GuardianRewardMessage(GuardianRewardMessage),
+    DistributeGuardianRewardTokens(i32),
+    DistributeGuardianRewardPrioritySelected(GuardianReward),
+    DistributeGuardianRewardsPressed,
     MaxGuardianRewardsPressed,
+    ExportGuardianRewardsAsCsv,
+    ExportGuardianRewardsAsCsvCompleted(MessageResult<PathBuf>),
 }
 
 #[derive(Debug, Clone)]
@@ -51,6 +64,7 @@ pub enum SkinUnlockedMessage {
     RoomDecorations(bool),
     WeaponSkins(bool),
     WeaponTrinkets(bool),
+    EventCosmetics(bool),
 }
 
 #[derive(Debug, Clone)]
@@ -179,6 +193,60 @@ pub fn view(profile_state: &mut ProfileState) -> Container<Bl3Message> {
         .push(borderlands_science_tokens)
         .spacing(20);
 
+    let xp_to_next_tier = profile_state.science_level_selected.xp_to_next_tier();
+
+    let science_xp_progress = if xp_to_next_tier > 0 {
+        format!(
+            "{} / {} XP to next tier",
+            profile_state.science_xp_input, xp_to_next_tier
+        )
+    } else {
+        "No higher tier".to_owned()
+    };
+
+    let borderlands_science_xp = Container::new(
+        LabelledElement::create(
+            "Borderlands Science XP",
+            Length::Units(215),
+            Row::new()
+                .push(
+                    NumberInput::new(
+                        &mut profile_state.science_xp_input_state,
+                        profile_state.science_xp_input,
+                        0,
+                        Some(xp_to_next_tier.saturating_sub(1)),
+                        |v| {
+                            InteractionMessage::ManageProfileInteraction(
+                                ManageProfileInteractionMessage::Profile(
+                                    ProfileInteractionMessage::ScienceXp(v),
+                                ),
+                            )
+                        },
+                    )
+                    .0
+                    .font(JETBRAINS_MONO)
+                    .padding(10)
+                    .size(17)
+                    .width(Length::Units(120))
+                    .style(Bl3UiStyle)
+                    .into_element(),
+                )
+                .push(
+                    Text::new(science_xp_progress)
+                        .font(JETBRAINS_MONO)
+                        .size(17)
+                        .color(Color::from_rgb8(220, 220, 220)),
+                )
+                .spacing(15)
+                .align_items(Alignment::Center),
+        )
+        .spacing(15)
+        .align_items(Alignment::Center),
+    )
+    .width(Length::Fill)
+    .height(Length::Units(36))
+    .style(Bl3UiStyle);
+
     let guardian_reward_unlocker = profile_state
         .guardian_reward_unlocker
         .view()
@@ -188,6 +256,7 @@ pub fn view(profile_state: &mut ProfileState) -> Container<Bl3Message> {
         Column::new()
             .push(guardian_rank_tokens)
             .push(borderlands_science_row)
+            .push(borderlands_science_xp)
             .push(guardian_reward_unlocker)
             .spacing(20),
     )
@@ -2,14 +2,14 @@ use std::rc::Rc;
 
 use derivative::Derivative;
 use iced::alignment::Horizontal;
-use iced::{Checkbox, Color, Column, Container, Element, Length, Text};
+use iced::{tooltip, Checkbox, Color, Column, Container, Element, Length, Text, Tooltip};
 
 use bl3_save_edit_core::bl3_profile::skins::{
     ProfileSkinData, ProfileSkinType, SkinSet, WeaponSkinSet,
 };
 
 use crate::bl3_ui::{Bl3Message, InteractionMessage};
-use crate::bl3_ui_style::Bl3UiStyle;
+use crate::bl3_ui_style::{Bl3UiStyle, Bl3UiTooltipStyle};
 use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
 use crate::views::manage_profile::profile::{ProfileInteractionMessage, SkinUnlockedMessage};
 use crate::views::manage_profile::ManageProfileInteractionMessage;
@@ -79,6 +79,7 @@ pub struct SkinUnlocker {
     pub room_decorations: SkinUnlockCheckbox,
     pub weapon_skins: SkinUnlockCheckbox,
     pub weapon_trinkets: SkinUnlockCheckbox,
+    pub include_event_cosmetics: bool,
 }
 
 impl std::default::Default for SkinUnlocker {
@@ -119,12 +120,52 @@ impl std::default::Default for SkinUnlocker {
                 ProfileSkinData::new(ProfileSkinType::Weapon(WeaponSkinSet::WeaponTrinkets), 0),
                 SkinUnlockedMessage::WeaponTrinkets,
             ),
+            include_event_cosmetics: false,
         }
     }
 }
 
 impl SkinUnlocker {
     pub fn view(&mut self) -> Container<Bl3Message> {
+        let skin_checkboxes = Column::new()
+            .push(self.character_skins.view())
+            .push(self.character_heads.view())
+            .push(self.echo_themes.view())
+            .push(self.emotes.view())
+            .push(self.room_decorations.view())
+            .push(self.weapon_skins.view())
+            .push(self.weapon_trinkets.view())
+            .push(
+                Tooltip::new(
+                    Checkbox::new(
+                        self.include_event_cosmetics,
+                        "Include Event Cosmetics",
+                        |c| {
+                            InteractionMessage::ManageProfileInteraction(
+                                ManageProfileInteractionMessage::Profile(
+                                    ProfileInteractionMessage::SkinMessage(
+                                        SkinUnlockedMessage::EventCosmetics(c),
+                                    ),
+                                ),
+                            )
+                        },
+                    )
+                    .size(20)
+                    .font(JETBRAINS_MONO)
+                    .text_color(Color::from_rgb8(220, 220, 220))
+                    .text_size(17)
+                    .style(Bl3UiStyle),
+                    "Also unlock the cosmetics from events and SHiFT, such as Twitch drops",
+                    tooltip::Position::Top,
+                )
+                .gap(10)
+                .padding(10)
+                .font(JETBRAINS_MONO)
+                .size(17)
+                .style(Bl3UiTooltipStyle),
+            )
+            .spacing(15);
+
         Container::new(
             Column::new()
                 .push(
@@ -140,21 +181,11 @@ impl SkinUnlocker {
                     .style(Bl3UiStyle),
                 )
                 .push(
-                    Container::new(
-                        Column::new()
-                            .push(self.character_skins.view())
-                            .push(self.character_heads.view())
-                            .push(self.echo_themes.view())
-                            .push(self.emotes.view())
-                            .push(self.room_decorations.view())
-                            .push(self.weapon_skins.view())
-                            .push(self.weapon_trinkets.view())
-                            .spacing(15),
-                    )
-                    .width(Length::Fill)
-                    .padding(15)
-                    .height(Length::Units(265))
-                    .style(Bl3UiStyle),
+                    Container::new(skin_checkboxes)
+                        .width(Length::Fill)
+                        .padding(15)
+                        .height(Length::Units(300))
+                        .style(Bl3UiStyle),
                 ),
         )
     }
@@ -0,0 +1,133 @@
+use iced::{Color, Column, Container, Length, Row, Text};
+
+use crate::bl3_ui::Bl3Message;
+use crate::bl3_ui_style::Bl3UiStyle;
+use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
+
+#[derive(Debug, Default)]
+pub struct SummaryState {
+    pub guardian_rank: i32,
+    pub bank_item_count: usize,
+    pub keys: Vec<(String, i32)>,
+    pub sdu_levels: Vec<(String, i32, i32)>,
+    pub skin_categories: Vec<SkinCategorySummary>,
+}
+
+#[derive(Debug, Default)]
+pub struct SkinCategorySummary {
+    pub name: String,
+    pub current: usize,
+    pub maximum: usize,
+}
+
+impl SkinCategorySummary {
+    pub fn is_complete(&self) -> bool {
+        self.current >= self.maximum
+    }
+}
+
+fn card<'a>(title: &str, contents: Column<'a, Bl3Message>) -> Container<'a, Bl3Message> {
+    Container::new(
+        Column::new()
+            .push(
+                Text::new(title)
+                    .font(JETBRAINS_MONO_BOLD)
+                    .size(17)
+                    .color(Color::from_rgb8(242, 203, 5)),
+            )
+            .push(contents.spacing(10))
+            .spacing(15),
+    )
+    .padding(15)
+    .width(Length::Fill)
+    .style(Bl3UiStyle)
+}
+
+fn summary_text(text: String) -> Text {
+    Text::new(text)
+        .font(JETBRAINS_MONO)
+        .size(17)
+        .color(Color::from_rgb8(220, 220, 220))
+}
+
+pub fn view(summary_state: &SummaryState) -> Container<Bl3Message> {
+    let overview = card(
+        "Overview",
+        Column::new()
+            .push(summary_text(format!(
+                "Guardian Rank: {}",
+                summary_state.guardian_rank
+            )))
+            .push(summary_text(format!(
+                "Bank Items: {}",
+                summary_state.bank_item_count
+            ))),
+    );
+
+    let keys = card(
+        "Keys",
+        summary_state
+            .keys
+            .iter()
+            .fold(Column::new(), |column, (name, amount)| {
+                column.push(summary_text(format!("{}: {}", name, amount)))
+            }),
+    );
+
+    let sdu_levels = card(
+        "SDU Levels",
+        summary_state
+            .sdu_levels
+            .iter()
+            .fold(Column::new(), |column, (name, current, max)| {
+                column.push(summary_text(format!("{}: {} / {}", name, current, max)))
+            }),
+    );
+
+    let skin_categories =
+        summary_state
+            .skin_categories
+            .chunks(2)
+            .fold(Column::new(), |column, categories| {
+                let row = categories.iter().fold(Row::new().spacing(20), |row, c| {
+                    let (status, color) = if c.is_complete() {
+                        ("Unlocked", Color::from_rgb8(84, 186, 108))
+                    } else {
+                        ("Incomplete", Color::from_rgb8(180, 180, 180))
+                    };
+
+                    row.push(
+                        Row::new()
+                            .push(summary_text(format!(
+                                "{} [{}/{}]",
+                                c.name, c.current, c.maximum
+                            )))
+                            .push(
+                                Text::new(status)
+                                    .font(JETBRAINS_MONO_BOLD)
+                                    .size(17)
+                                    .color(color),
+                            )
+                            .spacing(10)
+                            .width(Length::FillPortion(1)),
+                    )
+                });
+
+                column.push(row)
+            });
+
+    let cosmetics = card("Cosmetics", skin_categories);
+
+    let all_contents = Column::new()
+        .push(
+            Row::new()
+                .push(overview)
+                .push(keys)
+                .push(sdu_levels)
+                .spacing(20),
+        )
+        .push(cosmetics)
+        .spacing(20);
+
+    Container::new(all_contents).padding(30)
+}
@@ -0,0 +1,192 @@
+use iced::alignment::Horizontal;
+use iced::{
+    button, scrollable, tooltip, Alignment, Button, Checkbox, Color, Column, Container, Length,
+    ProgressBar, Row, Scrollable, Text, Tooltip,
+};
+
+use bl3_save_edit_core::bl3_save::challenge_data::ChallengeCategoryCompletion;
+
+use crate::bl3_ui::{Bl3Message, InteractionMessage};
+use crate::bl3_ui_style::{Bl3UiProgressBarStyle, Bl3UiStyle, Bl3UiTooltipStyle};
+use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
+use crate::views::manage_save::ManageSaveInteractionMessage;
+use crate::views::InteractionExt;
+
+#[derive(Debug, Default)]
+pub struct ChallengesState {
+    pub categories: Vec<ChallengeCategoryCompletion>,
+    pub include_dlc: bool,
+    // Set once the user has confirmed, the challenges are completed when the save is saved
+    pub complete_all: bool,
+    complete_all_button_state: button::State,
+    scrollable_state: scrollable::State,
+}
+
+#[derive(Debug, Clone)]
+pub enum SaveChallengesInteractionMessage {
+    IncludeDlcToggled(bool),
+    CompleteAllPressed,
+    CompleteAll,
+}
+
+impl ChallengesState {
+    /// Shows the categories that will be completed as done, so the percentages match the save
+    /// once it is saved.
+    pub fn complete_all(&mut self) {
+        self.complete_all = true;
+
+        let include_dlc = self.include_dlc;
+
+        self.categories
+            .iter_mut()
+            .filter(|c| c.is_completable(include_dlc))
+            .for_each(|c| c.completed = c.total);
+    }
+
+    fn overall(&self) -> ChallengeCategoryCompletion {
+        ChallengeCategoryCompletion {
+            completed: self.categories.iter().map(|c| c.completed).sum(),
+            total: self.categories.iter().map(|c| c.total).sum(),
+            ..ChallengeCategoryCompletion::default()
+        }
+    }
+}
+
+fn challenges_message(message: SaveChallengesInteractionMessage) -> InteractionMessage {
+    InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::Challenges(message))
+}
+
+fn completion_row<'a>(
+    label: String,
+    completion: &ChallengeCategoryCompletion,
+) -> Row<'a, Bl3Message> {
+    Row::new()
+        .push(
+            Text::new(label)
+                .font(JETBRAINS_MONO)
+                .size(17)
+                .color(Color::from_rgb8(220, 220, 220))
+                .width(Length::Units(320)),
+        )
+        .push(
+            ProgressBar::new(0.0..=100.0, completion.percentage())
+                .width(Length::Fill)
+                .height(Length::Units(12))
+                .style(Bl3UiProgressBarStyle),
+        )
+        .push(
+            Text::new(format!(
+                "{}/{} ({:.0}%)",
+                completion.completed,
+                completion.total,
+                completion.percentage()
+            ))
+            .font(JETBRAINS_MONO)
+            .size(17)
+            .color(Color::from_rgb8(220, 220, 220))
+            .width(Length::Units(180))
+            .horizontal_alignment(Horizontal::Right),
+        )
+        .spacing(15)
+        .align_items(Alignment::Center)
+}
+
+pub fn view(challenges_state: &mut ChallengesState) -> Container<Bl3Message> {
+    let overall = challenges_state.overall();
+
+    let mut complete_all_button = Button::new(
+        &mut challenges_state.complete_all_button_state,
+        Text::new("Complete All Challenges")
+            .font(JETBRAINS_MONO_BOLD)
+            .size(17),
+    )
+    .padding(10)
+    .style(Bl3UiStyle);
+
+    if !challenges_state.complete_all {
+        complete_all_button = complete_all_button.on_press(challenges_message(
+            SaveChallengesInteractionMessage::CompleteAllPressed,
+        ));
+    }
+
+    let complete_all = Container::new(
+        Row::new()
+            .push(
+                Tooltip::new(
+                    complete_all_button.into_element(),
+                    "Marks every base game challenge as complete when saving, the tier each challenge has reached is kept",
+                    tooltip::Position::Top,
+                )
+                .gap(10)
+                .padding(10)
+                .font(JETBRAINS_MONO)
+                .size(17)
+                .style(Bl3UiTooltipStyle),
+            )
+            .push(
+                Checkbox::new(
+                    challenges_state.include_dlc,
+                    "Include DLC and event challenges",
+                    |c| challenges_message(SaveChallengesInteractionMessage::IncludeDlcToggled(c)),
+                )
+                .size(20)
+                .font(JETBRAINS_MONO)
+                .text_color(Color::from_rgb8(220, 220, 220))
+                .text_size(17)
+                .style(Bl3UiStyle)
+                .into_element(),
+            )
+            .spacing(20)
+            .align_items(Alignment::Center),
+    )
+    .width(Length::Fill)
+    .padding(15)
+    .style(Bl3UiStyle);
+
+    let categories = challenges_state.categories.iter().fold(
+        Column::new()
+            .push(completion_row("Overall".to_owned(), &overall))
+            .spacing(15),
+        |curr, completion| {
+            curr.push(completion_row(
+                format!(
+                    "{} ({})",
+                    completion.category,
+                    completion.content_source.short_name()
+                ),
+                completion,
+            ))
+        },
+    );
+
+    let completion = Column::new()
+        .push(
+            Container::new(
+                Text::new("Challenge Completion")
+                    .font(JETBRAINS_MONO_BOLD)
+                    .size(17)
+                    .color(Color::from_rgb8(242, 203, 5)),
+            )
+            .padding(10)
+            .align_x(Horizontal::Center)
+            .width(Length::Fill)
+            .style(Bl3UiStyle),
+        )
+        .push(
+            Container::new(
+                Scrollable::new(&mut challenges_state.scrollable_state)
+                    .push(categories)
+                    .height(Length::Fill),
+            )
+            .padding(15)
+            .style(Bl3UiStyle),
+        )
+        .spacing(15);
+
+    let all_contents = Column::new()
+        .push(complete_all)
+        .push(completion)
+        .spacing(20);
+
+    Container::new(all_contents).padding(30)
+}
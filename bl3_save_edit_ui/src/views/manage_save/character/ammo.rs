@@ -7,7 +7,7 @@ use iced::{
     Tooltip,
 };
 
-use bl3_save_edit_core::bl3_save::ammo::AmmoPool;
+use bl3_save_edit_core::bl3_save::ammo::{AmmoPool, UnknownAmmoPoolData};
 
 use crate::bl3_ui::{Bl3Message, InteractionMessage};
 use crate::bl3_ui_style::{Bl3UiStyle, Bl3UiTooltipStyle};
@@ -26,6 +26,7 @@ pub struct AmmoSetterField {
     pub ammo_pool: AmmoPool,
     pub input: i32,
     input_state: text_input::State,
+    max_button_state: button::State,
     #[derivative(
         Debug = "ignore",
         Default(value = "Rc::new(CharacterAmmoMessage::Grenade)")
@@ -92,7 +93,24 @@ impl AmmoSetterField {
                 .size(17)
                 .style(Bl3UiTooltipStyle),
             )
+            .push(
+                Button::new(
+                    &mut self.max_button_state,
+                    Text::new("Max").font(JETBRAINS_MONO_BOLD).size(17),
+                )
+                .on_press(InteractionMessage::ManageSaveInteraction(
+                    ManageSaveInteractionMessage::Character(
+                        SaveCharacterInteractionMessage::AmmoMessage(CharacterAmmoMessage::Max(
+                            self.ammo_pool.clone(),
+                        )),
+                    ),
+                ))
+                .padding(10)
+                .style(Bl3UiStyle)
+                .into_element(),
+            )
             .width(Length::Fill)
+            .spacing(10)
             .align_items(Alignment::Center)
     }
 }
@@ -106,6 +124,7 @@ pub struct AmmoSetter {
     pub smg: AmmoSetterField,
     pub assault_rifle: AmmoSetterField,
     pub pistol: AmmoSetterField,
+    pub unknown_pools: Vec<UnknownAmmoPoolData>,
     max_all_button_state: button::State,
 }
 
@@ -123,13 +142,41 @@ impl std::default::Default for AmmoSetter {
                 CharacterAmmoMessage::AssaultRifle,
             ),
             pistol: AmmoSetterField::new(0, AmmoPool::Pistol, CharacterAmmoMessage::Pistol),
+            unknown_pools: Vec::new(),
             max_all_button_state: button::State::default(),
         }
     }
 }
 
 impl AmmoSetter {
+    pub fn field_mut(&mut self, ammo_pool: &AmmoPool) -> &mut AmmoSetterField {
+        match ammo_pool {
+            AmmoPool::Grenade => &mut self.grenade,
+            AmmoPool::Pistol => &mut self.pistol,
+            AmmoPool::Shotgun => &mut self.shotgun,
+            AmmoPool::Smg => &mut self.smg,
+            AmmoPool::Ar => &mut self.assault_rifle,
+            AmmoPool::Sniper => &mut self.sniper,
+            AmmoPool::Heavy => &mut self.heavy,
+        }
+    }
+
     pub fn view(&mut self) -> Container<Bl3Message> {
+        let unknown_pools = self.unknown_pools.iter().fold(
+            Column::new().spacing(10).width(Length::Fill),
+            |col, pool| {
+                col.push(
+                    Text::new(format!(
+                        "{}: {} (read only)",
+                        pool.resource_path, pool.current
+                    ))
+                    .font(JETBRAINS_MONO)
+                    .size(15)
+                    .color(Color::from_rgb8(220, 220, 220)),
+                )
+            },
+        );
+
         Container::new(
             Column::new()
                 .push(
@@ -163,6 +210,7 @@ impl AmmoSetter {
                                     .push(self.pistol.view())
                                     .push(Row::new().width(Length::Fill)),
                             )
+                            .push(unknown_pools)
                             .push(
                                 Container::new(
                                     Button::new(
@@ -0,0 +1,120 @@
+use iced::{button, Alignment, Button, Color, Column, Container, Length, Row, Text};
+
+use bl3_save_edit_core::bl3_item::Bl3Item;
+use bl3_save_edit_core::bl3_save::inventory_slot::InventorySlot;
+
+use crate::bl3_ui::{Bl3Message, InteractionMessage};
+use crate::bl3_ui_style::Bl3UiStyle;
+use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
+use crate::views::item_editor::list_item_contents::{level_label, ItemListSummary, RarityColor};
+use crate::views::manage_save::character::SaveCharacterInteractionMessage;
+use crate::views::manage_save::ManageSaveInteractionMessage;
+use crate::views::InteractionExt;
+
+#[derive(Debug, Default)]
+struct EquippedItemRow {
+    slot: InventorySlot,
+    inventory_index: usize,
+    summary: ItemListSummary,
+    jump_button_state: button::State,
+}
+
+/// Read only list of what the loaded save has equipped in each slot.
+#[derive(Debug, Default)]
+pub struct EquippedItemsSummary {
+    rows: Vec<EquippedItemRow>,
+}
+
+impl EquippedItemsSummary {
+    pub fn set_items(&mut self, equipped_items: Vec<(InventorySlot, usize, &Bl3Item)>) {
+        self.rows = equipped_items
+            .into_iter()
+            .map(|(slot, inventory_index, item)| EquippedItemRow {
+                slot,
+                inventory_index,
+                summary: ItemListSummary::new(item),
+                ..EquippedItemRow::default()
+            })
+            .collect();
+    }
+
+    pub fn view(&mut self) -> Container<Bl3Message> {
+        let mut rows = Column::new().spacing(10);
+
+        if self.rows.is_empty() {
+            rows = rows.push(
+                Text::new("Nothing is equipped.")
+                    .font(JETBRAINS_MONO)
+                    .size(17)
+                    .color(Color::from_rgb8(180, 180, 180)),
+            );
+        }
+
+        for row in self.rows.iter_mut() {
+            rows = rows.push(
+                Row::new()
+                    .push(
+                        Text::new(row.slot.to_string())
+                            .font(JETBRAINS_MONO_BOLD)
+                            .size(17)
+                            .color(Color::from_rgb8(220, 220, 220))
+                            .width(Length::Units(110)),
+                    )
+                    .push(
+                        Text::new(&row.summary.label)
+                            .font(JETBRAINS_MONO)
+                            .size(17)
+                            .color(row.summary.header.rarity.color())
+                            .width(Length::Fill),
+                    )
+                    .push(
+                        Text::new(level_label(&row.summary))
+                            .font(JETBRAINS_MONO)
+                            .size(17)
+                            .color(Color::from_rgb8(220, 220, 220))
+                            .width(Length::Units(170)),
+                    )
+                    .push(
+                        Text::new(row.summary.header.rarity.to_string())
+                            .font(JETBRAINS_MONO)
+                            .size(17)
+                            .color(Color::from_rgb8(180, 180, 180))
+                            .width(Length::Units(140)),
+                    )
+                    .push(
+                        Button::new(
+                            &mut row.jump_button_state,
+                            Text::new("Jump to item").font(JETBRAINS_MONO_BOLD).size(17),
+                        )
+                        .on_press(InteractionMessage::ManageSaveInteraction(
+                            ManageSaveInteractionMessage::Character(
+                                SaveCharacterInteractionMessage::JumpToEquippedItem(
+                                    row.inventory_index,
+                                ),
+                            ),
+                        ))
+                        .padding(10)
+                        .style(Bl3UiStyle)
+                        .into_element(),
+                    )
+                    .spacing(15)
+                    .align_items(Alignment::Center),
+            );
+        }
+
+        Container::new(
+            Column::new()
+                .push(
+                    Text::new("Equipped Items")
+                        .font(JETBRAINS_MONO_BOLD)
+                        .size(17)
+                        .color(Color::from_rgb8(242, 203, 5)),
+                )
+                .push(rows)
+                .spacing(10),
+        )
+        .width(Length::Fill)
+        .padding(15)
+        .style(Bl3UiStyle)
+    }
+}
@@ -1,16 +1,25 @@
+use std::path::PathBuf;
+
 use iced::{
-    pick_list, text_input, tooltip, Alignment, Column, Container, Length, PickList, Row, Tooltip,
+    button, pick_list, text_input, tooltip, Alignment, Button, Color, Column, Container, Length,
+    PickList, Row, Text, Tooltip,
 };
 
+use bl3_save_edit_core::bl3_save::ammo::AmmoPool;
 use bl3_save_edit_core::bl3_save::character_data::MAX_CHARACTER_LEVEL;
 use bl3_save_edit_core::bl3_save::player_class::PlayerClass;
-use bl3_save_edit_core::bl3_save::util::REQUIRED_XP_LIST;
+use bl3_save_edit_core::bl3_save::sdu::SaveSduSlot;
+use bl3_save_edit_core::bl3_save::util::{
+    ability_points_for_level, level_to_xp, unsupported_name_chars,
+};
+use bl3_save_edit_core::build_preset::BuildPreset;
 use bl3_save_edit_core::game_data::GameDataKv;
 
-use crate::bl3_ui::{Bl3Message, InteractionMessage};
+use crate::bl3_ui::{Bl3Message, InteractionMessage, MessageResult};
 use crate::bl3_ui_style::{Bl3UiStyle, Bl3UiTooltipStyle};
-use crate::resources::fonts::JETBRAINS_MONO;
+use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
 use crate::views::manage_save::character::ammo::AmmoSetter;
+use crate::views::manage_save::character::equipped::EquippedItemsSummary;
 use crate::views::manage_save::character::gear::GearUnlocker;
 use crate::views::manage_save::character::sdu::SduUnlocker;
 use crate::views::manage_save::character::skins::SkinSelectors;
@@ -18,13 +27,17 @@ use crate::views::manage_save::ManageSaveInteractionMessage;
 use crate::views::InteractionExt;
 use crate::widgets::labelled_element::LabelledElement;
 use crate::widgets::number_input::NumberInput;
+use crate::widgets::skill_tree::{SkillTreeInteractionMessage, SkillTreeWidget};
 use crate::widgets::text_input_limited::TextInputLimited;
 
 mod ammo;
+mod equipped;
 mod gear;
 mod sdu;
 mod skins;
 
+const ABILITY_POINTS_MISMATCH_THRESHOLD: i32 = 3;
+
 #[derive(Debug, Default)]
 pub struct CharacterState {
     pub name_input: String,
@@ -37,18 +50,41 @@ pub struct CharacterState {
     pub experience_points_input_state: text_input::State,
     pub ability_points_input: i32,
     pub ability_points_input_state: text_input::State,
+    pub spent_ability_points: i32,
+    pub equipped_items: EquippedItemsSummary,
+    pub set_expected_ability_points_button_state: button::State,
+    pub skill_tree: SkillTreeWidget,
+    pub export_build_button_state: button::State,
+    pub import_build_button_state: button::State,
+    pub export_stats_button_state: button::State,
+    pub pending_build: Option<BuildPreset>,
     pub skin_selectors: SkinSelectors,
     pub gear_unlocker: GearUnlocker,
     pub ammo_setter: AmmoSetter,
     pub sdu_unlocker: SduUnlocker,
 }
 
+impl CharacterState {
+    pub fn expected_ability_points(&self) -> i32 {
+        (ability_points_for_level(self.level_input) - self.spent_ability_points).max(0)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum SaveCharacterInteractionMessage {
     Name(String),
     Level(i32),
     ExperiencePoints(i32),
     AbilityPoints(i32),
+    SetExpectedAbilityPointsPressed,
+    SkillTree(SkillTreeInteractionMessage),
+    ExportBuildPressed,
+    ExportBuildCompleted(MessageResult<PathBuf>),
+    ImportBuildPressed,
+    BuildImported(MessageResult<BuildPreset>),
+    ImportBuildConfirmed(MessageResult<bool>),
+    ExportStatsAsCsv,
+    ExportStatsAsCsvCompleted(MessageResult<PathBuf>),
     PlayerClassSelected(PlayerClass),
     SkinMessage(CharacterSkinSelectedMessage),
     GearMessage(CharacterGearUnlockedMessage),
@@ -56,6 +92,7 @@ pub enum SaveCharacterInteractionMessage {
     AmmoMessage(CharacterAmmoMessage),
     MaxSduSlotsPressed,
     MaxAmmoAmountsPressed,
+    JumpToEquippedItem(usize),
 }
 
 #[derive(Debug, Default)]
@@ -99,6 +136,7 @@ pub enum CharacterSduMessage {
     Smg(i32),
     AssaultRifle(i32),
     Heavy(i32),
+    Max(SaveSduSlot),
 }
 
 #[derive(Debug, Clone)]
@@ -110,6 +148,7 @@ pub enum CharacterAmmoMessage {
     Smg(i32),
     AssaultRifle(i32),
     Heavy(i32),
+    Max(AmmoPool),
 }
 
 pub fn view(character_state: &mut CharacterState) -> Container<Bl3Message> {
@@ -141,10 +180,41 @@ pub fn view(character_state: &mut CharacterState) -> Container<Bl3Message> {
         )
         .align_items(Alignment::Center),
     )
-    .width(Length::FillPortion(3))
+    .width(Length::Fill)
     .height(Length::Units(36))
     .style(Bl3UiStyle);
 
+    let name_warning = if !unsupported_name_chars(&character_state.name_input).is_empty() {
+        Some("unsupported characters will be removed when saving")
+    } else {
+        None
+    };
+
+    let name_counter = format!(
+        "{} characters, {} bytes",
+        character_state.name_input.chars().count(),
+        character_state.name_input.len()
+    );
+
+    let (name_counter, name_counter_color) = match name_warning {
+        Some(warning) => (
+            format!("{} - {}", name_counter, warning),
+            Color::from_rgb8(242, 203, 5),
+        ),
+        None => (name_counter, Color::from_rgb8(180, 180, 180)),
+    };
+
+    let character_name = Column::new()
+        .push(character_name)
+        .push(
+            Text::new(name_counter)
+                .font(JETBRAINS_MONO)
+                .size(14)
+                .color(name_counter_color),
+        )
+        .spacing(5)
+        .width(Length::FillPortion(3));
+
     let player_class = Container::new(
         LabelledElement::create(
             "Class",
@@ -181,8 +251,8 @@ pub fn view(character_state: &mut CharacterState) -> Container<Bl3Message> {
 
     let level = Container::new(
         LabelledElement::create(
-            "Level",
-            Length::Units(60),
+            format!("Level (Max {})", MAX_CHARACTER_LEVEL),
+            Length::Units(130),
             Tooltip::new(
                 NumberInput::new(
                     &mut character_state.xp_level_input_state,
@@ -228,7 +298,7 @@ pub fn view(character_state: &mut CharacterState) -> Container<Bl3Message> {
                     &mut character_state.experience_points_input_state,
                     character_state.experience_points_input,
                     0,
-                    Some(REQUIRED_XP_LIST[MAX_CHARACTER_LEVEL - 1][0]),
+                    level_to_xp(MAX_CHARACTER_LEVEL as i32),
                     |v| {
                         InteractionMessage::ManageSaveInteraction(
                             ManageSaveInteractionMessage::Character(
@@ -296,7 +366,140 @@ pub fn view(character_state: &mut CharacterState) -> Container<Bl3Message> {
         .push(ability_points)
         .spacing(20);
 
-    let skin_unlocker = character_state.skin_selectors.view(&selected_class);
+    let expected_ability_points = character_state.expected_ability_points();
+
+    let ability_points_difference =
+        (character_state.ability_points_input - expected_ability_points).abs();
+
+    let mut ability_points_breakdown_row = Row::new()
+        .push(
+            Text::new(format!(
+                "Expected skill points for level {}: {} ({} spent in skill tree)",
+                character_state.level_input,
+                expected_ability_points,
+                character_state.spent_ability_points
+            ))
+            .font(JETBRAINS_MONO)
+            .size(17)
+            .color(Color::from_rgb8(220, 220, 220)),
+        )
+        .spacing(20)
+        .align_items(Alignment::Center);
+
+    if ability_points_difference > ABILITY_POINTS_MISMATCH_THRESHOLD {
+        ability_points_breakdown_row = ability_points_breakdown_row.push(
+            Text::new(format!(
+                "Skill points are off by {}, this character may have bugged points.",
+                ability_points_difference
+            ))
+            .font(JETBRAINS_MONO)
+            .size(17)
+            .color(Color::from_rgb8(224, 117, 0)),
+        );
+    }
+
+    let ability_points_breakdown = Container::new(
+        Row::new()
+            .push(
+                Container::new(ability_points_breakdown_row)
+                    .width(Length::Fill)
+                    .padding(10),
+            )
+            .push(
+                Button::new(
+                    &mut character_state.set_expected_ability_points_button_state,
+                    Text::new("Set to expected")
+                        .font(JETBRAINS_MONO_BOLD)
+                        .size(17),
+                )
+                .on_press(InteractionMessage::ManageSaveInteraction(
+                    ManageSaveInteractionMessage::Character(
+                        SaveCharacterInteractionMessage::SetExpectedAbilityPointsPressed,
+                    ),
+                ))
+                .padding(10)
+                .style(Bl3UiStyle)
+                .into_element(),
+            )
+            .align_items(Alignment::Center),
+    )
+    .width(Length::Fill)
+    .style(Bl3UiStyle);
+
+    let equipped_items = character_state.equipped_items.view();
+
+    let skill_tree = Container::new(character_state.skill_tree.view().map(|m| {
+        Bl3Message::Interaction(InteractionMessage::ManageSaveInteraction(
+            ManageSaveInteractionMessage::Character(SaveCharacterInteractionMessage::SkillTree(m)),
+        ))
+    }))
+    .width(Length::Fill)
+    .padding(10)
+    .style(Bl3UiStyle);
+
+    let build_buttons = Container::new(
+        Row::new()
+            .push(
+                Container::new(
+                    Text::new("Share the level, skill tree and equipped items as a build file")
+                        .font(JETBRAINS_MONO)
+                        .size(17)
+                        .color(Color::from_rgb8(220, 220, 220)),
+                )
+                .width(Length::Fill)
+                .padding(10),
+            )
+            .push(
+                Button::new(
+                    &mut character_state.export_build_button_state,
+                    Text::new("Export Build").font(JETBRAINS_MONO_BOLD).size(17),
+                )
+                .on_press(InteractionMessage::ManageSaveInteraction(
+                    ManageSaveInteractionMessage::Character(
+                        SaveCharacterInteractionMessage::ExportBuildPressed,
+                    ),
+                ))
+                .padding(10)
+                .style(Bl3UiStyle)
+                .into_element(),
+            )
+            .push(
+                Button::new(
+                    &mut character_state.import_build_button_state,
+                    Text::new("Import Build").font(JETBRAINS_MONO_BOLD).size(17),
+                )
+                .on_press(InteractionMessage::ManageSaveInteraction(
+                    ManageSaveInteractionMessage::Character(
+                        SaveCharacterInteractionMessage::ImportBuildPressed,
+                    ),
+                ))
+                .padding(10)
+                .style(Bl3UiStyle)
+                .into_element(),
+            )
+            .push(
+                Button::new(
+                    &mut character_state.export_stats_button_state,
+                    Text::new("Export Stats as CSV")
+                        .font(JETBRAINS_MONO_BOLD)
+                        .size(17),
+                )
+                .on_press(InteractionMessage::ManageSaveInteraction(
+                    ManageSaveInteractionMessage::Character(
+                        SaveCharacterInteractionMessage::ExportStatsAsCsv,
+                    ),
+                ))
+                .padding(10)
+                .style(Bl3UiStyle)
+                .into_element(),
+            )
+            .spacing(15)
+            .align_items(Alignment::Center),
+    )
+    .width(Length::Fill)
+    .style(Bl3UiStyle);
+
+    let skin_unlocker = character_state.skin_selectors.view();
 
     let gear_unlocker = character_state
         .gear_unlocker
@@ -322,6 +525,10 @@ pub fn view(character_state: &mut CharacterState) -> Container<Bl3Message> {
     let all_contents = Column::new()
         .push(name_class_row)
         .push(experience_and_level_row)
+        .push(ability_points_breakdown)
+        .push(equipped_items)
+        .push(skill_tree)
+        .push(build_buttons)
         .push(skin_unlocker)
         .push(slot_sdu_row)
         .spacing(20);
@@ -26,6 +26,7 @@ pub struct SduUnlockField {
     pub sdu_slot: SaveSduSlot,
     pub input: i32,
     input_state: text_input::State,
+    max_button_state: button::State,
     #[derivative(
         Debug = "ignore",
         Default(value = "Rc::new(CharacterSduMessage::Backpack)")
@@ -92,7 +93,24 @@ impl SduUnlockField {
                 .size(17)
                 .style(Bl3UiTooltipStyle),
             )
+            .push(
+                Button::new(
+                    &mut self.max_button_state,
+                    Text::new("Max").font(JETBRAINS_MONO_BOLD).size(17),
+                )
+                .on_press(InteractionMessage::ManageSaveInteraction(
+                    ManageSaveInteractionMessage::Character(
+                        SaveCharacterInteractionMessage::SduMessage(CharacterSduMessage::Max(
+                            self.sdu_slot.clone(),
+                        )),
+                    ),
+                ))
+                .padding(10)
+                .style(Bl3UiStyle)
+                .into_element(),
+            )
             .width(Length::Fill)
+            .spacing(10)
             .align_items(Alignment::Center)
     }
 }
@@ -131,6 +149,19 @@ impl std::default::Default for SduUnlocker {
 }
 
 impl SduUnlocker {
+    pub fn field_mut(&mut self, sdu_slot: &SaveSduSlot) -> &mut SduUnlockField {
+        match sdu_slot {
+            SaveSduSlot::Backpack => &mut self.backpack,
+            SaveSduSlot::Sniper => &mut self.sniper,
+            SaveSduSlot::Shotgun => &mut self.shotgun,
+            SaveSduSlot::Pistol => &mut self.pistol,
+            SaveSduSlot::Grenade => &mut self.grenade,
+            SaveSduSlot::Smg => &mut self.smg,
+            SaveSduSlot::Ar => &mut self.assault_rifle,
+            SaveSduSlot::Heavy => &mut self.heavy,
+        }
+    }
+
     pub fn view(&mut self) -> Container<Bl3Message> {
         Container::new(
             Column::new()
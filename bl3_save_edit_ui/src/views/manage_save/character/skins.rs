@@ -1,13 +1,15 @@
 use std::rc::Rc;
 
 use derivative::Derivative;
-use iced::{pick_list, Alignment, Column, Container, Length, PickList, Row};
+use iced::{pick_list, Alignment, Color, Column, Container, Length, PickList, Row, Text};
 use rayon::prelude::ParallelSliceMut;
 
+use bl3_save_edit_core::bl3_save::customization::{
+    class_customizations, default_customization, CustomizationType,
+};
 use bl3_save_edit_core::bl3_save::player_class::PlayerClass;
 use bl3_save_edit_core::game_data::{
-    GameDataKv, PROFILE_ECHO_THEMES, PROFILE_ECHO_THEMES_DEFAULTS, PROFILE_HEADS,
-    PROFILE_HEADS_DEFAULTS, PROFILE_SKINS, PROFILE_SKINS_DEFAULTS,
+    GameDataKv, PROFILE_ECHO_THEMES, PROFILE_ECHO_THEMES_DEFAULTS,
 };
 
 use crate::bl3_ui::{Bl3Message, InteractionMessage};
@@ -28,6 +30,8 @@ pub struct SkinPickList {
     available_skins: Vec<GameDataKv>,
     pick_list: pick_list::State<GameDataKv>,
     pub selected: GameDataKv,
+    // The skin selected in the loaded save, shown under the pick list so changes can be compared
+    current: Option<GameDataKv>,
     #[derivative(
         Debug = "ignore",
         Default(value = "Rc::new(CharacterSkinSelectedMessage::HeadSkin)")
@@ -62,37 +66,44 @@ impl SkinPickList {
             name_width,
             available_skins,
             selected: pre_selected_skin,
+            current: None,
             pick_list: pick_list::State::default(),
             on_selected: Rc::new(on_selected),
         }
     }
 
-    pub fn view(&mut self, player_class: Option<&PlayerClass>) -> Container<Bl3Message> {
-        let on_selected = self.on_selected.clone();
+    /// Replaces the skins to pick from, falling back to `default_skin` if the selected skin is no
+    /// longer in the list.
+    pub fn set_available_skins(
+        &mut self,
+        available_skins: Vec<GameDataKv>,
+        default_skin: GameDataKv,
+    ) {
+        if !available_skins.contains(&self.selected) {
+            self.selected = default_skin;
+        }
 
-        let available_skins = if let Some(player_class) = player_class {
-            let player_class_s = player_class.to_string().to_lowercase();
+        self.available_skins = available_skins;
+    }
 
-            self.available_skins
-                .iter()
-                .cloned()
-                .filter(|h| h.ident.to_lowercase().contains(&player_class_s))
-                .collect::<Vec<_>>()
-        } else {
-            self.available_skins.to_vec()
-        };
+    /// Selects the skin from the loaded save and remembers it as the current skin.
+    pub fn set_current(&mut self, current: GameDataKv) {
+        self.selected = current;
+        self.current = Some(current);
+    }
 
-        if !available_skins.contains(&self.selected) {
-            self.selected = available_skins[0];
-        }
+    pub fn view(&mut self) -> Column<Bl3Message> {
+        let on_selected = self.on_selected.clone();
 
-        Container::new(
+        let current_name = self.current.map(|c| c.name).unwrap_or("Unknown").to_owned();
+
+        let pick_list = Container::new(
             LabelledElement::create(
                 &self.name,
                 Length::Units(self.name_width),
                 PickList::new(
                     &mut self.pick_list,
-                    available_skins,
+                    self.available_skins.clone(),
                     Some(self.selected),
                     move |s| {
                         InteractionMessage::ManageSaveInteraction(
@@ -113,7 +124,17 @@ impl SkinPickList {
         )
         .width(Length::Fill)
         .height(Length::Units(36))
-        .style(Bl3UiStyle)
+        .style(Bl3UiStyle);
+
+        Column::new()
+            .push(pick_list)
+            .push(
+                Text::new(format!("Current: {}", current_name))
+                    .font(JETBRAINS_MONO)
+                    .size(15)
+                    .color(Color::from_rgb8(180, 180, 180)),
+            )
+            .spacing(8)
     }
 }
 
@@ -126,21 +147,31 @@ pub struct SkinSelectors {
 
 impl std::default::Default for SkinSelectors {
     fn default() -> Self {
+        let player_class = PlayerClass::default();
+
+        let mut head_skin = SkinPickList::new(
+            "Head Skin",
+            105,
+            &[],
+            &class_customizations(player_class, CustomizationType::Head),
+            CharacterSkinSelectedMessage::HeadSkin,
+        );
+
+        head_skin.selected = default_customization(player_class, CustomizationType::Head);
+
+        let mut character_skin = SkinPickList::new(
+            "Character Skin",
+            135,
+            &[],
+            &class_customizations(player_class, CustomizationType::Skin),
+            CharacterSkinSelectedMessage::CharacterSkin,
+        );
+
+        character_skin.selected = default_customization(player_class, CustomizationType::Skin);
+
         Self {
-            head_skin: SkinPickList::new(
-                "Head Skin",
-                105,
-                &PROFILE_HEADS_DEFAULTS,
-                &PROFILE_HEADS,
-                CharacterSkinSelectedMessage::HeadSkin,
-            ),
-            character_skin: SkinPickList::new(
-                "Character Skin",
-                135,
-                &PROFILE_SKINS_DEFAULTS,
-                &PROFILE_SKINS,
-                CharacterSkinSelectedMessage::CharacterSkin,
-            ),
+            head_skin,
+            character_skin,
             echo_theme: SkinPickList::new(
                 "ECHO Theme",
                 105,
@@ -153,10 +184,24 @@ impl std::default::Default for SkinSelectors {
 }
 
 impl SkinSelectors {
-    pub fn view(&mut self, player_class: &PlayerClass) -> Container<Bl3Message> {
-        let head_skin = self.head_skin.view(Some(player_class));
-        let character_skin = self.character_skin.view(Some(player_class));
-        let echo_theme = self.echo_theme.view(None);
+    /// Only lists the heads and skins the class can wear, resetting selections it can't to the
+    /// class default.
+    pub fn set_player_class(&mut self, player_class: PlayerClass) {
+        self.head_skin.set_available_skins(
+            class_customizations(player_class, CustomizationType::Head),
+            default_customization(player_class, CustomizationType::Head),
+        );
+
+        self.character_skin.set_available_skins(
+            class_customizations(player_class, CustomizationType::Skin),
+            default_customization(player_class, CustomizationType::Skin),
+        );
+    }
+
+    pub fn view(&mut self) -> Container<Bl3Message> {
+        let head_skin = self.head_skin.view();
+        let character_skin = self.character_skin.view();
+        let echo_theme = self.echo_theme.view();
 
         Container::new(
             Column::new()
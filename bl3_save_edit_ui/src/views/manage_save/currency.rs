@@ -1,6 +1,8 @@
+use std::path::PathBuf;
+
 use iced::{button, text_input, Alignment, Button, Column, Container, Length, Row, Text};
 
-use crate::bl3_ui::{Bl3Message, InteractionMessage};
+use crate::bl3_ui::{Bl3Message, InteractionMessage, MessageResult};
 use crate::bl3_ui_style::Bl3UiStyle;
 use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
 use crate::views::manage_save::ManageSaveInteractionMessage;
@@ -16,6 +18,7 @@ pub struct CurrencyState {
     pub eridium_input_state: text_input::State,
     pub max_eridium_button_state: button::State,
     pub max_money_button_state: button::State,
+    pub export_csv_button_state: button::State,
 }
 
 #[derive(Debug, Clone)]
@@ -24,6 +27,8 @@ pub enum SaveCurrencyInteractionMessage {
     Eridium(i32),
     MaxMoneyPressed,
     MaxEridiumPressed,
+    ExportAsCsv,
+    ExportAsCsvCompleted(MessageResult<PathBuf>),
 }
 
 pub fn view(currency_state: &mut CurrencyState) -> Container<Bl3Message> {
@@ -127,7 +132,24 @@ pub fn view(currency_state: &mut CurrencyState) -> Container<Bl3Message> {
     .height(Length::Units(36))
     .style(Bl3UiStyle);
 
-    let all_contents = Column::new().push(money).push(eridium).spacing(20);
+    let export_csv = Button::new(
+        &mut currency_state.export_csv_button_state,
+        Text::new("Export as CSV")
+            .font(JETBRAINS_MONO_BOLD)
+            .size(17),
+    )
+    .on_press(InteractionMessage::ManageSaveInteraction(
+        ManageSaveInteractionMessage::Currency(SaveCurrencyInteractionMessage::ExportAsCsv),
+    ))
+    .padding(10)
+    .style(Bl3UiStyle)
+    .into_element();
+
+    let all_contents = Column::new()
+        .push(money)
+        .push(eridium)
+        .push(export_csv)
+        .spacing(20);
 
     Container::new(all_contents).padding(30)
 }
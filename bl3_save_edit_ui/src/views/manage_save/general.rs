@@ -1,13 +1,21 @@
+use std::path::PathBuf;
+
+use iced::alignment::{Horizontal, Vertical};
 use iced::{
-    button, pick_list, text_input, tooltip, Alignment, Button, Column, Container, Length, PickList,
-    Row, Text, TextInput, Tooltip,
+    button, pick_list, svg, text_input, tooltip, Alignment, Button, Checkbox, Color, Column,
+    Container, Length, PickList, Radio, Row, Svg, Text, TextInput, Tooltip,
 };
 
+use bl3_save_edit_core::bl3_save::playthrough::PlaythroughType;
+use bl3_save_edit_core::bl3_save::travel_station::{MapLocation, TravelStation};
+use bl3_save_edit_core::bl3_save::util::MAX_MAYHEM_LEVEL;
+use bl3_save_edit_core::file_helper::FileMetadata;
 use bl3_save_edit_core::parser::HeaderType;
 
-use crate::bl3_ui::{Bl3Message, InteractionMessage};
+use crate::bl3_ui::{Bl3Message, InteractionMessage, MessageResult};
 use crate::bl3_ui_style::{Bl3UiStyle, Bl3UiTooltipStyle};
 use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
+use crate::views::file_metadata;
 use crate::views::manage_save::ManageSaveInteractionMessage;
 use crate::views::InteractionExt;
 use crate::widgets::labelled_element::LabelledElement;
@@ -25,6 +33,34 @@ pub struct GeneralState {
     pub generate_guid_button_state: button::State,
     pub save_type_selector: pick_list::State<HeaderType>,
     pub save_type_selected: HeaderType,
+    pub generate_share_qr_button_state: button::State,
+    pub export_report_button_state: button::State,
+    pub take_snapshot_button_state: button::State,
+    pub reveal_file_button_state: button::State,
+    pub close_share_qr_button_state: button::State,
+    pub share_qr_svg: Option<String>,
+    pub mayhem_level_input: u32,
+    pub mayhem_level_input_state: text_input::State,
+    pub current_mayhem_level: u32,
+    pub travel_station_options: Vec<TravelStation>,
+    pub travel_station_states: Vec<TravelStationState>,
+    pub unlock_travel_station: bool,
+    pub map_selector: pick_list::State<MapLocation>,
+    pub playthrough_options: Vec<PlaythroughType>,
+    pub playthrough_selected: PlaythroughType,
+    pub current_playthrough: PlaythroughType,
+    pub reset_playthrough: Option<PlaythroughType>,
+    pub reset_playthrough_button_states: [button::State; 2],
+    pub legacy_badass_rank_input: String,
+    pub legacy_badass_rank_input_state: text_input::State,
+    pub file_metadata: FileMetadata,
+}
+
+#[derive(Debug, Default)]
+pub struct TravelStationState {
+    pub selector: pick_list::State<TravelStation>,
+    pub selected: Option<TravelStation>,
+    pub is_unlocked: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -33,9 +69,72 @@ pub enum SaveGeneralInteractionMessage {
     Slot(u32),
     GenerateGuidPressed,
     SaveTypeSelected(HeaderType),
+    GenerateShareQr,
+    CloseShareQr,
+    ExportCharacterReport,
+    ExportCharacterReportCompleted(MessageResult<PathBuf>),
+    TakeSnapshot,
+    TakeSnapshotCompleted(MessageResult<PathBuf>),
+    RevealFile,
+    RevealFileCompleted(MessageResult<()>),
+    MayhemLevel(u32),
+    TravelStationSelected(usize, TravelStation),
+    UnlockTravelStation(bool),
+    SetLastMap(String),
+    SetPlaythrough(PlaythroughType),
+    ResetPlaythrough(PlaythroughType),
+    LegacyBadassRank(String),
+}
+
+fn share_qr_modal<'a>(
+    share_qr_svg: &str,
+    close_share_qr_button_state: &'a mut button::State,
+) -> Container<'a, Bl3Message> {
+    let qr_code = Svg::new(svg::Handle::from_memory(share_qr_svg.as_bytes().to_vec()))
+        .width(Length::Units(300))
+        .height(Length::Units(300));
+
+    let contents = Column::new()
+        .push(
+            Text::new("Share Character")
+                .font(JETBRAINS_MONO_BOLD)
+                .size(20)
+                .color(Color::from_rgb8(242, 203, 5)),
+        )
+        .push(qr_code)
+        .push(
+            Text::new("Scan with your phone to record your character and equipped weapons")
+                .font(JETBRAINS_MONO)
+                .size(16)
+                .color(Color::from_rgb8(220, 220, 220)),
+        )
+        .push(
+            Button::new(
+                close_share_qr_button_state,
+                Text::new("Close").font(JETBRAINS_MONO_BOLD).size(17),
+            )
+            .on_press(InteractionMessage::ManageSaveInteraction(
+                ManageSaveInteractionMessage::General(SaveGeneralInteractionMessage::CloseShareQr),
+            ))
+            .padding(10)
+            .style(Bl3UiStyle)
+            .into_element(),
+        )
+        .spacing(20)
+        .align_items(Alignment::Center);
+
+    Container::new(Container::new(contents).padding(30).style(Bl3UiStyle))
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .align_x(Horizontal::Center)
+        .align_y(Vertical::Center)
 }
 
 pub fn view(general_state: &mut GeneralState) -> Container<Bl3Message> {
+    if let Some(share_qr_svg) = &general_state.share_qr_svg {
+        return share_qr_modal(share_qr_svg, &mut general_state.close_share_qr_button_state);
+    }
+
     let file = Container::new(
         Row::new()
             .push(
@@ -193,11 +292,468 @@ pub fn view(general_state: &mut GeneralState) -> Container<Bl3Message> {
     .height(Length::Units(36))
     .style(Bl3UiStyle);
 
+    let legacy_badass_rank = Container::new(
+        LabelledElement::create(
+            "Legacy BL2 Badass Rank (display only)",
+            Length::Units(370),
+            Tooltip::new(
+                TextInputLimited::new(
+                    &mut general_state.legacy_badass_rank_input_state,
+                    "None",
+                    &general_state.legacy_badass_rank_input,
+                    9,
+                    |s| {
+                        InteractionMessage::ManageSaveInteraction(
+                            ManageSaveInteractionMessage::General(
+                                SaveGeneralInteractionMessage::LegacyBadassRank(s),
+                            ),
+                        )
+                    },
+                )
+                .0
+                .font(JETBRAINS_MONO)
+                .padding(10)
+                .size(17)
+                .style(Bl3UiStyle)
+                .into_element(),
+                "Kept in the editor's notes for this save, it is never written to the save file",
+                tooltip::Position::Top,
+            )
+            .gap(10)
+            .padding(10)
+            .font(JETBRAINS_MONO)
+            .size(17)
+            .style(Bl3UiTooltipStyle),
+        )
+        .spacing(15)
+        .align_items(Alignment::Center),
+    )
+    .width(Length::Fill)
+    .height(Length::Units(36))
+    .style(Bl3UiStyle);
+
+    let playthrough_radios = general_state.playthrough_options.iter().fold(
+        Row::new().spacing(30).align_items(Alignment::Center),
+        |curr, playthrough| {
+            curr.push(
+                Radio::new(
+                    *playthrough,
+                    playthrough.to_string(),
+                    Some(general_state.playthrough_selected),
+                    |p| {
+                        InteractionMessage::ManageSaveInteraction(
+                            ManageSaveInteractionMessage::General(
+                                SaveGeneralInteractionMessage::SetPlaythrough(p),
+                            ),
+                        )
+                    },
+                )
+                .size(20)
+                .font(JETBRAINS_MONO)
+                .text_color(Color::from_rgb8(220, 220, 220))
+                .text_size(17)
+                .style(Bl3UiStyle),
+            )
+        },
+    );
+
+    let mut playthrough = Column::new()
+        .push(
+            Container::new(
+                LabelledElement::create("Playthrough", Length::Units(130), playthrough_radios)
+                    .spacing(15)
+                    .align_items(Alignment::Center),
+            )
+            .width(Length::Fill)
+            .height(Length::Units(36))
+            .style(Bl3UiStyle),
+        )
+        .spacing(15);
+
+    if general_state.playthrough_selected == PlaythroughType::TrueVaultHunterMode
+        && general_state.current_playthrough != PlaythroughType::TrueVaultHunterMode
+    {
+        playthrough = playthrough.push(
+            Text::new(
+                "Mission progress in True Vault Hunter Mode is separate from Normal mode, the character will continue from where they were in that playthrough.",
+            )
+            .font(JETBRAINS_MONO)
+            .size(16)
+            .color(Color::from_rgb8(242, 203, 5)),
+        );
+    }
+
+    let reset_playthrough = general_state.reset_playthrough;
+
+    let reset_playthrough_buttons = general_state
+        .playthrough_options
+        .iter()
+        .zip(general_state.reset_playthrough_button_states.iter_mut())
+        .fold(
+            Row::new().spacing(15).align_items(Alignment::Center),
+            |curr, (playthrough, button_state)| {
+                let text = if reset_playthrough == Some(*playthrough) {
+                    format!("Cancel {} Reset", playthrough)
+                } else {
+                    format!("Reset {}", playthrough)
+                };
+
+                curr.push(
+                    Button::new(
+                        button_state,
+                        Text::new(text)
+                            .font(JETBRAINS_MONO_BOLD)
+                            .size(17)
+                            .color(Color::from_rgb8(255, 92, 92)),
+                    )
+                    .on_press(InteractionMessage::ManageSaveInteraction(
+                        ManageSaveInteractionMessage::General(
+                            SaveGeneralInteractionMessage::ResetPlaythrough(*playthrough),
+                        ),
+                    ))
+                    .padding(10)
+                    .style(Bl3UiStyle)
+                    .into_element(),
+                )
+            },
+        );
+
+    playthrough = playthrough.push(reset_playthrough_buttons);
+
+    if let Some(reset_playthrough) = reset_playthrough {
+        playthrough = playthrough.push(
+            Text::new(format!(
+                "{} will be reset when you save. All of its mission progress and discovered fast travel stations will be lost, inventory and character stats are kept.",
+                reset_playthrough
+            ))
+            .font(JETBRAINS_MONO)
+            .size(16)
+            .color(Color::from_rgb8(255, 92, 92)),
+        );
+    }
+
+    let mayhem_level = Container::new(
+        Row::new()
+            .push(
+                LabelledElement::create(
+                    "Mayhem Level",
+                    Length::Units(130),
+                    Tooltip::new(
+                        NumberInput::new(
+                            &mut general_state.mayhem_level_input_state,
+                            general_state.mayhem_level_input,
+                            0,
+                            Some(MAX_MAYHEM_LEVEL),
+                            |v| {
+                                InteractionMessage::ManageSaveInteraction(
+                                    ManageSaveInteractionMessage::General(
+                                        SaveGeneralInteractionMessage::MayhemLevel(v),
+                                    ),
+                                )
+                            },
+                        )
+                        .0
+                        .font(JETBRAINS_MONO)
+                        .padding(10)
+                        .size(17)
+                        .style(Bl3UiStyle)
+                        .into_element(),
+                        format!(
+                            "Mayhem level for the current playthrough, must be between 0 and {}",
+                            MAX_MAYHEM_LEVEL
+                        ),
+                        tooltip::Position::Top,
+                    )
+                    .gap(10)
+                    .padding(10)
+                    .font(JETBRAINS_MONO)
+                    .size(17)
+                    .style(Bl3UiTooltipStyle),
+                )
+                .spacing(15)
+                .width(Length::FillPortion(9))
+                .align_items(Alignment::Center),
+            )
+            .push(
+                Text::new(format!("Current: {}", general_state.current_mayhem_level))
+                    .font(JETBRAINS_MONO)
+                    .size(17)
+                    .color(Color::from_rgb8(220, 220, 220))
+                    .width(Length::FillPortion(2)),
+            )
+            .spacing(15)
+            .align_items(Alignment::Center),
+    )
+    .width(Length::Fill)
+    .height(Length::Units(36))
+    .style(Bl3UiStyle);
+
+    // The map is picked through the selected playthrough's spawn station
+    let map_selected = general_state
+        .travel_station_states
+        .get(general_state.playthrough_selected.index())
+        .and_then(|s| s.selected.as_ref())
+        .and_then(MapLocation::from_travel_station);
+
+    let last_map = Container::new(
+        LabelledElement::create(
+            "Current Map",
+            Length::Units(130),
+            Tooltip::new(
+                PickList::new(
+                    &mut general_state.map_selector,
+                    MapLocation::all(),
+                    map_selected,
+                    |m| {
+                        InteractionMessage::ManageSaveInteraction(
+                            ManageSaveInteractionMessage::General(
+                                SaveGeneralInteractionMessage::SetLastMap(
+                                    m.travel_station.to_owned(),
+                                ),
+                            ),
+                        )
+                    },
+                )
+                .font(JETBRAINS_MONO)
+                .text_size(17)
+                .width(Length::Fill)
+                .padding(10)
+                .style(Bl3UiStyle)
+                .into_element(),
+                "The map the character will load into, this sets the selected playthrough's spawn station",
+                tooltip::Position::Top,
+            )
+            .gap(10)
+            .padding(10)
+            .font(JETBRAINS_MONO)
+            .size(17)
+            .style(Bl3UiTooltipStyle),
+        )
+        .spacing(15)
+        .align_items(Alignment::Center),
+    )
+    .width(Length::Fill)
+    .height(Length::Units(36))
+    .style(Bl3UiStyle);
+
+    let travel_station_options = &general_state.travel_station_options;
+
+    let travel_stations = general_state
+        .travel_station_states
+        .iter_mut()
+        .enumerate()
+        .fold(Column::new().spacing(15), |curr, (i, state)| {
+            let mut row = Row::new()
+                .push(
+                    LabelledElement::create(
+                        format!("Playthrough {} Spawn", i + 1),
+                        Length::Units(210),
+                        PickList::new(
+                            &mut state.selector,
+                            &travel_station_options[..],
+                            state.selected.clone(),
+                            move |s| {
+                                InteractionMessage::ManageSaveInteraction(
+                                    ManageSaveInteractionMessage::General(
+                                        SaveGeneralInteractionMessage::TravelStationSelected(i, s),
+                                    ),
+                                )
+                            },
+                        )
+                        .font(JETBRAINS_MONO)
+                        .text_size(17)
+                        .width(Length::Fill)
+                        .padding(10)
+                        .style(Bl3UiStyle)
+                        .into_element(),
+                    )
+                    .spacing(15)
+                    .width(Length::FillPortion(9))
+                    .align_items(Alignment::Center),
+                )
+                .spacing(15)
+                .align_items(Alignment::Center);
+
+            if !state.is_unlocked {
+                row = row.push(
+                    Text::new("Not Unlocked")
+                        .font(JETBRAINS_MONO)
+                        .size(17)
+                        .color(Color::from_rgb8(242, 203, 5))
+                        .width(Length::FillPortion(2)),
+                );
+            }
+
+            curr.push(
+                Container::new(row)
+                    .width(Length::Fill)
+                    .height(Length::Units(36))
+                    .style(Bl3UiStyle),
+            )
+        });
+
+    let unlock_travel_station = Tooltip::new(
+        Checkbox::new(
+            general_state.unlock_travel_station,
+            "Unlock the spawn station if it has not been discovered",
+            |c| {
+                InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::General(
+                    SaveGeneralInteractionMessage::UnlockTravelStation(c),
+                ))
+            },
+        )
+        .size(20)
+        .font(JETBRAINS_MONO)
+        .text_color(Color::from_rgb8(220, 220, 220))
+        .text_size(17)
+        .style(Bl3UiStyle),
+        "Saving will fail if a spawn station is chosen that has not been discovered, unless this is checked",
+        tooltip::Position::Top,
+    )
+    .gap(10)
+    .padding(10)
+    .font(JETBRAINS_MONO)
+    .size(17)
+    .style(Bl3UiTooltipStyle);
+
+    let gameplay = Column::new()
+        .push(
+            Container::new(
+                Text::new("Gameplay")
+                    .font(JETBRAINS_MONO_BOLD)
+                    .size(17)
+                    .color(Color::from_rgb8(242, 203, 5)),
+            )
+            .padding(10)
+            .align_x(Horizontal::Center)
+            .width(Length::Fill)
+            .style(Bl3UiStyle),
+        )
+        .push(
+            Container::new(
+                Column::new()
+                    .push(playthrough)
+                    .push(mayhem_level)
+                    .push(last_map)
+                    .push(travel_stations)
+                    .push(unlock_travel_station)
+                    .spacing(15),
+            )
+            .width(Length::Fill)
+            .padding(15)
+            .style(Bl3UiStyle),
+        );
+
+    let export_report = Tooltip::new(
+        Button::new(
+            &mut general_state.export_report_button_state,
+            Text::new("Export Report")
+                .font(JETBRAINS_MONO_BOLD)
+                .size(17),
+        )
+        .on_press(InteractionMessage::ManageSaveInteraction(
+            ManageSaveInteractionMessage::General(
+                SaveGeneralInteractionMessage::ExportCharacterReport,
+            ),
+        ))
+        .padding(10)
+        .style(Bl3UiStyle)
+        .into_element(),
+        "Save a Markdown report of your character's equipment, build and inventory",
+        tooltip::Position::Top,
+    )
+    .gap(10)
+    .padding(10)
+    .font(JETBRAINS_MONO)
+    .size(17)
+    .style(Bl3UiTooltipStyle);
+
+    let take_snapshot = Tooltip::new(
+        Button::new(
+            &mut general_state.take_snapshot_button_state,
+            Text::new("Take Snapshot")
+                .font(JETBRAINS_MONO_BOLD)
+                .size(17),
+        )
+        .on_press(InteractionMessage::ManageSaveInteraction(
+            ManageSaveInteractionMessage::General(SaveGeneralInteractionMessage::TakeSnapshot),
+        ))
+        .padding(10)
+        .style(Bl3UiStyle)
+        .into_element(),
+        "Write a read only copy of the character as it is now to the backups folder",
+        tooltip::Position::Top,
+    )
+    .gap(10)
+    .padding(10)
+    .font(JETBRAINS_MONO)
+    .size(17)
+    .style(Bl3UiTooltipStyle);
+
+    let reveal_file = Tooltip::new(
+        Button::new(
+            &mut general_state.reveal_file_button_state,
+            Text::new("Reveal in File Manager")
+                .font(JETBRAINS_MONO_BOLD)
+                .size(17),
+        )
+        .on_press(InteractionMessage::ManageSaveInteraction(
+            ManageSaveInteractionMessage::General(SaveGeneralInteractionMessage::RevealFile),
+        ))
+        .padding(10)
+        .style(Bl3UiStyle)
+        .into_element(),
+        "Open the saves folder with this file selected",
+        tooltip::Position::Top,
+    )
+    .gap(10)
+    .padding(10)
+    .font(JETBRAINS_MONO)
+    .size(17)
+    .style(Bl3UiTooltipStyle);
+
+    let share_qr = Tooltip::new(
+        Button::new(
+            &mut general_state.generate_share_qr_button_state,
+            Text::new("Share as QR Code")
+                .font(JETBRAINS_MONO_BOLD)
+                .size(17),
+        )
+        .on_press(InteractionMessage::ManageSaveInteraction(
+            ManageSaveInteractionMessage::General(SaveGeneralInteractionMessage::GenerateShareQr),
+        ))
+        .padding(10)
+        .style(Bl3UiStyle)
+        .into_element(),
+        "Generate a QR code containing your character name, class, level and equipped weapons",
+        tooltip::Position::Top,
+    )
+    .gap(10)
+    .padding(10)
+    .font(JETBRAINS_MONO)
+    .size(17)
+    .style(Bl3UiTooltipStyle);
+
+    let share_buttons = Container::new(
+        Row::new()
+            .push(reveal_file)
+            .push(take_snapshot)
+            .push(export_report)
+            .push(share_qr)
+            .spacing(15),
+    )
+    .width(Length::Fill)
+    .align_x(Horizontal::Right);
+
     let all_contents = Column::new()
         .push(file)
         .push(save_guid)
         .push(save_slot)
         .push(save_type)
+        .push(legacy_badass_rank)
+        .push(gameplay)
+        .push(share_buttons)
+        .push(file_metadata::view(&general_state.file_metadata))
         .spacing(20);
 
     Container::new(all_contents).padding(30)
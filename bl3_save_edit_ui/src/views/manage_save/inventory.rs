@@ -1,24 +1,419 @@
-use iced::Container;
+use iced::{
+    button, text_input, tooltip, Alignment, Button, Color, Column, Container, Length, Row, Text,
+    Tooltip,
+};
+
+use bl3_save_edit_core::bl3_item::Bl3Item;
+use bl3_save_edit_core::bl3_save::character_data::MAX_CHARACTER_LEVEL;
+use bl3_save_edit_core::bl3_save::inventory_slot::InventorySlot;
 
 use crate::bl3_ui::{Bl3Message, InteractionMessage};
+use crate::bl3_ui_style::{Bl3UiPositiveButtonStyle, Bl3UiStyle, Bl3UiTooltipStyle};
+use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
 use crate::views::item_editor;
 use crate::views::item_editor::{ItemEditorInteractionMessage, ItemEditorState};
-use crate::views::manage_save::ManageSaveInteractionMessage;
+use crate::views::manage_save::transfer::{TransferInteractionMessage, TransferState};
+use crate::views::manage_save::{transfer, ManageSaveInteractionMessage};
+use crate::views::InteractionExt;
+use crate::widgets::labelled_element::LabelledElement;
+use crate::widgets::number_input::NumberInput;
+use crate::widgets::text_input_limited::TextInputLimited;
 
 #[derive(Debug, Default)]
 pub struct InventoryState {
     pub item_editor_state: ItemEditorState,
+    pub equip_best_items_button_state: button::State,
+    // Slots Equip Best Items picked an item for, by inventory index. Equipped when saving
+    pub best_items: Vec<(InventorySlot, usize)>,
+    pub deduplicate_inventory_button_state: button::State,
+    pub remove_all_items_button_state: button::State,
+    // How many items Remove All took out since the file was last loaded or saved
+    pub removed_all_items: Option<usize>,
+    pub shift_code_import_state: ShiftCodeImportState,
+    pub transfer_items_button_state: button::State,
+    pub transfer_state: TransferState,
+    pub level_range_state: LevelRangeState,
+}
+
+#[derive(Debug)]
+pub struct LevelRangeState {
+    pub min_level_input: i32,
+    pub min_level_input_state: text_input::State,
+    pub max_level_input: i32,
+    pub max_level_input_state: text_input::State,
+    pub apply_button_state: button::State,
+}
+
+impl std::default::Default for LevelRangeState {
+    fn default() -> Self {
+        Self {
+            min_level_input: 1,
+            min_level_input_state: Default::default(),
+            max_level_input: MAX_CHARACTER_LEVEL as i32,
+            max_level_input_state: Default::default(),
+            apply_button_state: Default::default(),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct ShiftCodeImportState {
+    pub shift_code_input: String,
+    pub shift_code_input_state: text_input::State,
+    pub shift_code_preview: Vec<Bl3Item>,
+    pub preview_shift_code_button_state: button::State,
+    pub import_shift_code_button_state: button::State,
+    pub cancel_shift_code_button_state: button::State,
 }
 
 #[derive(Debug, Clone)]
 pub enum SaveInventoryInteractionMessage {
     Editor(ItemEditorInteractionMessage),
+    EquipBestItems,
+    DeduplicateInventory,
+    ClearInventory,
+    ClearInventoryConfirmed,
+    ShiftCodeInputChanged(String),
+    PreviewShiftCodePressed,
+    ImportShiftInventoryCode(String),
+    CancelShiftCodeImport,
+    Transfer(TransferInteractionMessage),
+    LevelRangeMinLevel(i32),
+    LevelRangeMaxLevel(i32),
+    NormalizeItemLevelRange { min: u32, max: u32 },
+}
+
+pub fn item_summary(item: &Bl3Item) -> String {
+    let balance_part = item.balance_part();
+
+    let name = balance_part.name.clone().unwrap_or_else(|| {
+        balance_part
+            .short_ident
+            .clone()
+            .unwrap_or_else(|| balance_part.ident.clone())
+    });
+
+    format!("{} - {} (Level {})", name, item.item_type, item.level())
+}
+
+fn inventory_message(message: SaveInventoryInteractionMessage) -> InteractionMessage {
+    InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::Inventory(message))
+}
+
+fn shift_code_importer(
+    shift_code_import_state: &mut ShiftCodeImportState,
+) -> Container<Bl3Message> {
+    let shift_code_input = Row::new()
+        .push(
+            LabelledElement::create(
+                "SHiFT Code",
+                Length::Units(120),
+                Tooltip::new(
+                    TextInputLimited::new(
+                        &mut shift_code_import_state.shift_code_input_state,
+                        "BL3(...),BL3(...)",
+                        &shift_code_import_state.shift_code_input,
+                        50000,
+                        |s| {
+                            inventory_message(
+                                SaveInventoryInteractionMessage::ShiftCodeInputChanged(s),
+                            )
+                        },
+                    )
+                    .0
+                    .font(JETBRAINS_MONO)
+                    .padding(10)
+                    .size(17)
+                    .style(Bl3UiStyle)
+                    .into_element(),
+                    "Paste a community inventory code, item serials separated by commas",
+                    tooltip::Position::Top,
+                )
+                .gap(10)
+                .padding(10)
+                .font(JETBRAINS_MONO)
+                .size(17)
+                .style(Bl3UiTooltipStyle),
+            )
+            .spacing(15)
+            .width(Length::FillPortion(9))
+            .align_items(Alignment::Center),
+        )
+        .push(
+            Button::new(
+                &mut shift_code_import_state.preview_shift_code_button_state,
+                Text::new("Preview Import")
+                    .font(JETBRAINS_MONO_BOLD)
+                    .size(17),
+            )
+            .on_press(inventory_message(
+                SaveInventoryInteractionMessage::PreviewShiftCodePressed,
+            ))
+            .padding(10)
+            .style(Bl3UiStyle)
+            .into_element(),
+        )
+        .align_items(Alignment::Center);
+
+    let mut contents = Column::new().push(
+        Container::new(shift_code_input)
+            .width(Length::Fill)
+            .height(Length::Units(36))
+            .style(Bl3UiStyle),
+    );
+
+    if !shift_code_import_state.shift_code_preview.is_empty() {
+        let preview = shift_code_import_state.shift_code_preview.iter().fold(
+            Column::new().spacing(5),
+            |curr, item| {
+                curr.push(
+                    Text::new(item_summary(item))
+                        .font(JETBRAINS_MONO)
+                        .size(16)
+                        .color(Color::from_rgb8(220, 220, 220)),
+                )
+            },
+        );
+
+        let confirm_row = Row::new()
+            .push(
+                Text::new(format!(
+                    "{} items will be added to your inventory:",
+                    shift_code_import_state.shift_code_preview.len()
+                ))
+                .font(JETBRAINS_MONO_BOLD)
+                .size(17)
+                .color(Color::from_rgb8(242, 203, 5))
+                .width(Length::Fill),
+            )
+            .push(
+                Button::new(
+                    &mut shift_code_import_state.cancel_shift_code_button_state,
+                    Text::new("Cancel").font(JETBRAINS_MONO_BOLD).size(17),
+                )
+                .on_press(inventory_message(
+                    SaveInventoryInteractionMessage::CancelShiftCodeImport,
+                ))
+                .padding(10)
+                .style(Bl3UiStyle)
+                .into_element(),
+            )
+            .push(
+                Button::new(
+                    &mut shift_code_import_state.import_shift_code_button_state,
+                    Text::new("Import").font(JETBRAINS_MONO_BOLD).size(17),
+                )
+                .on_press(inventory_message(
+                    SaveInventoryInteractionMessage::ImportShiftInventoryCode(
+                        shift_code_import_state.shift_code_input.clone(),
+                    ),
+                ))
+                .padding(10)
+                .style(Bl3UiPositiveButtonStyle)
+                .into_element(),
+            )
+            .spacing(15)
+            .align_items(Alignment::Center);
+
+        contents = contents.push(
+            Container::new(Column::new().push(confirm_row).push(preview).spacing(10))
+                .padding(15)
+                .width(Length::Fill)
+                .style(Bl3UiStyle),
+        );
+    }
+
+    Container::new(contents.spacing(10)).width(Length::Fill)
+}
+
+fn level_range_input<'a>(
+    label: &str,
+    input_state: &'a mut text_input::State,
+    value: i32,
+    on_change: fn(i32) -> SaveInventoryInteractionMessage,
+) -> Container<'a, Bl3Message> {
+    Container::new(
+        LabelledElement::create(
+            label,
+            Length::Units(95),
+            NumberInput::new(
+                input_state,
+                value,
+                1,
+                Some(MAX_CHARACTER_LEVEL as i32),
+                move |v| inventory_message(on_change(v)),
+            )
+            .0
+            .font(JETBRAINS_MONO)
+            .padding(10)
+            .size(17)
+            .style(Bl3UiStyle)
+            .into_element(),
+        )
+        .spacing(15)
+        .width(Length::Units(165))
+        .align_items(Alignment::Center),
+    )
+    .height(Length::Units(36))
+    .style(Bl3UiStyle)
+}
+
+fn level_range_normalizer(level_range_state: &mut LevelRangeState) -> Row<Bl3Message> {
+    let min_level_input = level_range_input(
+        "Min Level",
+        &mut level_range_state.min_level_input_state,
+        level_range_state.min_level_input,
+        SaveInventoryInteractionMessage::LevelRangeMinLevel,
+    );
+
+    let max_level_input = level_range_input(
+        "Max Level",
+        &mut level_range_state.max_level_input_state,
+        level_range_state.max_level_input,
+        SaveInventoryInteractionMessage::LevelRangeMaxLevel,
+    );
+
+    let apply_button = Tooltip::new(
+        Button::new(
+            &mut level_range_state.apply_button_state,
+            Text::new("Apply Range").font(JETBRAINS_MONO_BOLD).size(17),
+        )
+        .on_press(inventory_message(
+            SaveInventoryInteractionMessage::NormalizeItemLevelRange {
+                min: level_range_state.min_level_input as u32,
+                max: level_range_state.max_level_input as u32,
+            },
+        ))
+        .padding(10)
+        .style(Bl3UiStyle)
+        .into_element(),
+        "Clamp the level of every item to between the minimum and maximum level",
+        tooltip::Position::Bottom,
+    )
+    .gap(10)
+    .padding(10)
+    .font(JETBRAINS_MONO)
+    .size(17)
+    .style(Bl3UiTooltipStyle);
+
+    Row::new()
+        .push(min_level_input)
+        .push(max_level_input)
+        .push(apply_button)
+        .spacing(15)
+        .align_items(Alignment::Center)
 }
 
 pub fn view(inventory_state: &mut InventoryState) -> Container<Bl3Message> {
-    item_editor::view(&mut inventory_state.item_editor_state, |i| {
-        InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::Inventory(
-            SaveInventoryInteractionMessage::Editor(i),
+    if inventory_state.transfer_state.is_open() {
+        return transfer::view(&mut inventory_state.transfer_state);
+    }
+
+    let equip_best_items_button = Tooltip::new(
+        Button::new(
+            &mut inventory_state.equip_best_items_button_state,
+            Text::new("Equip Best").font(JETBRAINS_MONO_BOLD).size(17),
+        )
+        .on_press(InteractionMessage::ManageSaveInteraction(
+            ManageSaveInteractionMessage::Inventory(
+                SaveInventoryInteractionMessage::EquipBestItems,
+            ),
         ))
-    })
+        .padding(10)
+        .style(Bl3UiStyle)
+        .into_element(),
+        "Equip the highest level item for each unlocked gear slot",
+        tooltip::Position::Bottom,
+    )
+    .gap(10)
+    .padding(10)
+    .font(JETBRAINS_MONO)
+    .size(17)
+    .style(Bl3UiTooltipStyle);
+
+    let deduplicate_inventory_button = Tooltip::new(
+        Button::new(
+            &mut inventory_state.deduplicate_inventory_button_state,
+            Text::new("Remove Duplicates")
+                .font(JETBRAINS_MONO_BOLD)
+                .size(17),
+        )
+        .on_press(inventory_message(
+            SaveInventoryInteractionMessage::DeduplicateInventory,
+        ))
+        .padding(10)
+        .style(Bl3UiStyle)
+        .into_element(),
+        "Remove items that have the same serial as another item, equipped items are kept",
+        tooltip::Position::Bottom,
+    )
+    .gap(10)
+    .padding(10)
+    .font(JETBRAINS_MONO)
+    .size(17)
+    .style(Bl3UiTooltipStyle);
+
+    let remove_all_items_button = Tooltip::new(
+        Button::new(
+            &mut inventory_state.remove_all_items_button_state,
+            Text::new("Remove All").font(JETBRAINS_MONO_BOLD).size(17),
+        )
+        .on_press(inventory_message(
+            SaveInventoryInteractionMessage::ClearInventory,
+        ))
+        .padding(10)
+        .style(Bl3UiStyle)
+        .into_element(),
+        "Remove every item from the inventory, including equipped items",
+        tooltip::Position::Bottom,
+    )
+    .gap(10)
+    .padding(10)
+    .font(JETBRAINS_MONO)
+    .size(17)
+    .style(Bl3UiTooltipStyle);
+
+    let transfer_items_button = Tooltip::new(
+        Button::new(
+            &mut inventory_state.transfer_items_button_state,
+            Text::new("Transfer").font(JETBRAINS_MONO_BOLD).size(17),
+        )
+        .on_press(inventory_message(
+            SaveInventoryInteractionMessage::Transfer(TransferInteractionMessage::Open),
+        ))
+        .padding(10)
+        .style(Bl3UiStyle)
+        .into_element(),
+        "Move items between this character and another save",
+        tooltip::Position::Bottom,
+    )
+    .gap(10)
+    .padding(10)
+    .font(JETBRAINS_MONO)
+    .size(17)
+    .style(Bl3UiTooltipStyle);
+
+    let extra_options = Row::new()
+        .push(equip_best_items_button)
+        .push(deduplicate_inventory_button)
+        .push(remove_all_items_button)
+        .push(transfer_items_button)
+        .push(level_range_normalizer(
+            &mut inventory_state.level_range_state,
+        ))
+        .spacing(15)
+        .align_items(Alignment::Center);
+
+    let shift_code_importer = shift_code_importer(&mut inventory_state.shift_code_import_state);
+
+    item_editor::view(
+        &mut inventory_state.item_editor_state,
+        Some(extra_options.into()),
+        Some(shift_code_importer.into()),
+        |i| {
+            InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::Inventory(
+                SaveInventoryInteractionMessage::Editor(i),
+            ))
+        },
+    )
 }
@@ -2,18 +2,24 @@ use iced::{button, svg, Column, Container, Length, Row};
 use strum::Display;
 
 use crate::bl3_ui::{Bl3Message, InteractionMessage};
-use crate::resources::svgs::{CHARACTER, CURRENCY, GENERAL, INVENTORY, SETTINGS, VEHICLE};
+use crate::resources::svgs::{
+    CHALLENGES, CHARACTER, CURRENCY, GENERAL, INVENTORY, MISSIONS, SETTINGS, VEHICLE,
+};
 use crate::views;
+use crate::views::manage_save::challenges::ChallengesState;
 use crate::views::manage_save::character::CharacterState;
 use crate::views::manage_save::currency::CurrencyState;
 use crate::views::manage_save::general::GeneralState;
 use crate::views::manage_save::inventory::InventoryState;
+use crate::views::manage_save::missions::MissionsState;
 use crate::views::manage_save::vehicle::VehicleState;
 use crate::views::manage_save::{
-    character, currency, general, inventory, vehicle, ManageSaveInteractionMessage, ManageSaveState,
+    challenges, character, currency, general, inventory, missions, vehicle,
+    ManageSaveInteractionMessage, ManageSaveState,
 };
 use crate::views::settings::SettingsState;
-use crate::views::{tab_bar_button, ManageTabBarStyle};
+use crate::views::validation_report;
+use crate::views::{reset_tab_button, tab_bar_button, ManageTabBarStyle};
 
 #[derive(Debug, Default)]
 pub struct SaveViewState {
@@ -23,6 +29,8 @@ pub struct SaveViewState {
     pub inventory_state: InventoryState,
     pub currency_state: CurrencyState,
     pub vehicle_state: VehicleState,
+    pub missions_state: MissionsState,
+    pub challenges_state: ChallengesState,
 }
 
 #[derive(Debug, Default)]
@@ -32,7 +40,10 @@ pub struct SaveTabBarState {
     inventory_button_state: button::State,
     currency_button_state: button::State,
     vehicle_button_state: button::State,
+    missions_button_state: button::State,
+    challenges_button_state: button::State,
     settings_button_state: button::State,
+    reset_tab_button_state: button::State,
 }
 
 #[derive(Debug, Clone)]
@@ -42,10 +53,13 @@ pub enum SaveTabBarInteractionMessage {
     Inventory,
     Currency,
     Vehicle,
+    Missions,
+    Challenges,
     Settings,
+    ResetTab,
 }
 
-#[derive(Debug, Display, PartialEq)]
+#[derive(Debug, Display, Clone, PartialEq)]
 #[strum(serialize_all = "title_case")]
 pub enum SaveTabBarView {
     General,
@@ -53,6 +67,8 @@ pub enum SaveTabBarView {
     Inventory,
     Currency,
     Vehicle,
+    Missions,
+    Challenges,
     Settings,
 }
 
@@ -73,6 +89,7 @@ pub fn view<'a>(
         )),
         svg::Handle::from_memory(GENERAL),
         100,
+        manage_save_state.tab_badges.badge(&SaveTabBarView::General),
     );
 
     let character_button = tab_bar_button(
@@ -87,6 +104,9 @@ pub fn view<'a>(
         )),
         svg::Handle::from_memory(CHARACTER),
         115,
+        manage_save_state
+            .tab_badges
+            .badge(&SaveTabBarView::Character),
     );
 
     let inventory_button = tab_bar_button(
@@ -101,6 +121,9 @@ pub fn view<'a>(
         )),
         svg::Handle::from_memory(INVENTORY),
         115,
+        manage_save_state
+            .tab_badges
+            .badge(&SaveTabBarView::Inventory),
     );
 
     let currency_button = tab_bar_button(
@@ -115,6 +138,9 @@ pub fn view<'a>(
         )),
         svg::Handle::from_memory(CURRENCY),
         105,
+        manage_save_state
+            .tab_badges
+            .badge(&SaveTabBarView::Currency),
     );
 
     let vehicle_button = tab_bar_button(
@@ -129,6 +155,41 @@ pub fn view<'a>(
         )),
         svg::Handle::from_memory(VEHICLE),
         100,
+        manage_save_state.tab_badges.badge(&SaveTabBarView::Vehicle),
+    );
+
+    let missions_button = tab_bar_button(
+        &mut manage_save_state
+            .save_view_state
+            .tab_bar_state
+            .missions_button_state,
+        SaveTabBarView::Missions,
+        tab_bar_view,
+        InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::TabBar(
+            SaveTabBarInteractionMessage::Missions,
+        )),
+        svg::Handle::from_memory(MISSIONS),
+        110,
+        manage_save_state
+            .tab_badges
+            .badge(&SaveTabBarView::Missions),
+    );
+
+    let challenges_button = tab_bar_button(
+        &mut manage_save_state
+            .save_view_state
+            .tab_bar_state
+            .challenges_button_state,
+        SaveTabBarView::Challenges,
+        tab_bar_view,
+        InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::TabBar(
+            SaveTabBarInteractionMessage::Challenges,
+        )),
+        svg::Handle::from_memory(CHALLENGES),
+        125,
+        manage_save_state
+            .tab_badges
+            .badge(&SaveTabBarView::Challenges),
     );
 
     let settings_button = tab_bar_button(
@@ -143,19 +204,34 @@ pub fn view<'a>(
         )),
         svg::Handle::from_memory(SETTINGS),
         105,
+        None,
     );
 
-    let tab_bar = Container::new(
-        Row::new()
-            .push(general_button)
-            .push(character_button)
-            .push(inventory_button)
-            .push(currency_button)
-            .push(vehicle_button)
-            .push(settings_button),
-    )
-    .width(Length::Fill)
-    .style(ManageTabBarStyle);
+    let mut tab_bar_buttons = Row::new()
+        .push(general_button)
+        .push(character_button)
+        .push(inventory_button)
+        .push(currency_button)
+        .push(vehicle_button)
+        .push(missions_button)
+        .push(challenges_button)
+        .push(settings_button);
+
+    if *tab_bar_view != SaveTabBarView::Settings {
+        tab_bar_buttons = tab_bar_buttons.push(reset_tab_button(
+            &mut manage_save_state
+                .save_view_state
+                .tab_bar_state
+                .reset_tab_button_state,
+            InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::TabBar(
+                SaveTabBarInteractionMessage::ResetTab,
+            )),
+        ));
+    }
+
+    let tab_bar = Container::new(tab_bar_buttons)
+        .width(Length::Fill)
+        .style(ManageTabBarStyle);
 
     let tab_content = match tab_bar_view {
         SaveTabBarView::General => {
@@ -173,10 +249,28 @@ pub fn view<'a>(
         SaveTabBarView::Vehicle => {
             vehicle::view(&mut manage_save_state.save_view_state.vehicle_state)
         }
+        SaveTabBarView::Missions => {
+            missions::view(&mut manage_save_state.save_view_state.missions_state)
+        }
+        SaveTabBarView::Challenges => {
+            challenges::view(&mut manage_save_state.save_view_state.challenges_state)
+        }
         SaveTabBarView::Settings => views::settings::view(settings_state),
     };
 
-    let all_contents = Column::new().push(tab_bar).push(tab_content);
+    let mut all_contents = Column::new();
+
+    if let Some(validation_report) =
+        validation_report::view(&mut manage_save_state.validation_report_state, |m| {
+            InteractionMessage::ManageSaveInteraction(
+                ManageSaveInteractionMessage::ValidationReport(m),
+            )
+        })
+    {
+        all_contents = all_contents.push(validation_report);
+    }
+
+    let all_contents = all_contents.push(tab_bar).push(tab_content);
 
     Container::new(all_contents)
         .width(Length::Fill)
@@ -0,0 +1,276 @@
+use iced::alignment::Horizontal;
+use iced::{
+    button, scrollable, text_input, Alignment, Button, Color, Column, Container, Length, Radio,
+    Row, Scrollable, Text,
+};
+
+use bl3_save_edit_core::bl3_save::mission::ActiveMission;
+use bl3_save_edit_core::bl3_save::playthrough::PlaythroughType;
+
+use crate::bl3_ui::{Bl3Message, InteractionMessage};
+use crate::bl3_ui_style::Bl3UiStyle;
+use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
+use crate::views::manage_save::ManageSaveInteractionMessage;
+use crate::views::InteractionExt;
+use crate::widgets::labelled_element::LabelledElement;
+use crate::widgets::number_input::NumberInput;
+
+#[derive(Debug, Default)]
+pub struct MissionsState {
+    pub playthrough_options: Vec<PlaythroughType>,
+    pub playthrough_selected: PlaythroughType,
+    pub playthroughs: Vec<PlaythroughMissions>,
+    scrollable_state: scrollable::State,
+}
+
+#[derive(Debug, Default)]
+pub struct PlaythroughMissions {
+    pub tracked_mission: Option<String>,
+    pub missions: Vec<MissionEditor>,
+}
+
+#[derive(Debug)]
+pub struct MissionEditor {
+    pub mission: ActiveMission,
+    objective_input_states: Vec<text_input::State>,
+    track_button_state: button::State,
+}
+
+#[derive(Debug, Clone)]
+pub enum SaveMissionsInteractionMessage {
+    PlaythroughSelected(PlaythroughType),
+    TrackMission(usize),
+    ObjectiveProgress(usize, usize, i32),
+}
+
+impl MissionEditor {
+    pub fn new(mission: ActiveMission) -> Self {
+        let objective_input_states = mission
+            .objectives
+            .iter()
+            .map(|_| text_input::State::default())
+            .collect();
+
+        Self {
+            mission,
+            objective_input_states,
+            track_button_state: button::State::default(),
+        }
+    }
+}
+
+impl MissionsState {
+    fn selected_playthrough_mut(&mut self) -> Option<&mut PlaythroughMissions> {
+        let index = self
+            .playthrough_options
+            .iter()
+            .position(|p| *p == self.playthrough_selected)?;
+
+        self.playthroughs.get_mut(index)
+    }
+
+    pub fn track_mission(&mut self, mission_index: usize) {
+        if let Some(playthrough) = self.selected_playthrough_mut() {
+            if let Some(mission) = playthrough.missions.get(mission_index) {
+                playthrough.tracked_mission = Some(mission.mission.path.clone());
+            }
+        }
+    }
+
+    pub fn set_objective_progress(
+        &mut self,
+        mission_index: usize,
+        objective_index: usize,
+        progress: i32,
+    ) {
+        if let Some(objective) = self
+            .selected_playthrough_mut()
+            .and_then(|p| p.missions.get_mut(mission_index))
+            .and_then(|m| m.mission.objectives.get_mut(objective_index))
+        {
+            objective.progress = progress;
+        }
+    }
+}
+
+fn missions_message(message: SaveMissionsInteractionMessage) -> InteractionMessage {
+    InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::Missions(message))
+}
+
+fn mission_view(
+    mission_index: usize,
+    mission_editor: &mut MissionEditor,
+    is_tracked: bool,
+) -> Container<Bl3Message> {
+    let name_color = if is_tracked {
+        Color::from_rgb8(242, 203, 5)
+    } else {
+        Color::from_rgb8(220, 220, 220)
+    };
+
+    let mut track_button = Button::new(
+        &mut mission_editor.track_button_state,
+        Text::new(if is_tracked { "Tracked" } else { "Track" })
+            .font(JETBRAINS_MONO_BOLD)
+            .size(17),
+    )
+    .padding(10)
+    .style(Bl3UiStyle);
+
+    if !is_tracked {
+        track_button = track_button.on_press(missions_message(
+            SaveMissionsInteractionMessage::TrackMission(mission_index),
+        ));
+    }
+
+    let header = Row::new()
+        .push(
+            Text::new(&mission_editor.mission.name)
+                .font(JETBRAINS_MONO_BOLD)
+                .size(17)
+                .color(name_color)
+                .width(Length::Fill),
+        )
+        .push(track_button.into_element())
+        .spacing(15)
+        .align_items(Alignment::Center);
+
+    let objectives = mission_editor
+        .mission
+        .objectives
+        .iter()
+        .zip(mission_editor.objective_input_states.iter_mut())
+        .enumerate()
+        .fold(
+            Column::new().spacing(10),
+            |curr, (objective_index, (objective, input_state))| {
+                let label = match objective.target {
+                    Some(target) => format!("Objective {} (of {})", objective_index + 1, target),
+                    None => format!("Objective {}", objective_index + 1),
+                };
+
+                curr.push(
+                    LabelledElement::create(
+                        label,
+                        Length::Units(220),
+                        NumberInput::new(
+                            input_state,
+                            objective.progress,
+                            0,
+                            objective.target,
+                            move |v| {
+                                missions_message(SaveMissionsInteractionMessage::ObjectiveProgress(
+                                    mission_index,
+                                    objective_index,
+                                    v,
+                                ))
+                            },
+                        )
+                        .0
+                        .font(JETBRAINS_MONO)
+                        .padding(10)
+                        .size(17)
+                        .style(Bl3UiStyle)
+                        .into_element(),
+                    )
+                    .spacing(15)
+                    .align_items(Alignment::Center),
+                )
+            },
+        );
+
+    Container::new(Column::new().push(header).push(objectives).spacing(15))
+        .width(Length::Fill)
+        .padding(15)
+        .style(Bl3UiStyle)
+}
+
+pub fn view(missions_state: &mut MissionsState) -> Container<Bl3Message> {
+    let playthrough_radios = missions_state.playthrough_options.iter().fold(
+        Row::new().spacing(30).align_items(Alignment::Center),
+        |curr, playthrough| {
+            curr.push(
+                Radio::new(
+                    *playthrough,
+                    playthrough.to_string(),
+                    Some(missions_state.playthrough_selected),
+                    |p| missions_message(SaveMissionsInteractionMessage::PlaythroughSelected(p)),
+                )
+                .size(20)
+                .font(JETBRAINS_MONO)
+                .text_color(Color::from_rgb8(220, 220, 220))
+                .text_size(17)
+                .style(Bl3UiStyle),
+            )
+        },
+    );
+
+    let playthrough = Container::new(
+        LabelledElement::create("Playthrough", Length::Units(130), playthrough_radios)
+            .spacing(15)
+            .align_items(Alignment::Center),
+    )
+    .width(Length::Fill)
+    .height(Length::Units(36))
+    .style(Bl3UiStyle);
+
+    let playthrough_index = missions_state
+        .playthrough_options
+        .iter()
+        .position(|p| *p == missions_state.playthrough_selected);
+
+    let missions = match playthrough_index.and_then(|i| missions_state.playthroughs.get_mut(i)) {
+        Some(playthrough) if !playthrough.missions.is_empty() => {
+            let tracked_mission = playthrough.tracked_mission.clone();
+
+            playthrough.missions.iter_mut().enumerate().fold(
+                Column::new().spacing(15),
+                |curr, (mission_index, mission_editor)| {
+                    let is_tracked = tracked_mission
+                        .as_deref()
+                        .is_some_and(|t| t.eq_ignore_ascii_case(&mission_editor.mission.path));
+
+                    curr.push(mission_view(mission_index, mission_editor, is_tracked))
+                },
+            )
+        }
+        _ => Column::new().push(
+            Container::new(
+                Text::new("There are no active missions in this playthrough.")
+                    .font(JETBRAINS_MONO)
+                    .size(17)
+                    .color(Color::from_rgb8(220, 220, 220)),
+            )
+            .width(Length::Fill)
+            .padding(15)
+            .style(Bl3UiStyle),
+        ),
+    };
+
+    let active_missions = Column::new()
+        .push(
+            Container::new(
+                Text::new("Active Missions")
+                    .font(JETBRAINS_MONO_BOLD)
+                    .size(17)
+                    .color(Color::from_rgb8(242, 203, 5)),
+            )
+            .padding(10)
+            .align_x(Horizontal::Center)
+            .width(Length::Fill)
+            .style(Bl3UiStyle),
+        )
+        .push(
+            Scrollable::new(&mut missions_state.scrollable_state)
+                .push(missions)
+                .height(Length::Fill),
+        )
+        .spacing(15);
+
+    let all_contents = Column::new()
+        .push(playthrough)
+        .push(active_missions)
+        .spacing(20);
+
+    Container::new(all_contents).padding(30)
+}
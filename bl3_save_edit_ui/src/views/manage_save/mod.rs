@@ -1,5 +1,7 @@
 use bl3_save_edit_core::bl3_save::Bl3Save;
+use bl3_save_edit_core::validation::Adjustment;
 
+use crate::views::manage_save::challenges::SaveChallengesInteractionMessage;
 use crate::views::manage_save::character::SaveCharacterInteractionMessage;
 use crate::views::manage_save::currency::SaveCurrencyInteractionMessage;
 use crate::views::manage_save::general::SaveGeneralInteractionMessage;
@@ -7,19 +9,33 @@ use crate::views::manage_save::inventory::SaveInventoryInteractionMessage;
 use crate::views::manage_save::main::{
     SaveTabBarInteractionMessage, SaveTabBarView, SaveViewState,
 };
+use crate::views::manage_save::missions::SaveMissionsInteractionMessage;
 use crate::views::manage_save::vehicle::SaveVehicleInteractionMessage;
+use crate::views::validation_report::{ValidationReportMessage, ValidationReportState};
+use crate::views::TabBadges;
 
+pub mod challenges;
 pub mod character;
 pub mod currency;
 pub mod general;
 pub mod inventory;
 pub mod main;
+pub mod missions;
+pub mod transfer;
 pub mod vehicle;
 
 #[derive(Debug, Default)]
 pub struct ManageSaveState {
     pub save_view_state: SaveViewState,
     pub current_file: Bl3Save,
+    pub unsupported_version_save_pending: bool,
+    pub reset_playthrough_save_pending: bool,
+    pub unsupported_name_save_pending: bool,
+    pub validation_report_state: ValidationReportState,
+    pub changes_summary: Option<String>,
+    pub tab_badges: TabBadges<SaveTabBarView>,
+    // Negative values that were set to 0 when the file was loaded
+    pub sanitized_on_load: Vec<Adjustment>,
 }
 
 #[derive(Debug, Clone)]
@@ -30,7 +46,12 @@ pub enum ManageSaveInteractionMessage {
     Inventory(SaveInventoryInteractionMessage),
     Currency(SaveCurrencyInteractionMessage),
     Vehicle(SaveVehicleInteractionMessage),
+    Missions(SaveMissionsInteractionMessage),
+    Challenges(SaveChallengesInteractionMessage),
     SaveFilePressed,
+    SaveCancelled,
+    PreviewChangesPressed,
+    ValidationReport(ValidationReportMessage),
 }
 
 #[derive(Debug, PartialEq)]
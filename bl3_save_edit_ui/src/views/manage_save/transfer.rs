@@ -0,0 +1,240 @@
+use std::collections::BTreeSet;
+
+use iced::{
+    button, pick_list, scrollable, Alignment, Button, Checkbox, Color, Column, Container, Length,
+    PickList, Row, Scrollable, Text,
+};
+
+use bl3_save_edit_core::bl3_save::Bl3Save;
+use bl3_save_edit_core::file_helper::Bl3FileType;
+
+use crate::bl3_ui::{Bl3Message, InteractionMessage};
+use crate::bl3_ui_style::{Bl3UiPositiveButtonStyle, Bl3UiStyle};
+use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
+use crate::views::manage_save::inventory::{item_summary, SaveInventoryInteractionMessage};
+use crate::views::manage_save::ManageSaveInteractionMessage;
+use crate::views::InteractionExt;
+use crate::widgets::labelled_element::LabelledElement;
+
+#[derive(Debug, Default)]
+pub struct TransferState {
+    pub left: Option<Bl3Save>,
+    pub right: Option<Bl3Save>,
+    pub targets: Vec<Bl3FileType>,
+    pub target_selector: pick_list::State<Bl3FileType>,
+    pub target_selected: Option<Bl3FileType>,
+    pub left_selected: BTreeSet<usize>,
+    pub right_selected: BTreeSet<usize>,
+    pub has_moved_items: bool,
+    pub left_scrollable_state: scrollable::State,
+    pub right_scrollable_state: scrollable::State,
+    pub move_right_button_state: button::State,
+    pub move_left_button_state: button::State,
+    pub confirm_button_state: button::State,
+    pub cancel_button_state: button::State,
+}
+
+impl TransferState {
+    pub fn is_open(&self) -> bool {
+        self.left.is_some()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum TransferInteractionMessage {
+    Open,
+    TargetSelected(Bl3FileType),
+    LeftItemToggled(usize, bool),
+    RightItemToggled(usize, bool),
+    MoveRight,
+    MoveLeft,
+    Confirm,
+    Cancel,
+}
+
+fn transfer_message(message: TransferInteractionMessage) -> InteractionMessage {
+    InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::Inventory(
+        SaveInventoryInteractionMessage::Transfer(message),
+    ))
+}
+
+fn inventory_list<'a>(
+    save: Option<&Bl3Save>,
+    selected: &BTreeSet<usize>,
+    scrollable_state: &'a mut scrollable::State,
+    on_toggled: fn(usize, bool) -> TransferInteractionMessage,
+) -> Container<'a, Bl3Message> {
+    let (title, items) = match save {
+        Some(save) => (
+            format!(
+                "{} ({}) - {}",
+                save.character_data.character.preferred_character_name,
+                save.character_data.player_class(),
+                save.file_name
+            ),
+            save.character_data.inventory_items().as_slice(),
+        ),
+        None => ("Select a save to transfer with".to_owned(), &[][..]),
+    };
+
+    let items = items
+        .iter()
+        .enumerate()
+        .fold(Column::new().spacing(10), |curr, (i, item)| {
+            curr.push(
+                Checkbox::new(selected.contains(&i), item_summary(item), move |c| {
+                    transfer_message(on_toggled(i, c))
+                })
+                .size(20)
+                .font(JETBRAINS_MONO)
+                .text_color(Color::from_rgb8(220, 220, 220))
+                .text_size(16)
+                .style(Bl3UiStyle),
+            )
+        });
+
+    let contents = Column::new()
+        .push(
+            Text::new(title)
+                .font(JETBRAINS_MONO_BOLD)
+                .size(17)
+                .color(Color::from_rgb8(242, 203, 5)),
+        )
+        .push(
+            Scrollable::new(scrollable_state)
+                .push(items)
+                .height(Length::Fill),
+        )
+        .spacing(15);
+
+    Container::new(contents)
+        .padding(15)
+        .width(Length::FillPortion(5))
+        .height(Length::Fill)
+        .style(Bl3UiStyle)
+}
+
+pub fn view(transfer_state: &mut TransferState) -> Container<Bl3Message> {
+    let target_selector = Container::new(
+        LabelledElement::create(
+            "Transfer With",
+            Length::Units(130),
+            PickList::new(
+                &mut transfer_state.target_selector,
+                &transfer_state.targets[..],
+                transfer_state.target_selected.clone(),
+                |f| transfer_message(TransferInteractionMessage::TargetSelected(f)),
+            )
+            .font(JETBRAINS_MONO)
+            .text_size(17)
+            .width(Length::Fill)
+            .padding(10)
+            .style(Bl3UiStyle)
+            .into_element(),
+        )
+        .spacing(15)
+        .align_items(Alignment::Center),
+    )
+    .width(Length::Fill)
+    .height(Length::Units(36))
+    .style(Bl3UiStyle);
+
+    let left_list = inventory_list(
+        transfer_state.left.as_ref(),
+        &transfer_state.left_selected,
+        &mut transfer_state.left_scrollable_state,
+        TransferInteractionMessage::LeftItemToggled,
+    );
+
+    let right_list = inventory_list(
+        transfer_state.right.as_ref(),
+        &transfer_state.right_selected,
+        &mut transfer_state.right_scrollable_state,
+        TransferInteractionMessage::RightItemToggled,
+    );
+
+    let mut move_right_button = Button::new(
+        &mut transfer_state.move_right_button_state,
+        Text::new("Move >").font(JETBRAINS_MONO_BOLD).size(17),
+    )
+    .padding(10)
+    .style(Bl3UiStyle);
+
+    if transfer_state.right.is_some() && !transfer_state.left_selected.is_empty() {
+        move_right_button =
+            move_right_button.on_press(transfer_message(TransferInteractionMessage::MoveRight));
+    }
+
+    let mut move_left_button = Button::new(
+        &mut transfer_state.move_left_button_state,
+        Text::new("< Move").font(JETBRAINS_MONO_BOLD).size(17),
+    )
+    .padding(10)
+    .style(Bl3UiStyle);
+
+    if !transfer_state.right_selected.is_empty() {
+        move_left_button =
+            move_left_button.on_press(transfer_message(TransferInteractionMessage::MoveLeft));
+    }
+
+    let move_buttons = Column::new()
+        .push(move_right_button.into_element())
+        .push(move_left_button.into_element())
+        .spacing(15)
+        .align_items(Alignment::Center);
+
+    let inventories = Row::new()
+        .push(left_list)
+        .push(move_buttons)
+        .push(right_list)
+        .spacing(15)
+        .align_items(Alignment::Center)
+        .height(Length::Fill);
+
+    let mut confirm_button = Button::new(
+        &mut transfer_state.confirm_button_state,
+        Text::new("Save Both Files")
+            .font(JETBRAINS_MONO_BOLD)
+            .size(17),
+    )
+    .padding(10)
+    .style(Bl3UiPositiveButtonStyle);
+
+    if transfer_state.has_moved_items {
+        confirm_button =
+            confirm_button.on_press(transfer_message(TransferInteractionMessage::Confirm));
+    }
+
+    let actions = Row::new()
+        .push(
+            Text::new("Both saves will be backed up before they are written.")
+                .font(JETBRAINS_MONO)
+                .size(16)
+                .color(Color::from_rgb8(220, 220, 220))
+                .width(Length::Fill),
+        )
+        .push(
+            Button::new(
+                &mut transfer_state.cancel_button_state,
+                Text::new("Cancel").font(JETBRAINS_MONO_BOLD).size(17),
+            )
+            .on_press(transfer_message(TransferInteractionMessage::Cancel))
+            .padding(10)
+            .style(Bl3UiStyle)
+            .into_element(),
+        )
+        .push(confirm_button.into_element())
+        .spacing(15)
+        .align_items(Alignment::Center);
+
+    let contents = Column::new()
+        .push(target_selector)
+        .push(inventories)
+        .push(actions)
+        .spacing(20);
+
+    Container::new(contents)
+        .padding(30)
+        .width(Length::Fill)
+        .height(Length::Fill)
+}
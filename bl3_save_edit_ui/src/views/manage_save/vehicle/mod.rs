@@ -1,18 +1,26 @@
 use iced::{Column, Container, Length};
 
+use bl3_save_edit_core::vehicle_data::{Vehicle, VehicleLoadoutOption};
+
 use crate::bl3_ui::Bl3Message;
-use crate::views::manage_save::vehicle::vehicle_unlocker::VehicleUnlocker;
+use crate::views::manage_save::vehicle::vehicle_loadout::VehicleLoadoutPicker;
+use crate::views::manage_save::vehicle::vehicle_unlocker::{VehiclePreset, VehicleUnlocker};
 
+pub mod vehicle_loadout;
 pub mod vehicle_unlocker;
 
 #[derive(Debug, Default)]
 pub struct VehicleState {
     pub unlocker: VehicleUnlocker,
+    pub loadout_pickers: Vec<VehicleLoadoutPicker>,
 }
 
 #[derive(Debug, Clone)]
 pub enum SaveVehicleInteractionMessage {
     UnlockMessage(VehicleUnlockedMessage),
+    ApplyPreset(VehiclePreset),
+    LoadoutSkinSelected(Vehicle, VehicleLoadoutOption),
+    LoadoutWheelsSelected(Vehicle, VehicleLoadoutOption),
 }
 
 #[derive(Debug, Clone)]
@@ -34,7 +42,12 @@ pub enum VehicleUnlockedMessage {
 pub fn view(vehicle_state: &mut VehicleState) -> Container<Bl3Message> {
     let vehicle_unlocker = vehicle_state.unlocker.view().width(Length::Fill);
 
-    let all_contents = Column::new().push(vehicle_unlocker).spacing(20);
+    let vehicle_loadouts = vehicle_loadout::view(&mut vehicle_state.loadout_pickers);
+
+    let all_contents = Column::new()
+        .push(vehicle_unlocker)
+        .push(vehicle_loadouts)
+        .spacing(20);
 
     Container::new(all_contents).padding(30)
 }
@@ -0,0 +1,157 @@
+use iced::alignment::Horizontal;
+use iced::{pick_list, Alignment, Color, Column, Container, Length, PickList, Row, Text};
+
+use bl3_save_edit_core::vehicle_data::{Vehicle, VehicleLoadout, VehicleLoadoutOption};
+
+use crate::bl3_ui::{Bl3Message, InteractionMessage};
+use crate::bl3_ui_style::Bl3UiStyle;
+use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
+use crate::views::manage_save::vehicle::SaveVehicleInteractionMessage;
+use crate::views::manage_save::ManageSaveInteractionMessage;
+use crate::views::InteractionExt;
+use crate::widgets::labelled_element::LabelledElement;
+
+#[derive(Debug, Default)]
+pub struct VehicleLoadoutPicker {
+    pub vehicle: Vehicle,
+    skin_options: Vec<VehicleLoadoutOption>,
+    pub skin_selected: VehicleLoadoutOption,
+    skin_selector: pick_list::State<VehicleLoadoutOption>,
+    wheel_options: Vec<VehicleLoadoutOption>,
+    pub wheels_selected: VehicleLoadoutOption,
+    wheels_selector: pick_list::State<VehicleLoadoutOption>,
+}
+
+fn options_with_selected(
+    mut options: Vec<VehicleLoadoutOption>,
+    loadout_asset_path: &str,
+) -> (Vec<VehicleLoadoutOption>, VehicleLoadoutOption) {
+    let selected = Vehicle::loadout_option(&options, loadout_asset_path);
+
+    if !options.contains(&selected) {
+        options.push(selected.clone());
+    }
+
+    (options, selected)
+}
+
+impl VehicleLoadoutPicker {
+    pub fn new(vehicle_loadout: &VehicleLoadout) -> Self {
+        let vehicle = vehicle_loadout.vehicle;
+
+        let (skin_options, skin_selected) =
+            options_with_selected(vehicle.skin_options(), &vehicle_loadout.skin);
+
+        let (wheel_options, wheels_selected) =
+            options_with_selected(vehicle.wheel_options(), &vehicle_loadout.wheels);
+
+        Self {
+            vehicle,
+            skin_options,
+            skin_selected,
+            wheel_options,
+            wheels_selected,
+            ..Default::default()
+        }
+    }
+
+    pub fn loadout(&self) -> VehicleLoadout {
+        VehicleLoadout {
+            vehicle: self.vehicle,
+            skin: self.skin_selected.loadout_asset_path.clone(),
+            wheels: self.wheels_selected.loadout_asset_path.clone(),
+        }
+    }
+
+    pub fn view(&mut self) -> Row<Bl3Message> {
+        let vehicle = self.vehicle;
+
+        let skin_picker = LabelledElement::create(
+            format!("{} Skin", vehicle),
+            Length::Units(160),
+            PickList::new(
+                &mut self.skin_selector,
+                &self.skin_options[..],
+                Some(self.skin_selected.clone()),
+                move |s| {
+                    InteractionMessage::ManageSaveInteraction(
+                        ManageSaveInteractionMessage::Vehicle(
+                            SaveVehicleInteractionMessage::LoadoutSkinSelected(vehicle, s),
+                        ),
+                    )
+                },
+            )
+            .font(JETBRAINS_MONO)
+            .text_size(17)
+            .width(Length::Fill)
+            .padding(10)
+            .style(Bl3UiStyle)
+            .into_element(),
+        )
+        .spacing(15)
+        .width(Length::FillPortion(1))
+        .align_items(Alignment::Center);
+
+        let wheels_picker = LabelledElement::create(
+            "Wheels",
+            Length::Units(70),
+            PickList::new(
+                &mut self.wheels_selector,
+                &self.wheel_options[..],
+                Some(self.wheels_selected.clone()),
+                move |w| {
+                    InteractionMessage::ManageSaveInteraction(
+                        ManageSaveInteractionMessage::Vehicle(
+                            SaveVehicleInteractionMessage::LoadoutWheelsSelected(vehicle, w),
+                        ),
+                    )
+                },
+            )
+            .font(JETBRAINS_MONO)
+            .text_size(17)
+            .width(Length::Fill)
+            .padding(10)
+            .style(Bl3UiStyle)
+            .into_element(),
+        )
+        .spacing(15)
+        .width(Length::FillPortion(1))
+        .align_items(Alignment::Center);
+
+        Row::new()
+            .push(skin_picker)
+            .push(wheels_picker)
+            .spacing(15)
+            .align_items(Alignment::Center)
+    }
+}
+
+pub fn view(pickers: &mut [VehicleLoadoutPicker]) -> Container<Bl3Message> {
+    let pickers = pickers
+        .iter_mut()
+        .fold(Column::new().spacing(15), |curr, picker| {
+            curr.push(picker.view())
+        });
+
+    Container::new(
+        Column::new()
+            .push(
+                Container::new(
+                    Text::new("Vehicle Loadouts")
+                        .font(JETBRAINS_MONO_BOLD)
+                        .size(17)
+                        .color(Color::from_rgb8(242, 203, 5)),
+                )
+                .padding(10)
+                .align_x(Horizontal::Center)
+                .width(Length::Fill)
+                .style(Bl3UiStyle),
+            )
+            .push(
+                Container::new(pickers)
+                    .width(Length::Fill)
+                    .padding(15)
+                    .style(Bl3UiStyle),
+            ),
+    )
+}
@@ -2,9 +2,12 @@ use std::rc::Rc;
 
 use derivative::Derivative;
 use iced::alignment::Horizontal;
-use iced::{Checkbox, Color, Column, Container, Element, Length, Text};
+use iced::{
+    pick_list, Alignment, Checkbox, Color, Column, Container, Element, Length, PickList, Text,
+};
+use strum::Display;
 
-use bl3_save_edit_core::vehicle_data::{VehicleData, VehicleSubType, VehicleType};
+use bl3_save_edit_core::vehicle_data::{Vehicle, VehicleData, VehicleSubType, VehicleType};
 
 use crate::bl3_ui::{Bl3Message, InteractionMessage};
 use crate::bl3_ui_style::Bl3UiStyle;
@@ -12,6 +15,44 @@ use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
 use crate::views::manage_save::vehicle::{SaveVehicleInteractionMessage, VehicleUnlockedMessage};
 use crate::views::manage_save::ManageSaveInteractionMessage;
 use crate::views::InteractionExt;
+use crate::widgets::labelled_element::LabelledElement;
+
+#[derive(Debug, Display, Copy, Clone, Eq, PartialEq)]
+pub enum VehiclePreset {
+    #[strum(to_string = "All Vehicles")]
+    AllVehicles,
+    #[strum(to_string = "All Outrunner")]
+    AllOutrunner,
+    #[strum(to_string = "All Technical")]
+    AllTechnical,
+    #[strum(to_string = "All Jetbeast")]
+    AllJetbeast,
+    #[strum(to_string = "All Cyclone")]
+    AllCyclone,
+    None,
+}
+
+impl VehiclePreset {
+    pub const ALL: [VehiclePreset; 6] = [
+        VehiclePreset::AllVehicles,
+        VehiclePreset::AllOutrunner,
+        VehiclePreset::AllTechnical,
+        VehiclePreset::AllJetbeast,
+        VehiclePreset::AllCyclone,
+        VehiclePreset::None,
+    ];
+
+    pub fn includes(&self, vehicle: Vehicle) -> bool {
+        match self {
+            VehiclePreset::AllVehicles => true,
+            VehiclePreset::AllOutrunner => vehicle == Vehicle::Outrunner,
+            VehiclePreset::AllTechnical => vehicle == Vehicle::Technical,
+            VehiclePreset::AllJetbeast => vehicle == Vehicle::Jetbeast,
+            VehiclePreset::AllCyclone => vehicle == Vehicle::Cyclone,
+            VehiclePreset::None => false,
+        }
+    }
+}
 
 #[derive(Derivative)]
 #[derivative(Debug, Default)]
@@ -80,6 +121,8 @@ pub struct VehicleUnlocker {
     pub cyclone_chassis: VehicleUnlockCheckbox,
     pub cyclone_parts: VehicleUnlockCheckbox,
     pub cyclone_skins: VehicleUnlockCheckbox,
+    pub preset_selector: pick_list::State<VehiclePreset>,
+    pub preset_selected: Option<VehiclePreset>,
 }
 
 impl std::default::Default for VehicleUnlocker {
@@ -145,12 +188,80 @@ impl std::default::Default for VehicleUnlocker {
                 VehicleData::new(VehicleType::Cyclone(VehicleSubType::Skins), 0),
                 VehicleUnlockedMessage::CycloneSkins,
             ),
+            preset_selector: pick_list::State::default(),
+            preset_selected: None,
         }
     }
 }
 
 impl VehicleUnlocker {
+    pub fn checkbox_mut(&mut self, vehicle_type: &VehicleType) -> &mut VehicleUnlockCheckbox {
+        match vehicle_type {
+            VehicleType::Outrunner(sub_type) => match sub_type {
+                VehicleSubType::Chassis => &mut self.outrunner_chassis,
+                VehicleSubType::Parts => &mut self.outrunner_parts,
+                VehicleSubType::Skins => &mut self.outrunner_skins,
+            },
+            VehicleType::Jetbeast(sub_type) => match sub_type {
+                VehicleSubType::Chassis => &mut self.jetbeast_chassis,
+                VehicleSubType::Parts => &mut self.jetbeast_parts,
+                VehicleSubType::Skins => &mut self.jetbeast_skins,
+            },
+            VehicleType::Technical(sub_type) => match sub_type {
+                VehicleSubType::Chassis => &mut self.technical_chassis,
+                VehicleSubType::Parts => &mut self.technical_parts,
+                VehicleSubType::Skins => &mut self.technical_skins,
+            },
+            VehicleType::Cyclone(sub_type) => match sub_type {
+                VehicleSubType::Chassis => &mut self.cyclone_chassis,
+                VehicleSubType::Parts => &mut self.cyclone_parts,
+                VehicleSubType::Skins => &mut self.cyclone_skins,
+            },
+        }
+    }
+
+    /// Checks every unlock for the vehicles in the preset and unchecks the rest.
+    pub fn apply_preset(&mut self, preset: VehiclePreset) {
+        for vehicle in Vehicle::ALL {
+            for sub_type in [
+                VehicleSubType::Chassis,
+                VehicleSubType::Parts,
+                VehicleSubType::Skins,
+            ] {
+                self.checkbox_mut(&vehicle.vehicle_type(sub_type))
+                    .is_unlocked = preset.includes(vehicle);
+            }
+        }
+
+        self.preset_selected = Some(preset);
+    }
+
     pub fn view(&mut self) -> Container<Bl3Message> {
+        let preset = LabelledElement::create(
+            "Preset",
+            Length::Units(75),
+            PickList::new(
+                &mut self.preset_selector,
+                &VehiclePreset::ALL[..],
+                self.preset_selected,
+                |p| {
+                    InteractionMessage::ManageSaveInteraction(
+                        ManageSaveInteractionMessage::Vehicle(
+                            SaveVehicleInteractionMessage::ApplyPreset(p),
+                        ),
+                    )
+                },
+            )
+            .font(JETBRAINS_MONO)
+            .text_size(17)
+            .width(Length::Fill)
+            .padding(10)
+            .style(Bl3UiStyle)
+            .into_element(),
+        )
+        .spacing(15)
+        .align_items(Alignment::Center);
+
         Container::new(
             Column::new()
                 .push(
@@ -168,6 +279,7 @@ impl VehicleUnlocker {
                 .push(
                     Container::new(
                         Column::new()
+                            .push(preset)
                             .push(self.outrunner_chassis.view())
                             .push(self.outrunner_parts.view())
                             .push(self.outrunner_skins.view())
@@ -184,7 +296,7 @@ impl VehicleUnlocker {
                     )
                     .width(Length::Fill)
                     .padding(15)
-                    .height(Length::Units(440))
+                    .height(Length::Units(495))
                     .style(Bl3UiStyle),
                 ),
         )
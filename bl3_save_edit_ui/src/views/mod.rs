@@ -1,19 +1,27 @@
 use std::fmt::Display;
 
 use iced::alignment::Horizontal;
-use iced::{button, container, svg, Alignment, Button, Color, Element, Length, Row, Svg, Text};
+use iced::{
+    button, container, svg, Alignment, Button, Color, Container, Element, Length, Row, Svg, Text,
+};
 
 use crate::bl3_ui::{Bl3Message, InteractionMessage};
 use crate::resources::fonts::JETBRAINS_MONO_BOLD;
+use crate::resources::svgs::REFRESH;
 
+pub mod backup_history;
 pub mod choose_save_directory;
+pub mod file_metadata;
+pub mod first_run_wizard;
 pub mod initialization;
 pub mod item_editor;
 pub mod loading;
 pub mod manage_profile;
 pub mod manage_save;
 pub mod settings;
+pub mod status_bar;
 pub mod tab_bar_button;
+pub mod validation_report;
 
 pub const NO_SEARCH_RESULTS_FOUND_MESSAGE: &str = "No results found.";
 
@@ -34,41 +42,121 @@ where
     }
 }
 
-fn tab_bar_button<'a, V: Display + PartialEq>(
+/// Dot shown next to a tab's name.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum TabBadge {
+    /// The tab has edits that haven't been saved yet.
+    Dirty,
+    /// Validation found an issue with something the tab edits.
+    Warning,
+}
+
+/// Which tabs show a badge, these are worked out after edits rather than on every redraw.
+#[derive(Debug)]
+pub struct TabBadges<V> {
+    pub dirty: Vec<V>,
+    pub warnings: Vec<V>,
+}
+
+impl<V> Default for TabBadges<V> {
+    fn default() -> Self {
+        Self {
+            dirty: Vec::new(),
+            warnings: Vec::new(),
+        }
+    }
+}
+
+impl<V: PartialEq> TabBadges<V> {
+    /// Warnings take priority as they matter more than a tab having edits.
+    pub fn badge(&self, tab_bar_view: &V) -> Option<TabBadge> {
+        if self.warnings.contains(tab_bar_view) {
+            Some(TabBadge::Warning)
+        } else if self.dirty.contains(tab_bar_view) {
+            Some(TabBadge::Dirty)
+        } else {
+            None
+        }
+    }
+}
+
+fn tab_bar_button<'a, 'b, V: Display + PartialEq + 'b>(
     state: &'a mut button::State,
     tab_bar_view: V,
-    current_tab_bar_view: &V,
+    current_tab_bar_view: impl Into<Option<&'b V>>,
     on_press_message: InteractionMessage,
     icon_handle: svg::Handle,
     length: u16,
+    badge: Option<TabBadge>,
 ) -> Element<'a, Bl3Message> {
     let icon = Svg::new(icon_handle)
         .height(Length::Units(17))
         .width(Length::Units(17));
 
+    let mut contents = Row::new()
+        .push(icon)
+        .push(
+            Text::new(tab_bar_view.to_string())
+                .horizontal_alignment(Horizontal::Center)
+                .font(JETBRAINS_MONO_BOLD)
+                .size(18),
+        )
+        .padding(5)
+        .spacing(10)
+        .align_items(Alignment::Center);
+
+    // The badge gets its own space so the tab's name isn't squashed
+    let length = match badge {
+        Some(badge) => {
+            let color = match badge {
+                TabBadge::Dirty => Color::from_rgb8(242, 203, 5),
+                TabBadge::Warning => Color::from_rgb8(230, 80, 80),
+            };
+
+            contents = contents.push(Text::new("●").size(14).color(color));
+
+            length + 20
+        }
+        None => length,
+    };
+
+    let button = Button::new(state, contents.width(Length::Units(length)))
+        .on_press(on_press_message)
+        .padding(5);
+
+    if current_tab_bar_view.into() == Some(&tab_bar_view) {
+        button.style(ManageTabBarActiveStyle).into_element()
+    } else {
+        button.style(ManageTabBarStyle).into_element()
+    }
+}
+
+fn reset_tab_button(
+    state: &mut button::State,
+    on_press_message: InteractionMessage,
+) -> Element<Bl3Message> {
+    let icon = Svg::new(svg::Handle::from_memory(REFRESH))
+        .height(Length::Units(17))
+        .width(Length::Units(17));
+
     let button = Button::new(
         state,
         Row::new()
             .push(icon)
-            .push(
-                Text::new(tab_bar_view.to_string())
-                    .horizontal_alignment(Horizontal::Center)
-                    .font(JETBRAINS_MONO_BOLD)
-                    .size(18),
-            )
+            .push(Text::new("Reset Tab").font(JETBRAINS_MONO_BOLD).size(18))
             .padding(5)
             .spacing(10)
-            .width(Length::Units(length))
             .align_items(Alignment::Center),
     )
     .on_press(on_press_message)
-    .padding(5);
-
-    if tab_bar_view == *current_tab_bar_view {
-        button.style(ManageTabBarActiveStyle).into_element()
-    } else {
-        button.style(ManageTabBarStyle).into_element()
-    }
+    .padding(5)
+    .style(ManageTabBarStyle)
+    .into_element();
+
+    Container::new(button)
+        .width(Length::Fill)
+        .align_x(Horizontal::Right)
+        .into()
 }
 
 struct ManageTabBarActiveStyle;
@@ -1,13 +1,22 @@
 use std::path::PathBuf;
 
 use iced::{
-    button, text_input, Alignment, Button, Color, Column, Container, Length, Row, Text, TextInput,
+    button, scrollable, text_input, tooltip, Alignment, Button, Checkbox, Color, Column, Container,
+    Length, Row, Scrollable, Text, TextInput, Tooltip,
 };
 
+use bl3_save_edit_core::content_source::ContentSource;
+use bl3_save_edit_core::data_overrides::DATA_OVERRIDES_FILE;
+use bl3_save_edit_core::round_trip::RoundTripReport;
+
 use crate::bl3_ui::{Bl3Message, InteractionMessage, MessageResult};
-use crate::bl3_ui_style::Bl3UiStyle;
+use crate::bl3_ui_style::{Bl3UiStyle, Bl3UiTooltipStyle};
+use crate::commands::interaction::bulk::SyncReport;
+use crate::config::Bl3Config;
 use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
-use crate::views::InteractionExt;
+use crate::views::backup_history::{BackupHistoryInteractionMessage, BackupHistoryState};
+use crate::views::item_editor::InventoryColumns;
+use crate::views::{backup_history, InteractionExt};
 use crate::widgets::labelled_element::LabelledElement;
 
 #[derive(Debug, Default)]
@@ -15,6 +24,8 @@ pub struct SettingsState {
     pub config_dir_input: String,
     pub config_dir_input_state: text_input::State,
     pub open_config_dir_button_state: button::State,
+    pub migrate_config_button_state: button::State,
+    pub choose_migrate_config_dir_window_open: bool,
     pub backup_dir_input: String,
     pub backup_dir_input_state: text_input::State,
     pub open_backup_dir_button_state: button::State,
@@ -28,12 +39,33 @@ pub struct SettingsState {
     pub decrease_ui_scale_button_state: button::State,
     pub increase_ui_scale_button_state: button::State,
     pub ui_scale_factor: f64,
+    pub owned_dlc: Vec<ContentSource>,
+    pub inventory_columns: InventoryColumns,
+    pub item_verification_enabled: bool,
+    pub item_api_url_input: String,
+    pub item_api_url_input_state: text_input::State,
+    pub save_item_api_url_button_state: button::State,
+    pub sanitize_negative_values: bool,
+    pub reload_data_overrides_button_state: button::State,
+    pub reset_to_defaults_button_state: button::State,
+    pub reset_to_defaults_dialog_open: bool,
+    pub verify_files_button_state: button::State,
+    pub is_verifying_files: bool,
+    pub round_trip_reports: Vec<RoundTripReport>,
+    pub round_trip_reports_scrollable_state: scrollable::State,
+    pub save_round_trip_report_button_state: button::State,
+    pub sync_guardian_rank_button_state: button::State,
+    pub is_syncing_guardian_rank: bool,
+    pub guardian_rank_sync_report: Option<SyncReport>,
+    pub backup_history_state: BackupHistoryState,
 }
 
 #[derive(Debug, Clone)]
 pub enum SettingsInteractionMessage {
     OpenConfigDir,
     OpenConfigDirCompleted(MessageResult<()>),
+    MigrateConfig,
+    MigrateConfigCompleted(MessageResult<Bl3Config>),
     OpenBackupDir,
     OpenBackupDirCompleted(MessageResult<()>),
     ChangeBackupDir,
@@ -44,6 +76,23 @@ pub enum SettingsInteractionMessage {
     ChangeSavesDirCompleted(MessageResult<PathBuf>),
     DecreaseUIScale,
     IncreaseUIScale,
+    OwnedDlcSelected(ContentSource, bool),
+    InventoryColumnsChanged(InventoryColumns),
+    ItemVerificationToggled(bool),
+    ItemApiUrlInputChanged(String),
+    ItemApiUrlSubmitted,
+    SanitizeNegativeValuesToggled(bool),
+    ReloadDataOverridesPressed,
+    ReloadDataOverridesCompleted(MessageResult<usize>),
+    ResetToDefaultsPressed,
+    ResetToDefaults(MessageResult<bool>),
+    VerifyFilesPressed,
+    VerifyFilesCompleted(MessageResult<Vec<RoundTripReport>>),
+    SaveRoundTripReportPressed,
+    SaveRoundTripReportCompleted(MessageResult<PathBuf>),
+    SyncGuardianRankPressed,
+    SyncGuardianRankCompleted(MessageResult<SyncReport>),
+    BackupHistory(BackupHistoryInteractionMessage),
 }
 
 pub fn view(settings_state: &mut SettingsState) -> Container<Bl3Message> {
@@ -62,6 +111,32 @@ pub fn view(settings_state: &mut SettingsState) -> Container<Bl3Message> {
         );
     }
 
+    let mut migrate_config_button = Button::new(
+        &mut settings_state.migrate_config_button_state,
+        Text::new("Migrate Config")
+            .font(JETBRAINS_MONO_BOLD)
+            .size(17),
+    )
+    .padding(10)
+    .style(Bl3UiStyle);
+
+    if !settings_state.choose_migrate_config_dir_window_open {
+        migrate_config_button = migrate_config_button.on_press(
+            InteractionMessage::SettingsInteraction(SettingsInteractionMessage::MigrateConfig),
+        );
+    }
+
+    let migrate_config_button = Tooltip::new(
+        migrate_config_button.into_element(),
+        "Copy the config from an old config folder, for example after reinstalling your OS",
+        tooltip::Position::Bottom,
+    )
+    .gap(10)
+    .padding(10)
+    .font(JETBRAINS_MONO)
+    .size(17)
+    .style(Bl3UiTooltipStyle);
+
     let config_dir = Container::new(
         Row::new()
             .push(
@@ -96,6 +171,7 @@ pub fn view(settings_state: &mut SettingsState) -> Container<Bl3Message> {
                 .style(Bl3UiStyle)
                 .into_element(),
             )
+            .push(migrate_config_button)
             .align_items(Alignment::Center),
     )
     .width(Length::Fill)
@@ -243,11 +319,428 @@ pub fn view(settings_state: &mut SettingsState) -> Container<Bl3Message> {
     )
     .style(Bl3UiStyle);
 
+    let owned_dlc_checkboxes =
+        ContentSource::all_dlc()
+            .into_iter()
+            .fold(Column::new().spacing(15), |curr, dlc| {
+                curr.push(
+                    Checkbox::new(
+                        settings_state.owned_dlc.contains(&dlc),
+                        dlc.to_string(),
+                        move |c| {
+                            InteractionMessage::SettingsInteraction(
+                                SettingsInteractionMessage::OwnedDlcSelected(dlc, c),
+                            )
+                        },
+                    )
+                    .size(20)
+                    .font(JETBRAINS_MONO)
+                    .text_color(Color::from_rgb8(220, 220, 220))
+                    .text_size(17)
+                    .style(Bl3UiStyle),
+                )
+            });
+
+    let owned_dlc = Container::new(
+        LabelledElement::create("DLC Owned", Length::Units(140), owned_dlc_checkboxes)
+            .spacing(15)
+            .width(Length::Fill)
+            .align_items(Alignment::Start),
+    )
+    .padding(15)
+    .style(Bl3UiStyle);
+
+    let columns = settings_state.inventory_columns;
+
+    let column_options: [(&str, bool, fn(&mut InventoryColumns, bool)); 5] = [
+        ("Level", columns.show_level, |c, v| c.show_level = v),
+        ("Mayhem Level", columns.show_mayhem, |c, v| {
+            c.show_mayhem = v
+        }),
+        ("Manufacturer", columns.show_manufacturer, |c, v| {
+            c.show_manufacturer = v
+        }),
+        ("Rarity", columns.show_rarity, |c, v| c.show_rarity = v),
+        ("Anointment", columns.show_anointment, |c, v| {
+            c.show_anointment = v
+        }),
+    ];
+
+    let inventory_column_checkboxes = column_options.into_iter().fold(
+        Column::new().spacing(15),
+        |curr, (label, is_shown, set_shown)| {
+            curr.push(
+                Checkbox::new(is_shown, label, move |c| {
+                    let mut columns = columns;
+                    set_shown(&mut columns, c);
+
+                    InteractionMessage::SettingsInteraction(
+                        SettingsInteractionMessage::InventoryColumnsChanged(columns),
+                    )
+                })
+                .size(20)
+                .font(JETBRAINS_MONO)
+                .text_color(Color::from_rgb8(220, 220, 220))
+                .text_size(17)
+                .style(Bl3UiStyle),
+            )
+        },
+    );
+
+    let inventory_columns = Container::new(
+        LabelledElement::create(
+            "Item Columns",
+            Length::Units(140),
+            inventory_column_checkboxes,
+        )
+        .spacing(15)
+        .width(Length::Fill)
+        .align_items(Alignment::Start),
+    )
+    .padding(15)
+    .style(Bl3UiStyle);
+
+    let item_verification = Container::new(
+        LabelledElement::create(
+            "Verify Items",
+            Length::Units(140),
+            Column::new()
+                .push(
+                    Checkbox::new(
+                        settings_state.item_verification_enabled,
+                        "Check item serials against an online item database",
+                        |c| {
+                            InteractionMessage::SettingsInteraction(
+                                SettingsInteractionMessage::ItemVerificationToggled(c),
+                            )
+                        },
+                    )
+                    .size(20)
+                    .font(JETBRAINS_MONO)
+                    .text_color(Color::from_rgb8(220, 220, 220))
+                    .text_size(17)
+                    .style(Bl3UiStyle),
+                )
+                .push(
+                    Row::new()
+                        .push(
+                            Tooltip::new(
+                                TextInput::new(
+                                    &mut settings_state.item_api_url_input_state,
+                                    "Item API URL...",
+                                    &settings_state.item_api_url_input,
+                                    |s| {
+                                        InteractionMessage::SettingsInteraction(
+                                            SettingsInteractionMessage::ItemApiUrlInputChanged(s),
+                                        )
+                                    },
+                                )
+                                .on_submit(InteractionMessage::SettingsInteraction(
+                                    SettingsInteractionMessage::ItemApiUrlSubmitted,
+                                ))
+                                .font(JETBRAINS_MONO)
+                                .padding(10)
+                                .size(17)
+                                .style(Bl3UiStyle)
+                                .into_element(),
+                                "Serials are sent to this URL as ?serial=..., only when Verify Items is pressed",
+                                tooltip::Position::Top,
+                            )
+                            .gap(10)
+                            .padding(10)
+                            .font(JETBRAINS_MONO)
+                            .size(17)
+                            .style(Bl3UiTooltipStyle),
+                        )
+                        .push(
+                            Button::new(
+                                &mut settings_state.save_item_api_url_button_state,
+                                Text::new("Save URL").font(JETBRAINS_MONO_BOLD).size(17),
+                            )
+                            .on_press(InteractionMessage::SettingsInteraction(
+                                SettingsInteractionMessage::ItemApiUrlSubmitted,
+                            ))
+                            .padding(10)
+                            .style(Bl3UiStyle)
+                            .into_element(),
+                        )
+                        .align_items(Alignment::Center),
+                )
+                .spacing(15),
+        )
+        .spacing(15)
+        .width(Length::Fill)
+        .align_items(Alignment::Start),
+    )
+    .padding(15)
+    .style(Bl3UiStyle);
+
+    let mut reset_to_defaults_button = Button::new(
+        &mut settings_state.reset_to_defaults_button_state,
+        Text::new("Reset All Settings")
+            .font(JETBRAINS_MONO_BOLD)
+            .size(17),
+    )
+    .padding(10)
+    .style(Bl3UiStyle);
+
+    if !settings_state.reset_to_defaults_dialog_open {
+        reset_to_defaults_button =
+            reset_to_defaults_button.on_press(InteractionMessage::SettingsInteraction(
+                SettingsInteractionMessage::ResetToDefaultsPressed,
+            ));
+    }
+
+    let reset_to_defaults = Container::new(
+        LabelledElement::create(
+            "Reset",
+            Length::Units(140),
+            Tooltip::new(
+                reset_to_defaults_button.into_element(),
+                "Reset every setting to its default, this also clears the saves and backups folders",
+                tooltip::Position::Top,
+            )
+            .gap(10)
+            .padding(10)
+            .font(JETBRAINS_MONO)
+            .size(17)
+            .style(Bl3UiTooltipStyle),
+        )
+        .spacing(15)
+        .width(Length::Fill)
+        .align_items(Alignment::Center),
+    )
+    .style(Bl3UiStyle);
+
+    let data_overrides = Container::new(
+        LabelledElement::create(
+            "Data Overrides",
+            Length::Units(140),
+            Column::new()
+                .push(
+                    Text::new(format!(
+                        "Part and balance names for modded items are read from {} in the config folder",
+                        DATA_OVERRIDES_FILE
+                    ))
+                    .font(JETBRAINS_MONO)
+                    .size(17)
+                    .color(Color::from_rgb8(220, 220, 220)),
+                )
+                .push(
+                    Button::new(
+                        &mut settings_state.reload_data_overrides_button_state,
+                        Text::new("Reload data overrides")
+                            .font(JETBRAINS_MONO_BOLD)
+                            .size(17),
+                    )
+                    .on_press(InteractionMessage::SettingsInteraction(
+                        SettingsInteractionMessage::ReloadDataOverridesPressed,
+                    ))
+                    .padding(10)
+                    .style(Bl3UiStyle)
+                    .into_element(),
+                )
+                .spacing(15),
+        )
+        .spacing(15)
+        .width(Length::Fill)
+        .align_items(Alignment::Start),
+    )
+    .padding(15)
+    .style(Bl3UiStyle);
+
+    let mut verify_files_button = Button::new(
+        &mut settings_state.verify_files_button_state,
+        Text::new(if settings_state.is_verifying_files {
+            "Verifying..."
+        } else {
+            "Verify my files"
+        })
+        .font(JETBRAINS_MONO_BOLD)
+        .size(17),
+    )
+    .padding(10)
+    .style(Bl3UiStyle);
+
+    if !settings_state.is_verifying_files {
+        verify_files_button = verify_files_button.on_press(
+            InteractionMessage::SettingsInteraction(SettingsInteractionMessage::VerifyFilesPressed),
+        );
+    }
+
+    let mut verify_files_contents = Column::new()
+        .push(
+            Tooltip::new(
+                verify_files_button.into_element(),
+                "Reads every loaded file and writes it back in memory to check nothing changes, no files are saved",
+                tooltip::Position::Top,
+            )
+            .gap(10)
+            .padding(10)
+            .font(JETBRAINS_MONO)
+            .size(17)
+            .style(Bl3UiTooltipStyle),
+        )
+        .spacing(15);
+
+    if !settings_state.round_trip_reports.is_empty() {
+        let reports = settings_state.round_trip_reports.iter().fold(
+            Column::new().spacing(10),
+            |curr, report| {
+                let color = if report.is_faithful() {
+                    Color::from_rgb8(180, 180, 180)
+                } else {
+                    Color::from_rgb8(220, 220, 220)
+                };
+
+                curr.push(
+                    Text::new(report.to_string())
+                        .font(JETBRAINS_MONO)
+                        .size(16)
+                        .color(color),
+                )
+            },
+        );
+
+        verify_files_contents = verify_files_contents
+            .push(
+                Container::new(
+                    Scrollable::new(&mut settings_state.round_trip_reports_scrollable_state)
+                        .push(reports)
+                        .height(Length::Units(200)),
+                )
+                .width(Length::Fill)
+                .padding(1),
+            )
+            .push(
+                Button::new(
+                    &mut settings_state.save_round_trip_report_button_state,
+                    Text::new("Save Report").font(JETBRAINS_MONO_BOLD).size(17),
+                )
+                .on_press(InteractionMessage::SettingsInteraction(
+                    SettingsInteractionMessage::SaveRoundTripReportPressed,
+                ))
+                .padding(10)
+                .style(Bl3UiStyle)
+                .into_element(),
+            );
+    }
+
+    let verify_files = Container::new(
+        LabelledElement::create("Verify Files", Length::Units(140), verify_files_contents)
+            .spacing(15)
+            .width(Length::Fill)
+            .align_items(Alignment::Start),
+    )
+    .padding(15)
+    .style(Bl3UiStyle);
+
+    let mut sync_guardian_rank_button = Button::new(
+        &mut settings_state.sync_guardian_rank_button_state,
+        Text::new(if settings_state.is_syncing_guardian_rank {
+            "Syncing..."
+        } else {
+            "Sync Guardian Rank to All Saves"
+        })
+        .font(JETBRAINS_MONO_BOLD)
+        .size(17),
+    )
+    .padding(10)
+    .style(Bl3UiStyle);
+
+    if !settings_state.is_syncing_guardian_rank {
+        sync_guardian_rank_button =
+            sync_guardian_rank_button.on_press(InteractionMessage::SettingsInteraction(
+                SettingsInteractionMessage::SyncGuardianRankPressed,
+            ));
+    }
+
+    let mut guardian_rank_contents = Column::new()
+        .push(
+            Tooltip::new(
+                sync_guardian_rank_button.into_element(),
+                "Copy the guardian rank, tokens and rewards from the loaded profile into every loaded save, each changed save is backed up first",
+                tooltip::Position::Top,
+            )
+            .gap(10)
+            .padding(10)
+            .font(JETBRAINS_MONO)
+            .size(17)
+            .style(Bl3UiTooltipStyle),
+        )
+        .spacing(15);
+
+    if let Some(report) = &settings_state.guardian_rank_sync_report {
+        guardian_rank_contents = guardian_rank_contents.push(
+            Text::new(report.to_string())
+                .font(JETBRAINS_MONO)
+                .size(16)
+                .color(Color::from_rgb8(220, 220, 220)),
+        );
+
+        for (label, file_names) in [("Updated", &report.updated), ("Failed", &report.failed)] {
+            if !file_names.is_empty() {
+                guardian_rank_contents = guardian_rank_contents.push(
+                    Text::new(format!("{}: {}", label, file_names.join(", ")))
+                        .font(JETBRAINS_MONO)
+                        .size(16)
+                        .color(Color::from_rgb8(180, 180, 180)),
+                );
+            }
+        }
+    }
+
+    let guardian_rank = Container::new(
+        LabelledElement::create("Guardian Rank", Length::Units(140), guardian_rank_contents)
+            .spacing(15)
+            .width(Length::Fill)
+            .align_items(Alignment::Start),
+    )
+    .padding(15)
+    .style(Bl3UiStyle);
+
+    let sanitize_negative_values = Container::new(
+        LabelledElement::create(
+            "Loading",
+            Length::Units(140),
+            Checkbox::new(
+                settings_state.sanitize_negative_values,
+                "Set negative currency, ammo, SDU and guardian token values to 0 when a file is loaded",
+                |c| {
+                    InteractionMessage::SettingsInteraction(
+                        SettingsInteractionMessage::SanitizeNegativeValuesToggled(c),
+                    )
+                },
+            )
+            .size(20)
+            .font(JETBRAINS_MONO)
+            .text_color(Color::from_rgb8(220, 220, 220))
+            .text_size(17)
+            .style(Bl3UiStyle),
+        )
+        .spacing(15)
+        .width(Length::Fill)
+        .align_items(Alignment::Center),
+    )
+    .padding(15)
+    .style(Bl3UiStyle);
+
     let all_contents = Column::new()
         .push(config_dir)
         .push(backup_dir)
         .push(saves_dir)
         .push(ui_scale)
+        .push(owned_dlc)
+        .push(inventory_columns)
+        .push(item_verification)
+        .push(sanitize_negative_values)
+        .push(data_overrides)
+        .push(verify_files)
+        .push(guardian_rank)
+        .push(reset_to_defaults)
+        .push(backup_history::view(
+            &mut settings_state.backup_history_state,
+        ))
         .spacing(20);
 
     Container::new(all_contents).padding(30)
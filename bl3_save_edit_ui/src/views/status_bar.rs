@@ -0,0 +1,78 @@
+use iced::alignment::Horizontal;
+use iced::{container, keyboard, Alignment, Color, Container, Length, Row, Text};
+
+use crate::bl3_ui::Bl3Message;
+use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
+
+fn modifier_badge<'a>(name: &str, is_held: bool) -> Container<'a, Bl3Message> {
+    let badge = Container::new(Text::new(name).font(JETBRAINS_MONO_BOLD).size(14))
+        .padding(4)
+        .align_x(Horizontal::Center);
+
+    if is_held {
+        badge.style(Bl3UiModifierHeldStyle)
+    } else {
+        badge.style(Bl3UiModifierReleasedStyle)
+    }
+}
+
+pub fn view<'a>(modifiers: keyboard::Modifiers) -> Container<'a, Bl3Message> {
+    let contents = Row::new()
+        .push(
+            Text::new("Modifiers")
+                .font(JETBRAINS_MONO)
+                .size(14)
+                .color(Color::from_rgb8(160, 160, 160)),
+        )
+        .push(modifier_badge("Ctrl", modifiers.control()))
+        .push(modifier_badge("Shift", modifiers.shift()))
+        .push(modifier_badge("Alt", modifiers.alt()))
+        .spacing(10)
+        .align_items(Alignment::Center);
+
+    Container::new(contents)
+        .padding(8)
+        .width(Length::Fill)
+        .style(Bl3UiStatusBarStyle)
+}
+
+struct Bl3UiStatusBarStyle;
+
+impl container::StyleSheet for Bl3UiStatusBarStyle {
+    fn style(&self) -> container::Style {
+        container::Style {
+            background: Some(Color::from_rgb8(35, 35, 35).into()),
+            border_width: 1.5,
+            border_color: Color::from_rgb8(25, 25, 25),
+            ..container::Style::default()
+        }
+    }
+}
+
+struct Bl3UiModifierHeldStyle;
+
+impl container::StyleSheet for Bl3UiModifierHeldStyle {
+    fn style(&self) -> container::Style {
+        container::Style {
+            text_color: Some(Color::from_rgb8(149, 240, 172)),
+            background: Some(Color::from_rgb8(29, 54, 35).into()),
+            border_radius: 1.0,
+            border_width: 1.0,
+            border_color: Color::from_rgb8(36, 61, 42),
+        }
+    }
+}
+
+struct Bl3UiModifierReleasedStyle;
+
+impl container::StyleSheet for Bl3UiModifierReleasedStyle {
+    fn style(&self) -> container::Style {
+        container::Style {
+            text_color: Some(Color::from_rgb8(100, 100, 100)),
+            background: Some(Color::from_rgb8(30, 30, 30).into()),
+            border_radius: 1.0,
+            border_width: 1.0,
+            border_color: Color::from_rgb8(45, 45, 45),
+        }
+    }
+}
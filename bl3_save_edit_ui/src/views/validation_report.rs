@@ -0,0 +1,123 @@
+use iced::{button, Alignment, Button, Color, Column, Container, Length, Row, Text};
+
+use bl3_save_edit_core::validation::{has_errors, Finding, Severity};
+
+use crate::bl3_ui::{Bl3Message, InteractionMessage};
+use crate::bl3_ui_style::{Bl3UiPositiveButtonStyle, Bl3UiStyle};
+use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
+use crate::views::InteractionExt;
+
+/// The issues found in a file when saving was pressed. Warnings have to be acknowledged before the
+/// file is written, errors block saving until they are fixed.
+#[derive(Debug, Default)]
+pub struct ValidationReportState {
+    pub findings: Vec<Finding>,
+    pub acknowledged: bool,
+    acknowledge_button_state: button::State,
+    dismiss_button_state: button::State,
+}
+
+impl ValidationReportState {
+    pub fn open(&mut self, findings: Vec<Finding>) {
+        self.findings = findings;
+        self.acknowledged = false;
+    }
+
+    /// Whether `findings` have already been acknowledged, a changed list has to be acknowledged again.
+    pub fn is_acknowledged(&self, findings: &[Finding]) -> bool {
+        self.acknowledged && self.findings == findings
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum ValidationReportMessage {
+    AcknowledgePressed,
+    DismissPressed,
+}
+
+pub fn view(
+    validation_report_state: &mut ValidationReportState,
+    on_message: fn(ValidationReportMessage) -> InteractionMessage,
+) -> Option<Container<Bl3Message>> {
+    if validation_report_state.findings.is_empty() {
+        return None;
+    }
+
+    let blocked = has_errors(&validation_report_state.findings);
+
+    let findings_list =
+        validation_report_state
+            .findings
+            .iter()
+            .fold(Column::new().spacing(5), |curr, finding| {
+                let color = match finding.severity {
+                    Severity::Error => Color::from_rgb8(230, 80, 80),
+                    Severity::Warning => Color::from_rgb8(224, 170, 90),
+                };
+
+                curr.push(
+                    Text::new(format!(
+                        "[{}] {}: {}",
+                        finding.severity, finding.kind, finding.message
+                    ))
+                    .font(JETBRAINS_MONO)
+                    .size(16)
+                    .color(color),
+                )
+            });
+
+    let summary = if blocked {
+        "These errors must be fixed before this file can be saved."
+    } else {
+        "Some of the changes may cause problems in game. Acknowledge the warnings to save anyway."
+    };
+
+    let description = Column::new()
+        .push(
+            Text::new(summary)
+                .font(JETBRAINS_MONO_BOLD)
+                .size(17)
+                .color(Color::from_rgb8(220, 220, 220)),
+        )
+        .push(findings_list)
+        .spacing(10)
+        .width(Length::Fill);
+
+    let mut contents = Row::new()
+        .push(description)
+        .spacing(15)
+        .align_items(Alignment::Center);
+
+    if !blocked {
+        contents = contents.push(
+            Button::new(
+                &mut validation_report_state.acknowledge_button_state,
+                Text::new("Acknowledge and Save")
+                    .font(JETBRAINS_MONO_BOLD)
+                    .size(17),
+            )
+            .on_press(on_message(ValidationReportMessage::AcknowledgePressed))
+            .padding(10)
+            .style(Bl3UiPositiveButtonStyle)
+            .into_element(),
+        );
+    }
+
+    contents = contents.push(
+        Button::new(
+            &mut validation_report_state.dismiss_button_state,
+            Text::new("Dismiss").font(JETBRAINS_MONO_BOLD).size(17),
+        )
+        .on_press(on_message(ValidationReportMessage::DismissPressed))
+        .padding(10)
+        .style(Bl3UiStyle)
+        .into_element(),
+    );
+
+    Some(
+        Container::new(contents)
+            .width(Length::Fill)
+            .padding(20)
+            .style(Bl3UiStyle),
+    )
+}
@@ -0,0 +1,171 @@
+use iced::alignment::{Horizontal, Vertical};
+use iced::{button, container, Alignment, Button, Color, Column, Container, Length, Row, Text};
+
+use crate::bl3_ui::{Bl3Message, InteractionMessage};
+use crate::bl3_ui_style::{Bl3UiPositiveButtonStyle, Bl3UiStyle};
+use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
+
+/// A yes/no prompt that takes over the content area until one of its buttons is pressed.
+///
+/// Enter presses the confirm button and Escape presses the cancel button.
+#[derive(Debug)]
+pub struct ConfirmationDialog {
+    title: String,
+    body: String,
+    confirm: DialogButton,
+    extra: Option<DialogButton>,
+    cancel: DialogButton,
+}
+
+#[derive(Debug)]
+struct DialogButton {
+    label: String,
+    on_press: Bl3Message,
+    state: button::State,
+}
+
+impl DialogButton {
+    fn new<S: AsRef<str>>(label: S, on_press: Bl3Message) -> Self {
+        DialogButton {
+            label: label.as_ref().to_owned(),
+            on_press,
+            state: button::State::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum ConfirmationDialogMessage {
+    ConfirmPressed,
+    ExtraPressed,
+    CancelPressed,
+}
+
+impl ConfirmationDialog {
+    pub fn new<T, B, C>(title: T, body: B, confirm_label: C, on_confirm: Bl3Message) -> Self
+    where
+        T: AsRef<str>,
+        B: AsRef<str>,
+        C: AsRef<str>,
+    {
+        ConfirmationDialog {
+            title: title.as_ref().to_owned(),
+            body: body.as_ref().to_owned(),
+            confirm: DialogButton::new(confirm_label, on_confirm),
+            extra: None,
+            cancel: DialogButton::new(
+                "Cancel",
+                Bl3Message::Interaction(InteractionMessage::Ignore),
+            ),
+        }
+    }
+
+    /// The message sent when the dialog is cancelled, by default nothing happens.
+    pub fn on_cancel(mut self, on_cancel: Bl3Message) -> Self {
+        self.cancel.on_press = on_cancel;
+        self
+    }
+
+    /// Adds a third button between confirm and cancel.
+    pub fn extra_button<S: AsRef<str>>(mut self, label: S, on_press: Bl3Message) -> Self {
+        self.extra = Some(DialogButton::new(label, on_press));
+        self
+    }
+
+    /// Closes the dialog, returning the message of the button that was pressed.
+    pub fn into_message(self, message: ConfirmationDialogMessage) -> Option<Bl3Message> {
+        match message {
+            ConfirmationDialogMessage::ConfirmPressed => Some(self.confirm.on_press),
+            ConfirmationDialogMessage::ExtraPressed => self.extra.map(|e| e.on_press),
+            ConfirmationDialogMessage::CancelPressed => Some(self.cancel.on_press),
+        }
+    }
+
+    pub fn view(&mut self) -> Container<Bl3Message> {
+        let mut buttons = Row::new().spacing(15).push(
+            Button::new(
+                &mut self.confirm.state,
+                Text::new(format!("{} [Enter]", self.confirm.label))
+                    .font(JETBRAINS_MONO_BOLD)
+                    .size(17),
+            )
+            .on_press(Bl3Message::ConfirmationDialog(
+                ConfirmationDialogMessage::ConfirmPressed,
+            ))
+            .padding(10)
+            .style(Bl3UiPositiveButtonStyle),
+        );
+
+        if let Some(extra) = &mut self.extra {
+            buttons = buttons.push(
+                Button::new(
+                    &mut extra.state,
+                    Text::new(&extra.label).font(JETBRAINS_MONO_BOLD).size(17),
+                )
+                .on_press(Bl3Message::ConfirmationDialog(
+                    ConfirmationDialogMessage::ExtraPressed,
+                ))
+                .padding(10)
+                .style(Bl3UiStyle),
+            );
+        }
+
+        buttons = buttons.push(
+            Button::new(
+                &mut self.cancel.state,
+                Text::new(format!("{} [Esc]", self.cancel.label))
+                    .font(JETBRAINS_MONO_BOLD)
+                    .size(17),
+            )
+            .on_press(Bl3Message::ConfirmationDialog(
+                ConfirmationDialogMessage::CancelPressed,
+            ))
+            .padding(10)
+            .style(Bl3UiStyle),
+        );
+
+        let dialog = Container::new(
+            Column::new()
+                .push(
+                    Text::new(&self.title)
+                        .font(JETBRAINS_MONO_BOLD)
+                        .size(19)
+                        .color(Color::from_rgb8(242, 203, 5)),
+                )
+                .push(
+                    Text::new(&self.body)
+                        .font(JETBRAINS_MONO)
+                        .size(17)
+                        .color(Color::from_rgb8(220, 220, 220)),
+                )
+                .push(
+                    Container::new(buttons)
+                        .width(Length::Fill)
+                        .align_x(Horizontal::Right),
+                )
+                .spacing(20)
+                .align_items(Alignment::Start),
+        )
+        .padding(25)
+        .max_width(700)
+        .style(Bl3UiStyle);
+
+        Container::new(dialog)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .align_x(Horizontal::Center)
+            .align_y(Vertical::Center)
+            .style(DimmedContentStyle)
+    }
+}
+
+struct DimmedContentStyle;
+
+impl container::StyleSheet for DimmedContentStyle {
+    fn style(&self) -> container::Style {
+        container::Style {
+            background: Color::from_rgb8(12, 12, 12).into(),
+            ..container::Style::default()
+        }
+    }
+}
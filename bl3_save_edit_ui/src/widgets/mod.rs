@@ -1,5 +1,7 @@
+pub mod confirmation_dialog;
 pub mod labelled_element;
 pub mod notification;
 pub mod number_input;
+pub mod skill_tree;
 pub mod text_input_limited;
 pub mod text_margin;
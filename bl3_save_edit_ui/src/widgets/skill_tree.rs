@@ -0,0 +1,316 @@
+use iced::alignment::{Horizontal, Vertical};
+use iced::canvas::event::Status;
+use iced::canvas::{self, Cache, Canvas, Cursor, Event, Frame, Geometry, Path, Program, Stroke};
+use iced::{mouse, Color, Element, Length, Point, Rectangle, Size};
+
+use bl3_save_edit_core::bl3_save::skill_tree::SkillTreeItem;
+
+use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
+
+const HEADER_HEIGHT: f32 = 40.0;
+const TIER_HEIGHT: f32 = 70.0;
+const NODE_SIZE: Size = Size {
+    width: 120.0,
+    height: 46.0,
+};
+
+#[derive(Debug, Clone)]
+pub enum SkillTreeInteractionMessage {
+    SetSkillRank(usize, i32),
+}
+
+/// Draws every skill tree of a character side by side, one row per tier. Left clicking a skill adds
+/// a rank (wrapping back to 0 after the max) and right clicking removes one.
+#[derive(Debug, Default)]
+pub struct SkillTreeWidget {
+    items: Vec<SkillTreeItem>,
+    cache: Cache,
+}
+
+impl SkillTreeWidget {
+    pub fn items(&self) -> &[SkillTreeItem] {
+        &self.items
+    }
+
+    pub fn set_items(&mut self, items: Vec<SkillTreeItem>) {
+        self.items = items;
+        self.cache.clear();
+    }
+
+    pub fn set_skill_rank(&mut self, node_id: usize, rank: i32) {
+        if let Some(item) = self.items.get_mut(node_id) {
+            item.points = rank.clamp(0, item.max_points);
+            self.cache.clear();
+        }
+    }
+
+    pub fn spent_points(&self) -> i32 {
+        self.items.iter().map(|i| i.points).sum()
+    }
+
+    fn trees(&self) -> Vec<i32> {
+        let mut trees = self.items.iter().map(|i| i.tree).collect::<Vec<_>>();
+        trees.dedup();
+        trees
+    }
+
+    fn height(&self) -> f32 {
+        let tiers = self.items.iter().map(|i| i.tier + 1).max().unwrap_or(0);
+
+        HEADER_HEIGHT + tiers as f32 * TIER_HEIGHT
+    }
+
+    fn tree_bounds(&self, size: Size) -> Vec<(i32, Rectangle)> {
+        let trees = self.trees();
+        let tree_width = size.width / trees.len().max(1) as f32;
+
+        trees
+            .into_iter()
+            .enumerate()
+            .map(|(i, tree)| {
+                (
+                    tree,
+                    Rectangle {
+                        x: i as f32 * tree_width,
+                        y: 0.0,
+                        width: tree_width,
+                        height: size.height,
+                    },
+                )
+            })
+            .collect()
+    }
+
+    fn node_bounds(&self, size: Size) -> Vec<Rectangle> {
+        let tree_bounds = self.tree_bounds(size);
+
+        self.items
+            .iter()
+            .map(|item| {
+                let tree = tree_bounds
+                    .iter()
+                    .find(|(t, _)| *t == item.tree)
+                    .map(|(_, b)| *b)
+                    .unwrap_or_else(|| Rectangle::new(Point::ORIGIN, size));
+
+                let slots = self
+                    .items
+                    .iter()
+                    .filter(|i| i.tree == item.tree)
+                    .map(|i| i.slot + 1)
+                    .max()
+                    .unwrap_or(1);
+
+                let slot_width = tree.width / slots as f32;
+
+                let center = Point::new(
+                    tree.x + (item.slot as f32 + 0.5) * slot_width,
+                    HEADER_HEIGHT + (item.tier as f32 + 0.5) * TIER_HEIGHT,
+                );
+
+                let width = NODE_SIZE.width.min(slot_width - 10.0);
+
+                Rectangle {
+                    x: center.x - width / 2.0,
+                    y: center.y - NODE_SIZE.height / 2.0,
+                    width,
+                    height: NODE_SIZE.height,
+                }
+            })
+            .collect()
+    }
+
+    fn draw_tree_lines(&self, frame: &mut Frame, node_bounds: &[Rectangle]) {
+        let line = Stroke::default()
+            .with_color(Color::from_rgb8(70, 70, 70))
+            .with_width(2.0);
+
+        for (tree, tree_bounds) in self.tree_bounds(frame.size()) {
+            let tree_nodes = self
+                .items
+                .iter()
+                .zip(node_bounds)
+                .filter(|(i, _)| i.tree == tree)
+                .collect::<Vec<_>>();
+
+            let last_tier = tree_nodes.iter().map(|(i, _)| i.tier).max().unwrap_or(0);
+            let spine_x = tree_bounds.center_x();
+
+            frame.stroke(
+                &Path::line(
+                    Point::new(spine_x, HEADER_HEIGHT),
+                    Point::new(
+                        spine_x,
+                        HEADER_HEIGHT + (last_tier as f32 + 0.5) * TIER_HEIGHT,
+                    ),
+                ),
+                line,
+            );
+
+            for (_, node) in tree_nodes {
+                frame.stroke(
+                    &Path::line(
+                        Point::new(spine_x, node.center_y()),
+                        Point::new(node.center_x(), node.center_y()),
+                    ),
+                    line,
+                );
+            }
+        }
+    }
+
+    fn draw_node(&self, frame: &mut Frame, item: &SkillTreeItem, bounds: Rectangle, hovered: bool) {
+        let (background, border) = if item.points >= item.max_points {
+            (
+                Color::from_rgb8(29, 54, 35),
+                Color::from_rgb8(149, 240, 172),
+            )
+        } else if item.points > 0 {
+            (Color::from_rgb8(54, 45, 29), Color::from_rgb8(224, 170, 90))
+        } else {
+            (Color::from_rgb8(30, 30, 30), Color::from_rgb8(70, 70, 70))
+        };
+
+        let node = Path::rectangle(bounds.position(), bounds.size());
+
+        frame.fill(&node, background);
+        frame.stroke(
+            &node,
+            Stroke::default()
+                .with_color(border)
+                .with_width(if hovered { 3.0 } else { 1.5 }),
+        );
+
+        frame.fill_text(canvas::Text {
+            content: item.name(),
+            position: Point::new(bounds.center_x(), bounds.y + 14.0),
+            color: Color::from_rgb8(220, 220, 220),
+            size: 14.0,
+            font: JETBRAINS_MONO,
+            horizontal_alignment: Horizontal::Center,
+            vertical_alignment: Vertical::Center,
+        });
+
+        frame.fill_text(canvas::Text {
+            content: format!("{} / {}", item.points, item.max_points),
+            position: Point::new(bounds.center_x(), bounds.y + 32.0),
+            color: border,
+            size: 15.0,
+            font: JETBRAINS_MONO_BOLD,
+            horizontal_alignment: Horizontal::Center,
+            vertical_alignment: Vertical::Center,
+        });
+    }
+
+    pub fn view(&mut self) -> Element<SkillTreeInteractionMessage> {
+        let height = self.height();
+
+        Canvas::new(self)
+            .width(Length::Fill)
+            .height(Length::Units(height as u16))
+            .into()
+    }
+}
+
+impl Program<SkillTreeInteractionMessage> for SkillTreeWidget {
+    fn update(
+        &mut self,
+        event: Event,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> (Status, Option<SkillTreeInteractionMessage>) {
+        let cursor_position = match cursor.position_in(&bounds) {
+            Some(position) => position,
+            None => return (Status::Ignored, None),
+        };
+
+        let rank_change = match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => 1,
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right)) => -1,
+            _ => return (Status::Ignored, None),
+        };
+
+        let clicked_node = self
+            .node_bounds(bounds.size())
+            .iter()
+            .position(|b| b.contains(cursor_position));
+
+        match clicked_node {
+            Some(node_id) => {
+                let item = &self.items[node_id];
+
+                let rank = match item.points + rank_change {
+                    rank if rank > item.max_points => 0,
+                    rank => rank.max(0),
+                };
+
+                (
+                    Status::Captured,
+                    Some(SkillTreeInteractionMessage::SetSkillRank(node_id, rank)),
+                )
+            }
+            None => (Status::Ignored, None),
+        }
+    }
+
+    fn draw(&self, bounds: Rectangle, cursor: Cursor) -> Vec<Geometry> {
+        let tree = self.cache.draw(bounds.size(), |frame| {
+            let node_bounds = self.node_bounds(frame.size());
+
+            self.draw_tree_lines(frame, &node_bounds);
+
+            for (tree, tree_bounds) in self.tree_bounds(frame.size()) {
+                let tree_name = self
+                    .items
+                    .iter()
+                    .find(|i| i.tree == tree)
+                    .map(|i| i.tree_name.clone())
+                    .unwrap_or_default();
+
+                frame.fill_text(canvas::Text {
+                    content: tree_name,
+                    position: Point::new(tree_bounds.center_x(), HEADER_HEIGHT / 2.0),
+                    color: Color::from_rgb8(242, 203, 5),
+                    size: 18.0,
+                    font: JETBRAINS_MONO_BOLD,
+                    horizontal_alignment: Horizontal::Center,
+                    vertical_alignment: Vertical::Center,
+                });
+            }
+
+            for (item, node) in self.items.iter().zip(node_bounds) {
+                self.draw_node(frame, item, node, false);
+            }
+        });
+
+        // Only the hovered node is redrawn each frame, the rest of the tree stays cached
+        let mut hover = Frame::new(bounds.size());
+
+        if let Some(cursor_position) = cursor.position_in(&bounds) {
+            if let Some((item, node)) = self
+                .items
+                .iter()
+                .zip(self.node_bounds(bounds.size()))
+                .find(|(_, node)| node.contains(cursor_position))
+            {
+                self.draw_node(&mut hover, item, node, true);
+            }
+        }
+
+        vec![tree, hover.into_geometry()]
+    }
+
+    fn mouse_interaction(&self, bounds: Rectangle, cursor: Cursor) -> mouse::Interaction {
+        let is_over_node = cursor.position_in(&bounds).is_some_and(|position| {
+            self.node_bounds(bounds.size())
+                .iter()
+                .any(|b| b.contains(position))
+        });
+
+        if is_over_node {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}